@@ -31,6 +31,7 @@ cd sea-orm-sync
 replace_rs "s/Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>>/Result<Self::Stream<'a>, DbErr>/" src
 replace_rs "s/Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'b>>/Result<T, E>/" src
 replace_rs "s/Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>/Result<T, E>/" src
+replace_rs "s/Pin<Box<dyn Future<Output = Result<Vec<Option<Self::Model>>, DbErr>> + Send + 'a>>/Result<Vec<Option<Self::Model>>, DbErr>/" src
 replace_rs 's/Box::pin(async move {/({/' src
 replace_rs 's/Box::pin(async move {/({/' tests
 replace_rs 's/AsyncFnOnce/FnOnce/g' src
@@ -88,8 +89,6 @@ replace_rs '/\/\/\/ use futures_util/d' src
 replace_rs '/use futures_util::future::BoxFuture;/d' src
 replace_rs '/use async_stream::/d' src
 replace_rs '/use futures_util::StreamExt/d' tests
-replace_rs 's/self.conn.try_lock()/self.conn.try_lock().ok()/' src
-
-sed_in_place 's/self.conn.try_lock().ok()/self.conn.try_lock()/' ./src/driver/rusqlite.rs
+replace_rs 's/self.conn.conn.try_lock()/self.conn.conn.try_lock().ok()/' src
 
 cargo +nightly fmt
@@ -0,0 +1,39 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use sea_orm::DbErr;
+
+/// A [`DbErr`] turned into a `500 Internal Server Error` response, so handlers
+/// can use `?` on database operations.
+///
+/// The error is not exposed in the response body, it is stored in the response
+/// extensions instead, for a logging middleware to pick it up.
+#[derive(Debug, Clone)]
+pub struct DbError(pub DbErr);
+
+impl From<DbErr> for DbError {
+    fn from(err: DbErr) -> Self {
+        Self(err)
+    }
+}
+
+impl std::fmt::Display for DbError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for DbError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+impl IntoResponse for DbError {
+    fn into_response(self) -> Response {
+        let mut res = StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        res.extensions_mut().insert(self.0);
+        res
+    }
+}
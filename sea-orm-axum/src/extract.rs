@@ -0,0 +1,85 @@
+use std::{ops::Deref, sync::Arc};
+
+use axum::{
+    extract::{FromRef, FromRequestParts},
+    http::{StatusCode, request::Parts},
+    response::{IntoResponse, Response},
+};
+use sea_orm::{DatabaseConnection, DatabaseTransaction};
+
+/// Extracts the [`DatabaseConnection`] from the application state.
+///
+/// The state must be a `DatabaseConnection`, or implement
+/// [`FromRef`] for it.
+#[derive(Debug, Clone)]
+pub struct DbConn(pub DatabaseConnection);
+
+impl<S> FromRequestParts<S> for DbConn
+where
+    DatabaseConnection: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(_: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self(DatabaseConnection::from_ref(state)))
+    }
+}
+
+impl Deref for DbConn {
+    type Target = DatabaseConnection;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Extracts the transaction opened for the current request by
+/// [`TransactionLayer`](crate::TransactionLayer).
+///
+/// The transaction is committed or rolled back by the layer once the handler
+/// has returned, it must not be kept alive beyond that.
+#[derive(Debug, Clone)]
+pub struct DbTxn(pub Arc<DatabaseTransaction>);
+
+impl<S> FromRequestParts<S> for DbTxn
+where
+    S: Send + Sync,
+{
+    type Rejection = MissingTransaction;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<DbTxn>()
+            .cloned()
+            .ok_or(MissingTransaction)
+    }
+}
+
+impl Deref for DbTxn {
+    type Target = DatabaseTransaction;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Rejection of [`DbTxn`] when the route is not wrapped in a
+/// [`TransactionLayer`](crate::TransactionLayer)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingTransaction;
+
+impl std::fmt::Display for MissingTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("No transaction for this request, is the route wrapped in a TransactionLayer?")
+    }
+}
+
+impl std::error::Error for MissingTransaction {}
+
+impl IntoResponse for MissingTransaction {
+    fn into_response(self) -> Response {
+        (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response()
+    }
+}
@@ -0,0 +1,145 @@
+use std::{
+    fmt,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use axum::{
+    extract::Request,
+    response::{IntoResponse, Response},
+};
+use futures_util::future::BoxFuture;
+use sea_orm::{DatabaseConnection, DbErr, TransactionTrait};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::{DbError, DbTxn};
+
+type ErrorHook = Arc<dyn Fn(DbErr) -> Response + Send + Sync>;
+
+/// A [`Layer`] running every request in a transaction, extracted by handlers
+/// with [`DbTxn`].
+///
+/// The transaction is committed if the response is successful (`2xx`), and
+/// rolled back otherwise. If the handler panics or the request is cancelled,
+/// the transaction is rolled back when dropped.
+///
+/// Failing to begin or commit the transaction results in a `500 Internal Server Error`,
+/// see [`TransactionLayer::on_error`] to customize the response.
+#[derive(Clone)]
+pub struct TransactionLayer<C = DatabaseConnection> {
+    db: C,
+    on_error: ErrorHook,
+}
+
+impl<C> TransactionLayer<C>
+where
+    C: TransactionTrait + Clone + Send + Sync + 'static,
+{
+    /// Begin transactions on the given connection
+    pub fn new(db: C) -> Self {
+        Self {
+            db,
+            on_error: Arc::new(|err| DbError(err).into_response()),
+        }
+    }
+
+    /// Map an error beginning, committing or rolling back the transaction to a
+    /// response, instead of the default `500 Internal Server Error` of [`DbError`]
+    pub fn on_error<F>(mut self, on_error: F) -> Self
+    where
+        F: Fn(DbErr) -> Response + Send + Sync + 'static,
+    {
+        self.on_error = Arc::new(on_error);
+        self
+    }
+}
+
+impl<C: fmt::Debug> fmt::Debug for TransactionLayer<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransactionLayer")
+            .field("db", &self.db)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, C> Layer<S> for TransactionLayer<C>
+where
+    C: Clone,
+{
+    type Service = TransactionService<S, C>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TransactionService {
+            inner,
+            db: self.db.clone(),
+            on_error: self.on_error.clone(),
+        }
+    }
+}
+
+/// The [`Service`] created by [`TransactionLayer`]
+#[derive(Clone)]
+pub struct TransactionService<S, C = DatabaseConnection> {
+    inner: S,
+    db: C,
+    on_error: ErrorHook,
+}
+
+impl<S: fmt::Debug, C: fmt::Debug> fmt::Debug for TransactionService<S, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransactionService")
+            .field("inner", &self.inner)
+            .field("db", &self.db)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, C> Service<Request> for TransactionService<S, C>
+where
+    S: Service<Request, Response = Response> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    C: TransactionTrait + Clone + Send + Sync + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request) -> Self::Future {
+        // the clone may not be ready, keep the service polled by `poll_ready`
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let db = self.db.clone();
+        let on_error = self.on_error.clone();
+
+        Box::pin(async move {
+            let txn = match db.begin().await {
+                Ok(txn) => Arc::new(txn),
+                Err(err) => return Ok(on_error(err)),
+            };
+            req.extensions_mut().insert(DbTxn(txn.clone()));
+
+            // on error or panic the transaction is dropped, hence rolled back
+            let res = inner.call(req).await?;
+
+            let Ok(txn) = Arc::try_unwrap(txn) else {
+                return Ok(on_error(DbErr::Custom(
+                    "Transaction is still in use after the handler returned".to_owned(),
+                )));
+            };
+            let result = if res.status().is_success() {
+                txn.commit().await
+            } else {
+                txn.rollback().await
+            };
+            match result {
+                Ok(()) => Ok(res),
+                Err(err) => Ok(on_error(err)),
+            }
+        })
+    }
+}
@@ -0,0 +1,45 @@
+//! SeaORM Axum support crate.
+//!
+//! Provides [`DbConn`] to extract a [`DatabaseConnection`](sea_orm::DatabaseConnection)
+//! from the application state, and a [`TransactionLayer`] running every request in
+//! a transaction, extracted by handlers with [`DbTxn`].
+//!
+//! ```
+//! use axum::{
+//!     Router,
+//!     routing::{get, post},
+//! };
+//! use sea_orm::{ConnectionTrait, DatabaseConnection};
+//! use sea_orm_axum::{DbConn, DbError, DbTxn, TransactionLayer};
+//!
+//! async fn health(DbConn(db): DbConn) -> Result<&'static str, DbError> {
+//!     db.ping().await?;
+//!     Ok("ok")
+//! }
+//!
+//! async fn create_post(DbTxn(txn): DbTxn) -> Result<&'static str, DbError> {
+//!     // committed if the handler returns a 2xx response, rolled back otherwise
+//!     txn.execute_unprepared("INSERT INTO post (title) VALUES ('Hello')")
+//!         .await?;
+//!     Ok("created")
+//! }
+//!
+//! fn app(db: DatabaseConnection) -> Router {
+//!     Router::new()
+//!         .route("/health", get(health))
+//!         .route("/posts", post(create_post))
+//!         .layer(TransactionLayer::new(db.clone()))
+//!         .with_state(db)
+//! }
+//! ```
+#![deny(missing_docs)]
+
+pub use axum;
+
+mod error;
+mod extract;
+mod layer;
+
+pub use self::error::DbError;
+pub use self::extract::{DbConn, DbTxn, MissingTransaction};
+pub use self::layer::{TransactionLayer, TransactionService};
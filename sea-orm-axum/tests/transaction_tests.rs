@@ -0,0 +1,177 @@
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode},
+    response::IntoResponse,
+    routing::get,
+};
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, DbBackend, DbErr, MockDatabase, MockExecResult, Statement,
+    Transaction,
+};
+use sea_orm_axum::{DbConn, DbError, DbTxn, TransactionLayer};
+use std::sync::{Arc, Mutex};
+use tower::ServiceExt;
+
+const INSERT: &str = "INSERT INTO post (title) VALUES ('Hello')";
+
+fn mock_db() -> DatabaseConnection {
+    MockDatabase::new(DbBackend::Postgres)
+        .append_exec_results([MockExecResult {
+            last_insert_id: 1,
+            rows_affected: 1,
+        }])
+        .into_connection()
+}
+
+fn app(db: &DatabaseConnection) -> Router {
+    Router::new()
+        .route("/ok", get(ok))
+        .route("/err", get(err))
+        .route("/bad_request", get(bad_request))
+        .route("/panic", get(panic))
+        .layer(TransactionLayer::new(db.clone()))
+}
+
+fn transaction(end: &str) -> Transaction {
+    Transaction::many([
+        Statement::from_string(DbBackend::Postgres, "BEGIN"),
+        Statement::from_string(DbBackend::Postgres, INSERT),
+        Statement::from_string(DbBackend::Postgres, end),
+    ])
+}
+
+fn get_request(uri: &str) -> Request<Body> {
+    Request::get(uri).body(Body::empty()).unwrap()
+}
+
+async fn ok(txn: DbTxn) -> Result<&'static str, DbError> {
+    txn.execute_unprepared(INSERT).await?;
+    Ok("ok")
+}
+
+async fn err(txn: DbTxn) -> Result<&'static str, DbError> {
+    txn.execute_unprepared(INSERT).await?;
+    Err(DbErr::Custom("Post is invalid".to_owned()).into())
+}
+
+async fn bad_request(txn: DbTxn) -> Result<StatusCode, DbError> {
+    txn.execute_unprepared(INSERT).await?;
+    Ok(StatusCode::BAD_REQUEST)
+}
+
+async fn panic(txn: DbTxn) -> &'static str {
+    txn.execute_unprepared(INSERT).await.unwrap();
+    panic!("Handler panicked");
+}
+
+#[tokio::test]
+async fn commit_on_success() {
+    let db = mock_db();
+
+    let res = app(&db).oneshot(get_request("/ok")).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    assert_eq!(db.into_transaction_log(), [transaction("COMMIT")]);
+}
+
+#[tokio::test]
+async fn rollback_on_error() {
+    let db = mock_db();
+
+    let res = app(&db).oneshot(get_request("/err")).await.unwrap();
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(
+        res.extensions().get::<DbErr>().map(ToString::to_string),
+        Some("Custom Error: Post is invalid".to_owned())
+    );
+
+    assert_eq!(db.into_transaction_log(), [transaction("ROLLBACK")]);
+}
+
+#[tokio::test]
+async fn rollback_on_client_error() {
+    let db = mock_db();
+
+    let res = app(&db).oneshot(get_request("/bad_request")).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    assert_eq!(db.into_transaction_log(), [transaction("ROLLBACK")]);
+}
+
+#[tokio::test]
+async fn rollback_on_panic() {
+    let db = mock_db();
+
+    let res = tokio::spawn(app(&db).oneshot(get_request("/panic"))).await;
+    assert!(res.unwrap_err().is_panic());
+
+    assert_eq!(db.into_transaction_log(), [transaction("ROLLBACK")]);
+}
+
+#[tokio::test]
+async fn error_hook() {
+    let db = mock_db();
+    let kept: Arc<Mutex<Option<DbTxn>>> = Default::default();
+
+    // the handler keeps the transaction alive, it can't be committed
+    let app = Router::new()
+        .route(
+            "/keep",
+            get({
+                let kept = kept.clone();
+                move |txn: DbTxn| async move {
+                    txn.execute_unprepared(INSERT).await.unwrap();
+                    kept.lock().unwrap().replace(txn);
+                    "ok"
+                }
+            }),
+        )
+        .layer(
+            TransactionLayer::new(db.clone())
+                .on_error(|_| StatusCode::SERVICE_UNAVAILABLE.into_response()),
+        );
+
+    let res = app.oneshot(get_request("/keep")).await.unwrap();
+    assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+    // rolled back once dropped
+    kept.lock().unwrap().take();
+    assert_eq!(db.into_transaction_log(), [transaction("ROLLBACK")]);
+}
+
+#[tokio::test]
+async fn missing_transaction() {
+    let db = mock_db();
+    let app = Router::new().route("/ok", get(ok));
+
+    let res = app.oneshot(get_request("/ok")).await.unwrap();
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    assert!(db.into_transaction_log().is_empty());
+}
+
+#[tokio::test]
+async fn db_conn_from_state() {
+    async fn insert(DbConn(db): DbConn) -> Result<&'static str, DbError> {
+        db.execute_unprepared(INSERT).await?;
+        Ok("ok")
+    }
+
+    let db = mock_db();
+    let app = Router::new()
+        .route("/insert", get(insert))
+        .with_state(db.clone());
+
+    let res = app.oneshot(get_request("/insert")).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    assert_eq!(
+        db.into_transaction_log(),
+        [Transaction::from_sql_and_values(
+            DbBackend::Postgres,
+            INSERT,
+            []
+        )]
+    );
+}
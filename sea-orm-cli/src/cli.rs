@@ -144,7 +144,19 @@ pub enum MigrateSubcommands {
     #[command(about = "Rollback all applied migrations", display_order = 50)]
     Reset,
     #[command(about = "Check the status of all migrations", display_order = 60)]
-    Status,
+    Status {
+        #[arg(
+            long,
+            help = "Print the status as a JSON array of {name, status, applied_at}"
+        )]
+        json: bool,
+
+        #[arg(
+            long,
+            help = "Exit with code 1 if any migration is pending, or 2 if the migration table is missing"
+        )]
+        exit_code: bool,
+    },
     #[command(about = "Apply pending migrations", display_order = 70)]
     Up {
         #[arg(short, long, help = "Number of pending migrations to apply")]
@@ -161,6 +173,30 @@ pub enum MigrateSubcommands {
         )]
         num: u32,
     },
+    #[command(
+        about = "Record a migration as applied without running it",
+        display_order = 100
+    )]
+    MarkApplied {
+        #[arg(required = true, help = "Name of the migration")]
+        migration_name: String,
+    },
+    #[command(
+        about = "Remove the record of an applied migration without rolling it back",
+        display_order = 110
+    )]
+    MarkRolledBack {
+        #[arg(required = true, help = "Name of the migration")]
+        migration_name: String,
+    },
+    #[command(
+        about = "Squash applied migrations up to and including the given one into a baseline migration (Postgres only)",
+        display_order = 120
+    )]
+    Squash {
+        #[arg(required = true, help = "Name of the last migration to squash")]
+        migration_name: String,
+    },
 }
 
 #[derive(Subcommand, PartialEq, Eq, Debug)]
@@ -190,8 +226,10 @@ pub enum GenerateSubcommands {
         #[arg(
             short = 't',
             long,
+            visible_alias = "only-tables",
             value_delimiter = ',',
-            help = "Generate entity file for specified tables only (comma separated)"
+            help = "Generate entity file for specified tables only (comma separated, glob patterns \
+                like 'billing_*' are accepted)"
         )]
         tables: Vec<String>,
 
@@ -199,7 +237,8 @@ pub enum GenerateSubcommands {
             long,
             value_delimiter = ',',
             default_value = "seaql_migrations",
-            help = "Skip generating entity file for specified tables (comma separated)"
+            help = "Skip generating entity file for specified tables (comma separated, glob patterns \
+                like 'tmp_*' are accepted)"
         )]
         ignore_tables: Vec<String>,
 
@@ -231,7 +270,8 @@ pub enum GenerateSubcommands {
             env = "DATABASE_SCHEMA",
             long_help = "Database schema\n \
                         - For MySQL, this argument is ignored.\n \
-                        - For PostgreSQL, this argument is optional with default value 'public'."
+                        - For PostgreSQL, this argument is optional with default value 'public'.\n \
+                          Comma separated schemas, e.g. 'billing,audit', are generated into a module per schema."
         )]
         database_schema: Option<String>,
 
@@ -282,6 +322,16 @@ pub enum GenerateSubcommands {
         )]
         with_copy_enums: bool,
 
+        #[arg(
+            long,
+            default_value = "false",
+            long_help = "Implement Display and FromStr on generated enums.\n\
+            Both use the database value of each variant, so enums can be parsed from and \
+            rendered to strings, e.g. in query strings or CLI arguments.
+            "
+        )]
+        impl_enum_display_fromstr: bool,
+
         #[arg(
             long,
             default_value_t,
@@ -343,6 +393,14 @@ pub enum GenerateSubcommands {
         )]
         seaography: bool,
 
+        #[arg(
+            long,
+            value_delimiter = ',',
+            requires = "seaography",
+            help = "Skip relations in the Seaography `RelatedEntity` enum, e.g. `--seaography-skip-relations messages.UsersRecipientId`"
+        )]
+        seaography_skip_relations: Vec<String>,
+
         #[arg(
             long,
             default_value = "true",
@@ -77,11 +77,8 @@ fn process_comma_separated_values(values: Vec<String>) -> Vec<String> {
         .collect()
 }
 
-/// Whether a discovered SQLite column is a generated (computed) column.
-///
-/// Generated columns cannot be inserted or updated, so they are dropped from
-/// generated entities — emitting them as ordinary fields makes every
-/// `INSERT`/`UPDATE` fail with "cannot INSERT/UPDATE a generated column" (#3094).
+/// Whether a discovered SQLite column is a generated (computed) column,
+/// as reported by the `hidden` field of `PRAGMA table_xinfo`.
 #[cfg(feature = "sqlx-sqlite")]
 fn sqlite_column_is_generated(col: &sea_schema::sqlite::def::ColumnInfo) -> bool {
     use sea_schema::sqlite::def::ColumnVisibility;
@@ -91,6 +88,230 @@ fn sqlite_column_is_generated(col: &sea_schema::sqlite::def::ColumnInfo) -> bool
     )
 }
 
+/// What `PRAGMA table_xinfo` does not tell about a SQLite table, recovered
+/// from the `CREATE TABLE` statement kept in `sqlite_master`.
+#[cfg(feature = "sqlx-sqlite")]
+#[derive(Debug, Default, PartialEq)]
+struct SqliteTableSql {
+    /// Whether the table is declared `STRICT`
+    strict: bool,
+    /// The declared type of each column, if any
+    types: Vec<(String, String)>,
+    /// The expression of each generated column
+    generated: Vec<(String, String)>,
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+impl SqliteTableSql {
+    fn parse(sql: &str) -> Self {
+        let mut table = Self::default();
+        let Some(open) = sql.find('(') else {
+            return table;
+        };
+        let Some(close) = sqlite_closing_paren(sql, open) else {
+            return table;
+        };
+        table.strict = sql[close + 1..]
+            .split(',')
+            .any(|option| option.trim().eq_ignore_ascii_case("strict"));
+
+        for def in sqlite_split_defs(&sql[open + 1..close]) {
+            let mut words = def.split_whitespace();
+            let Some(name) = words.next() else {
+                continue;
+            };
+            if ["constraint", "primary", "unique", "check", "foreign"]
+                .iter()
+                .any(|keyword| name.eq_ignore_ascii_case(keyword))
+            {
+                continue;
+            }
+            let name = name.trim_matches(['"', '`', '[', ']']).to_owned();
+            if let Some(ty) = words.next()
+                && !ty.starts_with('(')
+            {
+                table.types.push((name.clone(), ty.to_owned()));
+            }
+            if let Some(expr) = sqlite_generated_expr(def) {
+                table.generated.push((name, expr.to_owned()));
+            }
+        }
+        table
+    }
+
+    fn declared_type(&self, column: &str) -> Option<&str> {
+        Self::find(&self.types, column)
+    }
+
+    fn generated_expr(&self, column: &str) -> Option<&str> {
+        Self::find(&self.generated, column)
+    }
+
+    fn find<'a>(entries: &'a [(String, String)], column: &str) -> Option<&'a str> {
+        entries
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(column))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// The position of the parenthesis closing the one at `open`
+#[cfg(feature = "sqlx-sqlite")]
+fn sqlite_closing_paren(sql: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    sqlite_unquoted(&sql[open..]).find_map(|(i, c)| match c {
+        '(' => {
+            depth += 1;
+            None
+        }
+        ')' => {
+            depth -= 1;
+            (depth == 0).then_some(open + i)
+        }
+        _ => None,
+    })
+}
+
+/// Split the body of a `CREATE TABLE` statement into its column and
+/// constraint definitions
+#[cfg(feature = "sqlx-sqlite")]
+fn sqlite_split_defs(body: &str) -> Vec<&str> {
+    let mut defs = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in sqlite_unquoted(body) {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                defs.push(body[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    defs.push(body[start..].trim());
+    defs.retain(|def| !def.is_empty());
+    defs
+}
+
+/// The characters of `sql` outside of string literals and quoted identifiers
+#[cfg(feature = "sqlx-sqlite")]
+fn sqlite_unquoted(sql: &str) -> impl Iterator<Item = (usize, char)> + '_ {
+    let mut quote = None;
+    sql.char_indices().filter(move |&(_, c)| match quote {
+        Some(q) => {
+            if c == q {
+                quote = None;
+            }
+            false
+        }
+        None => {
+            if matches!(c, '\'' | '"' | '`' | '[') {
+                quote = Some(if c == '[' { ']' } else { c });
+                false
+            } else {
+                true
+            }
+        }
+    })
+}
+
+/// The expression of a `[GENERATED ALWAYS] AS (expr)` column definition
+#[cfg(feature = "sqlx-sqlite")]
+fn sqlite_generated_expr(def: &str) -> Option<&str> {
+    let upper = def.to_ascii_uppercase();
+    let mut from = 0;
+    while let Some(pos) = upper[from..].find("AS") {
+        let at = from + pos;
+        from = at + 2;
+        let word_start = at == 0 || !upper.as_bytes()[at - 1].is_ascii_alphanumeric();
+        let rest = def[from..].trim_start();
+        if word_start && rest.starts_with('(') {
+            let open = def.len() - rest.len();
+            let close = sqlite_closing_paren(def, open)?;
+            return Some(def[open + 1..close].trim());
+        }
+    }
+    None
+}
+
+/// The column type of a column declared in a `STRICT` table, which can only
+/// be `INT`, `INTEGER`, `REAL`, `TEXT`, `BLOB` or `ANY`
+#[cfg(feature = "sqlx-sqlite")]
+fn sqlite_strict_column_type(declared: &str) -> Option<sea_schema::sea_query::ColumnType> {
+    use sea_schema::sea_query::{Alias, ColumnType, IntoIden};
+
+    Some(match declared.to_ascii_uppercase().as_str() {
+        "INT" | "INTEGER" => ColumnType::BigInteger,
+        "REAL" => ColumnType::Double,
+        "TEXT" => ColumnType::Text,
+        "BLOB" => ColumnType::Blob,
+        "ANY" => ColumnType::Custom(Alias::new("ANY").into_iden()),
+        _ => return None,
+    })
+}
+
+/// Write a discovered SQLite table, taking the column types of a `STRICT`
+/// table from their declaration and marking its generated columns.
+///
+/// Generated columns cannot be inserted or updated (#3094), so they are
+/// written with their expression and emitted with `#[sea_orm(generated)]`,
+/// which keeps them out of every `INSERT`/`UPDATE`. A generated column whose
+/// expression cannot be found is dropped.
+#[cfg(feature = "sqlx-sqlite")]
+fn sqlite_table_create_stmt(
+    table: &sea_schema::sqlite::def::TableDef,
+    sql: &SqliteTableSql,
+) -> sea_schema::sea_query::TableCreateStatement {
+    use sea_schema::sea_query::{Expr, Table};
+    use sea_schema::sqlite::def::ColumnVisibility;
+
+    let mut table = table.clone();
+    if sql.strict {
+        for col in table.columns.iter_mut() {
+            if let Some(col_type) = sql
+                .declared_type(&col.name)
+                .and_then(sqlite_strict_column_type)
+            {
+                col.r#type = col_type;
+            }
+        }
+    }
+    table
+        .columns
+        .retain(|col| !sqlite_column_is_generated(col) || sql.generated_expr(&col.name).is_some());
+
+    let stmt = table.write();
+    if !table.columns.iter().any(sqlite_column_is_generated) {
+        return stmt;
+    }
+
+    let mut marked = Table::create();
+    if let Some(table_ref) = stmt.get_table_name() {
+        marked.table(table_ref.clone());
+    }
+    for col_def in stmt.get_columns() {
+        let mut col_def = col_def.clone();
+        let column_name = col_def.get_column_name();
+        if let Some(col) = table.columns.iter().find(|col| col.name == column_name)
+            && let Some(expr) = sql.generated_expr(&col.name)
+            && sqlite_column_is_generated(col)
+        {
+            let stored = matches!(col.hidden, ColumnVisibility::GeneratedStored);
+            col_def.generated(Expr::cust(expr.to_owned()), stored);
+        }
+        marked.col(col_def);
+    }
+    for fk in stmt.get_foreign_key_create_stmts() {
+        marked.foreign_key(&mut fk.clone());
+    }
+    for index in stmt.get_indexes() {
+        marked.index(&mut index.clone());
+    }
+    marked
+}
+
 pub async fn run_generate_command(
     command: GenerateSubcommands,
     verbose: bool,
@@ -114,6 +335,7 @@ pub async fn run_generate_command(
             serde_skip_deserializing_primary_key,
             serde_skip_hidden_column,
             with_copy_enums,
+            impl_enum_display_fromstr,
             date_time_crate,
             big_integer_type,
             lib,
@@ -123,6 +345,7 @@ pub async fn run_generate_command(
             enum_extra_attributes,
             column_extra_derives,
             seaography,
+            seaography_skip_relations,
             impl_active_model_behavior,
             preserve_user_modifications,
             banner_version,
@@ -156,10 +379,8 @@ pub async fn run_generate_command(
             // above
             let is_sqlite = url.scheme() == "sqlite";
 
-            // Closures for filtering tables
-            let filter_tables =
-                |table: &String| -> bool { tables.is_empty() || tables.contains(table) };
-
+            // Closure for filtering hidden tables, `--tables` and `--ignore-tables` are
+            // applied by the `EntityWriterContext` below
             let filter_hidden_tables = |table: &str| -> bool {
                 if include_hidden_tables {
                     true
@@ -168,8 +389,6 @@ pub async fn run_generate_command(
                 }
             };
 
-            let filter_skip_tables = |table: &String| -> bool { !ignore_tables.contains(table) };
-
             let _database_name = if !is_sqlite {
                 // The database name should be the first element of the path string
                 //
@@ -225,9 +444,7 @@ pub async fn run_generate_command(
                         let table_stmts = schema
                             .tables
                             .into_iter()
-                            .filter(|schema| filter_tables(&schema.info.name))
                             .filter(|schema| filter_hidden_tables(&schema.info.name))
-                            .filter(|schema| filter_skip_tables(&schema.info.name))
                             .map(|mut schema| {
                                 // Skip generated columns (see #3094).
                                 schema.columns.retain(|col| !col.extra.generated);
@@ -256,6 +473,12 @@ pub async fn run_generate_command(
                         )
                         .await?;
                         println!("Discovering schema ...");
+                        let table_sqls: Vec<(String, String)> = sqlx::query_as(
+                            "SELECT name, sql FROM sqlite_master \
+                             WHERE type = 'table' AND sql IS NOT NULL",
+                        )
+                        .fetch_all(&connection)
+                        .await?;
                         let schema_discovery = SchemaDiscovery::new(connection);
                         let schema = schema_discovery
                             .discover()
@@ -264,17 +487,14 @@ pub async fn run_generate_command(
                         let table_stmts = schema
                             .tables
                             .into_iter()
-                            .filter(|schema| filter_tables(&schema.name))
                             .filter(|schema| filter_hidden_tables(&schema.name))
-                            .filter(|schema| filter_skip_tables(&schema.name))
-                            .map(|mut schema| {
-                                // Skip generated columns: codegen can't round-trip them, and
-                                // emitting them as ordinary fields makes INSERT/UPDATE fail
-                                // ("cannot INSERT/UPDATE a generated column"). See #3094.
-                                schema
-                                    .columns
-                                    .retain(|col| !sqlite_column_is_generated(col));
-                                schema.write()
+                            .map(|schema| {
+                                let sql = table_sqls
+                                    .iter()
+                                    .find(|(name, _)| *name == schema.name)
+                                    .map(|(_, sql)| SqliteTableSql::parse(sql))
+                                    .unwrap_or_default();
+                                sqlite_table_create_stmt(&schema, &sql)
                             })
                             .collect();
                         (None, table_stmts)
@@ -291,30 +511,57 @@ pub async fn run_generate_command(
                         use sqlx::Postgres;
 
                         println!("Connecting to Postgres ...");
-                        let schema = database_schema.as_deref().unwrap_or("public");
+                        // Several comma separated schemas are generated into a module per schema
+                        let schemas: Vec<&str> = database_schema
+                            .as_deref()
+                            .unwrap_or("public")
+                            .split(',')
+                            .map(str::trim)
+                            .collect();
+                        let search_path = schemas
+                            .iter()
+                            .map(|schema| format!("'{schema}'"))
+                            .collect::<Vec<_>>()
+                            .join(", ");
                         let connection = sqlx_connect::<Postgres>(
                             max_connections,
                             acquire_timeout,
                             url.as_str(),
-                            Some(schema),
+                            Some(&search_path),
                         )
                         .await?;
                         println!("Discovering schema ...");
-                        let schema_discovery = SchemaDiscovery::new(connection, schema);
-                        let schema = schema_discovery.discover().await?;
-                        let table_stmts = schema
-                            .tables
-                            .into_iter()
-                            .filter(|schema| filter_tables(&schema.info.name))
-                            .filter(|schema| filter_hidden_tables(&schema.info.name))
-                            .filter(|schema| filter_skip_tables(&schema.info.name))
-                            .map(|mut schema| {
-                                // Skip generated columns (see #3094).
-                                schema.columns.retain(|col| col.generated.is_none());
-                                schema.write()
-                            })
-                            .collect();
-                        (database_schema, table_stmts)
+                        let mut table_stmts = Vec::new();
+                        for &schema_name in schemas.iter() {
+                            let schema_discovery =
+                                SchemaDiscovery::new(connection.clone(), schema_name);
+                            let schema = schema_discovery.discover().await?;
+                            table_stmts.extend(
+                                schema
+                                    .tables
+                                    .into_iter()
+                                    .filter(|schema| filter_hidden_tables(&schema.info.name))
+                                    .map(|mut schema| {
+                                        // Skip generated columns (see #3094).
+                                        schema.columns.retain(|col| col.generated.is_none());
+                                        let mut table_stmt = schema.write();
+                                        if schemas.len() > 1 {
+                                            // Foreign keys are resolved against the qualified tables
+                                            table_stmt.table((
+                                                schema_name.to_owned(),
+                                                schema.info.name.clone(),
+                                            ));
+                                        }
+                                        table_stmt
+                                    }),
+                            );
+                        }
+                        let schema_name = if schemas.len() > 1 {
+                            None
+                        } else {
+                            database_schema
+                        };
+                        (schema_name, table_stmts)
                     }
                 }
                 _ => unimplemented!("{} is not supported", url.scheme()),
@@ -343,6 +590,7 @@ pub async fn run_generate_command(
                 WithPrelude::from_str(&with_prelude).expect("Invalid prelude option"),
                 WithSerde::from_str(&with_serde).expect("Invalid serde derive option"),
                 with_copy_enums,
+                impl_enum_display_fromstr,
                 date_time_crate.into(),
                 big_integer_type.into(),
                 schema_name,
@@ -355,9 +603,13 @@ pub async fn run_generate_command(
                 enum_extra_attributes,
                 column_extra_derives,
                 seaography,
+                seaography_skip_relations,
                 impl_active_model_behavior,
                 banner_version.into(),
-            );
+            )
+            .include_tables(tables)
+            .exclude_tables(ignore_tables);
+            let table_stmts = writer_context.filter_tables(table_stmts);
             let entity_writer = EntityTransformer::transform(table_stmts)?;
 
             let dir = Path::new(&output_dir);
@@ -377,10 +629,14 @@ pub async fn run_generate_command(
             for OutputFile { name, content } in output.files.iter() {
                 let file_path = dir.join(name);
                 println!("Writing {}", file_path.display());
+                // Entities of multiple schemas are written into a directory per schema
+                if let Some(parent) = file_path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
 
                 if !matches!(
-                    name.as_str(),
-                    "mod.rs" | "lib.rs" | "prelude.rs" | "sea_orm_active_enums.rs"
+                    file_path.file_name().and_then(|name| name.to_str()),
+                    Some("mod.rs" | "lib.rs" | "prelude.rs" | "sea_orm_active_enums.rs")
                 ) && file_path.exists()
                     && preserve_user_modifications
                 {
@@ -437,7 +693,7 @@ async fn sqlx_connect<DB>(
     max_connections: u32,
     acquire_timeout: u64,
     url: &str,
-    schema: Option<&str>,
+    search_path: Option<&str>,
 ) -> Result<sqlx::Pool<DB>, Box<dyn Error>>
 where
     DB: sqlx::Database,
@@ -446,10 +702,10 @@ where
     let mut pool_options = sqlx::pool::PoolOptions::<DB>::new()
         .max_connections(max_connections)
         .acquire_timeout(time::Duration::from_secs(acquire_timeout));
-    // Set search_path for Postgres, E.g. Some("public") by default
+    // Set search_path for Postgres, E.g. Some("'public'") by default
     // MySQL & SQLite connection initialize with schema `None`
-    if let Some(schema) = schema {
-        let sql = format!("SET search_path = '{schema}'");
+    if let Some(search_path) = search_path {
+        let sql = format!("SET search_path = {search_path}");
         pool_options = pool_options.after_connect(move |conn, _| {
             let sql = sql.clone();
             Box::pin(async move {
@@ -750,4 +1006,125 @@ mod tests {
             .collect();
         assert_eq!(names, ["id", "w"]);
     }
+
+    #[cfg(feature = "sqlx-sqlite")]
+    #[test]
+    fn test_parse_sqlite_table_sql() {
+        let sql = super::SqliteTableSql::parse(
+            r#"CREATE TABLE "measurement" (
+                "id" INTEGER NOT NULL PRIMARY KEY,
+                width REAL NOT NULL DEFAULT (1.5),
+                label TEXT CHECK (label <> 'a, (b'),
+                reading ANY,
+                area REAL GENERATED ALWAYS AS (width * (width + 1)) STORED,
+                half real as (width / 2),
+                UNIQUE (label, reading)
+            ) STRICT, WITHOUT ROWID"#,
+        );
+        assert!(sql.strict);
+        assert_eq!(sql.declared_type("id"), Some("INTEGER"));
+        assert_eq!(sql.declared_type("reading"), Some("ANY"));
+        assert_eq!(sql.declared_type("half"), Some("real"));
+        assert_eq!(sql.generated_expr("width"), None);
+        assert_eq!(sql.generated_expr("area"), Some("width * (width + 1)"));
+        assert_eq!(sql.generated_expr("half"), Some("width / 2"));
+
+        let sql = super::SqliteTableSql::parse("CREATE TABLE t (id INTEGER, data ANY)");
+        assert!(!sql.strict);
+    }
+
+    #[cfg(feature = "sqlx-sqlite")]
+    #[test]
+    fn test_sqlite_table_create_stmt() {
+        use sea_schema::sea_query::{Alias, ColumnType, IntoIden};
+        use sea_schema::sqlite::def::{ColumnInfo, ColumnVisibility, DefaultType, TableDef};
+
+        let col = |cid, name: &str, r#type, hidden| ColumnInfo {
+            cid,
+            name: name.to_owned(),
+            r#type,
+            not_null: true,
+            default_value: DefaultType::Unspecified,
+            primary_key: cid == 0,
+            hidden,
+        };
+
+        let table = TableDef {
+            name: "widget".to_owned(),
+            foreign_keys: vec![],
+            indexes: vec![],
+            constraints: vec![],
+            columns: vec![
+                col(0, "id", ColumnType::BigInteger, ColumnVisibility::Visible),
+                col(1, "w", ColumnType::Double, ColumnVisibility::Visible),
+                col(2, "tag", ColumnType::Double, ColumnVisibility::Visible),
+                col(
+                    3,
+                    "area",
+                    ColumnType::Double,
+                    ColumnVisibility::GeneratedVirtual,
+                ),
+                col(
+                    4,
+                    "area_stored",
+                    ColumnType::Double,
+                    ColumnVisibility::GeneratedStored,
+                ),
+                col(
+                    5,
+                    "unknown",
+                    ColumnType::Double,
+                    ColumnVisibility::GeneratedVirtual,
+                ),
+            ],
+            auto_increment: false,
+        };
+        let sql = super::SqliteTableSql::parse(
+            "CREATE TABLE widget (id INTEGER PRIMARY KEY, w REAL, tag ANY, \
+             area REAL AS (w * w), area_stored REAL AS (w * w) STORED) STRICT",
+        );
+
+        // Generated columns are written with their expression and storage kind,
+        // the one without an expression is dropped.
+        let stmt = super::sqlite_table_create_stmt(&table, &sql);
+        let columns: Vec<_> = stmt
+            .get_columns()
+            .iter()
+            .map(|c| {
+                let generated = c.get_column_spec().generated.as_ref().map(|g| {
+                    let expr = sea_schema::sea_query::Query::select()
+                        .expr(g.expr.clone())
+                        .to_string(sea_schema::sea_query::SqliteQueryBuilder);
+                    (expr, g.stored)
+                });
+                (
+                    c.get_column_name(),
+                    c.get_column_type().unwrap().clone(),
+                    generated,
+                )
+            })
+            .collect();
+        assert_eq!(
+            columns,
+            [
+                ("id".to_owned(), ColumnType::BigInteger, None),
+                ("w".to_owned(), ColumnType::Double, None),
+                (
+                    "tag".to_owned(),
+                    ColumnType::Custom(Alias::new("ANY").into_iden()),
+                    None
+                ),
+                (
+                    "area".to_owned(),
+                    ColumnType::Double,
+                    Some(("SELECT w * w".to_owned(), false))
+                ),
+                (
+                    "area_stored".to_owned(),
+                    ColumnType::Double,
+                    Some(("SELECT w * w".to_owned(), true))
+                ),
+            ]
+        );
+    }
 }
@@ -32,11 +32,18 @@ pub fn run_migrate_command(
                 Some(MigrateSubcommands::Fresh) => ("fresh", migration_dir, None, verbose),
                 Some(MigrateSubcommands::Refresh) => ("refresh", migration_dir, None, verbose),
                 Some(MigrateSubcommands::Reset) => ("reset", migration_dir, None, verbose),
-                Some(MigrateSubcommands::Status) => ("status", migration_dir, None, verbose),
+                Some(MigrateSubcommands::Status { .. }) => ("status", migration_dir, None, verbose),
                 Some(MigrateSubcommands::Up { num }) => ("up", migration_dir, num, verbose),
                 Some(MigrateSubcommands::Down { num }) => {
                     ("down", migration_dir, Some(num), verbose)
                 }
+                Some(MigrateSubcommands::MarkApplied { .. }) => {
+                    ("mark-applied", migration_dir, None, verbose)
+                }
+                Some(MigrateSubcommands::MarkRolledBack { .. }) => {
+                    ("mark-rolled-back", migration_dir, None, verbose)
+                }
+                Some(MigrateSubcommands::Squash { .. }) => ("squash", migration_dir, None, verbose),
                 _ => ("up", migration_dir, None, verbose),
             };
 
@@ -48,6 +55,17 @@ pub fn run_migrate_command(
             };
             // Construct the arguments that will be supplied to `cargo` command
             let mut args = vec!["run", "--manifest-path", &manifest_path, "--", subcommand];
+            let migration_name = match &command {
+                Some(
+                    MigrateSubcommands::MarkApplied { migration_name }
+                    | MigrateSubcommands::MarkRolledBack { migration_name }
+                    | MigrateSubcommands::Squash { migration_name },
+                ) => Some(migration_name.clone()),
+                _ => None,
+            };
+            if let Some(migration_name) = &migration_name {
+                args.push(migration_name);
+            }
             let mut envs = vec![];
 
             let mut num: String = "".to_string();
@@ -66,9 +84,28 @@ pub fn run_migrate_command(
             if verbose {
                 args.push("-v");
             }
+            let (json, exit_code) = match command {
+                Some(MigrateSubcommands::Status { json, exit_code }) => (json, exit_code),
+                _ => (false, false),
+            };
+            if json {
+                args.push("--json");
+            }
+            if exit_code {
+                args.push("--exit-code");
+            }
             // Run migrator CLI on user's behalf
-            println!("Running `cargo {}`", args.join(" "));
+            if json {
+                // keep stdout parseable
+                eprintln!("Running `cargo {}`", args.join(" "));
+            } else {
+                println!("Running `cargo {}`", args.join(" "));
+            }
             let exit_status = Command::new("cargo").args(args).envs(envs).status()?; // Get the status code
+            if exit_code && let Some(code @ (1 | 2)) = exit_status.code() {
+                // Propagate the status reported by `status --exit-code`
+                std::process::exit(code);
+            }
             if !exit_status.success() {
                 // Propagate the error if any
                 return Err("Fail to run migration".into());
@@ -177,13 +214,23 @@ fn get_full_migration_dir(migration_dir: &str) -> PathBuf {
 }
 
 fn create_new_migration(migration_name: &str, migration_dir: &str) -> Result<(), Box<dyn Error>> {
+    let migration_template = fmt_migration_template(migration_name);
+    create_migration_file(migration_name, migration_dir, &migration_template)
+}
+
+/// Write the source of the migration `migration_name` into the migration directory,
+/// see [`get_full_migration_dir`]
+pub fn create_migration_file(
+    migration_name: &str,
+    migration_dir: &str,
+    source: &str,
+) -> Result<(), Box<dyn Error>> {
     let migration_filepath =
         get_full_migration_dir(migration_dir).join(format!("{}.rs", &migration_name));
     println!("Creating migration file `{}`", migration_filepath.display());
 
-    let migration_template = fmt_migration_template(migration_name);
     let mut migration_file = fs::File::create(migration_filepath)?;
-    migration_file.write_all(migration_template.as_bytes())?;
+    migration_file.write_all(source.as_bytes())?;
     Ok(())
 }
 
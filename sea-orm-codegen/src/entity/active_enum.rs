@@ -16,6 +16,7 @@ impl ActiveEnum {
         &self,
         with_serde: &WithSerde,
         with_copy_enums: bool,
+        display_from_str: bool,
         extra_derives: &TokenStream,
         extra_attributes: &TokenStream,
         entity_format: EntityFormat,
@@ -66,6 +67,11 @@ impl ActiveEnum {
         });
 
         let serde_derive = with_serde.extra_derive();
+        let display_from_str = if display_from_str {
+            quote! { , display_from_str }
+        } else {
+            quote! {}
+        };
         let copy_derive = if with_copy_enums {
             quote! { , Copy }
         } else {
@@ -85,7 +91,7 @@ impl ActiveEnum {
         } else {
             quote! {
                 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum #copy_derive #serde_derive #extra_derives)]
-                #[sea_orm(rs_type = "Enum", db_type = "Enum", enum_name = #enum_name)]
+                #[sea_orm(rs_type = "Enum", db_type = "Enum", enum_name = #enum_name #display_from_str)]
                 #extra_attributes
                 pub enum #enum_iden {
                     #(
@@ -118,6 +124,7 @@ mod tests {
             .impl_active_enum(
                 &WithSerde::None,
                 true,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
                 EntityFormat::Compact,
@@ -161,6 +168,7 @@ mod tests {
             .impl_active_enum(
                 &WithSerde::None,
                 true,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
                 EntityFormat::Compact,
@@ -213,6 +221,7 @@ mod tests {
             .impl_active_enum(
                 &WithSerde::None,
                 true,
+                false,
                 &bonus_derive(["specta::Type", "ts_rs::TS"]),
                 &TokenStream::new(),
                 EntityFormat::Compact,
@@ -250,6 +259,7 @@ mod tests {
             .impl_active_enum(
                 &WithSerde::None,
                 true,
+                false,
                 &TokenStream::new(),
                 &bonus_attributes([r#"serde(rename_all = "camelCase")"#]),
                 EntityFormat::Compact,
@@ -279,6 +289,7 @@ mod tests {
             .impl_active_enum(
                 &WithSerde::None,
                 true,
+                false,
                 &TokenStream::new(),
                 &bonus_attributes([r#"serde(rename_all = "camelCase")"#, "ts(export)"]),
                 EntityFormat::Compact,
@@ -326,6 +337,7 @@ mod tests {
             .impl_active_enum(
                 &WithSerde::None,
                 true,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
                 EntityFormat::Compact,
@@ -364,4 +376,42 @@ mod tests {
             .to_string()
         )
     }
+
+    #[test]
+    fn test_enum_display_from_str() {
+        assert_eq!(
+            ActiveEnum {
+                enum_name: Alias::new("tea").into_iden(),
+                values: vec!["EverydayTea", "BreakfastTea"]
+                    .into_iter()
+                    .map(|variant| Alias::new(variant).into_iden())
+                    .collect(),
+            }
+            .impl_active_enum(
+                &WithSerde::None,
+                false,
+                true,
+                &TokenStream::new(),
+                &TokenStream::new(),
+                EntityFormat::Compact,
+            )
+            .to_string(),
+            quote!(
+                #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+                #[sea_orm(
+                    rs_type = "Enum",
+                    db_type = "Enum",
+                    enum_name = "tea",
+                    display_from_str
+                )]
+                pub enum Tea {
+                    #[sea_orm(string_value = "EverydayTea")]
+                    EverydayTea,
+                    #[sea_orm(string_value = "BreakfastTea")]
+                    BreakfastTea,
+                }
+            )
+            .to_string()
+        )
+    }
 }
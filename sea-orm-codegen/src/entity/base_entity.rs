@@ -5,16 +5,23 @@ use quote::quote;
 use sea_query::ColumnType;
 
 use crate::{
-    Column, ColumnOption, ConjunctRelation, PrimaryKey, Relation, util::escape_rust_keyword,
+    Column, ColumnOption, ConjunctRelation, PrimaryKey, Relation, UniqueKey,
+    util::escape_rust_keyword,
 };
 
 #[derive(Clone, Debug)]
 pub struct Entity {
     pub(crate) table_name: String,
+    /// Schema of the table, if its name is schema-qualified
+    pub(crate) schema_name: Option<String>,
     pub(crate) columns: Vec<Column>,
     pub(crate) relations: Vec<Relation>,
     pub(crate) conjunct_relations: Vec<ConjunctRelation>,
+    /// Tables referenced by a foreign key but left out of the generated entities
+    pub(crate) omitted_relations: Vec<String>,
     pub(crate) primary_keys: Vec<PrimaryKey>,
+    /// Composite unique constraints not declared with `unique_key` on the columns
+    pub(crate) unique_keys: Vec<UniqueKey>,
 }
 
 impl Entity {
@@ -64,6 +71,10 @@ impl Entity {
             .collect()
     }
 
+    pub fn get_unique_key_attrs(&self) -> Vec<TokenStream> {
+        self.unique_keys.iter().map(|key| key.get_attr()).collect()
+    }
+
     pub fn get_primary_key_names_snake_case(&self) -> Vec<Ident> {
         self.primary_keys
             .iter()
@@ -94,19 +105,34 @@ impl Entity {
 
     /// Used to generate the names for the `enum RelatedEntity` that is useful to the Seaography project
     pub fn get_related_entity_enum_name(&self) -> Vec<Ident> {
-        // 1st step get conjunct relations data
-        let conjunct_related_names = self.get_conjunct_relations_to_upper_camel_case();
+        let relation_names: Vec<Ident> = self
+            .relations
+            .iter()
+            .map(|rel| rel.get_related_entity_enum_name())
+            .collect();
+
+        // 1st step get conjunct relations data, a conjunct relation that clashes with
+        // a direct relation is suffixed with the junction table, e.g. `UsersViaMessages`
+        let conjunct_related_names = self.conjunct_relations.iter().map(|conj| {
+            let name = conj.get_to_upper_camel_case();
+            if relation_names.contains(&name) {
+                format_ident!("{}Via{}", name, conj.via.to_upper_camel_case())
+            } else {
+                name
+            }
+        });
 
         // 2nd step get reverse self relations data
         let self_relations_reverse = self
             .relations
             .iter()
             .filter(|rel| rel.self_referencing)
-            .map(|rel| format_ident!("{}Reverse", rel.get_enum_name()));
+            .map(|rel| format_ident!("{}Reverse", rel.get_related_entity_enum_name()));
 
         // 3rd step get normal relations data
-        self.get_relation_enum_name()
-            .into_iter()
+        relation_names
+            .iter()
+            .cloned()
             .chain(self_relations_reverse)
             .chain(conjunct_related_names)
             .collect()
@@ -165,8 +191,8 @@ impl Entity {
 
         // helper function that generates attributes for `Relation` data
         let produce_relation_attrs = |rel: &Relation, reverse: bool| {
-            let entity = match rel.get_module_name() {
-                Some(module_name) => format!("super::{module_name}::Entity"),
+            let entity = match rel.get_module_path_str() {
+                Some(module_path) => format!("{module_path}::Entity"),
                 None => String::from("Entity"),
             };
 
@@ -305,6 +331,7 @@ mod tests {
     fn setup() -> Entity {
         Entity {
             table_name: "special_cake".to_owned(),
+            schema_name: None,
             columns: vec![
                 Column {
                     name: "id".to_owned(),
@@ -313,6 +340,7 @@ mod tests {
                     not_null: false,
                     unique: false,
                     unique_key: None,
+                    generated: false,
                 },
                 Column {
                     name: "name".to_owned(),
@@ -321,11 +349,13 @@ mod tests {
                     not_null: false,
                     unique: false,
                     unique_key: None,
+                    generated: false,
                 },
             ],
             relations: vec![
                 Relation {
                     ref_table: "fruit".to_owned(),
+                    ref_schema: None,
                     columns: vec!["id".to_owned()],
                     ref_columns: vec!["cake_id".to_owned()],
                     rel_type: RelationType::HasOne,
@@ -337,6 +367,7 @@ mod tests {
                 },
                 Relation {
                     ref_table: "filling".to_owned(),
+                    ref_schema: None,
                     columns: vec!["id".to_owned()],
                     ref_columns: vec!["cake_id".to_owned()],
                     rel_type: RelationType::HasOne,
@@ -348,9 +379,11 @@ mod tests {
                 },
             ],
             conjunct_relations: vec![],
+            omitted_relations: vec![],
             primary_keys: vec![PrimaryKey {
                 name: "id".to_owned(),
             }],
+            unique_keys: vec![],
         }
     }
 
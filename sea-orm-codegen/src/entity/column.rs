@@ -13,6 +13,7 @@ pub struct Column {
     pub(crate) not_null: bool,
     pub(crate) unique: bool,
     pub(crate) unique_key: Option<String>,
+    pub(crate) generated: bool,
 }
 
 #[derive(Debug, Default, Copy, Clone)]
@@ -105,6 +106,27 @@ impl Column {
         }
     }
 
+    /// The cast applied when selecting the column. Types the driver doesn't know,
+    /// e.g. Postgres extension types like `citext` or `ltree`, are read as text.
+    pub fn get_select_as(&self) -> Option<&'static str> {
+        match &self.col_type {
+            ColumnType::Custom(_) => Some("text"),
+            _ => None,
+        }
+    }
+
+    /// The cast applied when saving the column, turning the text of a custom type
+    /// back into the type. SQLite STRICT `ANY` columns take values of any storage class.
+    pub fn get_save_as(&self) -> Option<String> {
+        match &self.col_type {
+            ColumnType::Custom(iden) => {
+                let ty = iden.to_string();
+                (!ty.eq_ignore_ascii_case("any") && ty != "user-defined").then_some(ty)
+            }
+            _ => None,
+        }
+    }
+
     pub fn get_col_type_attrs(&self) -> Option<TokenStream> {
         let col_type = match &self.col_type {
             ColumnType::Float => Some("Float".to_owned()),
@@ -113,10 +135,20 @@ impl Column {
             ColumnType::Money(Some((p, s))) => Some(format!("Money(Some(({p}, {s})))")),
             ColumnType::Text => Some("Text".to_owned()),
             ColumnType::JsonBinary => Some("JsonBinary".to_owned()),
-            ColumnType::Custom(iden) => {
+            ColumnType::Custom(iden) if iden.to_string() == "user-defined" => {
+                // the actual type is unknown, so the column can't be read or written
                 let ty = format!("custom(\"{iden}\")");
                 return Some(quote! ( ignore, column_type = #ty, select_as = "text" ));
             }
+            ColumnType::Custom(iden) => {
+                let ty = format!("custom(\"{iden}\")");
+                return Some(match self.get_save_as() {
+                    Some(save_as) => {
+                        quote! ( column_type = #ty, select_as = "text", save_as = #save_as )
+                    }
+                    None => quote! ( column_type = #ty, select_as = "text" ),
+                });
+            }
             ColumnType::Binary(s) => Some(format!("Binary({s})")),
             ColumnType::VarBinary(s) => match s {
                 StringLen::N(s) => Some(format!("VarBinary(StringLen::N({s}))")),
@@ -231,6 +263,15 @@ impl Column {
             col_def.extend(quote! {
                 .unique()
             });
+        } else if let Some(unique_key) = &self.unique_key {
+            col_def.extend(quote! {
+                .unique_key(#unique_key)
+            });
+        }
+        if self.generated {
+            col_def.extend(quote! {
+                .generated()
+            });
         }
         col_def
     }
@@ -267,6 +308,9 @@ impl Column {
         if self.unique {
             write!(&mut info, ", unique").unwrap();
         }
+        if self.generated {
+            write!(&mut info, ", generated").unwrap();
+        }
         info
     }
 
@@ -316,6 +360,7 @@ impl From<&ColumnDef> for Column {
             None => false,
         };
         let unique = col_def.get_column_spec().unique;
+        let generated = col_def.get_column_spec().generated.is_some();
         Self {
             name,
             col_type,
@@ -323,6 +368,7 @@ impl From<&ColumnDef> for Column {
             not_null,
             unique,
             unique_key: None,
+            generated,
         }
     }
 }
@@ -358,6 +404,7 @@ mod tests {
                     not_null: false,
                     unique: false,
                     unique_key: None,
+                    generated: false,
                 }
             };
         }
@@ -600,6 +647,7 @@ mod tests {
             not_null: true,
             unique: false,
             unique_key: None,
+            generated: false,
         };
 
         // Money and Decimal both carry an `Option<(precision, scale)>`, so the
@@ -815,6 +863,7 @@ mod tests {
             not_null: true,
             unique: false,
             unique_key: None,
+            generated: false,
         };
         let cases = [
             (ColumnType::Year, "ColumnType::Year.def()"),
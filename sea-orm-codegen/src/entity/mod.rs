@@ -5,6 +5,7 @@ mod conjunct_relation;
 mod primary_key;
 mod relation;
 mod transformer;
+mod unique_key;
 mod writer;
 
 pub use active_enum::*;
@@ -14,4 +15,5 @@ pub use conjunct_relation::*;
 pub use primary_key::*;
 pub use relation::*;
 pub use transformer::*;
+pub use unique_key::*;
 pub use writer::*;
@@ -4,7 +4,7 @@ use quote::{format_ident, quote};
 use sea_query::{ForeignKeyAction, TableForeignKey};
 use syn::{punctuated::Punctuated, token::Comma};
 
-use crate::util::escape_rust_keyword;
+use crate::util::{escape_rust_keyword, get_table_schema};
 
 #[derive(Debug, Clone, Copy)]
 pub enum RelationType {
@@ -16,6 +16,8 @@ pub enum RelationType {
 #[derive(Clone, Debug)]
 pub struct Relation {
     pub(crate) ref_table: String,
+    /// Schema of the related entity, if it is generated into another schema module
+    pub(crate) ref_schema: Option<String>,
     pub(crate) columns: Vec<String>,
     pub(crate) ref_columns: Vec<String>,
     pub(crate) rel_type: RelationType,
@@ -40,6 +42,25 @@ impl Relation {
         }
     }
 
+    /// Name of the `enum RelatedEntity` variant, relations sharing the same target are told apart
+    /// by their foreign key columns instead of the numeric suffix, e.g. `UsersSenderId`
+    pub fn get_related_entity_enum_name(&self) -> Ident {
+        if self.num_suffix == 0 || self.columns.is_empty() {
+            return self.get_enum_name();
+        }
+        let name = if self.self_referencing {
+            "SelfRef".to_owned()
+        } else {
+            self.ref_table.to_upper_camel_case()
+        };
+        let columns: String = self
+            .columns
+            .iter()
+            .map(|column| column.to_upper_camel_case())
+            .collect();
+        format_ident!("{}{}", name, columns)
+    }
+
     pub fn get_module_name(&self) -> Option<Ident> {
         if self.self_referencing {
             None
@@ -51,11 +72,36 @@ impl Relation {
         }
     }
 
+    pub fn get_schema_module_name(&self) -> Option<Ident> {
+        self.ref_schema
+            .as_ref()
+            .map(|schema| format_ident!("{}", escape_rust_keyword(schema.to_snake_case())))
+    }
+
+    /// Path of the related entity's module, relative to the module of this entity,
+    /// e.g. `super::fruit`, or `super::super::audit::log` if it lives in another schema
+    pub fn get_module_path(&self) -> Option<TokenStream> {
+        let module_name = self.get_module_name()?;
+        Some(match self.get_schema_module_name() {
+            Some(schema) => quote! { super::super::#schema::#module_name },
+            None => quote! { super::#module_name },
+        })
+    }
+
+    /// Same as [`Self::get_module_path`], for use in attributes
+    pub fn get_module_path_str(&self) -> Option<String> {
+        let module_name = self.get_module_name()?;
+        Some(match self.get_schema_module_name() {
+            Some(schema) => format!("super::super::{schema}::{module_name}"),
+            None => format!("super::{module_name}"),
+        })
+    }
+
     pub fn get_def(&self) -> TokenStream {
         let rel_type = self.get_rel_type();
-        let module_name = self.get_module_name();
-        let ref_entity = if module_name.is_some() {
-            quote! { super::#module_name::Entity }
+        let module_path = self.get_module_path();
+        let ref_entity = if let Some(module_path) = &module_path {
+            quote! { #module_path::Entity }
         } else {
             quote! { Entity }
         };
@@ -70,8 +116,8 @@ impl Relation {
                     quote! { Column::#src_column }
                 };
                 let map_ref_column = |ref_column: &Ident| {
-                    if module_name.is_some() {
-                        quote! { super::#module_name::Column::#ref_column }
+                    if let Some(module_path) = &module_path {
+                        quote! { #module_path::Column::#ref_column }
                     } else {
                         quote! { Column::#ref_column }
                     }
@@ -95,8 +141,8 @@ impl Relation {
 
     pub fn get_attrs(&self) -> TokenStream {
         let rel_type = self.get_rel_type();
-        let module_name = if let Some(module_name) = self.get_module_name() {
-            format!("super::{module_name}::")
+        let module_name = if let Some(module_path) = self.get_module_path_str() {
+            format!("{module_path}::")
         } else {
             String::new()
         };
@@ -219,8 +265,10 @@ impl From<&TableForeignKey> for Relation {
         let rel_type = RelationType::BelongsTo;
         let on_delete = tbl_fk.get_on_delete();
         let on_update = tbl_fk.get_on_update();
+        let ref_schema = tbl_fk.get_ref_table().and_then(get_table_schema);
         Self {
             ref_table,
+            ref_schema,
             columns,
             ref_columns,
             rel_type,
@@ -242,6 +290,7 @@ mod tests {
     fn setup() -> Vec<Relation> {
         vec![
             Relation {
+                ref_schema: None,
                 ref_table: "fruit".to_owned(),
                 columns: vec!["id".to_owned()],
                 ref_columns: vec!["cake_id".to_owned()],
@@ -253,6 +302,7 @@ mod tests {
                 impl_related: true,
             },
             Relation {
+                ref_schema: None,
                 ref_table: "filling".to_owned(),
                 columns: vec!["filling_id".to_owned()],
                 ref_columns: vec!["id".to_owned()],
@@ -264,6 +314,7 @@ mod tests {
                 impl_related: true,
             },
             Relation {
+                ref_schema: None,
                 ref_table: "filling".to_owned(),
                 columns: vec!["filling_id".to_owned()],
                 ref_columns: vec!["id".to_owned()],
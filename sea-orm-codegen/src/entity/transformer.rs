@@ -1,21 +1,53 @@
 use crate::{
     ActiveEnum, Column, ConjunctRelation, Entity, EntityWriter, Error, PrimaryKey, Relation,
-    RelationType,
+    RelationType, UniqueKey, util::get_table_schema,
 };
 use sea_query::TableCreateStatement;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 #[derive(Clone, Debug)]
 pub struct EntityTransformer;
 
 impl EntityTransformer {
-    pub fn transform(table_create_stmts: Vec<TableCreateStatement>) -> Result<EntityWriter, Error> {
+    pub fn transform(
+        mut table_create_stmts: Vec<TableCreateStatement>,
+    ) -> Result<EntityWriter, Error> {
+        // Schema discovery doesn't guarantee the order of tables and foreign keys,
+        // sort them so that the generated code is identical across runs
+        table_create_stmts.sort_by_cached_key(|table_create| {
+            table_create.get_table_name().map(|table_ref| {
+                (
+                    get_table_schema(table_ref),
+                    table_ref.sea_orm_table().to_string(),
+                )
+            })
+        });
+        // Tables of each schema, used to resolve foreign keys to unqualified tables
+        let mut schema_tables: BTreeMap<String, BTreeSet<Option<String>>> = BTreeMap::new();
+        for table_ref in table_create_stmts
+            .iter()
+            .filter_map(|table_create| table_create.get_table_name())
+        {
+            schema_tables
+                .entry(table_ref.sea_orm_table().to_string())
+                .or_default()
+                .insert(get_table_schema(table_ref));
+        }
+        let schema_names: BTreeSet<_> = schema_tables.values().flatten().collect();
+        if schema_names.len() > 1 && schema_names.contains(&None) {
+            return Err(Error::TransformError(
+                "Tables of multiple schemas should all be schema-qualified".into(),
+            ));
+        }
         let mut enums: BTreeMap<String, ActiveEnum> = BTreeMap::new();
         let mut inverse_relations: BTreeMap<String, Vec<Relation>> = BTreeMap::new();
         let mut entities = BTreeMap::new();
         for table_create in table_create_stmts.into_iter() {
-            let table_name = match table_create.get_table_name() {
-                Some(table_ref) => table_ref.sea_orm_table().to_string(),
+            let (schema_name, table_name) = match table_create.get_table_name() {
+                Some(table_ref) => (
+                    get_table_schema(table_ref),
+                    table_ref.sea_orm_table().to_string(),
+                ),
                 None => {
                     return Err(Error::TransformError(
                         "Table name should not be empty".into(),
@@ -59,12 +91,31 @@ impl EntityTransformer {
                     }
                 })
                 .collect();
-            for index in table_create.get_indexes().iter() {
-                if index.is_unique_key() {
-                    let col_names = index.get_index_spec().get_column_names();
-                    if col_names.len() > 1
-                        && let Some(mut key_name) = index.get_index_spec().get_name()
-                    {
+            let mut composite_unique_indexes: Vec<_> = table_create
+                .get_indexes()
+                .iter()
+                .filter(|index| index.is_unique_key())
+                .map(|index| index.get_index_spec())
+                .filter(|index| index.get_column_names().len() > 1)
+                .collect();
+            composite_unique_indexes.sort_by_cached_key(|index| {
+                (
+                    index.get_name().map(ToOwned::to_owned),
+                    index.get_column_names(),
+                )
+            });
+            let mut unique_keys = Vec::new();
+            for index in composite_unique_indexes {
+                let col_names = index.get_column_names();
+                // A column belongs to at most one `unique_key`, and a `unique` column
+                // can't have one, other constraints are declared on the entity
+                let is_free = |col_name: &String| {
+                    columns.iter().any(|column| {
+                        &column.name == col_name && !column.unique && column.unique_key.is_none()
+                    })
+                };
+                match index.get_name() {
+                    Some(mut key_name) if col_names.iter().all(is_free) => {
                         if let Some((_, suffix)) = key_name.rsplit_once('-') {
                             key_name = suffix;
                         }
@@ -76,39 +127,47 @@ impl EntityTransformer {
                             }
                         }
                     }
+                    name => unique_keys.push(UniqueKey {
+                        name: name.map(ToOwned::to_owned),
+                        columns: col_names,
+                    }),
                 }
             }
-            let mut ref_table_counts: BTreeMap<String, usize> = BTreeMap::new();
-            let relations: Vec<Relation> = table_create
+            let mut foreign_keys: Vec<_> = table_create
                 .get_foreign_key_create_stmts()
                 .iter()
                 .map(|fk_create_stmt| fk_create_stmt.get_foreign_key())
-                .map(|tbl_fk| {
-                    let ref_tbl = tbl_fk.get_ref_table().unwrap().sea_orm_table().to_string();
-                    if let Some(count) = ref_table_counts.get_mut(&ref_tbl) {
-                        if *count == 0 {
-                            *count = 1;
-                        }
-                        *count += 1;
-                    } else {
-                        ref_table_counts.insert(ref_tbl, 0);
-                    };
-                    tbl_fk.into()
-                })
-                .collect::<Vec<_>>()
+                .collect();
+            foreign_keys.sort_by_cached_key(|tbl_fk| {
+                (
+                    tbl_fk.get_name().map(ToOwned::to_owned),
+                    tbl_fk.get_columns(),
+                )
+            });
+            let mut ref_table_counts: BTreeMap<String, usize> = BTreeMap::new();
+            for tbl_fk in foreign_keys.iter() {
+                let ref_tbl = tbl_fk.get_ref_table().unwrap().sea_orm_table().to_string();
+                *ref_table_counts.entry(ref_tbl).or_default() += 1;
+            }
+            // Tables referenced more than once get numbered variants, in foreign key name order
+            let mut ref_table_seen: BTreeMap<String, usize> = BTreeMap::new();
+            let relations: Vec<Relation> = foreign_keys
                 .into_iter()
-                .rev()
-                .map(|mut rel: Relation| {
-                    rel.self_referencing = rel.ref_table == table_name;
-                    if let Some(count) = ref_table_counts.get_mut(&rel.ref_table) {
-                        rel.num_suffix = *count;
-                        if *count > 0 {
-                            *count -= 1;
-                        }
+                .map(|tbl_fk| {
+                    let mut rel: Relation = tbl_fk.into();
+                    if rel.ref_schema.is_none() {
+                        rel.ref_schema =
+                            Self::resolve_ref_schema(&schema_tables, &schema_name, &rel.ref_table);
+                    }
+                    rel.self_referencing =
+                        rel.ref_table == table_name && rel.ref_schema == schema_name;
+                    if ref_table_counts[&rel.ref_table] > 1 {
+                        let seen = ref_table_seen.entry(rel.ref_table.clone()).or_default();
+                        *seen += 1;
+                        rel.num_suffix = *seen;
                     }
                     rel
                 })
-                .rev()
                 .collect();
             primary_keys.extend(
                 table_create
@@ -126,12 +185,18 @@ impl EntityTransformer {
             );
             let entity = Entity {
                 table_name: table_name.clone(),
+                schema_name: schema_name.clone(),
                 columns,
                 relations: relations.clone(),
                 conjunct_relations: vec![],
+                omitted_relations: vec![],
                 primary_keys,
+                unique_keys,
             };
-            entities.insert(table_name.clone(), entity.clone());
+            entities.insert(
+                Self::get_entity_key(&schema_name, &table_name),
+                entity.clone(),
+            );
             for mut rel in relations.into_iter() {
                 // This will produce a duplicated relation
                 if rel.self_referencing {
@@ -142,7 +207,7 @@ impl EntityTransformer {
                 if rel.num_suffix > 0 {
                     continue;
                 }
-                let ref_table = rel.ref_table;
+                let ref_key = Self::get_entity_key(&rel.ref_schema, &rel.ref_table);
                 let mut unique = true;
                 for column in rel.columns.iter() {
                     if !entity
@@ -173,22 +238,22 @@ impl EntityTransformer {
                 };
                 rel.rel_type = rel_type;
                 rel.ref_table = table_name.to_string();
+                rel.ref_schema = schema_name.clone();
                 rel.columns = Vec::new();
                 rel.ref_columns = Vec::new();
-                if let Some(vec) = inverse_relations.get_mut(&ref_table) {
+                if let Some(vec) = inverse_relations.get_mut(&ref_key) {
                     vec.push(rel);
                 } else {
-                    inverse_relations.insert(ref_table, vec![rel]);
+                    inverse_relations.insert(ref_key, vec![rel]);
                 }
             }
         }
-        for (tbl_name, relations) in inverse_relations.into_iter() {
-            if let Some(entity) = entities.get_mut(&tbl_name) {
+        for (entity_key, relations) in inverse_relations.into_iter() {
+            if let Some(entity) = entities.get_mut(&entity_key) {
                 for relation in relations.into_iter() {
-                    let duplicate_relation = entity
-                        .relations
-                        .iter()
-                        .any(|rel| rel.ref_table == relation.ref_table);
+                    let duplicate_relation = entity.relations.iter().any(|rel| {
+                        rel.ref_table == relation.ref_table && rel.ref_schema == relation.ref_schema
+                    });
                     if !duplicate_relation {
                         entity.relations.push(relation);
                     }
@@ -199,28 +264,43 @@ impl EntityTransformer {
         // When codegen is fed with a subset of tables (e.g. via `sea-orm-cli generate entity --tables`),
         // we must not generate relations that point to entities outside this set, otherwise it will
         // produce invalid paths like `super::<missing_table>::Entity`.
-        let table_names: HashSet<String> = entities.keys().cloned().collect();
+        // The omitted tables are recorded, so the writer can point them out in the generated file.
+        let entity_keys: HashSet<String> = entities.keys().cloned().collect();
         for entity in entities.values_mut() {
-            entity
-                .relations
-                .retain(|rel| rel.self_referencing || table_names.contains(&rel.ref_table));
+            let mut omitted_relations = BTreeSet::new();
+            entity.relations.retain(|rel| {
+                let ref_key = Self::get_entity_key(&rel.ref_schema, &rel.ref_table);
+                if rel.self_referencing || entity_keys.contains(&ref_key) {
+                    return true;
+                }
+                omitted_relations.insert(ref_key);
+                false
+            });
+            entity.omitted_relations = omitted_relations.into_iter().collect();
         }
 
-        for table_name in entities.clone().keys() {
-            let relations = match entities.get(table_name) {
+        for entity_key in entities.clone().keys() {
+            let (table_name, relations) = match entities.get(entity_key) {
                 Some(entity) => {
-                    let is_conjunct_relation =
-                        entity.relations.len() == 2 && entity.primary_keys.len() == 2;
+                    // Junction tables are only supported between tables of the same schema
+                    let is_conjunct_relation = entity.relations.len() == 2
+                        && entity.primary_keys.len() == 2
+                        && entity
+                            .relations
+                            .iter()
+                            .all(|rel| rel.ref_schema == entity.schema_name);
                     if !is_conjunct_relation {
                         continue;
                     }
-                    entity.relations.clone()
+                    (entity.table_name.clone(), entity.relations.clone())
                 }
                 None => unreachable!(),
             };
             for (i, rel) in relations.iter().enumerate() {
                 let another_rel = relations.get((i == 0) as usize).unwrap();
-                if let Some(entity) = entities.get_mut(&rel.ref_table) {
+                if let Some(entity) =
+                    entities.get_mut(&Self::get_entity_key(&rel.ref_schema, &rel.ref_table))
+                {
                     let conjunct_relation = ConjunctRelation {
                         via: table_name.clone(),
                         to: another_rel.ref_table.clone(),
@@ -233,6 +313,13 @@ impl EntityTransformer {
             entities: entities
                 .into_values()
                 .map(|mut v| {
+                    // Relations only keep the schema of entities generated into another schema module
+                    for relation in v.relations.iter_mut() {
+                        if relation.ref_schema == v.schema_name {
+                            relation.ref_schema = None;
+                        }
+                    }
+
                     // Filter duplicated conjunct relations
                     let duplicated_to: Vec<_> = v
                         .conjunct_relations
@@ -253,16 +340,23 @@ impl EntityTransformer {
                     // Skip `impl Related ... { fn to() ... }` implementation block,
                     // if the same related entity is being referenced by a conjunct relation
                     v.relations.iter_mut().for_each(|relation| {
-                        if v.conjunct_relations
-                            .iter()
-                            .any(|conjunct_relation| conjunct_relation.to == relation.ref_table)
+                        if relation.ref_schema.is_none()
+                            && v.conjunct_relations
+                                .iter()
+                                .any(|conjunct_relation| conjunct_relation.to == relation.ref_table)
                         {
                             relation.impl_related = false;
                         }
                     });
 
                     // Sort relation vectors
-                    v.relations.sort_by(|a, b| a.ref_table.cmp(&b.ref_table));
+                    v.relations.sort_by(|a, b| {
+                        (&a.ref_table, &a.ref_schema, a.num_suffix).cmp(&(
+                            &b.ref_table,
+                            &b.ref_schema,
+                            b.num_suffix,
+                        ))
+                    });
                     v.conjunct_relations.sort_by(|a, b| a.to.cmp(&b.to));
                     v
                 })
@@ -270,15 +364,42 @@ impl EntityTransformer {
             enums,
         })
     }
+
+    fn get_entity_key(schema_name: &Option<String>, table_name: &str) -> String {
+        match schema_name {
+            Some(schema_name) => format!("{schema_name}.{table_name}"),
+            None => table_name.to_owned(),
+        }
+    }
+
+    /// Schema of a table referenced without one: a table of the same schema takes precedence,
+    /// otherwise the only schema with a table of that name
+    fn resolve_ref_schema(
+        schema_tables: &BTreeMap<String, BTreeSet<Option<String>>>,
+        schema_name: &Option<String>,
+        ref_table: &str,
+    ) -> Option<String> {
+        match schema_tables.get(ref_table) {
+            Some(schemas) if !schemas.contains(schema_name) && schemas.len() == 1 => {
+                schemas.first().cloned().flatten()
+            }
+            _ => schema_name.clone(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        BannerVersion, BigIntegerType, DateTimeCrate, EntityFormat, EntityWriterContext,
+        WithPrelude, WithSerde,
+    };
     use pretty_assertions::assert_eq;
     use proc_macro2::TokenStream;
+    use quote::quote;
     use sea_orm::{DbBackend, Schema};
-    use sea_query::{ColumnDef, ForeignKey, Table};
+    use sea_query::{ColumnDef, ForeignKey, ForeignKeyCreateStatement, Index, Table};
     use std::{
         error::Error,
         io::{self, BufRead, BufReader},
@@ -390,6 +511,216 @@ mod tests {
         )
     }
 
+    #[test]
+    fn multi_schema() -> Result<(), Box<dyn Error>> {
+        use crate::tests_cfg::multi_schema::*;
+        let schema = Schema::new(DbBackend::Postgres);
+        let tables = || {
+            vec![
+                schema.create_table_from_entity(audit::log::Entity),
+                schema.create_table_from_entity(billing::customer::Entity),
+                schema.create_table_from_entity(billing::invoice::Entity),
+            ]
+        };
+
+        validate_compact_entities(
+            tables(),
+            vec![
+                (
+                    "log",
+                    include_str!("../tests_cfg/multi_schema/audit/log.rs"),
+                ),
+                (
+                    "customer",
+                    include_str!("../tests_cfg/multi_schema/billing/customer.rs"),
+                ),
+                (
+                    "invoice",
+                    include_str!("../tests_cfg/multi_schema/billing/invoice.rs"),
+                ),
+            ],
+        )?;
+
+        let context = EntityWriterContext::new(
+            EntityFormat::Compact,
+            WithPrelude::All,
+            WithSerde::None,
+            false,
+            false,
+            DateTimeCrate::Chrono,
+            BigIntegerType::I64,
+            None,
+            false,
+            false,
+            false,
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            false,
+            vec![],
+            true,
+            BannerVersion::Off,
+        );
+        let files: HashMap<_, _> = EntityTransformer::transform(tables())?
+            .generate(&context)
+            .files
+            .into_iter()
+            .map(|file| (file.name, file.content))
+            .collect();
+        for (name, file_content) in [
+            ("mod.rs", include_str!("../tests_cfg/multi_schema/mod.rs")),
+            (
+                "audit/mod.rs",
+                include_str!("../tests_cfg/multi_schema/audit/mod.rs"),
+            ),
+            (
+                "audit/prelude.rs",
+                include_str!("../tests_cfg/multi_schema/audit/prelude.rs"),
+            ),
+            (
+                "billing/mod.rs",
+                include_str!("../tests_cfg/multi_schema/billing/mod.rs"),
+            ),
+            (
+                "billing/prelude.rs",
+                include_str!("../tests_cfg/multi_schema/billing/prelude.rs"),
+            ),
+        ] {
+            let content: String = files
+                .get(name)
+                .expect("missing output file")
+                .lines()
+                .filter(|line| !line.starts_with("//!"))
+                .collect();
+            assert_eq!(
+                content.parse::<TokenStream>()?.to_string(),
+                file_content.parse::<TokenStream>()?.to_string()
+            );
+        }
+        let mut names: Vec<_> = files.keys().map(String::as_str).collect();
+        names.sort();
+        assert_eq!(
+            names,
+            [
+                "audit/log.rs",
+                "audit/mod.rs",
+                "audit/prelude.rs",
+                "billing/customer.rs",
+                "billing/invoice.rs",
+                "billing/mod.rs",
+                "billing/prelude.rs",
+                "mod.rs",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_unqualified_foreign_keys() -> Result<(), Box<dyn Error>> {
+        let id = || {
+            ColumnDef::new("id")
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key()
+                .to_owned()
+        };
+        let tables = vec![
+            Table::create().table(("audit", "log")).col(id()).to_owned(),
+            Table::create()
+                .table(("billing", "customer"))
+                .col(id())
+                .to_owned(),
+            Table::create()
+                .table(("billing", "log"))
+                .col(id())
+                .to_owned(),
+            Table::create()
+                .table(("billing", "invoice"))
+                .col(id())
+                .col(ColumnDef::new("customer_id").integer().not_null())
+                .col(ColumnDef::new("log_id").integer().not_null())
+                .foreign_key(
+                    ForeignKey::create()
+                        .name("fk-invoice-customer_id")
+                        .from("invoice", "customer_id")
+                        .to("customer", "id"),
+                )
+                .foreign_key(
+                    ForeignKey::create()
+                        .name("fk-invoice-log_id")
+                        .from("invoice", "log_id")
+                        .to("log", "id"),
+                )
+                .to_owned(),
+            Table::create()
+                .table(("audit", "event"))
+                .col(id())
+                .col(ColumnDef::new("customer_id").integer().not_null())
+                .foreign_key(
+                    ForeignKey::create()
+                        .name("fk-event-customer_id")
+                        .from("event", "customer_id")
+                        .to("customer", "id"),
+                )
+                .to_owned(),
+        ];
+
+        let entities: HashMap<_, _> = EntityTransformer::transform(tables)?
+            .entities
+            .into_iter()
+            .map(|entity| {
+                (
+                    format!(
+                        "{}.{}",
+                        entity.schema_name.clone().unwrap(),
+                        entity.table_name
+                    ),
+                    entity,
+                )
+            })
+            .collect();
+        let ref_tables = |key: &str| -> Vec<(String, Option<String>)> {
+            entities[key]
+                .relations
+                .iter()
+                .map(|rel| (rel.ref_table.clone(), rel.ref_schema.clone()))
+                .collect()
+        };
+
+        // A table of the same schema takes precedence
+        assert_eq!(
+            ref_tables("billing.invoice"),
+            [("customer".to_owned(), None), ("log".to_owned(), None)]
+        );
+        // Otherwise the only schema with a table of that name
+        assert_eq!(
+            ref_tables("audit.event"),
+            [("customer".to_owned(), Some("billing".to_owned()))]
+        );
+        assert_eq!(
+            ref_tables("billing.customer"),
+            [
+                ("event".to_owned(), Some("audit".to_owned())),
+                ("invoice".to_owned(), None)
+            ]
+        );
+        assert!(ref_tables("audit.log").is_empty());
+
+        assert!(
+            EntityTransformer::transform(vec![
+                Table::create().table(("audit", "log")).col(id()).to_owned(),
+                Table::create().table("customer").col(id()).to_owned(),
+            ])
+            .is_err()
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_indexes_transform() -> Result<(), Box<dyn Error>> {
         let schema = Schema::new(DbBackend::Postgres);
@@ -414,6 +745,116 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_unique_keys_transform() -> Result<(), Box<dyn Error>> {
+        let schema = Schema::new(DbBackend::Postgres);
+
+        validate_compact_entities(
+            vec![schema.create_table_with_index_from_entity(
+                crate::tests_cfg::compact::unique_keys::Entity,
+            )],
+            vec![(
+                "unique_keys",
+                include_str!("../tests_cfg/compact/unique_keys.rs"),
+            )],
+        )?;
+
+        validate_dense_entities(
+            vec![
+                schema.create_table_with_index_from_entity(
+                    crate::tests_cfg::dense::unique_keys::Entity,
+                ),
+            ],
+            vec![(
+                "unique_keys",
+                include_str!("../tests_cfg/dense/unique_keys.rs"),
+            )],
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_discovered_unique_constraints() -> Result<(), Box<dyn Error>> {
+        // As discovered from `information_schema`, with the constraint names of Postgres
+        let post = Table::create()
+            .table("post")
+            .col(
+                ColumnDef::new("id")
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new("slug").string().not_null())
+            .col(ColumnDef::new("title").string().not_null())
+            .col(ColumnDef::new("author").string().not_null())
+            .index(Index::create().name("post_slug_key").col("slug").unique())
+            .index(
+                Index::create()
+                    .name("post_title_author_key")
+                    .col("title")
+                    .col("author")
+                    .unique(),
+            )
+            .index(
+                Index::create()
+                    .name("post_author_slug_key")
+                    .col("author")
+                    .col("slug")
+                    .unique(),
+            )
+            .to_owned();
+
+        let entity = EntityTransformer::transform(vec![post])?
+            .entities
+            .pop()
+            .expect("missing entity `post`");
+        let column = |name: &str| {
+            entity
+                .columns
+                .iter()
+                .find(|col| col.name == name)
+                .expect("missing column")
+        };
+
+        assert!(column("slug").unique);
+        assert_eq!(
+            column("title").unique_key.as_deref(),
+            Some("post_title_author_key")
+        );
+        assert_eq!(
+            column("author").unique_key.as_deref(),
+            Some("post_title_author_key")
+        );
+        assert_eq!(
+            column("title").get_def().to_string(),
+            quote! {
+                ColumnType::String(StringLen::None).def().unique_key("post_title_author_key")
+            }
+            .to_string()
+        );
+        // `author` already belongs to a unique key, and `slug` is unique
+        assert_eq!(
+            entity.unique_keys,
+            [UniqueKey {
+                name: Some("post_author_slug_key".to_owned()),
+                columns: vec!["author".to_owned(), "slug".to_owned()],
+            }]
+        );
+        assert_eq!(
+            EntityWriter::gen_entity_struct(&entity).to_string(),
+            quote! {
+                #[derive(Copy, Clone, Default, Debug, DeriveEntity)]
+                #[sea_orm(index(name = "post_author_slug_key", columns("author", "slug"), unique))]
+                pub struct Entity;
+            }
+            .to_string()
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn filter_relations_to_missing_entities() -> Result<(), Box<dyn Error>> {
         let parent_stmt = || {
@@ -468,6 +909,107 @@ mod tests {
 
         let child = entities.get("child").expect("missing entity `child`");
         assert!(child.relations.is_empty());
+        assert_eq!(child.omitted_relations, ["parent"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn filter_tables_by_glob() -> Result<(), Box<dyn Error>> {
+        let id = || {
+            ColumnDef::new("id")
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key()
+                .to_owned()
+        };
+        let tables = vec![
+            Table::create()
+                .table("seaql_migrations")
+                .col(id())
+                .to_owned(),
+            Table::create().table("user").col(id()).to_owned(),
+            Table::create()
+                .table("billing_account")
+                .col(id())
+                .to_owned(),
+            Table::create()
+                .table("billing_invoice")
+                .col(id())
+                .col(ColumnDef::new("account_id").integer().not_null())
+                .col(ColumnDef::new("user_id").integer().not_null())
+                .foreign_key(
+                    ForeignKey::create()
+                        .name("fk-billing_invoice-account_id")
+                        .from("billing_invoice", "account_id")
+                        .to("billing_account", "id"),
+                )
+                .foreign_key(
+                    ForeignKey::create()
+                        .name("fk-billing_invoice-user_id")
+                        .from("billing_invoice", "user_id")
+                        .to("user", "id"),
+                )
+                .to_owned(),
+            Table::create().table("billing_tmp").col(id()).to_owned(),
+        ];
+
+        let context = EntityWriterContext::new(
+            EntityFormat::Compact,
+            WithPrelude::All,
+            WithSerde::None,
+            false,
+            false,
+            DateTimeCrate::Chrono,
+            BigIntegerType::I64,
+            None,
+            false,
+            false,
+            false,
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            false,
+            vec![],
+            true,
+            BannerVersion::Off,
+        )
+        .include_tables(vec!["billing_*".to_owned(), "seaql_?igrations".to_owned()])
+        .exclude_tables(vec!["seaql_*".to_owned(), "*_tmp".to_owned()]);
+
+        assert!(context.is_table_included(None, "billing_account"));
+        assert!(context.is_table_included(Some("public"), "billing_account"));
+        assert!(!context.is_table_included(None, "user"));
+        assert!(!context.is_table_included(None, "seaql_migrations"));
+        assert!(!context.is_table_included(None, "billing_tmp"));
+
+        let files: HashMap<_, _> = EntityTransformer::transform(context.filter_tables(tables))?
+            .generate(&context)
+            .files
+            .into_iter()
+            .map(|file| (file.name, file.content))
+            .collect();
+        let mut names: Vec<_> = files.keys().map(String::as_str).collect();
+        names.sort();
+        assert_eq!(
+            names,
+            [
+                "billing_account.rs",
+                "billing_invoice.rs",
+                "mod.rs",
+                "prelude.rs"
+            ]
+        );
+
+        // The relation to `user` is left out, instead of pointing at a missing `super::user`
+        let invoice = &files["billing_invoice.rs"];
+        assert!(invoice.contains("// relation to excluded table user omitted"));
+        assert!(invoice.contains("super::billing_account::Entity"));
+        assert!(!invoice.contains("super::user::"));
+        assert!(!files["billing_account.rs"].contains("omitted"));
 
         Ok(())
     }
@@ -551,6 +1093,115 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn deterministic_output() -> Result<(), Box<dyn Error>> {
+        // Same schema, with tables and foreign keys listed in either order
+        let snapshot = |reversed: bool| {
+            let id = || {
+                ColumnDef::new("id")
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key()
+                    .to_owned()
+            };
+            let with_foreign_keys =
+                |mut table: TableCreateStatement,
+                 mut foreign_keys: Vec<ForeignKeyCreateStatement>| {
+                    if reversed {
+                        foreign_keys.reverse();
+                    }
+                    for foreign_key in foreign_keys.iter_mut() {
+                        table.foreign_key(foreign_key);
+                    }
+                    table
+                };
+            let mut tables = vec![
+                Table::create().table("user").col(id()).to_owned(),
+                Table::create().table("role").col(id()).to_owned(),
+                with_foreign_keys(
+                    Table::create()
+                        .table("user_role")
+                        .col(ColumnDef::new("user_id").integer().not_null().primary_key())
+                        .col(ColumnDef::new("role_id").integer().not_null().primary_key())
+                        .to_owned(),
+                    vec![
+                        ForeignKey::create()
+                            .name("fk-user_role-user_id")
+                            .from("user_role", "user_id")
+                            .to("user", "id")
+                            .to_owned(),
+                        ForeignKey::create()
+                            .name("fk-user_role-role_id")
+                            .from("user_role", "role_id")
+                            .to("role", "id")
+                            .to_owned(),
+                    ],
+                ),
+                with_foreign_keys(
+                    Table::create()
+                        .table("post")
+                        .col(id())
+                        .col(ColumnDef::new("author_id").integer().not_null())
+                        .col(ColumnDef::new("editor_id").integer())
+                        .to_owned(),
+                    vec![
+                        ForeignKey::create()
+                            .name("fk-post-author_id")
+                            .from("post", "author_id")
+                            .to("user", "id")
+                            .to_owned(),
+                        ForeignKey::create()
+                            .name("fk-post-editor_id")
+                            .from("post", "editor_id")
+                            .to("user", "id")
+                            .to_owned(),
+                    ],
+                ),
+            ];
+            if reversed {
+                tables.reverse();
+            }
+            tables
+        };
+        let generate = |tables| -> Result<Vec<(String, String)>, crate::Error> {
+            let context = EntityWriterContext::new(
+                EntityFormat::Compact,
+                WithPrelude::All,
+                WithSerde::None,
+                false,
+                false,
+                DateTimeCrate::Chrono,
+                BigIntegerType::I64,
+                None,
+                false,
+                false,
+                false,
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                false,
+                vec![],
+                true,
+                BannerVersion::Off,
+            );
+            Ok(EntityTransformer::transform(tables)?
+                .generate(&context)
+                .files
+                .into_iter()
+                .map(|file| (file.name, file.content))
+                .collect())
+        };
+
+        let output = generate(snapshot(false))?;
+        assert_eq!(output, generate(snapshot(false))?);
+        assert_eq!(output, generate(snapshot(true))?);
+
+        Ok(())
+    }
+
     macro_rules! validate_entities_fn {
         ($fn_name: ident, $method: ident) => {
             fn $fn_name(
@@ -574,13 +1225,14 @@ mod tests {
                             entity,
                             &crate::WithSerde::None,
                             &Default::default(),
-                            &None,
+                            &entity.schema_name,
                             false,
                             false,
                             &Default::default(),
                             &Default::default(),
                             &Default::default(),
                             false,
+                            &[],
                             true,
                         )
                         .into_iter()
@@ -0,0 +1,47 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// A composite unique constraint that can't be declared with `unique_key` on its
+/// columns, because one of them already belongs to a unique key.
+/// It is declared with the struct-level `#[sea_orm(index(.., unique))]` instead.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UniqueKey {
+    pub(crate) name: Option<String>,
+    pub(crate) columns: Vec<String>,
+}
+
+impl UniqueKey {
+    pub fn get_attr(&self) -> TokenStream {
+        let name = self.name.as_ref().map(|name| quote! { name = #name, });
+        let columns = &self.columns;
+        quote! {
+            index(#name columns(#(#columns),*), unique)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UniqueKey;
+
+    #[test]
+    fn test_get_attr() {
+        let unique_key = UniqueKey {
+            name: Some("post_title_author_key".to_owned()),
+            columns: vec!["title".to_owned(), "author".to_owned()],
+        };
+        assert_eq!(
+            unique_key.get_attr().to_string(),
+            r#"index (name = "post_title_author_key" , columns ("title" , "author") , unique)"#
+        );
+
+        let unique_key = UniqueKey {
+            name: None,
+            columns: vec!["title".to_owned(), "author".to_owned()],
+        };
+        assert_eq!(
+            unique_key.get_attr().to_string(),
+            r#"index (columns ("title" , "author") , unique)"#
+        );
+    }
+}
@@ -1,13 +1,17 @@
-use crate::{ActiveEnum, ColumnOption, Entity, util::escape_rust_keyword};
-use heck::ToUpperCamelCase;
+use crate::{
+    ActiveEnum, ColumnOption, Entity,
+    util::{escape_rust_keyword, get_table_schema, glob_match},
+};
+use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
+use sea_query::TableCreateStatement;
 use std::{
     collections::{BTreeMap, BTreeSet},
     str::FromStr,
 };
 use syn::{punctuated::Punctuated, token::Comma};
-use tracing::info;
+use tracing::{info, warn};
 
 mod compact;
 mod dense;
@@ -92,6 +96,7 @@ pub struct EntityWriterContext {
     pub(crate) with_prelude: WithPrelude,
     pub(crate) with_serde: WithSerde,
     pub(crate) with_copy_enums: bool,
+    pub(crate) enum_display_from_str: bool,
     pub(crate) date_time_crate: DateTimeCrate,
     pub(crate) big_integer_type: BigIntegerType,
     pub(crate) schema_name: Option<String>,
@@ -104,8 +109,11 @@ pub struct EntityWriterContext {
     pub(crate) enum_extra_attributes: TokenStream,
     pub(crate) column_extra_derives: TokenStream,
     pub(crate) seaography: bool,
+    pub(crate) seaography_skip_relations: Vec<String>,
     pub(crate) impl_active_model_behavior: bool,
     pub(crate) banner_version: BannerVersion,
+    pub(crate) include_tables: Vec<String>,
+    pub(crate) exclude_tables: Vec<String>,
 }
 
 impl WithSerde {
@@ -230,6 +238,7 @@ impl EntityWriterContext {
         with_prelude: WithPrelude,
         with_serde: WithSerde,
         with_copy_enums: bool,
+        enum_display_from_str: bool,
         date_time_crate: DateTimeCrate,
         big_integer_type: BigIntegerType,
         schema_name: Option<String>,
@@ -242,6 +251,7 @@ impl EntityWriterContext {
         enum_extra_attributes: Vec<String>,
         column_extra_derives: Vec<String>,
         seaography: bool,
+        seaography_skip_relations: Vec<String>,
         impl_active_model_behavior: bool,
         banner_version: BannerVersion,
     ) -> Self {
@@ -250,6 +260,7 @@ impl EntityWriterContext {
             with_prelude,
             with_serde,
             with_copy_enums,
+            enum_display_from_str,
             date_time_crate,
             big_integer_type,
             schema_name,
@@ -262,11 +273,58 @@ impl EntityWriterContext {
             enum_extra_attributes: bonus_attributes(enum_extra_attributes),
             column_extra_derives: bonus_derive(column_extra_derives),
             seaography,
+            seaography_skip_relations,
             impl_active_model_behavior,
             banner_version,
+            include_tables: Vec::new(),
+            exclude_tables: Vec::new(),
         }
     }
 
+    /// Only generate tables matching one of these glob patterns, e.g. `billing_*`;
+    /// all tables are generated if empty
+    pub fn include_tables(mut self, patterns: Vec<String>) -> Self {
+        self.include_tables = patterns;
+        self
+    }
+
+    /// Skip tables matching any of these glob patterns, e.g. `seaql_*`
+    pub fn exclude_tables(mut self, patterns: Vec<String>) -> Self {
+        self.exclude_tables = patterns;
+        self
+    }
+
+    /// Whether a table is kept by the include / exclude patterns.
+    /// A pattern is matched against both `table` and `schema.table`.
+    pub fn is_table_included(&self, schema_name: Option<&str>, table_name: &str) -> bool {
+        let qualified_name = schema_name.map(|schema_name| format!("{schema_name}.{table_name}"));
+        let matches = |pattern: &String| {
+            glob_match(pattern, table_name)
+                || qualified_name
+                    .as_deref()
+                    .is_some_and(|name| glob_match(pattern, name))
+        };
+        (self.include_tables.is_empty() || self.include_tables.iter().any(matches))
+            && !self.exclude_tables.iter().any(matches)
+    }
+
+    /// Drop the tables filtered out by the include / exclude patterns, to be done before
+    /// [`EntityTransformer::transform`][crate::EntityTransformer::transform].
+    /// Foreign keys pointing at a dropped table are left out of the generated entities.
+    pub fn filter_tables(
+        &self,
+        mut table_create_stmts: Vec<TableCreateStatement>,
+    ) -> Vec<TableCreateStatement> {
+        table_create_stmts.retain(|table_create| match table_create.get_table_name() {
+            Some(table_ref) => self.is_table_included(
+                get_table_schema(table_ref).as_deref(),
+                &table_ref.sea_orm_table().to_string(),
+            ),
+            None => true,
+        });
+        table_create_stmts
+    }
+
     fn column_option(&self) -> ColumnOption {
         ColumnOption {
             date_time_crate: self.date_time_crate,
@@ -276,12 +334,67 @@ impl EntityWriterContext {
 }
 
 impl EntityWriter {
+    /// Entities of more than one schema are generated into a module per schema, e.g. `billing/invoice.rs`,
+    /// each with its own index, prelude and active enums
     pub fn generate(self, context: &EntityWriterContext) -> WriterOutput {
+        let schema_writers = self.split_by_schema();
+        if schema_writers.len() < 2 {
+            return WriterOutput {
+                files: self.generate_files(context, context.lib),
+            };
+        }
+        let mut files = Vec::new();
+        let mut schema_modules = Vec::new();
+        for (schema_name, writer) in schema_writers {
+            let schema_module =
+                escape_rust_keyword(schema_name.unwrap_or_default().to_snake_case());
+            files.extend(
+                writer
+                    .generate_files(context, false)
+                    .into_iter()
+                    .map(|file| OutputFile {
+                        name: format!("{schema_module}/{}", file.name),
+                        content: file.content,
+                    }),
+            );
+            schema_modules.push(format_ident!("{schema_module}"));
+        }
+        files.push(Self::write_schema_index_file(
+            &schema_modules,
+            context.lib,
+            context.banner_version,
+        ));
+        WriterOutput { files }
+    }
+
+    /// Entities grouped by schema, along with the active enums they use
+    fn split_by_schema(&self) -> BTreeMap<Option<String>, EntityWriter> {
+        let mut schema_writers: BTreeMap<Option<String>, EntityWriter> = BTreeMap::new();
+        for entity in self.entities.iter() {
+            let writer = schema_writers
+                .entry(entity.schema_name.clone())
+                .or_insert_with(|| EntityWriter {
+                    entities: Vec::new(),
+                    enums: BTreeMap::new(),
+                });
+            for col in entity.columns.iter() {
+                if let sea_query::ColumnType::Enum { name, .. } = col.get_inner_col_type()
+                    && let Some(active_enum) = self.enums.get(&name.to_string())
+                {
+                    writer.enums.insert(name.to_string(), active_enum.clone());
+                }
+            }
+            writer.entities.push(entity.clone());
+        }
+        schema_writers
+    }
+
+    fn generate_files(&self, context: &EntityWriterContext, lib: bool) -> Vec<OutputFile> {
         let mut files = Vec::new();
         files.extend(self.write_entities(context));
         let with_prelude = context.with_prelude != WithPrelude::None;
         files.push(self.write_index_file(
-            context.lib,
+            lib,
             with_prelude,
             context.seaography,
             context.banner_version,
@@ -297,13 +410,14 @@ impl EntityWriter {
             files.push(self.write_sea_orm_active_enums(
                 &context.with_serde,
                 context.with_copy_enums,
+                context.enum_display_from_str,
                 &context.enum_extra_derives,
                 &context.enum_extra_attributes,
                 context.entity_format,
                 context.banner_version,
             ));
         }
-        WriterOutput { files }
+        files
     }
 
     pub fn write_entities(&self, context: &EntityWriterContext) -> Vec<OutputFile> {
@@ -326,6 +440,22 @@ impl EntityWriter {
                         WithSerde::Both | WithSerde::Serialize | WithSerde::Deserialize
                     );
 
+                // Relations listed as `<table>.<RelatedEntity variant>` are left out of `enum RelatedEntity`
+                let seaography_skip_relations: Vec<String> = context
+                    .seaography_skip_relations
+                    .iter()
+                    .filter_map(|skip| {
+                        let (table_name, variant) = skip.split_once('.')?;
+                        (table_name == entity.table_name).then(|| variant.to_owned())
+                    })
+                    .collect();
+
+                // Entities of a schema-qualified table carry their own schema
+                let schema_name = entity
+                    .schema_name
+                    .clone()
+                    .or_else(|| context.schema_name.clone());
+
                 info!("Generating {}", entity_file);
                 for info in column_info.iter() {
                     info!("    > {}", info);
@@ -333,18 +463,25 @@ impl EntityWriter {
 
                 let mut lines = Vec::new();
                 Self::write_doc_comment(&mut lines, context.banner_version);
+                if !entity.omitted_relations.is_empty() {
+                    for ref_table in entity.omitted_relations.iter() {
+                        warn!("    > relation to excluded table {} omitted", ref_table);
+                    }
+                    lines.push(Self::gen_omitted_relations_comment(entity));
+                }
                 let code_blocks = if context.entity_format == EntityFormat::Frontend {
                     Self::gen_frontend_code_blocks(
                         entity,
                         &context.with_serde,
                         &context.column_option(),
-                        &context.schema_name,
+                        &schema_name,
                         serde_skip_deserializing_primary_key,
                         serde_skip_hidden_column,
                         &context.model_extra_derives,
                         &context.model_extra_attributes,
                         &context.column_extra_derives,
                         context.seaography,
+                        &seaography_skip_relations,
                         context.impl_active_model_behavior,
                     )
                 } else if context.entity_format == EntityFormat::Expanded {
@@ -352,13 +489,14 @@ impl EntityWriter {
                         entity,
                         &context.with_serde,
                         &context.column_option(),
-                        &context.schema_name,
+                        &schema_name,
                         serde_skip_deserializing_primary_key,
                         serde_skip_hidden_column,
                         &context.model_extra_derives,
                         &context.model_extra_attributes,
                         &context.column_extra_derives,
                         context.seaography,
+                        &seaography_skip_relations,
                         context.impl_active_model_behavior,
                     )
                 } else if context.entity_format == EntityFormat::Dense {
@@ -366,13 +504,14 @@ impl EntityWriter {
                         entity,
                         &context.with_serde,
                         &context.column_option(),
-                        &context.schema_name,
+                        &schema_name,
                         serde_skip_deserializing_primary_key,
                         serde_skip_hidden_column,
                         &context.model_extra_derives,
                         &context.model_extra_attributes,
                         &context.column_extra_derives,
                         context.seaography,
+                        &seaography_skip_relations,
                         context.impl_active_model_behavior,
                     )
                 } else {
@@ -380,13 +519,14 @@ impl EntityWriter {
                         entity,
                         &context.with_serde,
                         &context.column_option(),
-                        &context.schema_name,
+                        &schema_name,
                         serde_skip_deserializing_primary_key,
                         serde_skip_hidden_column,
                         &context.model_extra_derives,
                         &context.model_extra_attributes,
                         &context.column_extra_derives,
                         context.seaography,
+                        &seaography_skip_relations,
                         context.impl_active_model_behavior,
                     )
                 };
@@ -445,6 +585,34 @@ impl EntityWriter {
         }
     }
 
+    pub fn write_schema_index_file(
+        schema_modules: &[Ident],
+        lib: bool,
+        banner_version: BannerVersion,
+    ) -> OutputFile {
+        let mut lines = Vec::new();
+        Self::write_doc_comment(&mut lines, banner_version);
+        let code_blocks = schema_modules
+            .iter()
+            .map(|schema_module| {
+                quote! {
+                    pub mod #schema_module;
+                }
+            })
+            .collect();
+        Self::write(&mut lines, code_blocks);
+
+        let file_name = match lib {
+            true => "lib.rs".to_owned(),
+            false => "mod.rs".to_owned(),
+        };
+
+        OutputFile {
+            name: file_name,
+            content: lines.join("\n"),
+        }
+    }
+
     pub fn write_prelude(
         &self,
         with_prelude: WithPrelude,
@@ -474,10 +642,12 @@ impl EntityWriter {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn write_sea_orm_active_enums(
         &self,
         with_serde: &WithSerde,
         with_copy_enums: bool,
+        enum_display_from_str: bool,
         extra_derives: &TokenStream,
         extra_attributes: &TokenStream,
         entity_format: EntityFormat,
@@ -498,6 +668,7 @@ impl EntityWriter {
                 active_enum.impl_active_enum(
                     with_serde,
                     with_copy_enums,
+                    enum_display_from_str,
                     extra_derives,
                     extra_attributes,
                     entity_format,
@@ -519,6 +690,15 @@ impl EntityWriter {
         lines.extend(code_blocks);
     }
 
+    pub fn gen_omitted_relations_comment(entity: &Entity) -> String {
+        entity
+            .omitted_relations
+            .iter()
+            .map(|ref_table| format!("// relation to excluded table {ref_table} omitted"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     pub fn write_doc_comment(lines: &mut Vec<String>, banner_version: BannerVersion) {
         let ver = env!("CARGO_PKG_VERSION");
         let version_str = match banner_version {
@@ -582,9 +762,11 @@ impl EntityWriter {
         }
     }
 
-    pub fn gen_entity_struct() -> TokenStream {
+    pub fn gen_entity_struct(entity: &Entity) -> TokenStream {
+        let unique_keys = entity.get_unique_key_attrs();
         quote! {
             #[derive(Copy, Clone, Default, Debug, DeriveEntity)]
+            #(#[sea_orm(#unique_keys)])*
             pub struct Entity;
         }
     }
@@ -775,6 +957,40 @@ impl EntityWriter {
                 }
             })
             .collect();
+        let (select_as_names, select_as_types): (Vec<_>, Vec<_>) = entity
+            .columns
+            .iter()
+            .filter_map(|col| Some((col.get_name_camel_case(), col.get_select_as()?)))
+            .unzip();
+        let select_as = if select_as_names.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                fn select_as(&self, expr: Expr) -> Expr {
+                    match self {
+                        #(Self::#select_as_names => sea_orm::sea_query::ExprTrait::cast_as(expr, #select_as_types),)*
+                        _ => self.select_enum_as(expr),
+                    }
+                }
+            }
+        };
+        let (save_as_names, save_as_types): (Vec<_>, Vec<_>) = entity
+            .columns
+            .iter()
+            .filter_map(|col| Some((col.get_name_camel_case(), col.get_save_as()?)))
+            .unzip();
+        let save_as = if save_as_names.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                fn save_as(&self, val: Expr) -> Expr {
+                    match self {
+                        #(Self::#save_as_names => sea_orm::sea_query::ExprTrait::cast_as(val, #save_as_types),)*
+                        _ => self.save_enum_as(val),
+                    }
+                }
+            }
+        };
         quote! {
             impl ColumnTrait for Column {
                 type EntityName = Entity;
@@ -784,6 +1000,10 @@ impl EntityWriter {
                         #(Self::#column_names_camel_case => #column_defs,)*
                     }
                 }
+
+                #select_as
+
+                #save_as
             }
         }
     }
@@ -818,15 +1038,15 @@ impl EntityWriter {
             .filter(|rel| !rel.self_referencing && rel.num_suffix == 0 && rel.impl_related)
             .map(|rel| {
                 let enum_name = rel.get_enum_name();
-                let module_name = rel.get_module_name();
+                let module_path = rel.get_module_path();
                 let inner = quote! {
                     fn to() -> RelationDef {
                         Relation::#enum_name.def()
                     }
                 };
-                if module_name.is_some() {
+                if let Some(module_path) = module_path {
                     quote! {
-                        impl Related<super::#module_name::Entity> for Entity { #inner }
+                        impl Related<#module_path::Entity> for Entity { #inner }
                     }
                 } else {
                     quote! {
@@ -838,9 +1058,13 @@ impl EntityWriter {
     }
 
     /// Used to generate `enum RelatedEntity` that is useful to the Seaography project
-    pub fn gen_related_entity(entity: &Entity) -> TokenStream {
-        let related_enum_name = entity.get_related_entity_enum_name();
-        let related_attrs = entity.get_related_entity_attrs();
+    pub fn gen_related_entity(entity: &Entity, skip_relations: &[String]) -> TokenStream {
+        let (related_attrs, related_enum_name): (Vec<_>, Vec<_>) = entity
+            .get_related_entity_attrs()
+            .into_iter()
+            .zip(entity.get_related_entity_enum_name())
+            .filter(|(_, name)| !skip_relations.iter().any(|skip| name == skip))
+            .unzip();
 
         quote! {
             #[derive(Copy, Clone, Debug, EnumIter, DeriveRelatedEntity)]
@@ -982,6 +1206,7 @@ mod tests {
         vec![
             Entity {
                 table_name: "cake".to_owned(),
+                schema_name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -990,6 +1215,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "name".to_owned(),
@@ -998,10 +1224,12 @@ mod tests {
                         not_null: false,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                 ],
                 relations: vec![Relation {
                     ref_table: "fruit".to_owned(),
+                    ref_schema: None,
                     columns: vec![],
                     ref_columns: vec![],
                     rel_type: RelationType::HasMany,
@@ -1015,12 +1243,15 @@ mod tests {
                     via: "cake_filling".to_owned(),
                     to: "filling".to_owned(),
                 }],
+                omitted_relations: vec![],
                 primary_keys: vec![PrimaryKey {
                     name: "id".to_owned(),
                 }],
+                unique_keys: vec![],
             },
             Entity {
                 table_name: "_cake_filling_".to_owned(),
+                schema_name: None,
                 columns: vec![
                     Column {
                         name: "cake_id".to_owned(),
@@ -1029,6 +1260,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "filling_id".to_owned(),
@@ -1037,11 +1269,13 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                 ],
                 relations: vec![
                     Relation {
                         ref_table: "cake".to_owned(),
+                        ref_schema: None,
                         columns: vec!["cake_id".to_owned()],
                         ref_columns: vec!["id".to_owned()],
                         rel_type: RelationType::BelongsTo,
@@ -1053,6 +1287,7 @@ mod tests {
                     },
                     Relation {
                         ref_table: "filling".to_owned(),
+                        ref_schema: None,
                         columns: vec!["filling_id".to_owned()],
                         ref_columns: vec!["id".to_owned()],
                         rel_type: RelationType::BelongsTo,
@@ -1064,6 +1299,7 @@ mod tests {
                     },
                 ],
                 conjunct_relations: vec![],
+                omitted_relations: vec![],
                 primary_keys: vec![
                     PrimaryKey {
                         name: "cake_id".to_owned(),
@@ -1072,9 +1308,11 @@ mod tests {
                         name: "filling_id".to_owned(),
                     },
                 ],
+                unique_keys: vec![],
             },
             Entity {
                 table_name: "cake_filling_price".to_owned(),
+                schema_name: None,
                 columns: vec![
                     Column {
                         name: "cake_id".to_owned(),
@@ -1083,6 +1321,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "filling_id".to_owned(),
@@ -1091,6 +1330,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "price".to_owned(),
@@ -1099,10 +1339,12 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                 ],
                 relations: vec![Relation {
                     ref_table: "cake_filling".to_owned(),
+                    ref_schema: None,
                     columns: vec!["cake_id".to_owned(), "filling_id".to_owned()],
                     ref_columns: vec!["cake_id".to_owned(), "filling_id".to_owned()],
                     rel_type: RelationType::BelongsTo,
@@ -1113,6 +1355,7 @@ mod tests {
                     impl_related: true,
                 }],
                 conjunct_relations: vec![],
+                omitted_relations: vec![],
                 primary_keys: vec![
                     PrimaryKey {
                         name: "cake_id".to_owned(),
@@ -1121,9 +1364,11 @@ mod tests {
                         name: "filling_id".to_owned(),
                     },
                 ],
+                unique_keys: vec![],
             },
             Entity {
                 table_name: "filling".to_owned(),
+                schema_name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1132,6 +1377,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "name".to_owned(),
@@ -1140,6 +1386,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                 ],
                 relations: vec![],
@@ -1147,12 +1394,15 @@ mod tests {
                     via: "cake_filling".to_owned(),
                     to: "cake".to_owned(),
                 }],
+                omitted_relations: vec![],
                 primary_keys: vec![PrimaryKey {
                     name: "id".to_owned(),
                 }],
+                unique_keys: vec![],
             },
             Entity {
                 table_name: "fruit".to_owned(),
+                schema_name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1161,6 +1411,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "name".to_owned(),
@@ -1169,6 +1420,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "cake_id".to_owned(),
@@ -1177,11 +1429,13 @@ mod tests {
                         not_null: false,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                 ],
                 relations: vec![
                     Relation {
                         ref_table: "cake".to_owned(),
+                        ref_schema: None,
                         columns: vec!["cake_id".to_owned()],
                         ref_columns: vec!["id".to_owned()],
                         rel_type: RelationType::BelongsTo,
@@ -1193,6 +1447,7 @@ mod tests {
                     },
                     Relation {
                         ref_table: "vendor".to_owned(),
+                        ref_schema: None,
                         columns: vec![],
                         ref_columns: vec![],
                         rel_type: RelationType::HasMany,
@@ -1204,12 +1459,15 @@ mod tests {
                     },
                 ],
                 conjunct_relations: vec![],
+                omitted_relations: vec![],
                 primary_keys: vec![PrimaryKey {
                     name: "id".to_owned(),
                 }],
+                unique_keys: vec![],
             },
             Entity {
                 table_name: "vendor".to_owned(),
+                schema_name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1218,6 +1476,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "_name_".to_owned(),
@@ -1226,6 +1485,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "fruitId".to_owned(),
@@ -1234,10 +1494,12 @@ mod tests {
                         not_null: false,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                 ],
                 relations: vec![Relation {
                     ref_table: "fruit".to_owned(),
+                    ref_schema: None,
                     columns: vec!["fruitId".to_owned()],
                     ref_columns: vec!["id".to_owned()],
                     rel_type: RelationType::BelongsTo,
@@ -1248,12 +1510,15 @@ mod tests {
                     impl_related: true,
                 }],
                 conjunct_relations: vec![],
+                omitted_relations: vec![],
                 primary_keys: vec![PrimaryKey {
                     name: "id".to_owned(),
                 }],
+                unique_keys: vec![],
             },
             Entity {
                 table_name: "rust_keyword".to_owned(),
+                schema_name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1262,6 +1527,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "testing".to_owned(),
@@ -1270,6 +1536,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "rust".to_owned(),
@@ -1278,6 +1545,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "keywords".to_owned(),
@@ -1286,6 +1554,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "type".to_owned(),
@@ -1294,6 +1563,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "typeof".to_owned(),
@@ -1302,6 +1572,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "crate".to_owned(),
@@ -1310,6 +1581,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "self".to_owned(),
@@ -1318,6 +1590,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "self_id1".to_owned(),
@@ -1326,6 +1599,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "self_id2".to_owned(),
@@ -1334,6 +1608,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "fruit_id1".to_owned(),
@@ -1342,6 +1617,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "fruit_id2".to_owned(),
@@ -1350,6 +1626,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "cake_id".to_owned(),
@@ -1358,11 +1635,13 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                 ],
                 relations: vec![
                     Relation {
                         ref_table: "rust_keyword".to_owned(),
+                        ref_schema: None,
                         columns: vec!["self_id1".to_owned()],
                         ref_columns: vec!["id".to_owned()],
                         rel_type: RelationType::BelongsTo,
@@ -1374,6 +1653,7 @@ mod tests {
                     },
                     Relation {
                         ref_table: "rust_keyword".to_owned(),
+                        ref_schema: None,
                         columns: vec!["self_id2".to_owned()],
                         ref_columns: vec!["id".to_owned()],
                         rel_type: RelationType::BelongsTo,
@@ -1385,6 +1665,7 @@ mod tests {
                     },
                     Relation {
                         ref_table: "fruit".to_owned(),
+                        ref_schema: None,
                         columns: vec!["fruit_id1".to_owned()],
                         ref_columns: vec!["id".to_owned()],
                         rel_type: RelationType::BelongsTo,
@@ -1396,6 +1677,7 @@ mod tests {
                     },
                     Relation {
                         ref_table: "fruit".to_owned(),
+                        ref_schema: None,
                         columns: vec!["fruit_id2".to_owned()],
                         ref_columns: vec!["id".to_owned()],
                         rel_type: RelationType::BelongsTo,
@@ -1407,6 +1689,7 @@ mod tests {
                     },
                     Relation {
                         ref_table: "cake".to_owned(),
+                        ref_schema: None,
                         columns: vec!["cake_id".to_owned()],
                         ref_columns: vec!["id".to_owned()],
                         rel_type: RelationType::BelongsTo,
@@ -1418,12 +1701,15 @@ mod tests {
                     },
                 ],
                 conjunct_relations: vec![],
+                omitted_relations: vec![],
                 primary_keys: vec![PrimaryKey {
                     name: "id".to_owned(),
                 }],
+                unique_keys: vec![],
             },
             Entity {
                 table_name: "cake_with_float".to_owned(),
+                schema_name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1432,6 +1718,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "name".to_owned(),
@@ -1440,6 +1727,7 @@ mod tests {
                         not_null: false,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "price".to_owned(),
@@ -1448,10 +1736,12 @@ mod tests {
                         not_null: false,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                 ],
                 relations: vec![Relation {
                     ref_table: "fruit".to_owned(),
+                    ref_schema: None,
                     columns: vec![],
                     ref_columns: vec![],
                     rel_type: RelationType::HasMany,
@@ -1465,12 +1755,15 @@ mod tests {
                     via: "cake_filling".to_owned(),
                     to: "filling".to_owned(),
                 }],
+                omitted_relations: vec![],
                 primary_keys: vec![PrimaryKey {
                     name: "id".to_owned(),
                 }],
+                unique_keys: vec![],
             },
             Entity {
                 table_name: "cake_with_double".to_owned(),
+                schema_name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1479,6 +1772,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "name".to_owned(),
@@ -1487,6 +1781,7 @@ mod tests {
                         not_null: false,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "price".to_owned(),
@@ -1495,10 +1790,12 @@ mod tests {
                         not_null: false,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                 ],
                 relations: vec![Relation {
                     ref_table: "fruit".to_owned(),
+                    ref_schema: None,
                     columns: vec![],
                     ref_columns: vec![],
                     rel_type: RelationType::HasMany,
@@ -1512,12 +1809,15 @@ mod tests {
                     via: "cake_filling".to_owned(),
                     to: "filling".to_owned(),
                 }],
+                omitted_relations: vec![],
                 primary_keys: vec![PrimaryKey {
                     name: "id".to_owned(),
                 }],
+                unique_keys: vec![],
             },
             Entity {
                 table_name: "collection".to_owned(),
+                schema_name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1526,6 +1826,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "integers".to_owned(),
@@ -1534,6 +1835,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "integers_opt".to_owned(),
@@ -1542,16 +1844,20 @@ mod tests {
                         not_null: false,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                 ],
                 relations: vec![],
                 conjunct_relations: vec![],
+                omitted_relations: vec![],
                 primary_keys: vec![PrimaryKey {
                     name: "id".to_owned(),
                 }],
+                unique_keys: vec![],
             },
             Entity {
                 table_name: "collection_float".to_owned(),
+                schema_name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1560,6 +1866,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "floats".to_owned(),
@@ -1568,6 +1875,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "doubles".to_owned(),
@@ -1576,16 +1884,20 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                 ],
                 relations: vec![],
                 conjunct_relations: vec![],
+                omitted_relations: vec![],
                 primary_keys: vec![PrimaryKey {
                     name: "id".to_owned(),
                 }],
+                unique_keys: vec![],
             },
             Entity {
                 table_name: "parent".to_owned(),
+                schema_name: None,
                 columns: vec![
                     Column {
                         name: "id1".to_owned(),
@@ -1594,6 +1906,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "id2".to_owned(),
@@ -1602,10 +1915,12 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                 ],
                 relations: vec![Relation {
                     ref_table: "child".to_owned(),
+                    ref_schema: None,
                     columns: vec![],
                     ref_columns: vec![],
                     rel_type: RelationType::HasMany,
@@ -1616,6 +1931,7 @@ mod tests {
                     impl_related: true,
                 }],
                 conjunct_relations: vec![],
+                omitted_relations: vec![],
                 primary_keys: vec![
                     PrimaryKey {
                         name: "id1".to_owned(),
@@ -1624,9 +1940,11 @@ mod tests {
                         name: "id2".to_owned(),
                     },
                 ],
+                unique_keys: vec![],
             },
             Entity {
                 table_name: "child".to_owned(),
+                schema_name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1635,6 +1953,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "parent_id1".to_owned(),
@@ -1643,6 +1962,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "parent_id2".to_owned(),
@@ -1651,10 +1971,12 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                 ],
                 relations: vec![Relation {
                     ref_table: "parent".to_owned(),
+                    ref_schema: None,
                     columns: vec!["parent_id1".to_owned(), "parent_id2".to_owned()],
                     ref_columns: vec!["id1".to_owned(), "id2".to_owned()],
                     rel_type: RelationType::BelongsTo,
@@ -1665,12 +1987,15 @@ mod tests {
                     impl_related: true,
                 }],
                 conjunct_relations: vec![],
+                omitted_relations: vec![],
                 primary_keys: vec![PrimaryKey {
                     name: "id".to_owned(),
                 }],
+                unique_keys: vec![],
             },
             Entity {
                 table_name: "imports".to_owned(),
+                schema_name: None,
                 columns: vec![
                     Column {
                         name: "a".to_owned(),
@@ -1679,6 +2004,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "b".to_owned(),
@@ -1687,6 +2013,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "c".to_owned(),
@@ -1695,6 +2022,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "d".to_owned(),
@@ -1703,6 +2031,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "e".to_owned(),
@@ -1711,6 +2040,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "f".to_owned(),
@@ -1719,6 +2049,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "g".to_owned(),
@@ -1727,6 +2058,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "h".to_owned(),
@@ -1735,6 +2067,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "i".to_owned(),
@@ -1743,6 +2076,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "j".to_owned(),
@@ -1751,6 +2085,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "k".to_owned(),
@@ -1761,13 +2096,16 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                 ],
                 relations: vec![],
                 conjunct_relations: vec![],
+                omitted_relations: vec![],
                 primary_keys: vec![PrimaryKey {
                     name: "a".to_owned(),
                 }],
+                unique_keys: vec![],
             },
         ]
     }
@@ -1820,9 +2158,11 @@ mod tests {
             not_null: true,
             unique: false,
             unique_key: None,
+            generated: false,
         };
         let entity = Entity {
             table_name: "model_example".to_owned(),
+            schema_name: None,
             columns: vec![
                 Column {
                     name: "id".to_owned(),
@@ -1831,6 +2171,7 @@ mod tests {
                     not_null: true,
                     unique: false,
                     unique_key: None,
+                    generated: false,
                 },
                 enum_column("model"),
                 enum_column("active_model"),
@@ -1841,9 +2182,11 @@ mod tests {
             ],
             relations: vec![],
             conjunct_relations: vec![],
+            omitted_relations: vec![],
             primary_keys: vec![PrimaryKey {
                 name: "id".to_owned(),
             }],
+            unique_keys: vec![],
         };
 
         let code_blocks = EntityWriter::gen_expanded_code_blocks(
@@ -1857,6 +2200,7 @@ mod tests {
             &TokenStream::new(),
             &TokenStream::new(),
             false,
+            &[],
             true,
         );
 
@@ -1946,6 +2290,7 @@ mod tests {
                     &TokenStream::new(),
                     &TokenStream::new(),
                     false,
+                    &[],
                     true,
                 )
                 .into_iter()
@@ -1969,6 +2314,7 @@ mod tests {
                     &TokenStream::new(),
                     &TokenStream::new(),
                     false,
+                    &[],
                     true,
                 )
                 .into_iter()
@@ -2036,6 +2382,7 @@ mod tests {
                     &TokenStream::new(),
                     &TokenStream::new(),
                     false,
+                    &[],
                     true,
                 )
                 .into_iter()
@@ -2059,6 +2406,7 @@ mod tests {
                     &TokenStream::new(),
                     &TokenStream::new(),
                     false,
+                    &[],
                     true,
                 )
                 .into_iter()
@@ -2126,6 +2474,7 @@ mod tests {
                     &TokenStream::new(),
                     &TokenStream::new(),
                     false,
+                    &[],
                     true,
                 )
                 .into_iter()
@@ -2149,6 +2498,7 @@ mod tests {
                     &TokenStream::new(),
                     &TokenStream::new(),
                     false,
+                    &[],
                     true,
                 )
                 .into_iter()
@@ -2186,6 +2536,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2213,6 +2564,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2231,6 +2583,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2249,6 +2602,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2265,6 +2619,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2283,6 +2638,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2301,6 +2657,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2319,6 +2676,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2335,6 +2693,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2353,6 +2712,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2371,6 +2731,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2389,6 +2750,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2405,6 +2767,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2416,6 +2779,7 @@ mod tests {
     fn test_gen_with_seaography() -> io::Result<()> {
         let cake_entity = Entity {
             table_name: "cake".to_owned(),
+            schema_name: None,
             columns: vec![
                 Column {
                     name: "id".to_owned(),
@@ -2424,6 +2788,7 @@ mod tests {
                     not_null: true,
                     unique: false,
                     unique_key: None,
+                    generated: false,
                 },
                 Column {
                     name: "name".to_owned(),
@@ -2432,6 +2797,7 @@ mod tests {
                     not_null: false,
                     unique: false,
                     unique_key: None,
+                    generated: false,
                 },
                 Column {
                     name: "base_id".to_owned(),
@@ -2440,11 +2806,13 @@ mod tests {
                     not_null: false,
                     unique: false,
                     unique_key: None,
+                    generated: false,
                 },
             ],
             relations: vec![
                 Relation {
                     ref_table: "fruit".to_owned(),
+                    ref_schema: None,
                     columns: vec![],
                     ref_columns: vec![],
                     rel_type: RelationType::HasMany,
@@ -2456,6 +2824,7 @@ mod tests {
                 },
                 Relation {
                     ref_table: "cake".to_owned(),
+                    ref_schema: None,
                     columns: vec![],
                     ref_columns: vec![],
                     rel_type: RelationType::HasOne,
@@ -2470,9 +2839,11 @@ mod tests {
                 via: "cake_filling".to_owned(),
                 to: "filling".to_owned(),
             }],
+            omitted_relations: vec![],
             primary_keys: vec![PrimaryKey {
                 name: "id".to_owned(),
             }],
+            unique_keys: vec![],
         };
 
         assert_eq!(cake_entity.get_table_name_snake_case(), "cake");
@@ -2491,6 +2862,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 true,
+                &[],
                 true,
             ))
         );
@@ -2509,6 +2881,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 true,
+                &[],
                 true,
             ))
         );
@@ -2527,6 +2900,94 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 true,
+                &[],
+                true,
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gen_with_seaography_duplicated_relations() -> io::Result<()> {
+        let id_column = |name: &str| Column {
+            name: name.to_owned(),
+            col_type: ColumnType::Integer,
+            auto_increment: false,
+            not_null: true,
+            unique: false,
+            unique_key: None,
+            generated: false,
+        };
+        let users_relation = |column: &str, num_suffix: usize| Relation {
+            ref_table: "users".to_owned(),
+            ref_schema: None,
+            columns: vec![column.to_owned()],
+            ref_columns: vec!["id".to_owned()],
+            rel_type: RelationType::BelongsTo,
+            on_delete: None,
+            on_update: None,
+            self_referencing: false,
+            num_suffix,
+            impl_related: true,
+        };
+        let messages_entity = Entity {
+            table_name: "messages".to_owned(),
+            schema_name: None,
+            columns: vec![
+                Column {
+                    auto_increment: true,
+                    ..id_column("id")
+                },
+                id_column("sender_id"),
+                id_column("recipient_id"),
+            ],
+            relations: vec![
+                users_relation("sender_id", 1),
+                users_relation("recipient_id", 2),
+            ],
+            conjunct_relations: vec![],
+            omitted_relations: vec![],
+            primary_keys: vec![PrimaryKey {
+                name: "id".to_owned(),
+            }],
+            unique_keys: vec![],
+        };
+
+        assert_eq!(
+            comparable_file_string(include_str!("../../tests/with_seaography/messages.rs"))?,
+            generated_to_string(EntityWriter::gen_compact_code_blocks(
+                &messages_entity,
+                &WithSerde::None,
+                &default_column_option(),
+                &None,
+                false,
+                false,
+                &TokenStream::new(),
+                &TokenStream::new(),
+                &TokenStream::new(),
+                true,
+                &[],
+                true,
+            ))
+        );
+
+        assert_eq!(
+            comparable_file_string(include_str!(
+                "../../tests/with_seaography/messages_skip_relations.rs"
+            ))?,
+            generated_to_string(EntityWriter::gen_compact_code_blocks(
+                &messages_entity,
+                &WithSerde::None,
+                &default_column_option(),
+                &None,
+                false,
+                false,
+                &TokenStream::new(),
+                &TokenStream::new(),
+                &TokenStream::new(),
+                true,
+                &["UsersRecipientId".to_owned()],
                 true,
             ))
         );
@@ -2610,6 +3071,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2626,6 +3088,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2644,6 +3107,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2664,6 +3128,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2682,6 +3147,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2700,6 +3166,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2720,6 +3187,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2738,6 +3206,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2756,6 +3225,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2819,6 +3289,7 @@ mod tests {
                 &TokenStream::new(),
                 &bonus_derive(["async_graphql::Enum"]),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2837,6 +3308,7 @@ mod tests {
                 &TokenStream::new(),
                 &bonus_derive(["async_graphql::Enum", "Eq", "PartialEq"]),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2860,6 +3332,7 @@ mod tests {
                 &TokenStream,
                 &TokenStream,
                 bool,
+                &[String],
                 bool,
             ) -> Vec<TokenStream>,
         >,
@@ -2893,6 +3366,7 @@ mod tests {
             &TokenStream::new(),
             &TokenStream::new(),
             false,
+            &[],
             true,
         )
         .into_iter()
@@ -2927,6 +3401,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2945,6 +3420,7 @@ mod tests {
                 &bonus_attributes([r#"serde(rename_all = "camelCase")"#]),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2963,6 +3439,7 @@ mod tests {
                 &bonus_attributes([r#"serde(rename_all = "camelCase")"#, "ts(export)"]),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -2983,6 +3460,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -3001,6 +3479,7 @@ mod tests {
                 &bonus_attributes([r#"serde(rename_all = "camelCase")"#]),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -3019,6 +3498,7 @@ mod tests {
                 &bonus_attributes([r#"serde(rename_all = "camelCase")"#, "ts(export)"]),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -3039,6 +3519,7 @@ mod tests {
                 &TokenStream::new(),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -3057,6 +3538,7 @@ mod tests {
                 &bonus_attributes([r#"serde(rename_all = "camelCase")"#]),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -3075,6 +3557,7 @@ mod tests {
                 &bonus_attributes([r#"serde(rename_all = "camelCase")"#, "ts(export)"]),
                 &TokenStream::new(),
                 false,
+                &[],
                 true,
             ))
         );
@@ -3119,6 +3602,7 @@ mod tests {
             // https://github.com/SeaQL/sea-orm/issues/1344
             Entity {
                 table_name: "task".to_owned(),
+                schema_name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -3127,6 +3611,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "payload".to_owned(),
@@ -3135,6 +3620,7 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                     Column {
                         name: "payload_binary".to_owned(),
@@ -3143,13 +3629,16 @@ mod tests {
                         not_null: true,
                         unique: false,
                         unique_key: None,
+                        generated: false,
                     },
                 ],
                 relations: vec![],
                 conjunct_relations: vec![],
+                omitted_relations: vec![],
                 primary_keys: vec![PrimaryKey {
                     name: "id".to_owned(),
                 }],
+                unique_keys: vec![],
             },
         ];
         const ENTITY_FILES: [&str; 1] = [include_str!("../../tests/postgres/binary_json.rs")];
@@ -3173,6 +3662,7 @@ mod tests {
                     &TokenStream::new(),
                     &TokenStream::new(),
                     false,
+                    &[],
                     true,
                 )
                 .into_iter()
@@ -3196,6 +3686,193 @@ mod tests {
                     &TokenStream::new(),
                     &TokenStream::new(),
                     false,
+                    &[],
+                    true,
+                )
+                .into_iter()
+                .skip(1)
+                .fold(TokenStream::new(), |mut acc, tok| {
+                    acc.extend(tok);
+                    acc
+                })
+                .to_string()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gen_sqlite_strict() -> io::Result<()> {
+        use crate::EntityTransformer;
+        use sea_query::{ColumnDef, Expr, Index, Table};
+
+        // Mirrors what the SQLite schema discovery yields for:
+        //
+        // CREATE TABLE measurement (
+        //     id INTEGER NOT NULL PRIMARY KEY,
+        //     width REAL NOT NULL,
+        //     height REAL NOT NULL,
+        //     label TEXT,
+        //     raw BLOB,
+        //     reading ANY,
+        //     area REAL NOT NULL GENERATED ALWAYS AS (width * height) VIRTUAL
+        // ) STRICT;
+        let table = Table::create()
+            .table("measurement")
+            .col(ColumnDef::new_with_type("id", ColumnType::BigInteger).not_null())
+            .col(ColumnDef::new_with_type("width", ColumnType::Double).not_null())
+            .col(ColumnDef::new_with_type("height", ColumnType::Double).not_null())
+            .col(ColumnDef::new_with_type("label", ColumnType::Text))
+            .col(ColumnDef::new_with_type("raw", ColumnType::Blob))
+            .col(ColumnDef::new_with_type(
+                "reading",
+                ColumnType::custom("ANY"),
+            ))
+            .col(
+                ColumnDef::new_with_type("area", ColumnType::Double)
+                    .not_null()
+                    .generated(Expr::cust(""), false),
+            )
+            .primary_key(Index::create().col("id"))
+            .to_owned();
+        let entities = EntityTransformer::transform(vec![table]).unwrap().entities;
+
+        const ENTITY_FILES: [&str; 1] = [include_str!("../../tests/sqlite/strict.rs")];
+
+        const ENTITY_FILES_EXPANDED: [&str; 1] =
+            [include_str!("../../tests/sqlite/strict_expanded.rs")];
+
+        assert_eq!(entities.len(), ENTITY_FILES.len());
+
+        for (i, entity) in entities.iter().enumerate() {
+            assert_eq!(
+                parse_from_file(ENTITY_FILES[i].as_bytes())?.to_string(),
+                EntityWriter::gen_compact_code_blocks(
+                    entity,
+                    &crate::WithSerde::None,
+                    &default_column_option(),
+                    &None,
+                    false,
+                    false,
+                    &TokenStream::new(),
+                    &TokenStream::new(),
+                    &TokenStream::new(),
+                    false,
+                    &[],
+                    true,
+                )
+                .into_iter()
+                .skip(1)
+                .fold(TokenStream::new(), |mut acc, tok| {
+                    acc.extend(tok);
+                    acc
+                })
+                .to_string()
+            );
+            assert_eq!(
+                parse_from_file(ENTITY_FILES_EXPANDED[i].as_bytes())?.to_string(),
+                EntityWriter::gen_expanded_code_blocks(
+                    entity,
+                    &crate::WithSerde::None,
+                    &default_column_option(),
+                    &None,
+                    false,
+                    false,
+                    &TokenStream::new(),
+                    &TokenStream::new(),
+                    &TokenStream::new(),
+                    false,
+                    &[],
+                    true,
+                )
+                .into_iter()
+                .skip(1)
+                .fold(TokenStream::new(), |mut acc, tok| {
+                    acc.extend(tok);
+                    acc
+                })
+                .to_string()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gen_postgres_custom_type() -> io::Result<()> {
+        use crate::EntityTransformer;
+        use sea_query::{ColumnDef, Index, Table};
+
+        // Mirrors what the Postgres schema discovery yields for:
+        //
+        // CREATE TABLE collection (
+        //     id SERIAL PRIMARY KEY,
+        //     name CITEXT NOT NULL,
+        //     path LTREE
+        // );
+        let table = Table::create()
+            .table("collection")
+            .col(
+                ColumnDef::new_with_type("id", ColumnType::Integer)
+                    .not_null()
+                    .auto_increment(),
+            )
+            .col(ColumnDef::new_with_type("name", ColumnType::custom("citext")).not_null())
+            .col(ColumnDef::new_with_type(
+                "path",
+                ColumnType::custom("ltree"),
+            ))
+            .primary_key(Index::create().col("id"))
+            .to_owned();
+        let entities = EntityTransformer::transform(vec![table]).unwrap().entities;
+
+        const ENTITY_FILES: [&str; 1] = [include_str!("../../tests/postgres/custom_type.rs")];
+
+        const ENTITY_FILES_EXPANDED: [&str; 1] =
+            [include_str!("../../tests/postgres/custom_type_expanded.rs")];
+
+        assert_eq!(entities.len(), ENTITY_FILES.len());
+
+        for (i, entity) in entities.iter().enumerate() {
+            assert_eq!(
+                parse_from_file(ENTITY_FILES[i].as_bytes())?.to_string(),
+                EntityWriter::gen_compact_code_blocks(
+                    entity,
+                    &crate::WithSerde::None,
+                    &default_column_option(),
+                    &None,
+                    false,
+                    false,
+                    &TokenStream::new(),
+                    &TokenStream::new(),
+                    &TokenStream::new(),
+                    false,
+                    &[],
+                    true,
+                )
+                .into_iter()
+                .skip(1)
+                .fold(TokenStream::new(), |mut acc, tok| {
+                    acc.extend(tok);
+                    acc
+                })
+                .to_string()
+            );
+            assert_eq!(
+                parse_from_file(ENTITY_FILES_EXPANDED[i].as_bytes())?.to_string(),
+                EntityWriter::gen_expanded_code_blocks(
+                    entity,
+                    &crate::WithSerde::None,
+                    &default_column_option(),
+                    &None,
+                    false,
+                    false,
+                    &TokenStream::new(),
+                    &TokenStream::new(),
+                    &TokenStream::new(),
+                    false,
+                    &[],
                     true,
                 )
                 .into_iter()
@@ -3247,6 +3924,7 @@ mod tests {
                     &TokenStream::new(),
                     &TokenStream::new(),
                     false,
+                    &[],
                     true,
                 )
                 .into_iter()
@@ -13,6 +13,7 @@ impl EntityWriter {
         model_extra_attributes: &TokenStream,
         _column_extra_derives: &TokenStream,
         seaography: bool,
+        seaography_skip_relations: &[String],
         impl_active_model_behavior: bool,
     ) -> Vec<TokenStream> {
         let mut imports = Self::gen_import(with_serde);
@@ -39,7 +40,7 @@ impl EntityWriter {
             code_blocks.extend([Self::impl_active_model_behavior()]);
         }
         if seaography {
-            code_blocks.extend([Self::gen_related_entity(entity)]);
+            code_blocks.extend([Self::gen_related_entity(entity, seaography_skip_relations)]);
         }
         code_blocks
     }
@@ -96,6 +97,9 @@ impl EntityWriter {
                 } else if let Some(unique_key) = &col.unique_key {
                     attrs.push(quote! { unique_key = #unique_key });
                 }
+                if col.generated {
+                    attrs.push(quote! { generated });
+                }
                 let mut ts = quote! {};
                 if !attrs.is_empty() {
                     for (i, attr) in attrs.into_iter().enumerate() {
@@ -125,12 +129,14 @@ impl EntityWriter {
             None => quote! {},
         };
         let extra_derive = with_serde.extra_derive();
+        let unique_keys = entity.get_unique_key_attrs();
 
         quote! {
             #[derive(Clone, Debug, PartialEq #if_eq_needed, DeriveEntityModel #extra_derive #model_extra_derives)]
             #[sea_orm(
                 #schema_name
                 table_name = #table_name
+                #(, #unique_keys)*
             )]
             #model_extra_attributes
             pub struct Model {
@@ -15,6 +15,7 @@ impl EntityWriter {
         model_extra_attributes: &TokenStream,
         _column_extra_derives: &TokenStream,
         _seaography: bool,
+        _seaography_skip_relations: &[String],
         impl_active_model_behavior: bool,
     ) -> Vec<TokenStream> {
         let mut imports = Self::gen_import(with_serde);
@@ -92,6 +93,9 @@ impl EntityWriter {
                 } else if let Some(unique_key) = &col.unique_key {
                     attrs.push(quote! { unique_key = #unique_key });
                 }
+                if col.generated {
+                    attrs.push(quote! { generated });
+                }
                 let mut ts = quote! {};
                 if !attrs.is_empty() {
                     for (i, attr) in attrs.into_iter().enumerate() {
@@ -121,6 +125,7 @@ impl EntityWriter {
             None => quote! {},
         };
         let extra_derive = with_serde.extra_derive();
+        let unique_keys = entity.get_unique_key_attrs();
 
         let mut compound_objects: Punctuated<_, Comma> = Punctuated::new();
 
@@ -195,9 +200,10 @@ impl EntityWriter {
                     } else {
                         format_ident!("{field}_{}", rel.num_suffix)
                     };
+                    let module_path = rel.get_module_path();
                     compound_objects.push(quote! {
                         #sea_orm_attr
-                        pub #field: #rel_type<super::#to_entity::Entity>
+                        pub #field: #rel_type<#module_path::Entity>
                     });
                 }
             } else if rel.self_referencing {
@@ -257,6 +263,7 @@ impl EntityWriter {
             #[sea_orm(
                 #schema_name
                 table_name = #table_name
+                #(, #unique_keys)*
             )]
             #model_extra_attributes
             pub struct Model {
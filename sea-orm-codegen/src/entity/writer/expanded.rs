@@ -13,6 +13,7 @@ impl EntityWriter {
         model_extra_attributes: &TokenStream,
         column_extra_derives: &TokenStream,
         seaography: bool,
+        seaography_skip_relations: &[String],
         impl_active_model_behavior: bool,
     ) -> Vec<TokenStream> {
         let mut imports = Self::gen_import(with_serde);
@@ -20,7 +21,7 @@ impl EntityWriter {
         imports.extend(active_enums.imports);
         let mut code_blocks = vec![
             imports,
-            Self::gen_entity_struct(),
+            Self::gen_entity_struct(entity),
             Self::gen_impl_entity_name(entity, schema_name),
             Self::gen_expanded_model_struct(
                 entity,
@@ -45,7 +46,7 @@ impl EntityWriter {
             code_blocks.extend([Self::impl_active_model_behavior()]);
         }
         if seaography {
-            code_blocks.extend([Self::gen_related_entity(entity)]);
+            code_blocks.extend([Self::gen_related_entity(entity, seaography_skip_relations)]);
         }
         code_blocks
     }
@@ -49,6 +49,7 @@ impl EntityWriter {
         model_extra_attributes: &TokenStream,
         _column_extra_derives: &TokenStream,
         _seaography: bool,
+        _seaography_skip_relations: &[String],
         _impl_active_model_behavior: bool,
     ) -> Vec<TokenStream> {
         let mut imports = Self::gen_import_serde(with_serde);
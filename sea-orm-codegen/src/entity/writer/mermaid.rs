@@ -50,7 +50,12 @@ fn write_entity_block(out: &mut String, entity: &Entity, pks: &HashSet<&str>, fk
         let type_name = col_type_name(&col.col_type);
         let is_pk = pks.contains(col.name.as_str());
         let is_fk = fks.contains(col.name.as_str());
-        let is_uk = col.unique || col.unique_key.is_some();
+        let is_uk = col.unique
+            || col.unique_key.is_some()
+            || entity
+                .unique_keys
+                .iter()
+                .any(|key| key.columns.contains(&col.name));
 
         let constraint = match (is_pk, is_fk, is_uk) {
             (true, true, _) => " PK,FK",
@@ -160,6 +165,7 @@ mod tests {
             entities: vec![
                 Entity {
                     table_name: "user".to_owned(),
+                    schema_name: None,
                     columns: vec![
                         Column {
                             name: "id".to_owned(),
@@ -168,6 +174,7 @@ mod tests {
                             not_null: true,
                             unique: false,
                             unique_key: None,
+                            generated: false,
                         },
                         Column {
                             name: "name".to_owned(),
@@ -176,6 +183,7 @@ mod tests {
                             not_null: true,
                             unique: false,
                             unique_key: None,
+                            generated: false,
                         },
                         Column {
                             name: "email".to_owned(),
@@ -184,6 +192,7 @@ mod tests {
                             not_null: true,
                             unique: true,
                             unique_key: None,
+                            generated: false,
                         },
                         Column {
                             name: "parent_id".to_owned(),
@@ -192,11 +201,13 @@ mod tests {
                             not_null: false,
                             unique: false,
                             unique_key: None,
+                            generated: false,
                         },
                     ],
                     relations: vec![
                         Relation {
                             ref_table: "post".to_owned(),
+                            ref_schema: None,
                             columns: vec![],
                             ref_columns: vec![],
                             rel_type: RelationType::HasMany,
@@ -208,6 +219,7 @@ mod tests {
                         },
                         Relation {
                             ref_table: "user".to_owned(),
+                            ref_schema: None,
                             columns: vec!["parent_id".to_owned()],
                             ref_columns: vec!["id".to_owned()],
                             rel_type: RelationType::BelongsTo,
@@ -219,12 +231,15 @@ mod tests {
                         },
                     ],
                     conjunct_relations: vec![],
+                    omitted_relations: vec![],
                     primary_keys: vec![PrimaryKey {
                         name: "id".to_owned(),
                     }],
+                    unique_keys: vec![],
                 },
                 Entity {
                     table_name: "post".to_owned(),
+                    schema_name: None,
                     columns: vec![
                         Column {
                             name: "id".to_owned(),
@@ -233,6 +248,7 @@ mod tests {
                             not_null: true,
                             unique: false,
                             unique_key: None,
+                            generated: false,
                         },
                         Column {
                             name: "title".to_owned(),
@@ -241,6 +257,7 @@ mod tests {
                             not_null: true,
                             unique: false,
                             unique_key: None,
+                            generated: false,
                         },
                         Column {
                             name: "user_id".to_owned(),
@@ -249,10 +266,12 @@ mod tests {
                             not_null: true,
                             unique: false,
                             unique_key: None,
+                            generated: false,
                         },
                     ],
                     relations: vec![Relation {
                         ref_table: "user".to_owned(),
+                        ref_schema: None,
                         columns: vec!["user_id".to_owned()],
                         ref_columns: vec!["id".to_owned()],
                         rel_type: RelationType::BelongsTo,
@@ -266,12 +285,15 @@ mod tests {
                         via: "post_tag".to_owned(),
                         to: "tag".to_owned(),
                     }],
+                    omitted_relations: vec![],
                     primary_keys: vec![PrimaryKey {
                         name: "id".to_owned(),
                     }],
+                    unique_keys: vec![],
                 },
                 Entity {
                     table_name: "tag".to_owned(),
+                    schema_name: None,
                     columns: vec![
                         Column {
                             name: "id".to_owned(),
@@ -280,6 +302,7 @@ mod tests {
                             not_null: true,
                             unique: false,
                             unique_key: None,
+                            generated: false,
                         },
                         Column {
                             name: "name".to_owned(),
@@ -288,6 +311,7 @@ mod tests {
                             not_null: true,
                             unique: true,
                             unique_key: None,
+                            generated: false,
                         },
                     ],
                     relations: vec![],
@@ -295,12 +319,15 @@ mod tests {
                         via: "post_tag".to_owned(),
                         to: "post".to_owned(),
                     }],
+                    omitted_relations: vec![],
                     primary_keys: vec![PrimaryKey {
                         name: "id".to_owned(),
                     }],
+                    unique_keys: vec![],
                 },
                 Entity {
                     table_name: "post_tag".to_owned(),
+                    schema_name: None,
                     columns: vec![
                         Column {
                             name: "post_id".to_owned(),
@@ -309,6 +336,7 @@ mod tests {
                             not_null: true,
                             unique: false,
                             unique_key: None,
+                            generated: false,
                         },
                         Column {
                             name: "tag_id".to_owned(),
@@ -317,11 +345,13 @@ mod tests {
                             not_null: true,
                             unique: false,
                             unique_key: None,
+                            generated: false,
                         },
                     ],
                     relations: vec![
                         Relation {
                             ref_table: "post".to_owned(),
+                            ref_schema: None,
                             columns: vec!["post_id".to_owned()],
                             ref_columns: vec!["id".to_owned()],
                             rel_type: RelationType::BelongsTo,
@@ -333,6 +363,7 @@ mod tests {
                         },
                         Relation {
                             ref_table: "tag".to_owned(),
+                            ref_schema: None,
                             columns: vec!["tag_id".to_owned()],
                             ref_columns: vec!["id".to_owned()],
                             rel_type: RelationType::BelongsTo,
@@ -344,6 +375,7 @@ mod tests {
                         },
                     ],
                     conjunct_relations: vec![],
+                    omitted_relations: vec![],
                     primary_keys: vec![
                         PrimaryKey {
                             name: "post_id".to_owned(),
@@ -352,6 +384,7 @@ mod tests {
                             name: "tag_id".to_owned(),
                         },
                     ],
+                    unique_keys: vec![],
                 },
             ],
             enums: BTreeMap::new(),
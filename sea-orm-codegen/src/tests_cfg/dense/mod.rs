@@ -1 +1,2 @@
 pub mod indexes;
+pub mod unique_keys;
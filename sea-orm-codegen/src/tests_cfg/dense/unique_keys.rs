@@ -0,0 +1,26 @@
+//! An entity definition for testing unique constraint generation.
+use sea_orm::entity::prelude::*;
+
+#[sea_orm::model]
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(
+    table_name = "unique_keys",
+    index(
+        name = "unique_keys_author_year_key",
+        columns("author", "year"),
+        unique
+    )
+)]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(unique)]
+    pub slug: String,
+    #[sea_orm(unique_key = "title_author")]
+    pub title: String,
+    #[sea_orm(unique_key = "title_author")]
+    pub author: String,
+    pub year: i32,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -16,28 +16,28 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(
         belongs_to = "super::bills::Entity",
-        from = "Column::BillIdd",
+        from = "Column::BillId",
         to = "super::bills::Column::Id",
     )]
-    Bills2,
+    Bills1,
     #[sea_orm(
         belongs_to = "super::bills::Entity",
-        from = "Column::BillId",
+        from = "Column::BillIdd",
         to = "super::bills::Column::Id",
     )]
-    Bills1,
+    Bills2,
     #[sea_orm(
         belongs_to = "super::users::Entity",
-        from = "Column::UserIdd",
+        from = "Column::UserId",
         to = "super::users::Column::Id",
     )]
-    Users2,
+    Users1,
     #[sea_orm(
         belongs_to = "super::users::Entity",
-        from = "Column::UserId",
+        from = "Column::UserIdd",
         to = "super::users::Column::Id",
     )]
-    Users1,
+    Users2,
 }
 
 impl ActiveModelBehavior for ActiveModel {}
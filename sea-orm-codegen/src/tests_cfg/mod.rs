@@ -5,4 +5,5 @@ pub mod dense;
 pub mod duplicated_many_to_many_paths;
 pub mod many_to_many;
 pub mod many_to_many_multiple;
+pub mod multi_schema;
 pub mod self_referencing;
@@ -0,0 +1,3 @@
+pub mod prelude;
+
+pub mod log;
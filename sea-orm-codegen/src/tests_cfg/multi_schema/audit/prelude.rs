@@ -0,0 +1 @@
+pub use super::log::Entity as Log;
@@ -0,0 +1,44 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(schema_name = "billing", table_name = "invoice")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub customer_id: i32,
+    pub log_id: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::customer::Entity",
+        from = "Column::CustomerId",
+        to = "super::customer::Column::Id",
+        on_update = "NoAction",
+        on_delete = "NoAction",
+    )]
+    Customer,
+    #[sea_orm(
+        belongs_to = "super::super::audit::log::Entity",
+        from = "Column::LogId",
+        to = "super::super::audit::log::Column::Id",
+        on_update = "NoAction",
+        on_delete = "NoAction",
+    )]
+    Log,
+}
+
+impl Related<super::customer::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Customer.def()
+    }
+}
+
+impl Related<super::super::audit::log::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Log.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,4 @@
+pub mod prelude;
+
+pub mod customer;
+pub mod invoice;
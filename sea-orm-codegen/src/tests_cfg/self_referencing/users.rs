@@ -16,13 +16,13 @@ pub enum Relation {
         from = "Column::SelfId",
         to = "Column::Id",
     )]
-    SelfRef2,
+    SelfRef1,
     #[sea_orm(
         belongs_to = "Entity",
         from = "Column::SelfIdd",
         to = "Column::Id",
     )]
-    SelfRef1,
+    SelfRef2,
 }
 
 impl ActiveModelBehavior for ActiveModel {}
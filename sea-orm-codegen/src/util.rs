@@ -1,3 +1,5 @@
+use sea_query::{TableName, TableRef};
+
 pub(crate) fn escape_rust_keyword<T>(string: T) -> String
 where
     T: ToString,
@@ -12,6 +14,45 @@ where
     }
 }
 
+/// Schema of a schema-qualified table
+pub(crate) fn get_table_schema(table_ref: &TableRef) -> Option<String> {
+    match table_ref {
+        TableRef::Table(TableName(Some(schema), _), _) => Some(schema.1.to_string()),
+        _ => None,
+    }
+}
+
+/// Match a name against a glob pattern, where `*` matches any sequence of characters
+/// and `?` matches a single character
+pub(crate) fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // Position of the last `*` in the pattern, and the name position it is matched up to
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 pub(crate) const RUST_KEYWORDS: [&str; 49] = [
     "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false",
     "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
@@ -15,6 +15,7 @@ struct ActiveEnum {
     variant_idents: Vec<syn::Ident>,
     variant_values: Vec<TokenStream>,
     rename_all: Option<CaseStyle>,
+    display_from_str: bool,
 }
 
 enum RsType {
@@ -131,9 +132,11 @@ impl ActiveEnum {
         let ident = input.ident;
 
         let mut enum_name = ident.to_string().to_upper_camel_case();
+        let mut schema_name = None;
         let mut rs_type = None;
         let mut db_type = None;
         let mut rename_all = None;
+        let mut display_from_str = false;
 
         input
             .attrs
@@ -150,8 +153,13 @@ impl ActiveEnum {
                     } else if meta.path.is_ident("enum_name") {
                         let litstr: LitStr = meta.value()?.parse()?;
                         enum_name = litstr.value();
+                    } else if meta.path.is_ident("schema_name") {
+                        let litstr: LitStr = meta.value()?.parse()?;
+                        schema_name = Some(litstr.value());
                     } else if meta.path.is_ident("rename_all") {
                         rename_all = Some((&meta).try_into()?);
+                    } else if meta.path.is_ident("display_from_str") {
+                        display_from_str = true;
                     } else {
                         return Err(meta.error(format!(
                             "Unknown attribute parameter found: {:?}",
@@ -166,6 +174,16 @@ impl ActiveEnum {
         let db_type = DbType::from_attr(ident_span, db_type)?;
         let rs_type = RsType::from_attr(ident_span, rs_type, &db_type)?;
 
+        if let Some(schema_name) = schema_name {
+            if !db_type.is_enum() {
+                return Err(Error::TT(quote_spanned! {
+                    ident_span => compile_error!("`schema_name` requires `db_type = \"Enum\"`");
+                }));
+            }
+            // qualified wherever the type is named; split again when rendering casts
+            enum_name = format!("{schema_name}.{enum_name}");
+        }
+
         let variant_vec = match input.data {
             syn::Data::Enum(syn::DataEnum { variants, .. }) => variants,
             _ => return Err(Error::InputNotEnum),
@@ -270,6 +288,12 @@ impl ActiveEnum {
             }));
         }
 
+        if display_from_str && !is_string {
+            return Err(Error::TT(quote_spanned! {
+                ident_span => compile_error!("`display_from_str` requires string values, specify `string_value`, `rename` or `rename_all`");
+            }));
+        }
+
         let variant_idents: Vec<syn::Ident> = variants
             .iter()
             .map(|variant| variant.ident.clone())
@@ -306,6 +330,7 @@ impl ActiveEnum {
             variant_idents,
             variant_values,
             rename_all,
+            display_from_str,
         })
     }
 
@@ -587,6 +612,43 @@ impl ActiveEnum {
         }
     }
 
+    fn display_from_str_impl(&self) -> TokenStream {
+        if !self.display_from_str {
+            return quote!();
+        }
+
+        let ident = &self.ident;
+        let variant_idents = &self.variant_idents;
+        let variant_values = &self.variant_values;
+
+        quote! {
+            #[automatically_derived]
+            impl std::fmt::Display for #ident {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(match self {
+                        #( Self::#variant_idents => #variant_values, )*
+                    })
+                }
+            }
+
+            #[automatically_derived]
+            impl std::str::FromStr for #ident {
+                type Err = sea_orm::ParseActiveEnumError;
+
+                fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                    match s {
+                        #( #variant_values => Ok(Self::#variant_idents), )*
+                        _ => Err(sea_orm::ParseActiveEnumError {
+                            enum_name: stringify!(#ident),
+                            value: s.to_owned(),
+                            variants: &[#( #variant_values ),*],
+                        }),
+                    }
+                }
+            }
+        }
+    }
+
     fn sqlx_postgres_impl(&self) -> TokenStream {
         if !cfg!(feature = "sqlx-postgres") || !self.db_type.is_enum() {
             return quote!();
@@ -756,6 +818,7 @@ impl ActiveEnum {
         let active_enum_impl = self.active_enum_impl();
         let try_getable_impl = self.try_getable_impl();
         let sqlx_postgres_impl = self.sqlx_postgres_impl();
+        let display_from_str_impl = self.display_from_str_impl();
 
         quote!(
             #[doc = " Generated by sea-orm-macros"]
@@ -781,6 +844,8 @@ impl ActiveEnum {
 
             #sqlx_postgres_impl
 
+            #display_from_str_impl
+
             #value_type_impl
 
             #nullable_impl
@@ -1,4 +1,6 @@
 use super::case_style::CaseStyle;
+use super::model::impl_redacted_fmt;
+use super::util::field_redacted;
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote, quote_spanned};
 use syn::{LitInt, LitStr};
@@ -95,13 +97,31 @@ impl Display {
     }
 }
 
+/// `Display` for a struct, formatted like `Debug` but hiding `#[sea_orm(redact)]` fields
+fn expand_struct_display(ident: &syn::Ident, fields: &syn::FieldsNamed) -> TokenStream {
+    let fields: Vec<_> = fields
+        .named
+        .iter()
+        .map(|field| (field.ident.clone().unwrap(), field_redacted(field)))
+        .collect();
+    impl_redacted_fmt(quote!(std::fmt::Display), ident, &fields)
+}
+
 pub fn expand_derive_active_enum_display(input: syn::DeriveInput) -> syn::Result<TokenStream> {
     let ident_span = input.ident.span();
 
+    if let syn::Data::Struct(syn::DataStruct {
+        fields: syn::Fields::Named(fields),
+        ..
+    }) = &input.data
+    {
+        return Ok(expand_struct_display(&input.ident, fields));
+    }
+
     match Display::new(input) {
         Ok(model) => model.expand(),
         Err(Error::InputNotEnum) => Ok(quote_spanned! {
-            ident_span => compile_error!("you can only derive EnumDisplay on enums");
+            ident_span => compile_error!("you can only derive DeriveDisplay on enums and structs with named fields");
         }),
         Err(Error::Syn(e)) => Err(e),
     }
@@ -1,5 +1,6 @@
 use super::util::{
-    escape_rust_keyword, field_not_ignored, format_field_ident, trim_starting_raw_identifier,
+    escape_rust_keyword, field_not_ignored, field_redacted, format_field_ident,
+    trim_starting_raw_identifier,
 };
 use heck::ToUpperCamelCase;
 use proc_macro2::{Ident, TokenStream};
@@ -12,6 +13,7 @@ pub(crate) struct DeriveActiveModel {
     fields: Vec<Ident>,
     names: Vec<Ident>,
     types: Vec<Type>,
+    redacted: Vec<bool>,
 }
 
 impl DeriveActiveModel {
@@ -32,9 +34,11 @@ impl DeriveActiveModel {
         let mut fields = Vec::new();
         let mut names = Vec::new();
         let mut types = Vec::new();
+        let mut redacted = Vec::new();
 
         for field in all_fields.iter().filter(|f| field_not_ignored(f)) {
             fields.push(format_field_ident(field));
+            redacted.push(field_redacted(field));
 
             let ident = field.ident.as_ref().unwrap().to_string();
             let ident = trim_starting_raw_identifier(ident).to_upper_camel_case();
@@ -70,6 +74,7 @@ impl DeriveActiveModel {
             fields,
             names,
             types,
+            redacted,
         })
     }
 }
@@ -79,15 +84,54 @@ impl DeriveActiveModel {
         let vis = &self.vis;
         let fields = &self.fields;
         let types = &self.types;
+        let (derive_debug, impl_debug) = if self.redacted.contains(&true) {
+            (quote!(), self.impl_redacted_debug())
+        } else {
+            (quote!(Debug,), quote!())
+        };
         quote!(
             #[doc = " Generated by sea-orm-macros"]
-            #[derive(Clone, Debug, PartialEq)]
+            #[derive(Clone, #derive_debug PartialEq)]
             #vis struct ActiveModel {
                 #(
                     #[doc = " Generated by sea-orm-macros"]
                     pub #fields: sea_orm::ActiveValue<#types>
                 ),*
             }
+
+            #impl_debug
+        )
+    }
+
+    /// `Debug` printing e.g. `Set(<redacted>)` for redacted fields
+    fn impl_redacted_debug(&self) -> TokenStream {
+        let fields = self
+            .fields
+            .iter()
+            .zip(&self.redacted)
+            .map(|(field, redacted)| {
+                let field_name = trim_starting_raw_identifier(field);
+                if *redacted {
+                    quote!(.field(#field_name, &format_args!("{}", match &self.#field {
+                        sea_orm::ActiveValue::Set(_) => "Set(<redacted>)",
+                        sea_orm::ActiveValue::Unchanged(_) => "Unchanged(<redacted>)",
+                        sea_orm::ActiveValue::NotSet => "NotSet",
+                        sea_orm::ActiveValue::SetDefault => "SetDefault",
+                    })))
+                } else {
+                    quote!(.field(#field_name, &self.#field))
+                }
+            });
+
+        quote!(
+            #[automatically_derived]
+            impl std::fmt::Debug for ActiveModel {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.debug_struct("ActiveModel")
+                        #(#fields)*
+                        .finish()
+                }
+            }
         )
     }
 
@@ -261,7 +305,7 @@ fn derive_into_model(ident: &Ident, data: &Data) -> syn::Result<TokenStream> {
         impl std::convert::TryFrom<ActiveModel> for #ident {
             type Error = sea_orm::DbErr;
             fn try_from(a: ActiveModel) -> Result<Self, sea_orm::DbErr> {
-                #(if a.#active_model_field.is_not_set() {
+                #(if a.#active_model_field.try_as_ref().is_none() {
                     return Err(sea_orm::DbErr::AttrNotSet(stringify!(#active_model_field).to_owned()));
                 })*
                 Ok(
@@ -35,6 +35,10 @@ pub fn expand_derive_active_model_ex(
             attr.parse_nested_meta(|meta| {
                 if meta.path.is_ident("compact_model") {
                     compact = true;
+                } else if meta.path.is_ident("index") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let _: TokenStream = content.parse()?;
                 } else {
                     // Reads the value expression to advance the parse stream.
                     let _: Option<Expr> = meta.value().and_then(|v| v.parse()).ok();
@@ -268,7 +272,7 @@ pub fn expand_derive_active_model_ex(
         impl std::convert::TryFrom<ActiveModelEx> for ModelEx {
             type Error = sea_orm::DbErr;
             fn try_from(a: ActiveModelEx) -> Result<Self, sea_orm::DbErr> {
-                #(if a.#scalar_fields.is_not_set() {
+                #(if a.#scalar_fields.try_as_ref().is_none() {
                     return Err(sea_orm::DbErr::AttrNotSet(stringify!(#scalar_fields).to_owned()));
                 })*
                 Ok(
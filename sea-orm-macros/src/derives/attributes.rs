@@ -77,6 +77,8 @@ pub mod value_type_attr {
         pub from_str: Option<syn::LitStr>,
         pub to_str: Option<syn::LitStr>,
         pub try_from_u64: Option<()>,
+        pub select_expr: Option<syn::LitStr>,
+        pub save_expr: Option<syn::LitStr>,
     }
 }
 
@@ -117,12 +117,21 @@ pub fn impl_col_from_str(ident: &Ident, data: &Data) -> syn::Result<TokenStream>
                     Ok(())
                 })?;
             }
-            Ok::<TokenStream, syn::Error>(quote!(
-                #column_str_snake | #column_str_mixed | #column_name => Ok(#ident::#column_iden)
+            Ok::<_, syn::Error>((
+                quote!(
+                    #column_str_snake | #column_str_mixed | #column_name => Ok(#ident::#column_iden)
+                ),
+                column_iden,
+                column_name,
             ))
         })
         .collect::<Result<Vec<_>, _>>()?;
 
+    let variant_strs = columns.iter().map(|(_, iden, _)| iden.to_string());
+    let variant_idens = columns.iter().map(|(_, iden, _)| iden);
+    let column_names = columns.iter().map(|(_, _, name)| name);
+    let columns = columns.iter().map(|(arm, _, _)| arm);
+
     Ok(quote!(
         #[automatically_derived]
         impl std::str::FromStr for #ident {
@@ -131,7 +140,14 @@ pub fn impl_col_from_str(ident: &Ident, data: &Data) -> syn::Result<TokenStream>
             fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
                 match s {
                     #(#columns),*,
-                    _ => Err(sea_orm::ColumnFromStrErr(s.to_owned())),
+                    _ => {
+                        #(
+                            if s.eq_ignore_ascii_case(#variant_strs) {
+                                return Ok(#ident::#variant_idens);
+                            }
+                        )*
+                        Err(sea_orm::ColumnFromStrErr(s.to_owned(), &[#(#column_names),*]))
+                    }
                 }
             }
         }
@@ -3,7 +3,51 @@ use std::iter::FromIterator;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-use super::{attributes::derive_attr, impl_iden_for_unit_struct};
+use super::{attributes::derive_attr, impl_iden_for_unit_struct, util::split_index_attrs};
+
+/// A struct-level `#[sea_orm(index(name = "..", columns("..", ..), unique))]`
+struct IndexAttr {
+    name: Option<syn::LitStr>,
+    columns: Vec<syn::LitStr>,
+    unique: bool,
+}
+
+impl IndexAttr {
+    fn parse(tokens: TokenStream) -> syn::Result<Self> {
+        let span = proc_macro2::Span::call_site();
+        let mut name = None;
+        let mut columns = Vec::new();
+        let mut unique = false;
+        let parser = syn::meta::parser(|meta| {
+            if meta.path.is_ident("name") {
+                name = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("columns") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let lits = content
+                    .parse_terminated(<syn::LitStr as syn::parse::Parse>::parse, syn::Token![,])?;
+                columns.extend(lits);
+            } else if meta.path.is_ident("unique") {
+                unique = true;
+            } else {
+                return Err(meta.error("expected `name`, `columns` or `unique`"));
+            }
+            Ok(())
+        });
+        syn::parse::Parser::parse2(parser, tokens)?;
+        if columns.is_empty() {
+            return Err(syn::Error::new(
+                span,
+                "`index` requires at least one column, e.g. `columns(\"a\", \"b\")`",
+            ));
+        }
+        Ok(Self {
+            name,
+            columns,
+            unique,
+        })
+    }
+}
 
 struct DeriveEntity {
     column_ident: syn::Ident,
@@ -16,11 +60,17 @@ struct DeriveEntity {
     relation_ident: syn::Ident,
     schema_name: Option<syn::LitStr>,
     table_name: Option<syn::LitStr>,
+    indexes: Vec<IndexAttr>,
 }
 
 impl DeriveEntity {
     fn new(input: syn::DeriveInput) -> Result<Self, syn::Error> {
-        let sea_attr = derive_attr::SeaOrm::try_from_attributes(&input.attrs)?.unwrap_or_default();
+        let (attrs, indexes) = split_index_attrs(&input.attrs);
+        let indexes = indexes
+            .into_iter()
+            .map(IndexAttr::parse)
+            .collect::<syn::Result<_>>()?;
+        let sea_attr = derive_attr::SeaOrm::try_from_attributes(&attrs)?.unwrap_or_default();
 
         let ident = input.ident;
         let column_ident = sea_attr.column.unwrap_or_else(|| format_ident!("Column"));
@@ -53,6 +103,7 @@ impl DeriveEntity {
             relation_ident,
             schema_name,
             table_name,
+            indexes,
         })
     }
 
@@ -110,6 +161,7 @@ impl DeriveEntity {
             relation_ident,
             ..
         } = self;
+        let expanded_indexes = self.impl_indexes();
 
         quote!(
             #[automatically_derived]
@@ -127,6 +179,42 @@ impl DeriveEntity {
                 type PrimaryKey = #primary_key_ident;
 
                 type Relation = #relation_ident;
+
+                #expanded_indexes
+            }
+        )
+    }
+
+    fn impl_indexes(&self) -> TokenStream {
+        if self.indexes.is_empty() {
+            return TokenStream::new();
+        }
+        let column_ident = &self.column_ident;
+        let indexes = self.indexes.iter().map(|index| {
+            let name = match &index.name {
+                Some(name) => quote!(#name.to_owned()),
+                None => {
+                    let columns: Vec<_> = index.columns.iter().map(|col| col.value()).collect();
+                    let suffix = columns.join("-");
+                    quote!(format!("idx-{}-{}", sea_orm::EntityName::table_name(&Self::default()), #suffix))
+                }
+            };
+            let columns = &index.columns;
+            let unique = index.unique.then(|| quote!(.unique()));
+            quote!({
+                let name: String = #name;
+                sea_orm::entity::IndexDef::new(&name)
+                    #(.col(
+                        <#column_ident as std::str::FromStr>::from_str(#columns)
+                            .unwrap_or_else(|e| panic!("Index `{name}`: {e}")),
+                    ))*
+                    #unique
+            })
+        });
+
+        quote!(
+            fn indexes() -> Vec<sea_orm::entity::IndexDef<Self::Column>> {
+                vec![#(#indexes),*]
             }
         )
     }
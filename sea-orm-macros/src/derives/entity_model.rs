@@ -74,6 +74,7 @@ pub fn expand_derive_entity_model(
     let mut model_ex = false;
     let mut rename_all: Option<CaseStyle> = None;
     let mut serde_rename_all: Option<CaseStyle> = None;
+    let mut indexes = Vec::new();
 
     // Parse #[serde(rename_all = "...")] at struct level
     attrs
@@ -123,6 +124,12 @@ pub fn expand_derive_entity_model(
                     model_ex = true;
                 } else if meta.path.is_ident("rename_all") {
                     rename_all = Some((&meta).try_into()?);
+                } else if meta.path.is_ident("index") {
+                    // forwarded to the Entity, where DeriveEntity parses it
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let tokens: TokenStream = content.parse()?;
+                    indexes.push(quote!(#[sea_orm(index(#tokens))]));
                 } else {
                     consume_meta(meta);
                 }
@@ -143,6 +150,7 @@ pub fn expand_derive_entity_model(
                 #[doc = " Generated by sea-orm-macros"]
                 #[derive(Copy, Clone, Default, Debug, sea_orm::prelude::DeriveEntity)]
                 #entity_extra_attr
+                #(#indexes)*
                 #vis struct Entity;
 
                 #[automatically_derived]
@@ -167,11 +175,15 @@ pub fn expand_derive_entity_model(
     let mut columns_enum: Punctuated<_, Comma> = Punctuated::new();
     let mut columns_trait: Punctuated<_, Comma> = Punctuated::new();
     let mut columns_enum_type_name: Punctuated<_, Comma> = Punctuated::new();
+    let mut columns_value_type = Vec::new();
     let mut columns_select_as: Punctuated<_, Comma> = Punctuated::new();
     let mut columns_save_as: Punctuated<_, Comma> = Punctuated::new();
+    let mut columns_encrypt: Punctuated<_, Comma> = Punctuated::new();
+    let mut columns_decrypt: Punctuated<_, Comma> = Punctuated::new();
     let mut primary_keys: Punctuated<_, Comma> = Punctuated::new();
     let mut primary_key_types: Punctuated<_, Comma> = Punctuated::new();
     let mut auto_increment: Option<bool> = None;
+    let mut db_generated = false;
     #[cfg(feature = "with-json")]
     let mut columns_json_keys: Punctuated<_, Comma> = Punctuated::new();
 
@@ -203,16 +215,20 @@ pub fn expand_derive_entity_model(
                     let mut default_expr = None;
                     let mut select_as = None;
                     let mut save_as = None;
+                    let mut encrypt_with = None;
                     let mut unique_key = None;
                     let mut renamed_from = None;
                     let mut indexed = false;
+                    let mut generated = false;
                     let mut ignore = false;
                     let mut unique = false;
                     let mut sql_type = None;
                     let mut enum_name = None;
                     let mut is_primary_key = false;
                     let mut is_auto_increment = false;
+                    let mut is_db_generated = false;
                     let mut extra = None;
+                    let mut auto_uuid = None;
                     let mut seaography_ignore = false;
                     #[cfg(feature = "with-json")]
                     let mut serde_rename: Option<String> = None;
@@ -228,7 +244,7 @@ pub fn expand_derive_entity_model(
                         None
                     };
 
-                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(StringLen::N(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", enum_name = "Name", nullable, indexed, unique)]
+                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(StringLen::N(255))", default_value = "new user", default_expr = "gen_random_uuid()", auto_uuid = "v4", column_name = "name", enum_name = "Name", nullable, indexed, unique)]
                     for attr in field.attrs.iter() {
                         if attr.path().is_ident("sea_orm") {
                             // single param
@@ -303,6 +319,16 @@ pub fn expand_derive_entity_model(
                                     } else {
                                         return Err(meta.error(format!("Invalid save_as {lit:?}")));
                                     }
+                                } else if meta.path.is_ident("encrypt_with") {
+                                    let lit = meta.value()?.parse()?;
+                                    if let Lit::Str(litstr) = lit {
+                                        let ty: syn::Type = litstr.parse()?;
+                                        encrypt_with = Some(ty);
+                                    } else {
+                                        return Err(
+                                            meta.error(format!("Invalid encrypt_with {lit:?}"))
+                                        );
+                                    }
                                 } else if meta.path.is_ident("ignore") {
                                     ignore = true;
                                 } else if meta.path.is_ident("primary_key") {
@@ -312,6 +338,10 @@ pub fn expand_derive_entity_model(
                                     nullable = true;
                                 } else if meta.path.is_ident("indexed") {
                                     indexed = true;
+                                } else if meta.path.is_ident("generated") {
+                                    generated = true;
+                                } else if meta.path.is_ident("db_generated") {
+                                    is_db_generated = true;
                                 } else if meta.path.is_ident("unique") {
                                     unique = true;
                                 } else if meta.path.is_ident("unique_key") {
@@ -332,6 +362,31 @@ pub fn expand_derive_entity_model(
                                             meta.error(format!("Invalid renamed_from {lit:?}"))
                                         );
                                     }
+                                } else if meta.path.is_ident("auto_uuid") {
+                                    let lit = meta.value()?.parse()?;
+                                    match &lit {
+                                        Lit::Str(litstr) if litstr.value() == "v4" => {
+                                            if !cfg!(feature = "with-uuid-v4") {
+                                                return Err(meta.error(
+                                                    "auto_uuid = \"v4\" requires the `with-uuid-v4` feature of sea-orm",
+                                                ));
+                                            }
+                                            auto_uuid = Some(quote! { V4 });
+                                        }
+                                        Lit::Str(litstr) if litstr.value() == "v7" => {
+                                            if !cfg!(feature = "with-uuid-v7") {
+                                                return Err(meta.error(
+                                                    "auto_uuid = \"v7\" requires the `with-uuid-v7` feature of sea-orm",
+                                                ));
+                                            }
+                                            auto_uuid = Some(quote! { V7 });
+                                        }
+                                        _ => {
+                                            return Err(meta.error(format!(
+                                                "Invalid auto_uuid {lit:?}, expected \"v4\" or \"v7\""
+                                            )));
+                                        }
+                                    }
                                 } else if meta.path.is_ident("extra") {
                                     let lit = meta.value()?.parse()?;
                                     if let Lit::Str(litstr) = lit {
@@ -444,15 +499,25 @@ pub fn expand_derive_entity_model(
                         ));
                     }
 
-                    if let Some(select_as) = select_as {
-                        columns_select_as.push(quote! {
-                            Self::#field_name => sea_orm::sea_query::ExprTrait::cast_as(expr, #select_as)
-                        });
+                    if !is_primary_key && auto_uuid.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            ident,
+                            "auto_uuid can only be used on primary_key",
+                        ));
                     }
-                    if let Some(save_as) = save_as {
-                        columns_save_as.push(quote! {
-                            Self::#field_name => sea_orm::sea_query::ExprTrait::cast_as(val, #save_as)
-                        });
+
+                    if !is_primary_key && is_db_generated {
+                        return Err(syn::Error::new_spanned(
+                            ident,
+                            "db_generated can only be used on primary_key",
+                        ));
+                    }
+
+                    // a key with a column default is filled in by the database too
+                    if is_primary_key
+                        && (is_db_generated || default_value.is_some() || default_expr.is_some())
+                    {
+                        db_generated = true;
                     }
 
                     let field_type = if field_type.starts_with("Option<") {
@@ -488,6 +553,9 @@ pub fn expand_derive_entity_model(
                     if unique {
                         match_row = quote! { #match_row.unique() };
                     }
+                    if generated {
+                        match_row = quote! { #match_row.generated() };
+                    }
                     if seaography_ignore {
                         match_row = quote! { #match_row.seaography_ignore() };
                     }
@@ -509,6 +577,9 @@ pub fn expand_derive_entity_model(
                     if let Some(extra) = extra {
                         match_row = quote! { #match_row.extra(#extra) };
                     }
+                    if let Some(auto_uuid) = auto_uuid {
+                        match_row = quote! { #match_row.auto_uuid(sea_orm::entity::UuidVersion::#auto_uuid) };
+                    }
                     // match_row = quote! { #match_row.comment() };
                     columns_trait.push(match_row);
 
@@ -521,6 +592,44 @@ pub fn expand_derive_entity_model(
                     columns_enum_type_name.push(quote! {
                         Self::#field_name => #enum_type_name
                     });
+                    // the `select_expr` / `save_expr` of a value type, unless cast explicitly
+                    let value_type_exprs = quote::quote_spanned! { field_span =>
+                        (&sea_orm::value::ValueTypeExprsOf::<#ty>::default())
+                    };
+                    columns_select_as.push(match select_as {
+                        Some(select_as) => quote! {
+                            Self::#field_name => sea_orm::sea_query::ExprTrait::cast_as(expr, #select_as)
+                        },
+                        None => quote! {
+                            Self::#field_name => #value_type_exprs.select_as(self, expr)
+                        },
+                    });
+                    columns_save_as.push(match save_as {
+                        Some(save_as) => quote! {
+                            Self::#field_name => sea_orm::sea_query::ExprTrait::cast_as(val, #save_as)
+                        },
+                        None => quote! {
+                            Self::#field_name => #value_type_exprs.save_as(self, val)
+                        },
+                    });
+                    if let Some(cipher) = encrypt_with {
+                        columns_encrypt.push(quote! {
+                            Self::#field_name => <#cipher as sea_orm::ColumnCipher>::encrypt(val)
+                        });
+                        columns_decrypt.push(quote! {
+                            Self::#field_name => Some(sea_orm::try_get_decrypted::<#cipher>(
+                                res,
+                                pre,
+                                sea_orm::IdenStatic::as_str(self),
+                            ))
+                        });
+                    }
+                    columns_value_type.push(quote! {
+                        #[automatically_derived]
+                        impl sea_orm::entity::ColumnValueType<{ Column::#field_name as usize }> for Column {
+                            type Value = #ty;
+                        }
+                    });
                 }
             }
         }
@@ -559,6 +668,10 @@ pub fn expand_derive_entity_model(
                 fn auto_increment() -> bool {
                     #auto_increment
                 }
+
+                fn db_generated() -> bool {
+                    #db_generated
+                }
             }
         }
     };
@@ -576,6 +689,32 @@ pub fn expand_derive_entity_model(
         }
     };
 
+    let encrypt_impl = if columns_encrypt.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            fn encrypt_value(&self, val: sea_orm::Value) -> sea_orm::Value {
+                match self {
+                    #columns_encrypt,
+                    #[allow(unreachable_patterns)]
+                    _ => val,
+                }
+            }
+
+            fn try_get_decrypted(
+                &self,
+                res: &sea_orm::QueryResult,
+                pre: &str,
+            ) -> Option<Result<Option<sea_orm::Value>, sea_orm::DbErr>> {
+                match self {
+                    #columns_decrypt,
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+        }
+    };
+
     let with_json_impls = {
         #[cfg(feature = "with-json")]
         quote! {
@@ -616,22 +755,30 @@ pub fn expand_derive_entity_model(
             }
 
             fn select_as(&self, expr: sea_orm::sea_query::Expr) -> sea_orm::sea_query::SimpleExpr {
+                use sea_orm::value::{SelectSaveEnumExprs, SelectSaveExprs};
                 match self {
                     #columns_select_as
+                    #[allow(unreachable_patterns)]
                     _ => sea_orm::prelude::ColumnTrait::select_enum_as(self, expr),
                 }
             }
 
             fn save_as(&self, val: sea_orm::sea_query::Expr) -> sea_orm::sea_query::SimpleExpr {
+                use sea_orm::value::{SelectSaveEnumExprs, SelectSaveExprs};
                 match self {
                     #columns_save_as
+                    #[allow(unreachable_patterns)]
                     _ => sea_orm::prelude::ColumnTrait::save_enum_as(self, val),
                 }
             }
 
+            #encrypt_impl
+
             #with_json_impls
         }
 
+        #(#columns_value_type)*
+
         #entity_def
 
         #primary_key
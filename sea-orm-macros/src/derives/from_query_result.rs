@@ -1,6 +1,6 @@
 use std::collections::{HashMap, hash_map::Entry};
 
-use super::util::GetMeta;
+use super::util::{GetMeta, decrypted_read_type};
 use proc_macro2::{Ident, TokenStream};
 use quote::{ToTokens, quote};
 use syn::{
@@ -10,8 +10,15 @@ use syn::{
 
 pub(super) enum ItemType {
     Flat,
+    /// a flat field stored encrypted with `cipher`
+    Encrypted {
+        cipher: Box<syn::Type>,
+        field_type: Box<syn::Type>,
+    },
     Skip,
-    Nested { prefix: Option<String> },
+    Nested {
+        prefix: Option<String>,
+    },
 }
 
 pub(super) struct DeriveFromQueryResult {
@@ -55,6 +62,20 @@ impl ToTokens for TryFromQueryResultCheck<'_> {
                     };
                 });
             }
+            ItemType::Encrypted { cipher, field_type } => {
+                let name = alias
+                    .to_owned()
+                    .unwrap_or_else(|| ident.unraw().to_string());
+                let (read_type, unwrap) = decrypted_read_type(field_type, cipher);
+                tokens.extend(quote! {
+                    let #ident = match row.try_get_nullable::<#read_type>(pre, #name).map(#unwrap) {
+                        Err(v @ sea_orm::TryGetError::DbErr(_)) => {
+                            return Err(v);
+                        }
+                        v => v,
+                    };
+                });
+            }
             ItemType::Skip => {
                 tokens.extend(quote! {
                     let #ident = std::default::Default::default();
@@ -90,7 +111,7 @@ impl ToTokens for TryFromQueryResultAssignment<'_> {
         let FromQueryResultItem { ident, typ, .. } = self.0;
 
         match typ {
-            ItemType::Flat | ItemType::Nested { .. } => {
+            ItemType::Flat | ItemType::Encrypted { .. } | ItemType::Nested { .. } => {
                 tokens.extend(quote! {
                     #ident: #ident?,
                 });
@@ -132,6 +153,7 @@ impl DeriveFromQueryResult {
         for parsed_field in parsed_fields {
             let mut typ = ItemType::Flat;
             let mut alias = None;
+            let mut cipher = None;
             for attr in parsed_field.attrs.iter() {
                 if !attr.path().is_ident("sea_orm") {
                     continue;
@@ -159,6 +181,8 @@ impl DeriveFromQueryResult {
                             }
 
                             typ = ItemType::Nested { prefix };
+                        } else if let Some(c) = meta.get_as_kv("encrypt_with") {
+                            cipher = Some(syn::parse_str::<syn::Type>(&c)?);
                         } else {
                             alias = meta
                                 .get_as_kv("from_alias")
@@ -168,6 +192,13 @@ impl DeriveFromQueryResult {
                 }
             }
 
+            if let Some(cipher) = cipher.filter(|_| matches!(typ, ItemType::Flat)) {
+                typ = ItemType::Encrypted {
+                    cipher: Box::new(cipher),
+                    field_type: Box::new(parsed_field.ty.clone()),
+                };
+            }
+
             let field_tokens = parsed_field.to_token_stream();
             let ident = parsed_field.ident.unwrap();
 
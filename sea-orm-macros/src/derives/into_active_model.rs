@@ -168,9 +168,13 @@ impl DeriveIntoActiveModel {
 
         // Generate field conversion code based on field type
         let expanded_fields = fields.iter().map(|field| match field {
-            IntoActiveModelField::Normal(ident) => quote!(
-                sea_orm::IntoActiveValue::<_>::into_active_value(self.#ident).into()
-            ),
+            IntoActiveModelField::Normal(ident) => {
+                // spanned so a missing `IntoActiveValue` impl is reported on the field
+                let value = quote_spanned!(ident.span()=>
+                    sea_orm::IntoActiveValue::<_>::into_active_value(self.#ident)
+                );
+                quote!(#value.into())
+            }
             IntoActiveModelField::WithDefault { ident, expr } => quote!({
                 match self.#ident.into() {
                     Some(v) => sea_orm::ActiveValue::Set(v).into(),
@@ -1,6 +1,9 @@
 use super::{
     attributes::derive_attr,
-    util::{escape_rust_keyword, field_not_ignored, trim_starting_raw_identifier},
+    util::{
+        decrypted_read_type, escape_rust_keyword, field_not_ignored, field_redacted,
+        split_index_attrs, trim_starting_raw_identifier,
+    },
 };
 use heck::ToUpperCamelCase;
 use itertools::izip;
@@ -10,12 +13,14 @@ use std::iter::FromIterator;
 use syn::{Attribute, Data, Expr, Ident, LitStr, Type};
 
 pub(crate) struct DeriveModel {
+    ciphers: Vec<Option<Type>>,
     column_idents: Vec<Ident>,
     entity_ident: Ident,
     field_idents: Vec<Ident>,
     field_types: Vec<syn::Type>,
     ident: Ident,
     ignore_attrs: Vec<bool>,
+    redacted: Vec<bool>,
 }
 
 impl DeriveModel {
@@ -33,7 +38,8 @@ impl DeriveModel {
             }
         };
 
-        let sea_attr = derive_attr::SeaOrm::try_from_attributes(attrs)?.unwrap_or_default();
+        let (attrs, _) = split_index_attrs(attrs);
+        let sea_attr = derive_attr::SeaOrm::try_from_attributes(&attrs)?.unwrap_or_default();
 
         let entity_ident = sea_attr.entity.unwrap_or_else(|| format_ident!("Entity"));
 
@@ -75,28 +81,55 @@ impl DeriveModel {
             })
             .collect::<Result<_, syn::Error>>()?;
 
+        let ciphers = fields
+            .iter()
+            .map(|field| {
+                let mut cipher = None;
+                for attr in field.attrs.iter() {
+                    if !attr.path().is_ident("sea_orm") {
+                        continue;
+                    }
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("encrypt_with") {
+                            cipher = Some(meta.value()?.parse::<LitStr>()?.parse::<Type>()?);
+                        } else {
+                            let _: Option<Expr> = meta.value().and_then(|v| v.parse()).ok();
+                        }
+                        Ok(())
+                    })?;
+                }
+                Ok(cipher)
+            })
+            .collect::<Result<_, syn::Error>>()?;
+
         let ignore_attrs = fields
             .iter()
             .map(|field| !field_not_ignored(field))
             .collect();
 
+        let redacted = fields.iter().map(field_redacted).collect();
+
         Ok(DeriveModel {
+            ciphers,
             column_idents,
             entity_ident,
             field_idents,
             field_types,
             ident: ident.clone(),
             ignore_attrs,
+            redacted,
         })
     }
 
     fn expand(&self) -> syn::Result<TokenStream> {
         let expanded_impl_from_query_result = self.impl_from_query_result();
         let expanded_impl_model_trait = self.impl_model_trait();
+        let expanded_impl_debug = self.impl_debug();
 
         Ok(TokenStream::from_iter([
             expanded_impl_from_query_result,
             expanded_impl_model_trait,
+            expanded_impl_debug,
         ]))
     }
 
@@ -112,8 +145,9 @@ impl DeriveModel {
             column_idents,
             field_types,
             ignore_attrs,
+            &self.ciphers,
         )
-        .map(|(field_ident, column_ident, field_type, &ignore)| {
+        .map(|(field_ident, column_ident, field_type, &ignore, cipher)| {
             if ignore {
                 let reader = quote! {
                     let #field_ident: Option<()> = None;
@@ -123,15 +157,23 @@ impl DeriveModel {
                 };
                 (reader, unwrapper)
             } else {
+                // an encrypted field is read as its ciphertext, then decrypted
+                let (read_type, decrypt) = match cipher {
+                    Some(cipher) => {
+                        let (read_type, unwrap) = decrypted_read_type(field_type, cipher);
+                        (read_type, quote!(.map(#unwrap)))
+                    }
+                    None => (quote!(#field_type), quote!()),
+                };
                 let reader = quote! {
                     let #field_ident =
-                        row.try_get_nullable::<Option<#field_type>>(
+                        row.try_get_nullable::<Option<#read_type>>(
                             pre,
                             sea_orm::IdenStatic::as_str(
                                 &<<Self as sea_orm::ModelTrait>::Entity
                                     as sea_orm::entity::EntityTrait>::Column::#column_ident
                             ).into()
-                        )?;
+                        )?#decrypt;
                 };
                 let unwrapper = quote! {
                     #field_ident: #field_ident.ok_or_else(|| sea_orm::DbErr::Type(
@@ -251,6 +293,52 @@ impl DeriveModel {
     }
 }
 
+impl DeriveModel {
+    /// `Debug` hiding the values of `#[sea_orm(redact)]` fields, if there are any;
+    /// otherwise `Debug` is left to `#[derive(Debug)]`
+    fn impl_debug(&self) -> TokenStream {
+        if !self.redacted.contains(&true) {
+            return TokenStream::new();
+        }
+        let fields: Vec<_> = self
+            .field_idents
+            .iter()
+            .cloned()
+            .zip(self.redacted.iter().copied())
+            .collect();
+        impl_redacted_fmt(quote!(std::fmt::Debug), &self.ident, &fields)
+    }
+}
+
+/// Implement `fmt_trait` like `#[derive(Debug)]` does, printing `<redacted>`
+/// in place of the value of redacted fields
+pub(crate) fn impl_redacted_fmt(
+    fmt_trait: TokenStream,
+    ident: &Ident,
+    fields: &[(Ident, bool)],
+) -> TokenStream {
+    let name = ident.to_string();
+    let fields = fields.iter().map(|(field, redacted)| {
+        let field_name = trim_starting_raw_identifier(field);
+        if *redacted {
+            quote!(.field(#field_name, &format_args!("<redacted>")))
+        } else {
+            quote!(.field(#field_name, &self.#field))
+        }
+    });
+
+    quote!(
+        #[automatically_derived]
+        impl #fmt_trait for #ident {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct(#name)
+                    #(#fields)*
+                    .finish()
+            }
+        }
+    )
+}
+
 pub fn expand_derive_model(
     ident: &Ident,
     data: &Data,
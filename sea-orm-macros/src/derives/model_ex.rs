@@ -161,6 +161,10 @@ pub fn expand_derive_model_ex(
             attr.parse_nested_meta(|meta| {
                 if meta.path.is_ident("compact_model") {
                     compact = true;
+                } else if meta.path.is_ident("index") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let _: TokenStream = content.parse()?;
                 } else {
                     // Reads the value expression to advance the parse stream.
                     let _: Option<Expr> = meta.value().and_then(|v| v.parse()).ok();
@@ -50,6 +50,8 @@ struct DerivePartialModel {
     model_alias: Option<String>,
     ident: syn::Ident,
     fields: Vec<ColumnAs>,
+    /// `encrypt_with` cipher of each field, with the type of the field
+    ciphers: Vec<Option<(Type, Type)>>,
     from_query_result: bool,
     into_active_model: bool,
 }
@@ -108,6 +110,7 @@ impl DerivePartialModel {
         }
 
         let mut column_as_list = Vec::with_capacity(fields.len());
+        let mut ciphers = Vec::with_capacity(fields.len());
         let mut seen_nested: HashMap<(syn::Type, Option<String>), TokenStream> = HashMap::new();
 
         for field in fields {
@@ -119,6 +122,7 @@ impl DerivePartialModel {
             let mut nested_alias = None;
             let mut nested_prefix = None;
             let mut skip = false;
+            let mut cipher = None;
 
             for attr in field.attrs.iter() {
                 if !attr.path().is_ident("sea_orm") {
@@ -151,6 +155,8 @@ impl DerivePartialModel {
                             from_expr = Some(syn::parse_str::<Expr>(&s).map_err(Error::Syn)?);
                         } else if let Some(s) = meta.get_as_kv("alias") {
                             nested_alias = Some(s);
+                        } else if let Some(s) = meta.get_as_kv("encrypt_with") {
+                            cipher = Some(syn::parse_str::<Type>(&s).map_err(Error::Syn)?);
                         }
                     }
                 }
@@ -158,6 +164,7 @@ impl DerivePartialModel {
 
             let field_tokens = field.to_token_stream();
             let field_name = field.ident.unwrap();
+            ciphers.push(cipher.map(|cipher| (cipher, field.ty.clone())));
 
             let col_as = match (from_col, from_expr, nested) {
                 (Some(col), None, false) => {
@@ -223,6 +230,7 @@ impl DerivePartialModel {
             model_alias,
             ident: input.ident,
             fields: column_as_list,
+            ciphers,
             from_query_result,
             into_active_model,
         })
@@ -238,13 +246,18 @@ impl DerivePartialModel {
                 fields: self
                     .fields
                     .iter()
-                    .map(|col_as| FromQueryResultItem {
-                        typ: match col_as {
-                            ColumnAs::Nested { prefix, .. } => FqrItemType::Nested {
+                    .zip(&self.ciphers)
+                    .map(|(col_as, cipher)| FromQueryResultItem {
+                        typ: match (col_as, cipher) {
+                            (ColumnAs::Nested { prefix, .. }, _) => FqrItemType::Nested {
                                 prefix: prefix.clone(),
                             },
-                            ColumnAs::Skip(_) => FqrItemType::Skip,
-                            _ => FqrItemType::Flat,
+                            (ColumnAs::Skip(_), _) => FqrItemType::Skip,
+                            (_, Some((cipher, field_type))) => FqrItemType::Encrypted {
+                                cipher: Box::new(cipher.clone()),
+                                field_type: Box::new(field_type.clone()),
+                            },
+                            (_, None) => FqrItemType::Flat,
                         },
                         ident: match col_as {
                             ColumnAs::Col { field, .. } => field,
@@ -59,7 +59,66 @@ impl DeriveRelation {
                 .map_err(|_| syn::Error::new_spanned(lit, "attribute not valid"))
         }
 
-        let variant_relation_defs: Vec<TokenStream> = self
+        /// Parse `from` / `to`, a column or a tuple of columns. The tokens keep the span
+        /// of the attribute, so that an unknown column is reported there.
+        fn parse_columns(lit: &syn::Lit, name: &str) -> syn::Result<syn::Expr> {
+            let err = || {
+                syn::Error::new_spanned(
+                    lit,
+                    format!(
+                        "`{name}` must be a column, e.g. `Column::CakeId`, or a tuple of columns"
+                    ),
+                )
+            };
+            let expr: syn::Expr = match lit {
+                syn::Lit::Str(lit_str) => lit_str.parse().map_err(|_| err())?,
+                _ => return Err(syn::Error::new_spanned(lit, "attribute must be a string")),
+            };
+            let is_column = |expr: &syn::Expr| matches!(expr, syn::Expr::Path(_));
+            match &expr {
+                syn::Expr::Path(_) => Ok(expr),
+                syn::Expr::Paren(paren) if is_column(&paren.expr) => Ok(expr),
+                syn::Expr::Tuple(tuple) if tuple.elems.iter().all(is_column) => Ok(expr),
+                _ => Err(err()),
+            }
+        }
+
+        /// Split a column path into the `Column` type and the variant
+        fn column_type(expr: &syn::Expr) -> Option<(syn::Path, &syn::Path)> {
+            let syn::Expr::Path(expr_path) = expr else {
+                return None;
+            };
+            if expr_path.qself.is_some() {
+                return None;
+            }
+            let path = &expr_path.path;
+            let mut ty = path.clone();
+            ty.segments.pop()?;
+            ty.segments.pop_punct();
+            if ty.segments.is_empty() {
+                return None;
+            }
+            Some((ty, path))
+        }
+
+        fn parse_foreign_key_action(lit: &syn::Lit, name: &str) -> syn::Result<syn::Ident> {
+            const ACTIONS: [&str; 5] = ["Cascade", "NoAction", "Restrict", "SetDefault", "SetNull"];
+            match lit {
+                syn::Lit::Str(lit_str) if ACTIONS.contains(&lit_str.value().as_str()) => {
+                    Ok(syn::Ident::new(&lit_str.value(), lit_str.span()))
+                }
+                syn::Lit::Str(_) => Err(syn::Error::new_spanned(
+                    lit,
+                    format!(
+                        "`{name}` must be one of {}",
+                        ACTIONS.map(|action| format!("`{action}`")).join(", ")
+                    ),
+                )),
+                _ => Err(syn::Error::new_spanned(lit, "attribute must be a string")),
+            }
+        }
+
+        let variant_relation_defs: Vec<(TokenStream, Option<TokenStream>)> = self
             .variants
             .iter()
             .map(|variant| {
@@ -113,37 +172,58 @@ impl DeriveRelation {
                     )
                 };
 
+                let mut from = None;
                 if attr.from.is_some() {
-                    let from =
-                        attr.from
-                            .as_ref()
-                            .map(parse_lit_str)
-                            .ok_or_else(|| {
-                                syn::Error::new_spanned(variant, "Missing value for 'from'")
-                            })??;
-                    result = quote! { #result.from(#from) };
+                    let from_columns = attr
+                        .from
+                        .as_ref()
+                        .map(|lit| parse_columns(lit, "from"))
+                        .ok_or_else(|| {
+                            syn::Error::new_spanned(variant, "Missing value for 'from'")
+                        })??;
+                    result = quote! { #result.from(#from_columns) };
+                    from = Some(from_columns);
                 } else if attr.belongs_to.is_some() {
                     return Err(syn::Error::new_spanned(variant, "Missing attribute 'from'"));
                 }
 
+                let mut to = None;
                 if attr.to.is_some() {
-                    let to = attr
+                    let to_columns = attr
                         .to
                         .as_ref()
-                        .map(parse_lit_str)
+                        .map(|lit| parse_columns(lit, "to"))
                         .ok_or_else(|| {
                             syn::Error::new_spanned(variant, "Missing value for 'to'")
                         })??;
-                    result = quote! { #result.to(#to) };
+                    result = quote! { #result.to(#to_columns) };
+                    to = Some(to_columns);
                 } else if attr.belongs_to.is_some() {
                     return Err(syn::Error::new_spanned(variant, "Missing attribute 'to'"));
                 }
 
+                // the types of single columns are checked at compile time
+                let column_check = match (
+                    from.as_ref().and_then(column_type),
+                    to.as_ref().and_then(column_type),
+                ) {
+                    (Some((from_ty, from)), Some((to_ty, to))) => {
+                        Some(quote_spanned! { syn::spanned::Spanned::span(to) =>
+                            sea_orm::entity::assert_same_column_type(
+                                (&sea_orm::entity::RelationColumns::<
+                                    #from_ty, { #from as usize }, #to_ty, { #to as usize }
+                                >::new()).types()
+                            );
+                        })
+                    }
+                    _ => None,
+                };
+
                 if attr.on_update.is_some() {
                     let on_update = attr
                         .on_update
                         .as_ref()
-                        .map(parse_lit_str)
+                        .map(|lit| parse_foreign_key_action(lit, "on_update"))
                         .ok_or_else(|| {
                             syn::Error::new_spanned(variant, "Missing value for 'on_update'")
                         })??;
@@ -154,7 +234,7 @@ impl DeriveRelation {
                     let on_delete = attr
                         .on_delete
                         .as_ref()
-                        .map(parse_lit_str)
+                        .map(|lit| parse_foreign_key_action(lit, "on_delete"))
                         .ok_or_else(|| {
                             syn::Error::new_spanned(variant, "Missing value for 'on_delete'")
                         })??;
@@ -169,7 +249,16 @@ impl DeriveRelation {
                         .ok_or_else(|| {
                             syn::Error::new_spanned(variant, "Missing value for 'on_condition'")
                         })??;
-                    result = quote! { #result.on_condition(|_, _| sea_orm::sea_query::IntoCondition::into_condition(#on_condition)) };
+                    // A closure receives the table (or alias) on the left and right of the join,
+                    // any other expression applies to the join as is
+                    result = match syn::parse2::<syn::Expr>(on_condition.clone()) {
+                        Ok(syn::Expr::Closure(closure)) => quote! {
+                            #result.on_condition(|left, right| sea_orm::sea_query::IntoCondition::into_condition((#closure)(left, right)))
+                        },
+                        _ => quote! {
+                            #result.on_condition(|_, _| sea_orm::sea_query::IntoCondition::into_condition(#on_condition))
+                        },
+                    };
                 }
 
                 if attr.fk_name.is_some() {
@@ -216,9 +305,23 @@ impl DeriveRelation {
 
                 result = quote! { #result.into() };
 
-                Result::<_, syn::Error>::Ok(result)
+                Result::<_, syn::Error>::Ok((result, column_check))
             })
             .collect::<Result<Vec<_>, _>>()?;
+        let (variant_relation_defs, column_checks): (Vec<_>, Vec<_>) =
+            variant_relation_defs.into_iter().unzip();
+        let column_checks: Vec<TokenStream> = column_checks.into_iter().flatten().collect();
+
+        let column_type_checks = if column_checks.is_empty() {
+            quote!()
+        } else {
+            quote!(
+                const _: fn() = || {
+                    use sea_orm::entity::{RelationColumnTypes as _, RelationColumnTypesUnknown as _};
+                    #( #column_checks )*
+                };
+            )
+        };
 
         Ok(quote!(
             #[automatically_derived]
@@ -230,6 +333,8 @@ impl DeriveRelation {
                     }
                 }
             }
+
+            #column_type_checks
         ))
     }
 }
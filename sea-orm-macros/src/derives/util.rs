@@ -1,5 +1,10 @@
 use heck::ToUpperCamelCase;
-use syn::{Field, Ident, Meta, MetaNameValue, punctuated::Punctuated, token::Comma};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Attribute, Field, Ident, Meta, MetaNameValue, Type, parse_quote, punctuated::Punctuated,
+    token::Comma,
+};
 
 /// Remove ignored fields and compound fields
 pub(crate) fn field_not_ignored(field: &Field) -> bool {
@@ -18,6 +23,16 @@ pub(crate) fn field_not_ignored(field: &Field) -> bool {
 
 /// Remove ignored fields, compound fields okay
 pub(crate) fn field_not_ignored_compound(field: &Field) -> bool {
+    !field_has_flag(field, "ignore")
+}
+
+/// Fields marked `#[sea_orm(redact)]`, hidden from `Debug` and `Display`
+pub(crate) fn field_redacted(field: &Field) -> bool {
+    field_has_flag(field, "redact")
+}
+
+/// Whether the field has a `#[sea_orm(flag)]` without value
+fn field_has_flag(field: &Field, flag: &str) -> bool {
     for attr in field.attrs.iter() {
         if let Some(ident) = attr.path().get_ident() {
             if ident != "sea_orm" {
@@ -31,8 +46,8 @@ pub(crate) fn field_not_ignored_compound(field: &Field) -> bool {
             for meta in list.iter() {
                 if let Meta::Path(path) = meta {
                     if let Some(name) = path.get_ident() {
-                        if name == "ignore" {
-                            return false;
+                        if name == flag {
+                            return true;
                         }
                     }
                 }
@@ -40,7 +55,41 @@ pub(crate) fn field_not_ignored_compound(field: &Field) -> bool {
         }
     }
 
-    true
+    false
+}
+
+/// Split the `index(..)` lists out of the struct-level `#[sea_orm(..)]` attributes, as `bae`
+/// can't parse repeated lists. Returns the remaining attributes and the content of each list.
+pub(crate) fn split_index_attrs(attrs: &[Attribute]) -> (Vec<Attribute>, Vec<TokenStream>) {
+    let mut rest = Vec::new();
+    let mut indexes = Vec::new();
+    for attr in attrs {
+        let list = if attr.path().is_ident("sea_orm") {
+            attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
+                .unwrap_or_default()
+        } else {
+            Punctuated::new()
+        };
+        if !list.iter().any(is_index_list) {
+            rest.push(attr.clone());
+            continue;
+        }
+        let mut metas = Punctuated::<Meta, Comma>::new();
+        for meta in list {
+            match meta {
+                Meta::List(list) if list.path.is_ident("index") => indexes.push(list.tokens),
+                meta => metas.push(meta),
+            }
+        }
+        if !metas.is_empty() {
+            rest.push(parse_quote!(#[sea_orm(#metas)]));
+        }
+    }
+    (rest, indexes)
+}
+
+fn is_index_list(meta: &Meta) -> bool {
+    matches!(meta, Meta::List(list) if list.path.is_ident("index"))
 }
 
 pub(crate) fn is_compound_field(field_type: &str) -> bool {
@@ -68,6 +117,41 @@ pub(crate) fn format_field_ident(field: &Field) -> Ident {
     field.ident.clone().unwrap()
 }
 
+/// The type to read a field of type `ty` encrypted with `cipher` as, and the
+/// function unwrapping it into `ty`
+pub(crate) fn decrypted_read_type(ty: &Type, cipher: &Type) -> (TokenStream, TokenStream) {
+    match option_inner_type(ty) {
+        // a NULL ciphertext is None
+        Some(inner) => (
+            quote!(Option<sea_orm::Decrypted<#cipher, #inner>>),
+            quote!(|v: Option<sea_orm::Decrypted<#cipher, #inner>>| v.map(sea_orm::Decrypted::into_inner)),
+        ),
+        None => (
+            quote!(sea_orm::Decrypted<#cipher, #ty>),
+            quote!(sea_orm::Decrypted::into_inner),
+        ),
+    }
+}
+
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if type_path.qself.is_some() || segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+            match args.args.first()? {
+                syn::GenericArgument::Type(inner) => Some(inner),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 pub(crate) fn trim_starting_raw_identifier<T>(string: T) -> String
 where
     T: ToString,
@@ -16,6 +16,7 @@ struct DeriveValueTypeStruct {
     column_type: TokenStream,
     array_type: TokenStream,
     can_try_from_u64: bool,
+    exprs: ValueTypeExprs,
 }
 
 #[derive(Default)]
@@ -23,6 +24,52 @@ struct DeriveValueTypeStructAttrs {
     column_type: Option<TokenStream>,
     array_type: Option<TokenStream>,
     try_from_u64: bool,
+    exprs: ValueTypeExprs,
+}
+
+/// SQL wrapping a column of the value type on read and write, `$1` being the column or value
+#[derive(Default)]
+struct ValueTypeExprs {
+    select_expr: Option<syn::LitStr>,
+    save_expr: Option<syn::LitStr>,
+}
+
+impl ValueTypeExprs {
+    fn new(attrs: &value_type_attr::SeaOrm) -> Self {
+        Self {
+            select_expr: attrs.select_expr.clone(),
+            save_expr: attrs.save_expr.clone(),
+        }
+    }
+
+    fn impl_value_type_exprs(&self, name: &Ident) -> TokenStream {
+        if self.select_expr.is_none() && self.save_expr.is_none() {
+            return quote!();
+        }
+        let select_expr = self.select_expr.as_ref().map(|select_expr| {
+            quote!(
+                fn select_expr(expr: sea_orm::sea_query::Expr) -> sea_orm::sea_query::Expr {
+                    sea_orm::sea_query::Expr::cust_with_expr(#select_expr, expr)
+                }
+            )
+        });
+        let save_expr = self.save_expr.as_ref().map(|save_expr| {
+            quote!(
+                fn save_expr(val: sea_orm::sea_query::Expr) -> sea_orm::sea_query::Expr {
+                    sea_orm::sea_query::Expr::cust_with_expr(#save_expr, val)
+                }
+            )
+        });
+
+        quote!(
+            #[automatically_derived]
+            impl sea_orm::value::ValueTypeExprs for #name {
+                #select_expr
+
+                #save_expr
+            }
+        )
+    }
 }
 
 impl TryFrom<value_type_attr::SeaOrm> for DeriveValueTypeStructAttrs {
@@ -30,6 +77,7 @@ impl TryFrom<value_type_attr::SeaOrm> for DeriveValueTypeStructAttrs {
 
     fn try_from(attrs: value_type_attr::SeaOrm) -> syn::Result<Self> {
         Ok(Self {
+            exprs: ValueTypeExprs::new(&attrs),
             column_type: attrs.column_type.map(|s| s.parse()).transpose()?,
             array_type: attrs.array_type.map(|s| s.parse()).transpose()?,
             try_from_u64: attrs.try_from_u64.is_some(),
@@ -42,22 +90,25 @@ struct DeriveValueTypeString {
     from_str: Option<TokenStream>,
     to_str: Option<TokenStream>,
     column_type: Option<TokenStream>,
+    exprs: ValueTypeExprs,
 }
 
 struct DeriveValueTypeStringAttrs {
     from_str: Option<TokenStream>,
     to_str: Option<TokenStream>,
     column_type: Option<TokenStream>,
+    exprs: ValueTypeExprs,
 }
 
 impl TryFrom<value_type_attr::SeaOrm> for DeriveValueTypeStringAttrs {
     type Error = syn::Error;
 
     fn try_from(attrs: value_type_attr::SeaOrm) -> syn::Result<Self> {
-        let value_type = attrs.value_type.map(|s| s.value());
+        let value_type = attrs.value_type.as_ref().map(|s| s.value());
         assert_eq!(value_type.as_deref(), Some("String"));
 
         Ok(Self {
+            exprs: ValueTypeExprs::new(&attrs),
             from_str: attrs.from_str.map(|s| s.parse()).transpose()?,
             to_str: attrs.to_str.map(|s| s.parse()).transpose()?,
             column_type: attrs.column_type.map(|s| s.parse()).transpose()?,
@@ -159,6 +210,7 @@ impl DeriveValueTypeStruct {
             column_type,
             array_type,
             can_try_from_u64,
+            exprs: attrs.exprs,
         })
     }
 
@@ -195,6 +247,8 @@ impl DeriveValueTypeStruct {
             quote!()
         };
 
+        let impl_value_type_exprs = self.exprs.impl_value_type_exprs(name);
+
         quote!(
             #[automatically_derived]
             impl std::convert::From<#name> for sea_orm::Value {
@@ -247,6 +301,8 @@ impl DeriveValueTypeStruct {
             #try_from_u64_impl
 
             #impl_not_u8
+
+            #impl_value_type_exprs
         )
     }
 }
@@ -258,6 +314,7 @@ impl DeriveValueTypeString {
             from_str: attrs.from_str,
             to_str: attrs.to_str,
             column_type: attrs.column_type,
+            exprs: attrs.exprs,
         })
     }
 
@@ -285,6 +342,8 @@ impl DeriveValueTypeString {
             quote!()
         };
 
+        let impl_value_type_exprs = self.exprs.impl_value_type_exprs(name);
+
         quote!(
             #[automatically_derived]
             impl std::convert::From<#name> for sea_orm::Value {
@@ -343,6 +402,8 @@ impl DeriveValueTypeString {
             }
 
             #impl_not_u8
+
+            #impl_value_type_exprs
         )
     }
 }
@@ -12,6 +12,74 @@ mod strum;
 
 mod raw_sql;
 
+/// Build a [`Statement`](sea_orm::Statement) from raw SQL, like `format!` but binding
+/// every `{value}` as a parameter instead of splicing it into the SQL.
+///
+/// The first argument is the backend, either spelled out (`Postgres`, `DbBackend::Postgres`)
+/// or any expression evaluating to a `DbBackend`, e.g. `db.get_database_backend()`. With a
+/// backend only known at runtime it gives a `Result<Statement, DbErr>`, which is
+/// [`DbErr::BackendNotSupported`](sea_orm::DbErr::BackendNotSupported) for a backend
+/// `raw_sql!` can't build statements for.
+///
+/// ```
+/// # fn main() -> Result<(), sea_orm::DbErr> {
+/// use sea_orm::{DbBackend, raw_sql};
+///
+/// let (id, pattern) = (1, "%cheese%");
+/// let stmt = raw_sql!(
+///     Postgres,
+///     r#"SELECT * FROM "cake" WHERE "id" = {id} AND "name" LIKE {pattern}"#
+/// );
+/// assert_eq!(
+///     stmt.sql,
+///     r#"SELECT * FROM "cake" WHERE "id" = $1 AND "name" LIKE $2"#
+/// );
+/// assert_eq!(stmt.values.unwrap().0, [1.into(), "%cheese%".into()]);
+///
+/// let db_backend = DbBackend::MySql;
+/// let stmt = raw_sql!(db_backend, "SELECT * FROM `cake` WHERE `id` = {id}")?;
+/// assert_eq!(stmt.sql, "SELECT * FROM `cake` WHERE `id` = ?");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Values can also be passed after the SQL string, for `$N` or `?` placeholders. They are
+/// checked at compile time: every placeholder needs a value, and every value has to be used.
+///
+/// ```
+/// use sea_orm::raw_sql;
+///
+/// let stmt = raw_sql!(
+///     Postgres,
+///     r#"SELECT * FROM "cake" WHERE "id" = $1 OR "parent_id" = $1 AND "name" LIKE $2"#,
+///     1,
+///     "%cheese%"
+/// );
+/// assert_eq!(
+///     stmt.sql,
+///     r#"SELECT * FROM "cake" WHERE "id" = $1 OR "parent_id" = $2 AND "name" LIKE $3"#
+/// );
+/// assert_eq!(
+///     stmt.values.unwrap().0,
+///     [1.into(), 1.into(), "%cheese%".into()]
+/// );
+/// ```
+///
+/// A placeholder without a value does not compile,
+///
+/// ```compile_fail
+/// let stmt = sea_orm::raw_sql!(Postgres, r#"SELECT * FROM "cake" WHERE "id" = $1"#);
+/// ```
+///
+/// ```compile_fail
+/// let stmt = sea_orm::raw_sql!(Sqlite, r#"SELECT * FROM "cake" WHERE "id" = $1 AND "name" = $2"#, 1);
+/// ```
+///
+/// neither does a value that is never used.
+///
+/// ```compile_fail
+/// let stmt = sea_orm::raw_sql!(MySql, "SELECT * FROM `cake` WHERE `id` = ?", 1, "cheese");
+/// ```
 #[proc_macro]
 pub fn raw_sql(input: TokenStream) -> TokenStream {
     match raw_sql::expand(input) {
@@ -143,6 +211,38 @@ pub fn derive_entity(input: TokenStream) -> TokenStream {
 /// #
 /// # impl ActiveModelBehavior for ActiveModel {}
 /// ```
+///
+/// Columns marked `#[sea_orm(redact)]` are printed as `<redacted>` by `Debug`, so that
+/// secrets don't end up in logs. `Debug` of the Model and ActiveModel is then implemented
+/// by this macro, so don't derive it. Data access and serde are not affected.
+///
+/// ```
+/// use sea_orm::entity::prelude::*;
+///
+/// #[derive(Clone, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "user")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     #[sea_orm(redact)]
+///     pub email: String,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// #
+/// let user = Model {
+///     id: 1,
+///     email: "alice@example.com".to_owned(),
+/// };
+/// assert_eq!(format!("{user:?}"), "Model { id: 1, email: <redacted> }");
+/// assert_eq!(
+///     format!("{:?}", ActiveModel::from(user)),
+///     "ActiveModel { id: Unchanged(1), email: Unchanged(<redacted>) }"
+/// );
+/// ```
 #[cfg(feature = "derive")]
 #[proc_macro_derive(DeriveEntityModel, attributes(sea_orm, seaography))]
 pub fn derive_entity_model(input: TokenStream) -> TokenStream {
@@ -692,9 +792,15 @@ pub fn derive_active_model_behavior(input: TokenStream) -> TokenStream {
 ///     - `enum_name`: Define `String` returned by `ActiveEnum::name()`
 ///         - This attribute is optional with default value being the name of enum in camel-case
 ///         - Note that value has to be passed as string, i.e. `enum_name = "MyEnum"`
+///     - `schema_name`: The Postgres schema the native enum type lives in, e.g. `schema_name = "billing"`
+///         - The type is then referred to as `"billing"."my_enum"` in casts, and created in that schema
+///         - `ActiveEnum::name()` returns the qualified name, i.e. `billing.my_enum`
 ///     - Constraints for native enums (`db_type = "Enum"`):
 ///         - `rs_type` is optional; it defaults to `Enum`. If specified it must be `String` or `Enum`.
 ///         - `num_value` and numeric discriminants are not allowed.
+///     - `display_from_str`: Also implement `Display` and `FromStr` using the variants' string values
+///         - `FromStr` fails with `sea_orm::ParseActiveEnumError`, listing the valid values
+///         - Only for string-backed enums, i.e. `#[sea_orm(rs_type = "String", db_type = "Text", display_from_str)]`
 ///
 /// - For enum variant
 ///     - `string_value` or `num_value`:
@@ -735,6 +841,7 @@ pub fn derive_active_enum(input: TokenStream) -> TokenStream {
 /// - `skip`: will not try to pull this field from the query result. And set it to the default value of the type.
 /// - `nested`: allows nesting models. can be any type that implements `FromQueryResult`. supports `nested(prefix = "...")` to set an explicit column prefix.
 /// - `alias` / `from_alias`: get the value from this column alias
+/// - `encrypt_with = "MyCipher"`: the column is stored encrypted, decrypt it with this `ColumnCipher`
 ///
 /// ### Usage
 ///
@@ -812,6 +919,25 @@ pub fn derive_from_query_result(input: TokenStream) -> TokenStream {
 ///     CakeExpanded,
 /// }
 /// ```
+///
+/// An unknown column in `from` or `to` is reported at the attribute. When both are single
+/// columns of entities derived with `DeriveEntityModel`, their Rust types, ignoring `Option`,
+/// must match:
+///
+/// ```compile_fail
+/// # use sea_orm::tests_cfg::fruit::Entity;
+/// use sea_orm::entity::prelude::*;
+///
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {
+///     #[sea_orm(
+///         belongs_to = "sea_orm::tests_cfg::cake::Entity",
+///         from = "sea_orm::tests_cfg::fruit::Column::Name",
+///         to = "sea_orm::tests_cfg::cake::Column::Id"
+///     )]
+///     Cake,
+/// }
+/// ```
 #[cfg(feature = "derive")]
 #[proc_macro_derive(DeriveRelation, attributes(sea_orm))]
 pub fn derive_relation(input: TokenStream) -> TokenStream {
@@ -1115,6 +1241,9 @@ pub fn derive_from_json_query_result(input: TokenStream) -> TokenStream {
 /// LIMIT 1
 /// ```
 ///
+/// A field of a column declared with `encrypt_with` takes the same `#[sea_orm(encrypt_with = "MyCipher")]`
+/// attribute, to be decrypted like the field of the model.
+///
 /// A field cannot have attributes `from_col`, `from_expr` or `nested` at the same time.
 /// Or, it will result in a compile error.
 ///
@@ -1301,6 +1430,24 @@ pub fn enum_iter(input: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+///
+/// The conversion can also be done in SQL: `select_expr` wraps columns of the type when
+/// they're selected, and `save_expr` wraps values of it when they're inserted, updated or
+/// compared, with `$1` standing for the column or value. Columns with an explicit
+/// `select_as` / `save_as` cast use that instead.
+///
+/// ```rust
+/// use sea_orm::DeriveValueType;
+///
+/// /// A `NUMERIC(12, 2)` amount of money, as integer cents
+/// #[derive(DeriveValueType)]
+/// #[sea_orm(
+///     column_type = "Decimal(Some((12, 2)))",
+///     select_expr = "($1 * 100)::bigint",
+///     save_expr = "$1 / 100.0"
+/// )]
+/// pub struct Cents(i64);
+/// ```
 #[cfg(feature = "derive")]
 #[proc_macro_derive(DeriveValueType, attributes(sea_orm))]
 pub fn derive_value_type(input: TokenStream) -> TokenStream {
@@ -1311,6 +1458,35 @@ pub fn derive_value_type(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Implement `Display` for an ActiveEnum, using the `display_value` of each
+/// variant, or its name by default.
+///
+/// On a Model, `Display` formats it like `Debug`, printing `<redacted>` in place
+/// of the values of `#[sea_orm(redact)]` columns.
+///
+/// ```
+/// use sea_orm::entity::prelude::*;
+///
+/// #[derive(Clone, PartialEq, DeriveEntityModel, DeriveDisplay)]
+/// #[sea_orm(table_name = "session")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     #[sea_orm(redact)]
+///     pub token: String,
+/// }
+/// #
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+///
+/// let session = Model {
+///     id: 1,
+///     token: "secret".to_owned(),
+/// };
+/// assert_eq!(session.to_string(), "Model { id: 1, token: <redacted> }");
+/// ```
 #[cfg(feature = "derive")]
 #[proc_macro_derive(DeriveDisplay, attributes(sea_orm))]
 pub fn derive_active_enum_display(input: TokenStream) -> TokenStream {
@@ -1,49 +1,302 @@
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use std::ops::Range;
 use syn::{
-    Ident, LitStr, Token,
+    Expr, Ident, LitStr, Token,
     parse::{Parse, ParseStream},
+    spanned::Spanned,
 };
 
 struct CallArgs {
-    backend: Ident,
-    _comma: Token![,],
+    backend: Expr,
     sql_string: LitStr,
+    args: Vec<Expr>,
 }
 
 impl Parse for CallArgs {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let backend = input.parse()?;
+        let _comma: Token![,] = input.parse()?;
+        let sql_string = input.parse()?;
+        let mut args = Vec::new();
+        while !input.is_empty() {
+            let _comma: Token![,] = input.parse()?;
+            if input.is_empty() {
+                break;
+            }
+            args.push(input.parse()?);
+        }
         Ok(CallArgs {
-            backend: input.parse()?,
-            _comma: input.parse()?,
-            sql_string: input.parse()?,
+            backend,
+            sql_string,
+            args,
         })
     }
 }
 
+#[derive(Debug, PartialEq, Eq)]
+enum Placeholder {
+    /// `$1`, `$2`, ...
+    Numbered(usize),
+    /// `?`
+    Anonymous,
+}
+
 pub fn expand(input: proc_macro::TokenStream) -> syn::Result<TokenStream> {
     let CallArgs {
         backend,
         sql_string,
-        ..
+        args,
     } = syn::parse(input)?;
 
-    let builder = match backend.to_string().as_str() {
+    let literal_backend = literal_backend(&backend);
+    // `?` is an operator on Postgres, e.g. for `jsonb`
+    let anonymous = literal_backend
+        .as_ref()
+        .is_none_or(|backend| backend != "Postgres");
+    let sql = sql_string.value();
+    let placeholders = find_placeholders(&sql, anonymous);
+    check_placeholders(&placeholders, &sql_string, &args)?;
+
+    // bind positional arguments to variables, and interpolate them in place of the placeholders
+    let mut rewritten = String::with_capacity(sql.len());
+    let mut last = 0;
+    for (i, (range, placeholder)) in placeholders.iter().enumerate() {
+        let index = match placeholder {
+            Placeholder::Numbered(n) => n - 1,
+            Placeholder::Anonymous => i,
+        };
+        rewritten.push_str(&sql[last..range.start]);
+        rewritten.push_str(&format!("{{sea_orm_arg_{index}}}"));
+        last = range.end;
+    }
+    rewritten.push_str(&sql[last..]);
+    let sql_string = LitStr::new(&rewritten, sql_string.span());
+
+    let binds = args.iter().enumerate().map(|(i, arg)| {
+        let var = format_ident!("sea_orm_arg_{}", i);
+        quote!(let #var = #arg;)
+    });
+
+    let expanded = match literal_backend {
+        Some(backend) => {
+            let builder = query_builder(&backend);
+            quote! {{
+                use sea_orm::sea_query;
+                #(#binds)*
+
+                let query = sea_query::raw_query!(#builder, #sql_string);
+
+                sea_orm::Statement::from_sql_and_values(
+                    sea_orm::DbBackend::#backend,
+                    query.sql,
+                    query.values,
+                )
+            }}
+        }
+        None => {
+            let arms = ["MySql", "Postgres", "Sqlite"].into_iter().map(|backend| {
+                let builder = query_builder(&Ident::new(backend, Span::call_site()));
+                let backend = Ident::new(backend, Span::call_site());
+                quote!(sea_orm::DbBackend::#backend => Ok(sea_query::raw_query!(#builder, #sql_string)),)
+            });
+            quote! {{
+                use sea_orm::sea_query;
+                let __sea_orm_db_backend: sea_orm::DbBackend = #backend;
+                #(#binds)*
+
+                let query = match __sea_orm_db_backend {
+                    #(#arms)*
+                    #[allow(unreachable_patterns)]
+                    db_backend => Err(sea_orm::DbErr::BackendNotSupported {
+                        db: db_backend.as_str(),
+                        ctx: "raw_sql!",
+                    }),
+                };
+
+                query.map(|query| {
+                    sea_orm::Statement::from_sql_and_values(
+                        __sea_orm_db_backend,
+                        query.sql,
+                        query.values,
+                    )
+                })
+            }}
+        }
+    };
+
+    Ok(expanded)
+}
+
+/// The backend if it is spelled out, as `Postgres` or `DbBackend::Postgres`
+fn literal_backend(backend: &Expr) -> Option<Ident> {
+    let Expr::Path(path) = backend else {
+        return None;
+    };
+    let segments: Vec<_> = path.path.segments.iter().map(|s| &s.ident).collect();
+    let (last, prefix) = segments.split_last()?;
+    let is_variant = match prefix.last() {
+        None => true,
+        Some(ident) => *ident == "DbBackend" || *ident == "DatabaseBackend",
+    };
+    (is_variant && matches!(last.to_string().as_str(), "MySql" | "Postgres" | "Sqlite"))
+        .then(|| (*last).clone())
+}
+
+fn query_builder(backend: &Ident) -> TokenStream {
+    match backend.to_string().as_str() {
         "MySql" => quote!(MysqlQueryBuilder),
         "Postgres" => quote!(PostgresQueryBuilder),
-        "Sqlite" => quote!(SqliteQueryBuilder),
-        backend => panic!("Unsupported backend {backend}"),
-    };
+        _ => quote!(SqliteQueryBuilder),
+    }
+}
 
-    Ok(quote! {{
-        use sea_orm::sea_query;
+/// Every argument has to be used, and every placeholder needs an argument
+fn check_placeholders(
+    placeholders: &[(Range<usize>, Placeholder)],
+    sql_string: &LitStr,
+    args: &[Expr],
+) -> syn::Result<()> {
+    let err = |message: String| Err(syn::Error::new(sql_string.span(), message));
+    let unused = |arg: &Expr, message: String| Err(syn::Error::new(arg.span(), message));
 
-        let query = sea_query::raw_query!(#builder, #sql_string);
+    if args.is_empty() {
+        return match placeholders.first() {
+            Some((range, _)) => err(format!(
+                "`{}` has no argument; interpolate the value with `{{name}}`, or pass it after the SQL string",
+                &sql_string.value()[range.clone()]
+            )),
+            None => Ok(()),
+        };
+    }
+
+    let numbered = placeholders
+        .iter()
+        .filter(|(_, p)| matches!(p, Placeholder::Numbered(_)))
+        .count();
+    if numbered > 0 && numbered < placeholders.len() {
+        return err("cannot mix `$N` and `?` placeholders".to_owned());
+    }
 
-        sea_orm::Statement {
-            sql: query.sql,
-            values: Some(query.values),
-            db_backend: sea_orm::DbBackend::#backend,
+    if numbered > 0 {
+        for (_, placeholder) in placeholders {
+            if let Placeholder::Numbered(n) = placeholder {
+                if *n == 0 || *n > args.len() {
+                    return err(format!(
+                        "`${n}` has no argument, {} given",
+                        plural(args.len(), "argument")
+                    ));
+                }
+            }
         }
-    }})
+        for (i, arg) in args.iter().enumerate() {
+            if !placeholders
+                .iter()
+                .any(|(_, p)| *p == Placeholder::Numbered(i + 1))
+            {
+                return unused(arg, format!("argument never used, `${}` is missing", i + 1));
+            }
+        }
+    } else if placeholders.len() > args.len() {
+        return err(format!(
+            "{} but {} given",
+            plural(placeholders.len(), "`?` placeholder"),
+            plural(args.len(), "argument")
+        ));
+    } else if let Some(arg) = args.get(placeholders.len()) {
+        return unused(
+            arg,
+            format!(
+                "argument never used, {} but {} given",
+                plural(placeholders.len(), "placeholder"),
+                plural(args.len(), "argument")
+            ),
+        );
+    }
+
+    Ok(())
+}
+
+fn plural(n: usize, word: &str) -> String {
+    if n == 1 {
+        format!("{n} {word}")
+    } else {
+        format!("{n} {word}s")
+    }
+}
+
+/// Find the `$N` (and `?`, if `anonymous`) placeholders, skipping quoted strings and
+/// identifiers, comments, dollar quoted strings and `{..}` interpolations.
+fn find_placeholders(sql: &str, anonymous: bool) -> Vec<(Range<usize>, Placeholder)> {
+    let bytes = sql.as_bytes();
+    let mut placeholders = Vec::new();
+    let skip_to = |from: usize, end: &str| {
+        sql[from..]
+            .find(end)
+            .map_or(sql.len(), |i| from + i + end.len())
+    };
+
+    let mut i = 0;
+    while i < bytes.len() {
+        i = match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => skip_to(i + 1, &(quote as char).to_string()),
+            b'{' => skip_to(i + 1, "}"),
+            b'-' if bytes.get(i + 1) == Some(&b'-') => skip_to(i + 2, "\n"),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => skip_to(i + 2, "*/"),
+            b'$' if bytes.get(i + 1) == Some(&b'$') => skip_to(i + 2, "$$"),
+            b'$' if bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+                && (i == 0 || !is_identifier(bytes[i - 1])) =>
+            {
+                let end = (i + 1..bytes.len())
+                    .find(|&j| !bytes[j].is_ascii_digit())
+                    .unwrap_or(bytes.len());
+                let n = sql[i + 1..end].parse().unwrap_or(usize::MAX);
+                placeholders.push((i..end, Placeholder::Numbered(n)));
+                end
+            }
+            b'?' if anonymous => {
+                placeholders.push((i..i + 1, Placeholder::Anonymous));
+                i + 1
+            }
+            _ => i + 1,
+        };
+    }
+
+    placeholders
+}
+
+fn is_identifier(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_' || c == b'$'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Placeholder::*, find_placeholders};
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(
+            find_placeholders("SELECT * FROM cake WHERE id = $1 AND name = $12", true),
+            [(30..32, Numbered(1)), (44..47, Numbered(12))]
+        );
+        assert_eq!(
+            find_placeholders("SELECT * FROM cake WHERE id = ? AND name = ?", true),
+            [(30..31, Anonymous), (43..44, Anonymous)]
+        );
+        assert_eq!(
+            find_placeholders("SELECT * FROM cake WHERE data ? 'key'", false),
+            []
+        );
+    }
+
+    #[test]
+    fn placeholders_skipped() {
+        let sql = r#"SELECT '$1 ?', "a$1", `?`, b$2, $$ $3 $$ FROM cake -- $4 ?
+            /* $5 ? */ WHERE id = {id} AND name = $6"#;
+        let start = sql.find("$6").unwrap();
+        assert_eq!(
+            find_placeholders(sql, true),
+            [(start..start + 2, Numbered(6))]
+        );
+    }
 }
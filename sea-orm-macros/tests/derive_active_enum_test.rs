@@ -74,6 +74,28 @@ pub enum TestEnum3 {
     HelloWorld,
 }
 
+#[derive(Debug, EnumIter, DeriveActiveEnum, Eq, PartialEq)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "String(StringLen::None)",
+    rename_all = "snake_case",
+    display_from_str
+)]
+pub enum TestDisplayFromStr {
+    HelloWorld,
+    #[sea_orm(string_value = "hi!")]
+    Hi,
+}
+
+#[derive(Debug, EnumIter, DeriveActiveEnum, Eq, PartialEq)]
+#[sea_orm(db_type = "Enum", enum_name = "tea", display_from_str)]
+pub enum TestDisplayFromStrDbEnum {
+    #[sea_orm(string_value = "EverydayTea")]
+    EverydayTea,
+    #[sea_orm(string_value = "BreakfastTea")]
+    BreakfastTea,
+}
+
 #[test]
 fn derive_active_enum_value() {
     assert_eq!(TestEnum::DefaultVariant.to_value(), "defaultVariant");
@@ -219,3 +241,36 @@ fn derive_non_database_enum_value_type() {
     assert_eq!(TestEnum2::enum_type_name(), None);
     assert_eq!(TestEnum2::array_type(), ArrayType::String);
 }
+
+#[test]
+fn derive_active_enum_display_from_str() {
+    use sea_orm::{Iterable, ParseActiveEnumError};
+
+    assert_eq!(TestDisplayFromStr::HelloWorld.to_string(), "hello_world");
+    assert_eq!(TestDisplayFromStr::Hi.to_string(), "hi!");
+    assert_eq!(
+        TestDisplayFromStrDbEnum::BreakfastTea.to_string(),
+        "BreakfastTea"
+    );
+
+    for variant in TestDisplayFromStr::iter() {
+        assert_eq!(variant.to_string().parse(), Ok(variant));
+    }
+    for variant in TestDisplayFromStrDbEnum::iter() {
+        assert_eq!(variant.to_string().parse(), Ok(variant));
+    }
+
+    let err = "HelloWorld".parse::<TestDisplayFromStr>().unwrap_err();
+    assert_eq!(
+        err,
+        ParseActiveEnumError {
+            enum_name: "TestDisplayFromStr",
+            value: "HelloWorld".to_owned(),
+            variants: &["hello_world", "hi!"],
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "invalid value `HelloWorld` for TestDisplayFromStr, expected one of: hello_world, hi!"
+    );
+}
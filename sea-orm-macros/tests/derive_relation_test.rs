@@ -0,0 +1,6 @@
+#[test]
+fn derive_relation_ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/relation/pass.rs");
+    t.compile_fail("tests/ui/relation/fail_*.rs");
+}
@@ -0,0 +1,58 @@
+mod cake {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "cake")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod fruit {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "fruit")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub name: String,
+        pub cake_id: Option<i32>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[sea_orm(
+            belongs_to = "super::cake::Entity",
+            from = "Column::CakeId",
+            to = "super::cake::Column::Id",
+            on_update = "Cascade",
+            on_delete = "SetNull"
+        )]
+        Cake,
+        // the types of columns not derived by `DeriveEntityModel` are not checked
+        #[sea_orm(
+            belongs_to = "sea_orm::tests_cfg::cake_expanded::Entity",
+            from = "Column::CakeId",
+            to = "sea_orm::tests_cfg::cake_expanded::Column::Id"
+        )]
+        CakeExpanded,
+        #[sea_orm(
+            belongs_to = "super::cake::Entity",
+            from = "(Column::CakeId, Column::Name)",
+            to = "(super::cake::Column::Id, super::cake::Column::Name)"
+        )]
+        CakeByName,
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+fn main() {}
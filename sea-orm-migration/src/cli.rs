@@ -3,12 +3,15 @@ use std::future::Future;
 use clap::Parser;
 use dotenvy::dotenv;
 use std::{error::Error, fmt::Display, process::exit};
+use tracing::info;
 use tracing_subscriber::{EnvFilter, prelude::*};
 
 use sea_orm::{ConnectOptions, Database, DbConn, DbErr};
-use sea_orm_cli::{MigrateSubcommands, run_migrate_generate, run_migrate_init};
+use sea_orm_cli::{
+    MigrateSubcommands, create_migration_file, run_migrate_generate, run_migrate_init,
+};
 
-use super::MigratorTraitSelf;
+use super::{Migration, MigrationStatus, MigratorTraitSelf};
 
 const MIGRATION_DIR: &str = "./";
 
@@ -119,15 +122,100 @@ where
         Some(MigrateSubcommands::Fresh) => migrator.fresh(db).await?,
         Some(MigrateSubcommands::Refresh) => migrator.refresh(db).await?,
         Some(MigrateSubcommands::Reset) => migrator.reset(db).await?,
-        Some(MigrateSubcommands::Status) => migrator.status(db).await?,
+        Some(MigrateSubcommands::Status { json, exit_code }) => {
+            let code = run_migrate_status(&migrator, db, json, exit_code).await?;
+            if exit_code && code != 0 {
+                exit(code);
+            }
+        }
         Some(MigrateSubcommands::Up { num }) => migrator.up(db, num).await?,
         Some(MigrateSubcommands::Down { num }) => migrator.down(db, Some(num)).await?,
+        Some(MigrateSubcommands::MarkApplied { migration_name }) => {
+            migrator.mark_applied(db, &migration_name).await?
+        }
+        Some(MigrateSubcommands::MarkRolledBack { migration_name }) => {
+            migrator.mark_rolled_back(db, &migration_name).await?
+        }
+        Some(MigrateSubcommands::Squash { migration_name }) => {
+            let baseline = migrator.squash_baseline(db, &migration_name).await?;
+            create_migration_file(&baseline.name, MIGRATION_DIR, &baseline.to_migration_file())?;
+            info!(
+                "Replace the squashed migrations with '{}' in the migrator, and run `adopt_baseline` on the other databases",
+                baseline.name
+            );
+        }
         _ => migrator.up(db, None).await?,
     };
 
     Ok(())
 }
 
+/// Check the status of all migrations, printing it to stdout as a JSON array of
+/// `{name, status, applied_at, checksum, duration_ms}` if `json` is set, or logging it otherwise.
+///
+/// Returns the exit code of `status --exit-code`: 1 if any migration is pending,
+/// 2 if the migration table is missing and 0 otherwise. The migration table is
+/// only checked for, rather than created, when `exit_code` is set.
+pub async fn run_migrate_status<M>(
+    migrator: &M,
+    db: &DbConn,
+    json: bool,
+    exit_code: bool,
+) -> Result<i32, Box<dyn Error>>
+where
+    M: MigratorTraitSelf,
+{
+    let has_table = !exit_code || migrator.has_migration_table(db).await?;
+    let migrations = if has_table {
+        migrator.get_migration_with_status(db).await?
+    } else {
+        migrator.get_migration_files()
+    };
+
+    if json {
+        println!("{}", migration_status_json(&migrations));
+    } else {
+        info!("Checking migration status");
+        for migration in migrations.iter() {
+            info!("Migration '{}'... {}", migration.name(), migration.status());
+        }
+    }
+
+    let code = if !has_table {
+        2
+    } else if migrations
+        .iter()
+        .any(|migration| migration.status() == MigrationStatus::Pending)
+    {
+        1
+    } else {
+        0
+    };
+    Ok(code)
+}
+
+/// Describe migrations as a JSON array of `{name, status, applied_at, checksum, duration_ms}`,
+/// where `status` is `"applied"` or `"pending"`, `applied_at` is a Unix timestamp or `null`,
+/// and `checksum` and `duration_ms` are as recorded when the migration was applied, or `null`
+pub fn migration_status_json(migrations: &[Migration]) -> serde_json::Value {
+    migrations
+        .iter()
+        .map(|migration| {
+            let status = match migration.status() {
+                MigrationStatus::Applied => "applied",
+                MigrationStatus::Pending => "pending",
+            };
+            serde_json::json!({
+                "name": migration.name(),
+                "status": status,
+                "applied_at": migration.applied_at(),
+                "checksum": migration.checksum(),
+                "duration_ms": migration.duration_ms(),
+            })
+        })
+        .collect()
+}
+
 fn run_non_db_command(command: Option<&MigrateSubcommands>) -> Result<bool, Box<dyn Error>> {
     match command {
         Some(MigrateSubcommands::Init) => {
@@ -6,11 +6,14 @@ pub mod migrator;
 pub mod prelude;
 pub mod schema;
 pub mod seaql_migrations;
+mod seed;
+mod table_op;
 pub mod util;
 
 pub use connection::*;
 pub use manager::*;
 pub use migrator::*;
+pub use table_op::*;
 
 pub use async_trait;
 pub use sea_orm;
@@ -25,11 +28,65 @@ pub trait MigrationName {
 #[async_trait::async_trait]
 pub trait MigrationTrait: MigrationName + Send + Sync {
     /// Define actions to perform when applying the migration
-    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr>;
+    ///
+    /// Defaults to applying the operations of [`MigrationTrait::schema`] in order.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let ops = self.schema();
+        if ops.is_empty() {
+            return Err(DbErr::Migration(format!(
+                "Migration `{}` defines neither `up` nor `schema`",
+                self.name()
+            )));
+        }
+        for op in ops.iter() {
+            op.apply(manager).await?;
+        }
+        Ok(())
+    }
 
     /// Define actions to perform when rolling back the migration
-    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
-        Err(DbErr::Migration("We Don't Do That Here".to_owned()))
+    ///
+    /// Defaults to reverting the operations of [`MigrationTrait::schema`] in reverse order.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let ops = self.schema();
+        if ops.is_empty() {
+            return Err(DbErr::Migration("We Don't Do That Here".to_owned()));
+        }
+        for op in ops.iter().rev() {
+            op.revert(manager, &ops).await?;
+        }
+        Ok(())
+    }
+
+    /// Declare the schema operations of this migration, from which `up` and `down` are derived.
+    /// Override `up` and `down` instead for data migrations.
+    ///
+    /// ```
+    /// use sea_orm_migration::{prelude::*, schema::*};
+    ///
+    /// #[derive(DeriveMigrationName)]
+    /// pub struct Migration;
+    ///
+    /// impl MigrationTrait for Migration {
+    ///     fn schema(&self) -> Vec<TableOp> {
+    ///         vec![
+    ///             table_auto("cake")
+    ///                 .col(pk_auto("id"))
+    ///                 .col(string("name"))
+    ///                 .to_owned()
+    ///                 .into(),
+    ///             Index::create()
+    ///                 .name("idx-cake-name")
+    ///                 .table("cake")
+    ///                 .col("name")
+    ///                 .to_owned()
+    ///                 .into(),
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    fn schema(&self) -> Vec<TableOp> {
+        Vec::new()
     }
 
     /// Control whether this migration runs inside a transaction.
@@ -40,4 +97,32 @@ pub trait MigrationTrait: MigrationName + Send + Sync {
     fn use_transaction(&self) -> Option<bool> {
         None
     }
+
+    /// A checksum of the migration, recorded in the migration table when it's applied,
+    /// e.g. for auditing that the applied migrations are the ones in the source tree.
+    /// `None` by default.
+    ///
+    /// ```
+    /// use sea_orm_migration::prelude::*;
+    ///
+    /// #[derive(DeriveMigrationName)]
+    /// pub struct Migration;
+    ///
+    /// // bumped whenever the migration is changed
+    /// const REVISION: &str = "2";
+    ///
+    /// #[async_trait::async_trait]
+    /// impl MigrationTrait for Migration {
+    ///     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn checksum(&self) -> Option<String> {
+    ///         Some(format!("{}@{REVISION}", self.name()))
+    ///     }
+    /// }
+    /// ```
+    fn checksum(&self) -> Option<String> {
+        None
+    }
 }
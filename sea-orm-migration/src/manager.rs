@@ -1,11 +1,20 @@
 use super::{IntoSchemaManagerConnection, SchemaManagerConnection};
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-postgres",
+    feature = "sqlx-sqlite"
+))]
+use sea_orm::Statement;
 use sea_orm::sea_query::{
     ForeignKeyCreateStatement, ForeignKeyDropStatement, IndexCreateStatement, IndexDropStatement,
     SelectStatement, TableAlterStatement, TableCreateStatement, TableDropStatement,
     TableRenameStatement, TableTruncateStatement,
     extension::postgres::{TypeAlterStatement, TypeCreateStatement, TypeDropStatement},
 };
-use sea_orm::{ConnectionTrait, DbBackend, DbErr, StatementBuilder, TransactionTrait};
+use sea_orm::{
+    ConnectionTrait, DatabaseTransaction, DbBackend, DbErr, StatementBuilder, TransactionError,
+    TransactionTrait,
+};
 #[allow(unused_imports)]
 use sea_schema::probe::SchemaProbe;
 
@@ -72,6 +81,26 @@ impl SchemaManager<'_> {
     }
 }
 
+/// Seed Data
+impl SchemaManager<'_> {
+    /// Insert seed data inside a transaction, a savepoint if the migration is already running in one.
+    ///
+    /// Everything inserted by `seed` is rolled back if it returns an error, which is then returned as is.
+    /// Pair it with entities declared by [`migration_entity!`](crate::migration_entity) to keep
+    /// the migration decoupled from the application entities.
+    pub async fn seed<F>(&self, seed: F) -> Result<(), DbErr>
+    where
+        F: for<'c> AsyncFnOnce(&'c DatabaseTransaction) -> Result<(), DbErr> + Send,
+    {
+        self.conn
+            .transaction_async(seed)
+            .await
+            .map_err(|err| match err {
+                TransactionError::Connection(err) | TransactionError::Transaction(err) => err,
+            })
+    }
+}
+
 /// Schema Creation
 impl SchemaManager<'_> {
     pub async fn create_table(&self, stmt: TableCreateStatement) -> Result<(), DbErr> {
@@ -135,35 +164,12 @@ impl SchemaManager<'_> {
         has_table(&self.conn, table).await
     }
 
-    pub async fn has_column<T, C>(&self, _table: T, _column: C) -> Result<bool, DbErr>
+    pub async fn has_column<T, C>(&self, table: T, column: C) -> Result<bool, DbErr>
     where
         T: AsRef<str>,
         C: AsRef<str>,
     {
-        let _stmt: SelectStatement = match self.conn.get_database_backend() {
-            #[cfg(feature = "sqlx-mysql")]
-            DbBackend::MySql => sea_schema::mysql::MySql.has_column(_table, _column),
-            #[cfg(feature = "sqlx-postgres")]
-            DbBackend::Postgres => sea_schema::postgres::Postgres.has_column(_table, _column),
-            #[cfg(feature = "sqlx-sqlite")]
-            DbBackend::Sqlite => sea_schema::sqlite::Sqlite.has_column(_table, _column),
-            #[allow(unreachable_patterns)]
-            other => {
-                return Err(DbErr::BackendNotSupported {
-                    db: other.as_str(),
-                    ctx: "has_column",
-                });
-            }
-        };
-
-        #[allow(unreachable_code)]
-        let res = self
-            .conn
-            .query_one(&_stmt)
-            .await?
-            .ok_or_else(|| DbErr::Custom("Failed to check column exists".to_owned()))?;
-
-        res.try_get("", "has_column")
+        has_column(&self.conn, table, column).await
     }
 
     pub async fn has_index<T, I>(&self, _table: T, _index: I) -> Result<bool, DbErr>
@@ -196,6 +202,84 @@ impl SchemaManager<'_> {
 
         res.try_get("", "has_index")
     }
+
+    /// Get the definition of an index on `table`, `None` if there is no such index.
+    ///
+    /// Compare it to the expected definition to decide whether a migration has to rebuild the
+    /// index, e.g. a partial index created with `Index::create().and_where(..)` or an
+    /// expression index created with `Index::create().col(Expr::cust("lower(name)"))`.
+    pub async fn get_index_definition<T, I>(
+        &self,
+        _table: T,
+        _index: I,
+    ) -> Result<Option<IndexDefinition>, DbErr>
+    where
+        T: AsRef<str>,
+        I: AsRef<str>,
+    {
+        match self.conn.get_database_backend() {
+            #[cfg(feature = "sqlx-mysql")]
+            DbBackend::MySql => {
+                mysql_index_definition(&self.conn, _table.as_ref(), _index.as_ref()).await
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            DbBackend::Postgres => {
+                postgres_index_definition(&self.conn, _table.as_ref(), _index.as_ref()).await
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            DbBackend::Sqlite => {
+                sqlite_index_definition(&self.conn, _table.as_ref(), _index.as_ref()).await
+            }
+            #[allow(unreachable_patterns)]
+            other => Err(DbErr::BackendNotSupported {
+                db: other.as_str(),
+                ctx: "get_index_definition",
+            }),
+        }
+    }
+}
+
+/// Definition of an index as reported by the database, see [`SchemaManager::get_index_definition`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexDefinition {
+    /// Whether the index is unique
+    pub unique: bool,
+    /// Indexed columns and expressions, in order
+    pub columns: Vec<IndexedColumn>,
+    /// The `WHERE` clause of a partial index, `None` if the index covers the whole table.
+    /// Postgres reports it normalised, e.g. `(deleted_at IS NULL)`; SQLite as written in
+    /// `CREATE INDEX`. MySQL has no partial index.
+    pub predicate: Option<String>,
+}
+
+/// A part of an index: a column or an expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndexedColumn {
+    /// A table column
+    Column(String),
+    /// An expression, e.g. `lower((name)::text)` on Postgres, normalised by the database
+    /// except on SQLite, where it is reported as written in `CREATE INDEX`
+    Expression(String),
+}
+
+impl IndexDefinition {
+    /// Names of the indexed table columns, skipping expressions
+    pub fn column_names(&self) -> Vec<&str> {
+        self.columns
+            .iter()
+            .filter_map(|column| match column {
+                IndexedColumn::Column(name) => Some(name.as_str()),
+                IndexedColumn::Expression(_) => None,
+            })
+            .collect()
+    }
+
+    /// Whether any part of the index is an expression
+    pub fn has_expression(&self) -> bool {
+        self.columns
+            .iter()
+            .any(|column| matches!(column, IndexedColumn::Expression(_)))
+    }
 }
 
 pub(crate) async fn has_table<C, T>(conn: &C, _table: T) -> Result<bool, DbErr>
@@ -227,3 +311,272 @@ where
 
     res.try_get("", "has_table")
 }
+
+pub(crate) async fn has_column<C, T, N>(conn: &C, _table: T, _column: N) -> Result<bool, DbErr>
+where
+    C: ConnectionTrait,
+    T: AsRef<str>,
+    N: AsRef<str>,
+{
+    let _stmt: SelectStatement = match conn.get_database_backend() {
+        #[cfg(feature = "sqlx-mysql")]
+        DbBackend::MySql => sea_schema::mysql::MySql.has_column(_table, _column),
+        #[cfg(feature = "sqlx-postgres")]
+        DbBackend::Postgres => sea_schema::postgres::Postgres.has_column(_table, _column),
+        #[cfg(feature = "sqlx-sqlite")]
+        DbBackend::Sqlite => sea_schema::sqlite::Sqlite.has_column(_table, _column),
+        #[allow(unreachable_patterns)]
+        other => {
+            return Err(DbErr::BackendNotSupported {
+                db: other.as_str(),
+                ctx: "has_column",
+            });
+        }
+    };
+
+    #[allow(unreachable_code)]
+    let res = conn
+        .query_one(&_stmt)
+        .await?
+        .ok_or_else(|| DbErr::Custom("Failed to check column exists".to_owned()))?;
+
+    res.try_get("", "has_column")
+}
+
+#[cfg(feature = "sqlx-mysql")]
+async fn mysql_index_definition<C>(
+    conn: &C,
+    table: &str,
+    index: &str,
+) -> Result<Option<IndexDefinition>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    // `EXPRESSION` only exists on MySQL 8.0.13+, hence `s.*`
+    let rows = conn
+        .query_all_raw(Statement::from_sql_and_values(
+            DbBackend::MySql,
+            r#"SELECT CAST(`s`.`NON_UNIQUE` AS SIGNED) AS `non_unique`, `s`.*
+            FROM `information_schema`.`STATISTICS` AS `s`
+            WHERE `s`.`TABLE_SCHEMA` = DATABASE() AND `s`.`TABLE_NAME` = ? AND `s`.`INDEX_NAME` = ?
+            ORDER BY `s`.`SEQ_IN_INDEX`"#,
+            [table.into(), index.into()],
+        ))
+        .await?;
+    let Some(first) = rows.first() else {
+        return Ok(None);
+    };
+    let unique = first.try_get::<i64>("", "non_unique")? == 0;
+    let columns = rows
+        .iter()
+        .map(
+            |row| match row.try_get::<Option<String>>("", "COLUMN_NAME")? {
+                Some(name) => Ok(IndexedColumn::Column(name)),
+                None => row
+                    .try_get::<Option<String>>("", "EXPRESSION")
+                    .ok()
+                    .flatten()
+                    .map(IndexedColumn::Expression)
+                    .ok_or_else(|| {
+                        DbErr::Custom(format!("Failed to get expression of index {index}"))
+                    }),
+            },
+        )
+        .collect::<Result<_, DbErr>>()?;
+
+    Ok(Some(IndexDefinition {
+        unique,
+        columns,
+        predicate: None,
+    }))
+}
+
+#[cfg(feature = "sqlx-postgres")]
+async fn postgres_index_definition<C>(
+    conn: &C,
+    table: &str,
+    index: &str,
+) -> Result<Option<IndexDefinition>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    // one row per key column, `attnum` is 0 for expressions
+    let rows = conn
+        .query_all_raw(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT "ix"."indisunique" AS "is_unique",
+                pg_get_expr("ix"."indpred", "ix"."indrelid") AS "predicate",
+                "k"."attnum" = 0 AS "is_expression",
+                pg_get_indexdef("ix"."indexrelid", "k"."ord"::int, true) AS "definition"
+            FROM "pg_index" AS "ix"
+            JOIN "pg_class" AS "idx" ON "idx"."oid" = "ix"."indexrelid"
+            JOIN "pg_class" AS "tbl" ON "tbl"."oid" = "ix"."indrelid"
+            JOIN "pg_namespace" AS "ns" ON "ns"."oid" = "tbl"."relnamespace"
+            CROSS JOIN LATERAL unnest("ix"."indkey"::int2[]) WITH ORDINALITY AS "k"("attnum", "ord")
+            WHERE "ns"."nspname" = current_schema() AND "tbl"."relname" = $1
+                AND "idx"."relname" = $2 AND "k"."ord" <= "ix"."indnkeyatts"
+            ORDER BY "k"."ord""#,
+            [table.into(), index.into()],
+        ))
+        .await?;
+    let Some(first) = rows.first() else {
+        return Ok(None);
+    };
+    let unique = first.try_get("", "is_unique")?;
+    let predicate = first.try_get("", "predicate")?;
+    let columns = rows
+        .iter()
+        .map(|row| {
+            let definition: String = row.try_get("", "definition")?;
+            Ok(if row.try_get("", "is_expression")? {
+                IndexedColumn::Expression(definition)
+            } else {
+                IndexedColumn::Column(definition)
+            })
+        })
+        .collect::<Result<_, DbErr>>()?;
+
+    Ok(Some(IndexDefinition {
+        unique,
+        columns,
+        predicate,
+    }))
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+async fn sqlite_index_definition<C>(
+    conn: &C,
+    table: &str,
+    index: &str,
+) -> Result<Option<IndexDefinition>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(index_row) = conn
+        .query_one_raw(Statement::from_sql_and_values(
+            DbBackend::Sqlite,
+            r#"SELECT "il"."unique", "il"."partial", "m"."sql"
+            FROM pragma_index_list(?) AS "il"
+            LEFT JOIN "sqlite_master" AS "m" ON "m"."type" = 'index' AND "m"."name" = "il"."name"
+            WHERE "il"."name" = ?"#,
+            [table.into(), index.into()],
+        ))
+        .await?
+    else {
+        return Ok(None);
+    };
+    let unique = index_row.try_get::<i32>("", "unique")? != 0;
+    let partial = index_row.try_get::<i32>("", "partial")? != 0;
+    // indexes backing constraints have no `CREATE INDEX` statement
+    let sql: Option<String> = index_row.try_get("", "sql")?;
+    let parsed = sql.as_deref().and_then(split_sqlite_create_index);
+
+    // `cid` is -2 for expressions, whose text is only found in `CREATE INDEX`
+    let column_rows = conn
+        .query_all_raw(Statement::from_sql_and_values(
+            DbBackend::Sqlite,
+            r#"SELECT "cid", "name" FROM pragma_index_info(?) ORDER BY "seqno""#,
+            [index.into()],
+        ))
+        .await?;
+    let columns = column_rows
+        .iter()
+        .enumerate()
+        .map(
+            |(i, row)| match row.try_get::<Option<String>>("", "name")? {
+                Some(name) => Ok(IndexedColumn::Column(name)),
+                None => parsed
+                    .as_ref()
+                    .and_then(|(parts, _)| parts.get(i))
+                    .map(|part| IndexedColumn::Expression(unwrap_parens(part).to_owned()))
+                    .ok_or_else(|| {
+                        DbErr::Custom(format!("Failed to get expression of index {index}"))
+                    }),
+            },
+        )
+        .collect::<Result<_, DbErr>>()?;
+
+    let predicate = match (partial, parsed) {
+        (true, Some((_, predicate))) => predicate.map(ToOwned::to_owned),
+        _ => None,
+    };
+
+    Ok(Some(IndexDefinition {
+        unique,
+        columns,
+        predicate,
+    }))
+}
+
+/// Split a SQLite `CREATE INDEX` statement into the parts of its column list and its
+/// `WHERE` clause, skipping over quoted identifiers and string literals
+#[cfg(feature = "sqlx-sqlite")]
+fn split_sqlite_create_index(sql: &str) -> Option<(Vec<&str>, Option<&str>)> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    let mut quote = None;
+    for (i, c) in sql.char_indices() {
+        if let Some(end) = quote {
+            if c == end {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '\'' | '"' | '`' => quote = Some(c),
+            '[' => quote = Some(']'),
+            '(' => {
+                depth += 1;
+                if depth == 1 {
+                    start = i + 1;
+                }
+            }
+            ',' if depth == 1 => {
+                parts.push(sql[start..i].trim());
+                start = i + 1;
+            }
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    parts.push(sql[start..i].trim());
+                    let rest = sql[i + 1..].trim();
+                    let predicate = rest
+                        .get(..5)
+                        .filter(|keyword| keyword.eq_ignore_ascii_case("WHERE"))
+                        .map(|_| rest[5..].trim());
+                    return Some((parts, predicate));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Remove the parentheses around an expression key part, e.g. `(lower("name"))`
+#[cfg(feature = "sqlx-sqlite")]
+fn unwrap_parens(part: &str) -> &str {
+    let Some(inner) = part.strip_prefix('(').and_then(|p| p.strip_suffix(')')) else {
+        return part;
+    };
+    let mut depth = 0;
+    let mut quote = None;
+    for c in inner.chars() {
+        if let Some(end) = quote {
+            if c == end {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '\'' | '"' | '`' => quote = Some(c),
+            '[' => quote = Some(']'),
+            '(' => depth += 1,
+            ')' if depth == 0 => return part,
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    inner.trim()
+}
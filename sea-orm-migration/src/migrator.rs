@@ -3,15 +3,27 @@ mod queries;
 mod exec;
 use exec::*;
 
+mod lock;
+use lock::*;
+
+mod multi;
+pub use multi::*;
+
+mod squash;
+pub use squash::SquashedBaseline;
+use squash::*;
+
 mod with_self;
 pub use with_self::*;
 
 use std::fmt::Display;
 use tracing::info;
 
-use super::{IntoSchemaManagerConnection, MigrationTrait, SchemaManager, seaql_migrations};
+use super::{
+    IntoSchemaManagerConnection, MigrationTrait, SchemaManager, has_table, seaql_migrations,
+};
 use sea_orm::sea_query::IntoIden;
-use sea_orm::{ConnectionTrait, DbErr, DynIden};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, DynIden};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 /// Status of migration
@@ -35,6 +47,9 @@ impl Display for MigrationStatus {
 pub struct Migration {
     migration: Box<dyn MigrationTrait>,
     status: MigrationStatus,
+    applied_at: Option<i64>,
+    checksum: Option<String>,
+    duration_ms: Option<i64>,
 }
 
 impl Migration {
@@ -47,6 +62,21 @@ impl Migration {
     pub fn status(&self) -> MigrationStatus {
         self.status
     }
+
+    /// Get the Unix timestamp at which the migration was applied, if it was
+    pub fn applied_at(&self) -> Option<i64> {
+        self.applied_at
+    }
+
+    /// Get the checksum recorded when the migration was applied, if any
+    pub fn checksum(&self) -> Option<&str> {
+        self.checksum.as_deref()
+    }
+
+    /// Get how long applying the migration took in milliseconds, if it was recorded
+    pub fn duration_ms(&self) -> Option<i64> {
+        self.duration_ms
+    }
 }
 
 /// Performing migrations on a database
@@ -67,6 +97,9 @@ pub trait MigratorTrait: Send {
             .map(|migration| Migration {
                 migration,
                 status: MigrationStatus::Pending,
+                applied_at: None,
+                checksum: None,
+                duration_ms: None,
             })
             .collect()
     }
@@ -118,6 +151,14 @@ pub trait MigratorTrait: Send {
             .collect())
     }
 
+    /// Check whether the migration table exists, without creating it
+    async fn has_migration_table<C>(db: &C) -> Result<bool, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        has_table(db, Self::migration_table_name().to_string()).await
+    }
+
     /// Create migration table `seaql_migrations` in the database
     async fn install<C>(db: &C) -> Result<(), DbErr>
     where
@@ -135,7 +176,10 @@ pub trait MigratorTrait: Send {
 
         info!("Checking migration status");
 
-        for Migration { migration, status } in Self::get_migration_with_status(db).await? {
+        for Migration {
+            migration, status, ..
+        } in Self::get_migration_with_status(db).await?
+        {
             info!("Migration '{}'... {}", migration.name(), status);
         }
 
@@ -195,6 +239,28 @@ pub trait MigratorTrait: Send {
         exec_up::<Self>(&manager, steps).await
     }
 
+    /// Apply pending migrations while holding a database-wide lock, so that concurrent
+    /// callers (e.g. several app instances booting at once) apply them only once. Whoever
+    /// acquires the lock last waits, then finds nothing left to apply.
+    ///
+    /// The lock is keyed by the migration table name. It is an advisory lock on Postgres
+    /// and `GET_LOCK` on MySQL, both held on a second connection from the pool. On SQLite
+    /// the migrations are run in a `BEGIN IMMEDIATE` transaction, and waiting is bounded by
+    /// the busy timeout.
+    async fn up_with_lock<'c, C>(db: C, steps: Option<u32>) -> Result<(), DbErr>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        let db = db.into_database_executor();
+        let lock = MigrationLock::acquire(&db, Self::migration_table_name()).await?;
+        let res = match lock.connection() {
+            Some(txn) => exec_up::<Self>(&SchemaManager::new(txn), steps).await,
+            None => exec_up::<Self>(&SchemaManager::new(db), steps).await,
+        };
+        let released = lock.release(res.is_ok()).await;
+        res.and(released)
+    }
+
     /// Rollback applied migrations
     async fn down<'c, C>(db: C, steps: Option<u32>) -> Result<(), DbErr>
     where
@@ -204,6 +270,157 @@ pub trait MigratorTrait: Send {
         let manager = SchemaManager::new(db);
         exec_down::<Self>(&manager, steps).await
     }
+
+    /// Record a migration as applied without running it, e.g. when adopting
+    /// migrations on a database whose schema already exists. A warning is
+    /// logged if an earlier migration is still pending.
+    async fn mark_applied<'c, C>(db: C, name: &str) -> Result<(), DbErr>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        let db = db.into_database_executor();
+        Self::install(&db).await?;
+        exec_mark_applied(
+            &db,
+            Self::get_migration_with_status(&db).await?,
+            name,
+            Self::migration_table_name(),
+        )
+        .await
+    }
+
+    /// Remove the record of an applied migration without rolling it back, e.g.
+    /// after manually undoing a migration that failed halfway. A warning is
+    /// logged if a later migration is still applied.
+    async fn mark_rolled_back<'c, C>(db: C, name: &str) -> Result<(), DbErr>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        let db = db.into_database_executor();
+        Self::install(&db).await?;
+        exec_mark_rolled_back(
+            &db,
+            Self::get_migration_with_status(&db).await?,
+            name,
+            Self::migration_table_name(),
+        )
+        .await
+    }
+
+    /// Squash the migrations up to and including `up_to` into a baseline migration,
+    /// e.g. to speed up setting up a fresh database after a few hundred migrations.
+    ///
+    /// The database must have all migrations up to `up_to` applied, and none after it.
+    /// The schema they created is captured as DDL statements, returned in a
+    /// [`SquashedBaseline`] to be saved with [`SquashedBaseline::to_migration_file`].
+    /// Then the records of the squashed migrations in the migration table are replaced
+    /// by one of the baseline, as done by [`adopt_baseline`](Self::adopt_baseline).
+    ///
+    /// Replace the squashed migrations by the baseline in [`migrations`](Self::migrations),
+    /// so a fresh database runs the baseline then the later migrations, and call
+    /// [`adopt_baseline`](Self::adopt_baseline) on the other existing databases.
+    ///
+    /// Only Postgres is supported for now. Tables, enum types, foreign keys and indexes
+    /// of the current schema are captured, but not data, e.g. rows seeded by migrations.
+    ///
+    /// Unlike the other methods, the returned future is not `Send`, as the schema
+    /// discovery it runs isn't.
+    fn squash_baseline<'c, C>(
+        db: C,
+        up_to: &str,
+    ) -> impl Future<Output = Result<SquashedBaseline, DbErr>>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        async move {
+            let db = db.into_database_executor();
+            Self::install(&db).await?;
+            exec_squash_baseline(
+                &db,
+                Self::get_migration_with_status(&db).await?,
+                up_to,
+                Self::migration_table_name(),
+            )
+            .await
+        }
+    }
+
+    /// Replace the records of the migrations squashed by [`squash_baseline`](Self::squash_baseline)
+    /// with one of the baseline `name`, so an existing database sees the baseline as applied.
+    /// Does nothing if the baseline is already recorded.
+    ///
+    /// ```ignore
+    /// Migrator::adopt_baseline(
+    ///     db,
+    ///     "m20240101_000001_create_cake_baseline",
+    ///     m20240101_000001_create_cake_baseline::SQUASHED,
+    /// )
+    /// .await?;
+    /// ```
+    async fn adopt_baseline<'c, C>(db: C, name: &str, squashed: &[&str]) -> Result<(), DbErr>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        let db = db.into_database_executor();
+        Self::install(&db).await?;
+        exec_adopt_baseline(
+            &db,
+            Self::get_migration_models(&db).await?,
+            name,
+            squashed,
+            Self::migration_table_name(),
+        )
+        .await
+    }
+
+    /// Apply pending migrations to each of the databases, e.g. the shards of a
+    /// multi-tenant deployment. A failure is reported in the returned summary
+    /// instead of aborting the other databases, unless
+    /// [`fail_fast`](MigrateAllOptions::fail_fast) is set.
+    async fn up_all(
+        connections: &[DatabaseConnection],
+        steps: Option<u32>,
+        options: MigrateAllOptions,
+    ) -> MigrateAllReport<()> {
+        run_all(connections, &options, |db| async move {
+            if options.lock {
+                Self::up_with_lock(db, steps).await
+            } else {
+                Self::up(db, steps).await
+            }
+        })
+        .await
+    }
+
+    /// Rollback applied migrations on each of the databases, see [`up_all`](Self::up_all)
+    async fn down_all(
+        connections: &[DatabaseConnection],
+        steps: Option<u32>,
+        options: MigrateAllOptions,
+    ) -> MigrateAllReport<()> {
+        run_all(connections, &options, |db| Self::down(db, steps)).await
+    }
+
+    /// Drop all tables from each of the databases, then reapply all migrations,
+    /// see [`up_all`](Self::up_all)
+    async fn fresh_all(
+        connections: &[DatabaseConnection],
+        options: MigrateAllOptions,
+    ) -> MigrateAllReport<()> {
+        run_all(connections, &options, |db| Self::fresh(db)).await
+    }
+
+    /// Get the list of migrations with status of each of the databases,
+    /// see [`up_all`](Self::up_all)
+    async fn status_all(
+        connections: &[DatabaseConnection],
+        options: MigrateAllOptions,
+    ) -> MigrateAllReport<Vec<Migration>> {
+        run_all(connections, &options, |db| {
+            Self::get_migration_with_status(db)
+        })
+        .await
+    }
 }
 
 async fn exec_fresh<M>(manager: &SchemaManager<'_>) -> Result<(), DbErr>
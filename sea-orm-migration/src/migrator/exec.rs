@@ -1,16 +1,17 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 #[cfg(not(feature = "with-time"))]
 use std::time::SystemTime;
-use tracing::info;
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
 
 use super::{Migration, MigrationStatus, queries::*};
-use crate::{SchemaManager, seaql_migrations};
+use crate::{SchemaManager, has_column, seaql_migrations};
 use sea_orm::sea_query::{
     Alias, Expr, ExprTrait, ForeignKey, IntoIden, Order, Query, Table, extension::postgres::Type,
 };
 use sea_orm::{
     ActiveValue, ConnectionTrait, DbBackend, DbErr, DynIden, EntityTrait, FromQueryResult,
-    Iterable, QueryFilter, Schema, Statement, TransactionSession, TransactionTrait,
+    IdenStatic, Iterable, QueryFilter, Schema, Statement, TransactionSession, TransactionTrait,
 };
 
 pub async fn get_migration_models<C>(
@@ -39,10 +40,11 @@ pub fn get_migration_with_status(
 ) -> Result<Vec<Migration>, DbErr> {
     let mut migration_files = migration_files;
 
-    let migration_in_db: HashSet<String> = migration_models
+    let migration_models: HashMap<String, seaql_migrations::Model> = migration_models
         .into_iter()
-        .map(|model| model.version)
+        .map(|model| (model.version.clone(), model))
         .collect();
+    let migration_in_db: HashSet<String> = migration_models.keys().cloned().collect();
     let migration_in_fs: HashSet<String> = migration_files
         .iter()
         .map(|file| file.migration.name().to_string())
@@ -52,6 +54,11 @@ pub fn get_migration_with_status(
     for migration_file in migration_files.iter_mut() {
         if !pending_migrations.contains(migration_file.migration.name()) {
             migration_file.status = MigrationStatus::Applied;
+            if let Some(model) = migration_models.get(migration_file.migration.name()) {
+                migration_file.applied_at = Some(model.applied_at);
+                migration_file.checksum = model.checksum.clone();
+                migration_file.duration_ms = model.duration_ms;
+            }
         }
     }
 
@@ -77,9 +84,29 @@ where
     let schema = Schema::new(builder);
     let mut stmt = schema
         .create_table_from_entity(seaql_migrations::Entity)
-        .table_name(migration_table_name);
+        .table_name(migration_table_name.clone());
     stmt.if_not_exists();
     db.execute(&stmt).await?;
+
+    // upgrade a table created before these columns were added
+    for column in [
+        seaql_migrations::Column::Checksum,
+        seaql_migrations::Column::DurationMs,
+    ] {
+        let missing = match has_column(db, migration_table_name.to_string(), column.as_str()).await
+        {
+            Ok(exists) => !exists,
+            // the table can't be inspected without a SQLx driver, e.g. through a proxy
+            Err(DbErr::BackendNotSupported { .. }) => false,
+            Err(err) => return Err(err),
+        };
+        if missing {
+            let mut stmt = Table::alter();
+            stmt.table(migration_table_name.clone())
+                .add_column(schema.get_column_def::<seaql_migrations::Entity>(column));
+            db.execute(&stmt).await?;
+        }
+    }
     Ok(())
 }
 
@@ -188,21 +215,47 @@ fn should_use_transaction(migration: &dyn crate::MigrationTrait, backend: DbBack
     }
 }
 
+#[cfg(not(feature = "with-time"))]
+fn now_timestamp() -> i64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("SystemTime before UNIX EPOCH!")
+        .as_secs() as i64
+}
+
+#[cfg(feature = "with-time")]
+fn now_timestamp() -> i64 {
+    sea_orm::prelude::TimeDateTimeWithTimeZone::now_utc().unix_timestamp()
+}
+
 async fn insert_migration_record<C: ConnectionTrait>(
+    db: &C,
+    migration: &dyn crate::MigrationTrait,
+    duration: Option<Duration>,
+    migration_table_name: DynIden,
+) -> Result<(), DbErr> {
+    seaql_migrations::Entity::insert(seaql_migrations::ActiveModel {
+        version: ActiveValue::Set(migration.name().to_owned()),
+        applied_at: ActiveValue::Set(now_timestamp()),
+        checksum: ActiveValue::Set(migration.checksum()),
+        duration_ms: ActiveValue::Set(duration.map(|duration| duration.as_millis() as i64)),
+    })
+    .table_name(migration_table_name)
+    .exec(db)
+    .await?;
+    Ok(())
+}
+
+pub async fn insert_baseline_record<C: ConnectionTrait>(
     db: &C,
     name: &str,
     migration_table_name: DynIden,
 ) -> Result<(), DbErr> {
-    #[cfg(not(feature = "with-time"))]
-    let applied_at = SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .expect("SystemTime before UNIX EPOCH!")
-        .as_secs() as i64;
-    #[cfg(feature = "with-time")]
-    let applied_at = sea_orm::prelude::TimeDateTimeWithTimeZone::now_utc().unix_timestamp();
     seaql_migrations::Entity::insert(seaql_migrations::ActiveModel {
         version: ActiveValue::Set(name.to_owned()),
-        applied_at: ActiveValue::Set(applied_at),
+        applied_at: ActiveValue::Set(now_timestamp()),
+        checksum: ActiveValue::Set(None),
+        duration_ms: ActiveValue::Set(None),
     })
     .table_name(migration_table_name)
     .exec(db)
@@ -210,7 +263,7 @@ async fn insert_migration_record<C: ConnectionTrait>(
     Ok(())
 }
 
-async fn delete_migration_record<C: ConnectionTrait>(
+pub async fn delete_migration_record<C: ConnectionTrait>(
     db: &C,
     name: &str,
     migration_table_name: DynIden,
@@ -223,6 +276,75 @@ async fn delete_migration_record<C: ConnectionTrait>(
     Ok(())
 }
 
+pub fn find_migration(migrations: &[Migration], name: &str) -> Result<usize, DbErr> {
+    migrations
+        .iter()
+        .position(|file| file.migration.name() == name)
+        .ok_or_else(|| DbErr::Custom(format!("Migration '{name}' is not found in the migrator")))
+}
+
+pub async fn exec_mark_applied<C: ConnectionTrait>(
+    db: &C,
+    migrations: Vec<Migration>,
+    name: &str,
+    migration_table_name: DynIden,
+) -> Result<(), DbErr> {
+    let index = find_migration(&migrations, name)?;
+    if migrations[index].status == MigrationStatus::Applied {
+        return Err(DbErr::Custom(format!(
+            "Migration '{name}' has already been applied"
+        )));
+    }
+    if let Some(pending) = migrations[..index]
+        .iter()
+        .find(|file| file.status == MigrationStatus::Pending)
+    {
+        warn!(
+            "Marking migration '{}' as applied out of order, '{}' is still pending",
+            name,
+            pending.migration.name()
+        );
+    }
+
+    insert_migration_record(
+        db,
+        migrations[index].migration.as_ref(),
+        None,
+        migration_table_name,
+    )
+    .await?;
+    info!("Migration '{}' has been marked as applied", name);
+    Ok(())
+}
+
+pub async fn exec_mark_rolled_back<C: ConnectionTrait>(
+    db: &C,
+    migrations: Vec<Migration>,
+    name: &str,
+    migration_table_name: DynIden,
+) -> Result<(), DbErr> {
+    let index = find_migration(&migrations, name)?;
+    if migrations[index].status == MigrationStatus::Pending {
+        return Err(DbErr::Custom(format!(
+            "Migration '{name}' has not been applied"
+        )));
+    }
+    if let Some(applied) = migrations[index + 1..]
+        .iter()
+        .find(|file| file.status == MigrationStatus::Applied)
+    {
+        warn!(
+            "Marking migration '{}' as rolled back out of order, '{}' is still applied",
+            name,
+            applied.migration.name()
+        );
+    }
+
+    delete_migration_record(db, name, migration_table_name).await?;
+    info!("Migration '{}' has been marked as rolled back", name);
+    Ok(())
+}
+
 pub async fn exec_up_with(
     manager: &SchemaManager<'_>,
     mut steps: Option<u32>,
@@ -254,15 +376,30 @@ pub async fn exec_up_with(
         if use_txn {
             let transaction = db.begin().await?;
             let txn_manager = SchemaManager::new(&transaction);
+            let start = Instant::now();
             migration.up(&txn_manager).await?;
+            let duration = start.elapsed();
             info!("Migration '{}' has been applied", migration.name());
-            insert_migration_record(&transaction, migration.name(), migration_table_name.clone())
-                .await?;
+            insert_migration_record(
+                &transaction,
+                migration.as_ref(),
+                Some(duration),
+                migration_table_name.clone(),
+            )
+            .await?;
             transaction.commit().await?;
         } else {
+            let start = Instant::now();
             migration.up(manager).await?;
+            let duration = start.elapsed();
             info!("Migration '{}' has been applied", migration.name());
-            insert_migration_record(db, migration.name(), migration_table_name.clone()).await?;
+            insert_migration_record(
+                db,
+                migration.as_ref(),
+                Some(duration),
+                migration_table_name.clone(),
+            )
+            .await?;
         }
     }
 
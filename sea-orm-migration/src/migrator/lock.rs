@@ -0,0 +1,111 @@
+use tracing::info;
+
+use crate::SchemaManagerConnection;
+use sea_orm::{
+    ConnectionTrait, DatabaseTransaction, DbBackend, DbErr, DynIden, SqliteTransactionMode,
+    Statement, TransactionOptions, TransactionTrait,
+};
+
+/// A database-wide lock serializing concurrent migration runs, e.g. several replicas
+/// calling `up` on boot.
+///
+/// - Postgres: `pg_advisory_xact_lock`, held by a transaction on a dedicated connection
+/// - MySQL: `GET_LOCK` / `RELEASE_LOCK`, on a dedicated connection
+/// - SQLite: a `BEGIN IMMEDIATE` transaction, which the migrations have to run in
+pub struct MigrationLock {
+    txn: DatabaseTransaction,
+    name: String,
+}
+
+impl MigrationLock {
+    /// Block until the lock is acquired. The lock is keyed by the migration table name.
+    pub async fn acquire(
+        db: &SchemaManagerConnection<'_>,
+        migration_table_name: DynIden,
+    ) -> Result<Self, DbErr> {
+        let backend = db.get_database_backend();
+        let name = format!("{migration_table_name}_lock");
+
+        info!("Acquiring migration lock '{name}'");
+
+        let txn = match backend {
+            DbBackend::Sqlite => {
+                db.begin_with_options(TransactionOptions {
+                    sqlite_transaction_mode: Some(SqliteTransactionMode::Immediate),
+                    ..Default::default()
+                })
+                .await?
+            }
+            _ => db.begin().await?,
+        };
+
+        match backend {
+            DbBackend::Postgres => {
+                txn.execute_raw(Statement::from_sql_and_values(
+                    backend,
+                    "SELECT pg_advisory_xact_lock(hashtext($1))",
+                    [name.as_str().into()],
+                ))
+                .await?;
+            }
+            DbBackend::MySql => {
+                let acquired: Option<i64> = txn
+                    .query_one_raw(Statement::from_sql_and_values(
+                        backend,
+                        "SELECT GET_LOCK(CONCAT(DATABASE(), '.', ?), -1) AS `acquired`",
+                        [name.as_str().into()],
+                    ))
+                    .await?
+                    .map(|row| row.try_get("", "acquired"))
+                    .transpose()?;
+                if acquired != Some(1) {
+                    return Err(DbErr::Migration(format!(
+                        "Failed to acquire migration lock '{name}'"
+                    )));
+                }
+            }
+            _ => {}
+        }
+
+        info!("Migration lock '{name}' acquired");
+
+        Ok(Self { txn, name })
+    }
+
+    /// The transaction migrations have to be run in while the lock is held, if any.
+    ///
+    /// On SQLite the lock is a write transaction, so a migration running on another
+    /// connection would wait on it forever.
+    pub fn connection(&self) -> Option<&DatabaseTransaction> {
+        match self.txn.get_database_backend() {
+            DbBackend::Sqlite => Some(&self.txn),
+            _ => None,
+        }
+    }
+
+    /// Release the lock. Changes made through [`Self::connection`] are committed
+    /// only if `success`.
+    pub async fn release(self, success: bool) -> Result<(), DbErr> {
+        let backend = self.txn.get_database_backend();
+
+        if backend == DbBackend::MySql {
+            self.txn
+                .execute_raw(Statement::from_sql_and_values(
+                    backend,
+                    "SELECT RELEASE_LOCK(CONCAT(DATABASE(), '.', ?))",
+                    [self.name.as_str().into()],
+                ))
+                .await?;
+        }
+
+        if success {
+            self.txn.commit().await?;
+        } else {
+            self.txn.rollback().await?;
+        }
+
+        info!("Migration lock '{}' released", self.name);
+
+        Ok(())
+    }
+}
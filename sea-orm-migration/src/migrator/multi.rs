@@ -0,0 +1,136 @@
+use futures_util::{StreamExt, stream};
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::info;
+
+use sea_orm::{DatabaseConnection, DbErr};
+
+/// Options for running migrations against several databases, e.g. with
+/// [`MigratorTrait::up_all`](super::MigratorTrait::up_all).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MigrateAllOptions {
+    /// Maximum number of databases migrated at the same time. `None` migrates
+    /// all of them at once; `Some(1)` migrates them one after another.
+    pub parallelism: Option<usize>,
+    /// Stop starting new databases once one of them failed. Those already in
+    /// progress are run to completion; the rest are reported as
+    /// [`MigrateOutcome::Skipped`].
+    pub fail_fast: bool,
+    /// Apply migrations with [`MigratorTrait::up_with_lock`](super::MigratorTrait::up_with_lock),
+    /// so that concurrent deployments migrate each database only once. Only used by `up_all`.
+    pub lock: bool,
+}
+
+/// Outcome of running migrations against one database
+#[derive(Debug)]
+pub enum MigrateOutcome<T> {
+    /// Migrations completed
+    Completed(T),
+    /// Migrations failed with the given error
+    Failed(DbErr),
+    /// Not attempted because an earlier database failed and
+    /// [`MigrateAllOptions::fail_fast`] is set
+    Skipped,
+}
+
+impl<T> MigrateOutcome<T> {
+    /// Whether migrations completed on this database
+    pub fn is_completed(&self) -> bool {
+        matches!(self, Self::Completed(_))
+    }
+}
+
+/// Per-database outcomes of running migrations against several databases,
+/// in the order of the connections passed in
+#[derive(Debug)]
+pub struct MigrateAllReport<T> {
+    outcomes: Vec<MigrateOutcome<T>>,
+}
+
+impl<T> MigrateAllReport<T> {
+    /// Outcomes, one per connection and in the same order
+    pub fn outcomes(&self) -> &[MigrateOutcome<T>] {
+        &self.outcomes
+    }
+
+    /// Whether migrations completed on every database
+    pub fn is_ok(&self) -> bool {
+        self.outcomes.iter().all(MigrateOutcome::is_completed)
+    }
+
+    /// Index of the connection and error of every failed database
+    pub fn failures(&self) -> impl Iterator<Item = (usize, &DbErr)> {
+        self.outcomes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, outcome)| match outcome {
+                MigrateOutcome::Failed(err) => Some((i, err)),
+                _ => None,
+            })
+    }
+
+    /// Consume the report, returning the result of every database, or the
+    /// error of the first failed one
+    pub fn into_result(self) -> Result<Vec<T>, DbErr> {
+        let len = self.outcomes.len();
+        let mut res = Vec::with_capacity(len);
+        for (i, outcome) in self.outcomes.into_iter().enumerate() {
+            match outcome {
+                MigrateOutcome::Completed(value) => res.push(value),
+                MigrateOutcome::Failed(err) => return Err(err),
+                MigrateOutcome::Skipped => {
+                    return Err(DbErr::Migration(format!(
+                        "Database {} of {len} was skipped",
+                        i + 1
+                    )));
+                }
+            }
+        }
+        Ok(res)
+    }
+
+    /// Consume the report, returning the outcomes
+    pub fn into_outcomes(self) -> Vec<MigrateOutcome<T>> {
+        self.outcomes
+    }
+}
+
+pub(crate) async fn run_all<'a, T, F, Fut>(
+    connections: &'a [DatabaseConnection],
+    options: &MigrateAllOptions,
+    f: F,
+) -> MigrateAllReport<T>
+where
+    F: Fn(&'a DatabaseConnection) -> Fut,
+    Fut: Future<Output = Result<T, DbErr>>,
+{
+    let len = connections.len();
+    let failed = AtomicBool::new(false);
+    let (failed, f) = (&failed, &f);
+
+    let mut futures = Vec::with_capacity(len);
+    for (i, db) in connections.iter().enumerate() {
+        futures.push(async move {
+            if options.fail_fast && failed.load(Ordering::Acquire) {
+                info!("Skipping database {} of {len}", i + 1);
+                return MigrateOutcome::Skipped;
+            }
+            info!("Migrating database {} of {len}", i + 1);
+            match f(db).await {
+                Ok(value) => MigrateOutcome::Completed(value),
+                Err(err) => {
+                    failed.store(true, Ordering::Release);
+                    MigrateOutcome::Failed(err)
+                }
+            }
+        });
+    }
+
+    // futures are lazy, so at most `parallelism` databases are migrated at a time
+    let outcomes = stream::iter(futures)
+        .buffered(options.parallelism.unwrap_or(len).max(1))
+        .collect()
+        .await;
+
+    MigrateAllReport { outcomes }
+}
@@ -0,0 +1,289 @@
+use std::collections::BTreeSet;
+use tracing::info;
+
+use super::{Migration, MigrationStatus, exec::*};
+use crate::{SchemaManagerConnection, seaql_migrations};
+use sea_orm::{ConnectionTrait, DbErr, DynIden, TransactionSession, TransactionTrait};
+
+/// A baseline migration recreating the schema of the migrations it replaces,
+/// see [`MigratorTrait::squash_baseline`](super::MigratorTrait::squash_baseline)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SquashedBaseline {
+    /// Name of the baseline migration, the name of the last squashed migration
+    /// suffixed with `_baseline`
+    pub name: String,
+    /// Names of the squashed migrations, in order
+    pub squashed: Vec<String>,
+    /// DDL statements recreating the schema, in order of execution
+    pub statements: Vec<String>,
+}
+
+impl SquashedBaseline {
+    /// Source of the migration file of the baseline, to be saved as `<name>.rs`
+    /// in the migration crate.
+    ///
+    /// Its `up` executes [`statements`](Self::statements), and it exports the
+    /// names of the squashed migrations as `SQUASHED`, to be passed to
+    /// [`MigratorTrait::adopt_baseline`](super::MigratorTrait::adopt_baseline).
+    pub fn to_migration_file(&self) -> String {
+        let mut file = String::new();
+        file.push_str("use sea_orm_migration::prelude::*;\n\n");
+        file.push_str("/// Migrations squashed into this baseline\n");
+        file.push_str("pub const SQUASHED: &[&str] = &[\n");
+        for name in self.squashed.iter() {
+            file.push_str(&format!("    {name:?},\n"));
+        }
+        file.push_str("];\n\n");
+        file.push_str("const STATEMENTS: &[&str] = &[\n");
+        for stmt in self.statements.iter() {
+            file.push_str(&format!("    {},\n", raw_string_literal(stmt)));
+        }
+        file.push_str("];\n\n");
+        file.push_str(
+            r#"#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        for stmt in STATEMENTS {
+            db.execute_unprepared(stmt).await?;
+        }
+        Ok(())
+    }
+}
+"#,
+        );
+        file
+    }
+}
+
+fn raw_string_literal(s: &str) -> String {
+    let mut hashes = 1;
+    while s.contains(&format!("\"{}", "#".repeat(hashes))) {
+        hashes += 1;
+    }
+    let hashes = "#".repeat(hashes);
+    format!("r{hashes}\"{s}\"{hashes}")
+}
+
+// only Postgres schemas can be captured, the rest is unused without it
+#[cfg_attr(not(feature = "sqlx-postgres"), allow(unused_variables))]
+pub async fn exec_squash_baseline(
+    db: &SchemaManagerConnection<'_>,
+    migrations: Vec<Migration>,
+    up_to: &str,
+    migration_table_name: DynIden,
+) -> Result<SquashedBaseline, DbErr> {
+    let index = find_migration(&migrations, up_to)?;
+    if let Some(pending) = migrations[..=index]
+        .iter()
+        .find(|file| file.status == MigrationStatus::Pending)
+    {
+        return Err(DbErr::Migration(format!(
+            "Migration '{}' has not been applied, apply all migrations up to '{up_to}' before squashing",
+            pending.name()
+        )));
+    }
+    // the schema is captured as a whole, it must not contain the changes of later migrations
+    if let Some(applied) = migrations[index + 1..]
+        .iter()
+        .find(|file| file.status == MigrationStatus::Applied)
+    {
+        return Err(DbErr::Migration(format!(
+            "Migration '{}' has been applied after '{up_to}', squash on a database migrated up to '{up_to}' only",
+            applied.name()
+        )));
+    }
+
+    let statements: Vec<String> = match db.get_database_backend() {
+        #[cfg(feature = "sqlx-postgres")]
+        sea_orm::DbBackend::Postgres => {
+            capture_postgres_schema(db, &migration_table_name.to_string()).await?
+        }
+        #[allow(unreachable_patterns)]
+        other => {
+            return Err(DbErr::BackendNotSupported {
+                db: other.as_str(),
+                ctx: "squash_baseline",
+            });
+        }
+    };
+
+    #[allow(unreachable_code)]
+    let baseline = SquashedBaseline {
+        name: format!("{up_to}_baseline"),
+        squashed: migrations[..=index]
+            .iter()
+            .map(|file| file.name().to_owned())
+            .collect(),
+        statements,
+    };
+    info!(
+        "Squashed {} migrations into '{}'",
+        baseline.squashed.len(),
+        baseline.name
+    );
+
+    exec_adopt_baseline(
+        db,
+        get_migration_models(db, migration_table_name.clone()).await?,
+        &baseline.name,
+        &baseline.squashed[..],
+        migration_table_name,
+    )
+    .await?;
+
+    Ok(baseline)
+}
+
+pub async fn exec_adopt_baseline<C, S>(
+    db: &C,
+    migration_models: Vec<seaql_migrations::Model>,
+    name: &str,
+    squashed: &[S],
+    migration_table_name: DynIden,
+) -> Result<(), DbErr>
+where
+    C: ConnectionTrait + TransactionTrait,
+    S: AsRef<str>,
+{
+    let applied: BTreeSet<&str> = migration_models
+        .iter()
+        .map(|model| model.version.as_str())
+        .collect();
+    if applied.contains(name) {
+        info!("Baseline '{}' has already been adopted", name);
+        return Ok(());
+    }
+    if let Some(pending) = squashed
+        .iter()
+        .map(AsRef::as_ref)
+        .find(|squashed| !applied.contains(squashed))
+    {
+        return Err(DbErr::Migration(format!(
+            "Migration '{pending}' squashed into '{name}' has not been applied"
+        )));
+    }
+
+    let transaction = db.begin().await?;
+    for squashed in squashed.iter() {
+        delete_migration_record(
+            &transaction,
+            squashed.as_ref(),
+            migration_table_name.clone(),
+        )
+        .await?;
+    }
+    insert_baseline_record(&transaction, name, migration_table_name).await?;
+    transaction.commit().await?;
+
+    info!("Baseline '{}' has been marked as applied", name);
+    Ok(())
+}
+
+#[cfg(feature = "sqlx-postgres")]
+async fn capture_postgres_schema(
+    db: &SchemaManagerConnection<'_>,
+    migration_table_name: &str,
+) -> Result<Vec<String>, DbErr> {
+    use super::queries::get_current_schema;
+    use sea_orm::{RuntimeErr, Statement, sea_query::SelectStatement};
+    use sea_schema::postgres::discovery::SchemaDiscovery;
+    use std::collections::BTreeMap;
+
+    let backend = db.get_database_backend();
+    let current_schema: String = db
+        .query_one(SelectStatement::new().expr(get_current_schema(db)))
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Can't get current schema".into()))?
+        .try_get_by_index(0)?;
+
+    let schema = SchemaDiscovery::new_no_exec(&current_schema)
+        .discover_with(db)
+        .await
+        .map_err(|err| DbErr::Query(RuntimeErr::SqlxError(err.into())))?;
+
+    let mut statements = Vec::new();
+    for def in schema.enums.iter() {
+        statements.push(backend.build(&def.write()).sql);
+    }
+
+    let mut tables: BTreeMap<String, _> = schema
+        .tables
+        .iter()
+        .filter(|table| table.info.name != migration_table_name)
+        .map(|table| (table.info.name.clone(), table))
+        .collect();
+
+    // tables are created with their foreign keys, after the tables they reference
+    let mut references: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for row in db
+        .query_all_raw(Statement::from_sql_and_values(
+            backend,
+            r#"SELECT "t"."relname" AS "table_name", "r"."relname" AS "ref_table_name"
+FROM "pg_constraint" "c"
+JOIN "pg_class" "t" ON "t"."oid" = "c"."conrelid"
+JOIN "pg_class" "r" ON "r"."oid" = "c"."confrelid"
+JOIN "pg_namespace" "n" ON "n"."oid" = "t"."relnamespace"
+WHERE "c"."contype" = 'f' AND "n"."nspname" = $1"#,
+            [current_schema.clone().into()],
+        ))
+        .await?
+    {
+        let table: String = row.try_get("", "table_name")?;
+        let ref_table: String = row.try_get("", "ref_table_name")?;
+        if table != ref_table {
+            references.entry(table).or_default().insert(ref_table);
+        }
+    }
+
+    let mut order = Vec::new();
+    while !tables.is_empty() {
+        let ready: Vec<String> = tables
+            .keys()
+            .filter(|table| {
+                references
+                    .get(*table)
+                    .is_none_or(|refs| refs.iter().all(|ref_table| !tables.contains_key(ref_table)))
+            })
+            .cloned()
+            .collect();
+        if ready.is_empty() {
+            return Err(DbErr::Migration(format!(
+                "Circular foreign keys between tables {:?}, they can't be squashed",
+                tables.keys().collect::<Vec<_>>()
+            )));
+        }
+        for table in ready {
+            order.push(tables.remove(&table).expect("Checked above"));
+        }
+    }
+
+    for table in order {
+        statements.push(backend.build(&table.write()).sql);
+        // indexes not backing a constraint are not part of the table definition
+        for row in db
+            .query_all_raw(Statement::from_sql_and_values(
+                backend,
+                r#"SELECT pg_get_indexdef("i"."indexrelid") AS "definition"
+FROM "pg_index" "i"
+JOIN "pg_class" "t" ON "t"."oid" = "i"."indrelid"
+JOIN "pg_namespace" "n" ON "n"."oid" = "t"."relnamespace"
+WHERE "n"."nspname" = $1 AND "t"."relname" = $2
+AND NOT EXISTS (SELECT 1 FROM "pg_constraint" "c" WHERE "c"."conindid" = "i"."indexrelid")
+ORDER BY "definition""#,
+                [
+                    current_schema.clone().into(),
+                    table.info.name.clone().into(),
+                ],
+            ))
+            .await?
+        {
+            statements.push(row.try_get("", "definition")?);
+        }
+    }
+
+    Ok(statements)
+}
@@ -1,5 +1,7 @@
-use super::{Migration, MigrationStatus, exec::*};
-use crate::{IntoSchemaManagerConnection, MigrationTrait, SchemaManager, seaql_migrations};
+use super::{Migration, MigrationStatus, SquashedBaseline, exec::*, lock::*, squash::*};
+use crate::{
+    IntoSchemaManagerConnection, MigrationTrait, SchemaManager, has_table, seaql_migrations,
+};
 use sea_orm::sea_query::IntoIden;
 use sea_orm::{ConnectionTrait, DbErr, DynIden};
 
@@ -23,6 +25,9 @@ pub trait MigratorTraitSelf: Sized + Send + Sync {
             .map(|migration| Migration {
                 migration,
                 status: MigrationStatus::Pending,
+                applied_at: None,
+                checksum: None,
+                duration_ms: None,
             })
             .collect()
     }
@@ -76,6 +81,14 @@ pub trait MigratorTraitSelf: Sized + Send + Sync {
             .collect())
     }
 
+    /// Check whether the migration table exists, without creating it
+    async fn has_migration_table<C>(&self, db: &C) -> Result<bool, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        has_table(db, self.migration_table_name().to_string()).await
+    }
+
     /// Create migration table `seaql_migrations` in the database
     async fn install<C>(&self, db: &C) -> Result<(), DbErr>
     where
@@ -93,7 +106,10 @@ pub trait MigratorTraitSelf: Sized + Send + Sync {
 
         info!("Checking migration status");
 
-        for Migration { migration, status } in self.get_migration_with_status(db).await? {
+        for Migration {
+            migration, status, ..
+        } in self.get_migration_with_status(db).await?
+        {
             info!("Migration '{}'... {}", migration.name(), status);
         }
 
@@ -153,6 +169,22 @@ pub trait MigratorTraitSelf: Sized + Send + Sync {
         exec_up(self, &manager, steps).await
     }
 
+    /// Apply pending migrations while holding a database-wide lock,
+    /// see [`MigratorTrait::up_with_lock`](super::MigratorTrait::up_with_lock)
+    async fn up_with_lock<'c, C>(&self, db: C, steps: Option<u32>) -> Result<(), DbErr>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        let db = db.into_database_executor();
+        let lock = MigrationLock::acquire(&db, self.migration_table_name()).await?;
+        let res = match lock.connection() {
+            Some(txn) => exec_up(self, &SchemaManager::new(txn), steps).await,
+            None => exec_up(self, &SchemaManager::new(db), steps).await,
+        };
+        let released = lock.release(res.is_ok()).await;
+        res.and(released)
+    }
+
     /// Rollback applied migrations
     async fn down<'c, C>(&self, db: C, steps: Option<u32>) -> Result<(), DbErr>
     where
@@ -162,6 +194,81 @@ pub trait MigratorTraitSelf: Sized + Send + Sync {
         let manager = SchemaManager::new(db);
         exec_down(self, &manager, steps).await
     }
+
+    /// Record a migration as applied without running it,
+    /// see [`MigratorTrait::mark_applied`](super::MigratorTrait::mark_applied)
+    async fn mark_applied<'c, C>(&self, db: C, name: &str) -> Result<(), DbErr>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        let db = db.into_database_executor();
+        self.install(&db).await?;
+        exec_mark_applied(
+            &db,
+            self.get_migration_with_status(&db).await?,
+            name,
+            self.migration_table_name(),
+        )
+        .await
+    }
+
+    /// Remove the record of an applied migration without rolling it back,
+    /// see [`MigratorTrait::mark_rolled_back`](super::MigratorTrait::mark_rolled_back)
+    async fn mark_rolled_back<'c, C>(&self, db: C, name: &str) -> Result<(), DbErr>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        let db = db.into_database_executor();
+        self.install(&db).await?;
+        exec_mark_rolled_back(
+            &db,
+            self.get_migration_with_status(&db).await?,
+            name,
+            self.migration_table_name(),
+        )
+        .await
+    }
+
+    /// Squash the migrations up to and including `up_to` into a baseline migration,
+    /// see [`MigratorTrait::squash_baseline`](super::MigratorTrait::squash_baseline)
+    fn squash_baseline<'c, C>(
+        &self,
+        db: C,
+        up_to: &str,
+    ) -> impl Future<Output = Result<SquashedBaseline, DbErr>>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        async move {
+            let db = db.into_database_executor();
+            self.install(&db).await?;
+            exec_squash_baseline(
+                &db,
+                self.get_migration_with_status(&db).await?,
+                up_to,
+                self.migration_table_name(),
+            )
+            .await
+        }
+    }
+
+    /// Replace the records of squashed migrations with one of their baseline,
+    /// see [`MigratorTrait::adopt_baseline`](super::MigratorTrait::adopt_baseline)
+    async fn adopt_baseline<'c, C>(&self, db: C, name: &str, squashed: &[&str]) -> Result<(), DbErr>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        let db = db.into_database_executor();
+        self.install(&db).await?;
+        exec_adopt_baseline(
+            &db,
+            self.get_migration_models(&db).await?,
+            name,
+            squashed,
+            self.migration_table_name(),
+        )
+        .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -209,6 +316,13 @@ where
         M::get_applied_migrations(db).await
     }
 
+    async fn has_migration_table<C>(&self, db: &C) -> Result<bool, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        M::has_migration_table(db).await
+    }
+
     async fn install<C>(&self, db: &C) -> Result<(), DbErr>
     where
         C: ConnectionTrait,
@@ -259,12 +373,51 @@ where
         M::up(db, steps).await
     }
 
+    async fn up_with_lock<'c, C>(&self, db: C, steps: Option<u32>) -> Result<(), DbErr>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        M::up_with_lock(db, steps).await
+    }
+
     async fn down<'c, C>(&self, db: C, steps: Option<u32>) -> Result<(), DbErr>
     where
         C: IntoSchemaManagerConnection<'c>,
     {
         M::down(db, steps).await
     }
+
+    async fn mark_applied<'c, C>(&self, db: C, name: &str) -> Result<(), DbErr>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        M::mark_applied(db, name).await
+    }
+
+    async fn mark_rolled_back<'c, C>(&self, db: C, name: &str) -> Result<(), DbErr>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        M::mark_rolled_back(db, name).await
+    }
+
+    fn squash_baseline<'c, C>(
+        &self,
+        db: C,
+        up_to: &str,
+    ) -> impl Future<Output = Result<SquashedBaseline, DbErr>>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        M::squash_baseline(db, up_to)
+    }
+
+    async fn adopt_baseline<'c, C>(&self, db: C, name: &str, squashed: &[&str]) -> Result<(), DbErr>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        M::adopt_baseline(db, name, squashed).await
+    }
 }
 
 async fn exec_fresh<M>(migrator: &M, manager: &SchemaManager<'_>) -> Result<(), DbErr>
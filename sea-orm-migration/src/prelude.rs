@@ -3,7 +3,7 @@ pub use crate::cli;
 
 pub use crate::{
     IntoSchemaManagerConnection, MigrationName, MigrationTrait, MigratorTrait, SchemaManager,
-    SchemaManagerConnection,
+    SchemaManagerConnection, TableOp, migration_entity,
 };
 pub use async_trait;
 pub use sea_orm::{
@@ -7,6 +7,10 @@ pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub version: String,
     pub applied_at: i64,
+    /// [`MigrationTrait::checksum`](crate::MigrationTrait::checksum) of the migration when applied
+    pub checksum: Option<String>,
+    /// How long applying the migration took, in milliseconds
+    pub duration_ms: Option<i64>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
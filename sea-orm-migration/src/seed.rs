@@ -0,0 +1,53 @@
+/// Declare a frozen snapshot of a table as a minimal SeaORM entity, for seeding data inside a migration.
+///
+/// The migration stays decoupled from the application entities, which may evolve after the
+/// migration has been written. The module contains an `Entity`, `Model`, `ActiveModel` and `Column`,
+/// without any relations.
+///
+/// ```
+/// use sea_orm_migration::prelude::*;
+///
+/// migration_entity! {
+///     mod cake {
+///         #[sea_orm(table_name = "cake")]
+///         pub struct Model {
+///             #[sea_orm(primary_key)]
+///             pub id: i32,
+///             pub name: String,
+///         }
+///     }
+/// }
+///
+/// let cake = cake::ActiveModel {
+///     name: sea_orm::Set("Cheesecake".to_owned()),
+///     ..Default::default()
+/// };
+/// ```
+#[macro_export]
+macro_rules! migration_entity {
+    (
+        $(#[$mod_attr:meta])*
+        $vis:vis mod $name:ident {
+            $(#[$model_attr:meta])*
+            pub struct Model {
+                $($body:tt)*
+            }
+        }
+    ) => {
+        $(#[$mod_attr])*
+        $vis mod $name {
+            use $crate::sea_orm::{self, entity::prelude::*};
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            $(#[$model_attr])*
+            pub struct Model {
+                $($body)*
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+    };
+}
@@ -0,0 +1,100 @@
+use crate::SchemaManager;
+use sea_orm::{
+    DbBackend, DbErr,
+    sea_query::{Index, IndexCreateStatement, Table, TableCreateStatement, TableRef},
+};
+
+/// A reversible schema operation, returned by [`MigrationTrait::schema`](crate::MigrationTrait::schema).
+///
+/// The default `up()` applies the operations in order,
+/// and the default `down()` reverts them in reverse order.
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum TableOp {
+    /// `CREATE TABLE`, reverted with `DROP TABLE`
+    CreateTable(TableCreateStatement),
+    /// `CREATE INDEX`, reverted with `DROP INDEX`
+    CreateIndex(IndexCreateStatement),
+}
+
+impl From<TableCreateStatement> for TableOp {
+    fn from(stmt: TableCreateStatement) -> Self {
+        Self::CreateTable(stmt)
+    }
+}
+
+impl From<IndexCreateStatement> for TableOp {
+    fn from(stmt: IndexCreateStatement) -> Self {
+        Self::CreateIndex(stmt)
+    }
+}
+
+impl TableOp {
+    pub(crate) async fn apply(&self, manager: &SchemaManager<'_>) -> Result<(), DbErr> {
+        match self {
+            Self::CreateTable(stmt) => manager.create_table(stmt.clone()).await,
+            Self::CreateIndex(stmt) => manager.create_index(stmt.clone()).await,
+        }
+    }
+
+    /// Revert this operation; `ops` are all the operations of the same migration
+    pub(crate) async fn revert(
+        &self,
+        manager: &SchemaManager<'_>,
+        ops: &[TableOp],
+    ) -> Result<(), DbErr> {
+        match self {
+            Self::CreateTable(stmt) => {
+                let table = stmt.get_table_name().ok_or_else(|| {
+                    DbErr::Migration("Cannot revert a `CREATE TABLE` without table name".to_owned())
+                })?;
+                manager
+                    .drop_table(Table::drop().table(table.clone()).to_owned())
+                    .await
+            }
+            Self::CreateIndex(stmt) => {
+                let name = stmt.get_index_spec().get_name().ok_or_else(|| {
+                    DbErr::Migration("Cannot revert a `CREATE INDEX` without index name".to_owned())
+                })?;
+                let backend = manager.get_database_backend();
+                let mut drop = Index::drop();
+                drop.name(name);
+                match index_table(backend, stmt, ops) {
+                    Some(table) => {
+                        drop.table(table);
+                    }
+                    None if backend == DbBackend::MySql => {
+                        return Err(DbErr::Migration(format!(
+                            "Cannot revert index `{name}`: its table is not created in the same migration"
+                        )));
+                    }
+                    None => {}
+                }
+                manager.drop_index(drop).await
+            }
+        }
+    }
+}
+
+/// Find the table of an index among the tables created by `ops`
+///
+/// `IndexCreateStatement` does not expose its table, so each candidate is
+/// set on a copy of the statement and compared with the original.
+fn index_table(
+    backend: DbBackend,
+    stmt: &IndexCreateStatement,
+    ops: &[TableOp],
+) -> Option<TableRef> {
+    let sql = backend.build(stmt).sql;
+    ops.iter()
+        .filter_map(|op| match op {
+            TableOp::CreateTable(table) => table.get_table_name(),
+            TableOp::CreateIndex(_) => None,
+        })
+        .find(|table| {
+            let mut probe = stmt.clone();
+            probe.table((*table).clone());
+            backend.build(&probe).sql == sql
+        })
+        .cloned()
+}
@@ -41,4 +41,8 @@ impl MigrationTrait for Migration {
 
         Ok(())
     }
+
+    fn checksum(&self) -> Option<String> {
+        Some(format!("{}@1", self.name()))
+    }
 }
@@ -0,0 +1,23 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+impl MigrationTrait for Migration {
+    fn schema(&self) -> Vec<TableOp> {
+        vec![
+            Table::create()
+                .table("cake")
+                .col(pk_auto("id"))
+                .col(string("name"))
+                .to_owned()
+                .into(),
+            Index::create()
+                .name("cake_name_index")
+                .table("cake")
+                .col("name")
+                .to_owned()
+                .into(),
+        ]
+    }
+}
@@ -0,0 +1,24 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+impl MigrationTrait for Migration {
+    fn schema(&self) -> Vec<TableOp> {
+        vec![
+            Table::create()
+                .table("fruit")
+                .col(pk_auto("id"))
+                .col(string("name"))
+                .col(integer("cake_id"))
+                .foreign_key(
+                    ForeignKey::create()
+                        .name("fk-fruit-cake_id")
+                        .from("fruit", "cake_id")
+                        .to("cake", "id"),
+                )
+                .to_owned()
+                .into(),
+        ]
+    }
+}
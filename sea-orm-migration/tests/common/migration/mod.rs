@@ -6,3 +6,5 @@ pub mod m20220923_000001_seed_cake_table;
 pub mod m20230109_000001_seed_cake_table;
 pub mod m20250101_000001_create_test_table;
 pub mod m20250101_000002_manual_transaction;
+pub mod m20250601_000001_declare_cake_table;
+pub mod m20250601_000002_declare_fruit_table;
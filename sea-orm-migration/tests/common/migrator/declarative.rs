@@ -0,0 +1,14 @@
+use crate::common::migration::*;
+use sea_orm_migration::prelude::*;
+
+pub struct Migrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for Migrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(m20250601_000001_declare_cake_table::Migration),
+            Box::new(m20250601_000002_declare_fruit_table::Migration),
+        ]
+    }
+}
@@ -1,3 +1,4 @@
+pub mod declarative;
 pub mod default;
 pub mod override_migration_table_name;
 pub mod transaction_test;
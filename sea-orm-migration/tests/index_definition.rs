@@ -0,0 +1,155 @@
+use sea_orm::{ConnectionTrait, Database, DatabaseConnection, DbBackend, DbErr};
+use sea_orm_migration::{IndexDefinition, IndexedColumn, prelude::*, schema::*};
+
+#[tokio::test]
+async fn index_definition() -> Result<(), DbErr> {
+    let url = &std::env::var("DATABASE_URL").expect("Environment variable 'DATABASE_URL' not set");
+    let db = &create_db(url, "sea_orm_migration_index_definition").await?;
+    let manager = SchemaManager::new(db);
+    let backend = manager.get_database_backend();
+
+    manager
+        .create_table(
+            Table::create()
+                .table("post")
+                .col(pk_auto("id"))
+                .col(string("title"))
+                .col(timestamp_null("deleted_at"))
+                .to_owned(),
+        )
+        .await?;
+
+    manager
+        .create_index(
+            Index::create()
+                .name("post_title_index")
+                .table("post")
+                .col("title")
+                .unique()
+                .to_owned(),
+        )
+        .await?;
+    assert_eq!(
+        manager
+            .get_index_definition("post", "post_title_index")
+            .await?,
+        Some(IndexDefinition {
+            unique: true,
+            columns: vec![IndexedColumn::Column("title".to_owned())],
+            predicate: None,
+        })
+    );
+    assert_eq!(
+        manager
+            .get_index_definition("post", "non_existent_index")
+            .await?,
+        None
+    );
+    assert_eq!(
+        manager
+            .get_index_definition("cake", "post_title_index")
+            .await?,
+        None
+    );
+
+    // expression index, a functional key part on MySQL
+    let index = Index::create()
+        .name("post_lower_title_index")
+        .table("post")
+        .col(Func::lower(Expr::col("title")))
+        .col("id")
+        .to_owned();
+    if backend == DbBackend::Sqlite {
+        // sea-query can't write the expression for SQLite
+        assert_eq!(
+            manager.create_index(index).await,
+            Err(DbErr::BackendNotSupported {
+                db: "Sqlite",
+                ctx: "CREATE INDEX on an expression",
+            })
+        );
+        db.execute_unprepared(
+            r#"CREATE INDEX "post_lower_title_index" ON "post" (LOWER("title"), "id")"#,
+        )
+        .await?;
+    } else {
+        manager.create_index(index).await?;
+    }
+    let index = manager
+        .get_index_definition("post", "post_lower_title_index")
+        .await?
+        .expect("index exists");
+    assert!(!index.unique);
+    assert!(index.has_expression());
+    assert_eq!(index.column_names(), ["id"]);
+    assert_eq!(
+        index.columns[0],
+        IndexedColumn::Expression(
+            match backend {
+                DbBackend::MySql => "lower(`title`)",
+                DbBackend::Postgres => "lower(title::text)",
+                _ => r#"LOWER("title")"#,
+            }
+            .to_owned()
+        )
+    );
+    assert_eq!(index.predicate, None);
+
+    // MySQL has no partial index
+    if backend != DbBackend::MySql {
+        manager
+            .create_index(
+                Index::create()
+                    .name("post_live_title_index")
+                    .table("post")
+                    .col("title")
+                    .and_where(Expr::col("deleted_at").is_null())
+                    .to_owned(),
+            )
+            .await?;
+        assert_eq!(
+            manager
+                .get_index_definition("post", "post_live_title_index")
+                .await?,
+            Some(IndexDefinition {
+                unique: false,
+                columns: vec![IndexedColumn::Column("title".to_owned())],
+                predicate: Some(
+                    match backend {
+                        DbBackend::Postgres => "(deleted_at IS NULL)",
+                        _ => r#""deleted_at" IS NULL"#,
+                    }
+                    .to_owned()
+                ),
+            })
+        );
+    }
+
+    manager
+        .drop_table(Table::drop().table("post").to_owned())
+        .await?;
+
+    Ok(())
+}
+
+async fn create_db(url: &str, db_name: &str) -> Result<DatabaseConnection, DbErr> {
+    let db = Database::connect(url).await?;
+
+    match db.get_database_backend() {
+        DbBackend::MySql => {
+            db.execute_unprepared(&format!("DROP DATABASE IF EXISTS `{db_name}`"))
+                .await?;
+            db.execute_unprepared(&format!("CREATE DATABASE `{db_name}`"))
+                .await?;
+            Database::connect(format!("{url}/{db_name}")).await
+        }
+        DbBackend::Postgres => {
+            db.execute_unprepared(&format!(r#"DROP DATABASE IF EXISTS "{db_name}""#))
+                .await?;
+            db.execute_unprepared(&format!(r#"CREATE DATABASE "{db_name}""#))
+                .await?;
+            Database::connect(format!("{url}/{db_name}")).await
+        }
+        _ => Ok(db),
+    }
+}
@@ -47,6 +47,10 @@ async fn main() -> Result<(), DbErr> {
 
     run_transaction_test(url, "sea_orm_migration_txn", "public").await?;
 
+    run_declarative_test(url, "sea_orm_migration_declarative", "public").await?;
+
+    run_concurrent_up_test(url, "sea_orm_migration_lock", "public").await?;
+
     Ok(())
 }
 
@@ -142,6 +146,20 @@ where
     println!("\nMigrator::up");
     migrator.up(db, Some(1)).await?;
 
+    assert!(manager.has_column(migration_table_name, "checksum").await?);
+    assert!(
+        manager
+            .has_column(migration_table_name, "duration_ms")
+            .await?
+    );
+    let migrations = migrator.get_applied_migrations(db).await?;
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(
+        migrations[0].checksum(),
+        Some("m20220118_000001_create_cake_table@1")
+    );
+    assert!(migrations[0].duration_ms().is_some_and(|ms| ms >= 0));
+
     println!("\nMigrator::get_pending_migrations");
     let migrations = migrator.get_pending_migrations(db).await?;
     assert_eq!(migrations.len(), 5);
@@ -373,3 +391,72 @@ async fn run_transaction_test(url: &str, db_name: &str, schema: &str) -> Result<
 
     Ok(())
 }
+
+async fn run_declarative_test(url: &str, db_name: &str, schema: &str) -> Result<(), DbErr> {
+    let db = &create_db(url, db_name, schema).await?;
+    let manager = SchemaManager::new(db);
+
+    // up() and down() are both derived from MigrationTrait::schema
+    for _ in 0..2 {
+        println!("\nDeclarative test: up");
+        <declarative::Migrator as MigratorTrait>::up(db, None).await?;
+        assert!(manager.has_table("cake").await?);
+        assert!(manager.has_table("fruit").await?);
+        assert!(manager.has_index("cake", "cake_name_index").await?);
+
+        println!("\nDeclarative test: down one");
+        <declarative::Migrator as MigratorTrait>::down(db, Some(1)).await?;
+        assert!(manager.has_table("cake").await?);
+        assert!(!manager.has_table("fruit").await?);
+
+        println!("\nDeclarative test: down all");
+        <declarative::Migrator as MigratorTrait>::down(db, None).await?;
+        assert!(!manager.has_table("cake").await?);
+        assert!(!manager.has_table("fruit").await?);
+    }
+
+    Ok(())
+}
+
+async fn run_concurrent_up_test(url: &str, db_name: &str, schema: &str) -> Result<(), DbErr> {
+    let db = &create_db(url, db_name, schema).await?;
+    let manager = SchemaManager::new(db);
+    let migrator = default::Migrator;
+
+    // A second connection pool, standing in for another app instance
+    let other = &match db.get_database_backend() {
+        DbBackend::Sqlite => db.clone(),
+        _ => {
+            let connect_options = ConnectOptions::new(format!("{url}/{db_name}"))
+                .set_schema_search_path(format!("{schema},public"))
+                .to_owned();
+            Database::connect(connect_options).await?
+        }
+    };
+
+    println!(
+        "
+Migrator::up_with_lock, concurrently"
+    );
+    let (a, b) = tokio::join!(
+        migrator.up_with_lock(db, None),
+        migrator.up_with_lock(other, None),
+    );
+    a?;
+    b?;
+
+    assert!(manager.has_table("cake").await?);
+    assert!(manager.has_table("fruit").await?);
+    assert!(migrator.get_pending_migrations(db).await?.is_empty());
+    assert_eq!(
+        migrator.get_migration_models(db).await?.len(),
+        migrator.migrations().len()
+    );
+
+    // Nothing left to apply
+    migrator.up_with_lock(other, None).await?;
+
+    migrator.reset(db).await?;
+
+    Ok(())
+}
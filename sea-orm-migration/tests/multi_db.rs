@@ -0,0 +1,110 @@
+mod common;
+
+use common::migration::*;
+use sea_orm_migration::prelude::*;
+
+pub struct Migrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for Migrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(m20220118_000001_create_cake_table::Migration),
+            Box::new(m20220118_000003_seed_cake_table::Migration),
+        ]
+    }
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+mod sqlite {
+    use super::Migrator;
+    use sea_orm::{ConnectionTrait, Database, DatabaseConnection};
+    use sea_orm_migration::{
+        migrator::{MigrateAllOptions, MigrateOutcome, MigrationStatus},
+        prelude::*,
+    };
+
+    async fn connect(name: &str) -> Result<DatabaseConnection, DbErr> {
+        let path = std::env::temp_dir().join(format!("sea_orm_migration_multi_db_{name}.sqlite"));
+        let _ = std::fs::remove_file(&path);
+        Database::connect(format!("sqlite://{}?mode=rwc", path.display())).await
+    }
+
+    /// A shard on which the first migration fails, as the table already exists
+    async fn connect_broken(name: &str) -> Result<DatabaseConnection, DbErr> {
+        let db = connect(name).await?;
+        db.execute_unprepared("CREATE TABLE cake (id integer)")
+            .await?;
+        Ok(db)
+    }
+
+    async fn applied(db: &DatabaseConnection) -> Result<usize, DbErr> {
+        Ok(Migrator::get_applied_migrations(db).await?.len())
+    }
+
+    #[tokio::test]
+    async fn test_up_all() -> Result<(), DbErr> {
+        let dbs = [
+            connect("up_1").await?,
+            connect_broken("up_2").await?,
+            connect("up_3").await?,
+        ];
+
+        // the broken shard does not abort the others
+        let report = Migrator::up_all(&dbs, None, Default::default()).await;
+        assert!(!report.is_ok());
+        assert!(report.outcomes()[0].is_completed());
+        assert!(matches!(report.outcomes()[1], MigrateOutcome::Failed(_)));
+        assert!(report.outcomes()[2].is_completed());
+        assert_eq!(report.failures().map(|(i, _)| i).collect::<Vec<_>>(), [1]);
+        assert_eq!(applied(&dbs[0]).await?, 2);
+        assert_eq!(applied(&dbs[1]).await?, 0);
+        assert_eq!(applied(&dbs[2]).await?, 2);
+
+        let report = Migrator::status_all(&dbs, Default::default()).await;
+        let statuses: Vec<Vec<MigrationStatus>> = report
+            .into_result()?
+            .iter()
+            .map(|migrations| migrations.iter().map(|m| m.status()).collect())
+            .collect();
+        assert_eq!(
+            statuses,
+            [
+                vec![MigrationStatus::Applied, MigrationStatus::Applied],
+                vec![MigrationStatus::Pending, MigrationStatus::Pending],
+                vec![MigrationStatus::Applied, MigrationStatus::Applied],
+            ]
+        );
+
+        let report = Migrator::down_all(&dbs[..1], Some(1), Default::default()).await;
+        assert!(report.is_ok());
+        assert_eq!(applied(&dbs[0]).await?, 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_up_all_fail_fast() -> Result<(), DbErr> {
+        let dbs = [
+            connect_broken("fail_fast_1").await?,
+            connect("fail_fast_2").await?,
+        ];
+
+        let options = MigrateAllOptions {
+            parallelism: Some(1),
+            fail_fast: true,
+            ..Default::default()
+        };
+        let report = Migrator::up_all(&dbs, None, options).await;
+        assert!(matches!(report.outcomes()[0], MigrateOutcome::Failed(_)));
+        assert!(matches!(report.outcomes()[1], MigrateOutcome::Skipped));
+        assert!(report.into_result().is_err());
+        assert!(!Migrator::has_migration_table(&dbs[1]).await?);
+
+        let report = Migrator::up_all(&dbs[1..], None, options).await;
+        assert_eq!(report.into_result()?, [()]);
+        assert_eq!(applied(&dbs[1]).await?, 2);
+
+        Ok(())
+    }
+}
@@ -100,3 +100,153 @@ mod inner {
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "sqlx-postgres"))]
+mod squash {
+    use crate::common::{migration::*, migrator::default::*};
+    use sea_orm::{ConnectionTrait, Database, DbBackend, error::DbErr};
+    use sea_orm_migration::{
+        MigrationName, MigrationTrait, MigratorTraitSelf, SchemaManager, SquashedBaseline,
+    };
+
+    /// The migrator after squashing, with the baseline in place of the squashed migrations
+    struct SquashedMigrator {
+        baseline: SquashedBaseline,
+    }
+
+    struct BaselineMigration {
+        baseline: SquashedBaseline,
+    }
+
+    impl MigrationName for BaselineMigration {
+        fn name(&self) -> &str {
+            &self.baseline.name
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl MigrationTrait for BaselineMigration {
+        async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            let db = manager.get_connection();
+            for stmt in self.baseline.statements.iter() {
+                db.execute_unprepared(stmt).await?;
+            }
+            Ok(())
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl MigratorTraitSelf for SquashedMigrator {
+        fn migrations(&self) -> Vec<Box<dyn MigrationTrait>> {
+            vec![
+                Box::new(BaselineMigration {
+                    baseline: self.baseline.clone(),
+                }),
+                Box::new(m20220923_000001_seed_cake_table::Migration),
+                Box::new(m20230109_000001_seed_cake_table::Migration),
+            ]
+        }
+    }
+
+    async fn create_db(url: &str, db_name: &str) -> Result<sea_orm::DatabaseConnection, DbErr> {
+        let db = Database::connect(url).await?;
+        db.execute_unprepared(&format!(r#"DROP DATABASE IF EXISTS "{db_name}""#))
+            .await?;
+        db.execute_unprepared(&format!(r#"CREATE DATABASE "{db_name}""#))
+            .await?;
+        Database::connect(format!("{url}/{db_name}")).await
+    }
+
+    #[tokio::test]
+    async fn test_squash_baseline() -> Result<(), DbErr> {
+        let url =
+            &std::env::var("DATABASE_URL").expect("Environment variable 'DATABASE_URL' not set");
+        if !matches!(
+            Database::connect(url).await?.get_database_backend(),
+            DbBackend::Postgres
+        ) {
+            return Ok(());
+        }
+
+        let up_to = "m20220118_000004_create_tea_enum";
+        let db = create_db(url, "test_squash_baseline").await?;
+        Migrator.up(&db, Some(3)).await?;
+
+        // a migration up to the target is pending
+        assert!(Migrator.squash_baseline(&db, up_to).await.is_err());
+
+        Migrator.up(&db, Some(1)).await?;
+        let baseline = Migrator.squash_baseline(&db, up_to).await?;
+
+        assert_eq!(baseline.name, "m20220118_000004_create_tea_enum_baseline");
+        assert_eq!(
+            baseline.squashed,
+            [
+                "m20220118_000001_create_cake_table",
+                "m20220118_000002_create_fruit_table",
+                "m20220118_000003_seed_cake_table",
+                "m20220118_000004_create_tea_enum",
+            ]
+        );
+        let position = |needle: &str| {
+            baseline
+                .statements
+                .iter()
+                .position(|stmt| stmt.contains(needle))
+                .unwrap_or_else(|| panic!("no statement contains `{needle}`"))
+        };
+        position(r#"CREATE TYPE "tea""#);
+        position("cake_name_index");
+        assert!(position(r#"CREATE TABLE "cake""#) < position(r#"CREATE TABLE "fruit""#));
+        assert!(
+            baseline
+                .statements
+                .iter()
+                .all(|stmt| !stmt.contains("seaql_migrations"))
+        );
+
+        let file = baseline.to_migration_file();
+        assert!(file.contains(r#"pub const SQUASHED: &[&str] = &["#));
+        assert!(file.contains(r#""m20220118_000002_create_fruit_table","#));
+        assert!(file.contains(r#"r#"CREATE TABLE "cake""#));
+
+        // the squashed migrations are recorded as the baseline
+        let squashed = SquashedMigrator {
+            baseline: baseline.clone(),
+        };
+        let versions: Vec<String> = squashed
+            .get_migration_models(&db)
+            .await?
+            .into_iter()
+            .map(|model| model.version)
+            .collect();
+        assert_eq!(versions, [baseline.name.as_str()]);
+        assert_eq!(squashed.get_pending_migrations(&db).await?.len(), 2);
+        squashed.up(&db, None).await?;
+
+        // a fresh database runs the baseline then the rest
+        let fresh = create_db(url, "test_squash_baseline_fresh").await?;
+        squashed.up(&fresh, None).await?;
+        let manager = SchemaManager::new(&fresh);
+        assert!(manager.has_table("cake").await?);
+        assert!(manager.has_table("fruit").await?);
+        assert!(manager.has_index("cake", "cake_name_index").await?);
+        assert_eq!(squashed.get_applied_migrations(&fresh).await?.len(), 3);
+
+        // another existing database adopts the baseline
+        let existing = create_db(url, "test_squash_baseline_existing").await?;
+        Migrator.up(&existing, Some(4)).await?;
+        let names: Vec<&str> = baseline.squashed.iter().map(String::as_str).collect();
+        squashed
+            .adopt_baseline(&existing, &baseline.name, &names)
+            .await?;
+        // adopting again does nothing
+        squashed
+            .adopt_baseline(&existing, &baseline.name, &names)
+            .await?;
+        assert_eq!(squashed.get_applied_migrations(&existing).await?.len(), 1);
+        assert_eq!(squashed.get_pending_migrations(&existing).await?.len(), 2);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+mod common;
+
+use common::migration::*;
+use sea_orm_migration::prelude::*;
+
+pub struct Migrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for Migrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(m20220118_000001_create_cake_table::Migration),
+            Box::new(m20220118_000003_seed_cake_table::Migration),
+        ]
+    }
+}
+
+#[test]
+#[cfg(feature = "cli")]
+fn test_status_json_pending() {
+    use sea_orm_migration::cli::migration_status_json;
+
+    assert_eq!(
+        migration_status_json(&Migrator::get_migration_files()),
+        serde_json::json!([
+            {
+                "name": "m20220118_000001_create_cake_table",
+                "status": "pending",
+                "applied_at": null,
+                "checksum": null,
+                "duration_ms": null,
+            },
+            {
+                "name": "m20220118_000003_seed_cake_table",
+                "status": "pending",
+                "applied_at": null,
+                "checksum": null,
+                "duration_ms": null,
+            },
+        ])
+    );
+}
+
+#[cfg(all(feature = "cli", feature = "sqlx-sqlite"))]
+mod sqlite {
+    use super::Migrator;
+    use sea_orm::{Database, Statement};
+    use sea_orm_migration::{
+        cli::{migration_status_json, run_migrate_status},
+        prelude::*,
+    };
+    use std::error::Error;
+
+    #[tokio::test]
+    async fn test_status_exit_code() -> Result<(), Box<dyn Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+
+        // the migration table is reported missing and left alone
+        assert_eq!(run_migrate_status(&Migrator, &db, true, true).await?, 2);
+        assert!(!Migrator::has_migration_table(&db).await?);
+
+        Migrator::up(&db, Some(1)).await?;
+        assert_eq!(run_migrate_status(&Migrator, &db, true, true).await?, 1);
+
+        let status = migration_status_json(&Migrator::get_migration_with_status(&db).await?);
+        assert_eq!(status[0]["name"], "m20220118_000001_create_cake_table");
+        assert_eq!(status[0]["status"], "applied");
+        assert!(status[0]["applied_at"].is_i64());
+        assert_eq!(
+            status[0]["checksum"],
+            "m20220118_000001_create_cake_table@1"
+        );
+        assert!(status[0]["duration_ms"].is_i64());
+        assert_eq!(status[1]["name"], "m20220118_000003_seed_cake_table");
+        assert_eq!(status[1]["status"], "pending");
+        assert_eq!(status[1]["applied_at"], serde_json::Value::Null);
+
+        Migrator::up(&db, None).await?;
+        assert_eq!(run_migrate_status(&Migrator, &db, false, true).await?, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_mark_applied() -> Result<(), Box<dyn Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+
+        // out of order, the cake table is not created yet
+        Migrator::mark_applied(&db, "m20220118_000003_seed_cake_table").await?;
+        let status = migration_status_json(&Migrator::get_migration_with_status(&db).await?);
+        assert_eq!(status[0]["status"], "pending");
+        assert_eq!(status[1]["status"], "applied");
+        assert!(status[1]["applied_at"].is_i64());
+        // not run, so it took no time
+        assert_eq!(status[1]["duration_ms"], serde_json::Value::Null);
+
+        assert!(
+            Migrator::mark_applied(&db, "m20220118_000003_seed_cake_table")
+                .await
+                .is_err()
+        );
+        assert!(
+            Migrator::mark_applied(&db, "m00000000_000000_missing")
+                .await
+                .is_err()
+        );
+
+        // the seed is skipped
+        Migrator::up(&db, None).await?;
+        assert_eq!(run_migrate_status(&Migrator, &db, false, true).await?, 0);
+        let rows = db
+            .query_all_raw(Statement::from_string(
+                db.get_database_backend(),
+                "SELECT * FROM cake",
+            ))
+            .await?;
+        assert!(rows.is_empty());
+
+        Migrator::mark_rolled_back(&db, "m20220118_000003_seed_cake_table").await?;
+        assert!(
+            Migrator::mark_rolled_back(&db, "m20220118_000003_seed_cake_table")
+                .await
+                .is_err()
+        );
+        assert_eq!(run_migrate_status(&Migrator, &db, false, true).await?, 1);
+
+        // the seed is applied this time
+        Migrator::up(&db, None).await?;
+        let rows = db
+            .query_all_raw(Statement::from_string(
+                db.get_database_backend(),
+                "SELECT * FROM cake",
+            ))
+            .await?;
+        assert_eq!(rows.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_upgrade_migration_table() -> Result<(), Box<dyn Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+        let manager = SchemaManager::new(&db);
+
+        // a migration table from before `checksum` and `duration_ms`
+        db.execute_unprepared(
+            r#"CREATE TABLE "seaql_migrations" ("version" varchar NOT NULL PRIMARY KEY, "applied_at" bigint NOT NULL);
+            INSERT INTO "seaql_migrations" VALUES ('m20220118_000001_create_cake_table', 0);"#,
+        )
+        .await?;
+
+        Migrator::install(&db).await?;
+        assert!(manager.has_column("seaql_migrations", "checksum").await?);
+        assert!(
+            manager
+                .has_column("seaql_migrations", "duration_ms")
+                .await?
+        );
+        // installing again leaves the table as is
+        Migrator::install(&db).await?;
+
+        let status = migration_status_json(&Migrator::get_migration_with_status(&db).await?);
+        assert_eq!(status[0]["status"], "applied");
+        assert_eq!(status[0]["applied_at"], 0);
+        assert_eq!(status[0]["checksum"], serde_json::Value::Null);
+        assert_eq!(status[0]["duration_ms"], serde_json::Value::Null);
+
+        Ok(())
+    }
+}
@@ -19,10 +19,24 @@ pub trait ConnectionTrait {
     /// Execute a [`StatementBuilder`]
     fn execute<S: StatementBuilder>(&self, stmt: &S) -> Result<ExecResult, DbErr> {
         let db_backend = self.get_database_backend();
-        let stmt = db_backend.build(stmt);
+        let stmt = stmt.try_build(&db_backend)?;
         self.execute_raw(stmt)
     }
 
+    /// Execute a batch of [Statement]s in order, stopping at the first error.
+    ///
+    /// The whole batch runs on one connection: a pooled
+    /// [`DatabaseConnection`](crate::DatabaseConnection) acquires a single
+    /// connection up front, so session state like temporary tables is visible
+    /// to every statement in the batch.
+    fn execute_batch(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        let mut results = Vec::with_capacity(stmts.len());
+        for stmt in stmts {
+            results.push(self.execute_raw(stmt)?);
+        }
+        Ok(results)
+    }
+
     /// Execute a unprepared [Statement]
     fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr>;
 
@@ -32,7 +46,7 @@ pub trait ConnectionTrait {
     /// Execute a [`StatementBuilder`] and return a single row of `QueryResult`
     fn query_one<S: StatementBuilder>(&self, stmt: &S) -> Result<Option<QueryResult>, DbErr> {
         let db_backend = self.get_database_backend();
-        let stmt = db_backend.build(stmt);
+        let stmt = stmt.try_build(&db_backend)?;
         self.query_one_raw(stmt)
     }
 
@@ -42,7 +56,7 @@ pub trait ConnectionTrait {
     /// Execute a [`StatementBuilder`] and return a vector of `QueryResult`
     fn query_all<S: StatementBuilder>(&self, stmt: &S) -> Result<Vec<QueryResult>, DbErr> {
         let db_backend = self.get_database_backend();
-        let stmt = db_backend.build(stmt);
+        let stmt = stmt.try_build(&db_backend)?;
         self.query_all_raw(stmt)
     }
 
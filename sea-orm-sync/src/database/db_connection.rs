@@ -9,7 +9,9 @@ use tracing::instrument;
 use url::Url;
 
 #[cfg(feature = "sqlx-dep")]
-use sqlx::pool::PoolConnection;
+use crate::driver::SqlxInnerConnection;
+#[cfg(feature = "sqlx-dep")]
+use crate::driver::sqlx_conn_acquire_err;
 
 #[cfg(feature = "rusqlite")]
 use crate::driver::rusqlite::{RusqliteInnerConnection, RusqliteSharedConnection};
@@ -57,6 +59,10 @@ pub enum DatabaseConnectionType {
     #[cfg(feature = "sqlx-sqlite")]
     SqlxSqlitePoolConnection(crate::SqlxSqlitePoolConnection),
 
+    /// A single sqlx connection, not a pool (`sqlx-*`).
+    #[cfg(feature = "sqlx-dep")]
+    SqlxSingleConnection(crate::SqlxSingleConnection),
+
     /// SQLite connection shared across threads (`rusqlite`).
     #[cfg(feature = "rusqlite")]
     RusqliteSharedConnection(RusqliteSharedConnection),
@@ -74,6 +80,15 @@ pub enum DatabaseConnectionType {
     Disconnected,
 }
 
+/// Size of a connection pool, see [`DatabaseConnection::pool_stats`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Number of open connections, in use or idle
+    pub size: u32,
+    /// Number of idle connections
+    pub idle: usize,
+}
+
 /// Short alias for [`DatabaseConnection`].
 pub type DbConn = DatabaseConnection;
 
@@ -114,11 +129,11 @@ pub type DbBackend = DatabaseBackend;
 #[derive(Debug)]
 pub(crate) enum InnerConnection {
     #[cfg(feature = "sqlx-mysql")]
-    MySql(PoolConnection<sqlx::MySql>),
+    MySql(SqlxInnerConnection<sqlx::MySql>),
     #[cfg(feature = "sqlx-postgres")]
-    Postgres(PoolConnection<sqlx::Postgres>),
+    Postgres(SqlxInnerConnection<sqlx::Postgres>),
     #[cfg(feature = "sqlx-sqlite")]
-    Sqlite(PoolConnection<sqlx::Sqlite>),
+    Sqlite(SqlxInnerConnection<sqlx::Sqlite>),
     #[cfg(feature = "rusqlite")]
     Rusqlite(RusqliteInnerConnection),
     #[cfg(feature = "mock")]
@@ -127,6 +142,25 @@ pub(crate) enum InnerConnection {
     Proxy(Arc<crate::ProxyDatabaseConnection>),
 }
 
+/// The raw sqlx connection handed out by
+/// [`DatabaseConnection::with_raw_sqlx`] and
+/// [`DatabaseTransaction::with_raw_sqlx`], for driver features SeaORM does
+/// not wrap. Match on the backend you expect.
+#[cfg(feature = "sqlx-dep")]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SqlxConnection<'a> {
+    /// A MySQL connection.
+    #[cfg(feature = "sqlx-mysql")]
+    MySql(&'a mut sqlx::MySqlConnection),
+    /// A Postgres connection.
+    #[cfg(feature = "sqlx-postgres")]
+    Postgres(&'a mut sqlx::PgConnection),
+    /// A SQLite connection.
+    #[cfg(feature = "sqlx-sqlite")]
+    Sqlite(&'a mut sqlx::SqliteConnection),
+}
+
 impl Debug for DatabaseConnectionType {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(
@@ -139,6 +173,8 @@ impl Debug for DatabaseConnectionType {
                 Self::SqlxPostgresPoolConnection(_) => "SqlxPostgresPoolConnection",
                 #[cfg(feature = "sqlx-sqlite")]
                 Self::SqlxSqlitePoolConnection(_) => "SqlxSqlitePoolConnection",
+                #[cfg(feature = "sqlx-dep")]
+                Self::SqlxSingleConnection(_) => "SqlxSingleConnection",
                 #[cfg(feature = "rusqlite")]
                 Self::RusqliteSharedConnection(_) => "RusqliteSharedConnection",
                 #[cfg(feature = "mock")]
@@ -175,15 +211,75 @@ impl ConnectionTrait for DatabaseConnection {
                     #[cfg(feature = "rusqlite")]
                     DatabaseConnectionType::RusqliteSharedConnection(conn) => conn.execute(stmt),
                     #[cfg(feature = "mock")]
-                    DatabaseConnectionType::MockDatabaseConnection(conn) => conn.execute(stmt),
+                    DatabaseConnectionType::MockDatabaseConnection(conn) => {
+                        let metric_callback = conn.metric_callback();
+                        crate::metric::metric!(metric_callback, &stmt, {
+                            conn.execute(stmt.clone())
+                        })
+                    }
                     #[cfg(feature = "proxy")]
-                    DatabaseConnectionType::ProxyDatabaseConnection(conn) => conn.execute(stmt),
+                    DatabaseConnectionType::ProxyDatabaseConnection(conn) => {
+                        let metric_callback = conn.metric_callback();
+                        crate::metric::metric!(metric_callback, &stmt, {
+                            conn.execute(stmt.clone())
+                        })
+                    }
+                    #[cfg(feature = "sqlx-dep")]
+                    DatabaseConnectionType::SqlxSingleConnection(conn) => {
+                        let conn = conn.pin();
+                        conn.execute_raw(stmt)
+                    }
                     DatabaseConnectionType::Disconnected => Err(conn_err("Disconnected")),
                 }
             }
         )
     }
 
+    #[instrument(level = "trace", skip(stmts))]
+    #[allow(unused_variables)]
+    fn execute_batch(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        match &self.inner {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnectionType::SqlxMySqlPoolConnection(conn) => conn.execute_batch(stmts),
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnectionType::SqlxPostgresPoolConnection(conn) => conn.execute_batch(stmts),
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnectionType::SqlxSqlitePoolConnection(conn) => conn.execute_batch(stmts),
+            #[cfg(feature = "rusqlite")]
+            DatabaseConnectionType::RusqliteSharedConnection(conn) => conn.execute_batch(stmts),
+            #[cfg(feature = "mock")]
+            DatabaseConnectionType::MockDatabaseConnection(conn) => {
+                let metric_callback = conn.metric_callback();
+                stmts
+                    .into_iter()
+                    .map(|stmt| {
+                        crate::metric::metric!(metric_callback, &stmt, {
+                            conn.execute(stmt.clone())
+                        })
+                    })
+                    .collect()
+            }
+            #[cfg(feature = "proxy")]
+            DatabaseConnectionType::ProxyDatabaseConnection(conn) => {
+                let metric_callback = conn.metric_callback();
+                let mut results = Vec::with_capacity(stmts.len());
+                for stmt in stmts {
+                    let res = crate::metric::metric!(metric_callback, &stmt, {
+                        conn.execute(stmt.clone())
+                    });
+                    results.push(res?);
+                }
+                Ok(results)
+            }
+            #[cfg(feature = "sqlx-dep")]
+            DatabaseConnectionType::SqlxSingleConnection(conn) => {
+                let conn = conn.pin();
+                conn.execute_batch(stmts)
+            }
+            DatabaseConnectionType::Disconnected => Err(conn_err("Disconnected")),
+        }
+    }
+
     #[instrument(level = "trace", skip(sql))]
     #[allow(unused_variables)]
     fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
@@ -222,6 +318,11 @@ impl ConnectionTrait for DatabaseConnection {
                         let stmt = Statement::from_string(db_backend, sql);
                         conn.execute(stmt)
                     }
+                    #[cfg(feature = "sqlx-dep")]
+                    DatabaseConnectionType::SqlxSingleConnection(conn) => {
+                        let conn = conn.pin();
+                        conn.execute_unprepared(sql)
+                    }
                     DatabaseConnectionType::Disconnected => Err(conn_err("Disconnected")),
                 }
             }
@@ -249,9 +350,24 @@ impl ConnectionTrait for DatabaseConnection {
                     #[cfg(feature = "rusqlite")]
                     DatabaseConnectionType::RusqliteSharedConnection(conn) => conn.query_one(stmt),
                     #[cfg(feature = "mock")]
-                    DatabaseConnectionType::MockDatabaseConnection(conn) => conn.query_one(stmt),
+                    DatabaseConnectionType::MockDatabaseConnection(conn) => {
+                        let metric_callback = conn.metric_callback();
+                        crate::metric::metric!(metric_callback, &stmt, {
+                            conn.query_one(stmt.clone())
+                        })
+                    }
                     #[cfg(feature = "proxy")]
-                    DatabaseConnectionType::ProxyDatabaseConnection(conn) => conn.query_one(stmt),
+                    DatabaseConnectionType::ProxyDatabaseConnection(conn) => {
+                        let metric_callback = conn.metric_callback();
+                        crate::metric::metric!(metric_callback, &stmt, {
+                            conn.query_one(stmt.clone())
+                        })
+                    }
+                    #[cfg(feature = "sqlx-dep")]
+                    DatabaseConnectionType::SqlxSingleConnection(conn) => {
+                        let conn = conn.pin();
+                        conn.query_one_raw(stmt)
+                    }
                     DatabaseConnectionType::Disconnected => Err(conn_err("Disconnected")),
                 }
             }
@@ -279,15 +395,38 @@ impl ConnectionTrait for DatabaseConnection {
                     #[cfg(feature = "rusqlite")]
                     DatabaseConnectionType::RusqliteSharedConnection(conn) => conn.query_all(stmt),
                     #[cfg(feature = "mock")]
-                    DatabaseConnectionType::MockDatabaseConnection(conn) => conn.query_all(stmt),
+                    DatabaseConnectionType::MockDatabaseConnection(conn) => {
+                        let metric_callback = conn.metric_callback();
+                        crate::metric::metric!(metric_callback, &stmt, {
+                            conn.query_all(stmt.clone())
+                        })
+                    }
                     #[cfg(feature = "proxy")]
-                    DatabaseConnectionType::ProxyDatabaseConnection(conn) => conn.query_all(stmt),
+                    DatabaseConnectionType::ProxyDatabaseConnection(conn) => {
+                        let metric_callback = conn.metric_callback();
+                        crate::metric::metric!(metric_callback, &stmt, {
+                            conn.query_all(stmt.clone())
+                        })
+                    }
+                    #[cfg(feature = "sqlx-dep")]
+                    DatabaseConnectionType::SqlxSingleConnection(conn) => {
+                        let conn = conn.pin();
+                        conn.query_all_raw(stmt)
+                    }
                     DatabaseConnectionType::Disconnected => Err(conn_err("Disconnected")),
                 }
             }
         )
     }
 
+    fn support_returning(&self) -> bool {
+        match &self.inner {
+            #[cfg(feature = "proxy")]
+            DatabaseConnectionType::ProxyDatabaseConnection(conn) => conn.support_returning(),
+            _ => self.get_database_backend().support_returning(),
+        }
+    }
+
     #[cfg(feature = "mock")]
     fn is_mock_connection(&self) -> bool {
         matches!(
@@ -322,13 +461,15 @@ impl StreamTrait for DatabaseConnection {
                 #[cfg(feature = "rusqlite")]
                 DatabaseConnectionType::RusqliteSharedConnection(conn) => conn.stream(stmt),
                 #[cfg(feature = "mock")]
-                DatabaseConnectionType::MockDatabaseConnection(conn) => {
-                    Ok(crate::QueryStream::from((Arc::clone(conn), stmt, None)))
-                }
+                DatabaseConnectionType::MockDatabaseConnection(conn) => Ok(
+                    crate::QueryStream::from((Arc::clone(conn), stmt, conn.metric_callback())),
+                ),
                 #[cfg(feature = "proxy")]
-                DatabaseConnectionType::ProxyDatabaseConnection(conn) => {
-                    Ok(crate::QueryStream::from((Arc::clone(conn), stmt, None)))
-                }
+                DatabaseConnectionType::ProxyDatabaseConnection(conn) => Ok(
+                    crate::QueryStream::from((Arc::clone(conn), stmt, conn.metric_callback())),
+                ),
+                #[cfg(feature = "sqlx-dep")]
+                DatabaseConnectionType::SqlxSingleConnection(conn) => conn.stream(stmt),
                 DatabaseConnectionType::Disconnected => Err(conn_err("Disconnected")),
             }
         })
@@ -351,11 +492,16 @@ impl TransactionTrait for DatabaseConnection {
             DatabaseConnectionType::RusqliteSharedConnection(conn) => conn.begin(None, None, None),
             #[cfg(feature = "mock")]
             DatabaseConnectionType::MockDatabaseConnection(conn) => {
-                DatabaseTransaction::new_mock(Arc::clone(conn), None)
+                DatabaseTransaction::new_mock(Arc::clone(conn), conn.metric_callback())
             }
             #[cfg(feature = "proxy")]
             DatabaseConnectionType::ProxyDatabaseConnection(conn) => {
-                DatabaseTransaction::new_proxy(conn.clone(), None)
+                DatabaseTransaction::new_proxy(conn.clone(), conn.metric_callback())
+            }
+            #[cfg(feature = "sqlx-dep")]
+            DatabaseConnectionType::SqlxSingleConnection(conn) => {
+                let conn = conn.pin();
+                conn.begin()
             }
             DatabaseConnectionType::Disconnected => Err(conn_err("Disconnected")),
         }
@@ -386,11 +532,15 @@ impl TransactionTrait for DatabaseConnection {
             }
             #[cfg(feature = "mock")]
             DatabaseConnectionType::MockDatabaseConnection(conn) => {
-                DatabaseTransaction::new_mock(Arc::clone(conn), None)
+                DatabaseTransaction::new_mock(Arc::clone(conn), conn.metric_callback())
             }
             #[cfg(feature = "proxy")]
             DatabaseConnectionType::ProxyDatabaseConnection(conn) => {
-                DatabaseTransaction::new_proxy(conn.clone(), None)
+                DatabaseTransaction::new_proxy(conn.clone(), conn.metric_callback())
+            }
+            #[cfg(feature = "sqlx-dep")]
+            DatabaseConnectionType::SqlxSingleConnection(conn) => {
+                conn.pin().begin_with_config(_isolation_level, _access_mode)
             }
             DatabaseConnectionType::Disconnected => Err(conn_err("Disconnected")),
         }
@@ -424,11 +574,19 @@ impl TransactionTrait for DatabaseConnection {
             }
             #[cfg(feature = "mock")]
             DatabaseConnectionType::MockDatabaseConnection(conn) => {
-                DatabaseTransaction::new_mock(Arc::clone(conn), None)
+                DatabaseTransaction::new_mock(Arc::clone(conn), conn.metric_callback())
             }
             #[cfg(feature = "proxy")]
             DatabaseConnectionType::ProxyDatabaseConnection(conn) => {
-                DatabaseTransaction::new_proxy(conn.clone(), None)
+                DatabaseTransaction::new_proxy(conn.clone(), conn.metric_callback())
+            }
+            #[cfg(feature = "sqlx-dep")]
+            DatabaseConnectionType::SqlxSingleConnection(conn) => {
+                conn.pin().begin_with_options(TransactionOptions {
+                    isolation_level: _isolation_level,
+                    access_mode: _access_mode,
+                    sqlite_transaction_mode: _sqlite_transaction_mode,
+                })
             }
             DatabaseConnectionType::Disconnected => Err(conn_err("Disconnected")),
         }
@@ -461,16 +619,23 @@ impl TransactionTrait for DatabaseConnection {
             }
             #[cfg(feature = "mock")]
             DatabaseConnectionType::MockDatabaseConnection(conn) => {
-                let transaction = DatabaseTransaction::new_mock(Arc::clone(conn), None)
-                    .map_err(TransactionError::Connection)?;
+                let transaction =
+                    DatabaseTransaction::new_mock(Arc::clone(conn), conn.metric_callback())
+                        .map_err(TransactionError::Connection)?;
                 transaction.run(_callback)
             }
             #[cfg(feature = "proxy")]
             DatabaseConnectionType::ProxyDatabaseConnection(conn) => {
-                let transaction = DatabaseTransaction::new_proxy(conn.clone(), None)
-                    .map_err(TransactionError::Connection)?;
+                let transaction =
+                    DatabaseTransaction::new_proxy(conn.clone(), conn.metric_callback())
+                        .map_err(TransactionError::Connection)?;
                 transaction.run(_callback)
             }
+            #[cfg(feature = "sqlx-dep")]
+            DatabaseConnectionType::SqlxSingleConnection(conn) => {
+                let conn = conn.pin();
+                conn.transaction(_callback)
+            }
             DatabaseConnectionType::Disconnected => Err(conn_err("Disconnected").into()),
         }
     }
@@ -507,16 +672,22 @@ impl TransactionTrait for DatabaseConnection {
             }
             #[cfg(feature = "mock")]
             DatabaseConnectionType::MockDatabaseConnection(conn) => {
-                let transaction = DatabaseTransaction::new_mock(Arc::clone(conn), None)
-                    .map_err(TransactionError::Connection)?;
+                let transaction =
+                    DatabaseTransaction::new_mock(Arc::clone(conn), conn.metric_callback())
+                        .map_err(TransactionError::Connection)?;
                 transaction.run(_callback)
             }
             #[cfg(feature = "proxy")]
             DatabaseConnectionType::ProxyDatabaseConnection(conn) => {
-                let transaction = DatabaseTransaction::new_proxy(conn.clone(), None)
-                    .map_err(TransactionError::Connection)?;
+                let transaction =
+                    DatabaseTransaction::new_proxy(conn.clone(), conn.metric_callback())
+                        .map_err(TransactionError::Connection)?;
                 transaction.run(_callback)
             }
+            #[cfg(feature = "sqlx-dep")]
+            DatabaseConnectionType::SqlxSingleConnection(conn) => conn
+                .pin()
+                .transaction_with_config(_callback, _isolation_level, _access_mode),
             DatabaseConnectionType::Disconnected => Err(conn_err("Disconnected").into()),
         }
     }
@@ -549,6 +720,16 @@ impl DatabaseConnection {
             .expect("Fail to acquire mocker");
         mocker.drain_transaction_log()
     }
+
+    /// Check that every statement expected via [`MockDatabase::expect_query`](crate::MockDatabase::expect_query)
+    /// or [`MockDatabase::expect_exec`](crate::MockDatabase::expect_exec) was received
+    ///
+    /// # Panics
+    ///
+    /// Panics with a report of missing and unexpected statements, or if [DbConn] is not a mock connection.
+    pub fn verify(&self) {
+        self.as_mock_connection().verify()
+    }
 }
 
 #[cfg(feature = "proxy")]
@@ -648,6 +829,8 @@ impl DatabaseConnection {
             DatabaseConnectionType::SqlxSqlitePoolConnection(conn) => conn.record_stmt_in_spans,
             #[cfg(feature = "rusqlite")]
             DatabaseConnectionType::RusqliteSharedConnection(conn) => conn.record_stmt_in_spans,
+            #[cfg(feature = "sqlx-dep")]
+            DatabaseConnectionType::SqlxSingleConnection(conn) => conn.record_stmt_in_spans,
             DatabaseConnectionType::Disconnected => true,
             #[cfg(feature = "mock")]
             DatabaseConnectionType::MockDatabaseConnection(_) => true,
@@ -675,6 +858,8 @@ impl DatabaseConnection {
             DatabaseConnectionType::MockDatabaseConnection(conn) => conn.get_database_backend(),
             #[cfg(feature = "proxy")]
             DatabaseConnectionType::ProxyDatabaseConnection(conn) => conn.get_database_backend(),
+            #[cfg(feature = "sqlx-dep")]
+            DatabaseConnectionType::SqlxSingleConnection(conn) => conn.get_database_backend(),
             DatabaseConnectionType::Disconnected => panic!("Disconnected"),
         }
     }
@@ -693,28 +878,125 @@ impl DatabaseConnection {
     }
 
     /// Sets a callback to metric this connection
-    pub fn set_metric_callback<F>(&mut self, _callback: F)
+    pub fn set_metric_callback<F>(&mut self, callback: F)
     where
         F: Fn(&crate::metric::Info<'_>) + 'static,
     {
+        let hooks = crate::metric::with_callback(self.metric_callback(), callback);
+        self.replace_metric_callback(hooks);
+    }
+
+    /// Start counting the statements executed on this connection, including the ones in
+    /// transactions and streams started from it. Works alongside a metric callback set with
+    /// [`DatabaseConnection::set_metric_callback`]. Enabling it again keeps the count.
+    ///
+    /// Like the metric callback, the counter is only shared with clones of the connection
+    /// made after it is enabled.
+    pub fn enable_query_counting(&mut self) {
+        let hooks = crate::metric::with_counter(self.metric_callback());
+        self.replace_metric_callback(hooks);
+    }
+
+    /// Number of statements executed since [`DatabaseConnection::enable_query_counting`]
+    /// was called, or 0 if counting is disabled
+    pub fn query_count(&self) -> u64 {
+        self.metric_callback()
+            .and_then(|hooks| hooks.query_count())
+            .unwrap_or_default()
+    }
+
+    /// Run `f` and return its output along with the number of statements executed
+    /// meanwhile. Counting has to be enabled with
+    /// [`DatabaseConnection::enable_query_counting`], otherwise the count is 0.
+    ///
+    /// Statements executed concurrently on the same connection (e.g. by other tasks
+    /// using a clone of it) are counted too.
+    ///
+    /// ```
+    /// # #[cfg(feature = "mock")]
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[cfg(feature = "mock")]
+    /// # pub fn main() -> Result<(), DbErr> {
+    /// #
+    /// let mut db = MockDatabase::new(DbBackend::Postgres)
+    ///     .append_query_results([[cake::Model {
+    ///         id: 1,
+    ///         name: "Apple Cake".to_owned(),
+    ///     }]])
+    ///     .append_query_results([Vec::<fruit::Model>::new()])
+    ///     .into_connection();
+    /// db.enable_query_counting();
+    ///
+    /// let (cakes, count) = db.count_queries(|| {
+    ///     let cakes = cake::Entity::find().all(&db)?;
+    ///     cakes.load_many(fruit::Entity, &db)?;
+    ///     Ok::<_, DbErr>(cakes)
+    /// });
+    /// assert_eq!(cakes?.len(), 1);
+    /// assert_eq!(count, 2);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn count_queries<F, R>(&self, f: F) -> (R, u64)
+    where
+        F: FnOnce() -> R,
+    {
+        let before = self.query_count();
+        let res = f();
+        (res, self.query_count() - before)
+    }
+
+    fn metric_callback(&self) -> Option<crate::metric::Callback> {
+        match &self.inner {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnectionType::SqlxMySqlPoolConnection(conn) => conn.metric_callback(),
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnectionType::SqlxPostgresPoolConnection(conn) => conn.metric_callback(),
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnectionType::SqlxSqlitePoolConnection(conn) => conn.metric_callback(),
+            #[cfg(feature = "rusqlite")]
+            DatabaseConnectionType::RusqliteSharedConnection(conn) => conn.metric_callback(),
+            #[cfg(feature = "mock")]
+            DatabaseConnectionType::MockDatabaseConnection(conn) => conn.metric_callback(),
+            #[cfg(feature = "proxy")]
+            DatabaseConnectionType::ProxyDatabaseConnection(conn) => conn.metric_callback(),
+            #[cfg(feature = "sqlx-dep")]
+            DatabaseConnectionType::SqlxSingleConnection(conn) => conn.metric_callback(),
+            DatabaseConnectionType::Disconnected => None,
+        }
+    }
+
+    fn replace_metric_callback(&mut self, _hooks: crate::metric::Callback) {
         match &mut self.inner {
             #[cfg(feature = "sqlx-mysql")]
             DatabaseConnectionType::SqlxMySqlPoolConnection(conn) => {
-                conn.set_metric_callback(_callback)
+                conn.set_metric_callback(_hooks)
             }
             #[cfg(feature = "sqlx-postgres")]
             DatabaseConnectionType::SqlxPostgresPoolConnection(conn) => {
-                conn.set_metric_callback(_callback)
+                conn.set_metric_callback(_hooks)
             }
             #[cfg(feature = "sqlx-sqlite")]
             DatabaseConnectionType::SqlxSqlitePoolConnection(conn) => {
-                conn.set_metric_callback(_callback)
+                conn.set_metric_callback(_hooks)
             }
             #[cfg(feature = "rusqlite")]
             DatabaseConnectionType::RusqliteSharedConnection(conn) => {
-                conn.set_metric_callback(_callback)
+                conn.set_metric_callback(_hooks)
+            }
+            #[cfg(feature = "mock")]
+            DatabaseConnectionType::MockDatabaseConnection(conn) => {
+                conn.set_metric_callback(_hooks)
             }
-            _ => {}
+            #[cfg(feature = "proxy")]
+            DatabaseConnectionType::ProxyDatabaseConnection(conn) => {
+                conn.set_metric_callback(_hooks)
+            }
+            #[cfg(feature = "sqlx-dep")]
+            DatabaseConnectionType::SqlxSingleConnection(conn) => conn.set_metric_callback(_hooks),
+            DatabaseConnectionType::Disconnected => {}
         }
     }
 
@@ -733,6 +1015,8 @@ impl DatabaseConnection {
             DatabaseConnectionType::MockDatabaseConnection(conn) => conn.ping(),
             #[cfg(feature = "proxy")]
             DatabaseConnectionType::ProxyDatabaseConnection(conn) => conn.ping(),
+            #[cfg(feature = "sqlx-dep")]
+            DatabaseConnectionType::SqlxSingleConnection(conn) => conn.ping(),
             DatabaseConnectionType::Disconnected => Err(conn_err("Disconnected")),
         }
     }
@@ -743,7 +1027,8 @@ impl DatabaseConnection {
         self.close_by_ref()
     }
 
-    /// Explicitly close the database connection
+    /// Explicitly close the database connection. Closing an already closed
+    /// connection is a no-op.
     pub fn close_by_ref(&self) -> Result<(), DbErr> {
         match &self.inner {
             #[cfg(feature = "sqlx-mysql")]
@@ -764,7 +1049,49 @@ impl DatabaseConnection {
                 // Nothing to cleanup, we just consume the `DatabaseConnection`
                 Ok(())
             }
-            DatabaseConnectionType::Disconnected => Err(conn_err("Disconnected")),
+            #[cfg(feature = "sqlx-dep")]
+            DatabaseConnectionType::SqlxSingleConnection(_) => {
+                // Closed once the last clone of the `DatabaseConnection` is dropped
+                Ok(())
+            }
+            DatabaseConnectionType::Disconnected => Ok(()),
+        }
+    }
+
+    /// Gracefully close the database connection: stop handing out connections, then
+    /// wait at most `timeout` for queries in flight to finish. Queries on this
+    /// `DatabaseConnection` fail from the moment this is called.
+    ///
+    /// Past the timeout, the queries still running are cancelled, failing with
+    /// [`ConnAcquireErr::ConnectionClosed`](crate::ConnAcquireErr::ConnectionClosed), and their
+    /// connections closed. A transaction or stream still open keeps its connection until it's
+    /// dropped.
+    #[allow(unused_variables)]
+    pub fn close_with_timeout(&self, timeout: std::time::Duration) -> Result<(), DbErr> {
+        match &self.inner {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnectionType::SqlxMySqlPoolConnection(conn) => {
+                conn.close_with_timeout(timeout)
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnectionType::SqlxPostgresPoolConnection(conn) => {
+                conn.close_with_timeout(timeout)
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnectionType::SqlxSqlitePoolConnection(conn) => {
+                conn.close_with_timeout(timeout)
+            }
+            #[cfg(feature = "rusqlite")]
+            DatabaseConnectionType::RusqliteSharedConnection(conn) => {
+                conn.close_with_timeout(timeout)
+            }
+            #[cfg(feature = "mock")]
+            DatabaseConnectionType::MockDatabaseConnection(_) => Ok(()),
+            #[cfg(feature = "proxy")]
+            DatabaseConnectionType::ProxyDatabaseConnection(_) => Ok(()),
+            #[cfg(feature = "sqlx-dep")]
+            DatabaseConnectionType::SqlxSingleConnection(_) => Ok(()),
+            DatabaseConnectionType::Disconnected => Ok(()),
         }
     }
 }
@@ -808,6 +1135,69 @@ impl DatabaseConnection {
             _ => panic!("Not SQLite Connection"),
         }
     }
+
+    /// Number of open and idle connections of the pool, or `None` if this is not
+    /// a sqlx connection pool
+    pub fn pool_stats(&self) -> Option<PoolStats> {
+        match &self.inner {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnectionType::SqlxMySqlPoolConnection(conn) => Some(PoolStats {
+                size: conn.pool.size(),
+                idle: conn.pool.num_idle(),
+            }),
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnectionType::SqlxPostgresPoolConnection(conn) => Some(PoolStats {
+                size: conn.pool.size(),
+                idle: conn.pool.num_idle(),
+            }),
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnectionType::SqlxSqlitePoolConnection(conn) => Some(PoolStats {
+                size: conn.pool.size(),
+                idle: conn.pool.num_idle(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Run `f` with a raw sqlx connection acquired from the pool, for driver
+    /// features SeaORM does not wrap (e.g. `COPY` on Postgres). The
+    /// connection goes back to the pool once `f` returns.
+    ///
+    /// Statements run this way bypass SeaORM entirely: they are not logged,
+    /// metered or checked by RBAC. To share a transaction with SeaORM
+    /// queries, use [`DatabaseTransaction::with_raw_sqlx`] instead.
+    ///
+    /// Returns an error if this is not a sqlx connection.
+    #[cfg(feature = "sqlx-dep")]
+    pub fn with_raw_sqlx<F, R>(&self, f: F) -> Result<R, DbErr>
+    where
+        F: FnOnce(SqlxConnection<'_>) -> R,
+    {
+        match &self.inner {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnectionType::SqlxMySqlPoolConnection(conn) => {
+                let mut conn = conn.pool.acquire().map_err(sqlx_conn_acquire_err)?;
+                Ok(f(SqlxConnection::MySql(&mut conn)))
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnectionType::SqlxPostgresPoolConnection(conn) => {
+                let mut conn = conn.pool.acquire().map_err(sqlx_conn_acquire_err)?;
+                Ok(f(SqlxConnection::Postgres(&mut conn)))
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnectionType::SqlxSqlitePoolConnection(conn) => {
+                let mut conn = conn.pool.acquire().map_err(sqlx_conn_acquire_err)?;
+                Ok(f(SqlxConnection::Sqlite(&mut conn)))
+            }
+            DatabaseConnectionType::SqlxSingleConnection(conn) => {
+                let conn = conn.pin();
+                conn.with_raw_sqlx(f)
+            }
+            DatabaseConnectionType::Disconnected => Err(conn_err("Disconnected")),
+            #[allow(unreachable_patterns)]
+            _ => Err(conn_err("Not a sqlx connection")),
+        }
+    }
 }
 
 impl DbBackend {
@@ -1,4 +1,6 @@
-use crate::{ExecResult, ExecResultHolder, QueryResult, QueryResultRow, Statement, error::*};
+use crate::{
+    DbBackend, ExecResult, ExecResultHolder, QueryResult, QueryResultRow, Statement, error::*,
+};
 
 use sea_query::{Value, ValueType};
 use std::{collections::BTreeMap, fmt::Debug};
@@ -11,6 +13,31 @@ pub trait ProxyDatabaseTrait: Debug {
     /// Execute a command in the [ProxyDatabase], and report the number of rows affected
     fn execute(&self, statement: Statement) -> Result<ProxyExecResult, DbErr>;
 
+    /// Execute a query in the [ProxyDatabase], and stream the query results back.
+    /// The default implementation serves the rows returned by [`query`](Self::query);
+    /// override it to yield rows as they arrive, e.g. from a channel.
+    #[cfg(feature = "stream")]
+    fn stream(&self, statement: Statement) -> Result<ProxyRowStream, DbErr> {
+        let rows = self.query(statement)?;
+        #[cfg(not(feature = "sync"))]
+        {
+            Ok(Box::new(futures_util::stream::iter(
+                rows.into_iter().map(Ok),
+            )))
+        }
+        #[cfg(feature = "sync")]
+        {
+            Ok(Box::new(rows.into_iter().map(Ok)))
+        }
+    }
+
+    /// Check if the [ProxyDatabase] supports `RETURNING` syntax on insert and update.
+    /// If `true`, the returning rows are fetched with [`query`](Self::query) instead of
+    /// relying on [`ProxyExecResult::last_insert_id`]. Defaults to what the backend supports.
+    fn support_returning(&self, db_backend: DbBackend) -> bool {
+        db_backend.support_returning()
+    }
+
     /// Begin a transaction in the [ProxyDatabase]
     fn begin(&self) {}
 
@@ -29,6 +56,14 @@ pub trait ProxyDatabaseTrait: Debug {
     }
 }
 
+/// The rows streamed back from [ProxyDatabaseTrait::stream]
+#[cfg(all(feature = "stream", not(feature = "sync")))]
+pub type ProxyRowStream =
+    std::pin::Pin<Box<dyn futures_util::Stream<Item = Result<ProxyRow, DbErr>>>>;
+/// The rows streamed back from [ProxyDatabaseTrait::stream]
+#[cfg(all(feature = "stream", feature = "sync"))]
+pub type ProxyRowStream = Box<dyn Iterator<Item = Result<ProxyRow, DbErr>>>;
+
 /// Defines the results obtained from a [ProxyDatabase]
 #[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
 pub struct ProxyExecResult {
@@ -194,9 +229,9 @@ impl ProxyRow {
 mod tests {
     use crate::{
         Database, DbBackend, DbErr, ProxyDatabaseTrait, ProxyExecResult, ProxyRow, Statement,
-        entity::*, tests_cfg::*,
+        TransactionTrait, entity::*, tests_cfg::*,
     };
-    use std::sync::Arc;
+    use std::sync::{Arc, Mutex};
 
     #[derive(Debug)]
     struct ProxyDb {}
@@ -240,4 +275,125 @@ mod tests {
 
         cake::Entity::insert(item).exec(&db).unwrap();
     }
+
+    /// Records every statement and serves the same canned cake rows for
+    /// queries, streams and `RETURNING` inserts
+    #[derive(Debug, Default)]
+    struct RecordingProxyDb {
+        statements: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl RecordingProxyDb {
+        fn record(&self, statement: &Statement) {
+            self.statements.lock().unwrap().push(statement.sql.clone());
+        }
+
+        fn cakes() -> Vec<ProxyRow> {
+            (1..=3)
+                .map(|id| {
+                    ProxyRow::new(maplit::btreemap! {
+                        "id".to_owned() => id.into(),
+                        "name".to_owned() => format!("Cake {id}").into(),
+                    })
+                })
+                .collect()
+        }
+    }
+
+    impl ProxyDatabaseTrait for RecordingProxyDb {
+        fn query(&self, statement: Statement) -> Result<Vec<ProxyRow>, DbErr> {
+            self.record(&statement);
+            let mut rows = Self::cakes();
+            if statement.sql.starts_with("INSERT") {
+                // a single row was inserted, `RETURNING` yields it back
+                rows.truncate(1);
+            }
+            Ok(rows)
+        }
+
+        fn execute(&self, statement: Statement) -> Result<ProxyExecResult, DbErr> {
+            self.record(&statement);
+            Ok(ProxyExecResult::new(0, 1))
+        }
+
+        #[cfg(all(feature = "stream", not(feature = "sync")))]
+        fn stream(&self, statement: Statement) -> Result<crate::ProxyRowStream, DbErr> {
+            self.record(&statement);
+            let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+            for row in Self::cakes() {
+                sender.send(Ok(row)).unwrap();
+            }
+            Ok(Box::new(futures_util::stream::poll_fn(move |cx| {
+                receiver.poll_recv(cx)
+            })))
+        }
+
+        fn support_returning(&self, _: DbBackend) -> bool {
+            true
+        }
+    }
+
+    #[cfg(all(feature = "stream", not(feature = "sync")))]
+    #[test]
+    fn stream_rows() -> Result<(), DbErr> {
+        use futures_util::TryStreamExt;
+
+        let proxy = RecordingProxyDb::default();
+        let statements = proxy.statements.clone();
+        let db = Database::connect_proxy(DbBackend::MySql, Arc::new(Box::new(proxy)))?;
+
+        let cakes: Vec<cake::Model> = cake::Entity::find().stream(&db)?.try_collect()?;
+        assert_eq!(cakes.len(), 3);
+        assert_eq!(cakes[2].name, "Cake 3");
+
+        let txn = db.begin()?;
+        let cakes: Vec<cake::Model> = cake::Entity::find().stream(&txn)?.try_collect()?;
+        assert_eq!(cakes.len(), 3);
+        txn.commit()?;
+
+        assert_eq!(
+            *statements.lock().unwrap(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn insert_with_returning() -> Result<(), DbErr> {
+        let proxy = RecordingProxyDb::default();
+        let statements = proxy.statements.clone();
+        let db = Database::connect_proxy(DbBackend::Sqlite, Arc::new(Box::new(proxy)))?;
+
+        let item = cake::ActiveModel {
+            id: NotSet,
+            name: Set("Cake 1".to_owned()),
+        };
+        let cake = cake::Entity::insert(item.clone()).exec_with_returning(&db)?;
+        assert_eq!(
+            cake,
+            cake::Model {
+                id: 1,
+                name: "Cake 1".to_owned(),
+            }
+        );
+
+        let txn = db.begin()?;
+        let res = cake::Entity::insert(item).exec(&txn)?;
+        assert_eq!(res.last_insert_id, 1);
+        txn.commit()?;
+
+        assert_eq!(
+            *statements.lock().unwrap(),
+            [
+                r#"INSERT INTO "cake" ("name") VALUES (?) RETURNING "id", "name""#,
+                r#"INSERT INTO "cake" ("name") VALUES (?) RETURNING "id""#,
+            ]
+        );
+
+        Ok(())
+    }
 }
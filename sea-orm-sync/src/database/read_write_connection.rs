@@ -0,0 +1,372 @@
+use crate::{
+    AccessMode, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr,
+    ExecResult, IsolationLevel, QueryResult, Statement, TransactionError, TransactionOptions,
+    TransactionTrait,
+};
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use tracing::instrument;
+
+#[cfg(feature = "stream")]
+use crate::StreamTrait;
+
+/// A primary connection with read replicas, routing plain `SELECT`s to the
+/// replicas and everything else to the primary.
+///
+/// Obtained with [`DatabaseConnection::with_replicas`]. Replicas are chosen
+/// round-robin; with no replicas all statements run on the primary. Other
+/// queries, e.g. `INSERT .. RETURNING` or `SELECT .. FOR UPDATE`, as well as
+/// transactions and everything inside them, always run on the primary.
+///
+/// Replicas may lag behind the primary. Where a read must see a preceding
+/// write, read through [`use_primary`](Self::use_primary).
+///
+/// Statements are routed by their SQL alone, so a `SELECT` with side effects,
+/// e.g. `SELECT nextval('seq')` or a call to a function that writes, would be
+/// sent to a replica: run those through [`use_primary`](Self::use_primary) too.
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[cfg(feature = "mock")]
+/// # pub fn main() -> Result<(), DbErr> {
+/// # let primary = MockDatabase::new(DbBackend::Postgres).into_connection();
+/// # let replica = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([Vec::<cake::Model>::new()])
+/// #     .into_connection();
+/// let db = primary.with_replicas(vec![replica]);
+///
+/// // runs on the replica
+/// let cakes = cake::Entity::find().all(&db)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReadWriteConnection {
+    primary: DatabaseConnection,
+    replicas: Arc<[DatabaseConnection]>,
+    next: Arc<AtomicUsize>,
+    use_primary: bool,
+}
+
+impl DatabaseConnection {
+    /// Route read-only queries on this connection to `replicas`, see [`ReadWriteConnection`]
+    pub fn with_replicas(self, replicas: Vec<DatabaseConnection>) -> ReadWriteConnection {
+        ReadWriteConnection::new(self, replicas)
+    }
+}
+
+impl ReadWriteConnection {
+    /// Create a connection writing to `primary` and reading from `replicas`
+    pub fn new(primary: DatabaseConnection, replicas: Vec<DatabaseConnection>) -> Self {
+        Self {
+            primary,
+            replicas: replicas.into(),
+            next: Default::default(),
+            use_primary: false,
+        }
+    }
+
+    /// The primary connection
+    pub fn primary(&self) -> &DatabaseConnection {
+        &self.primary
+    }
+
+    /// The replica connections
+    pub fn replicas(&self) -> &[DatabaseConnection] {
+        &self.replicas
+    }
+
+    /// A handle to the same connections which runs every statement on the primary,
+    /// for reads that must see the writes made before them
+    pub fn use_primary(&self) -> Self {
+        Self {
+            use_primary: true,
+            ..self.clone()
+        }
+    }
+
+    /// The connection to run `stmt` on
+    fn route(&self, stmt: &Statement) -> &DatabaseConnection {
+        if self.use_primary || self.replicas.is_empty() || !is_plain_select(&stmt.sql) {
+            return &self.primary;
+        }
+        let i = self.next.fetch_add(1, Ordering::Relaxed);
+        &self.replicas[i % self.replicas.len()]
+    }
+}
+
+/// Whether `sql` is a `SELECT` that neither writes into a table nor takes row locks,
+/// comparing its words case-insensitively. Writes hidden in function calls are not seen.
+fn is_plain_select(sql: &str) -> bool {
+    let words: Vec<_> = sql
+        .split_whitespace()
+        .map(|word| word.trim_end_matches([',', ';', ')']))
+        .collect();
+    let is = |word: &str, keyword: &str| word.eq_ignore_ascii_case(keyword);
+    match words.split_first() {
+        Some((first, rest)) if is(first, "SELECT") => {
+            !rest.iter().any(|word| is(word, "INTO"))
+                && !rest.windows(2).any(|pair| {
+                    let (first, second) = (pair[0], pair[1]);
+                    (is(first, "FOR")
+                        && ["UPDATE", "NO", "SHARE", "KEY"]
+                            .iter()
+                            .any(|lock| is(second, lock)))
+                        || (is(first, "LOCK") && is(second, "IN"))
+                })
+        }
+        _ => false,
+    }
+}
+
+impl ConnectionTrait for ReadWriteConnection {
+    fn get_database_backend(&self) -> DbBackend {
+        self.primary.get_database_backend()
+    }
+
+    fn support_returning(&self) -> bool {
+        self.primary.support_returning()
+    }
+
+    #[instrument(level = "trace")]
+    fn execute_raw(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.primary.execute_raw(stmt)
+    }
+
+    #[instrument(level = "trace")]
+    fn execute_batch(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        self.primary.execute_batch(stmts)
+    }
+
+    #[instrument(level = "trace")]
+    fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.primary.execute_unprepared(sql)
+    }
+
+    #[instrument(level = "trace")]
+    fn query_one_raw(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.route(&stmt).query_one_raw(stmt)
+    }
+
+    #[instrument(level = "trace")]
+    fn query_all_raw(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.route(&stmt).query_all_raw(stmt)
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.primary.is_mock_connection()
+    }
+}
+
+#[cfg(feature = "stream")]
+impl StreamTrait for ReadWriteConnection {
+    type Stream<'a> = crate::QueryStream;
+
+    fn get_database_backend(&self) -> DbBackend {
+        self.primary.get_database_backend()
+    }
+
+    #[instrument(level = "trace", skip(stmt))]
+    fn stream_raw<'a>(&'a self, stmt: Statement) -> Result<Self::Stream<'a>, DbErr> {
+        self.route(&stmt).stream_raw(stmt)
+    }
+}
+
+impl TransactionTrait for ReadWriteConnection {
+    type Transaction = DatabaseTransaction;
+
+    #[instrument(level = "trace")]
+    fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        self.primary.begin()
+    }
+
+    #[instrument(level = "trace")]
+    fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        self.primary.begin_with_config(isolation_level, access_mode)
+    }
+
+    #[instrument(level = "trace")]
+    fn begin_with_options(
+        &self,
+        options: TransactionOptions,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        self.primary.begin_with_options(options)
+    }
+
+    /// Execute the function inside a transaction on the primary.
+    /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
+    #[instrument(level = "trace", skip(callback))]
+    fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(&'c DatabaseTransaction) -> Result<T, E>,
+        E: std::fmt::Display + std::fmt::Debug,
+    {
+        self.primary.transaction(callback)
+    }
+
+    /// Execute the function inside a transaction on the primary.
+    /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
+    #[instrument(level = "trace", skip(callback))]
+    fn transaction_with_config<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(&'c DatabaseTransaction) -> Result<T, E>,
+        E: std::fmt::Display + std::fmt::Debug,
+    {
+        self.primary
+            .transaction_with_config(callback, isolation_level, access_mode)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        ActiveModelTrait, ActiveValue::Set, ConnectionTrait, DbBackend, DbErr, EntityTrait,
+        MockDatabase, MockExecResult, QuerySelect, Statement, Transaction, TransactionTrait,
+        tests_cfg::cake,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn select_cakes(backend: DbBackend) -> Transaction {
+        Transaction::from_sql_and_values(
+            backend,
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+            [],
+        )
+    }
+
+    #[test]
+    fn read_write_routing() -> Result<(), DbErr> {
+        let primary = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake::Model {
+                id: 1,
+                name: "Cheese Cake".to_owned(),
+            }]])
+            .append_query_results([Vec::<cake::Model>::new(), Vec::new(), Vec::new()])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+        let replica = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([Vec::<cake::Model>::new()])
+            .into_connection();
+        let db = primary.clone().with_replicas(vec![replica.clone()]);
+
+        cake::Entity::find().all(&db)?;
+        // INSERT .. RETURNING is a query, but a write
+        cake::ActiveModel {
+            name: Set("Cheese Cake".to_owned()),
+            ..Default::default()
+        }
+        .insert(&db)?;
+        cake::Entity::find().all(&db.use_primary())?;
+        cake::Entity::find().lock_exclusive().all(&db)?;
+        db.transaction::<_, _, DbErr>(|txn| {
+            ({
+                cake::Entity::find().all(txn)?;
+                Ok(())
+            })
+        })
+        .unwrap();
+        db.execute_unprepared("TRUNCATE cake")?;
+
+        assert_eq!(
+            replica.into_transaction_log(),
+            [select_cakes(DbBackend::Postgres)]
+        );
+        assert_eq!(
+            primary.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "cake" ("name") VALUES ($1) RETURNING "id", "name""#,
+                    ["Cheese Cake".into()]
+                ),
+                select_cakes(DbBackend::Postgres),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" FOR UPDATE"#,
+                    []
+                ),
+                Transaction::many([
+                    Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                    Statement::from_sql_and_values(
+                        DbBackend::Postgres,
+                        r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                        []
+                    ),
+                    Statement::from_string(DbBackend::Postgres, "COMMIT"),
+                ]),
+                Transaction::one(Statement::from_string(DbBackend::Postgres, "TRUNCATE cake")),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn plain_select() {
+        use super::is_plain_select;
+
+        assert!(is_plain_select(r#"SELECT "id" FROM "cake""#));
+        assert!(is_plain_select(
+            r#"select "id" from "cake" where "name" = 'for update'"#
+        ));
+        assert!(!is_plain_select(r#"SELECT "id" FROM "cake" FOR UPDATE"#));
+        assert!(!is_plain_select(r#"select "id" from "cake" for update"#));
+        assert!(!is_plain_select("SELECT `id` FROM `cake`\nFor Share"));
+        assert!(!is_plain_select(
+            r#"SELECT "id" FROM "cake" FOR NO KEY UPDATE NOWAIT"#
+        ));
+        assert!(!is_plain_select(
+            "select `id` from `cake` lock in share mode"
+        ));
+        assert!(!is_plain_select(
+            r#"SELECT "id" INTO "cake_copy" FROM "cake""#
+        ));
+        assert!(!is_plain_select(r#"UPDATE "cake" SET "name" = 'select'"#));
+    }
+
+    #[test]
+    fn read_write_round_robin() -> Result<(), DbErr> {
+        let primary = MockDatabase::new(DbBackend::Postgres).into_connection();
+        let replicas: Vec<_> = (0..2)
+            .map(|_| {
+                MockDatabase::new(DbBackend::Postgres)
+                    .append_query_results([Vec::<cake::Model>::new(), Vec::new()])
+                    .into_connection()
+            })
+            .collect();
+        let db = primary.clone().with_replicas(replicas.clone());
+
+        for _ in 0..4 {
+            cake::Entity::find().all(&db)?;
+        }
+
+        assert!(primary.into_transaction_log().is_empty());
+        for replica in replicas {
+            assert_eq!(
+                replica.into_transaction_log(),
+                [
+                    select_cakes(DbBackend::Postgres),
+                    select_cakes(DbBackend::Postgres)
+                ]
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -1,16 +1,24 @@
-use crate::DbBackend;
+use crate::{DbBackend, DbErr, QueryScope};
 #[cfg(feature = "rbac")]
 pub use sea_query::audit::{AuditTrait, Error as AuditError, QueryAccessAudit};
-use sea_query::{MysqlQueryBuilder, PostgresQueryBuilder, SqliteQueryBuilder, inject_parameters};
+use sea_query::{
+    EscapeBuilder, IndexBuilder, IndexColumn, IndexCreateStatement, IndexDropStatement,
+    MysqlQueryBuilder, PostgresQueryBuilder, QueryStatementBuilder, Quote, QuotedBuilder,
+    SqlWriter, SqlWriterValues, SqliteQueryBuilder, TableRef, TableRefBuilder, inject_parameters,
+};
 pub use sea_query::{Value, Values};
-use std::fmt;
+use std::{cell::Cell, fmt, time::Duration};
 
 /// A SQL string together with its bound parameters, ready to send to a
 /// connection. Build one yourself with
 /// [`from_sql_and_values`](Self::from_sql_and_values) (or the
 /// [`raw_sql!`](crate::raw_sql) macro), or get one out of any query builder
 /// via [`QueryTrait::build`](crate::QueryTrait::build).
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Statements compare equal by SQL, values and backend; the timeout and the
+/// persistence setting are not part of the comparison.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct Statement {
     /// The SQL text, with backend-specific placeholders for the values.
     pub sql: String,
@@ -20,6 +28,33 @@ pub struct Statement {
     /// Backend this statement was built for; both the SQL dialect and the
     /// placeholder style must match.
     pub db_backend: DbBackend,
+    pub(crate) options: StatementOptions,
+}
+
+/// How a [`Statement`] is run, as opposed to what it runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct StatementOptions {
+    /// How long the statement may run before the database cancels it, see
+    /// [`Statement::with_timeout`]. `None` means no limit.
+    pub(crate) timeout: Option<Duration>,
+    /// Whether the statement is prepared once and cached on the connection, see
+    /// [`Statement::persistent`]. `true` by default.
+    pub(crate) persistent: bool,
+}
+
+impl Default for StatementOptions {
+    fn default() -> Self {
+        Self {
+            timeout: None,
+            persistent: true,
+        }
+    }
+}
+
+impl PartialEq for Statement {
+    fn eq(&self, other: &Self) -> bool {
+        self.sql == other.sql && self.values == other.values && self.db_backend == other.db_backend
+    }
 }
 
 /// Anything that can be rendered to a backend-specific [`Statement`].
@@ -32,25 +67,172 @@ pub trait StatementBuilder {
     /// Render `self` into a [`Statement`] for `db_backend`.
     fn build(&self, db_backend: &DbBackend) -> Statement;
 
+    /// Render `self` into a [`Statement`] for `db_backend`, failing with
+    /// [`DbErr::BackendNotSupported`] instead of panicking when the backend can't
+    /// express it. This is how a [`ConnectionTrait`](crate::ConnectionTrait) builds
+    /// the statements it runs.
+    fn try_build(&self, db_backend: &DbBackend) -> Result<Statement, DbErr> {
+        Ok(self.build(db_backend))
+    }
+
+    /// Render `self` into a [`Statement`] for `db_backend`, with the conditions
+    /// of `scope` added, see [`QueryScope::build`]. Statements not reading or
+    /// writing rows, like schema statements, are rendered as is.
+    fn build_scoped(&self, db_backend: &DbBackend, scope: &QueryScope) -> Result<Statement, DbErr> {
+        let _ = scope;
+        self.try_build(db_backend)
+    }
+
     #[cfg(feature = "rbac")]
     /// Inspect the statement and produce the access request that
     /// [`RbacEngine`](crate::rbac::RbacEngine) needs to authorise it.
     fn audit(&self) -> Result<QueryAccessAudit, AuditError>;
 }
 
+/// How value placeholders are written in the SQL of a [`Statement`].
+///
+/// Each [`DbBackend`] has its own style, see [`PlaceholderStyle::of`]. Use
+/// [`Statement::from_statement_builder_with_placeholder`] when the statement has to
+/// go through a transport that expects another style, e.g. a proxy only understanding `?`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaceholderStyle {
+    /// Numbered placeholders `$1`, `$2`, ..., as used by Postgres
+    Numbered,
+    /// Positional placeholders `?`, as used by MySQL and SQLite
+    Question,
+}
+
+impl PlaceholderStyle {
+    /// The placeholder style the backend builds statements with
+    pub fn of(db_backend: DbBackend) -> Self {
+        match db_backend {
+            DbBackend::Postgres => Self::Numbered,
+            DbBackend::MySql | DbBackend::Sqlite => Self::Question,
+        }
+    }
+}
+
 impl Statement {
-    /// Create a [Statement] from a [crate::DatabaseBackend] and a raw SQL statement
-    pub fn from_string<T>(db_backend: DbBackend, stmt: T) -> Statement
+    /// Build a [Statement] for the backend, with the values bound using the given
+    /// [PlaceholderStyle] instead of the backend's own.
+    ///
+    /// ```
+    /// use sea_orm::{DbBackend, PlaceholderStyle, Statement, entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let query = cake::Entity::find()
+    ///     .filter(cake::Column::Id.eq(1))
+    ///     .filter(cake::Column::Name.eq("Cheese"))
+    ///     .into_query();
+    ///
+    /// let stmt = Statement::from_statement_builder_with_placeholder(
+    ///     &query,
+    ///     DbBackend::Postgres,
+    ///     PlaceholderStyle::Question,
+    /// );
+    /// assert_eq!(
+    ///     stmt.sql,
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = ? AND "cake"."name" = ?"#
+    /// );
+    /// assert_eq!(stmt.values, Some(Values(vec![1i32.into(), "Cheese".into()])));
+    /// ```
+    pub fn from_statement_builder_with_placeholder<S>(
+        stmt: &S,
+        db_backend: DbBackend,
+        placeholder: PlaceholderStyle,
+    ) -> Statement
+    where
+        S: QueryStatementBuilder,
+    {
+        let mut writer = match placeholder {
+            PlaceholderStyle::Numbered => SqlWriterValues::new("$", true),
+            PlaceholderStyle::Question => SqlWriterValues::new("?", false),
+        };
+        match db_backend {
+            DbBackend::MySql => stmt.build_collect_any_into(&MysqlQueryBuilder, &mut writer),
+            DbBackend::Postgres => stmt.build_collect_any_into(&PostgresQueryBuilder, &mut writer),
+            DbBackend::Sqlite => stmt.build_collect_any_into(&SqliteQueryBuilder, &mut writer),
+        }
+        Statement::from_string_values_tuple(db_backend, writer.into_parts())
+    }
+
+    /// Limit how long the statement may run. Unlike dropping the future, the
+    /// statement is cancelled on the database once the deadline passes, failing
+    /// with [`DbErr::QueryTimeout`](crate::DbErr::QueryTimeout).
+    ///
+    /// - Postgres: `statement_timeout` is set with `SET LOCAL`, in a transaction begun
+    ///   around the statement, or in the one it runs in, so it never outlives it
+    /// - MySQL: only a `SELECT` can be limited, with a `MAX_EXECUTION_TIME` optimizer hint.
+    ///   It is added to selects without a `WITH` clause built with
+    ///   [`Selector::timeout`](crate::Selector::timeout), raw SQL has to include it itself
+    /// - SQLite: the statement is interrupted from a progress handler
+    ///
+    /// On Postgres and SQLite, a streamed query is not limited, as the connection
+    /// can't be restored once the stream is dropped. Mock and proxy connections
+    /// ignore the timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Statement {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// How long the statement may run, see [`with_timeout`](Self::with_timeout).
+    /// `None` means no limit.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.options.timeout
+    }
+
+    /// Whether the statement is prepared once and cached on the connection, to be
+    /// reused by later executions of the same SQL (default `true`).
+    ///
+    /// A non-persistent statement is still prepared, but unnamed and discarded after
+    /// running, so it works behind a connection pooler that hands each transaction to
+    /// a different server connection, e.g. PgBouncer in transaction mode. See also
+    /// [`ConnectOptions::disable_statement_caching`](crate::ConnectOptions::disable_statement_caching).
+    ///
+    /// Honored by the SQLx drivers; other connections ignore it.
+    ///
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let stmt = cake::Entity::find()
+    ///     .filter(cake::Column::Id.eq(1))
+    ///     .build(DbBackend::Postgres)
+    ///     .persistent(false);
+    /// assert!(!stmt.is_persistent());
+    /// ```
+    pub fn persistent(mut self, value: bool) -> Statement {
+        self.options.persistent = value;
+        self
+    }
+
+    /// Whether the statement is prepared once and cached on the connection, see
+    /// [`persistent`](Self::persistent)
+    pub fn is_persistent(&self) -> bool {
+        self.options.persistent
+    }
+
+    /// Create a [Statement] from a [crate::DatabaseBackend], a raw SQL statement
+    /// and optional param values, taking the place of a
+    /// `Statement { sql, values, db_backend }` literal
+    pub fn new<T>(db_backend: DbBackend, sql: T, values: Option<Values>) -> Statement
     where
         T: Into<String>,
     {
         Statement {
-            sql: stmt.into(),
-            values: None,
+            sql: sql.into(),
+            values,
             db_backend,
+            options: StatementOptions::default(),
         }
     }
 
+    /// Create a [Statement] from a [crate::DatabaseBackend] and a raw SQL statement
+    pub fn from_string<T>(db_backend: DbBackend, stmt: T) -> Statement
+    where
+        T: Into<String>,
+    {
+        Self::new(db_backend, stmt, None)
+    }
+
     /// Create a SQL statement from a [crate::DatabaseBackend], a
     /// raw SQL statement and param values
     pub fn from_sql_and_values<I, T>(db_backend: DbBackend, sql: T, values: I) -> Self
@@ -65,11 +247,7 @@ impl Statement {
     where
         T: Into<String>,
     {
-        Statement {
-            sql: stmt.0.into(),
-            values: Some(stmt.1),
-            db_backend,
-        }
+        Self::new(db_backend, stmt.0, Some(stmt.1))
     }
 }
 
@@ -122,6 +300,14 @@ macro_rules! build_query_stmt {
                 Statement::from_string_values_tuple(*db_backend, stmt)
             }
 
+            fn build_scoped(
+                &self,
+                db_backend: &DbBackend,
+                scope: &QueryScope,
+            ) -> Result<Statement, DbErr> {
+                scope.apply(self, db_backend)
+            }
+
             #[cfg(feature = "rbac")]
             fn audit(&self) -> Result<QueryAccessAudit, AuditError> {
                 AuditTrait::audit(self)
@@ -146,7 +332,7 @@ macro_rules! build_schema_stmt {
 
             #[cfg(feature = "rbac")]
             fn audit(&self) -> Result<QueryAccessAudit, AuditError> {
-                todo!("Audit not supported for {} yet", stringify!($stmt))
+                Err(AuditError::UnsupportedQuery)
             }
         }
     };
@@ -157,11 +343,80 @@ build_schema_stmt!(sea_query::TableDropStatement);
 build_schema_stmt!(sea_query::TableAlterStatement);
 build_schema_stmt!(sea_query::TableRenameStatement);
 build_schema_stmt!(sea_query::TableTruncateStatement);
-build_schema_stmt!(sea_query::IndexCreateStatement);
 build_schema_stmt!(sea_query::IndexDropStatement);
 build_schema_stmt!(sea_query::ForeignKeyCreateStatement);
 build_schema_stmt!(sea_query::ForeignKeyDropStatement);
 
+impl StatementBuilder for sea_query::IndexCreateStatement {
+    /// Panics for an index on an expression on SQLite, which sea-query can't
+    /// write, see [`try_build`](StatementBuilder::try_build)
+    fn build(&self, db_backend: &DbBackend) -> Statement {
+        let stmt = build_any_stmt!(self, db_backend);
+        Statement::from_string(*db_backend, stmt)
+    }
+
+    fn try_build(&self, db_backend: &DbBackend) -> Result<Statement, DbErr> {
+        if matches!(db_backend, DbBackend::Sqlite) && index_has_expression(self) {
+            return Err(DbErr::BackendNotSupported {
+                db: db_backend.as_str(),
+                ctx: "CREATE INDEX on an expression",
+            });
+        }
+        Ok(StatementBuilder::build(self, db_backend))
+    }
+
+    #[cfg(feature = "rbac")]
+    fn audit(&self) -> Result<QueryAccessAudit, AuditError> {
+        Err(AuditError::UnsupportedQuery)
+    }
+}
+
+/// The key parts of an index are not exposed by sea-query, so render the
+/// statement with [`IndexExprFinder`] and let it inspect them
+fn index_has_expression(stmt: &sea_query::IndexCreateStatement) -> bool {
+    let finder = IndexExprFinder::default();
+    finder.prepare_index_create_statement(stmt, &mut String::new());
+    finder.0.get()
+}
+
+/// A schema builder that writes nothing and only records whether any key part
+/// of the index is an expression rather than a table column
+#[derive(Default)]
+struct IndexExprFinder(Cell<bool>);
+
+impl IndexBuilder for IndexExprFinder {
+    fn prepare_index_create_statement(
+        &self,
+        create: &IndexCreateStatement,
+        sql: &mut impl SqlWriter,
+    ) {
+        self.prepare_table_index_expression(create, sql);
+    }
+
+    fn prepare_table_ref_index_stmt(&self, _: &TableRef, _: &mut impl SqlWriter) {}
+
+    fn prepare_index_drop_statement(&self, _: &IndexDropStatement, _: &mut impl SqlWriter) {}
+
+    fn prepare_index_prefix(&self, _: &IndexCreateStatement, _: &mut impl SqlWriter) {}
+
+    fn prepare_index_columns(&self, columns: &[IndexColumn], _: &mut impl SqlWriter) {
+        let has_expr = columns
+            .iter()
+            .any(|column| matches!(column, IndexColumn::Expr(_)));
+        self.0.set(has_expr);
+    }
+}
+
+impl QuotedBuilder for IndexExprFinder {
+    fn quote(&self) -> Quote {
+        Quote::new(b'"')
+    }
+}
+
+impl EscapeBuilder for IndexExprFinder {}
+
+impl TableRefBuilder for IndexExprFinder {}
+
 macro_rules! build_type_stmt {
     ($stmt: ty) => {
         impl StatementBuilder for $stmt {
@@ -181,3 +436,145 @@ macro_rules! build_type_stmt {
 build_type_stmt!(sea_query::extension::postgres::TypeAlterStatement);
 build_type_stmt!(sea_query::extension::postgres::TypeCreateStatement);
 build_type_stmt!(sea_query::extension::postgres::TypeDropStatement);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        DbBackend, PlaceholderStyle, Statement, Values, entity::*, query::*, tests_cfg::cake,
+    };
+    use sea_query::SelectStatement;
+
+    fn query() -> SelectStatement {
+        cake::Entity::find()
+            .filter(cake::Column::Name.contains("cheese"))
+            .filter(cake::Column::Id.is_in([1, 2]))
+            .limit(10)
+            .into_query()
+    }
+
+    #[test]
+    fn postgres_placeholders() {
+        let values = Some(Values(vec![
+            "%cheese%".into(),
+            1i32.into(),
+            2i32.into(),
+            10u64.into(),
+        ]));
+
+        let numbered = Statement::from_statement_builder_with_placeholder(
+            &query(),
+            DbBackend::Postgres,
+            PlaceholderStyle::Numbered,
+        );
+        assert_eq!(numbered, DbBackend::Postgres.build(&query()));
+        assert_eq!(
+            numbered.sql,
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" LIKE $1 AND "cake"."id" IN ($2, $3) LIMIT $4"#
+        );
+        assert_eq!(numbered.values, values);
+
+        let question = Statement::from_statement_builder_with_placeholder(
+            &query(),
+            DbBackend::Postgres,
+            PlaceholderStyle::Question,
+        );
+        assert_eq!(
+            question.sql,
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" LIKE ? AND "cake"."id" IN (?, ?) LIMIT ?"#
+        );
+        assert_eq!(question.values, values);
+    }
+
+    #[test]
+    fn mysql_placeholders() {
+        let question = Statement::from_statement_builder_with_placeholder(
+            &query(),
+            DbBackend::MySql,
+            PlaceholderStyle::Question,
+        );
+        assert_eq!(question, DbBackend::MySql.build(&query()));
+        assert_eq!(
+            question.sql,
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE ? AND `cake`.`id` IN (?, ?) LIMIT ?"
+        );
+
+        let numbered = Statement::from_statement_builder_with_placeholder(
+            &query(),
+            DbBackend::MySql,
+            PlaceholderStyle::Numbered,
+        );
+        assert_eq!(
+            numbered.sql,
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE $1 AND `cake`.`id` IN ($2, $3) LIMIT $4"
+        );
+        assert_eq!(numbered.values, question.values);
+    }
+
+    #[test]
+    fn sqlite_expression_index() {
+        use crate::{DbErr, StatementBuilder};
+        use sea_query::{Expr, Func, Index};
+
+        // sea-query can't write an expression as a key part for SQLite
+        let index = Index::create()
+            .name("cake_lower_name")
+            .table(cake::Entity)
+            .col(Func::lower(Expr::col(cake::Column::Name)))
+            .col(cake::Column::Id)
+            .to_owned();
+        assert_eq!(
+            index.try_build(&DbBackend::Sqlite),
+            Err(DbErr::BackendNotSupported {
+                db: "Sqlite",
+                ctx: "CREATE INDEX on an expression",
+            })
+        );
+        assert_eq!(
+            index.try_build(&DbBackend::Postgres).map(|stmt| stmt.sql),
+            Ok(r#"CREATE INDEX "cake_lower_name" ON "cake" ((LOWER("name")), "id")"#.to_owned())
+        );
+
+        let index = Index::create()
+            .name("cake_name")
+            .table(cake::Entity)
+            .col(cake::Column::Name)
+            .and_where(Expr::col(cake::Column::Id).gt(0))
+            .to_owned();
+        assert_eq!(
+            index.try_build(&DbBackend::Sqlite).map(|stmt| stmt.sql),
+            Ok(r#"CREATE INDEX "cake_name" ON "cake" ("name") WHERE "id" > 0"#.to_owned())
+        );
+    }
+
+    #[test]
+    fn statement_options_not_compared() {
+        let stmt = DbBackend::Postgres.build(&query());
+        let limited = stmt
+            .clone()
+            .with_timeout(std::time::Duration::from_secs(1))
+            .persistent(false);
+        assert_eq!(limited, stmt);
+        assert_eq!(
+            Statement::new(DbBackend::Postgres, stmt.sql.clone(), stmt.values.clone()),
+            stmt
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rbac")]
+    fn schema_statement_audit() {
+        use crate::{AuditError, StatementBuilder};
+        use sea_query::{Index, Table};
+
+        let index = Index::create()
+            .name("cake_name")
+            .table(cake::Entity)
+            .col(cake::Column::Name)
+            .to_owned();
+        assert!(matches!(index.audit(), Err(AuditError::UnsupportedQuery)));
+        assert!(matches!(
+            Table::drop().table(cake::Entity).to_owned().audit(),
+            Err(AuditError::UnsupportedQuery)
+        ));
+    }
+}
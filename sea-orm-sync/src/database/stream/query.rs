@@ -17,7 +17,7 @@ use crate::{DbErr, InnerConnection, QueryResult, Statement};
 #[ouroboros::self_referencing]
 pub struct QueryStream {
     stmt: Statement,
-    conn: InnerConnection,
+    conn: Option<InnerConnection>,
     metric_callback: Option<crate::metric::Callback>,
     #[borrows(mut conn, stmt, metric_callback)]
     #[not_covariant]
@@ -40,17 +40,20 @@ impl QueryStream {
     ) -> QueryStream {
         QueryStreamBuilder {
             stmt,
-            conn,
+            conn: Some(conn),
             metric_callback,
-            stream_builder: |conn, stmt, _metric_callback| match conn {
+            stream_builder: |conn, stmt, _metric_callback| match conn
+                .as_mut()
+                .expect("built with a connection")
+            {
                 #[cfg(feature = "sqlx-mysql")]
                 InnerConnection::MySql(c) => {
                     let query = crate::driver::sqlx_mysql::sqlx_query(stmt);
+                    let timeout = stmt.options.timeout;
                     let start = _metric_callback.is_some().then(std::time::SystemTime::now);
-                    let stream = c
-                        .fetch(query)
-                        .map_ok(Into::into)
-                        .map_err(sqlx_error_to_query_err);
+                    let stream = c.fetch(query).map_ok(Into::into).map_err(move |err| {
+                        sqlx_timeout_err(timeout, sqlx_error_to_query_err(err))
+                    });
                     let elapsed = start.map(|s| s.elapsed().unwrap_or_default());
                     MetricStream::new(_metric_callback, stmt, elapsed, stream)
                 }
@@ -97,12 +100,7 @@ impl QueryStream {
                 #[cfg(feature = "proxy")]
                 InnerConnection::Proxy(c) => {
                     let start = _metric_callback.is_some().then(std::time::SystemTime::now);
-                    let stream = futures_util::stream::once({
-                        Err(DbErr::BackendNotSupported {
-                            db: "Proxy",
-                            ctx: "QueryStream",
-                        })
-                    });
+                    let stream = c.fetch(stmt);
                     let elapsed = start.map(|s| s.elapsed().unwrap_or_default());
                     MetricStream::new(_metric_callback, stmt, elapsed, stream)
                 }
@@ -112,6 +110,21 @@ impl QueryStream {
         }
         .build()
     }
+
+    /// A stream over rows fetched beforehand, for connections it can't take hold of
+    #[cfg(feature = "sqlx-dep")]
+    pub(crate) fn from_rows(stmt: Statement, rows: Vec<QueryResult>) -> QueryStream {
+        QueryStreamBuilder {
+            stmt,
+            conn: None,
+            metric_callback: None,
+            stream_builder: |_conn, stmt, metric_callback| {
+                let stream = futures_util::stream::iter(rows.into_iter().map(Ok));
+                MetricStream::new(metric_callback, stmt, None, stream)
+            },
+        }
+        .build()
+    }
 }
 
 #[cfg(not(feature = "sync"))]
@@ -1,6 +1,8 @@
 #![allow(unused_assignments)]
 use std::sync::Arc;
 
+#[cfg(feature = "sqlx-dep")]
+use futures_util::lock::OwnedMutexGuard;
 #[cfg(feature = "sqlx-sqlite")]
 use sqlx_core::sql_str::SqlSafeStr;
 #[cfg(feature = "sqlx-dep")]
@@ -14,7 +16,7 @@ use crate::{
     TransactionTrait, debug_print, error::*,
 };
 #[cfg(feature = "sqlx-dep")]
-use crate::{sqlx_error_to_exec_err, sqlx_error_to_query_err};
+use crate::{SqlxConnection, sqlx_error_to_exec_err, sqlx_error_to_query_err};
 
 #[cfg(feature = "stream")]
 use crate::{StreamTrait, TransactionStream};
@@ -31,11 +33,27 @@ use crate::{StreamTrait, TransactionStream};
 /// mutation methods work against it transparently. Calling `begin` on a
 /// transaction starts a nested transaction via `SAVEPOINT`.
 pub struct DatabaseTransaction {
-    conn: Arc<Mutex<InnerConnection>>,
-    backend: DbBackend,
+    pub(crate) conn: PinnedConnection,
     open: bool,
-    metric_callback: Option<crate::metric::Callback>,
-    record_stmt_in_spans: bool,
+    pub(crate) support_returning: bool,
+}
+
+/// A connection held exclusively, outside of any transaction, e.g. by a
+/// [`SchemaScopedConnection`](crate::SchemaScopedConnection). Statements run on it
+/// directly, and transactions begun on it, including nested ones, share it.
+#[derive(Clone)]
+pub(crate) struct PinnedConnection {
+    pub(crate) conn: Arc<Mutex<InnerConnection>>,
+    pub(crate) backend: DbBackend,
+    pub(crate) metric_callback: Option<crate::metric::Callback>,
+    pub(crate) record_stmt_in_spans: bool,
+    /// Cleared when statement caching is disabled, so statements aren't prepared by name,
+    /// see [`ConnectOptions::disable_statement_caching`](crate::ConnectOptions::disable_statement_caching)
+    pub(crate) persistent_statements: bool,
+    /// Keeps everyone else off a single sqlx connection while this handle, or a
+    /// transaction begun on it, is alive, see [`SqlxSingleConnection`](crate::SqlxSingleConnection)
+    #[cfg(feature = "sqlx-dep")]
+    pub(crate) gate: Option<Arc<OwnedMutexGuard<()>>>,
 }
 
 #[instrument(level = "trace", skip(transaction, callback))]
@@ -65,6 +83,12 @@ impl std::fmt::Debug for DatabaseTransaction {
     }
 }
 
+impl std::fmt::Debug for PinnedConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PinnedConnection {{ backend: {:?} }}", self.backend)
+    }
+}
+
 impl DatabaseTransaction {
     #[instrument(level = "trace", skip(metric_callback))]
     pub(crate) fn begin(
@@ -77,11 +101,17 @@ impl DatabaseTransaction {
         sqlite_transaction_mode: Option<SqliteTransactionMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
         let res = DatabaseTransaction {
-            conn,
-            backend,
+            conn: PinnedConnection {
+                conn,
+                backend,
+                metric_callback,
+                record_stmt_in_spans,
+                persistent_statements: true,
+                #[cfg(feature = "sqlx-dep")]
+                gate: None,
+            },
             open: true,
-            metric_callback,
-            record_stmt_in_spans,
+            support_returning: backend.support_returning(),
         };
 
         let begin_result: Result<(), DbErr> = super::tracing_spans::with_db_span!(
@@ -91,9 +121,9 @@ impl DatabaseTransaction {
             record_stmt = false,
             {
                 #[cfg(not(feature = "sync"))]
-                let conn = &mut *res.conn.lock();
+                let conn = &mut *res.conn.conn.lock();
                 #[cfg(feature = "sync")]
-                let conn = &mut *res.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
+                let conn = &mut *res.conn.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
 
                 match conn {
                     #[cfg(feature = "sqlx-mysql")]
@@ -210,20 +240,115 @@ impl DatabaseTransaction {
         run_async_transaction_callback(transaction, callback)
     }
 
+    /// Start counting the statements executed in this transaction and its nested
+    /// transactions. Not needed if counting is enabled on the connection it was started
+    /// from, whose counter is shared, see
+    /// [`DatabaseConnection::enable_query_counting`](crate::DatabaseConnection::enable_query_counting).
+    pub fn enable_query_counting(&mut self) {
+        self.conn.metric_callback = Some(crate::metric::with_counter(
+            self.conn.metric_callback.take(),
+        ));
+    }
+
+    /// Number of statements executed since counting was enabled, or 0 if counting is disabled
+    pub fn query_count(&self) -> u64 {
+        self.conn
+            .metric_callback
+            .as_ref()
+            .and_then(|hooks| hooks.query_count())
+            .unwrap_or_default()
+    }
+
+    /// Run `f` and return its output along with the number of statements executed
+    /// meanwhile, see [`DatabaseConnection::count_queries`](crate::DatabaseConnection::count_queries)
+    pub fn count_queries<F, R>(&self, f: F) -> (R, u64)
+    where
+        F: FnOnce() -> R,
+    {
+        let before = self.query_count();
+        let res = f();
+        (res, self.query_count() - before)
+    }
+
+    /// Execute the function with an owned handle to this transaction.
+    /// If the function returns an error, the transaction will be rolled back.
+    /// Otherwise, the transaction will be committed.
+    ///
+    /// A transaction from [`begin`](crate::TransactionTrait::begin) is `'static`, and the
+    /// `Arc` handle can be cloned and moved into spawned tasks, e.g. with `tokio::spawn`.
+    /// All clones must be dropped by the time the function returns, otherwise the transaction
+    /// can't be committed: an error is returned, and the transaction is rolled back once the
+    /// last clone is dropped, returning the connection to the pool.
+    ///
+    /// ```ignore
+    /// let txn = db.begin()?;
+    /// txn.commit_on_success::<_, _, DbErr>(|txn| {
+    ///     let task = tokio::spawn({
+    ///         let txn = txn.clone();
+    ///         move { cake::Entity::find().all(txn.as_ref()) }
+    ///     });
+    ///     let cakes = task.expect("task panicked")?;
+    ///     Ok(cakes)
+    /// })
+    /// ?;
+    /// ```
+    #[instrument(level = "trace", skip(callback))]
+    pub fn commit_on_success<F, T, E>(self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: FnOnce(Arc<DatabaseTransaction>) -> Result<T, E>,
+        E: std::fmt::Display + std::fmt::Debug,
+    {
+        let transaction = Arc::new(self);
+        let res = callback(Arc::clone(&transaction)).map_err(TransactionError::Transaction);
+        let Some(transaction) = Arc::into_inner(transaction) else {
+            // rolled back by drop, once the last handle goes away
+            return res.and(Err(TransactionError::Connection(conn_err(
+                "Transaction is still referenced after commit_on_success",
+            ))));
+        };
+        if res.is_ok() {
+            transaction.commit().map_err(TransactionError::Connection)?;
+        } else {
+            transaction
+                .rollback()
+                .map_err(TransactionError::Connection)?;
+        }
+        res
+    }
+
+    /// Run `f` with the raw sqlx connection this transaction runs on, for
+    /// driver features SeaORM does not wrap (e.g. `COPY` on Postgres).
+    /// Whatever `f` does is part of this transaction.
+    ///
+    /// The connection is locked until `f` returns. Controlling the
+    /// transaction from within `f` is not supported: do not `COMMIT`,
+    /// `ROLLBACK`, or begin a sqlx transaction on the connection, use
+    /// [`begin`](TransactionTrait::begin) for nested transactions instead.
+    /// Statements run this way are not logged or metered by SeaORM.
+    ///
+    /// Returns an error if the transaction is not on a sqlx connection.
+    #[cfg(feature = "sqlx-dep")]
+    pub fn with_raw_sqlx<F, R>(&self, f: F) -> Result<R, DbErr>
+    where
+        F: FnOnce(SqlxConnection<'_>) -> R,
+    {
+        self.conn.with_raw_sqlx(f)
+    }
+
     /// Commit a transaction
     #[instrument(level = "trace")]
     #[allow(unreachable_code, unused_mut)]
     pub fn commit(mut self) -> Result<(), DbErr> {
         let result: Result<(), DbErr> = super::tracing_spans::with_db_span!(
             "sea_orm.commit",
-            self.backend,
+            self.conn.backend,
             "COMMIT",
             record_stmt = false,
             {
                 #[cfg(not(feature = "sync"))]
-                let conn = &mut *self.conn.lock();
+                let conn = &mut *self.conn.conn.lock();
                 #[cfg(feature = "sync")]
-                let conn = &mut *self.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
+                let conn = &mut *self.conn.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
 
                 match conn {
                     #[cfg(feature = "sqlx-mysql")]
@@ -270,14 +395,14 @@ impl DatabaseTransaction {
     pub fn rollback(mut self) -> Result<(), DbErr> {
         let result: Result<(), DbErr> = super::tracing_spans::with_db_span!(
             "sea_orm.rollback",
-            self.backend,
+            self.conn.backend,
             "ROLLBACK",
             record_stmt = false,
             {
                 #[cfg(not(feature = "sync"))]
-                let conn = &mut *self.conn.lock();
+                let conn = &mut *self.conn.conn.lock();
                 #[cfg(feature = "sync")]
-                let conn = &mut *self.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
+                let conn = &mut *self.conn.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
 
                 match conn {
                     #[cfg(feature = "sqlx-mysql")]
@@ -322,7 +447,7 @@ impl DatabaseTransaction {
     #[instrument(level = "trace")]
     fn start_rollback(&mut self) -> Result<(), DbErr> {
         if self.open {
-            if let Some(mut conn) = self.conn.try_lock().ok() {
+            if let Some(mut conn) = self.conn.conn.try_lock().ok() {
                 match &mut *conn {
                     #[cfg(feature = "sqlx-mysql")]
                     InnerConnection::MySql(c) => {
@@ -377,6 +502,121 @@ impl Drop for DatabaseTransaction {
 }
 
 impl ConnectionTrait for DatabaseTransaction {
+    fn get_database_backend(&self) -> DbBackend {
+        self.conn.backend
+    }
+
+    fn support_returning(&self) -> bool {
+        self.support_returning
+    }
+
+    fn execute_raw(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.conn.execute_raw(stmt)
+    }
+
+    fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.conn.execute_unprepared(sql)
+    }
+
+    fn query_one_raw(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.conn.query_one_raw(stmt)
+    }
+
+    fn query_all_raw(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.conn.query_all_raw(stmt)
+    }
+}
+
+#[cfg(feature = "stream")]
+impl StreamTrait for DatabaseTransaction {
+    type Stream<'a> = TransactionStream<'a>;
+
+    fn get_database_backend(&self) -> DbBackend {
+        self.conn.backend
+    }
+
+    #[instrument(level = "trace", skip(stmt))]
+    fn stream_raw<'a>(&'a self, stmt: Statement) -> Result<Self::Stream<'a>, DbErr> {
+        ({
+            #[cfg(not(feature = "sync"))]
+            let conn = self.conn.conn.lock();
+            #[cfg(feature = "sync")]
+            let conn = self.conn.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
+            Ok(crate::TransactionStream::build(
+                conn,
+                self.conn.statement(stmt),
+                self.conn.metric_callback.clone(),
+            ))
+        })
+    }
+}
+
+impl TransactionTrait for DatabaseTransaction {
+    type Transaction = DatabaseTransaction;
+
+    #[instrument(level = "trace")]
+    fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        let mut txn = self.conn.begin()?;
+        txn.support_returning = self.support_returning;
+        Ok(txn)
+    }
+
+    #[instrument(level = "trace")]
+    fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        let mut txn = self.conn.begin_with_config(isolation_level, access_mode)?;
+        txn.support_returning = self.support_returning;
+        Ok(txn)
+    }
+
+    #[instrument(level = "trace")]
+    fn begin_with_options(
+        &self,
+        options: TransactionOptions,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        let mut txn = self.conn.begin_with_options(options)?;
+        txn.support_returning = self.support_returning;
+        Ok(txn)
+    }
+
+    /// Execute the function inside a transaction.
+    /// If the function returns an error, the transaction will be rolled back.
+    /// Otherwise, the transaction will be committed.
+    #[instrument(level = "trace", skip(_callback))]
+    fn transaction<F, T, E>(&self, _callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(&'c DatabaseTransaction) -> Result<T, E>,
+        E: std::fmt::Display + std::fmt::Debug,
+    {
+        let transaction = self.begin().map_err(TransactionError::Connection)?;
+        transaction.run(_callback)
+    }
+
+    /// Execute the function inside a transaction.
+    /// If the function returns an error, the transaction will be rolled back.
+    /// Otherwise, the transaction will be committed.
+    #[instrument(level = "trace", skip(_callback))]
+    fn transaction_with_config<F, T, E>(
+        &self,
+        _callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(&'c DatabaseTransaction) -> Result<T, E>,
+        E: std::fmt::Display + std::fmt::Debug,
+    {
+        let transaction = self
+            .begin_with_config(isolation_level, access_mode)
+            .map_err(TransactionError::Connection)?;
+        transaction.run(_callback)
+    }
+}
+
+impl ConnectionTrait for PinnedConnection {
     fn get_database_backend(&self) -> DbBackend {
         // this way we don't need to lock just to know the backend
         self.backend
@@ -385,6 +625,7 @@ impl ConnectionTrait for DatabaseTransaction {
     #[instrument(level = "trace", skip(stmt))]
     #[allow(unused_variables)]
     fn execute_raw(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        let stmt = self.statement(stmt);
         debug_print!("{}", stmt);
 
         super::tracing_spans::with_db_span!(
@@ -403,35 +644,49 @@ impl ConnectionTrait for DatabaseTransaction {
                     InnerConnection::MySql(conn) => {
                         let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
                         let conn: &mut sqlx::MySqlConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
                             query.execute(conn).map(Into::into)
                         })
-                        .map_err(sqlx_error_to_exec_err)
+                        .map_err(sqlx_error_to_exec_err);
+                        crate::driver::sqlx_map_timeout_err(stmt.options.timeout, res)
                     }
                     #[cfg(feature = "sqlx-postgres")]
                     InnerConnection::Postgres(conn) => {
                         let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
                         let conn: &mut sqlx::PgConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
-                            query.execute(conn).map(Into::into)
+                        let mut timeout =
+                            crate::driver::sqlx_postgres::StatementTimeout::set(conn, &stmt)?;
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
+                            query.execute(timeout.conn()).map(Into::into)
                         })
-                        .map_err(sqlx_error_to_exec_err)
+                        .map_err(sqlx_error_to_exec_err);
+                        timeout.reset(&stmt, res)
                     }
                     #[cfg(feature = "sqlx-sqlite")]
                     InnerConnection::Sqlite(conn) => {
                         let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
                         let conn: &mut sqlx::SqliteConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
-                            query.execute(conn).map(Into::into)
+                        crate::driver::sqlx_sqlite::set_statement_timeout(conn, &stmt)?;
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
+                            query.execute(&mut *conn).map(Into::into)
                         })
-                        .map_err(sqlx_error_to_exec_err)
+                        .map_err(sqlx_error_to_exec_err);
+                        crate::driver::sqlx_sqlite::reset_statement_timeout(conn, &stmt, res)
                     }
                     #[cfg(feature = "rusqlite")]
                     InnerConnection::Rusqlite(conn) => conn.execute(stmt, &self.metric_callback),
                     #[cfg(feature = "mock")]
-                    InnerConnection::Mock(conn) => conn.execute(stmt),
+                    InnerConnection::Mock(conn) => {
+                        crate::metric::metric!(self.metric_callback, &stmt, {
+                            conn.execute(stmt.clone())
+                        })
+                    }
                     #[cfg(feature = "proxy")]
-                    InnerConnection::Proxy(conn) => conn.execute(stmt),
+                    InnerConnection::Proxy(conn) => {
+                        crate::metric::metric!(self.metric_callback, &stmt, {
+                            conn.execute(stmt.clone())
+                        })
+                    }
                     #[allow(unreachable_patterns)]
                     _ => Err(conn_err("Disconnected")),
                 }
@@ -501,6 +756,7 @@ impl ConnectionTrait for DatabaseTransaction {
     #[instrument(level = "trace", skip(stmt))]
     #[allow(unused_variables)]
     fn query_one_raw(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        let stmt = self.statement(stmt);
         debug_print!("{}", stmt);
 
         super::tracing_spans::with_db_span!(
@@ -519,38 +775,52 @@ impl ConnectionTrait for DatabaseTransaction {
                     InnerConnection::MySql(conn) => {
                         let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
                         let conn: &mut sqlx::MySqlConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
                             crate::sqlx_map_err_ignore_not_found(
                                 query.fetch_one(conn).map(|row| Some(row.into())),
                             )
-                        })
+                        });
+                        crate::driver::sqlx_map_timeout_err(stmt.options.timeout, res)
                     }
                     #[cfg(feature = "sqlx-postgres")]
                     InnerConnection::Postgres(conn) => {
                         let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
                         let conn: &mut sqlx::PgConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
+                        let mut timeout =
+                            crate::driver::sqlx_postgres::StatementTimeout::set(conn, &stmt)?;
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
                             crate::sqlx_map_err_ignore_not_found(
-                                query.fetch_one(conn).map(|row| Some(row.into())),
+                                query.fetch_one(timeout.conn()).map(|row| Some(row.into())),
                             )
-                        })
+                        });
+                        timeout.reset(&stmt, res)
                     }
                     #[cfg(feature = "sqlx-sqlite")]
                     InnerConnection::Sqlite(conn) => {
                         let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
                         let conn: &mut sqlx::SqliteConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
+                        crate::driver::sqlx_sqlite::set_statement_timeout(conn, &stmt)?;
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
                             crate::sqlx_map_err_ignore_not_found(
-                                query.fetch_one(conn).map(|row| Some(row.into())),
+                                query.fetch_one(&mut *conn).map(|row| Some(row.into())),
                             )
-                        })
+                        });
+                        crate::driver::sqlx_sqlite::reset_statement_timeout(conn, &stmt, res)
                     }
                     #[cfg(feature = "rusqlite")]
                     InnerConnection::Rusqlite(conn) => conn.query_one(stmt, &self.metric_callback),
                     #[cfg(feature = "mock")]
-                    InnerConnection::Mock(conn) => conn.query_one(stmt),
+                    InnerConnection::Mock(conn) => {
+                        crate::metric::metric!(self.metric_callback, &stmt, {
+                            conn.query_one(stmt.clone())
+                        })
+                    }
                     #[cfg(feature = "proxy")]
-                    InnerConnection::Proxy(conn) => conn.query_one(stmt),
+                    InnerConnection::Proxy(conn) => {
+                        crate::metric::metric!(self.metric_callback, &stmt, {
+                            conn.query_one(stmt.clone())
+                        })
+                    }
                     #[allow(unreachable_patterns)]
                     _ => Err(conn_err("Disconnected")),
                 }
@@ -561,6 +831,7 @@ impl ConnectionTrait for DatabaseTransaction {
     #[instrument(level = "trace", skip(stmt))]
     #[allow(unused_variables)]
     fn query_all_raw(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        let stmt = self.statement(stmt);
         debug_print!("{}", stmt);
 
         super::tracing_spans::with_db_span!(
@@ -579,41 +850,55 @@ impl ConnectionTrait for DatabaseTransaction {
                     InnerConnection::MySql(conn) => {
                         let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
                         let conn: &mut sqlx::MySqlConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
                             query
                                 .fetch_all(conn)
                                 .map(|rows| rows.into_iter().map(|r| r.into()).collect())
                                 .map_err(sqlx_error_to_query_err)
-                        })
+                        });
+                        crate::driver::sqlx_map_timeout_err(stmt.options.timeout, res)
                     }
                     #[cfg(feature = "sqlx-postgres")]
                     InnerConnection::Postgres(conn) => {
                         let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
                         let conn: &mut sqlx::PgConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
+                        let mut timeout =
+                            crate::driver::sqlx_postgres::StatementTimeout::set(conn, &stmt)?;
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
                             query
-                                .fetch_all(conn)
+                                .fetch_all(timeout.conn())
                                 .map(|rows| rows.into_iter().map(|r| r.into()).collect())
                                 .map_err(sqlx_error_to_query_err)
-                        })
+                        });
+                        timeout.reset(&stmt, res)
                     }
                     #[cfg(feature = "sqlx-sqlite")]
                     InnerConnection::Sqlite(conn) => {
                         let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
                         let conn: &mut sqlx::SqliteConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
+                        crate::driver::sqlx_sqlite::set_statement_timeout(conn, &stmt)?;
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
                             query
-                                .fetch_all(conn)
+                                .fetch_all(&mut *conn)
                                 .map(|rows| rows.into_iter().map(|r| r.into()).collect())
                                 .map_err(sqlx_error_to_query_err)
-                        })
+                        });
+                        crate::driver::sqlx_sqlite::reset_statement_timeout(conn, &stmt, res)
                     }
                     #[cfg(feature = "rusqlite")]
                     InnerConnection::Rusqlite(conn) => conn.query_all(stmt, &self.metric_callback),
                     #[cfg(feature = "mock")]
-                    InnerConnection::Mock(conn) => conn.query_all(stmt),
+                    InnerConnection::Mock(conn) => {
+                        crate::metric::metric!(self.metric_callback, &stmt, {
+                            conn.query_all(stmt.clone())
+                        })
+                    }
                     #[cfg(feature = "proxy")]
-                    InnerConnection::Proxy(conn) => conn.query_all(stmt),
+                    InnerConnection::Proxy(conn) => {
+                        crate::metric::metric!(self.metric_callback, &stmt, {
+                            conn.query_all(stmt.clone())
+                        })
+                    }
                     #[allow(unreachable_patterns)]
                     _ => Err(conn_err("Disconnected")),
                 }
@@ -622,31 +907,7 @@ impl ConnectionTrait for DatabaseTransaction {
     }
 }
 
-#[cfg(feature = "stream")]
-impl StreamTrait for DatabaseTransaction {
-    type Stream<'a> = TransactionStream<'a>;
-
-    fn get_database_backend(&self) -> DbBackend {
-        self.backend
-    }
-
-    #[instrument(level = "trace", skip(stmt))]
-    fn stream_raw<'a>(&'a self, stmt: Statement) -> Result<Self::Stream<'a>, DbErr> {
-        ({
-            #[cfg(not(feature = "sync"))]
-            let conn = self.conn.lock();
-            #[cfg(feature = "sync")]
-            let conn = self.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
-            Ok(crate::TransactionStream::build(
-                conn,
-                stmt,
-                self.metric_callback.clone(),
-            ))
-        })
-    }
-}
-
-impl TransactionTrait for DatabaseTransaction {
+impl TransactionTrait for PinnedConnection {
     type Transaction = DatabaseTransaction;
 
     #[instrument(level = "trace")]
@@ -660,6 +921,7 @@ impl TransactionTrait for DatabaseTransaction {
             None,
             None,
         )
+        .map(|txn| self.hand_over(txn))
     }
 
     #[instrument(level = "trace")]
@@ -677,6 +939,7 @@ impl TransactionTrait for DatabaseTransaction {
             access_mode,
             None,
         )
+        .map(|txn| self.hand_over(txn))
     }
 
     #[instrument(level = "trace")]
@@ -693,6 +956,7 @@ impl TransactionTrait for DatabaseTransaction {
             options.access_mode,
             options.sqlite_transaction_mode,
         )
+        .map(|txn| self.hand_over(txn))
     }
 
     /// Execute the function inside a transaction.
@@ -729,6 +993,53 @@ impl TransactionTrait for DatabaseTransaction {
     }
 }
 
+impl PinnedConnection {
+    /// `stmt`, not to be prepared by name if statement caching is disabled
+    pub(crate) fn statement(&self, stmt: Statement) -> Statement {
+        if self.persistent_statements {
+            stmt
+        } else {
+            stmt.persistent(false)
+        }
+    }
+
+    /// Let a transaction begun on this handle keep holding the connection
+    #[allow(unused_mut)]
+    fn hand_over(&self, mut txn: DatabaseTransaction) -> DatabaseTransaction {
+        txn.conn.persistent_statements = self.persistent_statements;
+        #[cfg(feature = "sqlx-dep")]
+        {
+            txn.conn.gate = self.gate.clone();
+        }
+        txn
+    }
+
+    /// Run `f` with the raw sqlx connection, see
+    /// [`DatabaseTransaction::with_raw_sqlx`]
+    #[cfg(feature = "sqlx-dep")]
+    pub fn with_raw_sqlx<F, R>(&self, f: F) -> Result<R, DbErr>
+    where
+        F: FnOnce(SqlxConnection<'_>) -> R,
+    {
+        #[cfg(not(feature = "sync"))]
+        let conn = &mut *self.conn.lock();
+        #[cfg(feature = "sync")]
+        let conn = &mut *self.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
+
+        let conn = match conn {
+            #[cfg(feature = "sqlx-mysql")]
+            InnerConnection::MySql(c) => SqlxConnection::MySql(c),
+            #[cfg(feature = "sqlx-postgres")]
+            InnerConnection::Postgres(c) => SqlxConnection::Postgres(c),
+            #[cfg(feature = "sqlx-sqlite")]
+            InnerConnection::Sqlite(c) => SqlxConnection::Sqlite(c),
+            #[allow(unreachable_patterns)]
+            _ => return Err(conn_err("Not a sqlx connection")),
+        };
+        Ok(f(conn))
+    }
+}
+
 /// Error returned by [`TransactionTrait::transaction`](crate::TransactionTrait::transaction):
 /// either the database itself failed, or the user closure returned an `Err`
 /// (causing a rollback).
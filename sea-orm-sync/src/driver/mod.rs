@@ -12,6 +12,8 @@ mod sqlx_common;
 pub(crate) mod sqlx_mysql;
 #[cfg(feature = "sqlx-postgres")]
 pub(crate) mod sqlx_postgres;
+#[cfg(feature = "sqlx-dep")]
+mod sqlx_single;
 #[cfg(feature = "sqlx-sqlite")]
 pub(crate) mod sqlx_sqlite;
 
@@ -25,5 +27,7 @@ pub(crate) use sqlx_common::*;
 pub use sqlx_mysql::*;
 #[cfg(feature = "sqlx-postgres")]
 pub use sqlx_postgres::*;
+#[cfg(feature = "sqlx-dep")]
+pub use sqlx_single::*;
 #[cfg(feature = "sqlx-sqlite")]
 pub use sqlx_sqlite::*;
@@ -1,4 +1,61 @@
 use crate::{ConnAcquireErr, ConnectOptions, DbErr, RuntimeErr};
+use futures_util::future::Either;
+use std::{
+    sync::{
+        Arc, Mutex, OnceLock,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+/// Wait for `duration` on the runtime selected with the `runtime-*` features
+pub(crate) fn rt_sleep(duration: Duration) {
+    sqlx_core::rt::sleep(duration)
+}
+
+/// Run `future` on the runtime selected with the `runtime-*` features for at
+/// most `duration`, returning [None] if it didn't complete in time. The future is
+/// dropped on timeout.
+pub(crate) fn rt_timeout<F: std::future::Future>(
+    duration: Duration,
+    future: F,
+) -> Option<F::Output> {
+    sqlx_core::rt::timeout(duration, future).ok()
+}
+
+/// Deadline set by `close_with_timeout`, past which the calls still running on the
+/// closed pool are cancelled, so that their connections are dropped and closed
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CloseDeadline(Arc<OnceLock<Instant>>);
+
+impl CloseDeadline {
+    /// Start the countdown, to be called before closing the pool
+    pub(crate) fn set(&self, timeout: Duration) {
+        let _ = self.0.set(Instant::now() + timeout);
+    }
+
+    /// Run `future`, unless `pool` is closed and the deadline passes first, failing
+    /// with [ConnAcquireErr::ConnectionClosed]. Without a deadline, a closed pool waits
+    /// for `future` to complete.
+    pub(crate) fn run<DB, F>(&self, pool: &sqlx::Pool<DB>, future: F) -> Result<F::Output, DbErr>
+    where
+        DB: sqlx::Database,
+        F: std::future::Future,
+    {
+        let deadline = {
+            pool.close_event();
+            match self.0.get() {
+                Some(deadline) => rt_sleep(deadline.saturating_duration_since(Instant::now())),
+                None => std::future::pending(),
+            }
+        };
+        futures_util::pin_mut!(future, deadline);
+        match futures_util::future::select(future, deadline) {
+            Either::Left((output, _)) => Ok(output),
+            Either::Right(_) => Err(DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed)),
+        }
+    }
+}
 
 /// Converts an [sqlx::error] execution error to a [DbErr]
 pub fn sqlx_error_to_exec_err(err: sqlx::Error) -> DbErr {
@@ -26,6 +83,53 @@ pub fn sqlx_map_err_ignore_not_found<T: std::fmt::Debug>(
     }
 }
 
+/// Turns the error of a [Statement](crate::Statement) cancelled for running over its
+/// `timeout` into [DbErr::QueryTimeout]
+pub(crate) fn sqlx_map_timeout_err<T>(
+    timeout: Option<Duration>,
+    res: Result<T, DbErr>,
+) -> Result<T, DbErr> {
+    res.map_err(|err| sqlx_timeout_err(timeout, err))
+}
+
+/// See [sqlx_map_timeout_err]
+pub(crate) fn sqlx_timeout_err(timeout: Option<Duration>, err: DbErr) -> DbErr {
+    match (timeout, &err) {
+        (
+            Some(timeout),
+            DbErr::Exec(RuntimeErr::SqlxError(sqlx_err))
+            | DbErr::Query(RuntimeErr::SqlxError(sqlx_err)),
+        ) if is_timeout_err(sqlx_err) => DbErr::QueryTimeout(timeout),
+        _ => err,
+    }
+}
+
+#[allow(unused_variables)]
+fn is_timeout_err(err: &sqlx::Error) -> bool {
+    let Some(db_err) = err.as_database_error() else {
+        return false;
+    };
+    #[cfg(feature = "sqlx-mysql")]
+    if let Some(mysql_err) = db_err.try_downcast_ref::<sqlx::mysql::MySqlDatabaseError>() {
+        // ER_QUERY_TIMEOUT: max execution time exceeded
+        return mysql_err.number() == 3024;
+    }
+    #[cfg(feature = "sqlx-postgres")]
+    if let Some(pg_err) = db_err.try_downcast_ref::<sqlx::postgres::PgDatabaseError>() {
+        // query_canceled, raised by `statement_timeout`
+        return pg_err.code() == "57014";
+    }
+    #[cfg(feature = "sqlx-sqlite")]
+    if db_err
+        .try_downcast_ref::<sqlx::sqlite::SqliteError>()
+        .is_some()
+    {
+        // SQLITE_INTERRUPT, raised by the progress handler
+        return db_err.code().as_deref() == Some("9");
+    }
+    false
+}
+
 /// Converts an [sqlx::error] error to a [DbErr]
 pub fn sqlx_conn_acquire_err(sqlx_err: sqlx::Error) -> DbErr {
     match sqlx_err {
@@ -35,6 +139,58 @@ pub fn sqlx_conn_acquire_err(sqlx_err: sqlx::Error) -> DbErr {
     }
 }
 
+/// A sqlx connection held by a transaction: taken out of a pool, or owned outright
+/// by a [DatabaseConnection](crate::DatabaseConnection) made from a single connection
+#[derive(Debug)]
+pub(crate) enum SqlxInnerConnection<DB: sqlx::Database> {
+    Pooled(sqlx::pool::PoolConnection<DB>),
+    /// Taken out of a pool with its session changed, e.g. by a
+    /// [SchemaScopedConnection](crate::SchemaScopedConnection). Returned to the pool
+    /// once the flag is set, after the session is restored, and closed otherwise.
+    #[cfg_attr(
+        not(any(feature = "sqlx-mysql", feature = "sqlx-postgres")),
+        allow(dead_code)
+    )]
+    Pinned(sqlx::pool::PoolConnection<DB>, Arc<AtomicBool>),
+    Single(DB::Connection),
+}
+
+impl<DB: sqlx::Database> Drop for SqlxInnerConnection<DB> {
+    fn drop(&mut self) {
+        if let Self::Pinned(conn, restored) = self
+            && !restored.load(Ordering::Acquire)
+        {
+            conn.close_on_drop();
+        }
+    }
+}
+
+impl<DB: sqlx::Database> From<sqlx::pool::PoolConnection<DB>> for SqlxInnerConnection<DB> {
+    fn from(conn: sqlx::pool::PoolConnection<DB>) -> Self {
+        Self::Pooled(conn)
+    }
+}
+
+impl<DB: sqlx::Database> std::ops::Deref for SqlxInnerConnection<DB> {
+    type Target = DB::Connection;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Pooled(conn) | Self::Pinned(conn, _) => conn,
+            Self::Single(conn) => conn,
+        }
+    }
+}
+
+impl<DB: sqlx::Database> std::ops::DerefMut for SqlxInnerConnection<DB> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Self::Pooled(conn) | Self::Pinned(conn, _) => conn,
+            Self::Single(conn) => conn,
+        }
+    }
+}
+
 impl ConnectOptions {
     /// Convert [ConnectOptions] into [sqlx::pool::PoolOptions]
     pub fn sqlx_pool_options<DB>(self) -> sqlx::pool::PoolOptions<DB>
@@ -60,7 +216,178 @@ impl ConnectOptions {
         if let Some(max_lifetime) = self.max_lifetime {
             opt = opt.max_lifetime(max_lifetime);
         }
+        if let (Some(jitter), Some(max_lifetime)) =
+            (self.max_lifetime_jitter, opt.get_max_lifetime())
+        {
+            // the pool retires connections at the upper bound, the rest are
+            // retired on acquire once past the lifetime drawn when they opened
+            let (min, max) = jittered_lifetime_bounds(max_lifetime, jitter);
+            let lifetimes = Arc::new(JitteredLifetimes::new(min, max));
+            let drawn = lifetimes.clone();
+            opt = opt
+                .max_lifetime(max)
+                .after_connect(move |_, _| {
+                    drawn.draw();
+                    Box::new({ Ok(()) })
+                })
+                .before_acquire(move |_, meta| {
+                    let alive = meta.age < lifetimes.lifetime(meta.age);
+                    ({ Ok(alive) })
+                });
+        }
         opt = opt.test_before_acquire(self.test_before_acquire);
         opt
     }
 }
+
+/// The range connection lifetimes are randomized within, `max_lifetime ± jitter`
+pub(crate) fn jittered_lifetime_bounds(
+    max_lifetime: Duration,
+    jitter: Duration,
+) -> (Duration, Duration) {
+    (
+        max_lifetime.saturating_sub(jitter),
+        max_lifetime.saturating_add(jitter),
+    )
+}
+
+/// The lifetimes drawn for the connections of a pool as they are opened.
+///
+/// sqlx has no place to keep data along a pooled connection, so each lifetime is
+/// recorded with the instant its connection was opened, and looked up by the
+/// connection's age.
+#[derive(Debug)]
+struct JitteredLifetimes {
+    min: Duration,
+    max: Duration,
+    opened: Mutex<Vec<(Instant, Duration)>>,
+}
+
+impl JitteredLifetimes {
+    fn new(min: Duration, max: Duration) -> Self {
+        Self {
+            min,
+            max,
+            opened: Default::default(),
+        }
+    }
+
+    /// Draw the lifetime of a connection opened just now
+    fn draw(&self) {
+        let lifetime = self.min + (self.max - self.min).mul_f64(fastrand::f64());
+        let mut opened = self.opened.lock().expect("Mutex poisoned");
+        // connections older than the upper bound are retired by the pool itself
+        opened.retain(|(at, _)| at.elapsed() <= self.max);
+        opened.push((Instant::now(), lifetime));
+    }
+
+    /// The lifetime of the connection of age `age`, drawn when it was opened
+    fn lifetime(&self, age: Duration) -> Duration {
+        let now = Instant::now();
+        let opened = self.opened.lock().expect("Mutex poisoned");
+        opened
+            .iter()
+            .min_by_key(|(at, _)| now.duration_since(*at).abs_diff(age))
+            .map_or(self.max, |(_, lifetime)| *lifetime)
+    }
+}
+
+/// Open connections until the pool has `connections` of them, bounded by its `max_connections`
+pub(crate) fn warm_up<DB>(pool: &sqlx::Pool<DB>, connections: u32) -> Result<(), DbErr>
+where
+    DB: sqlx::Database,
+{
+    let target = connections.min(pool.options().get_max_connections());
+    // held together, so that each acquire opens a new connection
+    let mut acquired =
+        futures_util::future::try_join_all((pool.size()..target).map(|_| pool.acquire()))
+            .map_err(sqlx_conn_acquire_err)?;
+    // an acquire may have reused a connection released in the meantime
+    while pool.size() < target && acquired.len() < target as usize {
+        acquired.push(pool.acquire().map_err(sqlx_conn_acquire_err)?);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "sqlx-sqlite")]
+    fn max_lifetime_jitter() {
+        let mut opts = ConnectOptions::new("sqlite::memory:");
+        opts.max_lifetime(Duration::from_secs(600))
+            .max_lifetime_jitter(Duration::from_secs(60));
+        let pool_opts = opts.sqlx_pool_options::<sqlx::Sqlite>();
+        assert_eq!(pool_opts.get_max_lifetime(), Some(Duration::from_secs(660)));
+
+        // jittered around sqlx's default max lifetime
+        let mut opts = ConnectOptions::new("sqlite::memory:");
+        opts.max_lifetime_jitter(Duration::from_secs(60));
+        let pool_opts = opts.sqlx_pool_options::<sqlx::Sqlite>();
+        assert_eq!(
+            pool_opts.get_max_lifetime(),
+            Some(Duration::from_secs(1860))
+        );
+
+        // no lifetime to jitter
+        let mut opts = ConnectOptions::new("sqlite::memory:");
+        opts.max_lifetime(None)
+            .max_lifetime_jitter(Duration::from_secs(60));
+        let pool_opts = opts.sqlx_pool_options::<sqlx::Sqlite>();
+        assert_eq!(pool_opts.get_max_lifetime(), None);
+    }
+
+    #[test]
+    fn jittered_lifetimes() {
+        assert_eq!(
+            jittered_lifetime_bounds(Duration::from_secs(600), Duration::from_secs(60)),
+            (Duration::from_secs(540), Duration::from_secs(660))
+        );
+        assert_eq!(
+            jittered_lifetime_bounds(Duration::from_secs(30), Duration::from_secs(60)),
+            (Duration::ZERO, Duration::from_secs(90))
+        );
+
+        let lifetimes = JitteredLifetimes::new(Duration::from_secs(540), Duration::from_secs(660));
+        // a connection opened before the lifetimes were recorded lives up to the upper bound
+        assert_eq!(lifetimes.lifetime(Duration::ZERO), Duration::from_secs(660));
+        lifetimes.draw();
+        let lifetime = lifetimes.lifetime(Duration::ZERO);
+        assert!((Duration::from_secs(540)..=Duration::from_secs(660)).contains(&lifetime));
+        // the lifetime is drawn once per connection, not on every acquire
+        for _ in 0..10 {
+            assert_eq!(lifetimes.lifetime(Duration::ZERO), lifetime);
+        }
+    }
+
+    #[test]
+    fn statement_persistent() {
+        use crate::{DbBackend, Statement};
+        use sqlx::Execute;
+
+        let stmt = Statement::from_string(DbBackend::Postgres, "SELECT 1");
+        let unprepared = stmt.clone().persistent(false);
+        assert!(stmt.is_persistent());
+
+        #[cfg(feature = "sqlx-mysql")]
+        {
+            use crate::driver::sqlx_mysql::sqlx_query;
+            assert!(Execute::persistent(&sqlx_query(&stmt)));
+            assert!(!Execute::persistent(&sqlx_query(&unprepared)));
+        }
+        #[cfg(feature = "sqlx-postgres")]
+        {
+            use crate::driver::sqlx_postgres::sqlx_query;
+            assert!(Execute::persistent(&sqlx_query(&stmt)));
+            assert!(!Execute::persistent(&sqlx_query(&unprepared)));
+        }
+        #[cfg(feature = "sqlx-sqlite")]
+        {
+            use crate::driver::sqlx_sqlite::sqlx_query;
+            assert!(Execute::persistent(&sqlx_query(&stmt)));
+            assert!(!Execute::persistent(&sqlx_query(&unprepared)));
+        }
+    }
+}
@@ -1,7 +1,12 @@
 use log::LevelFilter;
 use sea_query::Values;
-use std::sync::Arc;
 use std::sync::Mutex;
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, atomic::AtomicBool},
+    time::Duration,
+};
 
 use sqlx::{
     Connection, Executor, MySql, MySqlPool,
@@ -10,11 +15,12 @@ use sqlx::{
 };
 
 use sea_query_sqlx::SqlxValues;
-use tracing::instrument;
+use tracing::{instrument, warn};
 
 use crate::{
     AccessMode, ConnectOptions, DatabaseConnection, DatabaseConnectionType, DatabaseTransaction,
-    DbBackend, IsolationLevel, Statement, TransactionError, debug_print, error::*, executor::*,
+    DbBackend, IsolationLevel, SqlxSingleConnection, Statement, TransactionError, debug_print,
+    error::*, executor::*,
 };
 
 use super::sqlx_common::*;
@@ -30,6 +36,9 @@ pub struct SqlxMySqlConnector;
 #[derive(Clone)]
 pub struct SqlxMySqlPoolConnection {
     pub(crate) pool: MySqlPool,
+    /// Cancels the calls still running once [`close_with_timeout`](Self::close_with_timeout)
+    /// times out
+    close_deadline: CloseDeadline,
     metric_callback: Option<crate::metric::Callback>,
     pub(crate) record_stmt_in_spans: bool,
 }
@@ -44,6 +53,7 @@ impl From<MySqlPool> for SqlxMySqlPoolConnection {
     fn from(pool: MySqlPool) -> Self {
         SqlxMySqlPoolConnection {
             pool,
+            close_deadline: CloseDeadline::default(),
             metric_callback: None,
             record_stmt_in_spans: true,
         }
@@ -83,12 +93,17 @@ impl SqlxMySqlConnector {
             }
         }
 
+        if let Some(capacity) = options.statement_cache_capacity {
+            sqlx_opts = sqlx_opts.statement_cache_capacity(capacity);
+        }
+
         if let Some(f) = &options.mysql_opts_fn {
             sqlx_opts = f(sqlx_opts);
         }
         let after_connect = options.after_connect.clone();
         let connect_lazy = options.connect_lazy;
         let mysql_pool_opts_fn = options.mysql_pool_opts_fn.clone();
+        let warmup_connections = options.warmup_connections;
         let mut pool_options = options.sqlx_pool_options();
         if let Some(f) = &mysql_pool_opts_fn {
             pool_options = f(pool_options);
@@ -96,14 +111,19 @@ impl SqlxMySqlConnector {
         let pool = if connect_lazy {
             pool_options.connect_lazy_with(sqlx_opts)
         } else {
-            pool_options
+            let pool = pool_options
                 .connect_with(sqlx_opts)
-                .map_err(sqlx_error_to_conn_err)?
+                .map_err(sqlx_error_to_conn_err)?;
+            if let Some(connections) = warmup_connections {
+                warm_up(&pool, connections)?;
+            }
+            pool
         };
 
         let conn: DatabaseConnection =
             DatabaseConnectionType::SqlxMySqlPoolConnection(SqlxMySqlPoolConnection {
                 pool,
+                close_deadline: CloseDeadline::default(),
                 metric_callback: None,
                 record_stmt_in_spans,
             })
@@ -122,72 +142,124 @@ impl SqlxMySqlConnector {
     pub fn from_sqlx_mysql_pool(pool: MySqlPool) -> DatabaseConnection {
         DatabaseConnectionType::SqlxMySqlPoolConnection(SqlxMySqlPoolConnection {
             pool,
+            close_deadline: CloseDeadline::default(),
             metric_callback: None,
             record_stmt_in_spans: true,
         })
         .into()
     }
+
+    /// Instantiate a [DatabaseConnection] from a single sqlx connection, e.g. in a
+    /// serverless function that opens one connection per invocation.
+    ///
+    /// All clones of the returned [DatabaseConnection] share this one connection behind
+    /// a mutex, so access is serialized: concurrent statements wait for each other, and
+    /// while a transaction is open, statements run outside of it wait for it to end, so
+    /// a task holding a transaction must not use the [DatabaseConnection] itself.
+    /// Streams fetch all rows up front. The connection is closed once the last clone is
+    /// dropped.
+    pub fn from_sqlx_mysql_connection(conn: sqlx::MySqlConnection) -> DatabaseConnection {
+        SqlxSingleConnection::new(
+            crate::InnerConnection::MySql(SqlxInnerConnection::Single(conn)),
+            crate::DbBackend::MySql,
+        )
+        .into()
+    }
 }
 
 impl SqlxMySqlPoolConnection {
     /// Execute a [Statement] on a MySQL backend
     #[instrument(level = "trace", skip(stmt))]
     pub fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
-        debug_print!("{}", stmt);
+        self.close_deadline.run(&self.pool, {
+            debug_print!("{}", stmt);
+
+            let query = sqlx_query(&stmt);
+            let mut conn = self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
+            let res = crate::metric::metric!(self.metric_callback, &stmt, {
+                match query.execute(&mut *conn) {
+                    Ok(res) => Ok(res.into()),
+                    Err(err) => Err(sqlx_error_to_exec_err(err)),
+                }
+            });
+            sqlx_map_timeout_err(stmt.options.timeout, res)
+        })?
+    }
 
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.execute(&mut *conn) {
-                Ok(res) => Ok(res.into()),
-                Err(err) => Err(sqlx_error_to_exec_err(err)),
+    /// Execute a batch of [Statement]s in order on a single pooled connection,
+    /// stopping at the first error
+    #[instrument(level = "trace", skip(stmts))]
+    pub fn execute_batch(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        self.close_deadline.run(&self.pool, {
+            let mut conn = self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
+            let mut results = Vec::with_capacity(stmts.len());
+            for stmt in stmts {
+                debug_print!("{}", stmt);
+
+                let query = sqlx_query(&stmt);
+                let res = crate::metric::metric!(self.metric_callback, &stmt, {
+                    match query.execute(&mut *conn) {
+                        Ok(res) => Ok(res.into()),
+                        Err(err) => Err(sqlx_error_to_exec_err(err)),
+                    }
+                });
+                results.push(sqlx_map_timeout_err(stmt.options.timeout, res)?);
             }
-        })
+            Ok(results)
+        })?
     }
 
     /// Execute an unprepared SQL statement on a MySQL backend
     #[instrument(level = "trace", skip(sql))]
     pub fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
-        debug_print!("{}", sql);
+        self.close_deadline.run(&self.pool, {
+            debug_print!("{}", sql);
 
-        let conn = &mut self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
-        match conn.execute(sqlx::AssertSqlSafe(sql.to_owned())) {
-            Ok(res) => Ok(res.into()),
-            Err(err) => Err(sqlx_error_to_exec_err(err)),
-        }
+            let conn = &mut self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
+            match conn.execute(sqlx::AssertSqlSafe(sql.to_owned())) {
+                Ok(res) => Ok(res.into()),
+                Err(err) => Err(sqlx_error_to_exec_err(err)),
+            }
+        })?
     }
 
     /// Get one result from a SQL query. Returns [Option::None] if no match was found
     #[instrument(level = "trace", skip(stmt))]
     pub fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
-        debug_print!("{}", stmt);
-
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.fetch_one(&mut *conn) {
-                Ok(row) => Ok(Some(row.into())),
-                Err(err) => match err {
-                    sqlx::Error::RowNotFound => Ok(None),
-                    _ => Err(sqlx_error_to_query_err(err)),
-                },
-            }
-        })
+        self.close_deadline.run(&self.pool, {
+            debug_print!("{}", stmt);
+
+            let query = sqlx_query(&stmt);
+            let mut conn = self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
+            let res = crate::metric::metric!(self.metric_callback, &stmt, {
+                match query.fetch_one(&mut *conn) {
+                    Ok(row) => Ok(Some(row.into())),
+                    Err(err) => match err {
+                        sqlx::Error::RowNotFound => Ok(None),
+                        _ => Err(sqlx_error_to_query_err(err)),
+                    },
+                }
+            });
+            sqlx_map_timeout_err(stmt.options.timeout, res)
+        })?
     }
 
     /// Get the results of a query returning them as a Vec<[QueryResult]>
     #[instrument(level = "trace", skip(stmt))]
     pub fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
-        debug_print!("{}", stmt);
-
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.fetch_all(&mut *conn) {
-                Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
-                Err(err) => Err(sqlx_error_to_query_err(err)),
-            }
-        })
+        self.close_deadline.run(&self.pool, {
+            debug_print!("{}", stmt);
+
+            let query = sqlx_query(&stmt);
+            let mut conn = self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
+            let res = crate::metric::metric!(self.metric_callback, &stmt, {
+                match query.fetch_all(&mut *conn) {
+                    Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
+                    Err(err) => Err(sqlx_error_to_query_err(err)),
+                }
+            });
+            sqlx_map_timeout_err(stmt.options.timeout, res)
+        })?
     }
 
     /// Stream the results of executing a SQL query
@@ -221,6 +293,23 @@ impl SqlxMySqlPoolConnection {
         )
     }
 
+    /// Acquire a connection from the pool and hold on to it. It goes back to the pool
+    /// when dropped if `restored` is set by then, and is closed otherwise.
+    #[instrument(level = "trace")]
+    pub(crate) fn pin(&self, restored: Arc<AtomicBool>) -> Result<crate::PinnedConnection, DbErr> {
+        let conn = self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
+        Ok(crate::PinnedConnection {
+            conn: Arc::new(Mutex::new(crate::InnerConnection::MySql(
+                SqlxInnerConnection::Pinned(conn, restored),
+            ))),
+            backend: crate::DbBackend::MySql,
+            metric_callback: self.metric_callback.clone(),
+            record_stmt_in_spans: self.record_stmt_in_spans,
+            persistent_statements: true,
+            gate: None,
+        })
+    }
+
     /// Create a MySQL transaction
     #[instrument(level = "trace", skip(callback))]
     pub fn transaction<F, T, E>(
@@ -233,32 +322,39 @@ impl SqlxMySqlPoolConnection {
         F: for<'b> FnOnce(&'b DatabaseTransaction) -> Result<T, E>,
         E: std::fmt::Display + std::fmt::Debug,
     {
-        let conn = self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
-        let transaction = DatabaseTransaction::new_mysql(
-            conn,
-            self.metric_callback.clone(),
-            self.record_stmt_in_spans,
-            isolation_level,
-            access_mode,
-        )
-        .map_err(|e| TransactionError::Connection(e))?;
-        transaction.run(callback)
+        self.close_deadline
+            .run(&self.pool, {
+                let conn = self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
+                let transaction = DatabaseTransaction::new_mysql(
+                    conn,
+                    self.metric_callback.clone(),
+                    self.record_stmt_in_spans,
+                    isolation_level,
+                    access_mode,
+                )
+                .map_err(|e| TransactionError::Connection(e))?;
+                transaction.run(callback)
+            })
+            .map_err(|e| TransactionError::Connection(e))?
     }
 
-    pub(crate) fn set_metric_callback<F>(&mut self, callback: F)
-    where
-        F: Fn(&crate::metric::Info<'_>) + 'static,
-    {
-        self.metric_callback = Some(Arc::new(callback));
+    pub(crate) fn metric_callback(&self) -> Option<crate::metric::Callback> {
+        self.metric_callback.clone()
+    }
+
+    pub(crate) fn set_metric_callback(&mut self, callback: crate::metric::Callback) {
+        self.metric_callback = Some(callback);
     }
 
     /// Checks if a connection to the database is still valid.
     pub fn ping(&self) -> Result<(), DbErr> {
-        let conn = &mut self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
-        match conn.ping() {
-            Ok(_) => Ok(()),
-            Err(err) => Err(sqlx_error_to_conn_err(err)),
-        }
+        self.close_deadline.run(&self.pool, {
+            let conn = &mut self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
+            match conn.ping() {
+                Ok(_) => Ok(()),
+                Err(err) => Err(sqlx_error_to_conn_err(err)),
+            }
+        })?
     }
 
     /// Explicitly close the MySQL connection.
@@ -267,11 +363,25 @@ impl SqlxMySqlPoolConnection {
         self.close_by_ref()
     }
 
-    /// Explicitly close the MySQL connection
+    /// Explicitly close the MySQL connection. Closing it again is a no-op.
     pub fn close_by_ref(&self) -> Result<(), DbErr> {
         self.pool.close();
         Ok(())
     }
+
+    /// Close the MySQL connection pool, waiting at most `timeout` for connections in use
+    /// to be returned. New connections can't be acquired from the moment this is called.
+    ///
+    /// Once the timeout elapses, the queries still running on the pool are cancelled,
+    /// failing with [`ConnAcquireErr::ConnectionClosed`], and their connections closed.
+    /// A connection held by a transaction or a stream is closed as soon as it's dropped.
+    pub fn close_with_timeout(&self, timeout: Duration) -> Result<(), DbErr> {
+        self.close_deadline.set(timeout);
+        if rt_timeout(timeout, self.pool.close()).is_none() {
+            warn!("Timed out waiting for MySQL connections in use to be released, closing them");
+        }
+        Ok(())
+    }
 }
 
 impl From<MySqlRow> for QueryResult {
@@ -296,10 +406,11 @@ pub(crate) fn sqlx_query(stmt: &Statement) -> sqlx::query::Query<'_, MySql, Sqlx
         .as_ref()
         .map_or(Values(Vec::new()), |values| values.clone());
     sqlx::query_with(sqlx::AssertSqlSafe(stmt.sql.as_str()), SqlxValues(values))
+        .persistent(stmt.options.persistent)
 }
 
 pub(crate) fn set_transaction_config(
-    conn: &mut PoolConnection<MySql>,
+    conn: &mut sqlx::MySqlConnection,
     isolation_level: Option<IsolationLevel>,
     access_mode: Option<AccessMode>,
 ) -> Result<(), DbErr> {
@@ -314,11 +425,10 @@ pub(crate) fn set_transaction_config(
     }
 
     if !settings.is_empty() {
-        let stmt = Statement {
-            sql: format!("SET TRANSACTION {}", settings.join(", ")),
-            values: None,
-            db_backend: DbBackend::MySql,
-        };
+        let stmt = Statement::from_string(
+            DbBackend::MySql,
+            format!("SET TRANSACTION {}", settings.join(", ")),
+        );
         let query = sqlx_query(&stmt);
         conn.execute(query).map_err(sqlx_error_to_exec_err)?;
     }
@@ -340,7 +450,11 @@ impl
             Option<crate::metric::Callback>,
         ),
     ) -> Self {
-        crate::QueryStream::build(stmt, crate::InnerConnection::MySql(conn), metric_callback)
+        crate::QueryStream::build(
+            stmt,
+            crate::InnerConnection::MySql(conn.into()),
+            metric_callback,
+        )
     }
 }
 
@@ -353,7 +467,7 @@ impl crate::DatabaseTransaction {
         access_mode: Option<AccessMode>,
     ) -> Result<crate::DatabaseTransaction, DbErr> {
         Self::begin(
-            Arc::new(Mutex::new(crate::InnerConnection::MySql(inner))),
+            Arc::new(Mutex::new(crate::InnerConnection::MySql(inner.into()))),
             crate::DbBackend::MySql,
             metric_callback,
             record_stmt_in_spans,
@@ -1,12 +1,17 @@
 use log::LevelFilter;
 use sea_query::Values;
-use std::sync::Arc;
 use std::sync::Mutex;
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use sqlx::{
     Connection, Executor, Sqlite, SqlitePool,
     pool::PoolConnection,
-    sqlite::{SqliteConnectOptions, SqliteQueryResult, SqliteRow},
+    sqlite::{SqliteConnectOptions, SqliteConnection, SqliteQueryResult, SqliteRow},
 };
 
 use sea_query_sqlx::SqlxValues;
@@ -14,8 +19,8 @@ use tracing::{instrument, warn};
 
 use crate::{
     AccessMode, ConnectOptions, DatabaseConnection, DatabaseConnectionType, DatabaseTransaction,
-    IsolationLevel, SqliteTransactionMode, Statement, TransactionError, debug_print, error::*,
-    executor::*, sqlx_error_to_exec_err,
+    IsolationLevel, SqliteTransactionMode, SqlxSingleConnection, Statement, TransactionError,
+    debug_print, error::*, executor::*, sqlx_error_to_exec_err,
 };
 
 use super::sqlx_common::*;
@@ -31,8 +36,12 @@ pub struct SqlxSqliteConnector;
 #[derive(Clone)]
 pub struct SqlxSqlitePoolConnection {
     pub(crate) pool: SqlitePool,
+    /// Cancels the calls still running once [`close_with_timeout`](Self::close_with_timeout)
+    /// times out
+    close_deadline: CloseDeadline,
     metric_callback: Option<crate::metric::Callback>,
     pub(crate) record_stmt_in_spans: bool,
+    busy_retry: Option<(u32, Duration)>,
 }
 
 impl std::fmt::Debug for SqlxSqlitePoolConnection {
@@ -45,8 +54,10 @@ impl From<SqlitePool> for SqlxSqlitePoolConnection {
     fn from(pool: SqlitePool) -> Self {
         SqlxSqlitePoolConnection {
             pool,
+            close_deadline: CloseDeadline::default(),
             metric_callback: None,
             record_stmt_in_spans: true,
+            busy_retry: None,
         }
     }
 }
@@ -92,6 +103,10 @@ impl SqlxSqliteConnector {
             options.max_connections(1);
         }
 
+        if let Some(capacity) = options.statement_cache_capacity {
+            sqlx_opts = sqlx_opts.statement_cache_capacity(capacity);
+        }
+
         if let Some(f) = &options.sqlite_opts_fn {
             sqlx_opts = f(sqlx_opts);
         }
@@ -99,6 +114,8 @@ impl SqlxSqliteConnector {
         let after_conn = options.after_connect.clone();
         let connect_lazy = options.connect_lazy;
         let sqlite_pool_opts_fn = options.sqlite_pool_opts_fn.clone();
+        let warmup_connections = options.warmup_connections;
+        let busy_retry = options.sqlite_busy_retry;
         let mut pool_options = options.sqlx_pool_options();
 
         if let Some(f) = &sqlite_pool_opts_fn {
@@ -115,8 +132,10 @@ impl SqlxSqliteConnector {
 
         let pool = SqlxSqlitePoolConnection {
             pool,
+            close_deadline: CloseDeadline::default(),
             metric_callback: None,
             record_stmt_in_spans,
+            busy_retry,
         };
 
         #[cfg(feature = "sqlite-use-returning-for-3_35")]
@@ -125,6 +144,10 @@ impl SqlxSqliteConnector {
             super::sqlite::ensure_returning_version(&version)?;
         }
 
+        if let (false, Some(connections)) = (connect_lazy, warmup_connections) {
+            warm_up(&pool.pool, connections)?;
+        }
+
         let conn: DatabaseConnection =
             DatabaseConnectionType::SqlxSqlitePoolConnection(pool).into();
 
@@ -141,49 +164,124 @@ impl SqlxSqliteConnector {
     pub fn from_sqlx_sqlite_pool(pool: SqlitePool) -> DatabaseConnection {
         DatabaseConnectionType::SqlxSqlitePoolConnection(SqlxSqlitePoolConnection {
             pool,
+            close_deadline: CloseDeadline::default(),
             metric_callback: None,
             record_stmt_in_spans: true,
+            busy_retry: None,
         })
         .into()
     }
+
+    /// Instantiate a [DatabaseConnection] from a single sqlx connection, e.g. in a
+    /// serverless function that opens one connection per invocation.
+    ///
+    /// All clones of the returned [DatabaseConnection] share this one connection behind
+    /// a mutex, so access is serialized: concurrent statements wait for each other, and
+    /// while a transaction is open, statements run outside of it wait for it to end, so
+    /// a task holding a transaction must not use the [DatabaseConnection] itself.
+    /// Streams fetch all rows up front. The connection is closed once the last clone is
+    /// dropped.
+    pub fn from_sqlx_sqlite_connection(conn: SqliteConnection) -> DatabaseConnection {
+        SqlxSingleConnection::new(
+            crate::InnerConnection::Sqlite(SqlxInnerConnection::Single(conn)),
+            crate::DbBackend::Sqlite,
+        )
+        .into()
+    }
 }
 
 impl SqlxSqlitePoolConnection {
     /// Execute a [Statement] on a SQLite backend
     #[instrument(level = "trace", skip(stmt))]
     pub fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
-        debug_print!("{}", stmt);
+        self.close_deadline.run(&self.pool, {
+            debug_print!("{}", stmt);
+
+            let mut attempts = 1;
+            loop {
+                let res = self.execute_once(&stmt);
+                if !retry_busy(self.busy_retry, &mut attempts, &res) {
+                    return res;
+                }
+            }
+        })?
+    }
 
-        let query = sqlx_query(&stmt);
+    fn execute_once(&self, stmt: &Statement) -> Result<ExecResult, DbErr> {
+        let query = sqlx_query(stmt);
         let mut conn = self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
+        set_statement_timeout(&mut conn, stmt)?;
+        let res = crate::metric::metric!(self.metric_callback, stmt, {
             match query.execute(&mut *conn) {
                 Ok(res) => Ok(res.into()),
                 Err(err) => Err(sqlx_error_to_exec_err(err)),
             }
-        })
+        });
+        reset_statement_timeout(&mut conn, stmt, res)
+    }
+
+    /// Execute a batch of [Statement]s in order on a single pooled connection,
+    /// stopping at the first error
+    #[instrument(level = "trace", skip(stmts))]
+    pub fn execute_batch(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        self.close_deadline.run(&self.pool, {
+            let mut conn = self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
+            let mut results = Vec::with_capacity(stmts.len());
+            for stmt in stmts {
+                debug_print!("{}", stmt);
+
+                let query = sqlx_query(&stmt);
+                set_statement_timeout(&mut conn, &stmt)?;
+                let res = crate::metric::metric!(self.metric_callback, &stmt, {
+                    match query.execute(&mut *conn) {
+                        Ok(res) => Ok(res.into()),
+                        Err(err) => Err(sqlx_error_to_exec_err(err)),
+                    }
+                });
+                results.push(reset_statement_timeout(&mut conn, &stmt, res)?);
+            }
+            Ok(results)
+        })?
     }
 
-    /// Execute an unprepared SQL statement on a SQLite backend
+    /// Execute an unprepared SQL statement on a SQLite backend.
+    ///
+    /// Not retried on `SQLITE_BUSY`, as the SQL may be a script of several statements,
+    /// of which the ones before the busy one have run already
     #[instrument(level = "trace", skip(sql))]
     pub fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
-        debug_print!("{}", sql);
+        self.close_deadline.run(&self.pool, {
+            debug_print!("{}", sql);
 
-        let conn = &mut self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
-        match conn.execute(sqlx::AssertSqlSafe(sql.to_owned())) {
-            Ok(res) => Ok(res.into()),
-            Err(err) => Err(sqlx_error_to_exec_err(err)),
-        }
+            let conn = &mut self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
+            match conn.execute(sqlx::AssertSqlSafe(sql.to_owned())) {
+                Ok(res) => Ok(res.into()),
+                Err(err) => Err(sqlx_error_to_exec_err(err)),
+            }
+        })?
     }
 
     /// Get one result from a SQL query. Returns [Option::None] if no match was found
     #[instrument(level = "trace", skip(stmt))]
     pub fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
-        debug_print!("{}", stmt);
+        self.close_deadline.run(&self.pool, {
+            debug_print!("{}", stmt);
+
+            let mut attempts = 1;
+            loop {
+                let res = self.query_one_once(&stmt);
+                if !retry_busy(self.busy_retry, &mut attempts, &res) {
+                    return res;
+                }
+            }
+        })?
+    }
 
-        let query = sqlx_query(&stmt);
+    fn query_one_once(&self, stmt: &Statement) -> Result<Option<QueryResult>, DbErr> {
+        let query = sqlx_query(stmt);
         let mut conn = self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
+        set_statement_timeout(&mut conn, stmt)?;
+        let res = crate::metric::metric!(self.metric_callback, stmt, {
             match query.fetch_one(&mut *conn) {
                 Ok(row) => Ok(Some(row.into())),
                 Err(err) => match err {
@@ -191,22 +289,37 @@ impl SqlxSqlitePoolConnection {
                     _ => Err(sqlx_error_to_query_err(err)),
                 },
             }
-        })
+        });
+        reset_statement_timeout(&mut conn, stmt, res)
     }
 
     /// Get the results of a query returning them as a Vec<[QueryResult]>
     #[instrument(level = "trace", skip(stmt))]
     pub fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
-        debug_print!("{}", stmt);
+        self.close_deadline.run(&self.pool, {
+            debug_print!("{}", stmt);
+
+            let mut attempts = 1;
+            loop {
+                let res = self.query_all_once(&stmt);
+                if !retry_busy(self.busy_retry, &mut attempts, &res) {
+                    return res;
+                }
+            }
+        })?
+    }
 
-        let query = sqlx_query(&stmt);
+    fn query_all_once(&self, stmt: &Statement) -> Result<Vec<QueryResult>, DbErr> {
+        let query = sqlx_query(stmt);
         let mut conn = self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
+        set_statement_timeout(&mut conn, stmt)?;
+        let res = crate::metric::metric!(self.metric_callback, stmt, {
             match query.fetch_all(&mut *conn) {
                 Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
                 Err(err) => Err(sqlx_error_to_query_err(err)),
             }
-        })
+        });
+        reset_statement_timeout(&mut conn, stmt, res)
     }
 
     /// Stream the results of executing a SQL query
@@ -254,33 +367,40 @@ impl SqlxSqlitePoolConnection {
         F: for<'b> FnOnce(&'b DatabaseTransaction) -> Result<T, E>,
         E: std::fmt::Display + std::fmt::Debug,
     {
-        let conn = self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
-        let transaction = DatabaseTransaction::new_sqlite(
-            conn,
-            self.metric_callback.clone(),
-            self.record_stmt_in_spans,
-            isolation_level,
-            access_mode,
-            None,
-        )
-        .map_err(|e| TransactionError::Connection(e))?;
-        transaction.run(callback)
+        self.close_deadline
+            .run(&self.pool, {
+                let conn = self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
+                let transaction = DatabaseTransaction::new_sqlite(
+                    conn,
+                    self.metric_callback.clone(),
+                    self.record_stmt_in_spans,
+                    isolation_level,
+                    access_mode,
+                    None,
+                )
+                .map_err(|e| TransactionError::Connection(e))?;
+                transaction.run(callback)
+            })
+            .map_err(|e| TransactionError::Connection(e))?
     }
 
-    pub(crate) fn set_metric_callback<F>(&mut self, callback: F)
-    where
-        F: Fn(&crate::metric::Info<'_>) + 'static,
-    {
-        self.metric_callback = Some(Arc::new(callback));
+    pub(crate) fn metric_callback(&self) -> Option<crate::metric::Callback> {
+        self.metric_callback.clone()
+    }
+
+    pub(crate) fn set_metric_callback(&mut self, callback: crate::metric::Callback) {
+        self.metric_callback = Some(callback);
     }
 
     /// Checks if a connection to the database is still valid.
     pub fn ping(&self) -> Result<(), DbErr> {
-        let conn = &mut self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
-        match conn.ping() {
-            Ok(_) => Ok(()),
-            Err(err) => Err(sqlx_error_to_conn_err(err)),
-        }
+        self.close_deadline.run(&self.pool, {
+            let conn = &mut self.pool.acquire().map_err(sqlx_conn_acquire_err)?;
+            match conn.ping() {
+                Ok(_) => Ok(()),
+                Err(err) => Err(sqlx_error_to_conn_err(err)),
+            }
+        })?
     }
 
     /// Explicitly close the SQLite connection.
@@ -289,11 +409,25 @@ impl SqlxSqlitePoolConnection {
         self.close_by_ref()
     }
 
-    /// Explicitly close the SQLite connection
+    /// Explicitly close the SQLite connection. Closing it again is a no-op.
     pub fn close_by_ref(&self) -> Result<(), DbErr> {
         self.pool.close();
         Ok(())
     }
+
+    /// Close the SQLite connection pool, waiting at most `timeout` for connections in use
+    /// to be returned. New connections can't be acquired from the moment this is called.
+    ///
+    /// Once the timeout elapses, the queries still running on the pool are cancelled,
+    /// failing with [`ConnAcquireErr::ConnectionClosed`], and their connections closed.
+    /// A connection held by a transaction or a stream is closed as soon as it's dropped.
+    pub fn close_with_timeout(&self, timeout: Duration) -> Result<(), DbErr> {
+        self.close_deadline.set(timeout);
+        if rt_timeout(timeout, self.pool.close()).is_none() {
+            warn!("Timed out waiting for SQLite connections in use to be released, closing them");
+        }
+        Ok(())
+    }
 }
 
 impl From<SqliteRow> for QueryResult {
@@ -318,10 +452,66 @@ pub(crate) fn sqlx_query(stmt: &Statement) -> sqlx::query::Query<'_, Sqlite, Sql
         .as_ref()
         .map_or(Values(Vec::new()), |values| values.clone());
     sqlx::query_with(sqlx::AssertSqlSafe(stmt.sql.as_str()), SqlxValues(values))
+        .persistent(stmt.options.persistent)
+}
+
+/// Whether a statement that completed with `res` after `attempts` tries should run
+/// again, as it failed with [DbErr::is_busy]. Waits for the backoff before returning
+/// `true`, see [ConnectOptions::sqlite_busy_retry]
+fn retry_busy<T>(
+    busy_retry: Option<(u32, Duration)>,
+    attempts: &mut u32,
+    res: &Result<T, DbErr>,
+) -> bool {
+    let Some((max_attempts, backoff)) = busy_retry else {
+        return false;
+    };
+    match res {
+        Err(err) if err.is_busy() && *attempts < max_attempts => {
+            rt_sleep(backoff * *attempts);
+            *attempts += 1;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Install a progress handler interrupting a [Statement] with a timeout once the
+/// deadline passes. Removed by [reset_statement_timeout].
+pub(crate) fn set_statement_timeout(
+    conn: &mut SqliteConnection,
+    stmt: &Statement,
+) -> Result<(), DbErr> {
+    if let Some(timeout) = stmt.options.timeout {
+        let deadline = Instant::now() + timeout;
+        conn.lock_handle()
+            .map_err(sqlx_error_to_exec_err)?
+            .set_progress_handler(1000, move || Instant::now() < deadline);
+    }
+    Ok(())
+}
+
+/// Remove the progress handler after running `stmt`, turning the error of an
+/// interrupted statement into [DbErr::QueryTimeout]
+pub(crate) fn reset_statement_timeout<T>(
+    conn: &mut SqliteConnection,
+    stmt: &Statement,
+    res: Result<T, DbErr>,
+) -> Result<T, DbErr> {
+    if stmt.options.timeout.is_none() {
+        return res;
+    }
+    let reset = conn
+        .lock_handle()
+        .map(|mut handle| handle.remove_progress_handler())
+        .map_err(sqlx_error_to_exec_err);
+    let res = sqlx_map_timeout_err(stmt.options.timeout, res)?;
+    reset?;
+    Ok(res)
 }
 
 pub(crate) fn set_transaction_config(
-    _conn: &mut PoolConnection<Sqlite>,
+    _conn: &mut SqliteConnection,
     isolation_level: Option<IsolationLevel>,
     access_mode: Option<AccessMode>,
 ) -> Result<(), DbErr> {
@@ -336,11 +526,7 @@ pub(crate) fn set_transaction_config(
 
 #[cfg(feature = "sqlite-use-returning-for-3_35")]
 fn get_version(conn: &SqlxSqlitePoolConnection) -> Result<String, DbErr> {
-    let stmt = Statement {
-        sql: "SELECT sqlite_version()".to_string(),
-        values: None,
-        db_backend: crate::DbBackend::Sqlite,
-    };
+    let stmt = Statement::from_string(crate::DbBackend::Sqlite, "SELECT sqlite_version()");
     conn.query_one(stmt)?
         .ok_or_else(|| {
             DbErr::Conn(RuntimeErr::Internal(
@@ -365,7 +551,11 @@ impl
             Option<crate::metric::Callback>,
         ),
     ) -> Self {
-        crate::QueryStream::build(stmt, crate::InnerConnection::Sqlite(conn), metric_callback)
+        crate::QueryStream::build(
+            stmt,
+            crate::InnerConnection::Sqlite(conn.into()),
+            metric_callback,
+        )
     }
 }
 
@@ -379,7 +569,7 @@ impl crate::DatabaseTransaction {
         sqlite_transaction_mode: Option<SqliteTransactionMode>,
     ) -> Result<crate::DatabaseTransaction, DbErr> {
         Self::begin(
-            Arc::new(Mutex::new(crate::InnerConnection::Sqlite(inner))),
+            Arc::new(Mutex::new(crate::InnerConnection::Sqlite(inner.into()))),
             crate::DbBackend::Sqlite,
             metric_callback,
             record_stmt_in_spans,
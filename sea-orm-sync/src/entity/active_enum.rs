@@ -1,5 +1,8 @@
-use crate::{ColIdx, ColumnDef, DbErr, Iterable, QueryResult, TryFromU64, TryGetError, TryGetable};
-use sea_query::{DynIden, Expr, ExprTrait, Nullable, SimpleExpr, Value, ValueType};
+use crate::{
+    ColIdx, ColumnDef, DbErr, Iterable, QueryResult, TryFromU64, TryGetError, TryGetable,
+    entity::column::save_enum_as,
+};
+use sea_query::{DynIden, Expr, Nullable, SimpleExpr, Value, ValueType};
 
 /// A Rust representation of enum defined in database.
 ///
@@ -136,10 +139,11 @@ pub trait ActiveEnum: Sized + Iterable {
     /// Construct a enum expression with casting
     fn as_enum(&self) -> SimpleExpr {
         let value: Value = Self::to_value(self).into();
-        match value {
-            Value::Enum(_) => Expr::val(value),
-            _ => Expr::val(value).as_enum(Self::name()),
-        }
+        save_enum_as(
+            Expr::val(value),
+            Self::name(),
+            Self::db_type().get_column_type(),
+        )
     }
 
     /// Get the name of all enum variants
@@ -249,6 +253,24 @@ where
     }
 }
 
+/// Error returned by the `FromStr` impl that
+/// [`DeriveActiveEnum`](crate::DeriveActiveEnum) generates with
+/// `#[sea_orm(display_from_str)]`, when the string matches none of the
+/// variants' string values.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "invalid value `{value}` for {enum_name}, expected one of: {}",
+    variants.join(", ")
+)]
+pub struct ParseActiveEnumError {
+    /// Name of the Rust enum
+    pub enum_name: &'static str,
+    /// The string that failed to parse
+    pub value: String,
+    /// String values of all variants
+    pub variants: &'static [&'static str],
+}
+
 #[cfg(test)]
 mod tests {
     use crate as sea_orm;
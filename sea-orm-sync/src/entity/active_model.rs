@@ -1,12 +1,13 @@
 use super::{ActiveValue, ActiveValue::*};
 use crate::{
-    ColumnTrait, Condition, ConnectionTrait, DbBackend, DeleteResult, EntityName, EntityTrait,
-    IdenStatic, Iterable, PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter,
-    Related, RelatedSelfVia, RelationDef, RelationTrait, TryInsertResult, UpdateResult, Value,
+    BoxFuture, ColumnTrait, Condition, ConnectionTrait, DbBackend, DeleteResult, EntityName,
+    EntityTrait, IdenStatic, Iterable, PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait,
+    QueryFilter, Related, RelatedSelfVia, RelationDef, RelationTrait, Select, SelectExt,
+    TryInsertResult, UpdateResult, Value,
     error::*,
     query::{
         clear_key_on_active_model, column_tuple_in_condition, get_key_from_active_model,
-        set_key_on_active_model,
+        set_auto_uuid, set_key_on_active_model,
     },
 };
 use sea_query::ValueTuple;
@@ -63,8 +64,38 @@ pub trait ActiveModelTrait: Clone + Debug {
 
     /// A fresh ActiveModel pre-populated with `Set(default_value)` for each
     /// column that has a `Default` impl; other columns remain `NotSet`.
+    ///
+    /// These are Rust defaults such as `0` and `""`, not the column's database
+    /// default; see [`db_default_values`](Self::db_default_values) for that.
     fn default_values() -> Self;
 
+    /// A fresh ActiveModel pre-populated with `Set(value)` for each column whose
+    /// [`ColumnDef`](crate::ColumnDef) declares a plain value default the field can
+    /// hold, e.g. `#[sea_orm(default_value = 7)]`.
+    ///
+    /// Columns with an expression default, e.g. `Expr::current_timestamp()`, and
+    /// columns without a default remain `NotSet`, so the database fills them on
+    /// `INSERT`.
+    fn db_default_values() -> Self {
+        use crate::{ColumnTrait, Iterable};
+
+        let mut am = Self::default();
+        for col in <Self::Entity as EntityTrait>::Column::iter() {
+            let def = col.def();
+            if let Some(sea_query::SimpleExpr::Value(value)) = def.get_column_default() {
+                // a number literal is an `Int` or a `Double` whatever the field is
+                let value = crate::entity::column::numeric_value_as_column_type(
+                    value,
+                    def.get_column_type(),
+                )
+                .unwrap_or_else(|| value.clone());
+                // a default the field can't hold is left for the database to apply
+                let _ = am.try_set(col, value);
+            }
+        }
+        am
+    }
+
     /// Promote one column from [`Unchanged`](ActiveValue::Unchanged) to
     /// [`Set`](ActiveValue::Set) so it will be included in the next
     /// `UPDATE`. Leaves [`NotSet`](ActiveValue::NotSet) untouched.
@@ -219,13 +250,15 @@ pub trait ActiveModelTrait: Clone + Debug {
     /// # Ok(())
     /// # }
     /// ```
-    fn insert<'a, C>(self, db: &'a C) -> Result<<Self::Entity as EntityTrait>::Model, DbErr>
+    fn insert<'a, C>(mut self, db: &'a C) -> Result<<Self::Entity as EntityTrait>::Model, DbErr>
     where
         <Self::Entity as EntityTrait>::Model: IntoActiveModel<Self>,
         Self: ActiveModelBehavior,
         C: ConnectionTrait,
     {
+        set_auto_uuid(&mut self);
         let am = ActiveModelBehavior::before_save(self, db, true)?;
+        am.validate().map_err(DbErr::Validation)?;
         let model = <Self::Entity as EntityTrait>::insert(am).exec_with_returning(db)?;
         Self::after_save(model, db, true)
     }
@@ -344,6 +377,7 @@ pub trait ActiveModelTrait: Clone + Debug {
         C: ConnectionTrait,
     {
         let am = ActiveModelBehavior::before_save(self, db, false)?;
+        am.validate().map_err(DbErr::Validation)?;
         let model: <Self::Entity as EntityTrait>::Model = Self::Entity::update(am).exec(db)?;
         Self::after_save(model, db, false)
     }
@@ -353,7 +387,8 @@ pub trait ActiveModelTrait: Clone + Debug {
     /// RETURNING support, or when the updated model is not needed.
     ///
     /// Unlike [`ActiveModelTrait::update`], this runs
-    /// [`ActiveModelBehavior::before_save`] but does **not** run
+    /// [`ActiveModelBehavior::before_save`] and [`ActiveModelBehavior::validate`]
+    /// but does **not** run
     /// [`ActiveModelBehavior::after_save`] (there is no returned model to pass to
     /// it). Returns [`DbErr::RecordNotUpdated`] if no row matches.
     fn update_without_returning<'a, C>(self, db: &'a C) -> Result<UpdateResult, DbErr>
@@ -362,6 +397,7 @@ pub trait ActiveModelTrait: Clone + Debug {
         C: ConnectionTrait,
     {
         let am = ActiveModelBehavior::before_save(self, db, false)?;
+        am.validate().map_err(DbErr::Validation)?;
         Self::Entity::update(am).exec_without_returning(db)
     }
 
@@ -381,13 +417,54 @@ pub trait ActiveModelTrait: Clone + Debug {
         Ok(res.into_active_model())
     }
 
+    /// Insert the model if no row has its primary key yet, update it otherwise.
+    ///
+    /// Unlike [`save`](Self::save), this works for primary keys that the
+    /// application sets itself, e.g. UUIDs or natural keys: when the primary key
+    /// is fully set, whether the row exists is looked up with a `SELECT` first.
+    /// If any primary key column is `NotSet`, the model is inserted right away.
+    ///
+    /// The lookup and the write are separate statements, so they can race with
+    /// other connections. Two concurrent calls for the same new key can both find
+    /// it missing, and the second insert fails with
+    /// [`SqlErr::UniqueConstraintViolation`](crate::SqlErr::UniqueConstraintViolation);
+    /// a row deleted between the lookup and the update yields
+    /// [`DbErr::RecordNotUpdated`]. Running it in a transaction narrows, but
+    /// does not close, this window unless the isolation level is serializable.
+    /// Use [`Insert::on_conflict`](crate::Insert::on_conflict) for an atomic upsert.
+    fn save_by_lookup<'a, C>(self, db: &'a C) -> Result<Self, DbErr>
+    where
+        <Self::Entity as EntityTrait>::Model: IntoActiveModel<Self>,
+        Self: ActiveModelBehavior,
+        C: ConnectionTrait,
+    {
+        let exists = if self.is_update() {
+            let mut select = <Self::Entity as EntityTrait>::find();
+            for key in <Self::Entity as EntityTrait>::PrimaryKey::iter() {
+                let col = key.into_column();
+                if let Some(v) = self.get(col).into_value() {
+                    select = select.filter(col.eq(v));
+                }
+            }
+            select.exists(db)?
+        } else {
+            false
+        };
+        let res = if exists {
+            self.update(db)
+        } else {
+            self.insert(db)
+        }?;
+        Ok(res.into_active_model())
+    }
+
     /// Returns true if the primary key is fully-specified
     #[doc(hidden)]
     fn is_update(&self) -> bool {
         let mut is_update = true;
         for key in <Self::Entity as EntityTrait>::PrimaryKey::iter() {
             let col = key.into_column();
-            if self.is_not_set(col) {
+            if matches!(self.get(col), ActiveValue::NotSet | ActiveValue::SetDefault) {
                 is_update = false;
                 break;
             }
@@ -475,7 +552,7 @@ pub trait ActiveModelTrait: Clone + Debug {
         for (col, active_value) in primary_key_values {
             match active_value {
                 ActiveValue::Unchanged(v) | ActiveValue::Set(v) => self.set(col, v),
-                NotSet => self.not_set(col),
+                NotSet | ActiveValue::SetDefault => self.not_set(col),
             }
         }
 
@@ -500,6 +577,10 @@ pub trait ActiveModelTrait: Clone + Debug {
             )));
         };
 
+        // Placeholders for attributes absent from the JSON object, so that the Model
+        // can be deserialized. Prefer the database default over the Rust default,
+        // either way they are reset to `NotSet` below.
+        let db_default_am = Self::db_default_values();
         let dummy_am = Self::default_values();
         let len = <<Self::Entity as EntityTrait>::Column>::iter().len();
         // Mark down which attribute exists in the JSON object
@@ -510,14 +591,16 @@ pub trait ActiveModelTrait: Clone + Debug {
             let key = col.json_key();
             let has_key = input.contains_key(key);
             json_keys.push((col, has_key));
-            match dummy_am.get(col) {
-                ActiveValue::Unchanged(value) | ActiveValue::Set(value) => {
-                    merged.insert(key.to_owned(), sea_query::sea_value_to_json_value(&value));
-                }
-                _ => {}
+            if let Some(value) = db_default_am
+                .get(col)
+                .into_value()
+                .or_else(|| dummy_am.get(col).into_value())
+            {
+                merged.insert(key.to_owned(), sea_query::sea_value_to_json_value(&value));
             }
         }
 
+        let placeholders = merged.clone();
         merged.append(&mut input);
         let _ = input;
 
@@ -525,7 +608,9 @@ pub trait ActiveModelTrait: Clone + Debug {
 
         // Convert JSON object into ActiveModel via Model
         let model: <Self::Entity as EntityTrait>::Model =
-            serde_json::from_value(json_value).map_err(json_err)?;
+            serde::Deserialize::deserialize(&json_value).map_err(|err| {
+                json_field_err::<Self::Entity>(err, placeholders, &json_value, &json_keys)
+            })?;
         let mut am = model.into_active_model();
 
         // Transform attribute that exists in JSON object into ActiveValue::Set, otherwise ActiveValue::NotSet
@@ -543,6 +628,37 @@ pub trait ActiveModelTrait: Clone + Debug {
         Ok(am)
     }
 
+    /// Serialize the attributes that are set or unchanged into a JSON object, alongside
+    /// a parallel map describing every column as in
+    /// [`ModelTrait::to_json_with_meta`](crate::ModelTrait::to_json_with_meta), plus
+    /// its `state`: `"set"`, `"unchanged"`, `"set_default"` or `"not_set"`.
+    #[cfg(feature = "with-json")]
+    fn to_json_with_meta(&self) -> (crate::JsonMap, crate::JsonMap) {
+        use crate::Iterable;
+
+        let mut values = crate::JsonMap::new();
+        let mut meta = crate::JsonMap::new();
+        for col in <<Self::Entity as EntityTrait>::Column>::iter() {
+            let key = col.json_key();
+            let state = match self.get(col) {
+                ActiveValue::Set(value) => {
+                    values.insert(key.to_owned(), sea_query::sea_value_to_json_value(&value));
+                    "set"
+                }
+                ActiveValue::Unchanged(value) => {
+                    values.insert(key.to_owned(), sea_query::sea_value_to_json_value(&value));
+                    "unchanged"
+                }
+                ActiveValue::NotSet => "not_set",
+                ActiveValue::SetDefault => "set_default",
+            };
+            let mut col_meta = super::model::column_json_meta::<Self::Entity>(col);
+            col_meta.insert("state".to_owned(), state.into());
+            meta.insert(key.to_owned(), col_meta.into());
+        }
+        (values, meta)
+    }
+
     /// Create a Vec of ActiveModels from an Arrow RecordBatch.
     ///
     /// Each row in the RecordBatch becomes one ActiveModel.
@@ -645,7 +761,7 @@ pub trait ActiveModelTrait: Clone + Debug {
                     .iter()
                     .map(|m| match m.get(col) {
                         ActiveValue::Set(v) | ActiveValue::Unchanged(v) => Some(v),
-                        ActiveValue::NotSet => None,
+                        ActiveValue::NotSet | ActiveValue::SetDefault => None,
                     })
                     .collect();
 
@@ -1040,9 +1156,10 @@ pub trait ActiveModelTrait: Clone + Debug {
 /// Every entity must have an impl of this trait — even the empty
 /// `impl ActiveModelBehavior for ActiveModel {}` you see in the examples is
 /// required so that the default `new` / `before_save` / `after_save` /
-/// `before_delete` / `after_delete` implementations are wired up. Override
-/// the hooks to enforce invariants, populate computed columns, or run side
-/// effects around saves and deletes.
+/// `before_delete` / `after_delete` / `before_find` / `after_find`
+/// implementations are wired up. Override the hooks to enforce invariants,
+/// populate computed columns, or run side effects around saves, deletes and
+/// loads.
 ///
 /// ```ignore
 /// use sea_orm::entity::prelude::*;
@@ -1086,6 +1203,25 @@ pub trait ActiveModelBehavior: ActiveModelTrait {
         Ok(self)
     }
 
+    /// Hook invoked after [`before_save`](Self::before_save) on `insert`,
+    /// `update`, and `save`. Return every column failing validation; the
+    /// operation is then aborted with [`DbErr::Validation`].
+    ///
+    /// ```ignore
+    /// fn validate(&self) -> Result<(), Vec<FieldValidationError>> {
+    ///     let mut errors = Vec::new();
+    ///     if let ActiveValue::Set(name) = &self.name
+    ///         && name.is_empty()
+    ///     {
+    ///         errors.push(FieldValidationError::new("name", "must not be empty"));
+    ///     }
+    ///     if errors.is_empty() { Ok(()) } else { Err(errors) }
+    /// }
+    /// ```
+    fn validate(&self) -> Result<(), Vec<FieldValidationError>> {
+        Ok(())
+    }
+
     /// Hook invoked after `insert`, `update`, and `save` succeed. Receives
     /// (and may transform) the resulting `Model`.
     fn after_save<C>(
@@ -1114,6 +1250,50 @@ pub trait ActiveModelBehavior: ActiveModelTrait {
     {
         Ok(self)
     }
+
+    /// Hook invoked on the query by [`Select::one`], [`Select::all`] and the
+    /// `Select::stream*` methods before it is executed, e.g. to hide soft deleted
+    /// rows. Not invoked by `count`, `paginate` or `into_model` / `into_json` etc.
+    fn before_find(select: Select<Self::Entity>) -> Select<Self::Entity> {
+        select
+    }
+
+    /// Hook invoked on every `Model` loaded by [`Select::one`], [`Select::all`] and
+    /// the `Select::stream*` methods. Receives (and may transform) the `Model`,
+    /// e.g. to decrypt a column. Return an error to fail the query.
+    ///
+    /// Queries decoding into anything other than the entity's `Model`, such as
+    /// partial models, tuples or related models, do not invoke it. Use
+    /// [`Select::without_hooks`] to skip both find hooks.
+    ///
+    /// Unlike the other hooks, it returns a boxed future, so that finding doesn't
+    /// require the ActiveModel to be `Send`:
+    ///
+    /// ```ignore
+    /// use sea_orm::entity::prelude::*;
+    ///
+    /// impl ActiveModelBehavior for ActiveModel {
+    ///     fn after_find<'a, C>(mut model: Model, _db: &'a C) -> BoxFuture<'a, Result<Model, DbErr>>
+    ///     where
+    ///         C: ConnectionTrait,
+    ///     {
+    ///         ({
+    ///             model.name = model.name.trim().to_owned();
+    ///             Ok(model)
+    ///         })
+    ///     }
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn after_find<'a, C>(
+        model: <Self::Entity as EntityTrait>::Model,
+        db: &'a C,
+    ) -> BoxFuture<'a, Result<<Self::Entity as EntityTrait>::Model, DbErr>>
+    where
+        C: ConnectionTrait,
+    {
+        ({ Ok(model) })
+    }
 }
 
 /// A Trait for any type that can be converted into an `ActiveModel`,
@@ -1217,6 +1397,47 @@ where
     }
 }
 
+/// Attribute an error deserializing a Model from a JSON object to the field causing it,
+/// by deserializing the placeholders with one field of the object swapped in at a time
+#[cfg(feature = "with-json")]
+fn json_field_err<E>(
+    err: serde_json::Error,
+    placeholders: crate::JsonMap,
+    object: &serde_json::Value,
+    json_keys: &[(E::Column, bool)],
+) -> DbErr
+where
+    E: EntityTrait,
+    for<'de> E::Model: serde::de::Deserialize<'de>,
+{
+    use serde::Deserialize;
+
+    let mut probe = serde_json::Value::Object(placeholders);
+    if E::Model::deserialize(&probe).is_err() {
+        return json_err(err);
+    }
+    for (col, _) in json_keys.iter().filter(|(_, exists)| *exists) {
+        let key = col.json_key();
+        let value = object[key].clone();
+        let placeholder = probe
+            .as_object_mut()
+            .and_then(|map| map.insert(key.to_owned(), value));
+        if let Err(err) = E::Model::deserialize(&probe) {
+            return DbErr::JsonField {
+                field: key.to_owned(),
+                source_msg: err.to_string(),
+            };
+        }
+        if let Some(map) = probe.as_object_mut() {
+            match placeholder {
+                Some(placeholder) => map.insert(key.to_owned(), placeholder),
+                None => map.remove(key),
+            };
+        }
+    }
+    json_err(err)
+}
+
 fn establish_links<EM, J, RM, C>(
     model: &EM,
     related_models: &[RM],
@@ -1325,8 +1546,10 @@ where
     if !via_models.is_empty() {
         // run the before_save hooks
         let mut via_models_res = Vec::with_capacity(via_models.len());
-        for am in via_models {
+        for mut am in via_models {
+            set_auto_uuid(&mut am);
             let am = am.before_save(db, true)?;
+            am.validate().map_err(DbErr::Validation)?;
             via_models_res.push(am);
         }
 
@@ -1527,6 +1750,167 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(all(feature = "macros", feature = "with-chrono", feature = "with-uuid"))]
+    fn test_derive_into_active_model_nested_option() {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        mod event {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "event")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub happened_at: Option<DateTimeUtc>,
+                pub trace_id: Option<Uuid>,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        #[derive(DeriveIntoActiveModel)]
+        #[sea_orm(active_model = "event::ActiveModel")]
+        struct UpdateEventTime {
+            happened_at: Option<Option<DateTimeUtc>>,
+        }
+
+        let now = "2026-01-01T00:00:00Z".parse::<DateTimeUtc>().unwrap();
+
+        assert_eq!(
+            UpdateEventTime {
+                happened_at: Some(Some(now)),
+            }
+            .into_active_model(),
+            event::ActiveModel {
+                id: NotSet,
+                happened_at: Set(Some(now)),
+                trace_id: NotSet,
+            }
+        );
+
+        assert_eq!(
+            UpdateEventTime {
+                happened_at: Some(None),
+            }
+            .into_active_model(),
+            event::ActiveModel {
+                id: NotSet,
+                happened_at: Set(None),
+                trace_id: NotSet,
+            }
+        );
+
+        assert_eq!(
+            UpdateEventTime { happened_at: None }.into_active_model(),
+            event::ActiveModel {
+                id: NotSet,
+                happened_at: NotSet,
+                trace_id: NotSet,
+            }
+        );
+
+        #[derive(DeriveIntoActiveModel)]
+        #[sea_orm(active_model = "event::ActiveModel")]
+        struct UpdateEventTrace {
+            trace_id: Option<Option<Uuid>>,
+        }
+
+        let trace_id = Uuid::nil();
+
+        assert_eq!(
+            UpdateEventTrace {
+                trace_id: Some(Some(trace_id)),
+            }
+            .into_active_model(),
+            event::ActiveModel {
+                id: NotSet,
+                happened_at: NotSet,
+                trace_id: Set(Some(trace_id)),
+            }
+        );
+
+        assert_eq!(
+            UpdateEventTrace {
+                trace_id: Some(None),
+            }
+            .into_active_model(),
+            event::ActiveModel {
+                id: NotSet,
+                happened_at: NotSet,
+                trace_id: Set(None),
+            }
+        );
+
+        assert_eq!(
+            UpdateEventTrace { trace_id: None }.into_active_model(),
+            event::ActiveModel {
+                id: NotSet,
+                happened_at: NotSet,
+                trace_id: NotSet,
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn test_derive_into_active_model_active_enum() {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+        use crate::tests_cfg::sea_orm_active_enums::Tea;
+
+        #[derive(DeriveIntoActiveModel)]
+        #[sea_orm(active_model = "lunch_set::ActiveModel")]
+        struct RequiredLunchSetTea {
+            tea: Tea,
+        }
+
+        assert_eq!(
+            RequiredLunchSetTea {
+                tea: Tea::EverydayTea,
+            }
+            .into_active_model(),
+            lunch_set::ActiveModel {
+                id: NotSet,
+                name: NotSet,
+                tea: Set(Tea::EverydayTea),
+            }
+        );
+
+        #[derive(DeriveIntoActiveModel)]
+        #[sea_orm(active_model = "lunch_set::ActiveModel")]
+        struct OptionalLunchSetTea {
+            tea: Option<Tea>,
+        }
+
+        assert_eq!(
+            OptionalLunchSetTea {
+                tea: Some(Tea::BreakfastTea),
+            }
+            .into_active_model(),
+            lunch_set::ActiveModel {
+                id: NotSet,
+                name: NotSet,
+                tea: Set(Tea::BreakfastTea),
+            }
+        );
+
+        assert_eq!(
+            OptionalLunchSetTea { tea: None }.into_active_model(),
+            lunch_set::ActiveModel {
+                id: NotSet,
+                name: NotSet,
+                tea: NotSet,
+            }
+        );
+    }
+
     #[test]
     #[cfg(feature = "macros")]
     fn test_derive_into_active_model_set_single() {
@@ -2205,6 +2589,45 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "with-json")]
+    fn test_active_model_to_json_with_meta() {
+        let fruit = fruit::ActiveModel {
+            id: Unchanged(1),
+            name: Set("Apple".to_owned()),
+            cake_id: NotSet,
+        };
+        let (values, meta) = fruit.to_json_with_meta();
+
+        assert_eq!(
+            serde_json::Value::Object(values),
+            json!({ "id": 1, "name": "Apple" })
+        );
+        assert_eq!(
+            serde_json::Value::Object(meta),
+            json!({
+                "id": {
+                    "column_type": "Integer",
+                    "nullable": false,
+                    "primary_key": true,
+                    "state": "unchanged",
+                },
+                "name": {
+                    "column_type": "String",
+                    "nullable": false,
+                    "primary_key": false,
+                    "state": "set",
+                },
+                "cake_id": {
+                    "column_type": "Integer",
+                    "nullable": true,
+                    "primary_key": false,
+                    "state": "not_set",
+                },
+            })
+        );
+    }
+
     #[test]
     #[cfg(feature = "with-json")]
     fn test_active_model_set_from_json_2() -> Result<(), DbErr> {
@@ -2293,6 +2716,35 @@ mod tests {
             }
         );
 
+        let res = fruit.set_from_json(json!({
+            "id": 8,
+            "name": "Apple",
+            "cake_id": "1",
+        }));
+        assert_eq!(
+            res,
+            Err(DbErr::JsonField {
+                field: "cake_id".to_owned(),
+                source_msg: r#"invalid type: string "1", expected i32"#.to_owned(),
+            })
+        );
+        assert_eq!(
+            res.map_err(|err| err.to_string()),
+            Err(
+                r#"Json Error: field `cake_id`: invalid type: string "1", expected i32"#.to_owned()
+            )
+        );
+
+        assert_eq!(
+            fruit::ActiveModel::from_json(json!({
+                "name": 1,
+            })),
+            Err(DbErr::JsonField {
+                field: "name".to_owned(),
+                source_msg: "invalid type: integer `1`, expected a string".to_owned(),
+            })
+        );
+
         Ok(())
     }
 
@@ -2505,6 +2957,122 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "macros")]
+    fn test_active_model_validate() -> Result<(), DbErr> {
+        use crate::*;
+
+        mod validated_fruit {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+            use crate::{ActiveValue, Set};
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "fruit")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub name: String,
+                pub price: i32,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {
+                fn before_save<C>(mut self, _: &C, _: bool) -> Result<Self, DbErr>
+                where
+                    C: ConnectionTrait,
+                {
+                    if let ActiveValue::Set(name) = &self.name {
+                        self.name = Set(name.trim().to_owned());
+                    }
+                    Ok(self)
+                }
+
+                fn validate(&self) -> Result<(), Vec<FieldValidationError>> {
+                    let mut errors = Vec::new();
+                    if let ActiveValue::Set(name) = &self.name
+                        && name.is_empty()
+                    {
+                        errors.push(FieldValidationError::new(
+                            Column::Name.as_str(),
+                            "must not be empty",
+                        ));
+                    }
+                    if let ActiveValue::Set(price) = &self.price
+                        && *price < 0
+                    {
+                        errors.push(FieldValidationError::new(
+                            Column::Price.as_str(),
+                            "must not be negative",
+                        ));
+                    }
+                    if errors.is_empty() {
+                        Ok(())
+                    } else {
+                        Err(errors)
+                    }
+                }
+            }
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        // the name is only empty after `before_save` trimmed it
+        let res = validated_fruit::ActiveModel {
+            name: Set("  ".to_owned()),
+            price: Set(-1),
+            ..Default::default()
+        }
+        .insert(&db);
+
+        let Err(err) = res else {
+            panic!("unexpected result: {res:?}");
+        };
+        let DbErr::Validation(errors) = &err else {
+            panic!("unexpected error: {err}");
+        };
+        assert_eq!(
+            errors,
+            &[
+                FieldValidationError::new("name", "must not be empty"),
+                FieldValidationError::new("price", "must not be negative"),
+            ]
+        );
+        assert_eq!(
+            err.to_string(),
+            "Validation Error: name: must not be empty; price: must not be negative"
+        );
+
+        let mut fruit = validated_fruit::Model {
+            id: 1,
+            name: "Apple".to_owned(),
+            price: 10,
+        }
+        .into_active_model();
+        fruit.price = Set(-10);
+
+        assert_eq!(
+            fruit.clone().update(&db),
+            Err(DbErr::Validation(vec![FieldValidationError::new(
+                "price",
+                "must not be negative"
+            )]))
+        );
+        assert_eq!(
+            fruit.save(&db),
+            Err(DbErr::Validation(vec![FieldValidationError::new(
+                "price",
+                "must not be negative"
+            )]))
+        );
+
+        assert_eq!(db.into_transaction_log(), vec![]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_active_model_default_values() {
         assert_eq!(
@@ -2526,6 +3094,73 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "macros")]
+    fn test_active_model_db_default_values() -> Result<(), DbErr> {
+        mod profile {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[cfg_attr(feature = "with-json", derive(serde::Serialize, serde::Deserialize))]
+            #[sea_orm(table_name = "profile")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                #[sea_orm(default_value = "anonymous")]
+                pub name: String,
+                #[sea_orm(default_value = 10)]
+                pub score: i32,
+                #[sea_orm(default_value = 10)]
+                pub level: i64,
+                #[sea_orm(default_value = 300)]
+                pub rank: i8,
+                #[sea_orm(default_expr = "Expr::cust(\"gen_random_uuid()\")")]
+                pub token: String,
+                pub bio: Option<String>,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        // only plain defaults the field can hold are set
+        assert_eq!(
+            profile::ActiveModel::db_default_values(),
+            profile::ActiveModel {
+                id: NotSet,
+                name: Set("anonymous".into()),
+                score: Set(10),
+                level: Set(10),
+                rank: NotSet,
+                token: NotSet,
+                bio: NotSet,
+            },
+        );
+        assert_eq!(
+            fruit::ActiveModel::db_default_values(),
+            <fruit::ActiveModel as ActiveModelTrait>::default()
+        );
+
+        #[cfg(feature = "with-json")]
+        assert_eq!(
+            profile::ActiveModel::from_json(json!({ "bio": "Hello" }))?,
+            profile::ActiveModel {
+                id: NotSet,
+                name: NotSet,
+                score: NotSet,
+                level: NotSet,
+                rank: NotSet,
+                token: NotSet,
+                bio: Set(Some("Hello".into())),
+            },
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_active_model_set_parent_key() {
         let mut fruit = fruit::Model {
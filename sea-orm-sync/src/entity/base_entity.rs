@@ -1,11 +1,16 @@
 use crate::{
     ActiveModelBehavior, ActiveModelTrait, ColumnTrait, Delete, DeleteMany, DeleteOne,
-    FromQueryResult, Identity, Insert, InsertMany, ModelTrait, PrimaryKeyArity, PrimaryKeyToColumn,
-    PrimaryKeyTrait, QueryFilter, Related, RelationBuilder, RelationTrait, RelationType, Select,
-    Update, UpdateMany, UpdateOne, ValidatedDeleteOne,
+    FromQueryResult, Identity, IndexDef, Insert, InsertFromSelect, InsertMany, ModelTrait,
+    PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait, PrimaryKeyValueFromStr, QueryFilter,
+    Related, RelationBuilder, RelationDef, RelationTrait, RelationType, Select, Update, UpdateMany,
+    UpdateOne, ValidatedDeleteOne,
+    query::{column_tuple_in_condition, get_key_from_model},
 };
-use sea_query::{Iden, IntoIden, IntoTableRef, IntoValueTuple, TableRef};
-use std::fmt::Debug;
+use crate::{ConnectionTrait, DbBackend, DbErr};
+use sea_query::{
+    Condition, ForeignKeyAction, Iden, IntoIden, IntoTableRef, IntoValueTuple, TableRef, ValueTuple,
+};
+use std::{collections::HashMap, fmt::Debug};
 pub use strum::IntoEnumIterator as Iterable;
 
 /// An identifier (table or column name) that can be borrowed as a `&'static str`.
@@ -62,7 +67,7 @@ pub trait EntityName: IdenStatic + Default {
 ///   (see [`RelationTrait`]).
 ///
 /// And entry points for queries: [`find`](Self::find), [`find_by_id`](Self::find_by_id),
-/// [`insert`](Self::insert), [`insert_many`](Self::insert_many),
+/// [`find_by_ids`](Self::find_by_ids), [`insert`](Self::insert), [`insert_many`](Self::insert_many),
 /// [`update`](Self::update), [`update_many`](Self::update_many),
 /// [`delete`](Self::delete), [`delete_many`](Self::delete_many),
 /// [`delete_by_id`](Self::delete_by_id).
@@ -136,6 +141,64 @@ pub trait EntityTrait: EntityName {
         RelationBuilder::from_rel(RelationType::HasMany, rel.def().rev(), true)
     }
 
+    /// The `has_one` / `has_many` relations whose foreign key is declared with
+    /// `ON DELETE CASCADE`, `SET NULL` or `SET DEFAULT`, i.e. the related rows the
+    /// database changes when a row of this entity is deleted.
+    ///
+    /// Only the relations listed in [`Self::Relation`](EntityTrait::Relation) are
+    /// considered, and only one level deep.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, tests_cfg::filling};
+    ///
+    /// let cascades = filling::Entity::cascade_targets();
+    /// assert_eq!(cascades.len(), 1);
+    /// assert!(matches!(
+    ///     cascades[0].on_delete,
+    ///     Some(ForeignKeyAction::SetNull)
+    /// ));
+    /// ```
+    fn cascade_targets() -> Vec<RelationDef> {
+        Self::Relation::iter()
+            .map(|rel| rel.def())
+            .filter(|def| {
+                def.is_owner
+                    && matches!(
+                        def.on_delete,
+                        Some(
+                            ForeignKeyAction::Cascade
+                                | ForeignKeyAction::SetNull
+                                | ForeignKeyAction::SetDefault
+                        )
+                    )
+            })
+            .collect()
+    }
+
+    /// The indexes declared on the entity with the struct-level `#[sea_orm(index(..))]`
+    /// attribute, in declaration order. Columns marked `#[sea_orm(indexed)]` or
+    /// `#[sea_orm(unique_key = "..")]` are not listed here, but
+    /// [`Schema::create_index_from_entity`](crate::Schema::create_index_from_entity) creates both.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, tests_cfg::indexes};
+    ///
+    /// let indexes = indexes::Entity::indexes();
+    /// assert_eq!(indexes[0].get_name(), "idx_index1_index2");
+    /// assert_eq!(
+    ///     indexes[0]
+    ///         .get_columns()
+    ///         .iter()
+    ///         .map(|column| column.as_str())
+    ///         .collect::<Vec<_>>(),
+    ///     ["index1_attr", "index2_attr"]
+    /// );
+    /// assert!(!indexes[0].is_unique());
+    /// ```
+    fn indexes() -> Vec<IndexDef<Self::Column>> {
+        Vec::new()
+    }
+
     /// Construct select statement to find one / all models
     ///
     /// - To select columns, join tables and group by expressions, see [`QuerySelect`](crate::query::QuerySelect)
@@ -319,9 +382,10 @@ pub trait EntityTrait: EntityName {
     where
         T: Into<<Self::PrimaryKey as PrimaryKeyTrait>::ValueType>,
     {
+        let values = values.into().into_value_tuple();
         let mut select = Self::find();
         let mut keys = Self::PrimaryKey::iter();
-        for v in values.into().into_value_tuple() {
+        for v in values.clone() {
             if let Some(key) = keys.next() {
                 let col = key.into_column();
                 select = select.filter(col.eq(v));
@@ -329,9 +393,173 @@ pub trait EntityTrait: EntityName {
                 unreachable!("primary key arity mismatch");
             }
         }
+        select.primary_key = Some(values);
         select
     }
 
+    /// Find a model by its primary key given as a string, e.g. an id received in a URL path.
+    /// The values of a composite key are separated by `,`.
+    ///
+    /// Returns [`DbErr::Type`] if the string cannot be parsed as the primary key,
+    /// see [`PrimaryKeyValueFromStr`].
+    ///
+    /// ```
+    /// use sea_orm::{
+    ///     DbBackend, DbErr,
+    ///     entity::*,
+    ///     query::*,
+    ///     tests_cfg::{cake, cake_filling},
+    /// };
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find_by_id_str("11")?
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = 11"#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     cake_filling::Entity::find_by_id_str("2,3")?
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake_filling"."cake_id", "cake_filling"."filling_id" FROM "cake_filling""#,
+    ///         r#"WHERE "cake_filling"."cake_id" = 2 AND "cake_filling"."filling_id" = 3"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    ///
+    /// assert!(matches!(
+    ///     cake::Entity::find_by_id_str("eleven"),
+    ///     Err(DbErr::Type(_))
+    /// ));
+    /// # Ok::<(), DbErr>(())
+    /// ```
+    fn find_by_id_str(s: &str) -> Result<Select<Self>, DbErr>
+    where
+        <Self::PrimaryKey as PrimaryKeyTrait>::ValueType: PrimaryKeyValueFromStr,
+    {
+        let values = <Self::PrimaryKey as PrimaryKeyTrait>::ValueType::from_key_str(s)?;
+        Ok(Self::find_by_id(values))
+    }
+
+    /// Find multiple models by their primary keys.
+    ///
+    /// Single column keys are matched with `IN`, composite keys with a row value `IN`
+    /// (or an `OR` of per-key conditions where the connection's backend lacks row values).
+    /// Duplicate keys are only queried once. Rows come back in database order;
+    /// use [`find_by_ids_ordered`](Self::find_by_ids_ordered) to get them in input order.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[cfg(feature = "mock")]
+    /// # pub fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+    /// #
+    /// use sea_orm::{
+    ///     entity::*,
+    ///     query::*,
+    ///     tests_cfg::{cake, cake_filling},
+    /// };
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find_by_ids(&db, [1, 2, 3])?
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" IN (1, 2, 3)"#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     cake_filling::Entity::find_by_ids(&db, [(1, 2), (3, 4)])?
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake_filling"."cake_id", "cake_filling"."filling_id" FROM "cake_filling""#,
+    ///         r#"WHERE ("cake_filling"."cake_id", "cake_filling"."filling_id") IN ((1, 2), (3, 4))"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn find_by_ids<C, T, I>(db: &C, values: I) -> Result<Select<Self>, DbErr>
+    where
+        C: ConnectionTrait,
+        T: Into<<Self::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+        I: IntoIterator<Item = T>,
+    {
+        let keys = primary_key_tuples::<Self, _, _>(values);
+        let condition = primary_key_in_condition::<Self>(&keys, db.get_database_backend())?;
+        Ok(Self::find().filter(condition))
+    }
+
+    /// Find multiple models by their primary keys, returned in the same order as the input.
+    /// Keys without a matching row yield `None`, and repeated keys yield the same model again.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[cfg(feature = "mock")]
+    /// # pub fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[
+    /// #         cake::Model {
+    /// #             id: 1,
+    /// #             name: "New York Cheese".to_owned(),
+    /// #         },
+    /// #         cake::Model {
+    /// #             id: 3,
+    /// #             name: "Chocolate Forest".to_owned(),
+    /// #         },
+    /// #     ]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let cakes = cake::Entity::find_by_ids_ordered(&db, [3, 2, 1])?;
+    ///
+    /// assert_eq!(
+    ///     cakes
+    ///         .iter()
+    ///         .map(|c| c.as_ref().map(|c| c.id))
+    ///         .collect::<Vec<_>>(),
+    ///     [Some(3), None, Some(1)]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn find_by_ids_ordered<'a, C, T, I>(
+        db: &'a C,
+        values: I,
+    ) -> Result<Vec<Option<Self::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        T: Into<<Self::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+        I: IntoIterator<Item = T>,
+    {
+        let keys = primary_key_tuples::<Self, _, _>(values);
+        ({
+            if keys.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            let identity = Self::primary_key_identity();
+            let condition = primary_key_in_condition::<Self>(&keys, db.get_database_backend())?;
+            let mut models = HashMap::new();
+            for model in Self::find().filter(condition).all(db)? {
+                models.insert(get_key_from_model(&identity, &model)?, model);
+            }
+
+            Ok(keys.iter().map(|key| models.get(key).cloned()).collect())
+        })
+    }
+
     /// Get primary key as Identity
     fn primary_key_identity() -> Identity {
         let mut cols = Self::PrimaryKey::iter();
@@ -698,6 +926,100 @@ pub trait EntityTrait: EntityName {
         InsertMany::many(models)
     }
 
+    /// Bulk insert `models` with Postgres `COPY ... FROM STDIN`, returning the number of rows copied
+    ///
+    /// Considerably faster than [`insert_many`](Self::insert_many) for large ingests, as the rows
+    /// are streamed in the `COPY` text format instead of being bound as parameters. `COPY` takes a
+    /// fixed column list, so every model has to set the same columns as the first one; a model
+    /// that differs fails the whole `COPY` and nothing is inserted. Columns left `NotSet` on all
+    /// models take their database default.
+    ///
+    /// `db` can be a [`DatabaseConnection`](crate::DatabaseConnection) or a
+    /// [`DatabaseTransaction`](crate::DatabaseTransaction), the rows are then part of the transaction.
+    /// `ActiveModelBehavior` hooks are not run, and the statement is not logged or metered.
+    ///
+    /// ```no_run
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # fn function(db: &DatabaseConnection) -> Result<(), DbErr> {
+    /// use sea_orm::{entity::*, tests_cfg::cake};
+    ///
+    /// let cakes = (0..10_000).map(|i| cake::ActiveModel {
+    ///     name: Set(format!("Cake #{i}")),
+    ///     ..Default::default()
+    /// });
+    ///
+    /// assert_eq!(cake::Entity::copy_in(db, cakes)?, 10_000);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "sqlx-postgres")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sqlx-postgres")))]
+    fn copy_in<'c, C, I>(db: C, models: I) -> impl std::future::Future<Output = Result<u64, DbErr>>
+    where
+        C: crate::IntoDatabaseExecutor<'c>,
+        I: IntoIterator<Item = Self::ActiveModel>,
+        I::IntoIter: Send,
+        Self::ActiveModel: Send,
+    {
+        crate::executor::exec_copy_in::<Self, _>(db.into_database_executor(), models.into_iter())
+    }
+
+    /// Insert the rows selected from another entity, i.e. `INSERT INTO ... SELECT`
+    ///
+    /// The selected expressions are inserted into `columns`, in order.
+    ///
+    /// # Example (Postgres)
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[cfg(feature = "mock")]
+    /// # pub fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results([
+    /// #         MockExecResult {
+    /// #             last_insert_id: 0,
+    /// #             rows_affected: 2,
+    /// #         },
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}};
+    ///
+    /// let rows_affected = fruit::Entity::insert_from_select(
+    ///     [fruit::Column::Name, fruit::Column::CakeId],
+    ///     cake::Entity::find()
+    ///         .select_only()
+    ///         .column(cake::Column::Name)
+    ///         .column(cake::Column::Id),
+    /// )
+    /// .exec(&db)
+    /// ?;
+    ///
+    /// assert_eq!(rows_affected, 2);
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"INSERT INTO "fruit" ("name", "cake_id") SELECT "cake"."name", "cake"."id" FROM "cake""#,
+    ///         []
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn insert_from_select<I, S>(columns: I, select: Select<S>) -> InsertFromSelect<Self>
+    where
+        I: IntoIterator<Item = Self::Column>,
+        S: EntityTrait,
+    {
+        InsertFromSelect::new(columns, select)
+    }
+
     /// Update a model in database
     ///
     /// - To apply where conditions / filters, see [`QueryFilter`](crate::query::QueryFilter)
@@ -1062,10 +1384,119 @@ pub trait EntityTrait: EntityName {
         }
         Delete::one(am).validate().expect("Must be valid")
     }
+
+    /// Delete a model by its primary key given as a string, see [`EntityTrait::find_by_id_str`]
+    ///
+    /// ```
+    /// use sea_orm::{DbBackend, DbErr, entity::*, query::*, tests_cfg::cake_filling};
+    ///
+    /// assert_eq!(
+    ///     cake_filling::Entity::delete_by_id_str("2,3")?
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"DELETE FROM "cake_filling" WHERE "cake_filling"."cake_id" = 2 AND "cake_filling"."filling_id" = 3"#
+    /// );
+    ///
+    /// assert!(matches!(
+    ///     cake_filling::Entity::delete_by_id_str("2"),
+    ///     Err(DbErr::Type(_))
+    /// ));
+    /// # Ok::<(), DbErr>(())
+    /// ```
+    fn delete_by_id_str(s: &str) -> Result<ValidatedDeleteOne<Self>, DbErr>
+    where
+        <Self::PrimaryKey as PrimaryKeyTrait>::ValueType: PrimaryKeyValueFromStr,
+    {
+        let values = <Self::PrimaryKey as PrimaryKeyTrait>::ValueType::from_key_str(s)?;
+        Ok(Self::delete_by_id(values))
+    }
+}
+
+fn primary_key_tuples<E, T, I>(values: I) -> Vec<ValueTuple>
+where
+    E: EntityTrait,
+    T: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+    I: IntoIterator<Item = T>,
+{
+    values
+        .into_iter()
+        .map(|v| v.into().into_value_tuple())
+        .collect()
+}
+
+fn primary_key_in_condition<E>(keys: &[ValueTuple], backend: DbBackend) -> Result<Condition, DbErr>
+where
+    E: EntityTrait,
+{
+    column_tuple_in_condition(
+        &E::default().table_ref(),
+        &E::primary_key_identity(),
+        keys,
+        backend,
+    )
 }
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn test_cascade_targets() {
+        use crate::tests_cfg::{cake, cake_filling, filling, ingredient};
+        use crate::{EntityTrait, ForeignKeyAction, RelationTrait};
+
+        let cascades = filling::Entity::cascade_targets();
+        assert_eq!(cascades.len(), 1);
+        assert_eq!(
+            format!("{:?}", cascades[0].to_tbl),
+            format!("{:?}", filling::Relation::Ingredient.def().to_tbl)
+        );
+        assert!(matches!(
+            cascades[0].on_delete,
+            Some(ForeignKeyAction::SetNull)
+        ));
+
+        // the foreign key is on the junction table, which `cake` has no relation to
+        assert!(cake::Entity::cascade_targets().is_empty());
+        // the owning side is not affected by deleting the child
+        assert!(cake_filling::Entity::cascade_targets().is_empty());
+        assert!(ingredient::Entity::cascade_targets().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "mock")]
+    fn test_delete_with_report() -> Result<(), crate::DbErr> {
+        use crate::tests_cfg::filling;
+        use crate::{DbBackend, MockDatabase, MockExecResult, ModelTrait, Transaction};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let model = filling::Model {
+            id: 1,
+            name: "Apple Sauce".to_owned(),
+            vendor_id: None,
+            ignored_attr: 0,
+        };
+        let report = model.clone().delete_with_report(&db)?;
+        assert_eq!(report.model, model);
+        assert_eq!(report.rows_affected, 1);
+        assert_eq!(report.cascades.len(), 1);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"DELETE FROM "filling" WHERE "filling"."id" = $1"#,
+                [1i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_delete_by_id_1() {
         use crate::tests_cfg::cake;
@@ -1090,6 +1521,109 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "mock")]
+    fn test_find_by_ids_1() -> Result<(), crate::DbErr> {
+        use crate::tests_cfg::cake;
+        use crate::{DbBackend, MockDatabase, entity::*, query::*};
+        let db = MockDatabase::new(DbBackend::Sqlite).into_connection();
+        assert_eq!(
+            cake::Entity::find_by_ids(&db, [3, 1, 3])?
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" IN (3, 1)"#,
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "mock")]
+    fn test_find_by_ids_2() -> Result<(), crate::DbErr> {
+        use crate::tests_cfg::cake_filling_price;
+        use crate::{DbBackend, MockDatabase, entity::*, query::*};
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+        assert_eq!(
+            cake_filling_price::Entity::find_by_ids(&db, [(1, 2), (3, 4)])?
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake_filling_price`.`cake_id`, `cake_filling_price`.`filling_id`, `cake_filling_price`.`price`",
+                "FROM `public`.`cake_filling_price`",
+                "WHERE (`cake_filling_price`.`cake_id`, `cake_filling_price`.`filling_id`) IN ((1, 2), (3, 4))",
+            ]
+            .join(" "),
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "mock")]
+    fn test_find_by_ids_ordered() -> Result<(), crate::DbErr> {
+        use crate::tests_cfg::cake_filling;
+        use crate::{DbBackend, MockDatabase, Transaction, entity::*};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                cake_filling::Model {
+                    cake_id: 1,
+                    filling_id: 2,
+                },
+                cake_filling::Model {
+                    cake_id: 3,
+                    filling_id: 4,
+                },
+            ]])
+            .into_connection();
+
+        let found =
+            cake_filling::Entity::find_by_ids_ordered(&db, [(3, 4), (5, 6), (1, 2), (3, 4)])?;
+        assert_eq!(
+            found,
+            [
+                Some(cake_filling::Model {
+                    cake_id: 3,
+                    filling_id: 4,
+                }),
+                None,
+                Some(cake_filling::Model {
+                    cake_id: 1,
+                    filling_id: 2,
+                }),
+                Some(cake_filling::Model {
+                    cake_id: 3,
+                    filling_id: 4,
+                }),
+            ]
+        );
+
+        assert!(
+            cake_filling::Entity::find_by_ids_ordered(&db, Vec::<(i32, i32)>::new())?.is_empty()
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake_filling"."cake_id", "cake_filling"."filling_id" FROM "cake_filling""#,
+                    r#"WHERE ("cake_filling"."cake_id", "cake_filling"."filling_id") IN (($1, $2), ($3, $4), ($5, $6))"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [
+                    3i32.into(),
+                    4i32.into(),
+                    5i32.into(),
+                    6i32.into(),
+                    1i32.into(),
+                    2i32.into()
+                ]
+            )]
+        );
+
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "macros")]
     fn entity_model_1() {
@@ -23,9 +23,39 @@ pub struct ColumnDef {
     pub(crate) unique_key: Option<String>,
     pub(crate) renamed_from: Option<String>,
     pub(crate) extra: Option<String>,
+    pub(crate) generated: bool,
+    #[cfg(feature = "with-uuid")]
+    pub(crate) auto_uuid: Option<UuidVersion>,
     pub(crate) seaography: SeaographyColumnAttr,
 }
 
+/// The kind of UUID generated for an `auto_uuid` column,
+/// see [`ColumnDef::auto_uuid`].
+#[cfg(feature = "with-uuid")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UuidVersion {
+    /// A random UUID. Requires the `with-uuid-v4` feature.
+    #[cfg(feature = "with-uuid-v4")]
+    V4,
+    /// A time-ordered UUID, which keeps inserts into an index on the column sequential.
+    /// Requires the `with-uuid-v7` feature.
+    #[cfg(feature = "with-uuid-v7")]
+    V7,
+}
+
+#[cfg(feature = "with-uuid")]
+impl UuidVersion {
+    /// Generate a new UUID of this version
+    pub fn new_uuid(&self) -> uuid::Uuid {
+        match *self {
+            #[cfg(feature = "with-uuid-v4")]
+            Self::V4 => uuid::Uuid::new_v4(),
+            #[cfg(feature = "with-uuid-v7")]
+            Self::V7 => uuid::Uuid::now_v7(),
+        }
+    }
+}
+
 /// Column-level attributes consumed by [Seaography](https://github.com/SeaQL/seaography)
 /// when generating GraphQL schemas.
 #[non_exhaustive]
@@ -107,6 +137,21 @@ impl ColumnDef {
         self
     }
 
+    /// Mark the column as generated (computed) by the database.
+    /// Generated columns are selected as usual but never written by `INSERT` or `UPDATE`.
+    pub fn generated(mut self) -> Self {
+        self.generated = true;
+        self
+    }
+
+    /// Fill the column with a new UUID when inserting an ActiveModel which leaves it `NotSet`.
+    /// The value is generated before [`ActiveModelBehavior::before_save`](crate::ActiveModelBehavior::before_save) runs.
+    #[cfg(feature = "with-uuid")]
+    pub fn auto_uuid(mut self, version: UuidVersion) -> Self {
+        self.auto_uuid = Some(version);
+        self
+    }
+
     /// Get [ColumnType] as reference
     pub fn get_column_type(&self) -> &ColumnType {
         &self.col_type
@@ -127,6 +172,17 @@ impl ColumnDef {
         self.unique
     }
 
+    /// Returns true if the column is generated by the database
+    pub fn is_generated(&self) -> bool {
+        self.generated
+    }
+
+    /// The kind of UUID filled in on insert, if the column is `auto_uuid`
+    #[cfg(feature = "with-uuid")]
+    pub fn get_auto_uuid(&self) -> Option<UuidVersion> {
+        self.auto_uuid
+    }
+
     /// Get Seaography attribute
     pub fn seaography(&self) -> &SeaographyColumnAttr {
         &self.seaography
@@ -139,7 +195,7 @@ mod tests {
 
     #[test]
     fn test_col_from_str() {
-        use crate::IdenStatic;
+        use crate::{ColumnTrait, IdenStatic};
         use std::str::FromStr;
 
         assert!(matches!(
@@ -160,12 +216,23 @@ mod tests {
         ));
         assert!(matches!(
             fruit::Column::from_str("CakeId"),
-            Err(crate::ColumnFromStrErr(_))
+            Ok(fruit::Column::CakeId)
+        ));
+        assert!(matches!(
+            fruit::Column::from_str("CAKEID"),
+            Ok(fruit::Column::CakeId)
         ));
         assert!(matches!(
-            fruit::Column::from_str("does_not_exist"),
-            Err(crate::ColumnFromStrErr(_))
+            fruit::Column::from_str("cake-id"),
+            Err(crate::ColumnFromStrErr(..))
         ));
+        let err = fruit::Column::from_str("does_not_exist").unwrap_err();
+        assert_eq!(err.1, ["id", "name", "cake_id"]);
+        assert_eq!(
+            err.to_string(),
+            r#"Failed to match "does_not_exist" as Column, expected one of: id, name, cake_id"#
+        );
+        assert_eq!(fruit::Column::column_names(), ["id", "name", "cake_id"]);
         assert!(matches!(fruit::Column::CakeId.as_str(), "cake_id"));
     }
 
@@ -696,4 +763,66 @@ mod tests {
             ColumnType::string(None).def().renamed_from("old_b")
         );
     }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn column_def_generated() -> Result<(), crate::DbErr> {
+        use crate as sea_orm;
+        use crate::{DbBackend, IntoActiveModel, QueryTrait, Set, entity::prelude::*};
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "rectangle")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub width: i32,
+            pub height: i32,
+            #[sea_orm(generated)]
+            pub area: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+
+        assert_eq!(Column::Area.def(), ColumnType::Integer.def().generated());
+        assert!(Column::Area.def().is_generated());
+        assert!(!Column::Width.def().is_generated());
+
+        let model = Model {
+            id: 1,
+            width: 2,
+            height: 3,
+            area: 6,
+        };
+        assert_eq!(
+            Entity::insert(model.clone().into_active_model())
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            r#"INSERT INTO "rectangle" ("id", "width", "height") VALUES (1, 2, 3)"#,
+        );
+        assert_eq!(
+            Entity::insert_many([
+                model.clone().into_active_model(),
+                model.clone().into_active_model(),
+            ])
+            .build(DbBackend::Sqlite)
+            .to_string(),
+            r#"INSERT INTO "rectangle" ("id", "width", "height") VALUES (1, 2, 3), (1, 2, 3)"#,
+        );
+
+        let mut am = model.into_active_model();
+        am.width = Set(4);
+        am.area = Set(12);
+        assert_eq!(
+            Entity::update(am)
+                .validate()?
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            r#"UPDATE "rectangle" SET "width" = 4 WHERE "rectangle"."id" = 1"#,
+        );
+
+        Ok(())
+    }
 }
@@ -1,7 +1,9 @@
-use crate::{EntityTrait, QuerySelect, RelationDef, Select, join_tbl_on_condition};
+use crate::{EntityTrait, QuerySelect, Related, RelationDef, Select, join_tbl_on_condition};
 use sea_query::{
-    Alias, CommonTableExpression, Condition, IntoIden, IntoTableRef, JoinType, UnionType,
+    Alias, ColumnName, ColumnRef, CommonTableExpression, Condition, DynIden, Expr, IntoIden,
+    IntoTableRef, JoinType, TableName, UnionType,
 };
+use std::marker::PhantomData;
 
 /// One hop in a multi-hop [`Linked`] chain. Alias for [`RelationDef`].
 pub type LinkDef = RelationDef;
@@ -28,6 +30,135 @@ pub trait Linked {
     fn find_linked(&self) -> Select<Self::ToEntity> {
         find_linked(self.link().into_iter().rev(), JoinType::InnerJoin)
     }
+
+    /// Table alias of the `hop`-th table joined by
+    /// [`find_also_linked`](crate::Select::find_also_linked) and
+    /// [`find_with_linked`](crate::Select::find_with_linked): `r0`, `r1`, ...
+    ///
+    /// ```
+    /// # use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::*};
+    /// use sea_query::{Expr, ExprTrait};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .find_also_linked(entity_linked::CakeToFillingVendor)
+    ///         .filter(
+    ///             Expr::col((
+    ///                 entity_linked::CakeToFillingVendor::hop_alias(1),
+    ///                 filling::Column::Name,
+    ///             ))
+    ///             .eq("cream")
+    ///         )
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     cake::Entity::find()
+    ///         .find_also_linked(entity_linked::CakeToFillingVendor)
+    ///         .filter_on_hop(1, filling::Column::Name.eq("cream"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    /// );
+    /// ```
+    fn hop_alias(hop: usize) -> DynIden
+    where
+        Self: Sized,
+    {
+        hop_alias(hop)
+    }
+}
+
+/// A [`Linked`] chain `A -> B -> C` composed from the existing
+/// [`Related<B>`](Related) impl of `A` and [`Related<C>`](Related) impl of `B`,
+/// including any junction tables they go through.
+///
+/// Saves writing a bespoke [`Linked`] struct when both hops are already
+/// defined as relations.
+///
+/// ```
+/// # use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::*};
+/// assert_eq!(
+///     cake::Entity::find()
+///         .find_also_linked(TwoHopLink::<cake::Entity, filling::Entity, vendor::Entity>::new())
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+///     cake::Entity::find()
+///         .find_also_linked(entity_linked::CakeToFillingVendor)
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TwoHopLink<A, B, C>(PhantomData<(A, B, C)>);
+
+impl<A, B, C> TwoHopLink<A, B, C> {
+    /// Create a new link
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A, B, C> Linked for TwoHopLink<A, B, C>
+where
+    A: EntityTrait + Related<B>,
+    B: EntityTrait + Related<C>,
+    C: EntityTrait,
+{
+    type FromEntity = A;
+
+    type ToEntity = C;
+
+    fn link(&self) -> Vec<LinkDef> {
+        let mut link = Vec::new();
+        link.extend(<A as Related<B>>::via());
+        link.push(<A as Related<B>>::to());
+        link.extend(<B as Related<C>>::via());
+        link.push(<B as Related<C>>::to());
+        link
+    }
+}
+
+pub(crate) fn hop_alias(hop: usize) -> DynIden {
+    format!("r{hop}").into_iden()
+}
+
+fn is_hop_alias(tbl: &DynIden) -> bool {
+    tbl.inner()
+        .strip_prefix('r')
+        .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Re-qualify the columns in `expr` with the alias of `hop`. Columns of the `keep`
+/// table, columns already qualified with a hop alias and unqualified columns are left as is.
+pub(crate) fn qualify_with_hop_alias(expr: Expr, hop: usize, keep: &str) -> Expr {
+    fn requalify(expr: Expr, alias: &DynIden, keep: &str) -> Expr {
+        let rec = |expr: Expr| requalify(expr, alias, keep);
+        match expr {
+            Expr::Column(ColumnRef::Column(ColumnName(Some(TableName(_, tbl)), col)))
+                if tbl.inner() != keep && !is_hop_alias(&tbl) =>
+            {
+                Expr::Column(ColumnRef::Column(ColumnName(
+                    Some(TableName(None, alias.clone())),
+                    col,
+                )))
+            }
+            Expr::Tuple(exprs) => Expr::Tuple(exprs.into_iter().map(rec).collect()),
+            Expr::Unary(op, expr) => Expr::Unary(op, Box::new(rec(*expr))),
+            Expr::Binary(left, op, right) => {
+                Expr::Binary(Box::new(rec(*left)), op, Box::new(rec(*right)))
+            }
+            Expr::AsEnum(name, expr) => Expr::AsEnum(name, Box::new(rec(*expr))),
+            Expr::CustomWithExpr(sql, exprs) => {
+                Expr::CustomWithExpr(sql, exprs.into_iter().map(rec).collect())
+            }
+            // rebuilding the call would drop `DISTINCT` argument modifiers
+            Expr::FunctionCall(call) if call.get_mods().iter().all(|m| !m.distinct) => {
+                let args: Vec<_> = call.get_args().iter().cloned().map(rec).collect();
+                Expr::FunctionCall(call.args(args))
+            }
+            expr => expr,
+        }
+    }
+
+    requalify(expr, &hop_alias(hop), keep)
 }
 
 pub(crate) fn find_linked<I, E>(links: I, join: JoinType) -> Select<E>
@@ -37,9 +168,9 @@ where
 {
     let mut select = Select::new();
     for (i, mut rel) in links.enumerate() {
-        let from_tbl = format!("r{i}").into_iden();
+        let from_tbl = hop_alias(i);
         let to_tbl = if i > 0 {
-            format!("r{}", i - 1).into_iden()
+            hop_alias(i - 1)
         } else {
             rel.to_tbl.sea_orm_table().clone()
         };
@@ -50,6 +181,7 @@ where
             to_tbl.clone(),
             rel.from_col,
             rel.to_col,
+            rel.treat_nulls_as_equal,
         ));
         if let Some(f) = rel.on_condition.take() {
             condition = condition.add(f(from_tbl.clone(), to_tbl.clone()));
@@ -1,5 +1,5 @@
 use super::{ColumnTrait, IdenStatic, Iterable};
-use crate::{TryFromU64, TryGetableMany};
+use crate::{DbErr, TryFromU64, TryGetableMany};
 use sea_query::{FromValueTuple, IntoValueTuple};
 use std::fmt::Debug;
 
@@ -40,6 +40,13 @@ pub trait PrimaryKeyTrait: IdenStatic + Iterable {
     /// `IDENTITY` / `AUTOINCREMENT`). Auto-increment keys can be left as
     /// `NotSet` on insert.
     fn auto_increment() -> bool;
+
+    /// `true` if the database fills in a primary key that is not auto-increment,
+    /// e.g. with a trigger or a column default. Such keys can be left `NotSet` on insert,
+    /// otherwise inserting fails with [`DbErr::PrimaryKeyColumnNotSet`](crate::DbErr::PrimaryKeyColumnNotSet).
+    fn db_generated() -> bool {
+        false
+    }
 }
 
 /// Conversion between an entity's `PrimaryKey` enum and its `Column` enum.
@@ -93,9 +100,103 @@ impl_pk_arity!(10, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
 impl_pk_arity!(11, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
 impl_pk_arity!(12, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
 
+/// Parse a primary key value from strings, e.g. an id received in a URL path.
+///
+/// Implemented for integers, `String` and `Uuid`, and for tuples of them (composite keys).
+/// Parse failures yield [`DbErr::Type`].
+pub trait PrimaryKeyValueFromStr: PrimaryKeyArity + Sized {
+    /// Parse `Self` from one string per primary key column
+    fn from_strs(parts: &[&str]) -> Result<Self, DbErr>;
+
+    /// Parse `Self` from a single string; the values of a composite key are separated by `,`
+    fn from_key_str(s: &str) -> Result<Self, DbErr> {
+        if Self::ARITY == 1 {
+            Self::from_strs(&[s])
+        } else {
+            Self::from_strs(&s.split(',').collect::<Vec<_>>())
+        }
+    }
+}
+
+fn single_part<'a>(parts: &[&'a str]) -> Result<&'a str, DbErr> {
+    match parts {
+        [part] => Ok(*part),
+        _ => Err(arity_mismatch(1, parts.len())),
+    }
+}
+
+fn arity_mismatch(expected: usize, actual: usize) -> DbErr {
+    DbErr::Type(format!(
+        "Expected {expected} primary key value(s), got {actual}"
+    ))
+}
+
+macro_rules! pk_value_from_str {
+    ( $type: ty ) => {
+        impl PrimaryKeyValueFromStr for $type {
+            fn from_strs(parts: &[&str]) -> Result<Self, DbErr> {
+                let part = single_part(parts)?;
+                part.parse().map_err(|e| {
+                    DbErr::Type(format!(
+                        "Failed to parse primary key `{part}` as {}: {e}",
+                        stringify!($type)
+                    ))
+                })
+            }
+        }
+    };
+}
+
+macro_rules! pk_value_from_str_tuple {
+    ( $len: expr, $($idx: tt => $gen_type: ident),* ) => {
+        impl<$( $gen_type, )*> PrimaryKeyValueFromStr for ($( $gen_type, )*)
+        where
+            $( $gen_type: PrimaryKeyValueFromStr + TryGetableMany, )*
+        {
+            fn from_strs(parts: &[&str]) -> Result<Self, DbErr> {
+                if parts.len() != $len {
+                    return Err(arity_mismatch($len, parts.len()));
+                }
+                Ok(($( $gen_type::from_strs(&[parts[$idx]])?, )*))
+            }
+        }
+    };
+}
+
+pk_value_from_str!(i8);
+pk_value_from_str!(i16);
+pk_value_from_str!(i32);
+pk_value_from_str!(i64);
+pk_value_from_str!(u8);
+pk_value_from_str!(u16);
+pk_value_from_str!(u32);
+pk_value_from_str!(u64);
+pk_value_from_str!(String);
+
+#[cfg(feature = "with-uuid")]
+pk_value_from_str!(uuid::Uuid);
+
+#[rustfmt::skip]
+mod pk_value_from_str_tuple {
+    use super::*;
+
+    pk_value_from_str_tuple!(2, 0 => T0, 1 => T1);
+    pk_value_from_str_tuple!(3, 0 => T0, 1 => T1, 2 => T2);
+    pk_value_from_str_tuple!(4, 0 => T0, 1 => T1, 2 => T2, 3 => T3);
+    pk_value_from_str_tuple!(5, 0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4);
+    pk_value_from_str_tuple!(6, 0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5);
+    pk_value_from_str_tuple!(7, 0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5, 6 => T6);
+    pk_value_from_str_tuple!(8, 0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5, 6 => T6, 7 => T7);
+    pk_value_from_str_tuple!(9, 0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5, 6 => T6, 7 => T7, 8 => T8);
+    pk_value_from_str_tuple!(10, 0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5, 6 => T6, 7 => T7, 8 => T8, 9 => T9);
+    pk_value_from_str_tuple!(11, 0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5, 6 => T6, 7 => T7, 8 => T8, 9 => T9, 10 => T10);
+    pk_value_from_str_tuple!(12, 0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5, 6 => T6, 7 => T7, 8 => T8, 9 => T9, 10 => T10, 11 => T11);
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{EntityTrait, Identity};
+    use super::PrimaryKeyValueFromStr;
+    use crate::{DbErr, EntityTrait, Identity};
 
     #[test]
     #[cfg(feature = "macros")]
@@ -292,4 +393,41 @@ mod tests {
             Identity::Ternary("id_1".into(), "id_2".into(), "id_3".into())
         );
     }
+    #[test]
+    fn test_primary_key_value_from_str() {
+        assert_eq!(i64::from_key_str("42"), Ok(42));
+        assert_eq!(
+            i64::from_key_str("4x2"),
+            Err(DbErr::Type(
+                "Failed to parse primary key `4x2` as i64: invalid digit found in string".into()
+            ))
+        );
+        assert_eq!(String::from_key_str("a,b"), Ok("a,b".to_owned()));
+
+        assert_eq!(<(i32, i32)>::from_key_str("1,2"), Ok((1, 2)));
+        assert_eq!(<(i32, i32)>::from_strs(&["1", "2"]), Ok((1, 2)));
+        assert_eq!(
+            <(i32, i32)>::from_key_str("1"),
+            Err(DbErr::Type("Expected 2 primary key value(s), got 1".into()))
+        );
+        assert_eq!(
+            <(i32, i32)>::from_key_str("1,2,3"),
+            Err(DbErr::Type("Expected 2 primary key value(s), got 3".into()))
+        );
+        assert!(matches!(
+            <(i32, i32)>::from_key_str("1,b"),
+            Err(DbErr::Type(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "with-uuid")]
+    fn test_primary_key_value_from_str_uuid() {
+        let uuid = uuid::Uuid::new_v4();
+        assert_eq!(uuid::Uuid::from_key_str(&uuid.to_string()), Ok(uuid));
+        assert!(matches!(
+            uuid::Uuid::from_key_str("not-a-uuid"),
+            Err(DbErr::Type(_))
+        ));
+    }
 }
@@ -0,0 +1,323 @@
+use crate::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DatabaseExecutor, DbBackend,
+    EntityTrait, IdenStatic, Iterable, SqlxConnection, error::*, sqlx_error_to_exec_err,
+};
+use sea_query::Value;
+
+/// Rows are sent to the server in chunks of about this many bytes
+const COPY_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Stream `models` into the table of `E` with `COPY ... FROM STDIN`, returning the number of rows copied.
+/// See [`EntityTrait::copy_in`].
+pub(crate) fn exec_copy_in<E, I>(db: DatabaseExecutor<'_>, models: I) -> Result<u64, DbErr>
+where
+    E: EntityTrait,
+    I: Iterator<Item = E::ActiveModel>,
+{
+    let db_backend = db.get_database_backend();
+    if db_backend != DbBackend::Postgres {
+        return Err(DbErr::BackendNotSupported {
+            db: db_backend.as_str(),
+            ctx: "COPY",
+        });
+    }
+
+    let mut models = models.peekable();
+    let Some(first) = models.peek() else {
+        return Ok(0);
+    };
+    let columns = copy_columns::<E>(first)?;
+    let statement = copy_statement::<E>(&columns);
+
+    db.with_raw_sqlx(|conn| {
+        #[allow(irrefutable_let_patterns)]
+        let SqlxConnection::Postgres(conn) = conn else {
+            return Err(DbErr::BackendNotSupported {
+                db: db_backend.as_str(),
+                ctx: "COPY",
+            });
+        };
+        let mut copy = conn
+            .copy_in_raw(&statement)
+            .map_err(sqlx_error_to_exec_err)?;
+        let mut buf = Vec::with_capacity(COPY_CHUNK_SIZE);
+        for (row, model) in models.enumerate() {
+            if let Err(err) = encode_row::<E>(&columns, row, &model, &mut buf) {
+                // the server answers the abort with an error of its own, ours is more useful
+                let _ = copy.abort(err.to_string());
+                return Err(err);
+            }
+            if buf.len() >= COPY_CHUNK_SIZE {
+                copy.send(std::mem::replace(
+                    &mut buf,
+                    Vec::with_capacity(COPY_CHUNK_SIZE),
+                ))
+                .map_err(sqlx_error_to_exec_err)?;
+            }
+        }
+        if !buf.is_empty() {
+            copy.send(buf).map_err(sqlx_error_to_exec_err)?;
+        }
+        copy.finish().map_err(sqlx_error_to_exec_err)
+    })?
+}
+
+/// The columns set on `model`, which every other model has to set as well
+fn copy_columns<E>(model: &E::ActiveModel) -> Result<Vec<E::Column>, DbErr>
+where
+    E: EntityTrait,
+{
+    let columns: Vec<_> = E::Column::iter()
+        .filter(|col| !matches!(model.get(*col), ActiveValue::NotSet))
+        .collect();
+    if columns.is_empty() {
+        return Err(DbErr::Custom(
+            "COPY requires at least one column to be set".to_owned(),
+        ));
+    }
+    Ok(columns)
+}
+
+fn copy_statement<E>(columns: &[E::Column]) -> String
+where
+    E: EntityTrait,
+{
+    let entity = E::default();
+    let table = match entity.schema_name() {
+        Some(schema) => format!(
+            "{}.{}",
+            quote_ident(schema),
+            quote_ident(entity.table_name())
+        ),
+        None => quote_ident(entity.table_name()),
+    };
+    let columns: Vec<_> = columns
+        .iter()
+        .map(|col| quote_ident(col.as_str()))
+        .collect();
+    format!("COPY {table} ({}) FROM STDIN", columns.join(", "))
+}
+
+fn quote_ident(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+/// Append `model` to `buf` as one line of `COPY` text format. `row` is the position of the model,
+/// for error messages.
+fn encode_row<E>(
+    columns: &[E::Column],
+    row: usize,
+    model: &E::ActiveModel,
+    buf: &mut Vec<u8>,
+) -> Result<(), DbErr>
+where
+    E: EntityTrait,
+{
+    let mut columns = columns.iter().peekable();
+    let mut first = true;
+    for col in E::Column::iter() {
+        let value = model.get(col);
+        if value.is_set_default() {
+            return Err(DbErr::Custom(format!(
+                "COPY does not support `DEFAULT`, but `{}` is SetDefault on model {row}",
+                col.as_str()
+            )));
+        }
+        let value = value.into_value();
+        let expected = columns.next_if(|c| c.as_str() == col.as_str()).is_some();
+        let value = match (value, expected) {
+            (Some(value), true) => col.encrypt_value(value),
+            (None, false) => continue,
+            (None, true) => {
+                return Err(DbErr::Custom(format!(
+                    "COPY requires every model to set the same columns, but `{}` is not set on model {row}",
+                    col.as_str()
+                )));
+            }
+            (Some(_), false) => {
+                return Err(DbErr::Custom(format!(
+                    "COPY requires every model to set the same columns, but `{}` is set on model {row} and not on model 0",
+                    col.as_str()
+                )));
+            }
+        };
+        if !first {
+            buf.push(b'\t');
+        }
+        first = false;
+        write_value(&value, buf)?;
+    }
+    buf.push(b'\n');
+    Ok(())
+}
+
+/// Write `value` in the text representation Postgres parses on input, escaped for `COPY`
+fn write_value(value: &Value, buf: &mut Vec<u8>) -> Result<(), DbErr> {
+    if *value == value.as_null() {
+        buf.extend_from_slice(b"\\N");
+        return Ok(());
+    }
+    let text = match value {
+        Value::Bool(Some(v)) => if *v { "t" } else { "f" }.to_owned(),
+        Value::TinyInt(Some(v)) => v.to_string(),
+        Value::SmallInt(Some(v)) => v.to_string(),
+        Value::Int(Some(v)) => v.to_string(),
+        Value::BigInt(Some(v)) => v.to_string(),
+        Value::TinyUnsigned(Some(v)) => v.to_string(),
+        Value::SmallUnsigned(Some(v)) => v.to_string(),
+        Value::Unsigned(Some(v)) => v.to_string(),
+        Value::BigUnsigned(Some(v)) => v.to_string(),
+        Value::Float(Some(v)) => float_text(f64::from(*v), v.to_string()),
+        Value::Double(Some(v)) => float_text(*v, v.to_string()),
+        Value::String(Some(v)) => v.to_string(),
+        Value::Char(Some(v)) => v.to_string(),
+        Value::Bytes(Some(v)) => {
+            let mut text = String::with_capacity(2 + v.len() * 2);
+            text.push_str("\\x");
+            for byte in v.iter() {
+                text.push_str(&format!("{byte:02x}"));
+            }
+            text
+        }
+        #[cfg(feature = "with-json")]
+        Value::Json(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDate(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoTime(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTime(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeUtc(Some(v)) => v.to_rfc3339(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeLocal(Some(v)) => v.to_rfc3339(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeWithTimeZone(Some(v)) => v.to_rfc3339(),
+        #[cfg(feature = "with-time")]
+        Value::TimeDate(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-time")]
+        Value::TimeTime(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTime(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTimeWithTimeZone(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-rust_decimal")]
+        Value::Decimal(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-bigdecimal")]
+        Value::BigDecimal(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-ipnetwork")]
+        Value::IpNetwork(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-mac_address")]
+        Value::MacAddress(Some(v)) => v.to_string(),
+        _ => {
+            return Err(DbErr::Type(format!(
+                "{value:?} can not be written by COPY, insert it with `insert_many` instead"
+            )));
+        }
+    };
+    for byte in text.bytes() {
+        match byte {
+            b'\\' => buf.extend_from_slice(b"\\\\"),
+            b'\t' => buf.extend_from_slice(b"\\t"),
+            b'\n' => buf.extend_from_slice(b"\\n"),
+            b'\r' => buf.extend_from_slice(b"\\r"),
+            byte => buf.push(byte),
+        }
+    }
+    Ok(())
+}
+
+/// Rust spells infinity `inf`, Postgres `Infinity`
+fn float_text(v: f64, text: String) -> String {
+    if v == f64::INFINITY {
+        "Infinity".to_owned()
+    } else if v == f64::NEG_INFINITY {
+        "-Infinity".to_owned()
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ActiveValue::*, tests_cfg::fruit};
+
+    fn apple(cake_id: ActiveValue<Option<i32>>) -> fruit::ActiveModel {
+        fruit::ActiveModel {
+            id: NotSet,
+            name: Set("Apple\tgreen\\red\n".to_owned()),
+            cake_id,
+        }
+    }
+
+    #[test]
+    fn test_copy_statement() -> Result<(), DbErr> {
+        let columns = copy_columns::<fruit::Entity>(&apple(Set(None)))?;
+        assert_eq!(
+            copy_statement::<fruit::Entity>(&columns),
+            r#"COPY "fruit" ("name", "cake_id") FROM STDIN"#
+        );
+
+        assert!(matches!(
+            copy_columns::<fruit::Entity>(&fruit::ActiveModel {
+                id: NotSet,
+                name: NotSet,
+                cake_id: NotSet,
+            }),
+            Err(DbErr::Custom(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_encode_row() -> Result<(), DbErr> {
+        let columns = copy_columns::<fruit::Entity>(&apple(Set(None)))?;
+        let mut buf = Vec::new();
+        encode_row::<fruit::Entity>(&columns, 0, &apple(Set(None)), &mut buf)?;
+        encode_row::<fruit::Entity>(&columns, 1, &apple(Unchanged(Some(3))), &mut buf)?;
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            "Apple\\tgreen\\\\red\\n\t\\N\nApple\\tgreen\\\\red\\n\t3\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_column_list_mismatch() -> Result<(), DbErr> {
+        let columns = copy_columns::<fruit::Entity>(&apple(Set(None)))?;
+        let mut buf = Vec::new();
+        assert_eq!(
+            encode_row::<fruit::Entity>(&columns, 1, &apple(NotSet), &mut buf)
+                .unwrap_err()
+                .to_string(),
+            "Custom Error: COPY requires every model to set the same columns, but `cake_id` is not set on model 1"
+        );
+
+        let mut with_id = apple(Set(None));
+        with_id.id = Set(4);
+        assert_eq!(
+            encode_row::<fruit::Entity>(&columns, 2, &with_id, &mut buf)
+                .unwrap_err()
+                .to_string(),
+            "Custom Error: COPY requires every model to set the same columns, but `id` is set on model 2 and not on model 0"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_write_value() -> Result<(), DbErr> {
+        let text = |value: Value| {
+            let mut buf = Vec::new();
+            write_value(&value, &mut buf).map(|_| String::from_utf8(buf).unwrap())
+        };
+        assert_eq!(text(Value::Bool(Some(true)))?, "t");
+        assert_eq!(text(Value::Double(Some(f64::NEG_INFINITY)))?, "-Infinity");
+        assert_eq!(text(Value::Float(Some(1.5)))?, "1.5");
+        assert_eq!(text(Value::Bytes(Some(vec![0, 0xab])))?, "\\\\x00ab");
+        assert_eq!(text(Value::String(None))?, "\\N");
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use super::{ReturningSelector, SelectModel};
 use crate::{
-    ColumnTrait, ConnectionTrait, DeleteMany, DeleteOne, EntityTrait, Iterable, ValidatedDeleteOne,
-    error::*,
+    ColumnTrait, ConnectionTrait, DeleteMany, DeleteOne, EntityTrait, Iterable, RelationDef,
+    ValidatedDeleteOne, error::*,
 };
 use sea_query::{DeleteStatement, Query};
 
@@ -23,6 +23,20 @@ pub struct DeleteResult {
     pub rows_affected: u64,
 }
 
+/// Result of [`ModelTrait::delete_with_report`](crate::ModelTrait::delete_with_report):
+/// the deleted Model and the relations the database acts on by itself.
+#[derive(Clone, Debug)]
+pub struct DeleteReport<M> {
+    /// The Model that was deleted.
+    pub model: M,
+    /// Number of rows removed by the statement.
+    pub rows_affected: u64,
+    /// Relations whose rows are deleted or updated by the foreign key's
+    /// `ON DELETE` action, see
+    /// [`EntityTrait::cascade_targets`](crate::EntityTrait::cascade_targets).
+    pub cascades: Vec<RelationDef>,
+}
+
 impl<E> ValidatedDeleteOne<E>
 where
     E: EntityTrait,
@@ -211,4 +225,43 @@ mod tests {
             Err(DbErr::PrimaryKeyNotSet { .. })
         ));
     }
+
+    #[test]
+    fn delete_error_composite_key() {
+        use crate::tests_cfg::cake_filling;
+        use crate::{ActiveModelTrait, DbBackend, DbErr, Delete, MockDatabase, Set};
+
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        assert!(matches!(
+            Delete::one(cake_filling::ActiveModel {
+                ..Default::default()
+            })
+            .exec(&db),
+            Err(DbErr::PrimaryKeyNotSet { ctx: "DeleteOne" })
+        ));
+
+        let partial = cake_filling::ActiveModel {
+            cake_id: Set(1),
+            ..Default::default()
+        };
+        assert!(matches!(
+            Delete::one(partial.clone()).exec(&db),
+            Err(DbErr::PrimaryKeyNotSet { ctx: "DeleteOne" })
+        ));
+        assert!(matches!(
+            partial.delete(&db),
+            Err(DbErr::PrimaryKeyNotSet { ctx: "DeleteOne" })
+        ));
+        assert!(matches!(
+            (cake::ActiveModel {
+                ..Default::default()
+            })
+            .delete(&db),
+            Err(DbErr::PrimaryKeyNotSet { ctx: "DeleteOne" })
+        ));
+
+        // nothing was sent to the database
+        assert!(db.into_transaction_log().is_empty());
+    }
 }
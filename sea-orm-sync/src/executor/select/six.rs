@@ -124,6 +124,7 @@ where
     {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -139,12 +140,12 @@ where
         R: PartialModelTrait,
     {
         let select = QuerySelect::select_only(self);
-        let select = M::select_cols(select);
-        let select = N::select_cols(select);
-        let select = O::select_cols(select);
-        let select = P::select_cols(select);
-        let select = Q::select_cols(select);
-        let select = R::select_cols(select);
+        let select = M::select_cols_with_prefix(select, SelectA.as_str());
+        let select = N::select_cols_with_prefix(select, SelectB.as_str());
+        let select = O::select_cols_with_prefix(select, SelectC.as_str());
+        let select = P::select_cols_with_prefix(select, SelectD.as_str());
+        let select = Q::select_cols_with_prefix(select, SelectE.as_str());
+        let select = R::select_cols_with_prefix(select, SelectF.as_str());
         select.into_model::<M, N, O, P, Q, R>()
     }
 
@@ -156,6 +157,7 @@ where
     {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -115,6 +115,7 @@ where
     {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -127,9 +128,9 @@ where
         O: PartialModelTrait,
     {
         let select = QuerySelect::select_only(self);
-        let select = M::select_cols(select);
-        let select = N::select_cols(select);
-        let select = O::select_cols(select);
+        let select = M::select_cols_with_prefix(select, SelectA.as_str());
+        let select = N::select_cols_with_prefix(select, SelectB.as_str());
+        let select = O::select_cols_with_prefix(select, SelectC.as_str());
         select.into_model::<M, N, O>()
     }
 
@@ -138,6 +139,7 @@ where
     pub fn into_json(self) -> Selector<SelectThreeModel<JsonValue, JsonValue, JsonValue>> {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -239,6 +241,7 @@ where
     {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -0,0 +1,84 @@
+use crate::EntityTrait;
+use sea_query::{Alias, DynIden, Expr, IntoIden};
+use std::marker::PhantomData;
+
+/// A table alias bound to an [`EntityTrait`], for joining the same entity more
+/// than once, e.g. along a self-referencing relation.
+///
+/// Pass it to [`join_as`](crate::QuerySelect::join_as) /
+/// [`join_as_rev`](crate::QuerySelect::join_as_rev) and build the expressions on
+/// the aliased table with [`col`](Self::col), so the alias is spelled only once.
+///
+/// ```
+/// use sea_orm::{DbBackend, EntityAlias, JoinType, entity::*, query::*, tests_cfg::ingredient};
+///
+/// let parent = EntityAlias::<ingredient::Entity>::new("parent");
+///
+/// assert_eq!(
+///     ingredient::Entity::find()
+///         .column_as(parent.col(ingredient::Column::Name), "parent_name")
+///         .join_as(
+///             JoinType::InnerJoin,
+///             ingredient::Relation::Ingredient.def(),
+///             &parent
+///         )
+///         .filter(parent.col(ingredient::Column::Name).eq("Sugar"))
+///         .build(DbBackend::MySql)
+///         .to_string(),
+///     [
+///         "SELECT `ingredient`.`id`, `ingredient`.`name`, `ingredient`.`filling_id`, `ingredient`.`ingredient_id`,",
+///         "`parent`.`name` AS `parent_name` FROM `ingredient`",
+///         "INNER JOIN `ingredient` AS `parent` ON `ingredient`.`ingredient_id` = `parent`.`id`",
+///         "WHERE `parent`.`name` = 'Sugar'",
+///     ]
+///     .join(" ")
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct EntityAlias<E>
+where
+    E: EntityTrait,
+{
+    alias: DynIden,
+    entity: PhantomData<E>,
+}
+
+impl<E> EntityAlias<E>
+where
+    E: EntityTrait,
+{
+    /// Alias the table of `E` as `alias`
+    pub fn new<T>(alias: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            alias: Alias::new(alias).into_iden(),
+            entity: PhantomData,
+        }
+    }
+
+    /// A column of `E` qualified by this alias, for use in `filter`, `column_as`,
+    /// `order_by` and so on
+    pub fn col(&self, col: E::Column) -> Expr {
+        Expr::col((self.alias.clone(), col))
+    }
+}
+
+impl<E> From<EntityAlias<E>> for DynIden
+where
+    E: EntityTrait,
+{
+    fn from(alias: EntityAlias<E>) -> Self {
+        alias.alias
+    }
+}
+
+impl<E> From<&EntityAlias<E>> for DynIden
+where
+    E: EntityTrait,
+{
+    fn from(alias: &EntityAlias<E>) -> Self {
+        alias.alias.clone()
+    }
+}
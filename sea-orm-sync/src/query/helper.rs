@@ -1,10 +1,11 @@
 use crate::{
-    ActiveModelTrait, ColumnAsExpr, ColumnTrait, EntityTrait, Identity, IntoIdentity,
-    IntoSimpleExpr, Iterable, ModelTrait, PrimaryKeyToColumn, RelationDef,
+    ActiveModelTrait, ColumnAsExpr, ColumnTrait, DbBackend, DbErr, EntityTrait, Identity,
+    IntoIdentity, IntoSimpleExpr, Iterable, ModelTrait, PrimaryKeyToColumn, RelationDef, Value,
+    entity::column::select_as_named, query::check_group_by,
 };
 use sea_query::{
-    Alias, Expr, ExprTrait, IntoCondition, IntoIden, LockBehavior, LockType, NullOrdering, SeaRc,
-    SelectExpr, SelectStatement, SimpleExpr,
+    Alias, BinOper, Expr, ExprTrait, IntoCondition, IntoIden, LockBehavior, LockType, NullOrdering,
+    SeaRc, SelectExpr, SelectStatement, SimpleExpr,
 };
 pub use sea_query::{Condition, ConditionalStatement, DynIden, JoinType, Order, OrderedStatement};
 
@@ -70,7 +71,7 @@ pub trait QuerySelect: Sized {
     where
         C: ColumnTrait,
     {
-        self.query().expr(col.select_as(col.into_expr()));
+        self.query().expr(select_as_named(&col, col.into_expr()));
         self
     }
 
@@ -285,6 +286,48 @@ pub trait QuerySelect: Sized {
         self
     }
 
+    /// Check that every plain column selected also appears in the `GROUP BY` clause, as
+    /// Postgres and MySQL in `ONLY_FULL_GROUP_BY` mode require, returning a [`DbErr::GroupByMissing`]
+    /// listing the offending columns instead of a driver error at execution.
+    ///
+    /// Aggregates and other expressions are not checked, and neither is a query without
+    /// `GROUP BY`. Columns functionally dependent on a grouped primary key, which Postgres
+    /// accepts, are reported as well.
+    /// ```
+    /// use sea_orm::{DbErr, entity::*, query::*, tests_cfg::cake};
+    ///
+    /// assert!(
+    ///     cake::Entity::find()
+    ///         .select_only()
+    ///         .column(cake::Column::Name)
+    ///         .column_as(cake::Column::Id.count(), "count")
+    ///         .group_by(cake::Column::Name)
+    ///         .validate_group_by()
+    ///         .is_ok()
+    /// );
+    ///
+    /// assert!(
+    ///     cake::Entity::find()
+    ///         .select_only()
+    ///         .column_as(cake::Column::Id.count(), "count")
+    ///         .group_by(cake::Column::Name)
+    ///         .validate_group_by()
+    ///         .is_ok()
+    /// );
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .group_by(cake::Column::Name)
+    ///         .validate_group_by()
+    ///         .unwrap_err(),
+    ///     DbErr::GroupByMissing(vec!["cake.id".to_owned()])
+    /// );
+    /// ```
+    fn validate_group_by(mut self) -> Result<Self, DbErr> {
+        check_group_by(self.query())?;
+        Ok(self)
+    }
+
     /// Add an AND HAVING expression
     /// ```
     /// use sea_orm::{sea_query::{Alias, Expr, ExprTrait}, entity::*, query::*, tests_cfg::cake, DbBackend};
@@ -344,7 +387,8 @@ pub trait QuerySelect: Sized {
     }
 
     /// Add a DISTINCT ON expression
-    /// NOTE: this function is only supported by `sqlx-postgres`
+    /// NOTE: this function is only supported by `sqlx-postgres`,
+    /// executing the query on other backends returns [`DbErr::BackendNotSupported`](crate::DbErr::BackendNotSupported)
     /// ```
     /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
     /// struct Input {
@@ -373,6 +417,36 @@ pub trait QuerySelect: Sized {
         self
     }
 
+    /// Add a DISTINCT ON expression of entity columns, each column is qualified with its table name.
+    /// This keeps the expression unambiguous after joining, e.g. with `find_with_related`.
+    /// NOTE: this function is only supported by `sqlx-postgres`,
+    /// executing the query on other backends returns [`DbErr::BackendNotSupported`](crate::DbErr::BackendNotSupported)
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::{cake, fruit}};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .distinct_on_columns([cake::Column::Id])
+    ///         .find_with_related(fruit::Entity)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT DISTINCT ON ("cake"."id") "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+    ///         r#""fruit"."id" AS "B_id", "fruit"."name" AS "B_name", "fruit"."cake_id" AS "B_cake_id""#,
+    ///         r#"FROM "cake" LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+    ///         r#"ORDER BY "cake"."id" ASC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn distinct_on_columns<C, I>(self, cols: I) -> Self
+    where
+        C: ColumnTrait,
+        I: IntoIterator<Item = C>,
+    {
+        self.distinct_on(cols.into_iter().map(|col| col.as_column_ref()))
+    }
+
     #[doc(hidden)]
     fn join_join(mut self, join: JoinType, rel: RelationDef, via: Option<RelationDef>) -> Self {
         if let Some(via) = via {
@@ -906,16 +980,82 @@ pub trait QueryFilter: Sized {
     }
 }
 
+/// Constructors of [`Condition`] for filters assembled at runtime
+pub trait ConditionExt {
+    /// AND together an equality predicate per `(column, value)` pair, checking each value
+    /// against the column type with [`ColumnTrait::eq_value`]. The predicates follow the
+    /// declaration order of the columns, whatever order the pairs come in.
+    /// ```
+    /// use sea_orm::{DbBackend, DbErr, Value, entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let filters = [
+    ///     (fruit::Column::CakeId, Value::Int(Some(1))),
+    ///     (fruit::Column::Name, Value::from("Apple")),
+    /// ];
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .filter(Condition::from_column_values(filters)?)
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit` WHERE `fruit`.`name` = 'Apple' AND `fruit`.`cake_id` = 1"
+    /// );
+    ///
+    /// assert!(matches!(
+    ///     Condition::from_column_values([(fruit::Column::Id, Value::from("one"))]),
+    ///     Err(DbErr::Type(_))
+    /// ));
+    /// # Ok::<(), DbErr>(())
+    /// ```
+    fn from_column_values<C, I>(values: I) -> Result<Condition, DbErr>
+    where
+        C: ColumnTrait,
+        I: IntoIterator<Item = (C, Value)>;
+}
+
+impl ConditionExt for Condition {
+    fn from_column_values<C, I>(values: I) -> Result<Condition, DbErr>
+    where
+        C: ColumnTrait,
+        I: IntoIterator<Item = (C, Value)>,
+    {
+        let mut values: Vec<_> = values.into_iter().collect();
+        values.sort_by_key(|(col, _)| C::iter().position(|c| c.as_str() == col.as_str()));
+        values
+            .into_iter()
+            .try_fold(Condition::all(), |cond, (col, value)| {
+                Ok(cond.add(col.eq_value(value)?))
+            })
+    }
+}
+
 pub(crate) fn join_tbl_on_condition(
     from_tbl: DynIden,
     to_tbl: DynIden,
     owner_keys: Identity,
     foreign_keys: Identity,
+    treat_nulls_as_equal: Option<DbBackend>,
 ) -> Condition {
     let mut cond = Condition::all();
     for (owner_key, foreign_key) in owner_keys.into_iter().zip(foreign_keys) {
-        cond = cond
-            .add(Expr::col((from_tbl.clone(), owner_key)).equals((to_tbl.clone(), foreign_key)));
+        let owner = Expr::col((from_tbl.clone(), owner_key));
+        let foreign = (to_tbl.clone(), foreign_key);
+        cond = cond.add(match treat_nulls_as_equal {
+            Some(db_backend) => null_safe_eq(owner, Expr::col(foreign), db_backend),
+            None => owner.equals(foreign),
+        });
     }
     cond
 }
+
+/// `left = right`, except that `NULL` equals `NULL`
+fn null_safe_eq(left: Expr, right: Expr, db_backend: DbBackend) -> Expr {
+    match db_backend {
+        DbBackend::MySql => left.binary(BinOper::Custom("<=>"), right),
+        DbBackend::Postgres => left.binary(BinOper::Custom("IS NOT DISTINCT FROM"), right),
+        // `IS NOT DISTINCT FROM` is only understood from SQLite 3.39 on
+        DbBackend::Sqlite => left
+            .clone()
+            .eq(right.clone())
+            .or(left.is_null().and(right.is_null())),
+    }
+}
@@ -1,9 +1,12 @@
 use crate::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityName, EntityTrait, IntoActiveModel, Iterable,
-    PrimaryKeyTrait, QueryTrait,
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbErr, EntityName, EntityTrait, IdenStatic,
+    IntoActiveModel, Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, QueryTrait, Select,
 };
 use core::marker::PhantomData;
-use sea_query::{Expr, InsertStatement, Keyword, OnConflict, SimpleExpr, Value, ValueTuple};
+use sea_query::{
+    Expr, InsertStatement, IntoTableRef, Keyword, OnConflict, SimpleExpr, TableRef, Value,
+    ValueTuple,
+};
 
 /// Single-row `INSERT` builder, returned by
 /// [`EntityTrait::insert`](crate::EntityTrait::insert). Chain `.on_conflict(...)`
@@ -16,6 +19,13 @@ where
 {
     pub(crate) query: InsertStatement,
     pub(crate) primary_key: Option<ValueTuple>,
+    pub(crate) primary_key_check: Result<(), DbErr>,
+    /// Whether a value is `DEFAULT`, see [`ActiveValue::SetDefault`]
+    pub(crate) set_default: bool,
+    pub(crate) table_ref: Option<TableRef>,
+    /// The `ON CONFLICT` clause set on the query, which
+    /// [`Insert::exec_with_returning_outcome`] extends on MySQL
+    pub(crate) on_conflict: Option<OnConflict>,
     pub(crate) model: PhantomData<A>,
 }
 
@@ -30,15 +40,33 @@ where
 {
     pub(crate) query: InsertStatement,
     pub(crate) primary_key: Option<ValueTuple>,
+    pub(crate) primary_key_check: Result<(), DbErr>,
+    /// Whether a value is `DEFAULT`, see [`ActiveValue::SetDefault`]
+    pub(crate) set_default: bool,
+    pub(crate) table_ref: Option<TableRef>,
     pub(crate) empty: bool,
     pub(crate) model: PhantomData<A>,
 }
 
+/// `INSERT INTO ... SELECT` builder, returned by
+/// [`EntityTrait::insert_from_select`](crate::EntityTrait::insert_from_select).
+/// Chain `.on_conflict(...)` then dispatch with `.exec(db)` or
+/// `.exec_with_returning(db)`.
+#[derive(Debug)]
+pub struct InsertFromSelect<E>
+where
+    E: EntityTrait,
+{
+    pub(crate) query: InsertStatement,
+    pub(crate) entity: PhantomData<E>,
+}
+
 /// Wrapper of [`Insert`] / [`InsertMany`], treats "no row inserted/id returned" as a normal outcome.
 ///
 /// Its `exec*` methods return [`crate::TryInsertResult`].
-/// Mapping empty input to [`crate::TryInsertResult::Empty`] (no SQL executed) and
-/// `DbErr::RecordNotInserted` to [`crate::TryInsertResult::Conflicted`].
+/// Mapping empty input to [`crate::TryInsertResult::Empty`] (no SQL executed; an empty `Vec` for
+/// the methods returning many rows) and `DbErr::RecordNotInserted` to
+/// [`crate::TryInsertResult::Conflicted`].
 ///
 /// Useful for idempotent inserts such as `ON CONFLICT ... DO NOTHING` (Postgres / SQLite) or the
 /// MySQL polyfill (`ON DUPLICATE KEY UPDATE pk = pk`).
@@ -95,22 +123,29 @@ where
             .or_default_values();
 
         let mut am: A = m.into_active_model();
-        let primary_key =
-            if !<<A::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::auto_increment() {
-                am.get_primary_key_value()
-            } else {
-                None
-            };
+        set_auto_uuid(&mut am);
+        // a key that is set is read back as is, even if it is auto-increment
+        let primary_key = am.get_primary_key_value();
+        let primary_key_check = check_primary_key_set(&am, "Insert");
         let mut columns = Vec::new();
         let mut values = Vec::new();
+        let mut set_default = false;
 
         for col in <A::Entity as EntityTrait>::Column::iter() {
             let av = am.take(col);
+            if col.def().is_generated() {
+                continue;
+            }
 
             match av {
                 ActiveValue::Set(value) | ActiveValue::Unchanged(value) => {
                     columns.push(col);
-                    values.push(col.save_as(Expr::val(value)));
+                    values.push(col.save_as(Expr::val(col.encrypt_value(value))));
+                }
+                ActiveValue::SetDefault => {
+                    columns.push(col);
+                    values.push(default_keyword());
+                    set_default = true;
                 }
                 ActiveValue::NotSet => {}
             }
@@ -122,6 +157,10 @@ where
         Self {
             query,
             primary_key,
+            primary_key_check,
+            set_default,
+            table_ref: None,
+            on_conflict: None,
             model: PhantomData,
         }
     }
@@ -213,10 +252,45 @@ where
     /// );
     /// ```
     pub fn on_conflict(mut self, on_conflict: OnConflict) -> Self {
-        self.query.on_conflict(on_conflict);
+        self.query.on_conflict(on_conflict.clone());
+        self.on_conflict = Some(on_conflict);
         self
     }
 
+    /// Insert into `table` instead of the entity's own table, e.g. a shard of it chosen
+    /// at runtime. Where the backend has no `RETURNING`, the inserted row is also read
+    /// back from `table`.
+    ///
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, sea_query::Alias, tests_cfg::cake};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::insert(cake::ActiveModel {
+    ///         name: Set("Apple Pie".to_owned()),
+    ///         ..Default::default()
+    ///     })
+    ///     .into_table(Alias::new("cake_2024"))
+    ///     .build(DbBackend::Postgres)
+    ///     .to_string(),
+    ///     r#"INSERT INTO "cake_2024" ("name") VALUES ('Apple Pie')"#,
+    /// );
+    /// ```
+    pub fn into_table<T>(mut self, table: T) -> Self
+    where
+        T: IntoTableRef,
+    {
+        let table = table.into_table_ref();
+        self.query.into_table(table.clone());
+        self.table_ref = Some(table);
+        self
+    }
+
+    /// Insert into the table of `entity`, for entities with a table name set at runtime.
+    /// See [`into_table`](Self::into_table).
+    pub fn for_entity_instance(self, entity: A::Entity) -> Self {
+        self.into_table(entity.table_ref())
+    }
+
     /// Set ON CONFLICT do nothing, but with MySQL specific polyfill.
     pub fn on_conflict_do_nothing_on<I>(mut self, columns: I) -> TryInsert<A>
     where
@@ -301,6 +375,49 @@ where
 
         TryInsert::from_one(self)
     }
+
+    /// Set ON CONFLICT on primary key to return the existing row, instead of nothing.
+    ///
+    /// The conflict is turned into a no-op update (`DO UPDATE SET pk = excluded.pk`), so that
+    /// `RETURNING` yields the pre-existing row with its original values.
+    /// Unlike `DO NOTHING`, this takes a row lock on the existing row until the transaction ends,
+    /// and fires `UPDATE` triggers.
+    ///
+    /// Only useful on backends supporting `RETURNING` (Postgres / SQLite); on MySQL the
+    /// insert behaves like [`Insert::on_conflict_do_nothing`].
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let orange = cake::ActiveModel {
+    ///     id: ActiveValue::set(2),
+    ///     name: ActiveValue::set("Orange".to_owned()),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::insert(orange.clone())
+    ///         .on_conflict_do_nothing_return_existing()
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"INSERT INTO "cake" ("id", "name") VALUES (2, 'Orange') ON CONFLICT ("id") DO UPDATE SET "id" = "excluded"."id""#,
+    /// );
+    /// assert_eq!(
+    ///     cake::Entity::insert(orange)
+    ///         .on_conflict_do_nothing_return_existing()
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     r#"INSERT INTO "cake" ("id", "name") VALUES (2, 'Orange') ON CONFLICT ("id") DO UPDATE SET "id" = "excluded"."id""#,
+    /// );
+    /// ```
+    pub fn on_conflict_do_nothing_return_existing(mut self) -> TryInsert<A>
+    where
+        A: ActiveModelTrait,
+    {
+        self.query
+            .on_conflict(on_conflict_primary_key_return_existing::<A>());
+
+        TryInsert::from_one(self)
+    }
 }
 
 impl<A> InsertMany<A>
@@ -322,24 +439,35 @@ where
         let mut null_value: Vec<Option<Value>> = std::iter::repeat_n(None, columns.len()).collect();
         let mut all_values: Vec<Vec<SimpleExpr>> = Vec::new();
         let mut primary_key = None;
+        let mut primary_key_check = Ok(());
+        let mut set_default = false;
 
         for model in models.into_iter() {
             let mut am: A = model.into_active_model();
-            primary_key =
-                if !<<A::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::auto_increment() {
-                    am.get_primary_key_value()
-                } else {
-                    None
-                };
+            set_auto_uuid(&mut am);
+            primary_key = am.get_primary_key_value();
+            if primary_key_check.is_ok() {
+                primary_key_check = check_primary_key_set(&am, "InsertMany");
+            }
             let mut values = Vec::with_capacity(columns.len());
             for (idx, col) in <A::Entity as EntityTrait>::Column::iter().enumerate() {
                 let av = am.take(col);
+                if col.def().is_generated() {
+                    values.push(SimpleExpr::Keyword(Keyword::Null)); // never written
+                    continue;
+                }
                 match av {
                     ActiveValue::Set(value) | ActiveValue::Unchanged(value) => {
+                        let value = col.encrypt_value(value);
                         columns[idx] = Some(col); // mark the column as used
                         null_value[idx] = Some(value.as_null()); // store the null value with the correct type
                         values.push(col.save_as(Expr::val(value))); // same as add() above
                     }
+                    ActiveValue::SetDefault => {
+                        columns[idx] = Some(col); // mark the column as used
+                        values.push(default_keyword());
+                        set_default = true;
+                    }
                     ActiveValue::NotSet => {
                         values.push(SimpleExpr::Keyword(Keyword::Null)); // indicate a missing value
                     }
@@ -364,8 +492,12 @@ where
                         // use the value expression
                         Some(v)
                     } else {
-                        // use null as standin, which must be Some
-                        null_value[i].clone().map(SimpleExpr::Value)
+                        // use null as standin, or the default if no row has a value to type it
+                        Some(
+                            null_value[i]
+                                .clone()
+                                .map_or_else(default_keyword, SimpleExpr::Value),
+                        )
                     }
                 } else {
                     None
@@ -376,6 +508,9 @@ where
         Self {
             query,
             primary_key,
+            primary_key_check,
+            set_default,
+            table_ref: None,
             empty,
             model: PhantomData,
         }
@@ -387,6 +522,23 @@ where
         self
     }
 
+    /// Insert into `table` instead of the entity's own table, see [`Insert::into_table`]
+    pub fn into_table<T>(mut self, table: T) -> Self
+    where
+        T: IntoTableRef,
+    {
+        let table = table.into_table_ref();
+        self.query.into_table(table.clone());
+        self.table_ref = Some(table);
+        self
+    }
+
+    /// Insert into the table of `entity`, for entities with a table name set at runtime.
+    /// See [`Insert::into_table`].
+    pub fn for_entity_instance(self, entity: A::Entity) -> Self {
+        self.into_table(entity.table_ref())
+    }
+
     /// Set ON CONFLICT do nothing, but with MySQL specific polyfill.
     /// ```
     /// use sea_orm::{entity::*, query::*, sea_query::OnConflict, tests_cfg::cake, DbBackend};
@@ -463,6 +615,18 @@ where
         TryInsert::from_many(self)
     }
 
+    /// Set ON CONFLICT on primary key to return the existing rows, instead of nothing.
+    /// See [`Insert::on_conflict_do_nothing_return_existing`], including the row lock it takes.
+    pub fn on_conflict_do_nothing_return_existing(mut self) -> TryInsert<A>
+    where
+        A: ActiveModelTrait,
+    {
+        self.query
+            .on_conflict(on_conflict_primary_key_return_existing::<A>());
+
+        TryInsert::from_many(self)
+    }
+
     /// panic when self is empty
     pub(crate) fn into_one(self) -> Insert<A> {
         assert!(!self.empty);
@@ -470,6 +634,9 @@ where
         let Self {
             query,
             primary_key,
+            primary_key_check,
+            set_default,
+            table_ref,
             empty: _,
             model,
         } = self;
@@ -477,11 +644,88 @@ where
         Insert {
             query,
             primary_key,
+            primary_key_check,
+            set_default,
+            table_ref,
+            on_conflict: None,
             model,
         }
     }
 }
 
+impl<E> InsertFromSelect<E>
+where
+    E: EntityTrait,
+{
+    /// Insert the rows selected by `select` into `columns`, in order
+    ///
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::{cake, fruit}};
+    ///
+    /// assert_eq!(
+    ///     InsertFromSelect::<fruit::Entity>::new(
+    ///         [fruit::Column::Name, fruit::Column::CakeId],
+    ///         cake::Entity::find()
+    ///             .select_only()
+    ///             .column(cake::Column::Name)
+    ///             .column(cake::Column::Id)
+    ///             .filter(cake::Column::Name.contains("chocolate")),
+    ///     )
+    ///     .build(DbBackend::Postgres)
+    ///     .to_string(),
+    ///     r#"INSERT INTO "fruit" ("name", "cake_id") SELECT "cake"."name", "cake"."id" FROM "cake" WHERE "cake"."name" LIKE '%chocolate%'"#,
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of columns doesn't match the number of selected expressions.
+    pub fn new<I, S>(columns: I, select: Select<S>) -> Self
+    where
+        I: IntoIterator<Item = E::Column>,
+        S: EntityTrait,
+    {
+        let mut query = InsertStatement::new();
+        query.into_table(E::default().table_ref()).columns(columns);
+        if let Err(err) = query.select_from(select.into_query()) {
+            panic!("Columns of INSERT INTO ... SELECT don't match the selected expressions: {err}");
+        }
+        Self {
+            query,
+            entity: PhantomData,
+        }
+    }
+
+    /// On conflict
+    ///
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, sea_query::OnConflict, tests_cfg::{cake, fruit}};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::insert_from_select(
+    ///         [fruit::Column::Id, fruit::Column::Name],
+    ///         cake::Entity::find()
+    ///             .select_only()
+    ///             .column(cake::Column::Id)
+    ///             .column(cake::Column::Name)
+    ///             .filter(cake::Column::Id.gt(10)),
+    ///     )
+    ///     .on_conflict(
+    ///         OnConflict::column(fruit::Column::Id)
+    ///             .update_column(fruit::Column::Name)
+    ///             .to_owned()
+    ///     )
+    ///     .build(DbBackend::Postgres)
+    ///     .to_string(),
+    ///     r#"INSERT INTO "fruit" ("id", "name") SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" > 10 ON CONFLICT ("id") DO UPDATE SET "name" = "excluded"."name""#,
+    /// );
+    /// ```
+    pub fn on_conflict(mut self, on_conflict: OnConflict) -> Self {
+        self.query.on_conflict(on_conflict);
+        self
+    }
+}
+
 impl<A> QueryTrait for Insert<A>
 where
     A: ActiveModelTrait,
@@ -520,6 +764,25 @@ where
     }
 }
 
+impl<E> QueryTrait for InsertFromSelect<E>
+where
+    E: EntityTrait,
+{
+    type QueryStatement = InsertStatement;
+
+    fn query(&mut self) -> &mut InsertStatement {
+        &mut self.query
+    }
+
+    fn as_query(&self) -> &InsertStatement {
+        &self.query
+    }
+
+    fn into_query(self) -> InsertStatement {
+        self.query
+    }
+}
+
 impl<A> TryInsert<A>
 where
     A: ActiveModelTrait,
@@ -535,6 +798,9 @@ where
         let InsertMany {
             query,
             primary_key,
+            primary_key_check,
+            set_default,
+            table_ref,
             empty,
             model,
         } = insert;
@@ -543,6 +809,10 @@ where
             insert_struct: Insert {
                 query,
                 primary_key,
+                primary_key_check,
+                set_default,
+                table_ref,
+                on_conflict: None,
                 model,
             },
             empty,
@@ -567,9 +837,8 @@ where
     }
 
     /// Set ON CONFLICT logic
-    pub fn on_conflict(mut self, on_conflict: OnConflict) -> Insert<A> {
-        self.insert_struct.query.on_conflict(on_conflict);
-        self.insert_struct
+    pub fn on_conflict(self, on_conflict: OnConflict) -> Insert<A> {
+        self.insert_struct.on_conflict(on_conflict)
     }
 
     /// Set ON CONFLICT on primary key do nothing, but with MySQL specific polyfill.
@@ -592,6 +861,16 @@ where
         self.insert_struct.query.on_conflict(on_conflict);
         self
     }
+
+    /// Set ON CONFLICT on primary key to return the existing row, instead of nothing.
+    /// See [`Insert::on_conflict_do_nothing_return_existing`], including the row lock it takes.
+    pub fn on_conflict_do_nothing_return_existing(mut self) -> Self {
+        self.insert_struct
+            .query
+            .on_conflict(on_conflict_primary_key_return_existing::<A>());
+
+        self
+    }
 }
 
 impl<A> QueryTrait for TryInsert<A>
@@ -613,6 +892,46 @@ where
     }
 }
 
+/// Fill the `auto_uuid` columns left `NotSet` with a new UUID. Values already set are kept.
+#[cfg(feature = "with-uuid")]
+pub(crate) fn set_auto_uuid<A: ActiveModelTrait>(am: &mut A) {
+    for col in <A::Entity as EntityTrait>::Column::iter() {
+        if let Some(version) = col.def().get_auto_uuid()
+            && am.is_not_set(col)
+        {
+            am.set(col, version.new_uuid().into());
+        }
+    }
+}
+
+#[cfg(not(feature = "with-uuid"))]
+pub(crate) fn set_auto_uuid<A: ActiveModelTrait>(_: &mut A) {}
+
+/// A primary key the database can't fill in must be set on every inserted ActiveModel.
+/// Checked up front, but only reported when executing, because the constructors are infallible.
+/// The `DEFAULT` keyword, as the value of an [`ActiveValue::SetDefault`]
+fn default_keyword() -> SimpleExpr {
+    Expr::cust("DEFAULT")
+}
+
+fn check_primary_key_set<A: ActiveModelTrait>(am: &A, ctx: &'static str) -> Result<(), DbErr> {
+    type PrimaryKey<A> = <<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey;
+
+    if PrimaryKey::<A>::auto_increment() || PrimaryKey::<A>::db_generated() {
+        return Ok(());
+    }
+    for key in PrimaryKey::<A>::iter() {
+        let col = key.into_column();
+        if am.is_not_set(col) {
+            return Err(DbErr::PrimaryKeyColumnNotSet {
+                ctx,
+                column: col.as_str(),
+            });
+        }
+    }
+    Ok(())
+}
+
 fn on_conflict_primary_key<A: ActiveModelTrait>() -> OnConflict {
     let primary_keys = <A::Entity as EntityTrait>::PrimaryKey::iter();
     let mut on_conflict = OnConflict::columns(primary_keys.clone());
@@ -620,6 +939,14 @@ fn on_conflict_primary_key<A: ActiveModelTrait>() -> OnConflict {
     on_conflict
 }
 
+/// A no-op update of the primary key, so `RETURNING` yields the conflicting row
+fn on_conflict_primary_key_return_existing<A: ActiveModelTrait>() -> OnConflict {
+    let primary_keys = <A::Entity as EntityTrait>::PrimaryKey::iter();
+    let mut on_conflict = OnConflict::columns(primary_keys.clone());
+    on_conflict.update_columns(primary_keys);
+    on_conflict
+}
+
 #[cfg(test)]
 mod tests {
     use sea_query::OnConflict;
@@ -627,7 +954,7 @@ mod tests {
     use crate::tests_cfg::{cake, cake_filling};
     use crate::{
         ActiveValue, DbBackend, DbErr, EntityTrait, Insert, IntoActiveModel, NotSet, QueryTrait,
-        Set,
+        Set, TryInsert,
     };
 
     #[test]
@@ -725,6 +1052,93 @@ mod tests {
         );
     }
 
+    #[test]
+    fn insert_set_default() {
+        let apple = cake::ActiveModel {
+            id: ActiveValue::set_default(),
+            name: ActiveValue::set("Apple Pie".to_owned()),
+        };
+        assert_eq!(
+            Insert::<cake::ActiveModel>::one(apple)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"INSERT INTO "cake" ("id", "name") VALUES (DEFAULT, 'Apple Pie')"#,
+        );
+    }
+
+    #[test]
+    fn insert_many_set_default() {
+        let models = || {
+            [
+                cake_filling::ActiveModel {
+                    cake_id: ActiveValue::set(2),
+                    filling_id: ActiveValue::set_default(),
+                },
+                cake_filling::ActiveModel {
+                    cake_id: ActiveValue::set_default(),
+                    filling_id: ActiveValue::set(3),
+                },
+                cake_filling::ActiveModel {
+                    cake_id: ActiveValue::set(4),
+                    filling_id: ActiveValue::NotSet,
+                },
+            ]
+        };
+        assert_eq!(
+            Insert::<cake_filling::ActiveModel>::many(models())
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"INSERT INTO "cake_filling" ("cake_id", "filling_id") VALUES (2, DEFAULT), (DEFAULT, 3), (4, NULL)"#,
+        );
+        assert_eq!(
+            Insert::<cake_filling::ActiveModel>::many(models())
+                .build(DbBackend::MySql)
+                .to_string(),
+            r#"INSERT INTO `cake_filling` (`cake_id`, `filling_id`) VALUES (2, DEFAULT), (DEFAULT, 3), (4, NULL)"#,
+        );
+    }
+
+    #[test]
+    fn insert_many_set_default_only() {
+        // a column never set to a value falls back to `DEFAULT` where not set
+        let apple = cake::ActiveModel {
+            id: ActiveValue::NotSet,
+            name: ActiveValue::set("Apple Pie".to_owned()),
+        };
+        let orange = cake::ActiveModel {
+            id: ActiveValue::set_default(),
+            name: ActiveValue::set("Orange".to_owned()),
+        };
+        assert_eq!(
+            Insert::<cake::ActiveModel>::many([apple, orange])
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"INSERT INTO "cake" ("id", "name") VALUES (DEFAULT, 'Apple Pie'), (DEFAULT, 'Orange')"#,
+        );
+    }
+
+    #[test]
+    fn insert_set_default_sqlite() {
+        use crate::MockDatabase;
+
+        let db = MockDatabase::new(DbBackend::Sqlite).into_connection();
+
+        let res = cake::Entity::insert(cake::ActiveModel {
+            id: ActiveValue::set_default(),
+            name: ActiveValue::set("Apple Pie".to_owned()),
+        })
+        .exec(&db);
+
+        assert_eq!(
+            res.err(),
+            Some(DbErr::BackendNotSupported {
+                db: "Sqlite",
+                ctx: "ActiveValue::SetDefault",
+            })
+        );
+        assert!(db.into_transaction_log().is_empty());
+    }
+
     #[test]
     fn insert_6() {
         let orange = cake::ActiveModel {
@@ -788,6 +1202,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_on_conflict_do_nothing_return_existing() {
+        let orange = || cake::ActiveModel {
+            id: ActiveValue::set(2),
+            name: ActiveValue::set("Orange".to_owned()),
+        };
+
+        assert_eq!(
+            cake::Entity::insert_many([orange()])
+                .on_conflict_do_nothing_return_existing()
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"INSERT INTO "cake" ("id", "name") VALUES (2, 'Orange') ON CONFLICT ("id") DO UPDATE SET "id" = "excluded"."id""#,
+        );
+        assert_eq!(
+            TryInsert::<cake::ActiveModel>::one(orange())
+                .on_conflict_do_nothing_return_existing()
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            r#"INSERT INTO "cake" ("id", "name") VALUES (2, 'Orange') ON CONFLICT ("id") DO UPDATE SET "id" = "excluded"."id""#,
+        );
+        assert_eq!(
+            cake::Entity::insert(orange())
+                .on_conflict_do_nothing_return_existing()
+                .build(DbBackend::MySql)
+                .to_string(),
+            r#"INSERT INTO `cake` (`id`, `name`) VALUES (2, 'Orange') ON DUPLICATE KEY UPDATE `id` = VALUES(`id`)"#,
+        );
+    }
+
+    #[test]
+    fn insert_from_select() {
+        use crate::tests_cfg::fruit;
+        use crate::{ColumnTrait, QueryFilter, QuerySelect};
+
+        let query = || {
+            fruit::Entity::insert_from_select(
+                [fruit::Column::Name, fruit::Column::CakeId],
+                cake::Entity::find()
+                    .select_only()
+                    .column(cake::Column::Name)
+                    .column(cake::Column::Id)
+                    .filter(cake::Column::Name.contains("chocolate")),
+            )
+        };
+
+        assert_eq!(
+            query().build(DbBackend::Postgres).to_string(),
+            r#"INSERT INTO "fruit" ("name", "cake_id") SELECT "cake"."name", "cake"."id" FROM "cake" WHERE "cake"."name" LIKE '%chocolate%'"#,
+        );
+        assert_eq!(
+            query().build(DbBackend::MySql).to_string(),
+            r#"INSERT INTO `fruit` (`name`, `cake_id`) SELECT `cake`.`name`, `cake`.`id` FROM `cake` WHERE `cake`.`name` LIKE '%chocolate%'"#,
+        );
+
+        let on_conflict = OnConflict::column(fruit::Column::Name)
+            .update_column(fruit::Column::CakeId)
+            .to_owned();
+        assert_eq!(
+            query()
+                .on_conflict(on_conflict.clone())
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            r#"INSERT INTO "fruit" ("name", "cake_id") SELECT "cake"."name", "cake"."id" FROM "cake" WHERE "cake"."name" LIKE '%chocolate%' ON CONFLICT ("name") DO UPDATE SET "cake_id" = "excluded"."cake_id""#,
+        );
+        assert_eq!(
+            query()
+                .on_conflict(on_conflict)
+                .build(DbBackend::MySql)
+                .to_string(),
+            r#"INSERT INTO `fruit` (`name`, `cake_id`) SELECT `cake`.`name`, `cake`.`id` FROM `cake` WHERE `cake`.`name` LIKE '%chocolate%' ON DUPLICATE KEY UPDATE `cake_id` = VALUES(`cake_id`)"#,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "don't match the selected expressions")]
+    fn insert_from_select_column_mismatch() {
+        use crate::QuerySelect;
+        use crate::tests_cfg::fruit;
+
+        fruit::Entity::insert_from_select(
+            [fruit::Column::Name, fruit::Column::CakeId],
+            cake::Entity::find()
+                .select_only()
+                .column(cake::Column::Name),
+        );
+    }
+
     #[test]
     fn insert_8() -> Result<(), DbErr> {
         use crate::{DbBackend, MockDatabase, Statement, Transaction};
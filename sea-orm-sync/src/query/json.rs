@@ -1,4 +1,6 @@
-use crate::{FromQueryResult, QueryResult, error::*};
+use crate::{
+    ColumnTrait, EntityTrait, FromQueryResult, IdenStatic, Iterable, QueryResult, error::*,
+};
 use serde_json::Map;
 pub use serde_json::Value as JsonValue;
 
@@ -277,6 +279,34 @@ impl FromQueryResult for JsonValue {
     }
 }
 
+/// Replace the values of the `encrypt_with` columns of `E` in `json`, read from
+/// `res`, with their decrypted values
+pub(crate) fn decrypt_json<E>(
+    mut json: JsonValue,
+    res: &QueryResult,
+    pre: &str,
+) -> Result<JsonValue, DbErr>
+where
+    E: EntityTrait,
+{
+    let JsonValue::Object(map) = &mut json else {
+        return Ok(json);
+    };
+    for col in E::Column::iter() {
+        // the column may not be selected
+        let Some(json_value) = map.get_mut(col.as_str()) else {
+            continue;
+        };
+        if let Some(value) = col.try_get_decrypted(res, pre) {
+            *json_value = match value? {
+                Some(value) => sea_query::sea_value_to_json_value(&value),
+                None => JsonValue::Null,
+            };
+        }
+    }
+    Ok(json)
+}
+
 #[cfg(test)]
 #[cfg(feature = "mock")]
 mod tests {
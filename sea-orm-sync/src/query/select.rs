@@ -1,10 +1,13 @@
 use crate::{
-    ColumnTrait, EntityTrait, Iterable, Order, PrimaryKeyToColumn, QueryFilter, QueryOrder,
-    QuerySelect, QueryTrait,
+    ColumnTrait, EntityTrait, IdenStatic, Iterable, Order, PrimaryKeyToColumn, PrimaryKeyTrait,
+    QueryFilter, QueryOrder, QuerySelect, QueryTrait, entity::column::select_as_named,
+    query::table_as_entity,
 };
 use core::fmt::Debug;
 use core::marker::PhantomData;
-use sea_query::{FunctionCall, IntoColumnRef, SelectStatement, SimpleExpr};
+use sea_query::{
+    FunctionCall, IntoColumnRef, IntoTableRef, SelectStatement, SimpleExpr, TableRef, ValueTuple,
+};
 
 /// A `SELECT` query against entity `E`. Returned by
 /// [`EntityTrait::find`](crate::EntityTrait::find); chain filters, joins,
@@ -19,6 +22,9 @@ where
     pub(crate) query: SelectStatement,
     pub(crate) entity: PhantomData<E>,
     pub(crate) linked_index: usize,
+    pub(crate) hooks: bool,
+    /// Primary key looked up by [`EntityTrait::find_by_id`], for error messages
+    pub(crate) primary_key: Option<ValueTuple>,
 }
 
 /// A `SELECT` joining two entities, yielding `(E::Model, Option<F::Model>)`
@@ -277,6 +283,8 @@ where
             query: SelectStatement::new(),
             entity: PhantomData,
             linked_index: 0,
+            hooks: true,
+            primary_key: None,
         }
         .prepare_select()
         .prepare_from()
@@ -289,7 +297,7 @@ where
 
     fn column_list(&self) -> Vec<SimpleExpr> {
         E::Column::iter()
-            .map(|col| col.select_as(col.into_expr()))
+            .map(|col| select_as_named(&col, col.into_expr()))
             .collect()
     }
 
@@ -298,6 +306,105 @@ where
         self
     }
 
+    /// Select from `table` instead of the entity's own table, e.g. a shard of it chosen
+    /// at runtime. The table is aliased as the entity's table name, so the columns and
+    /// filters of the entity keep working.
+    ///
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, sea_query::Alias, tests_cfg::cake};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .into_table(Alias::new("cake_2024"))
+    ///         .filter(cake::Column::Id.eq(1))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake_2024" AS "cake" WHERE "cake"."id" = 1"#
+    /// );
+    /// ```
+    pub fn into_table<T>(mut self, table: T) -> Self
+    where
+        T: IntoTableRef,
+    {
+        self.query
+            .from_clear()
+            .from(table_as_entity::<E>(table.into_table_ref()));
+        self
+    }
+
+    /// Select from the table of `entity`, for entities with a table name set at runtime.
+    /// See [`into_table`](Self::into_table).
+    pub fn for_entity_instance(self, entity: E) -> Self {
+        self.into_table(entity.table_ref())
+    }
+
+    /// Find a model by primary key in `table`, or the entity's own table if `None`.
+    /// Reads back the row written by an insert or update into another table.
+    pub(crate) fn find_by_id_in<T>(values: T, table: Option<TableRef>) -> Self
+    where
+        T: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+    {
+        let select = E::find_by_id(values);
+        match table {
+            Some(table) => select.into_table(table),
+            None => select,
+        }
+    }
+
+    /// Skip the [`before_find`](crate::ActiveModelBehavior::before_find) and
+    /// [`after_find`](crate::ActiveModelBehavior::after_find) hooks of the entity
+    /// when this query is executed.
+    pub fn without_hooks(mut self) -> Self {
+        self.hooks = false;
+        self
+    }
+
+    /// Select all columns of the entity except the given ones, replacing the current
+    /// selection. Columns are selected the same way as [`EntityTrait::find`] does, so
+    /// the result is stable when the entity gains a field.
+    ///
+    /// ```
+    /// use sea_orm::{
+    ///     DbBackend,
+    ///     entity::*,
+    ///     query::*,
+    ///     tests_cfg::{fruit, lunch_set},
+    /// };
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .select_except([fruit::Column::Name])
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "fruit"."id", "fruit"."cake_id" FROM "fruit""#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     lunch_set::Entity::find()
+    ///         .select_except([lunch_set::Column::Id])
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "lunch_set"."name", CAST("lunch_set"."tea" AS "text") FROM "lunch_set""#
+    /// );
+    /// ```
+    ///
+    /// The result can no longer be decoded into `E::Model` if a non-nullable column is
+    /// excluded, use `into_json` or [`into_model`](Self::into_model) with a
+    /// [`FromQueryResult`](crate::FromQueryResult) struct instead.
+    pub fn select_except<I>(mut self, cols: I) -> Self
+    where
+        I: IntoIterator<Item = E::Column>,
+    {
+        let except: Vec<_> = cols.into_iter().map(|col| col.as_str()).collect();
+        self.query.clear_selects();
+        self.query.exprs(
+            E::Column::iter()
+                .filter(|col| !except.contains(&col.as_str()))
+                .map(|col| select_as_named(&col, col.into_expr())),
+        );
+        self
+    }
+
     /// Apply order by primary key to the query statement
     pub fn order_by_id_asc(self) -> Self {
         self.order_by_id(Order::Asc)
@@ -2,8 +2,18 @@ use crate::{
     ActiveModelTrait, ColumnTrait, Condition, DbBackend, DbErr, EntityTrait, ExprTrait, IdenStatic,
     Identity, ModelTrait, Value,
 };
-use sea_query::{ColumnRef, DynIden, Expr, IntoColumnRef, TableRef, ValueTuple};
-use std::str::FromStr;
+use sea_query::{
+    BinOper, ColumnRef, ConditionHolder, DynIden, EscapeBuilder, ExplainStatement, Expr,
+    IntoColumnRef, JoinExpr, LockClause, Oper, OperLeftAssocDecider, OrderExpr, PrecedenceDecider,
+    QueryBuilder, Quote, QuotedBuilder, SeaRc, SelectDistinct, SelectExpr, SelectInto,
+    SelectStatement, SqlWriter, SubQueryStatement, TableRef, TableRefBuilder, UnionType,
+    ValueTuple, WindowStatement, WithClause,
+};
+use std::{
+    cell::{Cell, RefCell},
+    str::FromStr,
+    time::Duration,
+};
 
 #[derive(Default)]
 pub struct ValueTupleBuilder(Option<ValueTuple>);
@@ -144,6 +154,170 @@ where
     Ok(true)
 }
 
+/// Alias `table` as the entity's own table name, so the columns of the entity, which are
+/// qualified by that name in selects and filters, refer to `table` instead.
+/// A table that already has an alias is left as is.
+pub(crate) fn table_as_entity<E: EntityTrait>(table: TableRef) -> TableRef {
+    match table {
+        TableRef::Table(table, None) => TableRef::Table(table, Some(SeaRc::new(E::default()))),
+        table => table,
+    }
+}
+
+/// `DISTINCT ON` is only understood by Postgres, the other backends silently drop it
+/// when rendering, which changes the semantics of the query. Reject it before execution.
+pub(crate) fn check_distinct_on(query: &SelectStatement, backend: DbBackend) -> Result<(), DbErr> {
+    if matches!(backend, DbBackend::Postgres) {
+        return Ok(());
+    }
+    let clauses = SelectClauses::default();
+    query.build_collect_any_into(&clauses, &mut String::new());
+    if !clauses.distinct_on.get() {
+        return Ok(());
+    }
+    Err(DbErr::BackendNotSupported {
+        db: backend.as_str(),
+        ctx: "SELECT DISTINCT ON",
+    })
+}
+
+/// MySQL only cancels a query with a timeout through a `MAX_EXECUTION_TIME` optimizer
+/// hint after its `SELECT` keyword. With a `WITH` clause, that keyword only comes after the
+/// `SELECT`s of the common table expressions, and the hint can't be added, so reject the query
+/// rather than running it without a limit.
+pub(crate) fn check_timeout(
+    query: &SelectStatement,
+    timeout: Option<Duration>,
+    backend: DbBackend,
+) -> Result<(), DbErr> {
+    if timeout.is_none() || !matches!(backend, DbBackend::MySql) {
+        return Ok(());
+    }
+    let clauses = SelectClauses::default();
+    query.build_collect_any_into(&clauses, &mut String::new());
+    if !clauses.with.get() {
+        return Ok(());
+    }
+    Err(DbErr::BackendNotSupported {
+        db: backend.as_str(),
+        ctx: "timeout of a query with a WITH clause",
+    })
+}
+
+/// With a `GROUP BY` clause, every plain column selected has to be grouped by, otherwise
+/// Postgres and MySQL in `ONLY_FULL_GROUP_BY` mode reject the query. Aggregates, other
+/// expressions and queries without `GROUP BY` are not checked, nor are columns that are
+/// functionally dependent on the grouped ones.
+pub(crate) fn check_group_by(query: &mut SelectStatement) -> Result<(), DbErr> {
+    let clauses = SelectClauses::default();
+    query.build_collect_any_into(&clauses, &mut String::new());
+    let groups = clauses.group_by.into_inner();
+    if groups.is_empty() {
+        return Ok(());
+    }
+
+    let mut missing = Vec::new();
+    query.exprs_mut_for_each(|sel| {
+        let col = match &sel.expr {
+            Expr::Column(ColumnRef::Column(col)) => col,
+            Expr::AsEnum(_, expr) => match expr.as_ref() {
+                Expr::Column(ColumnRef::Column(col)) => col,
+                _ => return,
+            },
+            _ => return,
+        };
+        if sel.window.is_some() {
+            return;
+        }
+        let grouped = groups
+            .iter()
+            .any(|group| matches!(group, Expr::Column(ColumnRef::Column(c)) if c == col));
+        if !grouped {
+            missing.push(match &col.0 {
+                Some(table) => format!("{}.{}", table.1, col.1),
+                None => col.1.to_string(),
+            });
+        }
+    });
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(DbErr::GroupByMissing(missing))
+    }
+}
+
+/// `SelectStatement` does not expose its distinct and group by clauses, so walk the
+/// statement with a builder that writes nothing and only records those. Every other
+/// clause holding expressions is skipped, which leaves the group by list to `prepare_expr`.
+#[derive(Default)]
+struct SelectClauses {
+    distinct_on: Cell<bool>,
+    with: Cell<bool>,
+    group_by: RefCell<Vec<Expr>>,
+}
+
+impl QueryBuilder for SelectClauses {
+    fn prepare_select_distinct(&self, distinct: &SelectDistinct, _: &mut impl SqlWriter) {
+        self.distinct_on
+            .set(matches!(distinct, SelectDistinct::DistinctOn(cols) if !cols.is_empty()));
+    }
+
+    fn prepare_select_expr(&self, _: &SelectExpr, _: &mut impl SqlWriter) {}
+
+    fn prepare_table_ref(&self, _: &TableRef, _: &mut impl SqlWriter) {}
+
+    fn prepare_join_expr(&self, _: &JoinExpr, _: &mut impl SqlWriter) {}
+
+    fn prepare_condition(&self, _: &ConditionHolder, _: &str, _: &mut impl SqlWriter) {}
+
+    fn prepare_union_statement(&self, _: UnionType, _: &SelectStatement, _: &mut impl SqlWriter) {}
+
+    fn prepare_order_expr(&self, _: &OrderExpr, _: &mut impl SqlWriter) {}
+
+    fn prepare_select_lock(&self, _: &LockClause, _: &mut impl SqlWriter) {}
+
+    fn prepare_window_statement(&self, _: &WindowStatement, _: &mut impl SqlWriter) {}
+
+    fn prepare_with_clause(&self, _: &WithClause, _: &mut impl SqlWriter) {
+        self.with.set(true);
+    }
+
+    fn prepare_expr(&self, expr: &Expr, _: &mut impl SqlWriter) {
+        self.group_by.borrow_mut().push(expr.clone());
+    }
+
+    fn prepare_query_statement(&self, _: &SubQueryStatement, _: &mut impl SqlWriter) {}
+
+    fn prepare_select_into(&self, _: &SelectInto, _: &mut impl SqlWriter) {}
+
+    fn prepare_explain_statement(&self, _: &ExplainStatement, _: &mut impl SqlWriter) {}
+
+    fn prepare_value(&self, _: Value, _: &mut impl SqlWriter) {}
+}
+
+impl QuotedBuilder for SelectClauses {
+    fn quote(&self) -> Quote {
+        Quote::new(b'"')
+    }
+}
+
+impl EscapeBuilder for SelectClauses {}
+
+impl TableRefBuilder for SelectClauses {}
+
+impl OperLeftAssocDecider for SelectClauses {
+    fn well_known_left_associative(&self, _: &BinOper) -> bool {
+        false
+    }
+}
+
+impl PrecedenceDecider for SelectClauses {
+    fn inner_expr_well_known_greater_precedence(&self, _: &Expr, _: &Oper) -> bool {
+        false
+    }
+}
+
 /// Constructs a `WHERE (c1, c2, ...) IN ((v11, v12, ...), (v21, v22, ...), ...)` expression.
 /// Degenerates to `WHERE col IN (v1, v2, ...)` when arity = 1.
 pub fn column_tuple_in_condition(
@@ -244,3 +418,46 @@ fn create_table_columns(table: &TableRef, cols: &Identity) -> Vec<Expr> {
         .map(Expr::col)
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::check_group_by;
+    use crate::{DbErr, entity::*, query::*, tests_cfg::*};
+
+    fn missing(query: &mut sea_query::SelectStatement) -> Vec<String> {
+        match check_group_by(query) {
+            Ok(()) => Vec::new(),
+            Err(DbErr::GroupByMissing(columns)) => columns,
+            Err(err) => panic!("unexpected {err:?}"),
+        }
+    }
+
+    #[test]
+    fn test_check_group_by() {
+        // no GROUP BY
+        assert!(missing(QuerySelect::query(&mut cake::Entity::find())).is_empty());
+
+        let mut query = cake::Entity::find()
+            .select_only()
+            .column(cake::Column::Id)
+            .column(cake::Column::Name)
+            .column_as(fruit::Column::Id.count(), "fruits")
+            .left_join(fruit::Entity)
+            .column(fruit::Column::CakeId)
+            .group_by(cake::Column::Name)
+            .group_by(cake::Column::Id);
+        assert_eq!(missing(QuerySelect::query(&mut query)), ["fruit.cake_id"]);
+
+        let mut query = query.group_by(fruit::Column::CakeId);
+        assert!(missing(QuerySelect::query(&mut query)).is_empty());
+
+        // an enum column is selected with a cast on Postgres, the filter is not a group
+        let mut query = lunch_set::Entity::find()
+            .select_only()
+            .column(lunch_set::Column::Tea)
+            .column(lunch_set::Column::Name)
+            .filter(lunch_set::Column::Tea.is_not_null())
+            .group_by(lunch_set::Column::Name);
+        assert_eq!(missing(QuerySelect::query(&mut query)), ["lunch_set.tea"]);
+    }
+}
@@ -1,5 +1,6 @@
 use crate as sea_orm;
 use crate::entity::prelude::*;
+use sea_query::{Expr, ExprTrait};
 
 #[cfg(feature = "with-json")]
 use serde::{Deserialize, Serialize};
@@ -29,6 +30,11 @@ pub enum Relation {
         on_condition = r#"super::fruit::Column::Name.like("%tropical%")"#
     )]
     OrTropicalFruit,
+    #[sea_orm(
+        has_many = "super::fruit::Entity",
+        on_condition = r#"|_left, right| Expr::col((right, super::fruit::Column::Name)).like("%tropical%")"#
+    )]
+    AliasedTropicalFruit,
 }
 
 impl Related<super::fruit::Entity> for Entity {
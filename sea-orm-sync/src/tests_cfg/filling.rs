@@ -30,6 +30,12 @@ pub enum Relation {
     Ingredient,
 }
 
+impl Related<super::vendor::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Vendor.def()
+    }
+}
+
 impl Related<super::ingredient::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Ingredient.def()
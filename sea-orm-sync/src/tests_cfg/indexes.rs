@@ -3,7 +3,11 @@ use crate as sea_orm;
 use crate::entity::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
-#[sea_orm(schema_name = "public", table_name = "indexes")]
+#[sea_orm(
+    schema_name = "public",
+    table_name = "indexes",
+    index(name = "idx_index1_index2", columns("index1_attr", "index2_attr"))
+)]
 pub struct Model {
     #[sea_orm(primary_key)]
     pub indexes_id: i32,
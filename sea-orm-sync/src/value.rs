@@ -1,5 +1,6 @@
-use crate::sea_query::{Nullable, ValueType};
-use crate::{ActiveValue, Value};
+use crate::sea_query::{Expr, Nullable, ValueType};
+use crate::{ActiveValue, ColumnTrait, Value};
+use std::marker::PhantomData;
 
 macro_rules! impl_serde_with_str {
     ($ty:ty) => {
@@ -61,6 +62,9 @@ pub(super) use {impl_serde_with_i64, impl_serde_with_str};
 mod timestamp;
 use timestamp::*;
 
+mod fixed_bytes;
+pub use fixed_bytes::FixedBytes;
+
 #[cfg(feature = "with-chrono")]
 mod with_chrono;
 #[cfg(feature = "with-chrono")]
@@ -71,11 +75,23 @@ mod with_time;
 #[cfg(feature = "with-time")]
 pub use with_time::*;
 
+#[cfg(feature = "with-rust_decimal")]
+mod with_rust_decimal;
+#[cfg(feature = "with-rust_decimal")]
+pub use with_rust_decimal::TryIntoDecimal;
+
 #[cfg(feature = "with-uuid")]
 mod text_uuid;
 #[cfg(feature = "with-uuid")]
 pub use text_uuid::*;
 
+#[cfg(feature = "with-ipnetwork")]
+mod ip_address;
+#[cfg(feature = "with-ipnetwork")]
+pub use ip_address::IpAddress;
+#[cfg(feature = "with-ipnetwork")]
+pub(crate) use ip_address::ip_addr_from_network;
+
 /// Default value for `T`.
 pub trait DefaultActiveValue {
     /// `Default::default()` if implemented, dummy value otherwise.
@@ -129,6 +145,73 @@ where
     }
 }
 
+/// SQL expressions wrapping a column of a value type when it's read, and a value of it
+/// when it's written, e.g. to keep a `NUMERIC(12, 2)` column as integer cents in Rust.
+///
+/// Implemented by `DeriveValueType` with `#[sea_orm(select_expr = "..", save_expr = "..")]`,
+/// where `$1` stands for the column or value; applied by
+/// [`ColumnTrait::select_as`](crate::ColumnTrait::select_as) and
+/// [`ColumnTrait::save_as`](crate::ColumnTrait::save_as) of columns of this type.
+pub trait ValueTypeExprs {
+    /// Wrap a selected column of this type
+    fn select_expr(expr: Expr) -> Expr {
+        expr
+    }
+
+    /// Wrap a value of this type written to, or compared with, a column
+    fn save_expr(val: Expr) -> Expr {
+        val
+    }
+}
+
+/// The [ValueTypeExprs] of `T`, if it implements them
+#[doc(hidden)]
+pub struct ValueTypeExprsOf<T>(PhantomData<T>);
+
+impl<T> std::fmt::Debug for ValueTypeExprsOf<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValueTypeExprsOf").finish()
+    }
+}
+
+impl<T> Default for ValueTypeExprsOf<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Wrap a column of a type implementing [ValueTypeExprs]
+#[doc(hidden)]
+pub trait SelectSaveExprs {
+    fn select_as<C: ColumnTrait>(&self, col: &C, expr: Expr) -> Expr;
+
+    fn save_as<C: ColumnTrait>(&self, col: &C, val: Expr) -> Expr;
+}
+
+impl<T: ValueTypeExprs> SelectSaveExprs for ValueTypeExprsOf<T> {
+    fn select_as<C: ColumnTrait>(&self, _: &C, expr: Expr) -> Expr {
+        T::select_expr(expr)
+    }
+
+    fn save_as<C: ColumnTrait>(&self, _: &C, val: Expr) -> Expr {
+        T::save_expr(val)
+    }
+}
+
+/// Wrap a column of any other type, casting enums
+#[doc(hidden)]
+pub trait SelectSaveEnumExprs {
+    fn select_as<C: ColumnTrait>(&self, col: &C, expr: Expr) -> Expr {
+        col.select_enum_as(expr)
+    }
+
+    fn save_as<C: ColumnTrait>(&self, col: &C, val: Expr) -> Expr {
+        col.save_enum_as(val)
+    }
+}
+
+impl<T> SelectSaveEnumExprs for &ValueTypeExprsOf<T> {}
+
 #[cfg(test)]
 mod test {
     use super::*;
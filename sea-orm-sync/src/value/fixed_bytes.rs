@@ -0,0 +1,172 @@
+use std::{
+    array::TryFromSliceError,
+    ops::{Deref, DerefMut},
+};
+
+use sea_query::{ValueType, ValueTypeErr};
+
+use crate::TryGetable;
+use crate::{self as sea_orm, TryFromU64};
+use crate::{DbErr, TryGetError};
+
+/// Newtype storing a fixed-size byte array in a `binary(N)` column.
+///
+/// `[u8; N]` can't be a Model field as it can't be converted into a
+/// [`Value`](sea_query::Value). Values are bound as bytes, the same as
+/// `Vec<u8>`; reading a value of a different length fails instead of
+/// truncating or padding it.
+///
+/// ```
+/// use sea_orm::entity::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "device")]
+/// pub struct Model {
+///     #[sea_orm(primary_key, auto_increment = false)]
+///     pub mac: FixedBytes<6>,
+///     pub name: String,
+/// }
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// # impl ActiveModelBehavior for ActiveModel {}
+///
+/// let _ = Entity::find_by_id([0x00, 0x1b, 0x44, 0x11, 0x3a, 0xb7]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Copy, Hash, PartialOrd, Ord)]
+pub struct FixedBytes<const N: usize>(pub [u8; N]);
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for FixedBytes<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        // same representation as `Vec<u8>`
+        serde::Serialize::serialize(self.0.as_slice(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for FixedBytes<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let bytes = <Vec<u8> as serde::Deserialize>::deserialize(deserializer)?;
+        <[u8; N]>::try_from(bytes.as_slice())
+            .map(FixedBytes)
+            .map_err(|_| {
+                serde::de::Error::invalid_length(bytes.len(), &format!("{N} bytes").as_str())
+            })
+    }
+}
+
+impl<const N: usize> From<FixedBytes<N>> for sea_query::Value {
+    fn from(value: FixedBytes<N>) -> Self {
+        value.0.to_vec().into()
+    }
+}
+
+impl<const N: usize> TryGetable for FixedBytes<N> {
+    fn try_get_by<I: sea_orm::ColIdx>(
+        res: &sea_orm::QueryResult,
+        index: I,
+    ) -> Result<Self, sea_orm::TryGetError> {
+        let bytes: Vec<u8> = res.try_get_by(index)?;
+        <[u8; N]>::try_from(bytes.as_slice())
+            .map(FixedBytes)
+            .map_err(|_| {
+                TryGetError::DbErr(DbErr::Type(format!(
+                    "Expected {N} bytes, got {}",
+                    bytes.len()
+                )))
+            })
+    }
+}
+
+impl<const N: usize> ValueType for FixedBytes<N> {
+    fn try_from(v: sea_orm::Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            sea_orm::Value::Bytes(Some(bytes)) => <[u8; N]>::try_from(bytes.as_slice())
+                .map(FixedBytes)
+                .map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        format!("FixedBytes<{N}>")
+    }
+
+    fn array_type() -> sea_query::ArrayType {
+        <Vec<u8> as sea_query::ValueType>::array_type()
+    }
+
+    fn column_type() -> sea_orm::ColumnType {
+        sea_orm::ColumnType::Binary(N as u32)
+    }
+}
+
+impl<const N: usize> TryFromU64 for FixedBytes<N> {
+    fn try_from_u64(_n: u64) -> Result<Self, sea_orm::DbErr> {
+        Err(sea_orm::DbErr::ConvertFromU64("FixedBytes"))
+    }
+}
+
+impl<const N: usize> sea_query::Nullable for FixedBytes<N> {
+    fn null() -> sea_orm::Value {
+        <Vec<u8> as sea_query::Nullable>::null()
+    }
+}
+
+impl<const N: usize> sea_orm::IntoActiveValue<FixedBytes<N>> for FixedBytes<N> {
+    fn into_active_value(self) -> crate::ActiveValue<FixedBytes<N>> {
+        sea_orm::ActiveValue::Set(self)
+    }
+}
+
+impl<const N: usize> Deref for FixedBytes<N> {
+    type Target = [u8; N];
+
+    fn deref(&self) -> &[u8; N] {
+        &self.0
+    }
+}
+
+impl<const N: usize> DerefMut for FixedBytes<N> {
+    fn deref_mut(&mut self) -> &mut [u8; N] {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for FixedBytes<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for FixedBytes<N> {
+    fn from(value: [u8; N]) -> Self {
+        FixedBytes(value)
+    }
+}
+
+impl<const N: usize> From<&[u8; N]> for FixedBytes<N> {
+    fn from(value: &[u8; N]) -> Self {
+        FixedBytes(*value)
+    }
+}
+
+impl<const N: usize> From<FixedBytes<N>> for [u8; N] {
+    fn from(value: FixedBytes<N>) -> Self {
+        value.0
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for FixedBytes<N> {
+    type Error = TryFromSliceError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        value.try_into().map(FixedBytes)
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    fmt,
+    net::{AddrParseError, IpAddr, Ipv4Addr, Ipv6Addr},
+    ops::{Deref, DerefMut},
+    str::FromStr,
+};
+
+use ipnetwork::IpNetwork;
+use sea_query::{ValueType, ValueTypeErr};
+
+use crate::TryGetable;
+use crate::{self as sea_orm, TryFromU64};
+
+/// Newtype storing a single IP address in an `inet` column (Postgres only).
+///
+/// [`std::net::IpAddr`] can be read from query results as is, but it can't be
+/// a Model field as it can't be converted into a [`Value`](sea_query::Value).
+/// Values are bound as [`IpNetwork`] with a full prefix (`/32` or `/128`);
+/// reading an address with a shorter prefix fails instead of dropping it.
+#[derive(Clone, Debug, PartialEq, Eq, Copy, Hash, PartialOrd, Ord)]
+pub struct IpAddress(pub IpAddr);
+
+impl fmt::Display for IpAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for IpAddress {
+    type Err = AddrParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(IpAddress)
+    }
+}
+
+super::impl_serde_with_str!(IpAddress);
+
+/// The address of `net`, if it is a single host
+pub(crate) fn ip_addr_from_network(net: IpNetwork) -> Option<IpAddr> {
+    let full_prefix = match net {
+        IpNetwork::V4(_) => 32,
+        IpNetwork::V6(_) => 128,
+    };
+    (net.prefix() == full_prefix).then(|| net.ip())
+}
+
+impl From<IpAddress> for sea_query::Value {
+    fn from(value: IpAddress) -> Self {
+        IpNetwork::from(value.0).into()
+    }
+}
+
+impl TryGetable for IpAddress {
+    fn try_get_by<I: sea_orm::ColIdx>(
+        res: &sea_orm::QueryResult,
+        index: I,
+    ) -> Result<Self, sea_orm::TryGetError> {
+        IpAddr::try_get_by(res, index).map(IpAddress)
+    }
+}
+
+impl ValueType for IpAddress {
+    fn try_from(v: sea_orm::Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            sea_orm::Value::IpNetwork(Some(net)) => {
+                ip_addr_from_network(net).map(IpAddress).ok_or(ValueTypeErr)
+            }
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "IpAddress".to_string()
+    }
+
+    fn array_type() -> sea_query::ArrayType {
+        <IpNetwork as sea_query::ValueType>::array_type()
+    }
+
+    fn column_type() -> sea_orm::ColumnType {
+        <IpNetwork as sea_query::ValueType>::column_type()
+    }
+}
+
+impl TryFromU64 for IpAddress {
+    fn try_from_u64(_n: u64) -> Result<Self, sea_orm::DbErr> {
+        Err(sea_orm::DbErr::ConvertFromU64("IpAddress"))
+    }
+}
+
+impl sea_query::Nullable for IpAddress {
+    fn null() -> sea_orm::Value {
+        <IpNetwork as sea_query::Nullable>::null()
+    }
+}
+
+impl sea_orm::IntoActiveValue<IpAddress> for IpAddress {
+    fn into_active_value(self) -> crate::ActiveValue<IpAddress> {
+        sea_orm::ActiveValue::Set(self)
+    }
+}
+
+impl Deref for IpAddress {
+    type Target = IpAddr;
+
+    fn deref(&self) -> &IpAddr {
+        &self.0
+    }
+}
+
+impl DerefMut for IpAddress {
+    fn deref_mut(&mut self) -> &mut IpAddr {
+        &mut self.0
+    }
+}
+
+impl From<IpAddr> for IpAddress {
+    fn from(value: IpAddr) -> Self {
+        IpAddress(value)
+    }
+}
+
+impl From<Ipv4Addr> for IpAddress {
+    fn from(value: Ipv4Addr) -> Self {
+        IpAddress(value.into())
+    }
+}
+
+impl From<Ipv6Addr> for IpAddress {
+    fn from(value: Ipv6Addr) -> Self {
+        IpAddress(value.into())
+    }
+}
+
+impl From<IpAddress> for IpAddr {
+    fn from(value: IpAddress) -> Self {
+        value.0
+    }
+}
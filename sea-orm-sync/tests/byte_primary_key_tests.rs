@@ -11,6 +11,9 @@ fn main() -> Result<(), DbErr> {
     let ctx = TestContext::new("byte_primary_key_tests");
     create_byte_primary_key_table(&ctx.db)?;
     create_and_update(&ctx.db)?;
+    cursor_and_delete(&ctx.db)?;
+    create_byte_array_primary_key_table(&ctx.db)?;
+    byte_array_primary_key(&ctx.db)?;
     ctx.delete();
 
     Ok(())
@@ -83,3 +86,98 @@ pub fn create_and_update(db: &DatabaseConnection) -> Result<(), DbErr> {
 
     Ok(())
 }
+
+pub fn cursor_and_delete(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use common::features::byte_primary_key::*;
+
+    // bytes above 0x7f sort after the others only if compared as binary
+    for id in [[0x80, 0, 0], [0x7f, 0, 0], [0xff, 0, 0]] {
+        Entity::insert(ActiveModel {
+            id: Set(id.to_vec()),
+            value: Set(format!("{id:?}")),
+        })
+        .exec(db)?;
+    }
+
+    let ids = |models: Vec<Model>| models.into_iter().map(|m| m.id).collect::<Vec<_>>();
+
+    assert_eq!(
+        ids(Entity::find()
+            .cursor_by(Column::Id)
+            .after(vec![1_u8, 2_u8, 3_u8])
+            .first(2)
+            .all(db)?),
+        [vec![0x7f, 0, 0], vec![0x80, 0, 0]]
+    );
+
+    assert_eq!(
+        ids(Entity::find()
+            .cursor_by(Column::Id)
+            .before(vec![0xff_u8, 0_u8, 0_u8])
+            .last(2)
+            .all(db)?),
+        [vec![0x7f, 0, 0], vec![0x80, 0, 0]]
+    );
+
+    let id: &[u8] = &[0x80, 0, 0];
+    let res = Entity::delete_by_id(id).exec(db)?;
+    assert_eq!(res.rows_affected, 1);
+    assert_eq!(Entity::find_by_id(id).one(db)?, None);
+    assert!(Entity::find_by_id(&[0xff, 0, 0][..]).one(db)?.is_some());
+
+    Ok(())
+}
+
+pub fn byte_array_primary_key(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use common::features::byte_array_primary_key::*;
+
+    for id in [
+        [0x80, 0, 0, 1],
+        [0x00, 0, 0, 1],
+        [0x7f, 0xff, 0xff, 0xff],
+        [0xff, 0, 0, 0],
+    ] {
+        let model = Model {
+            id: FixedBytes(id),
+            value: format!("{id:?}"),
+        };
+        let res = Entity::insert(model.clone().into_active_model()).exec(db)?;
+        assert_eq!(res.last_insert_id, model.id);
+    }
+
+    let model = Entity::find_by_id([0x7f, 0xff, 0xff, 0xff])
+        .one(db)?
+        .unwrap();
+    assert_eq!(model.id, FixedBytes([0x7f, 0xff, 0xff, 0xff]));
+    assert_eq!(*model.id, [0x7f, 0xff, 0xff, 0xff]);
+
+    let ids = |models: Vec<Model>| models.into_iter().map(|m| m.id.0).collect::<Vec<_>>();
+
+    let mut cursor = Entity::find().cursor_by(Column::Id);
+    assert_eq!(
+        ids(cursor.first(2).all(db)?),
+        [[0x00, 0, 0, 1], [0x7f, 0xff, 0xff, 0xff]]
+    );
+    assert_eq!(
+        ids(cursor
+            .after(FixedBytes([0x7f, 0xff, 0xff, 0xff]))
+            .first(2)
+            .all(db)?),
+        [[0x80, 0, 0, 1], [0xff, 0, 0, 0]]
+    );
+    assert_eq!(
+        ids(Entity::find()
+            .cursor_by(Column::Id)
+            .before(FixedBytes([0x80, 0, 0, 1]))
+            .desc()
+            .first(1)
+            .all(db)?),
+        [[0xff, 0, 0, 0]]
+    );
+
+    let res = Entity::delete_by_id([0x80, 0, 0, 1]).exec(db)?;
+    assert_eq!(res.rows_affected, 1);
+    assert_eq!(Entity::find().count(db)?, 3);
+
+    Ok(())
+}
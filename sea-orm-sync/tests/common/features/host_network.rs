@@ -9,6 +9,9 @@ pub struct Model {
     pub ipaddress: IpNetwork,
     #[sea_orm(column_type = "Cidr")]
     pub network: IpNetwork,
+    pub address: IpAddress,
+    pub gateway: Option<IpAddress>,
+    pub mac_address: MacAddress,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -44,3 +44,22 @@ pub mod json_struct_vec {
 
     impl ActiveModelBehavior for ActiveModel {}
 }
+
+pub mod json_struct_vec_nested {
+    use super::json_struct_vec::JsonColumn;
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(table_name = "json_struct_vec_nested")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        #[sea_orm(column_type = "JsonBinary", nullable)]
+        pub opt_vec: Option<Vec<JsonColumn>>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
@@ -0,0 +1,24 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "money")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub amount: Cents,
+    pub refund: Option<Cents>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// A `NUMERIC(12, 2)` amount, converted to and from integer cents by the database
+#[derive(Copy, Clone, Debug, PartialEq, Eq, DeriveValueType)]
+#[sea_orm(
+    column_type = "Decimal(Some((12, 2)))",
+    select_expr = "($1 * 100)::bigint",
+    save_expr = "$1 / 100.0"
+)]
+pub struct Cents(pub i64);
@@ -170,6 +170,25 @@ pub fn create_byte_primary_key_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     create_table_without_asserts(db, &stmt)
 }
 
+pub fn create_byte_array_primary_key_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let stmt = sea_query::Table::create()
+        .table(byte_array_primary_key::Entity)
+        .col(
+            ColumnDef::new(byte_array_primary_key::Column::Id)
+                .binary_len(4)
+                .not_null()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(byte_array_primary_key::Column::Value)
+                .string()
+                .not_null(),
+        )
+        .to_owned();
+
+    create_table(db, &stmt, ByteArrayPrimaryKey)
+}
+
 pub fn create_active_enum_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     let create_table_stmt = sea_query::Table::create()
         .table(active_enum::Entity.table_ref())
@@ -394,6 +413,22 @@ pub fn create_json_struct_vec_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     create_table(db, &create_table_stmt, JsonStructVec)
 }
 
+pub fn create_json_struct_vec_nested_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let create_table_stmt = sea_query::Table::create()
+        .table(JsonStructVecNested.table_ref())
+        .col(
+            ColumnDef::new(json_vec_derive::json_struct_vec_nested::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(json_vec_derive::json_struct_vec_nested::Column::OptVec).json_binary())
+        .to_owned();
+
+    create_table(db, &create_table_stmt, JsonStructVecNested)
+}
+
 pub fn create_collection_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     db.execute_raw(sea_orm::Statement::from_string(
         db.get_database_backend(),
@@ -463,7 +498,7 @@ pub fn create_collection_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     create_table(db, &stmt, Collection)
 }
 
-#[cfg(feature = "with-ipnetwork")]
+#[cfg(all(feature = "with-ipnetwork", feature = "with-mac_address"))]
 pub fn create_host_network_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     let stmt = sea_query::Table::create()
         .table(host_network::Entity)
@@ -489,6 +524,17 @@ pub fn create_host_network_table(db: &DbConn) -> Result<ExecResult, DbErr> {
                 .cidr()
                 .not_null(),
         )
+        .col(
+            ColumnDef::new(host_network::Column::Address)
+                .inet()
+                .not_null(),
+        )
+        .col(ColumnDef::new(host_network::Column::Gateway).inet())
+        .col(
+            ColumnDef::new(host_network::Column::MacAddress)
+                .mac_address()
+                .not_null(),
+        )
         .to_owned();
 
     create_table(db, &stmt, host_network::Entity)
@@ -522,6 +568,27 @@ pub fn create_pi_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     create_table(db, &stmt, pi::Entity)
 }
 
+pub fn create_money_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let stmt = sea_query::Table::create()
+        .table(money::Entity)
+        .col(
+            ColumnDef::new(money::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(money::Column::Amount)
+                .decimal_len(12, 2)
+                .not_null(),
+        )
+        .col(ColumnDef::new(money::Column::Refund).decimal_len(12, 2))
+        .to_owned();
+
+    create_table(db, &stmt, money::Entity)
+}
+
 pub fn create_event_trigger_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     let stmt = sea_query::Table::create()
         .table(event_trigger::Entity)
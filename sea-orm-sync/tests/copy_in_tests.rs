@@ -0,0 +1,66 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{TestContext, bakery_chain::*, setup::*};
+use pretty_assertions::assert_eq;
+use sea_orm::{Set, TransactionTrait, entity::*, prelude::*};
+
+fn bakeries(n: usize) -> impl Iterator<Item = bakery::ActiveModel> + Send {
+    (0..n).map(|i| bakery::ActiveModel {
+        name: Set(format!("Bakery #{i}\twith a tab")),
+        profit_margin: Set(i as f64 / 4.0),
+        ..Default::default()
+    })
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+pub fn copy_in_bulk_insert() -> Result<(), DbErr> {
+    let ctx = TestContext::new("copy_in_bulk_insert_test");
+    create_bakery_table(&ctx.db)?;
+
+    assert_eq!(bakery::Entity::copy_in(&ctx.db, bakeries(0))?, 0);
+    assert_eq!(bakery::Entity::copy_in(&ctx.db, bakeries(10_000))?, 10_000);
+    assert_eq!(bakery::Entity::find().count(&ctx.db)?, 10_000);
+
+    let last = bakery::Entity::find()
+        .filter(bakery::Column::Name.eq("Bakery #9999\twith a tab"))
+        .one(&ctx.db)?
+        .expect("copied row");
+    assert_eq!(last.profit_margin, 2499.75);
+
+    ctx.delete();
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+pub fn copy_in_transaction() -> Result<(), DbErr> {
+    let ctx = TestContext::new("copy_in_transaction_test");
+    create_bakery_table(&ctx.db)?;
+
+    let txn = ctx.db.begin()?;
+    assert_eq!(bakery::Entity::copy_in(&txn, bakeries(100))?, 100);
+    assert_eq!(bakery::Entity::find().count(&txn)?, 100);
+    assert_eq!(bakery::Entity::find().count(&ctx.db)?, 0);
+    txn.rollback()?;
+    assert_eq!(bakery::Entity::find().count(&ctx.db)?, 0);
+
+    let txn = ctx.db.begin()?;
+    bakery::Entity::copy_in(&txn, bakeries(100))?;
+    txn.commit()?;
+    assert_eq!(bakery::Entity::find().count(&ctx.db)?, 100);
+
+    // a model setting a different column list fails the whole COPY
+    let mut models: Vec<_> = bakeries(3).collect();
+    models[2].id = Set(1_000_000);
+    assert!(matches!(
+        bakery::Entity::copy_in(&ctx.db, models),
+        Err(DbErr::Custom(_))
+    ));
+    assert_eq!(bakery::Entity::find().count(&ctx.db)?, 100);
+
+    ctx.delete();
+    Ok(())
+}
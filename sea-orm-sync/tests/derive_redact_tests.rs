@@ -0,0 +1,72 @@
+use sea_orm::{ActiveValue::*, IntoActiveModel};
+
+mod user {
+    use sea_orm::entity::prelude::*;
+    use serde::Serialize;
+
+    // `Debug` is implemented by `DeriveEntityModel` because of the redacted columns
+    #[derive(Clone, PartialEq, Eq, DeriveEntityModel, DeriveDisplay, Serialize)]
+    #[sea_orm(table_name = "user")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub name: String,
+        #[sea_orm(redact)]
+        pub email: String,
+        #[sea_orm(redact, nullable)]
+        pub token: Option<String>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+fn user() -> user::Model {
+    user::Model {
+        id: 1,
+        name: "Alice".to_owned(),
+        email: "alice@example.com".to_owned(),
+        token: Some("secret".to_owned()),
+    }
+}
+
+#[test]
+fn redacted_model() {
+    let model = user();
+
+    assert_eq!(
+        format!("{model:?}"),
+        r#"Model { id: 1, name: "Alice", email: <redacted>, token: <redacted> }"#
+    );
+    assert_eq!(
+        format!("{model}"),
+        r#"Model { id: 1, name: "Alice", email: <redacted>, token: <redacted> }"#
+    );
+    // the data itself is untouched
+    assert_eq!(model.email, "alice@example.com");
+}
+
+#[test]
+fn redacted_active_model() {
+    let mut active_model = user().into_active_model();
+    active_model.email = Set("bob@example.com".to_owned());
+    active_model.token = NotSet;
+
+    assert_eq!(
+        format!("{active_model:?}"),
+        r#"ActiveModel { id: Unchanged(1), name: Unchanged("Alice"), email: Set(<redacted>), token: NotSet }"#
+    );
+    assert_eq!(active_model.email, Set("bob@example.com".to_owned()));
+}
+
+#[test]
+fn redacted_serialize() -> Result<(), serde_json::Error> {
+    assert_eq!(
+        serde_json::to_string(&user())?,
+        r#"{"id":1,"name":"Alice","email":"alice@example.com","token":"secret"}"#
+    );
+
+    Ok(())
+}
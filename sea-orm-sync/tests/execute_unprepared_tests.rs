@@ -4,13 +4,14 @@ pub mod common;
 
 pub use common::{TestContext, features::*, setup::*};
 use pretty_assertions::assert_eq;
-use sea_orm::{ConnectionTrait, DatabaseConnection, entity::prelude::*};
+use sea_orm::{ConnectionTrait, DatabaseConnection, Statement, entity::prelude::*};
 
 #[sea_orm_macros::test]
 fn main() -> Result<(), DbErr> {
     let ctx = TestContext::new("execute_unprepared_tests");
     create_insert_default_table(&ctx.db)?;
     execute_unprepared(&ctx.db)?;
+    execute_batch(&ctx.db)?;
     ctx.delete();
 
     Ok(())
@@ -35,3 +36,36 @@ pub fn execute_unprepared(db: &DatabaseConnection) -> Result<(), DbErr> {
 
     Ok(())
 }
+
+pub fn execute_batch(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use insert_default::*;
+
+    let backend = db.get_database_backend();
+    // the temporary table only exists on the connection that created it
+    let results = db.execute_batch(
+        [
+            "CREATE TEMPORARY TABLE batch_tmp (id integer)",
+            "INSERT INTO batch_tmp (id) VALUES (7), (9)",
+            "INSERT INTO insert_default (id) SELECT id FROM batch_tmp",
+            "DROP TABLE batch_tmp",
+        ]
+        .into_iter()
+        .map(|sql| Statement::from_string(backend, sql))
+        .collect(),
+    )?;
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[2].rows_affected(), 2);
+    assert_eq!(
+        Entity::find().all(db)?,
+        [
+            Model { id: 1 },
+            Model { id: 3 },
+            Model { id: 5 },
+            Model { id: 7 },
+            Model { id: 9 },
+        ]
+    );
+
+    Ok(())
+}
@@ -1,12 +1,14 @@
 #![allow(unused_imports, dead_code)]
-#![cfg(feature = "with-ipnetwork")]
+#![cfg(all(feature = "with-ipnetwork", feature = "with-mac_address"))]
 
 pub mod common;
 
 use common::{TestContext, features::*, setup::*};
 use pretty_assertions::assert_eq;
-use sea_orm::{DatabaseConnection, entity::prelude::*, entity::*};
-use std::net::{Ipv4Addr, Ipv6Addr};
+use sea_orm::{
+    DatabaseConnection, DbBackend, QuerySelect, Statement, entity::prelude::*, entity::*,
+};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 #[sea_orm_macros::test]
 #[cfg(feature = "sqlx-postgres")]
@@ -14,6 +16,39 @@ fn main() -> Result<(), DbErr> {
     let ctx = TestContext::new("host_network_tests");
     create_host_network_table(&ctx.db)?;
     create_and_update_host_network(&ctx.db)?;
+    select_ip_addresses(&ctx.db)?;
+    ctx.delete();
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(feature = "sqlx-mysql", feature = "sqlx-sqlite", feature = "rusqlite"))]
+fn ip_address_unsupported() -> Result<(), DbErr> {
+    let ctx = TestContext::new("host_network_unsupported_tests");
+    let backend = ctx.db.get_database_backend();
+
+    let row = ctx
+        .db
+        .query_one_raw(Statement::from_string(
+            backend,
+            "SELECT '192.168.0.20' AS address",
+        ))?
+        .unwrap();
+
+    assert!(matches!(
+        row.try_get::<IpAddr>("", "address"),
+        Err(DbErr::Type(_))
+    ));
+    assert!(matches!(
+        row.try_get::<IpAddress>("", "address"),
+        Err(DbErr::Type(_))
+    ));
+    assert!(matches!(
+        row.try_get::<MacAddress>("", "address"),
+        Err(DbErr::Type(_))
+    ));
+
     ctx.delete();
 
     Ok(())
@@ -28,6 +63,9 @@ fn create_and_update_host_network(db: &DatabaseConnection) -> Result<(), DbErr>
         hostname: "example.com".to_owned(),
         ipaddress: addr,
         network: net,
+        address: Ipv4Addr::new(192, 168, 0, 20).into(),
+        gateway: None,
+        mac_address: MacAddress::new([0x02, 0x42, 0xac, 0x11, 0x00, 0x02]),
     };
     let res = host.clone().into_active_model().insert(db)?;
 
@@ -46,6 +84,10 @@ fn create_and_update_host_network(db: &DatabaseConnection) -> Result<(), DbErr>
         id: Set(1),
         ipaddress: Set(addrv6),
         network: Set(netv6),
+        address: Set(addrv6.ip().into()),
+        gateway: Set(Some(
+            Ipv6Addr::new(0xfd89, 0x1926, 0x4cae, 0x8abd, 0, 0, 0, 1).into(),
+        )),
         ..Default::default()
     }
     .update(db)?;
@@ -57,8 +99,55 @@ fn create_and_update_host_network(db: &DatabaseConnection) -> Result<(), DbErr>
             hostname: "example.com".to_owned(),
             ipaddress: addrv6,
             network: netv6,
+            address: addrv6.ip().into(),
+            gateway: Some(Ipv6Addr::new(0xfd89, 0x1926, 0x4cae, 0x8abd, 0, 0, 0, 1).into()),
+            mac_address: host.mac_address,
         }
     );
 
     Ok(())
 }
+
+fn select_ip_addresses(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let addrv6 = Ipv6Addr::new(0xfd89, 0x1926, 0x4cae, 0x8abd, 0, 0, 0, 0x6f52);
+
+    let (address, gateway): (IpAddr, Option<Ipv6Addr>) = host_network::Entity::find()
+        .select_only()
+        .column(host_network::Column::Address)
+        .column(host_network::Column::Gateway)
+        .into_tuple()
+        .one(db)?
+        .unwrap();
+    assert_eq!(address, IpAddr::V6(addrv6));
+    assert_eq!(
+        gateway,
+        Some(Ipv6Addr::new(0xfd89, 0x1926, 0x4cae, 0x8abd, 0, 0, 0, 1))
+    );
+
+    // the address is not an IPv4 one
+    assert!(matches!(
+        host_network::Entity::find()
+            .select_only()
+            .column(host_network::Column::Address)
+            .into_tuple::<Ipv4Addr>()
+            .one(db),
+        Err(DbErr::Type(_))
+    ));
+
+    // `ipaddress` is a /64 network, which can't be read as a single address
+    assert!(matches!(
+        host_network::Entity::find()
+            .select_only()
+            .column(host_network::Column::Ipaddress)
+            .into_tuple::<IpAddr>()
+            .one(db),
+        Err(DbErr::Type(_))
+    ));
+
+    let found = host_network::Entity::find()
+        .filter(host_network::Column::Address.eq(IpAddress::from(addrv6)))
+        .one(db)?;
+    assert!(found.is_some());
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{TestContext, features::*, setup::*};
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    DatabaseConnection, DbBackend, IntoActiveModel, QueryOrder, QuerySelect, entity::prelude::*,
+    sea_query::OnConflict,
+};
+
+#[sea_orm_macros::test]
+fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("insert_from_select_tests");
+    create_log_table(&ctx.db)?;
+    create_edit_log_table(&ctx.db)?;
+    archive_applog(&ctx.db)?;
+    ctx.delete();
+
+    Ok(())
+}
+
+pub fn archive_applog(db: &DatabaseConnection) -> Result<(), DbErr> {
+    for (id, action) in [(1, "login"), (2, "logout"), (3, "login")] {
+        applog::Model {
+            id,
+            action: action.to_owned(),
+            json: Json::from(id),
+            created_at: "2021-09-17T17:50:20+08:00".parse().unwrap(),
+        }
+        .into_active_model()
+        .insert(db)?;
+    }
+
+    let select_logins = || {
+        Applog::find()
+            .select_only()
+            .column(applog::Column::Id)
+            .column(applog::Column::Action)
+            .column(applog::Column::Json)
+            .filter(applog::Column::Action.eq("login"))
+    };
+    let columns = [
+        edit_log::Column::Id,
+        edit_log::Column::Action,
+        edit_log::Column::Values,
+    ];
+
+    let rows_affected = EditLog::insert_from_select(columns, select_logins()).exec(db)?;
+    assert_eq!(rows_affected, 2);
+
+    let edit_log_model = |id: i32, action: &str| edit_log::Model {
+        id,
+        action: action.to_owned(),
+        values: Json::from(id),
+    };
+    assert_eq!(
+        EditLog::find().order_by_asc(edit_log::Column::Id).all(db)?,
+        [edit_log_model(1, "login"), edit_log_model(3, "login")]
+    );
+
+    // Rows already archived are left untouched
+    let rows_affected = EditLog::insert_from_select(
+        columns,
+        Applog::find()
+            .select_only()
+            .column(applog::Column::Id)
+            .column(applog::Column::Action)
+            .column(applog::Column::Json)
+            .filter(applog::Column::Id.gt(0)),
+    )
+    .on_conflict(
+        OnConflict::column(edit_log::Column::Id)
+            .do_nothing_on([edit_log::Column::Id])
+            .to_owned(),
+    )
+    .exec(db)?;
+    assert_eq!(EditLog::find().count(db)?, 3);
+    if db.get_database_backend() != DbBackend::MySql {
+        assert_eq!(rows_affected, 1);
+    }
+
+    if db.support_returning() {
+        EditLog::delete_many().exec(db)?;
+
+        let inserted =
+            EditLog::insert_from_select(columns, select_logins()).exec_with_returning(db)?;
+        assert_eq!(
+            inserted,
+            [edit_log_model(1, "login"), edit_log_model(3, "login")]
+        );
+    }
+
+    Ok(())
+}
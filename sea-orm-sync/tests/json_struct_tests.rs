@@ -41,6 +41,74 @@ fn json_struct_tests() -> Result<(), DbErr> {
     Ok(())
 }
 
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+fn json_struct_operators() -> Result<(), DbErr> {
+    use json_struct::*;
+    use sea_orm::{QueryOrder, QuerySelect};
+
+    let ctx = TestContext::new("json_struct_operators");
+    let db = &ctx.db;
+    create_json_struct_table(db)?;
+    insert_json_struct_1(db)?;
+    insert_json_struct_2(db)?;
+    insert_json_struct_3(db)?;
+
+    let db_backend = db.get_database_backend();
+    let ids = |models: Vec<Model>| models.into_iter().map(|m| m.id).collect::<Vec<_>>();
+
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::Json.json_contains(json!({ "name": "apple" }), db_backend)?)
+            .all(db)?),
+        [1]
+    );
+    assert_eq!(
+        ids(Entity::find()
+            .filter(Column::JsonValue.json_path_exists("$.price ? (@ < 11)", db_backend)?)
+            .all(db)?),
+        [2]
+    );
+    assert_eq!(
+        ids(Entity::find()
+            .filter(
+                Column::JsonValueOpt
+                    .json_get_text("notes", db_backend)?
+                    .like("%organic%")
+            )
+            .all(db)?),
+        [1]
+    );
+    assert_eq!(
+        Entity::find()
+            .select_only()
+            .column_as(Column::Json.json_get_text("name", db_backend)?, "name")
+            .order_by_asc(Column::Id)
+            .into_tuple::<String>()
+            .all(db)?,
+        ["apple", "orange"]
+    );
+
+    // `jsonb` column, no cast needed
+    assert_eq!(
+        json_compact::Entity::find()
+            .filter(
+                json_compact::COLUMN
+                    .json
+                    .json_contains(json!({ "id": 22 }), db_backend)?
+            )
+            .all(db)?
+            .into_iter()
+            .map(|m| m.id)
+            .collect::<Vec<_>>(),
+        [3]
+    );
+
+    ctx.delete();
+
+    Ok(())
+}
+
 #[sea_orm_macros::test]
 #[should_panic(
     expected = "Failed to serialize 'NonSerializableStruct': Error(\"intentionally failing serialization\", line: 0, column: 0)"
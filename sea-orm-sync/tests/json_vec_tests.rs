@@ -12,9 +12,11 @@ fn main() -> Result<(), DbErr> {
     create_json_vec_table(&ctx.db)?;
     create_json_string_vec_table(&ctx.db)?;
     create_json_struct_vec_table(&ctx.db)?;
+    create_json_struct_vec_nested_table(&ctx.db)?;
     insert_json_vec(&ctx.db)?;
     insert_json_string_vec_derive(&ctx.db)?;
     insert_json_struct_vec_derive(&ctx.db)?;
+    insert_json_struct_vec_nested(&ctx.db)?;
 
     ctx.delete();
 
@@ -105,3 +107,50 @@ pub fn insert_json_struct_vec_derive(db: &DatabaseConnection) -> Result<(), DbEr
 
     Ok(())
 }
+
+pub fn insert_json_struct_vec_nested(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use json_vec_derive::{json_struct_vec::JsonColumn, json_struct_vec_nested::*};
+
+    let models = [
+        Model {
+            id: 1,
+            opt_vec: Some(vec![
+                JsonColumn {
+                    value: "7".to_string(),
+                },
+                JsonColumn {
+                    value: "8".to_string(),
+                },
+            ]),
+        },
+        Model {
+            id: 2,
+            opt_vec: Some(vec![]),
+        },
+        Model {
+            id: 3,
+            opt_vec: None,
+        },
+    ];
+
+    for model in models.iter() {
+        model.clone().into_active_model().insert(db)?;
+        assert_eq!(Entity::find_by_id(model.id).one(db)?.as_ref(), Some(model));
+    }
+
+    // a JSON `null` reads back the same as SQL `NULL`
+    Entity::update_many()
+        .col_expr(Column::OptVec, Expr::val(serde_json::Value::Null))
+        .filter(Column::Id.eq(3))
+        .exec(db)?;
+
+    assert_eq!(
+        Entity::find_by_id(3).one(db)?,
+        Some(Model {
+            id: 3,
+            opt_vec: None,
+        })
+    );
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+use common::features::{money::Cents, *};
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    DatabaseConnection, DbBackend, entity::prelude::*, entity::*, query::*, sea_query::ExprTrait,
+};
+
+#[test]
+fn money_statements() {
+    assert_eq!(
+        Money::find()
+            .filter(money::Column::Amount.gt(Cents(1999)))
+            .build(DbBackend::Postgres)
+            .to_string(),
+        [
+            r#"SELECT "money"."id","#,
+            r#"("money"."amount" * 100)::bigint AS "amount","#,
+            r#"("money"."refund" * 100)::bigint AS "refund""#,
+            r#"FROM "money" WHERE "money"."amount" > (1999 / 100.0)"#,
+        ]
+        .join(" ")
+    );
+
+    assert_eq!(
+        Money::insert(money::ActiveModel {
+            id: Set(1),
+            amount: Set(Cents(1999)),
+            refund: Set(None),
+        })
+        .build(DbBackend::Postgres)
+        .to_string(),
+        r#"INSERT INTO "money" ("id", "amount", "refund") VALUES (1, 1999 / 100.0, NULL / 100.0)"#
+    );
+
+    assert_eq!(
+        Money::update_many()
+            .set(money::ActiveModel {
+                refund: Set(Some(Cents(500))),
+                ..Default::default()
+            })
+            .filter(money::Column::Id.eq(1))
+            .build(DbBackend::Postgres)
+            .to_string(),
+        r#"UPDATE "money" SET "refund" = 500 / 100.0 WHERE "money"."id" = 1"#
+    );
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+fn main() -> Result<(), DbErr> {
+    let ctx = common::TestContext::new("money_tests");
+    create_money_table(&ctx.db)?;
+    create_and_update_money(&ctx.db)?;
+    ctx.delete();
+
+    Ok(())
+}
+
+pub fn create_and_update_money(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let model = money::ActiveModel {
+        id: Set(1),
+        amount: Set(Cents(1999)),
+        refund: Set(None),
+    }
+    .insert(db)?;
+
+    assert_eq!(
+        model,
+        money::Model {
+            id: 1,
+            amount: Cents(1999),
+            refund: None,
+        }
+    );
+
+    // stored as a decimal amount
+    let amount: Option<String> = Money::find_by_id(1)
+        .select_only()
+        .column_as(Expr::col(money::Column::Amount).cast_as("text"), "amount")
+        .into_tuple()
+        .one(db)?;
+    assert_eq!(amount.as_deref(), Some("19.99"));
+
+    let model = money::ActiveModel {
+        refund: Set(Some(Cents(500))),
+        ..model.into_active_model()
+    }
+    .update(db)?;
+
+    assert_eq!(model.refund, Some(Cents(500)));
+    assert_eq!(Money::find_by_id(1).one(db)?, Some(model));
+    assert_eq!(
+        Money::find()
+            .filter(money::Column::Amount.gt(Cents(1999)))
+            .count(db)?,
+        0
+    );
+    assert_eq!(
+        Money::find()
+            .filter(money::Column::Amount.eq(Cents(1999)))
+            .count(db)?,
+        1
+    );
+
+    Ok(())
+}
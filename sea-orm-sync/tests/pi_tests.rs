@@ -13,6 +13,8 @@ fn main() -> Result<(), DbErr> {
     let ctx = TestContext::new("pi_tests");
     create_pi_table(&ctx.db)?;
     create_and_update_pi(&ctx.db)?;
+    sum_pi(&ctx.db)?;
+    decimal_from_f64(&ctx.db)?;
     ctx.delete();
 
     Ok(())
@@ -70,3 +72,101 @@ pub fn create_and_update_pi(db: &DatabaseConnection) -> Result<(), DbErr> {
 
     Ok(())
 }
+
+#[cfg(feature = "with-bigdecimal")]
+pub fn sum_pi(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use pi::Entity as Pi;
+    use sea_orm::{DbBackend, QuerySelect};
+
+    // make the decimals sum up to exactly 4
+    let pi = Pi::find_by_id(1).one(db)?.unwrap();
+    pi::ActiveModel {
+        id: Set(2),
+        decimal: Set(rust_dec(4) - pi.decimal),
+        big_decimal: Set(BigDecimal::from(4) - pi.big_decimal),
+        decimal_opt: Set(None),
+        big_decimal_opt: Set(None),
+    }
+    .insert(db)?;
+
+    // SUM over an integer column
+    let total: Option<i64> = Pi::find()
+        .select_only()
+        .column_as(pi::Column::Id.sum(), "total")
+        .into_tuple()
+        .one(db)?;
+    assert_eq!(total, Some(3));
+
+    // SUM over a decimal column
+    let total: Option<Decimal> = Pi::find()
+        .select_only()
+        .column_as(pi::Column::Decimal.sum(), "total")
+        .into_tuple()
+        .one(db)?;
+    assert_eq!(total.map(|d| d.round_dp(6)), Some(rust_dec(4)));
+
+    if db.get_database_backend() != DbBackend::Sqlite {
+        // An integral DECIMAL / NUMERIC can be decoded as an integer
+        let total: Option<i64> = Pi::find()
+            .select_only()
+            .column_as(pi::Column::Decimal.sum(), "total")
+            .into_tuple()
+            .one(db)?;
+        assert_eq!(total, Some(4));
+
+        // but one with a fractional part can't
+        let res: Result<Option<i64>, DbErr> = Pi::find()
+            .select_only()
+            .column_as(pi::Column::Decimal.sum(), "total")
+            .filter(pi::Column::Id.eq(1))
+            .into_tuple()
+            .one(db);
+        assert!(res.is_err());
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "with-bigdecimal")]
+pub fn decimal_from_f64(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use pi::Entity as Pi;
+    use sea_orm::{ConnectionTrait, QuerySelect, QueryTrait};
+
+    assert!(matches!(
+        ActiveValue::<Decimal>::set_decimal_from_f64_rounded(f64::NAN, 4),
+        Err(DbErr::Type(_))
+    ));
+
+    let model = pi::ActiveModel {
+        id: Set(3),
+        decimal: ActiveValue::set_decimal_from_f64_rounded(1.23456, 4)?,
+        big_decimal: Set(BigDecimal::from_str("1.2346").unwrap()),
+        decimal_opt: Set(Some(9.87654f64.try_into_decimal_rounded(2)?)),
+        big_decimal_opt: Set(None),
+    }
+    .insert(db)?;
+    assert_eq!(model.decimal.normalize(), rust_dec("1.2346"));
+    assert_eq!(
+        model.decimal_opt.map(|d| d.normalize()),
+        Some(rust_dec("9.88"))
+    );
+
+    let model = Pi::find_by_id(3).one(db)?.unwrap();
+    assert_eq!(model.decimal.normalize(), rust_dec("1.2346"));
+    assert_eq!(
+        model.decimal_opt.map(|d| d.normalize()),
+        Some(rust_dec("9.88"))
+    );
+
+    let row = db
+        .query_one(
+            &Pi::find_by_id(3)
+                .select_only()
+                .column(pi::Column::Decimal)
+                .into_query(),
+        )?
+        .unwrap();
+    assert_eq!(row.try_get_f64_lossy("", "decimal")?, 1.2346);
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{TestContext, bakery_chain::*, setup::*};
+use pretty_assertions::assert_eq;
+pub use sea_orm::entity::*;
+pub use sea_orm::{ConnectionTrait, DbErr, QueryFilter, TransactionTrait};
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+
+#[sea_orm_macros::test]
+pub fn query_count() -> Result<(), DbErr> {
+    let mut ctx = TestContext::new("query_count_tests");
+    create_bakery_table(&ctx.db)?;
+
+    let metric_calls = Arc::new(AtomicU64::new(0));
+    ctx.db.enable_query_counting();
+    ctx.db.set_metric_callback({
+        let metric_calls = metric_calls.clone();
+        move |_| {
+            metric_calls.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+    let db = &ctx.db;
+    let start = db.query_count();
+
+    let (res, count) = db.count_queries(|| {
+        Bakery::insert(bakery::ActiveModel {
+            name: Set("SeaSide Bakery".to_owned()),
+            profit_margin: Set(10.4),
+            ..Default::default()
+        })
+        .exec(db)
+    });
+    let bakery_id = res?.last_insert_id;
+    assert_eq!(count, 1);
+
+    let (bakery, count) = db.count_queries(|| Bakery::find_by_id(bakery_id).one(db));
+    assert_eq!(bakery?.map(|b| b.name), Some("SeaSide Bakery".to_owned()));
+    assert_eq!(count, 1);
+
+    let (res, count) = db.count_queries(|| {
+        db.transaction::<_, _, DbErr>(|txn| {
+            let (bakeries, count) = txn.count_queries(|| Bakery::find().all(txn));
+            assert_eq!(bakeries?.len(), 1);
+            assert_eq!(count, 1);
+
+            Bakery::update_many()
+                .col_expr(bakery::Column::ProfitMargin, 12.5.into())
+                .filter(bakery::Column::Id.eq(bakery_id))
+                .exec(txn)?;
+            Ok(txn.query_count())
+        })
+    });
+    assert_eq!(res.map_err(|e| DbErr::Custom(e.to_string()))?, start + 4);
+    assert_eq!(count, 2);
+
+    #[cfg(feature = "stream")]
+    {
+        let mut stream = Bakery::find().stream(db)?;
+        let mut rows = 0;
+        while let Some(bakery) = stream.next() {
+            bakery?;
+            rows += 1;
+        }
+        drop(stream);
+        assert_eq!(rows, 1);
+        assert_eq!(db.query_count(), start + 5);
+    }
+
+    // the user callback is called for every counted statement
+    assert_eq!(
+        metric_calls.load(Ordering::Relaxed),
+        db.query_count() - start
+    );
+
+    ctx.delete();
+
+    Ok(())
+}
@@ -3,7 +3,10 @@
 pub mod common;
 pub use common::{TestContext, features::*, setup::*};
 use pretty_assertions::assert_eq;
-use sea_orm::{DatabaseConnection, IntoActiveModel, entity::prelude::*};
+use sea_orm::{
+    DatabaseConnection, DatePart, DbBackend, ExprTrait, IntoActiveModel, QuerySelect, QueryTrait,
+    entity::prelude::*,
+};
 use serde_json::json;
 use time::macros::{date, time};
 
@@ -12,6 +15,7 @@ fn main() {
     let ctx = TestContext::new("time_crate_tests");
     create_transaction_log_table(&ctx.db).unwrap();
     create_transaction_log(&ctx.db).unwrap();
+    transaction_log_date_time_fns(&ctx.db).unwrap();
 
     ctx.delete();
 }
@@ -89,3 +93,97 @@ pub fn create_transaction_log(db: &DatabaseConnection) -> Result<(), DbErr> {
 
     Ok(())
 }
+
+pub fn transaction_log_date_time_fns(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let db_backend = db.get_database_backend();
+    let ids: Vec<i32> = TransactionLog::find()
+        .select_only()
+        .column(transaction_log::Column::Id)
+        .filter(
+            transaction_log::Column::DateTime
+                .extract(DatePart::Month, db_backend)
+                .eq(3),
+        )
+        .filter(
+            transaction_log::Column::DateTimeTz
+                .extract(DatePart::Minute, db_backend)
+                .eq(24),
+        )
+        .into_tuple()
+        .all(db)?;
+    assert_eq!(ids, [1]);
+
+    let ids: Vec<i32> = TransactionLog::find()
+        .select_only()
+        .column(transaction_log::Column::Id)
+        .filter(
+            transaction_log::Column::DateTimeTz
+                .to_unix_epoch(db_backend)
+                .eq(1647188640),
+        )
+        .into_tuple()
+        .all(db)?;
+    assert_eq!(ids, [1]);
+
+    Ok(())
+}
+
+#[test]
+fn transaction_log_date_time_fns_sql() {
+    let query = |db_backend: DbBackend| {
+        TransactionLog::find()
+            .select_only()
+            .column_as(
+                transaction_log::Column::DateTime.date_trunc(DatePart::Month, db_backend),
+                "month",
+            )
+            .column_as(
+                transaction_log::Column::DateTimeTz.extract(DatePart::Day, db_backend),
+                "day",
+            )
+            .filter(
+                transaction_log::Column::DateTimeTz
+                    .to_unix_epoch(db_backend)
+                    .gt(0),
+            )
+            .group_by(transaction_log::Column::DateTime.date_trunc(DatePart::Month, db_backend))
+    };
+
+    assert_eq!(
+        query(DbBackend::Postgres)
+            .build(DbBackend::Postgres)
+            .to_string(),
+        [
+            r#"SELECT date_trunc('month', "transaction_log"."date_time") AS "month","#,
+            r#"EXTRACT(DAY FROM "transaction_log"."date_time_tz") AS "day""#,
+            r#"FROM "transaction_log""#,
+            r#"WHERE EXTRACT(EPOCH FROM "transaction_log"."date_time_tz") > 0"#,
+            r#"GROUP BY date_trunc('month', "transaction_log"."date_time")"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        query(DbBackend::MySql).build(DbBackend::MySql).to_string(),
+        [
+            r#"SELECT CAST(DATE_FORMAT(`transaction_log`.`date_time`, '%Y-%m-01 00:00:00') AS DATETIME) AS `month`,"#,
+            r#"EXTRACT(DAY FROM `transaction_log`.`date_time_tz`) AS `day`"#,
+            r#"FROM `transaction_log`"#,
+            r#"WHERE UNIX_TIMESTAMP(`transaction_log`.`date_time_tz`) > 0"#,
+            r#"GROUP BY CAST(DATE_FORMAT(`transaction_log`.`date_time`, '%Y-%m-01 00:00:00') AS DATETIME)"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        query(DbBackend::Sqlite)
+            .build(DbBackend::Sqlite)
+            .to_string(),
+        [
+            r#"SELECT strftime('%Y-%m-01 00:00:00', "transaction_log"."date_time") AS "month","#,
+            r#"CAST(strftime('%d', "transaction_log"."date_time_tz") AS INTEGER) AS "day""#,
+            r#"FROM "transaction_log""#,
+            r#"WHERE CAST(strftime('%s', "transaction_log"."date_time_tz") AS INTEGER) > 0"#,
+            r#"GROUP BY strftime('%Y-%m-01 00:00:00', "transaction_log"."date_time")"#,
+        ]
+        .join(" ")
+    );
+}
@@ -3,7 +3,10 @@
 pub mod common;
 pub use common::{TestContext, features::*, setup::*};
 use pretty_assertions::assert_eq;
-use sea_orm::{DatabaseConnection, IntoActiveModel, NotSet, Set, entity::prelude::*};
+use sea_orm::{
+    DatabaseConnection, DatePart, DbBackend, ExprTrait, IntoActiveModel, NotSet, QuerySelect,
+    QueryTrait, Set, entity::prelude::*,
+};
 
 #[sea_orm_macros::test]
 fn bakery_chain_schema_timestamp_tests() -> Result<(), DbErr> {
@@ -12,6 +15,7 @@ fn bakery_chain_schema_timestamp_tests() -> Result<(), DbErr> {
     create_satellites_table(&ctx.db)?;
     create_applog(&ctx.db)?;
     create_satellites_log(&ctx.db)?;
+    satellites_date_time_fns(&ctx.db)?;
 
     ctx.delete();
 
@@ -276,3 +280,119 @@ pub fn create_satellites_log(db: &DatabaseConnection) -> Result<(), DbErr> {
 
     Ok(())
 }
+
+pub fn satellites_date_time_fns(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let db_backend = db.get_database_backend();
+    let counts: Vec<i64> = Satellite::find()
+        .select_only()
+        .column_as(satellite::Column::Id.count(), "count")
+        .filter(
+            satellite::Column::LaunchDate
+                .extract(DatePart::Year, db_backend)
+                .eq(2022),
+        )
+        .filter(
+            satellite::Column::LaunchDate
+                .to_unix_epoch(db_backend)
+                .eq(1641557483),
+        )
+        .group_by(satellite::Column::LaunchDate.date_trunc(DatePart::Day, db_backend))
+        .into_tuple()
+        .all(db)?;
+    assert_eq!(counts, [1]);
+
+    let counts: Vec<i64> = Satellite::find()
+        .select_only()
+        .column_as(satellite::Column::Id.count(), "count")
+        .filter(
+            satellite::Column::LaunchDate
+                .extract(DatePart::Month, db_backend)
+                .eq(2),
+        )
+        .into_tuple()
+        .all(db)?;
+    assert_eq!(counts, [0]);
+
+    Ok(())
+}
+
+#[test]
+fn satellite_date_time_fns_sql() {
+    let query = |db_backend: DbBackend| {
+        Satellite::find()
+            .select_only()
+            .column_as(
+                satellite::Column::LaunchDate.date_trunc(DatePart::Day, db_backend),
+                "day",
+            )
+            .column_as(satellite::Column::Id.count(), "count")
+            .filter(
+                satellite::Column::LaunchDate
+                    .extract(DatePart::Year, db_backend)
+                    .eq(2022),
+            )
+            .group_by(satellite::Column::LaunchDate.date_trunc(DatePart::Day, db_backend))
+    };
+
+    assert_eq!(
+        query(DbBackend::Postgres).build(DbBackend::Postgres).to_string(),
+        [
+            r#"SELECT date_trunc('day', "satellite"."launch_date") AS "day", COUNT("satellite"."id") AS "count""#,
+            r#"FROM "satellite""#,
+            r#"WHERE EXTRACT(YEAR FROM "satellite"."launch_date") = 2022"#,
+            r#"GROUP BY date_trunc('day', "satellite"."launch_date")"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        query(DbBackend::MySql).build(DbBackend::MySql).to_string(),
+        [
+            r#"SELECT CAST(DATE_FORMAT(`satellite`.`launch_date`, '%Y-%m-%d 00:00:00') AS DATETIME) AS `day`, COUNT(`satellite`.`id`) AS `count`"#,
+            r#"FROM `satellite`"#,
+            r#"WHERE EXTRACT(YEAR FROM `satellite`.`launch_date`) = 2022"#,
+            r#"GROUP BY CAST(DATE_FORMAT(`satellite`.`launch_date`, '%Y-%m-%d 00:00:00') AS DATETIME)"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        query(DbBackend::Sqlite).build(DbBackend::Sqlite).to_string(),
+        [
+            r#"SELECT strftime('%Y-%m-%d 00:00:00', "satellite"."launch_date") AS "day", COUNT("satellite"."id") AS "count""#,
+            r#"FROM "satellite""#,
+            r#"WHERE CAST(strftime('%Y', "satellite"."launch_date") AS INTEGER) = 2022"#,
+            r#"GROUP BY strftime('%Y-%m-%d 00:00:00', "satellite"."launch_date")"#,
+        ]
+        .join(" ")
+    );
+
+    let query = |db_backend: DbBackend| {
+        Satellite::find()
+            .select_only()
+            .column_as(
+                satellite::Column::DeploymentDate.to_unix_epoch(db_backend),
+                "epoch",
+            )
+            .filter(
+                satellite::Column::DeploymentDate
+                    .extract(DatePart::Hour, db_backend)
+                    .lt(12),
+            )
+    };
+
+    assert_eq!(
+        query(DbBackend::Postgres)
+            .build(DbBackend::Postgres)
+            .to_string(),
+        r#"SELECT EXTRACT(EPOCH FROM "satellite"."deployment_date") AS "epoch" FROM "satellite" WHERE EXTRACT(HOUR FROM "satellite"."deployment_date") < 12"#
+    );
+    assert_eq!(
+        query(DbBackend::MySql).build(DbBackend::MySql).to_string(),
+        "SELECT UNIX_TIMESTAMP(`satellite`.`deployment_date`) AS `epoch` FROM `satellite` WHERE EXTRACT(HOUR FROM `satellite`.`deployment_date`) < 12"
+    );
+    assert_eq!(
+        query(DbBackend::Sqlite)
+            .build(DbBackend::Sqlite)
+            .to_string(),
+        r#"SELECT CAST(strftime('%s', "satellite"."deployment_date") AS INTEGER) AS "epoch" FROM "satellite" WHERE CAST(strftime('%H', "satellite"."deployment_date") AS INTEGER) < 12"#
+    );
+}
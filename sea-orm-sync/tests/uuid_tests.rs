@@ -13,6 +13,7 @@ fn main() -> Result<(), DbErr> {
     create_metadata_table(&ctx.db)?;
     create_and_update_metadata(&ctx.db)?;
     insert_metadata(&ctx.db)?;
+    save_metadata_by_lookup(&ctx.db)?;
     ctx.delete();
 
     Ok(())
@@ -106,3 +107,47 @@ pub fn create_and_update_metadata(db: &DatabaseConnection) -> Result<(), DbErr>
 
     Ok(())
 }
+
+pub fn save_metadata_by_lookup(db: &DatabaseConnection) -> Result<(), DbErr> {
+    fn save(db: &DatabaseConnection, metadata: &metadata::Model) -> Result<(), DbErr> {
+        metadata
+            .clone()
+            .into_active_model()
+            .reset_all()
+            .save_by_lookup(db)?;
+        Ok(())
+    }
+
+    let mut metadata = metadata::Model {
+        uuid: Uuid::new_v4(),
+        ty: "Type".to_owned(),
+        key: "lookup".to_owned(),
+        value: "1.18".to_owned(),
+        bytes: vec![4, 5, 6],
+        date: None,
+        time: None,
+    };
+
+    // a new row is inserted, where `save` would update and fail
+    assert_eq!(
+        metadata.clone().into_active_model().reset_all().save(db),
+        Err(DbErr::RecordNotUpdated)
+    );
+    save(db, &metadata)?;
+    assert_eq!(
+        Metadata::find_by_id(metadata.uuid).one(db)?,
+        Some(metadata.clone())
+    );
+
+    // the existing row is updated through the same code path
+    metadata.value = "0.22".to_owned();
+    save(db, &metadata)?;
+    assert_eq!(
+        Metadata::find()
+            .filter(metadata::Column::Key.eq("lookup"))
+            .all(db)?,
+        [metadata]
+    );
+
+    Ok(())
+}
@@ -25,10 +25,24 @@ pub trait ConnectionTrait: Sync {
     /// Execute a [`StatementBuilder`]
     async fn execute<S: StatementBuilder>(&self, stmt: &S) -> Result<ExecResult, DbErr> {
         let db_backend = self.get_database_backend();
-        let stmt = db_backend.build(stmt);
+        let stmt = stmt.try_build(&db_backend)?;
         self.execute_raw(stmt).await
     }
 
+    /// Execute a batch of [Statement]s in order, stopping at the first error.
+    ///
+    /// The whole batch runs on one connection: a pooled
+    /// [`DatabaseConnection`](crate::DatabaseConnection) acquires a single
+    /// connection up front, so session state like temporary tables is visible
+    /// to every statement in the batch.
+    async fn execute_batch(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        let mut results = Vec::with_capacity(stmts.len());
+        for stmt in stmts {
+            results.push(self.execute_raw(stmt).await?);
+        }
+        Ok(results)
+    }
+
     /// Execute a unprepared [Statement]
     async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr>;
 
@@ -38,7 +52,7 @@ pub trait ConnectionTrait: Sync {
     /// Execute a [`StatementBuilder`] and return a single row of `QueryResult`
     async fn query_one<S: StatementBuilder>(&self, stmt: &S) -> Result<Option<QueryResult>, DbErr> {
         let db_backend = self.get_database_backend();
-        let stmt = db_backend.build(stmt);
+        let stmt = stmt.try_build(&db_backend)?;
         self.query_one_raw(stmt).await
     }
 
@@ -48,7 +62,7 @@ pub trait ConnectionTrait: Sync {
     /// Execute a [`StatementBuilder`] and return a vector of `QueryResult`
     async fn query_all<S: StatementBuilder>(&self, stmt: &S) -> Result<Vec<QueryResult>, DbErr> {
         let db_backend = self.get_database_backend();
-        let stmt = db_backend.build(stmt);
+        let stmt = stmt.try_build(&db_backend)?;
         self.query_all_raw(stmt).await
     }
 
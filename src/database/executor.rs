@@ -56,6 +56,14 @@ impl ConnectionTrait for DatabaseExecutor<'_> {
         }
     }
 
+    async fn execute_batch(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        match self {
+            DatabaseExecutor::Connection(conn) => conn.execute_batch(stmts).await,
+            DatabaseExecutor::Transaction(trans) => trans.execute_batch(stmts).await,
+            DatabaseExecutor::OwnedTransaction(trans) => trans.execute_batch(stmts).await,
+        }
+    }
+
     async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
         match self {
             DatabaseExecutor::Connection(conn) => conn.execute_unprepared(sql).await,
@@ -79,6 +87,14 @@ impl ConnectionTrait for DatabaseExecutor<'_> {
             DatabaseExecutor::OwnedTransaction(trans) => trans.query_all_raw(stmt).await,
         }
     }
+
+    fn support_returning(&self) -> bool {
+        match self {
+            DatabaseExecutor::Connection(conn) => conn.support_returning(),
+            DatabaseExecutor::Transaction(trans) => trans.support_returning(),
+            DatabaseExecutor::OwnedTransaction(trans) => trans.support_returning(),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -250,6 +266,20 @@ impl DatabaseExecutor<'_> {
         )
     }
 
+    /// Run `f` with the raw sqlx connection behind this executor; see
+    /// [`DatabaseConnection::with_raw_sqlx`] and [`DatabaseTransaction::with_raw_sqlx`].
+    #[cfg(feature = "sqlx-dep")]
+    pub async fn with_raw_sqlx<F, R>(&self, f: F) -> Result<R, DbErr>
+    where
+        F: AsyncFnOnce(crate::SqlxConnection<'_>) -> R,
+    {
+        match self {
+            DatabaseExecutor::Connection(conn) => conn.with_raw_sqlx(f).await,
+            DatabaseExecutor::Transaction(trans) => trans.with_raw_sqlx(f).await,
+            DatabaseExecutor::OwnedTransaction(trans) => trans.with_raw_sqlx(f).await,
+        }
+    }
+
     /// Creates a [`SchemaBuilder`] for this backend
     pub fn get_schema_builder(&self) -> SchemaBuilder {
         Schema::new(self.get_database_backend()).builder()
@@ -1,7 +1,8 @@
 use crate::{
     DatabaseConnection, DatabaseConnectionType, DbBackend, EntityTrait, ExecResult,
     ExecResultHolder, Iden, IdenStatic, Iterable, MockDatabaseConnection, MockDatabaseTrait,
-    ModelTrait, QueryResult, QueryResultRow, SelectA, SelectB, Statement, error::*,
+    ModelTrait, PrimaryKeyToColumn, QueryResult, QueryResultRow, SelectA, SelectB, Statement,
+    error::*,
 };
 use sea_query::{Value, ValueType, Values};
 use std::{collections::BTreeMap, sync::Arc};
@@ -15,6 +16,13 @@ use tracing::instrument;
 /// you can pass to your code, then inspect what was executed via
 /// [`into_transaction_log`](crate::MockDatabaseConnection::into_transaction_log)
 /// on the connection.
+///
+/// Alternatively, declare the statements you expect up front with
+/// [`expect_query`](Self::expect_query) / [`expect_exec`](Self::expect_exec).
+/// Expected statements may arrive in any order; missing, surplus or unexpected
+/// statements fail the test when [`verify`](crate::DatabaseConnection::verify)
+/// is called or the connection is dropped. Statements not matching any
+/// expectation are served from the `append_*` buffers as usual.
 #[derive(Debug)]
 pub struct MockDatabase {
     db_backend: DbBackend,
@@ -22,6 +30,52 @@ pub struct MockDatabase {
     transaction_log: Vec<Transaction>,
     exec_results: Vec<Result<MockExecResult, DbErr>>,
     query_results: Vec<Result<Vec<MockRow>, DbErr>>,
+    expectations: Vec<MockExpectation>,
+    unexpected: Vec<(MockStatementKind, Statement)>,
+    /// statements answered without consuming the `append_*` buffers
+    unbuffered_execs: usize,
+    unbuffered_queries: usize,
+    verified: bool,
+}
+
+/// SQL an expectation is matched against, either the exact SQL (with
+/// placeholders, or with values inlined) or a regular expression.
+#[derive(Debug, Clone)]
+pub enum MockSql {
+    /// Match the statement exactly
+    Exact(String),
+    /// Match the statement against a regular expression
+    Regex(regex::Regex),
+}
+
+/// A query registered with [`MockDatabase::expect_query`].
+#[derive(Debug)]
+pub struct MockQueryExpectation {
+    sql: MockSql,
+    result: Result<Vec<MockRow>, DbErr>,
+    times: usize,
+    calls: usize,
+}
+
+/// A statement registered with [`MockDatabase::expect_exec`].
+#[derive(Debug)]
+pub struct MockExecExpectation {
+    sql: MockSql,
+    result: Result<MockExecResult, DbErr>,
+    times: usize,
+    calls: usize,
+}
+
+#[derive(Debug)]
+enum MockExpectation {
+    Query(MockQueryExpectation),
+    Exec(MockExecExpectation),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MockStatementKind {
+    Query,
+    Exec,
 }
 
 /// Canned [`ExecResult`](crate::ExecResult)-equivalent returned by a
@@ -76,6 +130,11 @@ impl MockDatabase {
             transaction_log: Vec::new(),
             exec_results: Vec::new(),
             query_results: Vec::new(),
+            expectations: Vec::new(),
+            unexpected: Vec::new(),
+            unbuffered_execs: 0,
+            unbuffered_queries: 0,
+            verified: false,
         }
     }
 
@@ -125,16 +184,395 @@ impl MockDatabase {
         self.query_results.extend(vec.into_iter().map(Result::Err));
         self
     }
-}
 
-impl MockDatabaseTrait for MockDatabase {
-    #[instrument(level = "trace", skip(statement))]
-    fn execute(&mut self, counter: usize, statement: Statement) -> Result<ExecResult, DbErr> {
+    /// Queue the results `ActiveModel::insert` consumes when it inserts `model`
+    ///
+    /// Backends supporting `RETURNING` answer the insert with a single query result,
+    /// the others execute the insert and then select the row back by primary key.
+    /// This appends whichever sequence the backend of this mock expects, so the same
+    /// fixture serves a test run against Postgres and MySQL alike.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// let apple = fruit::Model {
+    ///     id: 1,
+    ///     name: "Apple".to_owned(),
+    ///     cake_id: None,
+    /// };
+    ///
+    /// for backend in [DbBackend::Postgres, DbBackend::MySql] {
+    ///     let db = MockDatabase::new(backend)
+    ///         .expect_insert_returning(apple.clone())
+    ///         .into_connection();
+    ///
+    ///     let inserted = fruit::ActiveModel {
+    ///         name: Set("Apple".to_owned()),
+    ///         ..Default::default()
+    ///     }
+    ///     .insert(&db)
+    ///     .await?;
+    ///
+    ///     assert_eq!(inserted, apple);
+    /// }
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn expect_insert_returning<M>(self, model: M) -> Self
+    where
+        M: ModelTrait,
+    {
+        if self.db_backend.support_returning() {
+            self.append_query_results([[model]])
+        } else {
+            let last_insert_id = last_insert_id_of(&model);
+            self.append_exec_results([MockExecResult {
+                last_insert_id,
+                rows_affected: 1,
+            }])
+            .append_query_results([[model]])
+        }
+    }
+
+    /// Queue the results `ActiveModel::update` consumes when it updates a row into `model`
+    ///
+    /// Like [`expect_insert_returning`](Self::expect_insert_returning), this is a single
+    /// query result on backends supporting `RETURNING`, and an exec result followed by
+    /// the select of the updated row on the others. An update with no changed columns
+    /// only selects the row, queue it with [`append_query_results`](Self::append_query_results).
+    pub fn expect_update_returning<M>(self, model: M) -> Self
+    where
+        M: ModelTrait,
+    {
+        if self.db_backend.support_returning() {
+            self.append_query_results([[model]])
+        } else {
+            self.append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .append_query_results([[model]])
+        }
+    }
+
+    /// Queue the result `ActiveModel::delete` or `Entity::delete_many` consumes,
+    /// reporting `rows_affected` deleted rows
+    ///
+    /// Deletes without `RETURNING` are executed the same way on every backend, this
+    /// exists so insert, update and delete fixtures read alike.
+    pub fn expect_delete(self, rows_affected: u64) -> Self {
+        self.append_exec_results([MockExecResult {
+            last_insert_id: 0,
+            rows_affected,
+        }])
+    }
+
+    /// Expect a query matching `sql`, by default exactly once and returning no rows
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// let mut db = MockDatabase::new(DbBackend::Postgres);
+    /// db.expect_query(r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = 1 LIMIT 1"#)
+    ///     .returning([cake::Model {
+    ///         id: 1,
+    ///         name: "Cheese Cake".to_owned(),
+    ///     }])
+    ///     .times(2);
+    /// db.expect_query(MockSql::regex(r#"FROM "fruit""#));
+    /// let db = db.into_connection();
+    ///
+    /// fruit::Entity::find().all(&db).await?;
+    /// cake::Entity::find_by_id(1).one(&db).await?;
+    /// cake::Entity::find_by_id(1).one(&db).await?;
+    ///
+    /// db.verify();
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn expect_query<S>(&mut self, sql: S) -> &mut MockQueryExpectation
+    where
+        S: Into<MockSql>,
+    {
+        self.expectations
+            .push(MockExpectation::Query(MockQueryExpectation {
+                sql: sql.into(),
+                result: Ok(Vec::new()),
+                times: 1,
+                calls: 0,
+            }));
+        match self.expectations.last_mut() {
+            Some(MockExpectation::Query(expectation)) => expectation,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Expect a non-`SELECT` statement matching `sql`, by default exactly once
+    /// and returning a default [`MockExecResult`]
+    pub fn expect_exec<S>(&mut self, sql: S) -> &mut MockExecExpectation
+    where
+        S: Into<MockSql>,
+    {
+        self.expectations
+            .push(MockExpectation::Exec(MockExecExpectation {
+                sql: sql.into(),
+                result: Ok(MockExecResult::default()),
+                times: 1,
+                calls: 0,
+            }));
+        match self.expectations.last_mut() {
+            Some(MockExpectation::Exec(expectation)) => expectation,
+            _ => unreachable!(),
+        }
+    }
+
+    fn log(&mut self, statement: Statement) {
         if let Some(transaction) = &mut self.transaction {
             transaction.push(statement);
         } else {
             self.transaction_log.push(Transaction::one(statement));
         }
+    }
+
+    fn match_expectation(
+        &mut self,
+        kind: MockStatementKind,
+        statement: &Statement,
+    ) -> Option<&mut MockExpectation> {
+        let rendered = statement.to_string();
+        let mut matching = self.expectations.iter_mut().filter(|expectation| {
+            expectation.kind() == kind && expectation.sql().matches(&statement.sql, &rendered)
+        });
+        let first = matching.next()?;
+        if !first.is_exhausted() {
+            return Some(first);
+        }
+        // prefer one still awaiting calls, otherwise surplus calls count against the last one
+        let mut last = first;
+        for expectation in matching {
+            if !expectation.is_exhausted() {
+                return Some(expectation);
+            }
+            last = expectation;
+        }
+        Some(last)
+    }
+
+    fn report(&self) -> Option<String> {
+        let mut lines = Vec::new();
+        for expectation in self.expectations.iter() {
+            let (kind, sql, times, calls) = match expectation {
+                MockExpectation::Query(e) => ("query", &e.sql, e.times, e.calls),
+                MockExpectation::Exec(e) => ("exec", &e.sql, e.times, e.calls),
+            };
+            if calls < times {
+                lines.push(format!(
+                    "- {kind} {sql} (expected {times}, matched {calls})"
+                ));
+            } else if calls > times {
+                lines.push(format!(
+                    "+ {kind} {sql} (expected {times}, matched {calls})"
+                ));
+            }
+        }
+        for (kind, statement) in self.unexpected.iter() {
+            let kind = match kind {
+                MockStatementKind::Query => "query",
+                MockStatementKind::Exec => "exec",
+            };
+            lines.push(format!("+ {kind} `{statement}`"));
+        }
+        if lines.is_empty() {
+            None
+        } else {
+            Some(format!(
+                "MockDatabase expectations not met (- missing, + unexpected):\n{}",
+                lines.join("\n")
+            ))
+        }
+    }
+}
+
+/// The `last_insert_id` a backend without `RETURNING` reports for inserting `model`,
+/// `0` unless the primary key is a single integer column
+fn last_insert_id_of<M>(model: &M) -> u64
+where
+    M: ModelTrait,
+{
+    let mut keys = <<M::Entity as EntityTrait>::PrimaryKey as Iterable>::iter();
+    let (Some(key), None) = (keys.next(), keys.next()) else {
+        return 0;
+    };
+    let id = match model.get(key.into_column()) {
+        Value::TinyInt(Some(v)) => <u64 as TryFrom<_>>::try_from(v).ok(),
+        Value::SmallInt(Some(v)) => <u64 as TryFrom<_>>::try_from(v).ok(),
+        Value::Int(Some(v)) => <u64 as TryFrom<_>>::try_from(v).ok(),
+        Value::BigInt(Some(v)) => <u64 as TryFrom<_>>::try_from(v).ok(),
+        Value::TinyUnsigned(Some(v)) => Some(v.into()),
+        Value::SmallUnsigned(Some(v)) => Some(v.into()),
+        Value::Unsigned(Some(v)) => Some(v.into()),
+        Value::BigUnsigned(Some(v)) => Some(v),
+        _ => None,
+    };
+    id.unwrap_or_default()
+}
+
+impl Drop for MockDatabase {
+    fn drop(&mut self) {
+        if !self.verified
+            && !std::thread::panicking()
+            && let Some(report) = self.report()
+        {
+            panic!("{report}");
+        }
+    }
+}
+
+impl MockSql {
+    /// Match statements against a regular expression
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is not a valid regular expression.
+    pub fn regex(pattern: &str) -> Self {
+        Self::Regex(regex::Regex::new(pattern).expect("Invalid regular expression"))
+    }
+
+    fn matches(&self, sql: &str, rendered: &str) -> bool {
+        match self {
+            Self::Exact(expected) => expected == sql || expected == rendered,
+            Self::Regex(regex) => regex.is_match(sql) || regex.is_match(rendered),
+        }
+    }
+}
+
+impl std::fmt::Display for MockSql {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exact(sql) => write!(f, "`{sql}`"),
+            Self::Regex(regex) => write!(f, "/{regex}/"),
+        }
+    }
+}
+
+impl From<&str> for MockSql {
+    fn from(sql: &str) -> Self {
+        Self::Exact(sql.to_owned())
+    }
+}
+
+impl From<String> for MockSql {
+    fn from(sql: String) -> Self {
+        Self::Exact(sql)
+    }
+}
+
+impl From<regex::Regex> for MockSql {
+    fn from(regex: regex::Regex) -> Self {
+        Self::Regex(regex)
+    }
+}
+
+impl MockQueryExpectation {
+    /// Rows returned each time the query is matched
+    pub fn returning<T, I>(&mut self, rows: I) -> &mut Self
+    where
+        T: IntoMockRow,
+        I: IntoIterator<Item = T>,
+    {
+        self.result = Ok(rows.into_iter().map(IntoMockRow::into_mock_row).collect());
+        self
+    }
+
+    /// Error returned each time the query is matched
+    pub fn returning_error(&mut self, err: DbErr) -> &mut Self {
+        self.result = Err(err);
+        self
+    }
+
+    /// Number of times the query is expected
+    pub fn times(&mut self, times: usize) -> &mut Self {
+        self.times = times;
+        self
+    }
+}
+
+impl MockExecExpectation {
+    /// Result returned each time the statement is matched
+    pub fn returning(&mut self, result: MockExecResult) -> &mut Self {
+        self.result = Ok(result);
+        self
+    }
+
+    /// Error returned each time the statement is matched
+    pub fn returning_error(&mut self, err: DbErr) -> &mut Self {
+        self.result = Err(err);
+        self
+    }
+
+    /// Number of times the statement is expected
+    pub fn times(&mut self, times: usize) -> &mut Self {
+        self.times = times;
+        self
+    }
+}
+
+impl MockExpectation {
+    fn kind(&self) -> MockStatementKind {
+        match self {
+            Self::Query(_) => MockStatementKind::Query,
+            Self::Exec(_) => MockStatementKind::Exec,
+        }
+    }
+
+    fn sql(&self) -> &MockSql {
+        match self {
+            Self::Query(e) => &e.sql,
+            Self::Exec(e) => &e.sql,
+        }
+    }
+
+    fn is_exhausted(&self) -> bool {
+        match self {
+            Self::Query(e) => e.calls >= e.times,
+            Self::Exec(e) => e.calls >= e.times,
+        }
+    }
+}
+
+impl MockDatabaseTrait for MockDatabase {
+    #[instrument(level = "trace", skip(statement))]
+    fn execute(&mut self, counter: usize, statement: Statement) -> Result<ExecResult, DbErr> {
+        if let Some(MockExpectation::Exec(expectation)) =
+            self.match_expectation(MockStatementKind::Exec, &statement)
+        {
+            expectation.calls += 1;
+            let result = expectation.result.clone();
+            self.unbuffered_execs += 1;
+            self.log(statement);
+            return result.map(|result| ExecResult {
+                result: ExecResultHolder::Mock(result),
+            });
+        }
+        let counter = counter - self.unbuffered_execs;
+        if counter >= self.exec_results.len() && !self.expectations.is_empty() {
+            self.unbuffered_execs += 1;
+            self.unexpected
+                .push((MockStatementKind::Exec, statement.clone()));
+            self.log(statement);
+            return Err(exec_err("Unexpected statement"));
+        }
+        self.log(statement);
         if counter < self.exec_results.len() {
             match std::mem::replace(
                 &mut self.exec_results[counter],
@@ -152,11 +590,30 @@ impl MockDatabaseTrait for MockDatabase {
 
     #[instrument(level = "trace", skip(statement))]
     fn query(&mut self, counter: usize, statement: Statement) -> Result<Vec<QueryResult>, DbErr> {
-        if let Some(transaction) = &mut self.transaction {
-            transaction.push(statement);
-        } else {
-            self.transaction_log.push(Transaction::one(statement));
+        if let Some(MockExpectation::Query(expectation)) =
+            self.match_expectation(MockStatementKind::Query, &statement)
+        {
+            expectation.calls += 1;
+            let result = expectation.result.clone();
+            self.unbuffered_queries += 1;
+            self.log(statement);
+            return result.map(|rows| {
+                rows.into_iter()
+                    .map(|row| QueryResult {
+                        row: QueryResultRow::Mock(row),
+                    })
+                    .collect()
+            });
+        }
+        let counter = counter - self.unbuffered_queries;
+        if counter >= self.query_results.len() && !self.expectations.is_empty() {
+            self.unbuffered_queries += 1;
+            self.unexpected
+                .push((MockStatementKind::Query, statement.clone()));
+            self.log(statement);
+            return Err(query_err("Unexpected query"));
         }
+        self.log(statement);
         if counter < self.query_results.len() {
             match std::mem::replace(
                 &mut self.query_results[counter],
@@ -187,10 +644,10 @@ impl MockDatabaseTrait for MockDatabase {
     fn commit(&mut self) {
         match self.transaction.as_mut() {
             Some(transaction) => {
-                if transaction.commit(self.db_backend) {
-                    if let Some(transaction) = self.transaction.take() {
-                        self.transaction_log.push(transaction.into_transaction());
-                    }
+                if transaction.commit(self.db_backend)
+                    && let Some(transaction) = self.transaction.take()
+                {
+                    self.transaction_log.push(transaction.into_transaction());
                 }
             }
             None => panic!("There is no open transaction to commit"),
@@ -201,10 +658,10 @@ impl MockDatabaseTrait for MockDatabase {
     fn rollback(&mut self) {
         match self.transaction.as_mut() {
             Some(transaction) => {
-                if transaction.rollback(self.db_backend) {
-                    if let Some(transaction) = self.transaction.take() {
-                        self.transaction_log.push(transaction.into_transaction());
-                    }
+                if transaction.rollback(self.db_backend)
+                    && let Some(transaction) = self.transaction.take()
+                {
+                    self.transaction_log.push(transaction.into_transaction());
                 }
             }
             None => panic!("There is no open transaction to rollback"),
@@ -222,6 +679,14 @@ impl MockDatabaseTrait for MockDatabase {
     fn ping(&self) -> Result<(), DbErr> {
         Ok(())
     }
+
+    fn verify(&mut self) -> Result<(), String> {
+        self.verified = true;
+        match self.report() {
+            Some(report) => Err(report),
+            None => Ok(()),
+        }
+    }
 }
 
 impl MockRow {
@@ -442,8 +907,9 @@ mod tests {
     #[cfg(feature = "sync")]
     use crate::util::StreamShim;
     use crate::{
-        DbBackend, DbErr, IntoMockRow, MockDatabase, Statement, Transaction, TransactionError,
-        TransactionTrait, entity::*, error::*, tests_cfg::*,
+        ConnectionTrait, DbBackend, DbErr, IntoMockRow, MockDatabase, MockExecResult, MockSql,
+        Statement, Transaction, TransactionError, TransactionTrait, entity::*, error::*,
+        tests_cfg::*,
     };
     use futures_util::TryStreamExt;
     use pretty_assertions::assert_eq;
@@ -881,4 +1347,323 @@ mod tests {
             Err(exec_err("this is a mock exec error"))
         );
     }
+
+    fn batch_stmts() -> Vec<Statement> {
+        (1..=3)
+            .map(|id| {
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"UPDATE "cake" SET "name" = $1 WHERE "id" = $2"#,
+                    [format!("Cake {id}").into(), id.into()],
+                )
+            })
+            .collect()
+    }
+
+    #[smol_potat::test]
+    async fn test_execute_batch() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results((1..=3).map(|rows_affected| MockExecResult {
+                last_insert_id: 0,
+                rows_affected,
+            }))
+            .into_connection();
+
+        let results = db.execute_batch(batch_stmts()).await?;
+        assert_eq!(
+            results
+                .iter()
+                .map(|res| res.rows_affected())
+                .collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+        assert!(db.execute_batch(Vec::new()).await?.is_empty());
+
+        assert_eq!(
+            db.into_transaction_log(),
+            batch_stmts()
+                .into_iter()
+                .map(Transaction::one)
+                .collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_execute_batch_stops_at_error() {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .append_exec_errors([exec_err("this is a mock exec error")])
+            .into_connection();
+
+        assert_eq!(
+            db.execute_batch(batch_stmts()).await.err(),
+            Some(exec_err("this is a mock exec error"))
+        );
+
+        // the third statement is never sent
+        let mut stmts = batch_stmts();
+        stmts.truncate(2);
+        assert_eq!(
+            db.into_transaction_log(),
+            stmts.into_iter().map(Transaction::one).collect::<Vec<_>>()
+        );
+    }
+
+    #[smol_potat::test]
+    async fn test_execute_batch_in_transaction() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results((1..=3).map(|_| MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }))
+            .into_connection();
+
+        let txn = db.begin().await?;
+        assert_eq!(txn.execute_batch(batch_stmts()).await?.len(), 3);
+        txn.commit().await?;
+
+        let mut stmts = vec![Statement::from_string(DbBackend::Postgres, "BEGIN")];
+        stmts.extend(batch_stmts());
+        stmts.push(Statement::from_string(DbBackend::Postgres, "COMMIT"));
+        assert_eq!(db.into_transaction_log(), [Transaction::many(stmts)]);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_non_persistent_statement() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cheese_cake()]])
+            .into_connection();
+
+        let stmt = Statement::from_string(
+            DbBackend::Postgres,
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+        )
+        .persistent(false);
+        db.query_all_raw(stmt.clone()).await?;
+
+        let log = db.into_transaction_log();
+        assert_eq!(log, [Transaction::one(stmt)]);
+        assert!(!log[0].statements()[0].is_persistent());
+
+        Ok(())
+    }
+
+    fn cheese_cake() -> cake::Model {
+        cake::Model {
+            id: 1,
+            name: "Cheese Cake".to_owned(),
+        }
+    }
+
+    #[smol_potat::test]
+    async fn test_expect_out_of_order() -> Result<(), DbErr> {
+        let mut mock = MockDatabase::new(DbBackend::Postgres);
+        mock.expect_query(MockSql::regex(r#"FROM "fruit""#));
+        mock.expect_query(
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = 1 LIMIT 1"#,
+        )
+        .returning([cheese_cake()])
+        .times(2);
+        mock.expect_exec(r#"DELETE FROM "cake" WHERE "cake"."id" = $1"#)
+            .returning(MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            });
+        let db = mock
+            .append_query_results([[cheese_cake()]])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::find_by_id(1).one(&db).await?,
+            Some(cheese_cake())
+        );
+        assert_eq!(
+            cake::Entity::delete_by_id(1).exec(&db).await?.rows_affected,
+            1
+        );
+        assert!(fruit::Entity::find().all(&db).await?.is_empty());
+        assert_eq!(
+            cake::Entity::find_by_id(1).one(&db).await?,
+            Some(cheese_cake())
+        );
+        // not expected, so served from the appended results
+        assert_eq!(cake::Entity::find().all(&db).await?, [cheese_cake()]);
+
+        db.verify();
+        assert_eq!(db.into_transaction_log().len(), 5);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_expect_report() {
+        let mut mock = MockDatabase::new(DbBackend::Postgres);
+        mock.expect_query(MockSql::regex(r#"FROM "cake""#)).times(2);
+        mock.expect_query(MockSql::regex(r#"FROM "fruit""#));
+        mock.expect_exec(MockSql::regex("^DELETE"));
+        let db = mock.into_connection();
+
+        assert!(cake::Entity::find().all(&db).await.is_ok());
+        assert!(fruit::Entity::find().all(&db).await.is_ok());
+        assert!(fruit::Entity::find().all(&db).await.is_ok());
+        assert_eq!(
+            filling::Entity::find().all(&db).await,
+            Err(query_err("Unexpected query"))
+        );
+
+        let report = db
+            .as_mock_connection()
+            .get_mocker_mutex()
+            .lock()
+            .expect("Fail to acquire mocker")
+            .verify();
+        assert_eq!(
+            report,
+            Err([
+                "MockDatabase expectations not met (- missing, + unexpected):",
+                r#"- query /FROM "cake"/ (expected 2, matched 1)"#,
+                r#"+ query /FROM "fruit"/ (expected 1, matched 2)"#,
+                "- exec /^DELETE/ (expected 1, matched 0)",
+                r#"+ query `SELECT "filling"."id", "filling"."name", "filling"."vendor_id" FROM "filling"`"#,
+            ]
+            .join("\n"))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "MockDatabase expectations not met")]
+    fn test_expect_panic_on_drop() {
+        let mut mock = MockDatabase::new(DbBackend::Postgres);
+        mock.expect_query(MockSql::regex(r#"FROM "cake""#));
+        drop(mock.into_connection());
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "stream")]
+    async fn test_query_count() -> Result<(), DbErr> {
+        use std::sync::{Arc, Mutex};
+
+        let apple = fruit::Model {
+            id: 1,
+            name: "Apple".to_owned(),
+            cake_id: Some(1),
+        };
+        let mut db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([[cake::Model {
+                id: 1,
+                name: "Apple Cake".to_owned(),
+            }]])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 2,
+                rows_affected: 1,
+            }])
+            .append_query_results([[apple.clone()], [apple.clone()]])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let statements = Arc::new(Mutex::new(Vec::new()));
+        db.set_metric_callback({
+            let statements = statements.clone();
+            move |info| {
+                if let Ok(mut statements) = statements.lock() {
+                    statements.push(info.statement.sql.clone());
+                }
+            }
+        });
+        assert_eq!(db.query_count(), 0);
+        db.enable_query_counting();
+
+        let (cake, count) = db
+            .count_queries(async || cake::Entity::find_by_id(1).one(&db).await)
+            .await;
+        assert!(cake?.is_some());
+        assert_eq!(count, 1);
+
+        let (res, count) = db
+            .count_queries(async || {
+                fruit::Entity::insert(fruit::ActiveModel {
+                    name: Set("Banana".to_owned()),
+                    ..Default::default()
+                })
+                .exec(&db)
+                .await
+            })
+            .await;
+        assert_eq!(res?.last_insert_id, 2);
+        assert_eq!(count, 1);
+
+        let mut stream = fruit::Entity::find().stream(&db).await?;
+        assert_eq!(stream.try_next().await?, Some(apple.clone()));
+        assert_eq!(stream.try_next().await?, None);
+        drop(stream);
+        assert_eq!(db.query_count(), 3);
+
+        let txn = db.begin().await?;
+        let (fruits, count) = txn
+            .count_queries(async || fruit::Entity::find().all(&txn).await)
+            .await;
+        assert_eq!(fruits?, [apple]);
+        assert_eq!(count, 1);
+        fruit::Entity::delete_by_id(1).exec(&txn).await?;
+        txn.commit().await?;
+
+        // the transaction shares the counter of the connection
+        assert_eq!(db.query_count(), 5);
+        // and the user callback is still called
+        assert_eq!(statements.lock().map(|s| s.len()).unwrap_or_default(), 5);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_expect_returning_per_backend() -> Result<(), DbErr> {
+        let apple = fruit::Model {
+            id: 1,
+            name: "Apple".to_owned(),
+            cake_id: None,
+        };
+        let green_apple = fruit::Model {
+            name: "Green Apple".to_owned(),
+            ..apple.clone()
+        };
+
+        for backend in [DbBackend::Postgres, DbBackend::MySql] {
+            let db = MockDatabase::new(backend)
+                .expect_insert_returning(apple.clone())
+                .expect_update_returning(green_apple.clone())
+                .expect_delete(1)
+                .into_connection();
+
+            let inserted = fruit::ActiveModel {
+                name: Set("Apple".to_owned()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await?;
+            assert_eq!(inserted, apple);
+
+            let mut fruit = inserted.into_active_model();
+            fruit.name = Set("Green Apple".to_owned());
+            let updated = fruit.update(&db).await?;
+            assert_eq!(updated, green_apple);
+
+            let res = updated.delete(&db).await?;
+            assert_eq!(res.rows_affected, 1);
+
+            let statements = if backend.support_returning() { 3 } else { 5 };
+            assert_eq!(db.into_transaction_log().len(), statements, "{backend:?}");
+        }
+
+        Ok(())
+    }
 }
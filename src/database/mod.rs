@@ -1,7 +1,7 @@
 use std::{sync::Arc, time::Duration};
 
 #[cfg(not(feature = "sync"))]
-use futures_util::future::BoxFuture;
+pub(crate) use futures_util::future::BoxFuture;
 #[cfg(feature = "sqlx-mysql")]
 use sqlx::mysql::MySqlConnectOptions;
 #[cfg(feature = "sqlx-postgres")]
@@ -18,8 +18,11 @@ mod mock;
 #[cfg(feature = "proxy")]
 #[cfg_attr(docsrs, doc(cfg(feature = "proxy")))]
 mod proxy;
+mod read_write_connection;
 #[cfg(feature = "rbac")]
 mod restricted_connection;
+mod schema_scoped_connection;
+mod scoped_connection;
 #[cfg(all(feature = "schema-sync", feature = "rusqlite"))]
 mod sea_schema_rusqlite;
 #[cfg(all(feature = "schema-sync", feature = "sqlx-dep"))]
@@ -39,8 +42,11 @@ pub use mock::*;
 #[cfg(feature = "proxy")]
 #[cfg_attr(docsrs, doc(cfg(feature = "proxy")))]
 pub use proxy::*;
+pub use read_write_connection::*;
 #[cfg(feature = "rbac")]
 pub use restricted_connection::*;
+pub use schema_scoped_connection::*;
+pub use scoped_connection::*;
 pub use statement::*;
 use std::borrow::Cow;
 #[cfg(feature = "stream")]
@@ -55,7 +61,7 @@ use crate::error::*;
 pub struct Database;
 
 #[cfg(feature = "sync")]
-type BoxFuture<'a, T> = T;
+pub(crate) type BoxFuture<'a, T> = T;
 
 #[cfg(feature = "sqlx-mysql")]
 type MapMySqlPoolOptsFn = Arc<
@@ -108,6 +114,10 @@ pub struct ConnectOptions {
     pub(crate) acquire_timeout: Option<Duration>,
     /// Set the maximum lifetime of individual connections
     pub(crate) max_lifetime: Option<Option<Duration>>,
+    /// Randomize the lifetime of individual connections within `max_lifetime ± jitter`
+    pub(crate) max_lifetime_jitter: Option<Duration>,
+    /// Number of connections opened when connecting
+    pub(crate) warmup_connections: Option<u32>,
     /// Enable SQLx statement logging
     pub(crate) sqlx_logging: bool,
     /// Record SQL statements in tracing spans
@@ -120,12 +130,16 @@ pub struct ConnectOptions {
     pub(crate) sqlx_slow_statements_logging_threshold: Duration,
     /// set sqlcipher key
     pub(crate) sqlcipher_key: Option<Cow<'static, str>>,
+    /// Retry statements failing with `SQLITE_BUSY`: max attempts and backoff (SQLite only)
+    pub(crate) sqlite_busy_retry: Option<(u32, Duration)>,
     /// Schema search path (PostgreSQL only)
     pub(crate) schema_search_path: Option<String>,
     /// Application name (PostgreSQL only)
     pub(crate) application_name: Option<String>,
     /// Statement timeout (PostgreSQL only)
     pub(crate) statement_timeout: Option<Duration>,
+    /// Capacity of the prepared statement cache of each connection
+    pub(crate) statement_cache_capacity: Option<usize>,
     pub(crate) test_before_acquire: bool,
     /// Only establish connections to the DB as needed. If set to `true`, the db connection will
     /// be created using SQLx's [connect_lazy](https://docs.rs/sqlx/latest/sqlx/struct.Pool.html#method.connect_lazy)
@@ -254,15 +268,19 @@ impl ConnectOptions {
             idle_timeout: None,
             acquire_timeout: None,
             max_lifetime: None,
+            max_lifetime_jitter: None,
+            warmup_connections: None,
             sqlx_logging: true,
             record_stmt_in_spans: true,
             sqlx_logging_level: log::LevelFilter::Info,
             sqlx_slow_statements_logging_level: log::LevelFilter::Off,
             sqlx_slow_statements_logging_threshold: Duration::from_secs(1),
             sqlcipher_key: None,
+            sqlite_busy_retry: None,
             schema_search_path: None,
             application_name: None,
             statement_timeout: None,
+            statement_cache_capacity: None,
             test_before_acquire: true,
             connect_lazy: false,
             after_connect: None,
@@ -358,6 +376,33 @@ impl ConnectOptions {
         self.max_lifetime
     }
 
+    /// Retire each connection at a random age within `max_lifetime ± jitter`, instead of
+    /// exactly at `max_lifetime`, so that connections opened together are not all
+    /// reconnected at once. Applies to sqlx pools only.
+    pub fn max_lifetime_jitter(&mut self, jitter: Duration) -> &mut Self {
+        self.max_lifetime_jitter = Some(jitter);
+        self
+    }
+
+    /// Get the maximum lifetime jitter of individual connections, if set
+    pub fn get_max_lifetime_jitter(&self) -> Option<Duration> {
+        self.max_lifetime_jitter
+    }
+
+    /// Open `value` connections (at most `max_connections`) before
+    /// [`Database::connect`] returns, so that the first queries don't wait for
+    /// connections to be established. Applies to sqlx pools only, and is
+    /// ignored with [`connect_lazy`](Self::connect_lazy).
+    pub fn warmup_connections(&mut self, value: u32) -> &mut Self {
+        self.warmup_connections = Some(value);
+        self
+    }
+
+    /// Get the number of connections opened when connecting, if set
+    pub fn get_warmup_connections(&self) -> Option<u32> {
+        self.warmup_connections
+    }
+
     /// Enable SQLx statement logging (default true)
     pub fn sqlx_logging(&mut self, value: bool) -> &mut Self {
         self.sqlx_logging = value;
@@ -421,6 +466,25 @@ impl ConnectOptions {
         self
     }
 
+    /// Retry statements failing because the database is busy or locked by another
+    /// connection (SQLite only). A statement is run at most `max_attempts` times,
+    /// waiting `backoff` times the number of attempts so far in between.
+    ///
+    /// Only statements run outside of a transaction are retried: inside of one,
+    /// the transaction as a whole has to be retried. Neither is SQL run with
+    /// [`execute_unprepared`](crate::ConnectionTrait::execute_unprepared), which may
+    /// hold several statements, the first of which would run again. Check for the error with
+    /// [`DbErr::is_busy`](crate::DbErr::is_busy).
+    pub fn sqlite_busy_retry(&mut self, max_attempts: u32, backoff: Duration) -> &mut Self {
+        self.sqlite_busy_retry = Some((max_attempts, backoff));
+        self
+    }
+
+    /// Get the max attempts and backoff of statements retried on `SQLITE_BUSY`, if set
+    pub fn get_sqlite_busy_retry(&self) -> Option<(u32, Duration)> {
+        self.sqlite_busy_retry
+    }
+
     /// Set schema search path (PostgreSQL only)
     pub fn set_schema_search_path<T>(&mut self, schema_search_path: T) -> &mut Self
     where
@@ -456,6 +520,29 @@ impl ConnectOptions {
         self.statement_timeout
     }
 
+    /// Set how many prepared statements each connection keeps cached, defaults to SQLx's 100.
+    ///
+    /// Statements evicted from the cache are closed on the database.
+    pub fn statement_cache_capacity(&mut self, value: usize) -> &mut Self {
+        self.statement_cache_capacity = Some(value);
+        self
+    }
+
+    /// Don't keep prepared statements around between executions, as if every
+    /// [Statement](crate::Statement) was [not persistent](crate::Statement::persistent).
+    ///
+    /// Required behind a connection pooler that hands each transaction to a different
+    /// server connection, e.g. PgBouncer in transaction mode, where a statement prepared
+    /// on one server connection doesn't exist on the next.
+    pub fn disable_statement_caching(&mut self) -> &mut Self {
+        self.statement_cache_capacity(0)
+    }
+
+    /// Get the capacity of the prepared statement cache, if set
+    pub fn get_statement_cache_capacity(&self) -> Option<usize> {
+        self.statement_cache_capacity
+    }
+
     /// If true, the connection will be pinged upon acquiring from the pool (default true).
     pub fn test_before_acquire(&mut self, value: bool) -> &mut Self {
         self.test_before_acquire = value;
@@ -542,6 +629,24 @@ impl ConnectOptions {
     #[cfg_attr(docsrs, doc(cfg(feature = "sqlx-sqlite")))]
     /// Apply a function to modify the underlying [`SqliteConnectOptions`] before
     /// creating the connection pool.
+    ///
+    /// Useful for settings that can't be expressed in the connection URL, e.g.
+    /// loading an extension:
+    ///
+    /// ```no_run
+    /// # use sea_orm::ConnectOptions;
+    /// use sqlx::sqlite::SqliteJournalMode;
+    /// use std::time::Duration;
+    ///
+    /// let mut opt = ConnectOptions::new("sqlite://db.sqlite?mode=rwc");
+    /// opt.map_sqlx_sqlite_opts(|opts| {
+    ///     let opts = opts
+    ///         .journal_mode(SqliteJournalMode::Wal)
+    ///         .busy_timeout(Duration::from_secs(10));
+    ///     // SAFETY: only load extensions you trust
+    ///     unsafe { opts.extension("vector0") }
+    /// });
+    /// ```
     pub fn map_sqlx_sqlite_opts<F>(&mut self, f: F) -> &mut Self
     where
         F: Fn(SqliteConnectOptions) -> SqliteConnectOptions + Send + Sync + 'static,
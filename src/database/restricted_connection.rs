@@ -51,6 +51,10 @@ impl ConnectionTrait for RestrictedConnection {
         self.conn.get_database_backend()
     }
 
+    fn support_returning(&self) -> bool {
+        self.conn.support_returning()
+    }
+
     async fn execute_raw(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
         Err(DbErr::RbacError(format!(
             "Raw query is not supported: {stmt}"
@@ -97,6 +101,10 @@ impl ConnectionTrait for RestrictedTransaction {
         self.conn.get_database_backend()
     }
 
+    fn support_returning(&self) -> bool {
+        self.conn.support_returning()
+    }
+
     async fn execute_raw(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
         Err(DbErr::RbacError(format!(
             "Raw query is not supported: {stmt}"
@@ -0,0 +1,340 @@
+use crate::{
+    AccessMode, ConnectionTrait, DatabaseConnection, DatabaseConnectionType, DatabaseTransaction,
+    DbBackend, DbErr, ExecResult, IsolationLevel, PinnedConnection, QueryResult, Statement,
+    TransactionError, TransactionOptions, TransactionTrait, error::*,
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::{future::Future, pin::Pin, sync::Arc};
+use tracing::instrument;
+
+/// A connection taken out of the pool, with its schema search path switched,
+/// e.g. to serve one request in a schema-per-tenant application.
+///
+/// Obtained with [`DatabaseConnection::with_search_path`]. On Postgres it runs
+/// `SET search_path`, on MySQL `USE`. All statements, including transactions
+/// started on it, run on this one connection, so the setting never applies
+/// to other users of the pool.
+///
+/// Call [`release`](Self::release) when done, which restores the previous
+/// setting and returns the connection to the pool. If it's dropped without
+/// being released, the connection is closed instead of being reused.
+#[derive(Debug)]
+pub struct SchemaScopedConnection {
+    conn: PinnedConnection,
+    restore: Option<String>,
+    /// Set once the previous setting is restored, the connection is closed on drop otherwise
+    restored: Arc<AtomicBool>,
+}
+
+impl DatabaseConnection {
+    /// Acquire a connection from the pool and set its search path to `schema`.
+    /// Only supported on Postgres and MySQL.
+    #[instrument(level = "trace")]
+    #[allow(unreachable_code, unused_variables, unused_assignments)]
+    pub async fn with_search_path(&self, schema: &str) -> Result<SchemaScopedConnection, DbErr> {
+        let restored = Arc::new(AtomicBool::new(false));
+        let conn: PinnedConnection = match &self.inner {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnectionType::SqlxMySqlPoolConnection(conn) => {
+                conn.pin(Arc::clone(&restored)).await?
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnectionType::SqlxPostgresPoolConnection(conn) => {
+                conn.pin(Arc::clone(&restored)).await?
+            }
+            #[cfg(feature = "mock")]
+            DatabaseConnectionType::MockDatabaseConnection(conn) => {
+                PinnedConnection::new_mock(Arc::clone(conn))
+            }
+            DatabaseConnectionType::Disconnected => return Err(conn_err("Disconnected")),
+            #[allow(unreachable_patterns)]
+            _ => {
+                return Err(DbErr::BackendNotSupported {
+                    db: self.get_database_backend().as_str(),
+                    ctx: "with_search_path",
+                });
+            }
+        };
+
+        let restore = match conn.get_database_backend() {
+            DbBackend::Postgres => {
+                let previous: String = conn
+                    .query_one_raw(Statement::from_string(
+                        DbBackend::Postgres,
+                        "SHOW search_path",
+                    ))
+                    .await?
+                    .ok_or_else(|| DbErr::RecordNotFound("search_path".to_owned()))?
+                    .try_get("", "search_path")?;
+                conn.execute_unprepared(&format!(
+                    "SET search_path TO {}",
+                    quote_identifier('"', schema)
+                ))
+                .await?;
+                Some(format!("SET search_path TO {previous}"))
+            }
+            DbBackend::MySql => {
+                let previous: Option<String> = conn
+                    .query_one_raw(Statement::from_string(
+                        DbBackend::MySql,
+                        "SELECT DATABASE() AS `database`",
+                    ))
+                    .await?
+                    .ok_or_else(|| DbErr::RecordNotFound("database".to_owned()))?
+                    .try_get("", "database")?;
+                conn.execute_unprepared(&format!("USE {}", quote_identifier('`', schema)))
+                    .await?;
+                // without a previous database there's no way back, the connection is closed on release
+                previous.map(|previous| format!("USE {}", quote_identifier('`', &previous)))
+            }
+            backend => {
+                return Err(DbErr::BackendNotSupported {
+                    db: backend.as_str(),
+                    ctx: "with_search_path",
+                });
+            }
+        };
+
+        Ok(SchemaScopedConnection {
+            conn,
+            restore,
+            restored,
+        })
+    }
+}
+
+impl SchemaScopedConnection {
+    /// Restore the previous search path and return the connection to the pool
+    #[instrument(level = "trace")]
+    pub async fn release(self) -> Result<(), DbErr> {
+        if let Some(sql) = &self.restore {
+            self.conn.execute_unprepared(sql).await?;
+            self.restored.store(true, Ordering::Release);
+        }
+        Ok(())
+    }
+}
+
+fn quote_identifier(quote: char, name: &str) -> String {
+    let escaped = name.replace(quote, &format!("{quote}{quote}"));
+    format!("{quote}{escaped}{quote}")
+}
+
+#[async_trait::async_trait]
+impl ConnectionTrait for SchemaScopedConnection {
+    fn get_database_backend(&self) -> DbBackend {
+        self.conn.get_database_backend()
+    }
+
+    fn support_returning(&self) -> bool {
+        self.conn.support_returning()
+    }
+
+    async fn execute_raw(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.conn.execute_raw(stmt).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.conn.execute_unprepared(sql).await
+    }
+
+    async fn query_one_raw(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.conn.query_one_raw(stmt).await
+    }
+
+    async fn query_all_raw(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.conn.query_all_raw(stmt).await
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.conn.is_mock_connection()
+    }
+}
+
+#[async_trait::async_trait]
+impl TransactionTrait for SchemaScopedConnection {
+    type Transaction = DatabaseTransaction;
+
+    #[instrument(level = "trace")]
+    async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        self.conn.begin().await
+    }
+
+    #[instrument(level = "trace")]
+    async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        self.conn
+            .begin_with_config(isolation_level, access_mode)
+            .await
+    }
+
+    #[instrument(level = "trace")]
+    async fn begin_with_options(
+        &self,
+        options: TransactionOptions,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        self.conn.begin_with_options(options).await
+    }
+
+    /// Execute the function inside a transaction.
+    /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
+    #[instrument(level = "trace", skip(callback))]
+    async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        self.conn.transaction(callback).await
+    }
+
+    /// Execute the function inside a transaction.
+    /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
+    #[instrument(level = "trace", skip(callback))]
+    async fn transaction_with_config<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        self.conn
+            .transaction_with_config(callback, isolation_level, access_mode)
+            .await
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        DbBackend, DbErr, EntityTrait, MockDatabase, MockExecResult, Statement, Transaction,
+        TransactionTrait, tests_cfg::cake,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn with_search_path_postgres() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[maplit::btreemap! {
+                "search_path" => Into::<sea_query::Value>::into("\"$user\", public"),
+            }]])
+            .append_query_results([Vec::<cake::Model>::new()])
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 0,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 0,
+                },
+            ])
+            .into_connection();
+
+        let conn = db.with_search_path("tenant \"a\"").await?;
+        cake::Entity::find().all(&conn).await?;
+        conn.release().await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::one(Statement::from_string(
+                    DbBackend::Postgres,
+                    "SHOW search_path"
+                )),
+                Transaction::one(Statement::from_string(
+                    DbBackend::Postgres,
+                    r#"SET search_path TO "tenant ""a""""#
+                )),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                    []
+                ),
+                Transaction::one(Statement::from_string(
+                    DbBackend::Postgres,
+                    r#"SET search_path TO "$user", public"#
+                )),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn with_search_path_mysql() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([[maplit::btreemap! {
+                "database" => Into::<sea_query::Value>::into("bakery"),
+            }]])
+            .append_query_results([Vec::<cake::Model>::new()])
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 0,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 0,
+                },
+            ])
+            .into_connection();
+
+        let conn = db.with_search_path("tenant_a").await?;
+        let txn = conn.begin().await?;
+        cake::Entity::find().all(&txn).await?;
+        txn.commit().await?;
+        conn.release().await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::one(Statement::from_string(
+                    DbBackend::MySql,
+                    "SELECT DATABASE() AS `database`"
+                )),
+                Transaction::one(Statement::from_string(DbBackend::MySql, "USE `tenant_a`")),
+                Transaction::many([
+                    Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                    Statement::from_sql_and_values(
+                        DbBackend::MySql,
+                        "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                        []
+                    ),
+                    Statement::from_string(DbBackend::MySql, "COMMIT"),
+                ]),
+                Transaction::one(Statement::from_string(DbBackend::MySql, "USE `bakery`")),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn with_search_path_not_supported() {
+        let db = MockDatabase::new(DbBackend::Sqlite).into_connection();
+
+        assert_eq!(
+            db.with_search_path("tenant_a").await.err(),
+            Some(DbErr::BackendNotSupported {
+                db: "Sqlite",
+                ctx: "with_search_path",
+            })
+        );
+        assert!(db.into_transaction_log().is_empty());
+    }
+}
@@ -0,0 +1,853 @@
+use crate::{
+    AccessMode, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr,
+    EntityName, ExecResult, IsolationLevel, QueryResult, Statement, StatementBuilder,
+    TransactionError, TransactionOptions, TransactionSession, TransactionTrait,
+};
+use sea_query::{
+    Alias, BinOper, Condition, ConditionHolder, DeleteStatement, EscapeBuilder, ExplainStatement,
+    Expr, InsertStatement, IntoCondition, IntoIden, Oper, OperLeftAssocDecider, PrecedenceDecider,
+    QueryBuilder, QueryStatementBuilder, Quote, QuotedBuilder, SchemaName, SelectInto,
+    SelectStatement, SqlWriter, SubQueryStatement, TableName, TableRef, TableRefBuilder, UnionType,
+    UpdateStatement, Value, WithQuery,
+    audit::{AccessType, AuditTrait},
+};
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::{future::Future, pin::Pin, sync::Arc};
+use tracing::instrument;
+
+/// The conditions every statement against a set of entities must carry,
+/// e.g. `WHERE tenant_id = ?` in a multi-tenant application.
+///
+/// Entities opt in one by one with [`QueryScope::entity`]. Use
+/// [`DatabaseConnection::with_scope`] to get a [`ScopedConnection`] that
+/// adds the conditions to every statement it builds.
+///
+/// ```
+/// use sea_orm::{DbBackend, QueryScope, entity::*, query::*, tests_cfg::cake};
+///
+/// let scope = QueryScope::new().entity(cake::Entity, cake::Column::Id.eq(1));
+///
+/// assert_eq!(
+///     scope
+///         .build(DbBackend::Postgres, &cake::Entity::find().into_query())?
+///         .to_string(),
+///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = 1"#
+/// );
+/// # Ok::<(), sea_orm::DbErr>(())
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct QueryScope {
+    entities: Vec<(TableName, Condition)>,
+}
+
+/// Wrapper of [`DatabaseConnection`] that adds the conditions of a
+/// [`QueryScope`] to all executed statements.
+///
+/// The conditions are added to the `WHERE` of every `SELECT`, `UPDATE` and
+/// `DELETE` built by SeaORM that reads or writes the table of a scoped
+/// entity, see [`QueryScope::build`]. Raw statements, e.g. from
+/// [`raw_sql!`](crate::raw_sql) and run with [`SelectorRaw`](crate::SelectorRaw),
+/// are executed as is.
+#[derive(Debug, Clone)]
+pub struct ScopedConnection {
+    scope: Arc<QueryScope>,
+    conn: DatabaseConnection,
+}
+
+/// Wrapper of [`DatabaseTransaction`] that adds the conditions of a
+/// [`QueryScope`] to all executed statements, see [`ScopedConnection`].
+#[derive(Debug)]
+pub struct ScopedTransaction {
+    scope: Arc<QueryScope>,
+    conn: DatabaseTransaction,
+}
+
+/// A statement [`QueryScope`] can add its conditions to
+pub(crate) trait ScopedStatement:
+    AuditTrait + QueryStatementBuilder + StatementBuilder + Clone
+{
+    /// Add the condition to the `WHERE` of the statement itself, returning
+    /// `false` if it has none to add to
+    fn add_scope_condition(&mut self, condition: Condition) -> bool;
+}
+
+impl QueryScope {
+    /// Create an empty scope
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scope the entity, requiring `condition` on every statement against it
+    pub fn entity<E, C>(mut self, entity: E, condition: C) -> Self
+    where
+        E: EntityName,
+        C: IntoCondition,
+    {
+        self.entities
+            .push((entity_table_name(entity), condition.into_condition()));
+        self
+    }
+
+    /// The condition required for the entity, if it is scoped
+    pub fn condition<E>(&self, entity: E) -> Option<Condition>
+    where
+        E: EntityName,
+    {
+        let table_name = entity_table_name(entity);
+        self.entities
+            .iter()
+            .find(|(table, _)| *table == table_name)
+            .map(|(_, condition)| condition.clone())
+    }
+
+    /// Build the statement with the conditions of the scoped entities it
+    /// reads or writes added to its `WHERE`.
+    ///
+    /// Inserts into a scoped table are built as is. Only the statement itself
+    /// is scoped, so reading a scoped table anywhere but unaliased in its own
+    /// `FROM`, joins or `UPDATE` / `DELETE` target, e.g. in a subquery, a
+    /// `UNION`, an `INSERT`, a `WITH` query or under an alias, returns
+    /// [`DbErr::ScopeViolation`]. As the condition is added to the `WHERE`, a
+    /// `LEFT JOIN` of a scoped entity only returns the rows where the joined
+    /// entity is found and in scope.
+    pub fn build<S>(&self, db_backend: DbBackend, stmt: &S) -> Result<Statement, DbErr>
+    where
+        S: StatementBuilder,
+    {
+        stmt.build_scoped(&db_backend, self)
+    }
+
+    pub(crate) fn apply<S>(&self, stmt: &S, db_backend: &DbBackend) -> Result<Statement, DbErr>
+    where
+        S: ScopedStatement,
+    {
+        Ok(self.scope(stmt, db_backend)?.build(db_backend))
+    }
+
+    /// Add the conditions to the statement, without building it
+    pub(crate) fn scope<'s, S>(
+        &self,
+        stmt: &'s S,
+        db_backend: &DbBackend,
+    ) -> Result<Cow<'s, S>, DbErr>
+    where
+        S: ScopedStatement,
+    {
+        let audit = AuditTrait::audit(stmt)
+            .map_err(|err| DbErr::Custom(format!("Failed to audit statement: {err}")))?;
+        let refs = TableRefs::default();
+        stmt.build_collect_any_into(&refs, &mut String::new());
+        let refs = refs.tables.into_inner();
+
+        let mut scoped = Cow::Borrowed(stmt);
+        for (table, condition) in self.entities.iter() {
+            let accessed = audit.requests.iter().any(|request| {
+                request.access_type != AccessType::Insert && request.schema_table == *table
+            });
+            // the audit skips the subqueries in the `WHERE` of updates and deletes
+            let mut refs = refs.iter().filter(|(name, _)| name == table);
+            let nested = refs.clone().any(|(_, top_level)| !top_level);
+            if !accessed && !nested {
+                continue;
+            }
+            if nested
+                || !refs.any(|(_, top_level)| *top_level)
+                || !scoped.to_mut().add_scope_condition(condition.clone())
+            {
+                return Err(DbErr::ScopeViolation {
+                    table: table.1.to_string(),
+                    sql: stmt.build(db_backend).to_string(),
+                });
+            }
+        }
+        Ok(scoped)
+    }
+}
+
+fn entity_table_name<E>(entity: E) -> TableName
+where
+    E: EntityName,
+{
+    let schema = entity
+        .schema_name()
+        .map(|schema| SchemaName(None, Alias::new(schema).into_iden()));
+    TableName(schema, entity.into_iden())
+}
+
+/// Walk a statement with a builder that writes nothing and only records the
+/// tables it reads or writes, and whether each is referenced unaliased by the
+/// statement itself rather than by a subquery, a `UNION` or a `WITH` query.
+#[derive(Default)]
+struct TableRefs {
+    depth: Cell<usize>,
+    tables: RefCell<Vec<(TableName, bool)>>,
+}
+
+impl TableRefs {
+    fn nested(&self, f: impl FnOnce()) {
+        self.depth.set(self.depth.get() + 1);
+        f();
+        self.depth.set(self.depth.get() - 1);
+    }
+}
+
+impl QueryBuilder for TableRefs {
+    fn prepare_table_ref(&self, table_ref: &TableRef, sql: &mut impl SqlWriter) {
+        match table_ref {
+            TableRef::Table(table, alias) => self
+                .tables
+                .borrow_mut()
+                .push((table.clone(), self.depth.get() == 0 && alias.is_none())),
+            TableRef::SubQuery(query, _) => {
+                self.nested(|| self.prepare_select_statement(query, sql))
+            }
+            TableRef::FunctionCall(func, _) => {
+                self.nested(|| self.prepare_function_arguments(func, sql))
+            }
+            _ => {}
+        }
+    }
+
+    fn prepare_union_statement(
+        &self,
+        _: UnionType,
+        query: &SelectStatement,
+        sql: &mut impl SqlWriter,
+    ) {
+        self.nested(|| self.prepare_select_statement(query, sql));
+    }
+
+    fn prepare_update_join(
+        &self,
+        from: &[TableRef],
+        _: &ConditionHolder,
+        sql: &mut impl SqlWriter,
+    ) {
+        self.prepare_update_from(from, sql);
+    }
+
+    fn prepare_query_statement(&self, query: &SubQueryStatement, sql: &mut impl SqlWriter) {
+        self.nested(|| match query {
+            SubQueryStatement::SelectStatement(stmt) => self.prepare_select_statement(stmt, sql),
+            SubQueryStatement::InsertStatement(stmt) => self.prepare_insert_statement(stmt, sql),
+            SubQueryStatement::UpdateStatement(stmt) => self.prepare_update_statement(stmt, sql),
+            SubQueryStatement::DeleteStatement(stmt) => self.prepare_delete_statement(stmt, sql),
+            SubQueryStatement::WithStatement(stmt) => self.prepare_with_query(stmt, sql),
+        });
+    }
+
+    fn prepare_select_into(&self, _: &SelectInto, _: &mut impl SqlWriter) {}
+
+    fn prepare_explain_statement(&self, _: &ExplainStatement, _: &mut impl SqlWriter) {}
+
+    fn prepare_value(&self, _: Value, _: &mut impl SqlWriter) {}
+}
+
+impl QuotedBuilder for TableRefs {
+    fn quote(&self) -> Quote {
+        Quote::new(b'"')
+    }
+}
+
+impl EscapeBuilder for TableRefs {}
+
+impl TableRefBuilder for TableRefs {}
+
+impl OperLeftAssocDecider for TableRefs {
+    fn well_known_left_associative(&self, _: &BinOper) -> bool {
+        false
+    }
+}
+
+impl PrecedenceDecider for TableRefs {
+    fn inner_expr_well_known_greater_precedence(&self, _: &Expr, _: &Oper) -> bool {
+        false
+    }
+}
+
+macro_rules! impl_scoped_statement {
+    ($stmt: ty) => {
+        impl ScopedStatement for $stmt {
+            fn add_scope_condition(&mut self, condition: Condition) -> bool {
+                self.cond_where(condition);
+                true
+            }
+        }
+    };
+}
+
+impl_scoped_statement!(SelectStatement);
+impl_scoped_statement!(UpdateStatement);
+impl_scoped_statement!(DeleteStatement);
+
+impl ScopedStatement for InsertStatement {
+    fn add_scope_condition(&mut self, _: Condition) -> bool {
+        false
+    }
+}
+
+impl ScopedStatement for WithQuery {
+    fn add_scope_condition(&mut self, _: Condition) -> bool {
+        false
+    }
+}
+
+impl DatabaseConnection {
+    /// Create a connection that checks all executed statements against the scope
+    pub fn with_scope(&self, scope: QueryScope) -> ScopedConnection {
+        ScopedConnection {
+            scope: Arc::new(scope),
+            conn: self.clone(),
+        }
+    }
+}
+
+impl ScopedConnection {
+    /// Get the [`QueryScope`] bounded to this connection
+    pub fn scope(&self) -> &QueryScope {
+        &self.scope
+    }
+}
+
+impl ScopedTransaction {
+    /// Get the [`QueryScope`] bounded to this transaction
+    pub fn scope(&self) -> &QueryScope {
+        &self.scope
+    }
+
+    /// Runs a transaction to completion passing through the result.
+    /// Rolling back the transaction on encountering an error.
+    #[instrument(level = "trace", skip(callback))]
+    async fn run<F, T, E>(self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'b> FnOnce(
+                &'b ScopedTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'b>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        let res = callback(&self).await.map_err(TransactionError::Transaction);
+        if res.is_ok() {
+            self.commit().await.map_err(TransactionError::Connection)?;
+        } else {
+            self.rollback()
+                .await
+                .map_err(TransactionError::Connection)?;
+        }
+        res
+    }
+
+    /// Commit a transaction
+    #[instrument(level = "trace")]
+    pub async fn commit(self) -> Result<(), DbErr> {
+        self.conn.commit().await
+    }
+
+    /// Rolls back a transaction explicitly
+    #[instrument(level = "trace")]
+    pub async fn rollback(self) -> Result<(), DbErr> {
+        self.conn.rollback().await
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectionTrait for ScopedConnection {
+    fn get_database_backend(&self) -> DbBackend {
+        self.conn.get_database_backend()
+    }
+
+    fn support_returning(&self) -> bool {
+        self.conn.support_returning()
+    }
+
+    async fn execute_raw(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.conn.execute_raw(stmt).await
+    }
+
+    async fn execute<S: StatementBuilder>(&self, stmt: &S) -> Result<ExecResult, DbErr> {
+        let stmt = self.scope.build(self.get_database_backend(), stmt)?;
+        self.conn.execute_raw(stmt).await
+    }
+
+    async fn execute_batch(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        self.conn.execute_batch(stmts).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.conn.execute_unprepared(sql).await
+    }
+
+    async fn query_one_raw(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.conn.query_one_raw(stmt).await
+    }
+
+    async fn query_one<S: StatementBuilder>(&self, stmt: &S) -> Result<Option<QueryResult>, DbErr> {
+        let stmt = self.scope.build(self.get_database_backend(), stmt)?;
+        self.conn.query_one_raw(stmt).await
+    }
+
+    async fn query_all_raw(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.conn.query_all_raw(stmt).await
+    }
+
+    async fn query_all<S: StatementBuilder>(&self, stmt: &S) -> Result<Vec<QueryResult>, DbErr> {
+        let stmt = self.scope.build(self.get_database_backend(), stmt)?;
+        self.conn.query_all_raw(stmt).await
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.conn.is_mock_connection()
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectionTrait for ScopedTransaction {
+    fn get_database_backend(&self) -> DbBackend {
+        self.conn.get_database_backend()
+    }
+
+    fn support_returning(&self) -> bool {
+        self.conn.support_returning()
+    }
+
+    async fn execute_raw(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.conn.execute_raw(stmt).await
+    }
+
+    async fn execute<S: StatementBuilder>(&self, stmt: &S) -> Result<ExecResult, DbErr> {
+        let stmt = self.scope.build(self.get_database_backend(), stmt)?;
+        self.conn.execute_raw(stmt).await
+    }
+
+    async fn execute_batch(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        self.conn.execute_batch(stmts).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.conn.execute_unprepared(sql).await
+    }
+
+    async fn query_one_raw(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.conn.query_one_raw(stmt).await
+    }
+
+    async fn query_one<S: StatementBuilder>(&self, stmt: &S) -> Result<Option<QueryResult>, DbErr> {
+        let stmt = self.scope.build(self.get_database_backend(), stmt)?;
+        self.conn.query_one_raw(stmt).await
+    }
+
+    async fn query_all_raw(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.conn.query_all_raw(stmt).await
+    }
+
+    async fn query_all<S: StatementBuilder>(&self, stmt: &S) -> Result<Vec<QueryResult>, DbErr> {
+        let stmt = self.scope.build(self.get_database_backend(), stmt)?;
+        self.conn.query_all_raw(stmt).await
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.conn.is_mock_connection()
+    }
+}
+
+#[async_trait::async_trait]
+impl TransactionTrait for ScopedConnection {
+    type Transaction = ScopedTransaction;
+
+    #[instrument(level = "trace")]
+    async fn begin(&self) -> Result<ScopedTransaction, DbErr> {
+        Ok(ScopedTransaction {
+            scope: self.scope.clone(),
+            conn: self.conn.begin().await?,
+        })
+    }
+
+    #[instrument(level = "trace")]
+    async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<ScopedTransaction, DbErr> {
+        Ok(ScopedTransaction {
+            scope: self.scope.clone(),
+            conn: self
+                .conn
+                .begin_with_config(isolation_level, access_mode)
+                .await?,
+        })
+    }
+
+    #[instrument(level = "trace")]
+    async fn begin_with_options(
+        &self,
+        options: TransactionOptions,
+    ) -> Result<ScopedTransaction, DbErr> {
+        Ok(ScopedTransaction {
+            scope: self.scope.clone(),
+            conn: self.conn.begin_with_options(options).await?,
+        })
+    }
+
+    /// Execute the function inside a transaction.
+    /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
+    #[instrument(level = "trace", skip(callback))]
+    async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c ScopedTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        let transaction = self.begin().await.map_err(TransactionError::Connection)?;
+        transaction.run(callback).await
+    }
+
+    /// Execute the function inside a transaction.
+    /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
+    #[instrument(level = "trace", skip(callback))]
+    async fn transaction_with_config<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c ScopedTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        let transaction = self
+            .begin_with_config(isolation_level, access_mode)
+            .await
+            .map_err(TransactionError::Connection)?;
+        transaction.run(callback).await
+    }
+}
+
+#[async_trait::async_trait]
+impl TransactionTrait for ScopedTransaction {
+    type Transaction = ScopedTransaction;
+
+    #[instrument(level = "trace")]
+    async fn begin(&self) -> Result<ScopedTransaction, DbErr> {
+        Ok(ScopedTransaction {
+            scope: self.scope.clone(),
+            conn: self.conn.begin().await?,
+        })
+    }
+
+    #[instrument(level = "trace")]
+    async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<ScopedTransaction, DbErr> {
+        Ok(ScopedTransaction {
+            scope: self.scope.clone(),
+            conn: self
+                .conn
+                .begin_with_config(isolation_level, access_mode)
+                .await?,
+        })
+    }
+
+    #[instrument(level = "trace")]
+    async fn begin_with_options(
+        &self,
+        options: TransactionOptions,
+    ) -> Result<ScopedTransaction, DbErr> {
+        Ok(ScopedTransaction {
+            scope: self.scope.clone(),
+            conn: self.conn.begin_with_options(options).await?,
+        })
+    }
+
+    /// Execute the function inside a transaction.
+    /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
+    #[instrument(level = "trace", skip(callback))]
+    async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c ScopedTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        let transaction = self.begin().await.map_err(TransactionError::Connection)?;
+        transaction.run(callback).await
+    }
+
+    /// Execute the function inside a transaction.
+    /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
+    #[instrument(level = "trace", skip(callback))]
+    async fn transaction_with_config<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c ScopedTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        let transaction = self
+            .begin_with_config(isolation_level, access_mode)
+            .await
+            .map_err(TransactionError::Connection)?;
+        transaction.run(callback).await
+    }
+}
+
+#[async_trait::async_trait]
+impl TransactionSession for ScopedTransaction {
+    async fn commit(self) -> Result<(), DbErr> {
+        self.commit().await
+    }
+
+    async fn rollback(self) -> Result<(), DbErr> {
+        self.rollback().await
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        DbBackend, DbErr, MockDatabase, MockExecResult, QueryScope, Statement, Transaction,
+        TransactionTrait, entity::*, query::*, tests_cfg::*,
+    };
+    use sea_query::{Expr, Query};
+
+    fn invoice(id: i32) -> invoice::Model {
+        invoice::Model {
+            id,
+            tenant_id: 1,
+            amount: 100,
+        }
+    }
+
+    fn scope() -> QueryScope {
+        QueryScope::new().entity(invoice::Entity, invoice::Column::TenantId.eq(1))
+    }
+
+    #[test]
+    fn build_statements() -> Result<(), DbErr> {
+        let scope = scope();
+        assert!(scope.condition(invoice::Entity).is_some());
+        assert!(scope.condition(cake::Entity).is_none());
+        let build = |stmt: sea_query::SelectStatement| {
+            scope
+                .build(DbBackend::Postgres, &stmt)
+                .map(|stmt| stmt.to_string())
+        };
+
+        assert_eq!(
+            build(invoice::Entity::find().into_query())?,
+            r#"SELECT "invoice"."id", "invoice"."tenant_id", "invoice"."amount" FROM "invoice" WHERE "invoice"."tenant_id" = 1"#
+        );
+        // an existing filter does not satisfy the scope, the condition is added regardless
+        assert_eq!(
+            build(
+                invoice::Entity::find()
+                    .filter(invoice::Column::TenantId.eq(2))
+                    .into_query()
+            )?,
+            r#"SELECT "invoice"."id", "invoice"."tenant_id", "invoice"."amount" FROM "invoice" WHERE "invoice"."tenant_id" = 2 AND "invoice"."tenant_id" = 1"#
+        );
+        assert_eq!(
+            build(cake::Entity::find().into_query())?,
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake""#
+        );
+        // the condition cannot be added to the select of an insert
+        assert!(matches!(
+            scope.build(
+                DbBackend::Postgres,
+                Query::insert()
+                    .into_table(cake::Entity)
+                    .columns([cake::Column::Id])
+                    .select_from(
+                        Query::select()
+                            .column(invoice::Column::Id)
+                            .from(invoice::Entity)
+                            .to_owned()
+                    )
+                    .expect("one column")
+            ),
+            Err(DbErr::ScopeViolation { table, .. }) if table == "invoice"
+        ));
+
+        // the scoped table must be read by the statement itself, unaliased
+        let out_of_scope = |stmt: sea_query::SelectStatement| {
+            matches!(
+                scope.build(DbBackend::Postgres, &stmt),
+                Err(DbErr::ScopeViolation { table, .. }) if table == "invoice"
+            )
+        };
+        assert!(out_of_scope(
+            cake::Entity::find()
+                .filter(
+                    cake::Column::Id.in_subquery(
+                        Query::select()
+                            .column(invoice::Column::Id)
+                            .from(invoice::Entity)
+                            .to_owned()
+                    )
+                )
+                .into_query()
+        ));
+        assert!(out_of_scope(
+            Query::select()
+                .column(cake::Column::Id)
+                .from(cake::Entity)
+                .union(
+                    sea_query::UnionType::All,
+                    Query::select()
+                        .column(invoice::Column::Id)
+                        .from(invoice::Entity)
+                        .to_owned()
+                )
+                .to_owned()
+        ));
+        assert!(out_of_scope(
+            Query::select()
+                .column(invoice::Column::Id)
+                .from_as(invoice::Entity, "i")
+                .to_owned()
+        ));
+        // even if it is also read in place
+        assert!(out_of_scope(
+            invoice::Entity::find()
+                .filter(
+                    invoice::Column::Id.in_subquery(
+                        Query::select()
+                            .column(invoice::Column::Id)
+                            .from(invoice::Entity)
+                            .to_owned()
+                    )
+                )
+                .into_query()
+        ));
+        assert!(matches!(
+            scope.build(
+                DbBackend::Postgres,
+                &cake::Entity::delete_many()
+                    .filter(
+                        cake::Column::Id.in_subquery(
+                            Query::select()
+                                .column(invoice::Column::Id)
+                                .from(invoice::Entity)
+                                .to_owned()
+                        )
+                    )
+                    .into_query()
+            ),
+            Err(DbErr::ScopeViolation { table, .. }) if table == "invoice"
+        ));
+        // a table of the same name in another schema is not scoped
+        assert_eq!(
+            build(
+                Query::select()
+                    .column(invoice::Column::Id)
+                    .from(("other", invoice::Entity))
+                    .to_owned()
+            )?,
+            r#"SELECT "id" FROM "other"."invoice""#
+        );
+
+        assert_eq!(
+            scope
+                .build(
+                    DbBackend::MySql,
+                    &invoice::Entity::update_many()
+                        .col_expr(invoice::Column::Amount, Expr::value(0))
+                        .filter(invoice::Column::Id.eq(3))
+                        .into_query()
+                )?
+                .to_string(),
+            "UPDATE `invoice` SET `amount` = 0 WHERE `invoice`.`id` = 3 AND `invoice`.`tenant_id` = 1"
+        );
+        assert_eq!(
+            scope
+                .build(
+                    DbBackend::Sqlite,
+                    invoice::Entity::delete_many().into_query().returning_all()
+                )?
+                .to_string(),
+            r#"DELETE FROM "invoice" WHERE "invoice"."tenant_id" = 1 RETURNING *"#
+        );
+        // inserts are built as is
+        assert_eq!(
+            scope
+                .build(
+                    DbBackend::Postgres,
+                    &invoice::Entity::insert(invoice(1).into_active_model()).into_query()
+                )?
+                .to_string(),
+            r#"INSERT INTO "invoice" ("id", "tenant_id", "amount") VALUES (1, 1, 100)"#
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn scoped_connection() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[invoice(1)], [invoice(2)]])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+        let scoped = db.with_scope(scope());
+
+        assert_eq!(invoice::Entity::find().all(&scoped).await?, [invoice(1)]);
+
+        let txn = scoped.begin().await?;
+        assert_eq!(
+            invoice::Entity::find_by_id(2).one(&txn).await?,
+            Some(invoice(2))
+        );
+        invoice::Entity::delete_many().exec(&txn).await?;
+        txn.commit().await?;
+
+        drop(scoped);
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "invoice"."id", "invoice"."tenant_id", "invoice"."amount" FROM "invoice" WHERE "invoice"."tenant_id" = $1"#,
+                    [1.into()]
+                ),
+                Transaction::many([
+                    Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                    Statement::from_sql_and_values(
+                        DbBackend::Postgres,
+                        r#"SELECT "invoice"."id", "invoice"."tenant_id", "invoice"."amount" FROM "invoice" WHERE "invoice"."id" = $1 AND "invoice"."tenant_id" = $2 LIMIT $3"#,
+                        [2.into(), 1.into(), 1u64.into()]
+                    ),
+                    Statement::from_sql_and_values(
+                        DbBackend::Postgres,
+                        r#"DELETE FROM "invoice" WHERE "invoice"."tenant_id" = $1"#,
+                        [1.into()]
+                    ),
+                    Statement::from_string(DbBackend::Postgres, "COMMIT"),
+                ]),
+            ]
+        );
+
+        Ok(())
+    }
+}
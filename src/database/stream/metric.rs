@@ -82,7 +82,7 @@ impl Drop for MetricStream<'_> {
                 statement: self.stmt,
                 failed: false,
             };
-            callback(&info);
+            callback.notify(&info);
         }
     }
 }
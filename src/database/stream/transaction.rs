@@ -19,6 +19,7 @@ use crate::{DbErr, InnerConnection, QueryResult, Statement};
 
 /// `TransactionStream` cannot be used in a `transaction` closure as it does not impl `Send`.
 /// It seems to be a Rust limitation right now, and solution to work around this deemed to be extremely hard.
+/// Use [`Selector::stream_buffered`](crate::Selector::stream_buffered) there instead.
 #[ouroboros::self_referencing]
 pub struct TransactionStream<'a> {
     stmt: Statement,
@@ -51,11 +52,11 @@ impl TransactionStream<'_> {
                 #[cfg(feature = "sqlx-mysql")]
                 InnerConnection::MySql(c) => {
                     let query = crate::driver::sqlx_mysql::sqlx_query(stmt);
+                    let timeout = stmt.options.timeout;
                     let start = _metric_callback.is_some().then(std::time::SystemTime::now);
-                    let stream = c
-                        .fetch(query)
-                        .map_ok(Into::into)
-                        .map_err(sqlx_error_to_query_err);
+                    let stream = c.fetch(query).map_ok(Into::into).map_err(move |err| {
+                        sqlx_timeout_err(timeout, sqlx_error_to_query_err(err))
+                    });
                     let elapsed = start.map(|s| s.elapsed().unwrap_or_default());
                     MetricStream::new(_metric_callback, stmt, elapsed, stream)
                 }
@@ -91,12 +92,7 @@ impl TransactionStream<'_> {
                 #[cfg(feature = "proxy")]
                 InnerConnection::Proxy(c) => {
                     let start = _metric_callback.is_some().then(std::time::SystemTime::now);
-                    let stream = futures_util::stream::once(async {
-                        Err(DbErr::BackendNotSupported {
-                            db: "Proxy",
-                            ctx: "TransactionStream",
-                        })
-                    });
+                    let stream = c.fetch(stmt);
                     let elapsed = start.map(|s| s.elapsed().unwrap_or_default());
                     MetricStream::new(_metric_callback, stmt, elapsed, stream)
                 }
@@ -2,6 +2,8 @@
 use std::{future::Future, pin::Pin, sync::Arc};
 
 use futures_util::lock::Mutex;
+#[cfg(feature = "sqlx-dep")]
+use futures_util::lock::OwnedMutexGuard;
 #[cfg(feature = "sqlx-sqlite")]
 use sqlx_core::sql_str::SqlSafeStr;
 #[cfg(feature = "sqlx-dep")]
@@ -14,7 +16,7 @@ use crate::{
     TransactionTrait, debug_print, error::*,
 };
 #[cfg(feature = "sqlx-dep")]
-use crate::{sqlx_error_to_exec_err, sqlx_error_to_query_err};
+use crate::{SqlxConnection, sqlx_error_to_exec_err, sqlx_error_to_query_err};
 
 #[cfg(feature = "stream")]
 use crate::{StreamTrait, TransactionStream};
@@ -31,11 +33,27 @@ use crate::{StreamTrait, TransactionStream};
 /// mutation methods work against it transparently. Calling `begin` on a
 /// transaction starts a nested transaction via `SAVEPOINT`.
 pub struct DatabaseTransaction {
-    conn: Arc<Mutex<InnerConnection>>,
-    backend: DbBackend,
+    pub(crate) conn: PinnedConnection,
     open: bool,
-    metric_callback: Option<crate::metric::Callback>,
-    record_stmt_in_spans: bool,
+    pub(crate) support_returning: bool,
+}
+
+/// A connection held exclusively, outside of any transaction, e.g. by a
+/// [`SchemaScopedConnection`](crate::SchemaScopedConnection). Statements run on it
+/// directly, and transactions begun on it, including nested ones, share it.
+#[derive(Clone)]
+pub(crate) struct PinnedConnection {
+    pub(crate) conn: Arc<Mutex<InnerConnection>>,
+    pub(crate) backend: DbBackend,
+    pub(crate) metric_callback: Option<crate::metric::Callback>,
+    pub(crate) record_stmt_in_spans: bool,
+    /// Cleared when statement caching is disabled, so statements aren't prepared by name,
+    /// see [`ConnectOptions::disable_statement_caching`](crate::ConnectOptions::disable_statement_caching)
+    pub(crate) persistent_statements: bool,
+    /// Keeps everyone else off a single sqlx connection while this handle, or a
+    /// transaction begun on it, is alive, see [`SqlxSingleConnection`](crate::SqlxSingleConnection)
+    #[cfg(feature = "sqlx-dep")]
+    pub(crate) gate: Option<Arc<OwnedMutexGuard<()>>>,
 }
 
 #[instrument(level = "trace", skip(transaction, callback))]
@@ -72,6 +90,12 @@ impl std::fmt::Debug for DatabaseTransaction {
     }
 }
 
+impl std::fmt::Debug for PinnedConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PinnedConnection {{ backend: {:?} }}", self.backend)
+    }
+}
+
 impl DatabaseTransaction {
     #[instrument(level = "trace", skip(metric_callback))]
     pub(crate) async fn begin(
@@ -84,11 +108,17 @@ impl DatabaseTransaction {
         sqlite_transaction_mode: Option<SqliteTransactionMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
         let res = DatabaseTransaction {
-            conn,
-            backend,
+            conn: PinnedConnection {
+                conn,
+                backend,
+                metric_callback,
+                record_stmt_in_spans,
+                persistent_statements: true,
+                #[cfg(feature = "sqlx-dep")]
+                gate: None,
+            },
             open: true,
-            metric_callback,
-            record_stmt_in_spans,
+            support_returning: backend.support_returning(),
         };
 
         let begin_result: Result<(), DbErr> = super::tracing_spans::with_db_span!(
@@ -98,9 +128,9 @@ impl DatabaseTransaction {
             record_stmt = false,
             async {
                 #[cfg(not(feature = "sync"))]
-                let conn = &mut *res.conn.lock().await;
+                let conn = &mut *res.conn.conn.lock().await;
                 #[cfg(feature = "sync")]
-                let conn = &mut *res.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
+                let conn = &mut *res.conn.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
 
                 match conn {
                     #[cfg(feature = "sqlx-mysql")]
@@ -232,20 +262,122 @@ impl DatabaseTransaction {
         run_async_transaction_callback(transaction, callback).await
     }
 
+    /// Start counting the statements executed in this transaction and its nested
+    /// transactions. Not needed if counting is enabled on the connection it was started
+    /// from, whose counter is shared, see
+    /// [`DatabaseConnection::enable_query_counting`](crate::DatabaseConnection::enable_query_counting).
+    pub fn enable_query_counting(&mut self) {
+        self.conn.metric_callback = Some(crate::metric::with_counter(
+            self.conn.metric_callback.take(),
+        ));
+    }
+
+    /// Number of statements executed since counting was enabled, or 0 if counting is disabled
+    pub fn query_count(&self) -> u64 {
+        self.conn
+            .metric_callback
+            .as_ref()
+            .and_then(|hooks| hooks.query_count())
+            .unwrap_or_default()
+    }
+
+    /// Run `f` and return its output along with the number of statements executed
+    /// meanwhile, see [`DatabaseConnection::count_queries`](crate::DatabaseConnection::count_queries)
+    pub async fn count_queries<F, R>(&self, f: F) -> (R, u64)
+    where
+        F: AsyncFnOnce() -> R,
+    {
+        let before = self.query_count();
+        let res = f().await;
+        (res, self.query_count() - before)
+    }
+
+    /// Execute the function with an owned handle to this transaction.
+    /// If the function returns an error, the transaction will be rolled back.
+    /// Otherwise, the transaction will be committed.
+    ///
+    /// A transaction from [`begin`](crate::TransactionTrait::begin) is `Send + 'static`, and the
+    /// `Arc` handle can be cloned and moved into spawned tasks, e.g. with `tokio::spawn`.
+    /// All clones must be dropped by the time the function returns, otherwise the transaction
+    /// can't be committed: an error is returned, and the transaction is rolled back once the
+    /// last clone is dropped, returning the connection to the pool.
+    ///
+    /// ```ignore
+    /// let txn = db.begin().await?;
+    /// txn.commit_on_success::<_, _, DbErr>(async |txn| {
+    ///     let task = tokio::spawn({
+    ///         let txn = txn.clone();
+    ///         async move { cake::Entity::find().all(txn.as_ref()).await }
+    ///     });
+    ///     let cakes = task.await.expect("task panicked")?;
+    ///     Ok(cakes)
+    /// })
+    /// .await?;
+    /// ```
+    #[instrument(level = "trace", skip(callback))]
+    pub async fn commit_on_success<F, T, E>(self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: AsyncFnOnce(Arc<DatabaseTransaction>) -> Result<T, E> + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        let transaction = Arc::new(self);
+        let res = callback(Arc::clone(&transaction))
+            .await
+            .map_err(TransactionError::Transaction);
+        let Some(transaction) = Arc::into_inner(transaction) else {
+            // rolled back by drop, once the last handle goes away
+            return res.and(Err(TransactionError::Connection(conn_err(
+                "Transaction is still referenced after commit_on_success",
+            ))));
+        };
+        if res.is_ok() {
+            transaction
+                .commit()
+                .await
+                .map_err(TransactionError::Connection)?;
+        } else {
+            transaction
+                .rollback()
+                .await
+                .map_err(TransactionError::Connection)?;
+        }
+        res
+    }
+
+    /// Run `f` with the raw sqlx connection this transaction runs on, for
+    /// driver features SeaORM does not wrap (e.g. `COPY` on Postgres).
+    /// Whatever `f` does is part of this transaction.
+    ///
+    /// The connection is locked until `f` returns. Controlling the
+    /// transaction from within `f` is not supported: do not `COMMIT`,
+    /// `ROLLBACK`, or begin a sqlx transaction on the connection, use
+    /// [`begin`](TransactionTrait::begin) for nested transactions instead.
+    /// Statements run this way are not logged or metered by SeaORM.
+    ///
+    /// Returns an error if the transaction is not on a sqlx connection.
+    #[cfg(feature = "sqlx-dep")]
+    pub async fn with_raw_sqlx<F, R>(&self, f: F) -> Result<R, DbErr>
+    where
+        F: AsyncFnOnce(SqlxConnection<'_>) -> R,
+    {
+        self.conn.with_raw_sqlx(f).await
+    }
+
     /// Commit a transaction
     #[instrument(level = "trace")]
     #[allow(unreachable_code, unused_mut)]
     pub async fn commit(mut self) -> Result<(), DbErr> {
         let result: Result<(), DbErr> = super::tracing_spans::with_db_span!(
             "sea_orm.commit",
-            self.backend,
+            self.conn.backend,
             "COMMIT",
             record_stmt = false,
             async {
                 #[cfg(not(feature = "sync"))]
-                let conn = &mut *self.conn.lock().await;
+                let conn = &mut *self.conn.conn.lock().await;
                 #[cfg(feature = "sync")]
-                let conn = &mut *self.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
+                let conn = &mut *self.conn.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
 
                 match conn {
                     #[cfg(feature = "sqlx-mysql")]
@@ -295,14 +427,14 @@ impl DatabaseTransaction {
     pub async fn rollback(mut self) -> Result<(), DbErr> {
         let result: Result<(), DbErr> = super::tracing_spans::with_db_span!(
             "sea_orm.rollback",
-            self.backend,
+            self.conn.backend,
             "ROLLBACK",
             record_stmt = false,
             async {
                 #[cfg(not(feature = "sync"))]
-                let conn = &mut *self.conn.lock().await;
+                let conn = &mut *self.conn.conn.lock().await;
                 #[cfg(feature = "sync")]
-                let conn = &mut *self.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
+                let conn = &mut *self.conn.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
 
                 match conn {
                     #[cfg(feature = "sqlx-mysql")]
@@ -350,7 +482,7 @@ impl DatabaseTransaction {
     #[instrument(level = "trace")]
     fn start_rollback(&mut self) -> Result<(), DbErr> {
         if self.open {
-            if let Some(mut conn) = self.conn.try_lock() {
+            if let Some(mut conn) = self.conn.conn.try_lock() {
                 match &mut *conn {
                     #[cfg(feature = "sqlx-mysql")]
                     InnerConnection::MySql(c) => {
@@ -407,6 +539,138 @@ impl Drop for DatabaseTransaction {
 
 #[async_trait::async_trait]
 impl ConnectionTrait for DatabaseTransaction {
+    fn get_database_backend(&self) -> DbBackend {
+        self.conn.backend
+    }
+
+    fn support_returning(&self) -> bool {
+        self.support_returning
+    }
+
+    async fn execute_raw(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.conn.execute_raw(stmt).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.conn.execute_unprepared(sql).await
+    }
+
+    async fn query_one_raw(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.conn.query_one_raw(stmt).await
+    }
+
+    async fn query_all_raw(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.conn.query_all_raw(stmt).await
+    }
+}
+
+#[cfg(feature = "stream")]
+impl StreamTrait for DatabaseTransaction {
+    type Stream<'a> = TransactionStream<'a>;
+
+    fn get_database_backend(&self) -> DbBackend {
+        self.conn.backend
+    }
+
+    #[instrument(level = "trace", skip(stmt))]
+    fn stream_raw<'a>(
+        &'a self,
+        stmt: Statement,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        Box::pin(async move {
+            #[cfg(not(feature = "sync"))]
+            let conn = self.conn.conn.lock().await;
+            #[cfg(feature = "sync")]
+            let conn = self.conn.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
+            Ok(crate::TransactionStream::build(
+                conn,
+                self.conn.statement(stmt),
+                self.conn.metric_callback.clone(),
+            ))
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl TransactionTrait for DatabaseTransaction {
+    type Transaction = DatabaseTransaction;
+
+    #[instrument(level = "trace")]
+    async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        let mut txn = self.conn.begin().await?;
+        txn.support_returning = self.support_returning;
+        Ok(txn)
+    }
+
+    #[instrument(level = "trace")]
+    async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        let mut txn = self
+            .conn
+            .begin_with_config(isolation_level, access_mode)
+            .await?;
+        txn.support_returning = self.support_returning;
+        Ok(txn)
+    }
+
+    #[instrument(level = "trace")]
+    async fn begin_with_options(
+        &self,
+        options: TransactionOptions,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        let mut txn = self.conn.begin_with_options(options).await?;
+        txn.support_returning = self.support_returning;
+        Ok(txn)
+    }
+
+    /// Execute the async function inside a transaction.
+    /// If the function returns an error, the transaction will be rolled back.
+    /// Otherwise, the transaction will be committed.
+    #[instrument(level = "trace", skip(_callback))]
+    async fn transaction<F, T, E>(&self, _callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        let transaction = self.begin().await.map_err(TransactionError::Connection)?;
+        transaction.run(_callback).await
+    }
+
+    /// Execute the async function inside a transaction.
+    /// If the function returns an error, the transaction will be rolled back.
+    /// Otherwise, the transaction will be committed.
+    #[instrument(level = "trace", skip(_callback))]
+    async fn transaction_with_config<F, T, E>(
+        &self,
+        _callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        let transaction = self
+            .begin_with_config(isolation_level, access_mode)
+            .await
+            .map_err(TransactionError::Connection)?;
+        transaction.run(_callback).await
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectionTrait for PinnedConnection {
     fn get_database_backend(&self) -> DbBackend {
         // this way we don't need to lock just to know the backend
         self.backend
@@ -415,6 +679,7 @@ impl ConnectionTrait for DatabaseTransaction {
     #[instrument(level = "trace", skip(stmt))]
     #[allow(unused_variables)]
     async fn execute_raw(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        let stmt = self.statement(stmt);
         debug_print!("{}", stmt);
 
         super::tracing_spans::with_db_span!(
@@ -433,35 +698,50 @@ impl ConnectionTrait for DatabaseTransaction {
                     InnerConnection::MySql(conn) => {
                         let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
                         let conn: &mut sqlx::MySqlConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
                             query.execute(conn).await.map(Into::into)
                         })
-                        .map_err(sqlx_error_to_exec_err)
+                        .map_err(sqlx_error_to_exec_err);
+                        crate::driver::sqlx_map_timeout_err(stmt.options.timeout, res)
                     }
                     #[cfg(feature = "sqlx-postgres")]
                     InnerConnection::Postgres(conn) => {
                         let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
                         let conn: &mut sqlx::PgConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
-                            query.execute(conn).await.map(Into::into)
+                        let mut timeout =
+                            crate::driver::sqlx_postgres::StatementTimeout::set(conn, &stmt)
+                                .await?;
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
+                            query.execute(timeout.conn()).await.map(Into::into)
                         })
-                        .map_err(sqlx_error_to_exec_err)
+                        .map_err(sqlx_error_to_exec_err);
+                        timeout.reset(&stmt, res).await
                     }
                     #[cfg(feature = "sqlx-sqlite")]
                     InnerConnection::Sqlite(conn) => {
                         let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
                         let conn: &mut sqlx::SqliteConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
-                            query.execute(conn).await.map(Into::into)
+                        crate::driver::sqlx_sqlite::set_statement_timeout(conn, &stmt).await?;
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
+                            query.execute(&mut *conn).await.map(Into::into)
                         })
-                        .map_err(sqlx_error_to_exec_err)
+                        .map_err(sqlx_error_to_exec_err);
+                        crate::driver::sqlx_sqlite::reset_statement_timeout(conn, &stmt, res).await
                     }
                     #[cfg(feature = "rusqlite")]
                     InnerConnection::Rusqlite(conn) => conn.execute(stmt, &self.metric_callback),
                     #[cfg(feature = "mock")]
-                    InnerConnection::Mock(conn) => conn.execute(stmt),
+                    InnerConnection::Mock(conn) => {
+                        crate::metric::metric!(self.metric_callback, &stmt, {
+                            conn.execute(stmt.clone())
+                        })
+                    }
                     #[cfg(feature = "proxy")]
-                    InnerConnection::Proxy(conn) => conn.execute(stmt).await,
+                    InnerConnection::Proxy(conn) => {
+                        crate::metric::metric!(self.metric_callback, &stmt, {
+                            conn.execute(stmt.clone()).await
+                        })
+                    }
                     #[allow(unreachable_patterns)]
                     _ => Err(conn_err("Disconnected")),
                 }
@@ -534,6 +814,7 @@ impl ConnectionTrait for DatabaseTransaction {
     #[instrument(level = "trace", skip(stmt))]
     #[allow(unused_variables)]
     async fn query_one_raw(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        let stmt = self.statement(stmt);
         debug_print!("{}", stmt);
 
         super::tracing_spans::with_db_span!(
@@ -552,38 +833,59 @@ impl ConnectionTrait for DatabaseTransaction {
                     InnerConnection::MySql(conn) => {
                         let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
                         let conn: &mut sqlx::MySqlConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
                             crate::sqlx_map_err_ignore_not_found(
                                 query.fetch_one(conn).await.map(|row| Some(row.into())),
                             )
-                        })
+                        });
+                        crate::driver::sqlx_map_timeout_err(stmt.options.timeout, res)
                     }
                     #[cfg(feature = "sqlx-postgres")]
                     InnerConnection::Postgres(conn) => {
                         let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
                         let conn: &mut sqlx::PgConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
+                        let mut timeout =
+                            crate::driver::sqlx_postgres::StatementTimeout::set(conn, &stmt)
+                                .await?;
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
                             crate::sqlx_map_err_ignore_not_found(
-                                query.fetch_one(conn).await.map(|row| Some(row.into())),
+                                query
+                                    .fetch_one(timeout.conn())
+                                    .await
+                                    .map(|row| Some(row.into())),
                             )
-                        })
+                        });
+                        timeout.reset(&stmt, res).await
                     }
                     #[cfg(feature = "sqlx-sqlite")]
                     InnerConnection::Sqlite(conn) => {
                         let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
                         let conn: &mut sqlx::SqliteConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
+                        crate::driver::sqlx_sqlite::set_statement_timeout(conn, &stmt).await?;
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
                             crate::sqlx_map_err_ignore_not_found(
-                                query.fetch_one(conn).await.map(|row| Some(row.into())),
+                                query
+                                    .fetch_one(&mut *conn)
+                                    .await
+                                    .map(|row| Some(row.into())),
                             )
-                        })
+                        });
+                        crate::driver::sqlx_sqlite::reset_statement_timeout(conn, &stmt, res).await
                     }
                     #[cfg(feature = "rusqlite")]
                     InnerConnection::Rusqlite(conn) => conn.query_one(stmt, &self.metric_callback),
                     #[cfg(feature = "mock")]
-                    InnerConnection::Mock(conn) => conn.query_one(stmt),
+                    InnerConnection::Mock(conn) => {
+                        crate::metric::metric!(self.metric_callback, &stmt, {
+                            conn.query_one(stmt.clone())
+                        })
+                    }
                     #[cfg(feature = "proxy")]
-                    InnerConnection::Proxy(conn) => conn.query_one(stmt).await,
+                    InnerConnection::Proxy(conn) => {
+                        crate::metric::metric!(self.metric_callback, &stmt, {
+                            conn.query_one(stmt.clone()).await
+                        })
+                    }
                     #[allow(unreachable_patterns)]
                     _ => Err(conn_err("Disconnected")),
                 }
@@ -594,6 +896,7 @@ impl ConnectionTrait for DatabaseTransaction {
     #[instrument(level = "trace", skip(stmt))]
     #[allow(unused_variables)]
     async fn query_all_raw(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        let stmt = self.statement(stmt);
         debug_print!("{}", stmt);
 
         super::tracing_spans::with_db_span!(
@@ -612,44 +915,59 @@ impl ConnectionTrait for DatabaseTransaction {
                     InnerConnection::MySql(conn) => {
                         let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
                         let conn: &mut sqlx::MySqlConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
                             query
                                 .fetch_all(conn)
                                 .await
                                 .map(|rows| rows.into_iter().map(|r| r.into()).collect())
                                 .map_err(sqlx_error_to_query_err)
-                        })
+                        });
+                        crate::driver::sqlx_map_timeout_err(stmt.options.timeout, res)
                     }
                     #[cfg(feature = "sqlx-postgres")]
                     InnerConnection::Postgres(conn) => {
                         let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
                         let conn: &mut sqlx::PgConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
+                        let mut timeout =
+                            crate::driver::sqlx_postgres::StatementTimeout::set(conn, &stmt)
+                                .await?;
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
                             query
-                                .fetch_all(conn)
+                                .fetch_all(timeout.conn())
                                 .await
                                 .map(|rows| rows.into_iter().map(|r| r.into()).collect())
                                 .map_err(sqlx_error_to_query_err)
-                        })
+                        });
+                        timeout.reset(&stmt, res).await
                     }
                     #[cfg(feature = "sqlx-sqlite")]
                     InnerConnection::Sqlite(conn) => {
                         let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
                         let conn: &mut sqlx::SqliteConnection = &mut *conn;
-                        crate::metric::metric!(self.metric_callback, &stmt, {
+                        crate::driver::sqlx_sqlite::set_statement_timeout(conn, &stmt).await?;
+                        let res = crate::metric::metric!(self.metric_callback, &stmt, {
                             query
-                                .fetch_all(conn)
+                                .fetch_all(&mut *conn)
                                 .await
                                 .map(|rows| rows.into_iter().map(|r| r.into()).collect())
                                 .map_err(sqlx_error_to_query_err)
-                        })
+                        });
+                        crate::driver::sqlx_sqlite::reset_statement_timeout(conn, &stmt, res).await
                     }
                     #[cfg(feature = "rusqlite")]
                     InnerConnection::Rusqlite(conn) => conn.query_all(stmt, &self.metric_callback),
                     #[cfg(feature = "mock")]
-                    InnerConnection::Mock(conn) => conn.query_all(stmt),
+                    InnerConnection::Mock(conn) => {
+                        crate::metric::metric!(self.metric_callback, &stmt, {
+                            conn.query_all(stmt.clone())
+                        })
+                    }
                     #[cfg(feature = "proxy")]
-                    InnerConnection::Proxy(conn) => conn.query_all(stmt).await,
+                    InnerConnection::Proxy(conn) => {
+                        crate::metric::metric!(self.metric_callback, &stmt, {
+                            conn.query_all(stmt.clone()).await
+                        })
+                    }
                     #[allow(unreachable_patterns)]
                     _ => Err(conn_err("Disconnected")),
                 }
@@ -658,35 +976,8 @@ impl ConnectionTrait for DatabaseTransaction {
     }
 }
 
-#[cfg(feature = "stream")]
-impl StreamTrait for DatabaseTransaction {
-    type Stream<'a> = TransactionStream<'a>;
-
-    fn get_database_backend(&self) -> DbBackend {
-        self.backend
-    }
-
-    #[instrument(level = "trace", skip(stmt))]
-    fn stream_raw<'a>(
-        &'a self,
-        stmt: Statement,
-    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
-        Box::pin(async move {
-            #[cfg(not(feature = "sync"))]
-            let conn = self.conn.lock().await;
-            #[cfg(feature = "sync")]
-            let conn = self.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
-            Ok(crate::TransactionStream::build(
-                conn,
-                stmt,
-                self.metric_callback.clone(),
-            ))
-        })
-    }
-}
-
 #[async_trait::async_trait]
-impl TransactionTrait for DatabaseTransaction {
+impl TransactionTrait for PinnedConnection {
     type Transaction = DatabaseTransaction;
 
     #[instrument(level = "trace")]
@@ -701,6 +992,7 @@ impl TransactionTrait for DatabaseTransaction {
             None,
         )
         .await
+        .map(|txn| self.hand_over(txn))
     }
 
     #[instrument(level = "trace")]
@@ -719,6 +1011,7 @@ impl TransactionTrait for DatabaseTransaction {
             None,
         )
         .await
+        .map(|txn| self.hand_over(txn))
     }
 
     #[instrument(level = "trace")]
@@ -736,6 +1029,7 @@ impl TransactionTrait for DatabaseTransaction {
             options.sqlite_transaction_mode,
         )
         .await
+        .map(|txn| self.hand_over(txn))
     }
 
     /// Execute the async function inside a transaction.
@@ -781,6 +1075,53 @@ impl TransactionTrait for DatabaseTransaction {
     }
 }
 
+impl PinnedConnection {
+    /// `stmt`, not to be prepared by name if statement caching is disabled
+    pub(crate) fn statement(&self, stmt: Statement) -> Statement {
+        if self.persistent_statements {
+            stmt
+        } else {
+            stmt.persistent(false)
+        }
+    }
+
+    /// Let a transaction begun on this handle keep holding the connection
+    #[allow(unused_mut)]
+    fn hand_over(&self, mut txn: DatabaseTransaction) -> DatabaseTransaction {
+        txn.conn.persistent_statements = self.persistent_statements;
+        #[cfg(feature = "sqlx-dep")]
+        {
+            txn.conn.gate = self.gate.clone();
+        }
+        txn
+    }
+
+    /// Run `f` with the raw sqlx connection, see
+    /// [`DatabaseTransaction::with_raw_sqlx`]
+    #[cfg(feature = "sqlx-dep")]
+    pub async fn with_raw_sqlx<F, R>(&self, f: F) -> Result<R, DbErr>
+    where
+        F: AsyncFnOnce(SqlxConnection<'_>) -> R,
+    {
+        #[cfg(not(feature = "sync"))]
+        let conn = &mut *self.conn.lock().await;
+        #[cfg(feature = "sync")]
+        let conn = &mut *self.conn.lock().map_err(|_| DbErr::MutexPoisonError)?;
+
+        let conn = match conn {
+            #[cfg(feature = "sqlx-mysql")]
+            InnerConnection::MySql(c) => SqlxConnection::MySql(c),
+            #[cfg(feature = "sqlx-postgres")]
+            InnerConnection::Postgres(c) => SqlxConnection::Postgres(c),
+            #[cfg(feature = "sqlx-sqlite")]
+            InnerConnection::Sqlite(c) => SqlxConnection::Sqlite(c),
+            #[allow(unreachable_patterns)]
+            _ => return Err(conn_err("Not a sqlx connection")),
+        };
+        Ok(f(conn).await)
+    }
+}
+
 /// Error returned by [`TransactionTrait::transaction`](crate::TransactionTrait::transaction):
 /// either the database itself failed, or the user closure returned an `Err`
 /// (causing a rollback).
@@ -28,6 +28,7 @@ pub struct MockDatabaseConnection {
     execute_counter: AtomicUsize,
     query_counter: AtomicUsize,
     mocker: Mutex<Box<dyn MockDatabaseTrait>>,
+    metric_callback: Mutex<Option<crate::metric::Callback>>,
 }
 
 /// A Trait for any type wanting to perform operations on the [MockDatabase]
@@ -55,6 +56,11 @@ pub trait MockDatabaseTrait: Send + Debug {
 
     /// Ping the [MockDatabase]
     fn ping(&self) -> Result<(), DbErr>;
+
+    /// Check that all expected statements were received, returning a report otherwise
+    fn verify(&mut self) -> Result<(), String> {
+        Ok(())
+    }
 }
 
 impl MockDatabaseConnector {
@@ -124,6 +130,7 @@ impl MockDatabaseConnection {
             execute_counter: AtomicUsize::new(0),
             query_counter: AtomicUsize::new(0),
             mocker: Mutex::new(Box::new(m)),
+            metric_callback: Mutex::new(None),
         }
     }
 
@@ -240,6 +247,31 @@ impl MockDatabaseConnection {
     pub fn ping(&self) -> Result<(), DbErr> {
         self.mocker.lock().map_err(query_err)?.ping()
     }
+
+    pub(crate) fn metric_callback(&self) -> Option<crate::metric::Callback> {
+        self.metric_callback
+            .lock()
+            .expect("Fail to acquire metric callback")
+            .clone()
+    }
+
+    pub(crate) fn set_metric_callback(&self, callback: crate::metric::Callback) {
+        *self
+            .metric_callback
+            .lock()
+            .expect("Fail to acquire metric callback") = Some(callback);
+    }
+
+    /// Check that every statement expected by the [MockDatabase] was received
+    ///
+    /// # Panics
+    ///
+    /// Panics with a report of missing and unexpected statements, or if the lock cannot be acquired.
+    pub fn verify(&self) {
+        if let Err(report) = self.mocker.lock().expect("Fail to acquire mocker").verify() {
+            panic!("{report}");
+        }
+    }
 }
 
 #[cfg(feature = "stream")]
@@ -261,6 +293,21 @@ impl
     }
 }
 
+impl crate::PinnedConnection {
+    pub(crate) fn new_mock(inner: Arc<crate::MockDatabaseConnection>) -> Self {
+        use futures_util::lock::Mutex;
+        Self {
+            backend: inner.get_database_backend(),
+            metric_callback: inner.metric_callback(),
+            conn: Arc::new(Mutex::new(crate::InnerConnection::Mock(inner))),
+            record_stmt_in_spans: true,
+            persistent_statements: true,
+            #[cfg(feature = "sqlx-dep")]
+            gate: None,
+        }
+    }
+}
+
 impl crate::DatabaseTransaction {
     pub(crate) async fn new_mock(
         inner: Arc<crate::MockDatabaseConnection>,
@@ -5,6 +5,12 @@ use crate::{
 use std::{fmt::Debug, sync::Arc};
 use tracing::instrument;
 
+#[cfg(all(feature = "stream", not(feature = "sync")))]
+type PinBoxStream =
+    std::pin::Pin<Box<dyn futures_util::Stream<Item = Result<QueryResult, DbErr>> + Send>>;
+#[cfg(all(feature = "stream", feature = "sync"))]
+type PinBoxStream = Box<dyn Iterator<Item = Result<QueryResult, DbErr>>>;
+
 /// Defines a database driver for the [ProxyDatabase]
 #[derive(Debug)]
 pub struct ProxyDatabaseConnector;
@@ -14,6 +20,7 @@ pub struct ProxyDatabaseConnector;
 pub struct ProxyDatabaseConnection {
     db_backend: DbBackend,
     proxy: Arc<Box<dyn ProxyDatabaseTrait>>,
+    metric_callback: std::sync::Mutex<Option<crate::metric::Callback>>,
 }
 
 impl ProxyDatabaseConnector {
@@ -46,6 +53,7 @@ impl ProxyDatabaseConnection {
         Self {
             db_backend,
             proxy: funcs.to_owned(),
+            metric_callback: Default::default(),
         }
     }
 
@@ -90,6 +98,33 @@ impl ProxyDatabaseConnection {
             .collect())
     }
 
+    /// Return [QueryResult]s from the stream served by the [ProxyDatabase]
+    #[cfg(feature = "stream")]
+    #[instrument(level = "trace", skip(statement))]
+    pub fn fetch(&self, statement: &Statement) -> PinBoxStream {
+        debug_print!("{}", statement);
+        #[cfg(not(feature = "sync"))]
+        {
+            use futures_util::TryStreamExt;
+
+            let rows =
+                futures_util::stream::once(proxy_stream(self.proxy.clone(), statement.clone()));
+            Box::pin(rows.try_flatten().map_ok(Into::into))
+        }
+        #[cfg(feature = "sync")]
+        {
+            match self.proxy.stream(statement.clone()) {
+                Ok(rows) => Box::new(rows.map(|row| row.map(Into::into))),
+                Err(e) => Box::new(Some(Err(e)).into_iter()),
+            }
+        }
+    }
+
+    /// Check if the [ProxyDatabase] supports `RETURNING` syntax on insert and update
+    pub fn support_returning(&self) -> bool {
+        self.proxy.support_returning(self.db_backend)
+    }
+
     /// Create a statement block  of SQL statements that execute together.
     #[instrument(level = "trace")]
     pub async fn begin(&self) {
@@ -118,6 +153,28 @@ impl ProxyDatabaseConnection {
     pub async fn ping(&self) -> Result<(), DbErr> {
         self.proxy.ping().await
     }
+
+    pub(crate) fn metric_callback(&self) -> Option<crate::metric::Callback> {
+        self.metric_callback
+            .lock()
+            .expect("Fail to acquire metric callback")
+            .clone()
+    }
+
+    pub(crate) fn set_metric_callback(&self, callback: crate::metric::Callback) {
+        *self
+            .metric_callback
+            .lock()
+            .expect("Fail to acquire metric callback") = Some(callback);
+    }
+}
+
+#[cfg(all(feature = "stream", not(feature = "sync")))]
+async fn proxy_stream(
+    proxy: Arc<Box<dyn ProxyDatabaseTrait>>,
+    statement: Statement,
+) -> Result<crate::ProxyRowStream, DbErr> {
+    proxy.stream(statement).await
 }
 
 #[cfg(feature = "stream")]
@@ -146,7 +203,8 @@ impl crate::DatabaseTransaction {
     ) -> Result<crate::DatabaseTransaction, DbErr> {
         use futures_util::lock::Mutex;
         let backend = inner.get_database_backend();
-        Self::begin(
+        let support_returning = inner.support_returning();
+        let mut txn = Self::begin(
             Arc::new(Mutex::new(crate::InnerConnection::Proxy(inner))),
             backend,
             metric_callback,
@@ -155,6 +213,8 @@ impl crate::DatabaseTransaction {
             None,
             None,
         )
-        .await
+        .await?;
+        txn.support_returning = support_returning;
+        Ok(txn)
     }
 }
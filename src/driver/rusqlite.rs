@@ -2,7 +2,8 @@
 use std::time::Instant;
 use std::{
     ops::Deref,
-    sync::{Arc, Mutex, MutexGuard, TryLockError},
+    sync::{Arc, Mutex, MutexGuard, TryLockError, mpsc},
+    thread,
     time::Duration,
 };
 use tracing::{debug, instrument, warn};
@@ -289,7 +290,11 @@ impl RusqliteConnector {
 
 impl RusqliteSharedConnection {
     pub fn acquire(&self) -> Result<MutexGuard<'_, State>, DbErr> {
-        let deadline = Instant::now() + self.acquire_timeout;
+        self.acquire_within(self.acquire_timeout)
+    }
+
+    fn acquire_within(&self, timeout: Duration) -> Result<MutexGuard<'_, State>, DbErr> {
+        let deadline = Instant::now() + timeout;
         loop {
             match self.conn.try_lock() {
                 Ok(state) => match *state {
@@ -331,6 +336,7 @@ impl RusqliteSharedConnection {
         let values = sql_values(&stmt);
         let conn = self.acquire()?;
         let conn = conn.conn();
+        let timeout = StatementTimeout::start(conn, &stmt)?;
         crate::metric::metric!(self.metric_callback, &stmt, {
             match conn.execute(&stmt.sql, &*values.as_params()) {
                 Ok(rows_affected) => Ok(RusqliteExecResult {
@@ -338,11 +344,38 @@ impl RusqliteSharedConnection {
                     last_insert_rowid: conn.last_insert_rowid(),
                 }
                 .into()),
-                Err(err) => Err(exec_err(err)),
+                Err(err) => Err(timeout.exec_err(err)),
             }
         })
     }
 
+    /// Execute a batch of [Statement]s in order while holding the connection,
+    /// stopping at the first error
+    #[instrument(level = "trace", skip(stmts))]
+    pub fn execute_batch(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        let conn = self.acquire()?;
+        let conn = conn.conn();
+        let mut results = Vec::with_capacity(stmts.len());
+        for stmt in stmts {
+            debug!("{}", stmt);
+
+            let values = sql_values(&stmt);
+            let timeout = StatementTimeout::start(conn, &stmt)?;
+            let res = crate::metric::metric!(self.metric_callback, &stmt, {
+                match conn.execute(&stmt.sql, &*values.as_params()) {
+                    Ok(rows_affected) => Ok(RusqliteExecResult {
+                        rows_affected: rows_affected as u64,
+                        last_insert_rowid: conn.last_insert_rowid(),
+                    }
+                    .into()),
+                    Err(err) => Err(timeout.exec_err(err)),
+                }
+            })?;
+            results.push(res);
+        }
+        Ok(results)
+    }
+
     /// Execute an unprepared SQL statement on a SQLite backend
     #[instrument(level = "trace", skip(sql))]
     pub fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
@@ -371,16 +404,17 @@ impl RusqliteSharedConnection {
         let mut sql = conn.prepare_cached(&stmt.sql).map_err(query_err)?;
         let columns: Vec<Arc<str>> = column_names(&sql);
 
+        let timeout = StatementTimeout::start(conn, &stmt)?;
         crate::metric::metric!(self.metric_callback, &stmt, {
             match sql.query(&*values.as_params()) {
                 Ok(mut rows) => {
                     let mut out = None;
-                    if let Some(row) = rows.next().map_err(query_err)? {
+                    if let Some(row) = rows.next().map_err(|err| timeout.query_err(err))? {
                         out = Some(OwnedRow::from_row(columns.clone(), row).into());
                     }
                     Ok(out)
                 }
-                Err(err) => Err(query_err(err)),
+                Err(err) => Err(timeout.query_err(err)),
             }
         })
     }
@@ -396,16 +430,17 @@ impl RusqliteSharedConnection {
         let mut sql = conn.prepare_cached(&stmt.sql).map_err(query_err)?;
         let columns: Vec<Arc<str>> = column_names(&sql);
 
+        let timeout = StatementTimeout::start(conn, &stmt)?;
         crate::metric::metric!(self.metric_callback, &stmt, {
             match sql.query(&*values.as_params()) {
                 Ok(mut rows) => {
                     let mut out = Vec::new();
-                    while let Some(row) = rows.next().map_err(query_err)? {
+                    while let Some(row) = rows.next().map_err(|err| timeout.query_err(err))? {
                         out.push(OwnedRow::from_row(columns.clone(), row).into());
                     }
                     Ok(out)
                 }
-                Err(err) => Err(query_err(err)),
+                Err(err) => Err(timeout.query_err(err)),
             }
         })
     }
@@ -460,11 +495,12 @@ impl RusqliteSharedConnection {
             .run(callback)
     }
 
-    pub(crate) fn set_metric_callback<F>(&mut self, callback: F)
-    where
-        F: Fn(&crate::metric::Info<'_>) + 'static,
-    {
-        self.metric_callback = Some(Arc::new(callback));
+    pub(crate) fn metric_callback(&self) -> Option<crate::metric::Callback> {
+        self.metric_callback.clone()
+    }
+
+    pub(crate) fn set_metric_callback(&mut self, callback: crate::metric::Callback) {
+        self.metric_callback = Some(callback);
     }
 
     /// Checks if a connection to the database is still valid.
@@ -484,11 +520,22 @@ impl RusqliteSharedConnection {
         self.close_by_ref()
     }
 
-    /// Explicitly close the SQLite connection
+    /// Explicitly close the SQLite connection. Closing it again is a no-op.
     pub fn close_by_ref(&self) -> Result<(), DbErr> {
-        let mut conn = self.acquire()?;
-        *conn = State::Disconnected;
-        Ok(())
+        self.close_with_timeout(self.acquire_timeout)
+    }
+
+    /// Close the SQLite connection, waiting at most `timeout` for it to be returned
+    /// if it's in use by a transaction or stream.
+    pub fn close_with_timeout(&self, timeout: Duration) -> Result<(), DbErr> {
+        match self.acquire_within(timeout) {
+            Ok(mut conn) => {
+                *conn = State::Disconnected;
+                Ok(())
+            }
+            Err(DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed)) => Ok(()),
+            Err(err) => Err(err),
+        }
     }
 }
 
@@ -503,6 +550,7 @@ impl RusqliteInnerConnection {
 
         let values = sql_values(&stmt);
         let conn = self.conn.conn();
+        let timeout = StatementTimeout::start(conn, &stmt)?;
         crate::metric::metric!(metric_callback, &stmt, {
             match conn.execute(&stmt.sql, &*values.as_params()) {
                 Ok(rows_affected) => Ok(RusqliteExecResult {
@@ -510,7 +558,7 @@ impl RusqliteInnerConnection {
                     last_insert_rowid: conn.last_insert_rowid(),
                 }
                 .into()),
-                Err(err) => Err(exec_err(err)),
+                Err(err) => Err(timeout.exec_err(err)),
             }
         })
     }
@@ -543,16 +591,17 @@ impl RusqliteInnerConnection {
         let mut sql = conn.prepare_cached(&stmt.sql).map_err(query_err)?;
         let columns: Vec<Arc<str>> = column_names(&sql);
 
+        let timeout = StatementTimeout::start(conn, &stmt)?;
         crate::metric::metric!(metric_callback, &stmt, {
             match sql.query(&*values.as_params()) {
                 Ok(mut rows) => {
                     let mut out = None;
-                    if let Some(row) = rows.next().map_err(query_err)? {
+                    if let Some(row) = rows.next().map_err(|err| timeout.query_err(err))? {
                         out = Some(OwnedRow::from_row(columns.clone(), row).into());
                     }
                     Ok(out)
                 }
-                Err(err) => Err(query_err(err)),
+                Err(err) => Err(timeout.query_err(err)),
             }
         })
     }
@@ -570,16 +619,17 @@ impl RusqliteInnerConnection {
         let mut sql = conn.prepare_cached(&stmt.sql).map_err(query_err)?;
         let columns: Vec<Arc<str>> = column_names(&sql);
 
+        let timeout = StatementTimeout::start(conn, &stmt)?;
         crate::metric::metric!(metric_callback, &stmt, {
             match sql.query(&*values.as_params()) {
                 Ok(mut rows) => {
                     let mut out = Vec::new();
-                    while let Some(row) = rows.next().map_err(query_err)? {
+                    while let Some(row) = rows.next().map_err(|err| timeout.query_err(err))? {
                         out.push(OwnedRow::from_row(columns.clone(), row).into());
                     }
                     Ok(out)
                 }
-                Err(err) => Err(query_err(err)),
+                Err(err) => Err(timeout.query_err(err)),
             }
         })
     }
@@ -593,15 +643,16 @@ impl RusqliteInnerConnection {
         let mut sql = conn.prepare_cached(&stmt.sql).map_err(query_err)?;
         let columns: Vec<Arc<str>> = column_names(&sql);
 
+        let timeout = StatementTimeout::start(conn, stmt)?;
         let rows = match sql.query(&*values.as_params()) {
             Ok(mut rows) => {
                 let mut out = Vec::new();
-                while let Some(row) = rows.next().map_err(query_err)? {
+                while let Some(row) = rows.next().map_err(|err| timeout.query_err(err))? {
                     out.push(OwnedRow::from_row(columns.clone(), row).into());
                 }
                 out
             }
-            Err(err) => return Err(query_err(err)),
+            Err(err) => return Err(timeout.query_err(err)),
         };
 
         Ok(rows)
@@ -722,11 +773,7 @@ fn column_names(sql: &CachedStatement) -> Vec<Arc<str>> {
 
 #[cfg(feature = "sqlite-use-returning-for-3_35")]
 fn get_version(conn: &RusqliteSharedConnection) -> Result<String, DbErr> {
-    let stmt = Statement {
-        sql: "SELECT sqlite_version()".to_string(),
-        values: None,
-        db_backend: crate::DbBackend::Sqlite,
-    };
+    let stmt = Statement::from_string(crate::DbBackend::Sqlite, "SELECT sqlite_version()");
     conn.query_one(stmt)?
         .ok_or_else(|| {
             DbErr::Conn(RuntimeErr::Internal(
@@ -747,3 +794,63 @@ fn exec_err(err: RusqliteError) -> DbErr {
 fn query_err(err: RusqliteError) -> DbErr {
     DbErr::Query(RuntimeErr::Rusqlite(err.into()))
 }
+
+/// Interrupts the statement running on a connection once its [Statement::timeout]
+/// passes, until dropped
+struct StatementTimeout {
+    timeout: Option<Duration>,
+    watchdog: Option<(mpsc::Sender<()>, thread::JoinHandle<()>)>,
+}
+
+impl StatementTimeout {
+    fn start(conn: &RusqliteConnection, stmt: &Statement) -> Result<Self, DbErr> {
+        let Some(timeout) = stmt.options.timeout else {
+            return Ok(Self {
+                timeout: None,
+                watchdog: None,
+            });
+        };
+        let interrupt = conn.get_interrupt_handle();
+        let (done, finished) = mpsc::channel::<()>();
+        let watchdog = thread::Builder::new()
+            .name("sea-orm-statement-timeout".to_owned())
+            .spawn(move || {
+                if let Err(mpsc::RecvTimeoutError::Timeout) = finished.recv_timeout(timeout) {
+                    interrupt.interrupt();
+                }
+            })
+            .map_err(|err| {
+                DbErr::Exec(RuntimeErr::Internal(format!(
+                    "Failed to start statement timeout: {err}"
+                )))
+            })?;
+        Ok(Self {
+            timeout: Some(timeout),
+            watchdog: Some((done, watchdog)),
+        })
+    }
+
+    fn exec_err(&self, err: RusqliteError) -> DbErr {
+        self.timeout_err(&err).unwrap_or_else(|| exec_err(err))
+    }
+
+    fn query_err(&self, err: RusqliteError) -> DbErr {
+        self.timeout_err(&err).unwrap_or_else(|| query_err(err))
+    }
+
+    fn timeout_err(&self, err: &RusqliteError) -> Option<DbErr> {
+        let timeout = self.timeout?;
+        (err.sqlite_error_code() == Some(rusqlite::ErrorCode::OperationInterrupted))
+            .then_some(DbErr::QueryTimeout(timeout))
+    }
+}
+
+impl Drop for StatementTimeout {
+    fn drop(&mut self) {
+        if let Some((done, watchdog)) = self.watchdog.take() {
+            drop(done);
+            // wait for the watchdog, so a late interrupt can't hit the next statement
+            let _ = watchdog.join();
+        }
+    }
+}
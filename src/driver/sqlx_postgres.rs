@@ -1,20 +1,28 @@
 use futures_util::lock::Mutex;
 use log::LevelFilter;
 use sea_query::Values;
-use std::{fmt::Write, future::Future, pin::Pin, sync::Arc};
+use std::{
+    fmt::Write,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, atomic::AtomicBool},
+    time::Duration,
+};
 
 use sqlx::{
     Connection, Executor, PgPool, Postgres,
     pool::PoolConnection,
-    postgres::{PgConnectOptions, PgQueryResult, PgRow},
+    postgres::{PgConnectOptions, PgConnection, PgQueryResult, PgRow, PgTransactionManager},
 };
+use sqlx_core::{sql_str::SqlSafeStr, transaction::TransactionManager};
 
 use sea_query_sqlx::SqlxValues;
-use tracing::instrument;
+use tracing::{instrument, warn};
 
 use crate::{
     AccessMode, ConnectOptions, DatabaseConnection, DatabaseConnectionType, DatabaseTransaction,
-    IsolationLevel, Statement, TransactionError, debug_print, error::*, executor::*,
+    IsolationLevel, SqlxSingleConnection, Statement, TransactionError, debug_print, error::*,
+    executor::*,
 };
 
 use super::sqlx_common::*;
@@ -30,8 +38,14 @@ pub struct SqlxPostgresConnector;
 #[derive(Clone)]
 pub struct SqlxPostgresPoolConnection {
     pub(crate) pool: PgPool,
+    /// Cancels the calls still running once [`close_with_timeout`](Self::close_with_timeout)
+    /// times out
+    close_deadline: CloseDeadline,
     metric_callback: Option<crate::metric::Callback>,
     pub(crate) record_stmt_in_spans: bool,
+    /// Cleared when statement caching is disabled: sqlx would still prepare statements
+    /// by name, and never close them
+    persistent_statements: bool,
 }
 
 impl std::fmt::Debug for SqlxPostgresPoolConnection {
@@ -44,8 +58,10 @@ impl From<PgPool> for SqlxPostgresPoolConnection {
     fn from(pool: PgPool) -> Self {
         SqlxPostgresPoolConnection {
             pool,
+            close_deadline: CloseDeadline::default(),
             metric_callback: None,
             record_stmt_in_spans: true,
+            persistent_statements: true,
         }
     }
 }
@@ -91,6 +107,11 @@ impl SqlxPostgresConnector {
             sqlx_opts = sqlx_opts.options([("statement_timeout", timeout.as_millis().to_string())]);
         }
 
+        let persistent_statements = options.statement_cache_capacity != Some(0);
+        if let Some(capacity) = options.statement_cache_capacity {
+            sqlx_opts = sqlx_opts.statement_cache_capacity(capacity);
+        }
+
         if let Some(f) = &options.pg_opts_fn {
             sqlx_opts = f(sqlx_opts);
         }
@@ -117,6 +138,7 @@ impl SqlxPostgresConnector {
         let lazy = options.connect_lazy;
         let after_connect = options.after_connect.clone();
         let pg_pool_opts_fn = options.pg_pool_opts_fn.clone();
+        let warmup_connections = options.warmup_connections;
         let mut pool_options = options.sqlx_pool_options();
 
         if let Some(sql) = set_search_path_sql {
@@ -135,17 +157,23 @@ impl SqlxPostgresConnector {
         let pool = if lazy {
             pool_options.connect_lazy_with(sqlx_opts)
         } else {
-            pool_options
+            let pool = pool_options
                 .connect_with(sqlx_opts)
                 .await
-                .map_err(sqlx_error_to_conn_err)?
+                .map_err(sqlx_error_to_conn_err)?;
+            if let Some(connections) = warmup_connections {
+                warm_up(&pool, connections).await?;
+            }
+            pool
         };
 
         let conn: DatabaseConnection =
             DatabaseConnectionType::SqlxPostgresPoolConnection(SqlxPostgresPoolConnection {
                 pool,
+                close_deadline: CloseDeadline::default(),
                 metric_callback: None,
                 record_stmt_in_spans,
+                persistent_statements,
             })
             .into();
 
@@ -162,78 +190,150 @@ impl SqlxPostgresConnector {
     pub fn from_sqlx_postgres_pool(pool: PgPool) -> DatabaseConnection {
         DatabaseConnectionType::SqlxPostgresPoolConnection(SqlxPostgresPoolConnection {
             pool,
+            close_deadline: CloseDeadline::default(),
             metric_callback: None,
             record_stmt_in_spans: true,
+            persistent_statements: true,
         })
         .into()
     }
+
+    /// Instantiate a [DatabaseConnection] from a single sqlx connection, e.g. in a
+    /// serverless function that opens one connection per invocation.
+    ///
+    /// All clones of the returned [DatabaseConnection] share this one connection behind
+    /// a mutex, so access is serialized: concurrent statements wait for each other, and
+    /// while a transaction is open, statements run outside of it wait for it to end, so
+    /// a task holding a transaction must not use the [DatabaseConnection] itself.
+    /// Streams fetch all rows up front. The connection is closed once the last clone is
+    /// dropped.
+    pub fn from_sqlx_postgres_connection(conn: PgConnection) -> DatabaseConnection {
+        SqlxSingleConnection::new(
+            crate::InnerConnection::Postgres(SqlxInnerConnection::Single(conn)),
+            crate::DbBackend::Postgres,
+        )
+        .into()
+    }
 }
 
 impl SqlxPostgresPoolConnection {
     /// Execute a [Statement] on a PostgreSQL backend
     #[instrument(level = "trace", skip(stmt))]
     pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
-        debug_print!("{}", stmt);
+        self.close_deadline
+            .run(&self.pool, async {
+                let stmt = self.statement(stmt);
+                debug_print!("{}", stmt);
+
+                let query = sqlx_query(&stmt);
+                let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+                let mut timeout = StatementTimeout::set(&mut conn, &stmt).await?;
+                let res = crate::metric::metric!(self.metric_callback, &stmt, {
+                    match query.execute(timeout.conn()).await {
+                        Ok(res) => Ok(res.into()),
+                        Err(err) => Err(sqlx_error_to_exec_err(err)),
+                    }
+                });
+                timeout.reset(&stmt, res).await
+            })
+            .await?
+    }
 
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.execute(&mut *conn).await {
-                Ok(res) => Ok(res.into()),
-                Err(err) => Err(sqlx_error_to_exec_err(err)),
-            }
-        })
+    /// Execute a batch of [Statement]s in order on a single pooled connection,
+    /// stopping at the first error
+    #[instrument(level = "trace", skip(stmts))]
+    pub async fn execute_batch(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        self.close_deadline
+            .run(&self.pool, async {
+                let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+                let mut results = Vec::with_capacity(stmts.len());
+                for stmt in stmts {
+                    let stmt = self.statement(stmt);
+                    debug_print!("{}", stmt);
+
+                    let query = sqlx_query(&stmt);
+                    let mut timeout = StatementTimeout::set(&mut conn, &stmt).await?;
+                    let res = crate::metric::metric!(self.metric_callback, &stmt, {
+                        match query.execute(timeout.conn()).await {
+                            Ok(res) => Ok(res.into()),
+                            Err(err) => Err(sqlx_error_to_exec_err(err)),
+                        }
+                    });
+                    results.push(timeout.reset(&stmt, res).await?);
+                }
+                Ok(results)
+            })
+            .await?
     }
 
     /// Execute an unprepared SQL statement on a PostgreSQL backend
     #[instrument(level = "trace", skip(sql))]
     pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
-        debug_print!("{}", sql);
-
-        let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        match conn.execute(sqlx::AssertSqlSafe(sql.to_owned())).await {
-            Ok(res) => Ok(res.into()),
-            Err(err) => Err(sqlx_error_to_exec_err(err)),
-        }
+        self.close_deadline
+            .run(&self.pool, async {
+                debug_print!("{}", sql);
+
+                let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+                match conn.execute(sqlx::AssertSqlSafe(sql.to_owned())).await {
+                    Ok(res) => Ok(res.into()),
+                    Err(err) => Err(sqlx_error_to_exec_err(err)),
+                }
+            })
+            .await?
     }
 
     /// Get one result from a SQL query. Returns [Option::None] if no match was found
     #[instrument(level = "trace", skip(stmt))]
     pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
-        debug_print!("{}", stmt);
-
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.fetch_one(&mut *conn).await {
-                Ok(row) => Ok(Some(row.into())),
-                Err(err) => match err {
-                    sqlx::Error::RowNotFound => Ok(None),
-                    _ => Err(sqlx_error_to_query_err(err)),
-                },
-            }
-        })
+        self.close_deadline
+            .run(&self.pool, async {
+                let stmt = self.statement(stmt);
+                debug_print!("{}", stmt);
+
+                let query = sqlx_query(&stmt);
+                let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+                let mut timeout = StatementTimeout::set(&mut conn, &stmt).await?;
+                let res = crate::metric::metric!(self.metric_callback, &stmt, {
+                    match query.fetch_one(timeout.conn()).await {
+                        Ok(row) => Ok(Some(row.into())),
+                        Err(err) => match err {
+                            sqlx::Error::RowNotFound => Ok(None),
+                            _ => Err(sqlx_error_to_query_err(err)),
+                        },
+                    }
+                });
+                timeout.reset(&stmt, res).await
+            })
+            .await?
     }
 
     /// Get the results of a query returning them as a Vec<[QueryResult]>
     #[instrument(level = "trace", skip(stmt))]
     pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
-        debug_print!("{}", stmt);
-
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.fetch_all(&mut *conn).await {
-                Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
-                Err(err) => Err(sqlx_error_to_query_err(err)),
-            }
-        })
+        self.close_deadline
+            .run(&self.pool, async {
+                let stmt = self.statement(stmt);
+                debug_print!("{}", stmt);
+
+                let query = sqlx_query(&stmt);
+                let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+                let mut timeout = StatementTimeout::set(&mut conn, &stmt).await?;
+                let res = crate::metric::metric!(self.metric_callback, &stmt, {
+                    match query.fetch_all(timeout.conn()).await {
+                        Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
+                        Err(err) => Err(sqlx_error_to_query_err(err)),
+                    }
+                });
+                timeout.reset(&stmt, res).await
+            })
+            .await?
     }
 
     /// Stream the results of executing a SQL query
     #[instrument(level = "trace", skip(stmt))]
     #[cfg(feature = "stream")]
     pub async fn stream(&self, stmt: Statement) -> Result<QueryStream, DbErr> {
+        let stmt = self.statement(stmt);
         debug_print!("{}", stmt);
 
         let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
@@ -256,12 +356,33 @@ impl SqlxPostgresPoolConnection {
             conn,
             self.metric_callback.clone(),
             self.record_stmt_in_spans,
+            self.persistent_statements,
             isolation_level,
             access_mode,
         )
         .await
     }
 
+    /// Acquire a connection from the pool and hold on to it. It goes back to the pool
+    /// when dropped if `restored` is set by then, and is closed otherwise.
+    #[instrument(level = "trace")]
+    pub(crate) async fn pin(
+        &self,
+        restored: Arc<AtomicBool>,
+    ) -> Result<crate::PinnedConnection, DbErr> {
+        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        Ok(crate::PinnedConnection {
+            conn: Arc::new(Mutex::new(crate::InnerConnection::Postgres(
+                SqlxInnerConnection::Pinned(conn, restored),
+            ))),
+            backend: crate::DbBackend::Postgres,
+            metric_callback: self.metric_callback.clone(),
+            record_stmt_in_spans: self.record_stmt_in_spans,
+            persistent_statements: self.persistent_statements,
+            gate: None,
+        })
+    }
+
     /// Create a PostgreSQL transaction
     #[instrument(level = "trace", skip(callback))]
     pub async fn transaction<F, T, E>(
@@ -278,33 +399,53 @@ impl SqlxPostgresPoolConnection {
         T: Send,
         E: std::fmt::Display + std::fmt::Debug + Send,
     {
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        let transaction = DatabaseTransaction::new_postgres(
-            conn,
-            self.metric_callback.clone(),
-            self.record_stmt_in_spans,
-            isolation_level,
-            access_mode,
-        )
-        .await
-        .map_err(|e| TransactionError::Connection(e))?;
-        transaction.run(callback).await
+        self.close_deadline
+            .run(&self.pool, async {
+                let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+                let transaction = DatabaseTransaction::new_postgres(
+                    conn,
+                    self.metric_callback.clone(),
+                    self.record_stmt_in_spans,
+                    self.persistent_statements,
+                    isolation_level,
+                    access_mode,
+                )
+                .await
+                .map_err(|e| TransactionError::Connection(e))?;
+                transaction.run(callback).await
+            })
+            .await
+            .map_err(|e| TransactionError::Connection(e))?
     }
 
-    pub(crate) fn set_metric_callback<F>(&mut self, callback: F)
-    where
-        F: Fn(&crate::metric::Info<'_>) + Send + Sync + 'static,
-    {
-        self.metric_callback = Some(Arc::new(callback));
+    /// `stmt`, not to be prepared by name if statement caching is disabled
+    fn statement(&self, stmt: Statement) -> Statement {
+        if self.persistent_statements {
+            stmt
+        } else {
+            stmt.persistent(false)
+        }
+    }
+
+    pub(crate) fn metric_callback(&self) -> Option<crate::metric::Callback> {
+        self.metric_callback.clone()
+    }
+
+    pub(crate) fn set_metric_callback(&mut self, callback: crate::metric::Callback) {
+        self.metric_callback = Some(callback);
     }
 
     /// Checks if a connection to the database is still valid.
     pub async fn ping(&self) -> Result<(), DbErr> {
-        let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        match conn.ping().await {
-            Ok(_) => Ok(()),
-            Err(err) => Err(sqlx_error_to_conn_err(err)),
-        }
+        self.close_deadline
+            .run(&self.pool, async {
+                let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+                match conn.ping().await {
+                    Ok(_) => Ok(()),
+                    Err(err) => Err(sqlx_error_to_conn_err(err)),
+                }
+            })
+            .await?
     }
 
     /// Explicitly close the Postgres connection.
@@ -313,11 +454,25 @@ impl SqlxPostgresPoolConnection {
         self.close_by_ref().await
     }
 
-    /// Explicitly close the Postgres connection
+    /// Explicitly close the Postgres connection. Closing it again is a no-op.
     pub async fn close_by_ref(&self) -> Result<(), DbErr> {
         self.pool.close().await;
         Ok(())
     }
+
+    /// Close the Postgres connection pool, waiting at most `timeout` for connections in use
+    /// to be returned. New connections can't be acquired from the moment this is called.
+    ///
+    /// Once the timeout elapses, the queries still running on the pool are cancelled,
+    /// failing with [`ConnAcquireErr::ConnectionClosed`], and their connections closed.
+    /// A connection held by a transaction or a stream is closed as soon as it's dropped.
+    pub async fn close_with_timeout(&self, timeout: Duration) -> Result<(), DbErr> {
+        self.close_deadline.set(timeout);
+        if rt_timeout(timeout, self.pool.close()).await.is_none() {
+            warn!("Timed out waiting for Postgres connections in use to be released, closing them");
+        }
+        Ok(())
+    }
 }
 
 impl From<PgRow> for QueryResult {
@@ -342,10 +497,109 @@ pub(crate) fn sqlx_query(stmt: &Statement) -> sqlx::query::Query<'_, Postgres, S
         .as_ref()
         .map_or(Values(Vec::new()), |values| values.clone());
     sqlx::query_with(sqlx::AssertSqlSafe(stmt.sql.as_str()), SqlxValues(values))
+        .persistent(stmt.options.persistent)
+}
+
+/// The `statement_timeout` of a [Statement] with a timeout, set with `SET LOCAL` so the
+/// server cancels the statement once the deadline passes, and the setting never
+/// outlives the transaction it is set in.
+///
+/// Outside of a transaction, one is begun along with the `SET LOCAL`, in a single round
+/// trip, and ended by [reset](Self::reset). If the statement's future is dropped before,
+/// the transaction is rolled back as soon as the connection is used again or returned to
+/// the pool. Inside of a transaction, the previous setting is read while setting the new
+/// one, and put back by [reset](Self::reset), or else when the transaction ends.
+pub(crate) struct StatementTimeout<'c> {
+    conn: &'c mut PgConnection,
+    state: TimeoutState,
+}
+
+enum TimeoutState {
+    Unset,
+    Begun,
+    Previous(String),
+}
+
+impl<'c> StatementTimeout<'c> {
+    pub(crate) async fn set(conn: &'c mut PgConnection, stmt: &Statement) -> Result<Self, DbErr> {
+        let Some(timeout) = stmt.options.timeout else {
+            return Ok(Self {
+                conn,
+                state: TimeoutState::Unset,
+            });
+        };
+        // a timeout of 0 disables it
+        let millis = timeout.as_millis().max(1);
+        let state = if PgTransactionManager::get_transaction_depth(conn) == 0 {
+            let sql = format!("BEGIN; SET LOCAL statement_timeout = {millis}");
+            PgTransactionManager::begin(conn, Some(sqlx::AssertSqlSafe(sql).into_sql_str()))
+                .await
+                .map_err(sqlx_error_to_exec_err)?;
+            TimeoutState::Begun
+        } else {
+            let sql = "SELECT current_setting('statement_timeout'), \
+                set_config('statement_timeout', $1, true)";
+            let (previous, _): (String, String) = sqlx::query_as(sql)
+                .bind(millis.to_string())
+                .persistent(stmt.options.persistent)
+                .fetch_one(&mut *conn)
+                .await
+                .map_err(sqlx_error_to_query_err)?;
+            TimeoutState::Previous(previous)
+        };
+        Ok(Self { conn, state })
+    }
+
+    /// The connection to run the statement on
+    pub(crate) fn conn(&mut self) -> &mut PgConnection {
+        self.conn
+    }
+
+    /// End the transaction begun by [set](Self::set), or put back the previous setting,
+    /// after running `stmt`, turning the error of a cancelled statement into
+    /// [DbErr::QueryTimeout]
+    pub(crate) async fn reset<T>(
+        mut self,
+        stmt: &Statement,
+        res: Result<T, DbErr>,
+    ) -> Result<T, DbErr> {
+        let reset = match std::mem::replace(&mut self.state, TimeoutState::Unset) {
+            TimeoutState::Unset => Ok(()),
+            TimeoutState::Begun if res.is_ok() => PgTransactionManager::commit(self.conn)
+                .await
+                .map_err(sqlx_error_to_exec_err),
+            TimeoutState::Begun => PgTransactionManager::rollback(self.conn)
+                .await
+                .map_err(sqlx_error_to_exec_err),
+            TimeoutState::Previous(previous) if res.is_ok() => {
+                sqlx::query("SELECT set_config('statement_timeout', $1, true)")
+                    .bind(previous)
+                    .persistent(stmt.options.persistent)
+                    .execute(&mut *self.conn)
+                    .await
+                    .map(|_| ())
+                    .map_err(sqlx_error_to_exec_err)
+            }
+            // the failed statement aborted the transaction, so nothing else can run in it
+            // until it is rolled back, which undoes the setting anyway
+            TimeoutState::Previous(_) => Ok(()),
+        };
+        let res = sqlx_map_timeout_err(stmt.options.timeout, res)?;
+        reset?;
+        Ok(res)
+    }
+}
+
+impl Drop for StatementTimeout<'_> {
+    fn drop(&mut self) {
+        if let TimeoutState::Begun = self.state {
+            PgTransactionManager::start_rollback(self.conn);
+        }
+    }
 }
 
 pub(crate) async fn set_transaction_config(
-    conn: &mut PoolConnection<Postgres>,
+    conn: &mut sqlx::PgConnection,
     isolation_level: Option<IsolationLevel>,
     access_mode: Option<AccessMode>,
 ) -> Result<(), DbErr> {
@@ -362,7 +616,8 @@ pub(crate) async fn set_transaction_config(
     if !settings.is_empty() {
         let sql = format!("SET TRANSACTION {}", settings.join(" "));
         sqlx::query(sqlx::AssertSqlSafe(sql))
-            .execute(&mut **conn)
+            .persistent(false)
+            .execute(&mut *conn)
             .await
             .map_err(sqlx_error_to_exec_err)?;
     }
@@ -386,7 +641,7 @@ impl
     ) -> Self {
         crate::QueryStream::build(
             stmt,
-            crate::InnerConnection::Postgres(conn),
+            crate::InnerConnection::Postgres(conn.into()),
             metric_callback,
         )
     }
@@ -397,11 +652,12 @@ impl crate::DatabaseTransaction {
         inner: PoolConnection<sqlx::Postgres>,
         metric_callback: Option<crate::metric::Callback>,
         record_stmt_in_spans: bool,
+        persistent_statements: bool,
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<crate::DatabaseTransaction, DbErr> {
-        Self::begin(
-            Arc::new(Mutex::new(crate::InnerConnection::Postgres(inner))),
+        let mut txn = Self::begin(
+            Arc::new(Mutex::new(crate::InnerConnection::Postgres(inner.into()))),
             crate::DbBackend::Postgres,
             metric_callback,
             record_stmt_in_spans,
@@ -409,7 +665,9 @@ impl crate::DatabaseTransaction {
             access_mode,
             None,
         )
-        .await
+        .await?;
+        txn.conn.persistent_statements = persistent_statements;
+        Ok(txn)
     }
 }
 
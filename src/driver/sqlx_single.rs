@@ -0,0 +1,111 @@
+use futures_util::lock::Mutex;
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::{
+    DatabaseConnection, DatabaseConnectionType, DbBackend, DbErr, InnerConnection,
+    PinnedConnection, SqlxConnection,
+};
+
+use super::sqlx_common::*;
+
+#[cfg(feature = "stream")]
+use crate::{ConnectionTrait, QueryStream, Statement};
+
+/// Defines a single sqlx connection, shared by all clones of the
+/// [DatabaseConnection] made from it
+///
+/// Created with e.g. [`SqlxSqliteConnector::from_sqlx_sqlite_connection`](crate::SqlxSqliteConnector::from_sqlx_sqlite_connection).
+/// All access is serialized: a statement waits for the one before it to finish,
+/// and while a transaction is open, statements run on the [DatabaseConnection]
+/// wait for it to be committed or rolled back.
+#[derive(Clone)]
+pub struct SqlxSingleConnection {
+    conn: Arc<Mutex<InnerConnection>>,
+    /// Held by a statement while it runs, and by a transaction until it ends
+    gate: Arc<Mutex<()>>,
+    backend: DbBackend,
+    metric_callback: Option<crate::metric::Callback>,
+    pub(crate) record_stmt_in_spans: bool,
+}
+
+impl std::fmt::Debug for SqlxSingleConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SqlxSingleConnection {{ backend: {:?} }}", self.backend)
+    }
+}
+
+impl SqlxSingleConnection {
+    pub(crate) fn new(conn: InnerConnection, backend: DbBackend) -> Self {
+        Self {
+            conn: Arc::new(Mutex::new(conn)),
+            gate: Arc::new(Mutex::new(())),
+            backend,
+            metric_callback: None,
+            record_stmt_in_spans: true,
+        }
+    }
+
+    pub(crate) fn get_database_backend(&self) -> DbBackend {
+        self.backend
+    }
+
+    /// A handle to the connection, once no other handle or transaction holds it.
+    /// Others wait until the handle, and any transaction begun on it, is dropped.
+    pub(crate) async fn pin(&self) -> PinnedConnection {
+        let gate = Arc::clone(&self.gate).lock_owned().await;
+        PinnedConnection {
+            conn: Arc::clone(&self.conn),
+            backend: self.backend,
+            metric_callback: self.metric_callback.clone(),
+            record_stmt_in_spans: self.record_stmt_in_spans,
+            persistent_statements: true,
+            gate: Some(Arc::new(gate)),
+        }
+    }
+
+    /// Stream the results of executing a SQL query
+    ///
+    /// The rows are fetched before the stream is returned, so that the
+    /// connection isn't held while the stream is consumed.
+    #[cfg(feature = "stream")]
+    #[instrument(level = "trace", skip(stmt))]
+    pub async fn stream(&self, stmt: Statement) -> Result<QueryStream, DbErr> {
+        let conn = self.pin().await;
+        let rows = conn.query_all_raw(stmt.clone()).await?;
+        Ok(QueryStream::from_rows(stmt, rows))
+    }
+
+    pub(crate) fn metric_callback(&self) -> Option<crate::metric::Callback> {
+        self.metric_callback.clone()
+    }
+
+    pub(crate) fn set_metric_callback(&mut self, callback: crate::metric::Callback) {
+        self.metric_callback = Some(callback);
+    }
+
+    /// Checks if the connection to the database is still valid.
+    #[instrument(level = "trace")]
+    pub async fn ping(&self) -> Result<(), DbErr> {
+        use sqlx::Connection;
+
+        self.pin()
+            .await
+            .with_raw_sqlx(async |conn| match conn {
+                #[cfg(feature = "sqlx-mysql")]
+                SqlxConnection::MySql(c) => c.ping().await,
+                #[cfg(feature = "sqlx-postgres")]
+                SqlxConnection::Postgres(c) => c.ping().await,
+                #[cfg(feature = "sqlx-sqlite")]
+                SqlxConnection::Sqlite(c) => c.ping().await,
+            })
+            .await?
+            .map_err(sqlx_error_to_conn_err)
+    }
+}
+
+impl From<SqlxSingleConnection> for DatabaseConnection {
+    fn from(conn: SqlxSingleConnection) -> Self {
+        DatabaseConnectionType::SqlxSingleConnection(conn).into()
+    }
+}
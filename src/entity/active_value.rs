@@ -6,11 +6,12 @@ pub use ActiveValue::{NotSet, Set, Unchanged};
 
 /// The state of a field in an [ActiveModel][crate::ActiveModelTrait].
 ///
-/// There are three possible states represented by three enum variants:
+/// There are four possible states represented by four enum variants:
 ///
 /// - [Set] - a value that's explicitly set by the application and sent to the database.
 /// - [Unchanged] - an existing, unchanged value from the database.
 /// - [NotSet] - an undefined value (nothing is sent to the database).
+/// - [SetDefault][ActiveValue::SetDefault] - the database default, sent as `DEFAULT` in an `INSERT`.
 ///
 /// The difference between these states is useful
 /// when constructing `INSERT` and `UPDATE` SQL statements (see an example below).
@@ -83,6 +84,35 @@ where
     /// - You insert a new record and want the database to generate a default value (e.g., an id).
     /// - In an `UPDATE` statement, you don't want to update some field.
     NotSet,
+    /// The default of the column, written as the `DEFAULT` keyword in an `INSERT`.
+    ///
+    /// Unlike [NotSet][ActiveValue::NotSet], the column stays in the column list. In an
+    /// `insert_many` where other rows set the column, the row gets the database default
+    /// instead of `NULL`.
+    ///
+    /// It is only supported in `INSERT`: an `UPDATE` returns an error, and so does SQLite,
+    /// which lacks `DEFAULT` in `VALUES`.
+    ///
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::cake};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::insert_many([
+    ///         cake::ActiveModel {
+    ///             id: NotSet,
+    ///             name: Set("Apple Pie".to_owned()),
+    ///         },
+    ///         cake::ActiveModel {
+    ///             id: NotSet,
+    ///             name: ActiveValue::set_default(),
+    ///         },
+    ///     ])
+    ///     .build(DbBackend::Postgres)
+    ///     .to_string(),
+    ///     r#"INSERT INTO "cake" ("name") VALUES ('Apple Pie'), (DEFAULT)"#,
+    /// );
+    /// ```
+    SetDefault,
 }
 
 /// Defines an not set operation on an [ActiveValue]
@@ -99,6 +129,19 @@ where
 }
 
 /// Any type that can be converted into an [ActiveValue]
+///
+/// Besides the value types themselves, `Option<V>` converts into `NotSet` when
+/// `None`, and `Option<Option<V>>` into `ActiveValue<Option<V>>` for nullable
+/// columns, with `Some(None)` setting the column to `NULL`. Both forms are
+/// available for every type implementing `IntoActiveValue<V>` and `Nullable`,
+/// including the chrono, time, uuid, decimal, ipnetwork and json types, as well
+/// as types deriving `DeriveActiveEnum` or `DeriveValueType`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be converted into `ActiveValue<{V}>`",
+    label = "the trait `IntoActiveValue<{V}>` is not implemented for `{Self}`",
+    note = "for a nullable column use `Option<Option<T>>`, for an optional field use `Option<T>`",
+    note = "custom types can implement it by deriving `DeriveValueType` or `DeriveActiveEnum`"
+)]
 pub trait IntoActiveValue<V>
 where
     V: Into<Value>,
@@ -131,6 +174,18 @@ where
     }
 }
 
+#[cfg(feature = "postgres-array")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgres-array")))]
+impl<T> IntoActiveValue<Vec<T>> for Vec<T>
+where
+    T: crate::ActiveEnum,
+    Vec<T>: Into<Value>,
+{
+    fn into_active_value(self) -> ActiveValue<Vec<T>> {
+        Set(self)
+    }
+}
+
 macro_rules! impl_into_active_value {
     ($ty: ty) => {
         impl IntoActiveValue<$ty> for $ty {
@@ -260,11 +315,21 @@ where
         matches!(self, Self::NotSet)
     }
 
+    /// Create an [ActiveValue::SetDefault]
+    pub fn set_default() -> Self {
+        Self::SetDefault
+    }
+
+    /// Check if the [ActiveValue] is [ActiveValue::SetDefault]
+    pub fn is_set_default(&self) -> bool {
+        matches!(self, Self::SetDefault)
+    }
+
     /// Take ownership of the inner value, also setting self to `NotSet`
     pub fn take(&mut self) -> Option<V> {
         match std::mem::take(self) {
             ActiveValue::Set(value) | ActiveValue::Unchanged(value) => Some(value),
-            ActiveValue::NotSet => None,
+            ActiveValue::NotSet | ActiveValue::SetDefault => None,
         }
     }
 
@@ -272,11 +337,12 @@ where
     ///
     /// # Panics
     ///
-    /// Panics if it is [ActiveValue::NotSet]
+    /// Panics if it is [ActiveValue::NotSet] or [ActiveValue::SetDefault]
     pub fn unwrap(self) -> V {
         match self {
             ActiveValue::Set(value) | ActiveValue::Unchanged(value) => value,
             ActiveValue::NotSet => panic!("Cannot unwrap ActiveValue::NotSet"),
+            ActiveValue::SetDefault => panic!("Cannot unwrap ActiveValue::SetDefault"),
         }
     }
 
@@ -284,7 +350,7 @@ where
     pub fn into_value(self) -> Option<Value> {
         match self {
             ActiveValue::Set(value) | ActiveValue::Unchanged(value) => Some(value.into()),
-            ActiveValue::NotSet => None,
+            ActiveValue::NotSet | ActiveValue::SetDefault => None,
         }
     }
 
@@ -294,15 +360,16 @@ where
             Self::Set(value) => ActiveValue::set(value.into()),
             Self::Unchanged(value) => ActiveValue::unchanged(value.into()),
             Self::NotSet => ActiveValue::not_set(),
+            Self::SetDefault => ActiveValue::set_default(),
         }
     }
 
     /// Reset the value from [ActiveValue::Unchanged] to [ActiveValue::Set],
-    /// leaving [ActiveValue::NotSet] untouched.
+    /// leaving [ActiveValue::NotSet] and [ActiveValue::SetDefault] untouched.
     pub fn reset(&mut self) {
-        *self = match self.take() {
-            Some(value) => ActiveValue::Set(value),
-            None => ActiveValue::NotSet,
+        *self = match std::mem::take(self) {
+            ActiveValue::Unchanged(value) => ActiveValue::Set(value),
+            value => value,
         };
     }
 
@@ -394,7 +461,8 @@ where
         self.set_ne_and(value, f);
     }
 
-    /// Get the inner value, unless `self` is [NotSet][ActiveValue::NotSet].
+    /// Get the inner value, unless `self` is [NotSet][ActiveValue::NotSet] or
+    /// [SetDefault][ActiveValue::SetDefault].
     ///
     /// There's also a panicking version: [ActiveValue::as_ref].
     ///
@@ -410,24 +478,51 @@ where
     pub fn try_as_ref(&self) -> Option<&V> {
         match self {
             ActiveValue::Set(value) | ActiveValue::Unchanged(value) => Some(value),
-            ActiveValue::NotSet => None,
+            ActiveValue::NotSet | ActiveValue::SetDefault => None,
         }
     }
 }
 
+#[cfg(feature = "with-rust_decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-rust_decimal")))]
+impl ActiveValue<crate::prelude::Decimal> {
+    /// Create an [ActiveValue::Set] from an `f64`, rounded to `dp` decimal places.
+    ///
+    /// Midpoints are rounded away from zero. `NaN`, infinite and out of range
+    /// values are rejected with [DbErr::Type][crate::DbErr::Type].
+    /// See [TryIntoDecimal][crate::value::TryIntoDecimal] for other float conversions.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use sea_orm::{ActiveValue, prelude::Decimal};
+    /// #
+    /// let price = ActiveValue::set_decimal_from_f64_rounded(12.3456, 2).unwrap();
+    /// assert_eq!(price, ActiveValue::Set(Decimal::new(1235, 2)));
+    ///
+    /// assert!(ActiveValue::set_decimal_from_f64_rounded(f64::NAN, 2).is_err());
+    /// ```
+    pub fn set_decimal_from_f64_rounded(value: f64, dp: u32) -> Result<Self, crate::DbErr> {
+        use crate::value::TryIntoDecimal;
+
+        Ok(Self::Set(value.try_into_decimal_rounded(dp)?))
+    }
+}
+
 impl<V> std::convert::AsRef<V> for ActiveValue<V>
 where
     V: Into<Value>,
 {
     /// # Panics
     ///
-    /// Panics if it is [ActiveValue::NotSet].
+    /// Panics if it is [ActiveValue::NotSet] or [ActiveValue::SetDefault].
     ///
     /// See [ActiveValue::try_as_ref] for a fallible non-panicking version.
     fn as_ref(&self) -> &V {
         match self {
             ActiveValue::Set(value) | ActiveValue::Unchanged(value) => value,
             ActiveValue::NotSet => panic!("Cannot borrow ActiveValue::NotSet"),
+            ActiveValue::SetDefault => panic!("Cannot borrow ActiveValue::SetDefault"),
         }
     }
 }
@@ -441,6 +536,7 @@ where
             (ActiveValue::Set(l), ActiveValue::Set(r)) => l == r,
             (ActiveValue::Unchanged(l), ActiveValue::Unchanged(r)) => l == r,
             (ActiveValue::NotSet, ActiveValue::NotSet) => true,
+            (ActiveValue::SetDefault, ActiveValue::SetDefault) => true,
             _ => false,
         }
     }
@@ -455,6 +551,7 @@ where
             ActiveValue::Set(value) => ActiveValue::set(Some(value)),
             ActiveValue::Unchanged(value) => ActiveValue::unchanged(Some(value)),
             ActiveValue::NotSet => ActiveValue::not_set(),
+            ActiveValue::SetDefault => ActiveValue::set_default(),
         }
     }
 }
@@ -0,0 +1,374 @@
+use crate::{ColIdx, DbErr, QueryResult, TryGetError, TryGetable, Value};
+use sea_query::ValueType;
+use std::{
+    collections::HashMap,
+    marker::PhantomData,
+    sync::{Arc, LazyLock, PoisonError, RwLock},
+};
+
+/// Encryption of a column at rest, declared with `#[sea_orm(encrypt_with = "MyCipher")]`
+/// on a field of an entity.
+///
+/// Values are encrypted wherever an [`ActiveValue`](crate::ActiveValue) of the
+/// column is bound to an `INSERT` or `UPDATE`, see [`ColumnTrait::encrypt_value`](crate::ColumnTrait::encrypt_value),
+/// and decrypted when the field is read into a model, see [`Decrypted`].
+/// Partial models and [`FromQueryResult`](crate::FromQueryResult) structs reading
+/// the column take the same `encrypt_with` attribute on the field.
+///
+/// The `column_type` of the field must be that of the ciphertext, e.g.
+/// `column_type = "Blob"` for a binary ciphertext.
+///
+/// Filters compare against the stored ciphertext, so a plaintext can only be
+/// searched for with a deterministic cipher, by encrypting it first.
+///
+/// ```
+/// use sea_orm::{ColumnCipher, DbErr, Value, cipher_key};
+///
+/// /// Not a real cipher, for demonstration only
+/// pub struct XorCipher;
+///
+/// impl ColumnCipher for XorCipher {
+///     type Ciphertext = Vec<u8>;
+///
+///     fn encrypt(value: Value) -> Value {
+///         let key = cipher_key("xor").expect("key is registered on startup");
+///         match value {
+///             Value::String(Some(s)) => Value::Bytes(Some(xor(s.as_bytes(), &key))),
+///             _ => Value::Bytes(None),
+///         }
+///     }
+///
+///     fn decrypt(value: Value) -> Result<Value, DbErr> {
+///         let key = cipher_key("xor")?;
+///         match value {
+///             Value::Bytes(Some(b)) => String::from_utf8(xor(&b, &key))
+///                 .map(|s| Value::String(Some(s)))
+///                 .map_err(|e| DbErr::Type(e.to_string())),
+///             _ => Err(DbErr::Type("Expected bytes".to_owned())),
+///         }
+///     }
+/// }
+///
+/// fn xor(bytes: &[u8], key: &[u8]) -> Vec<u8> {
+///     bytes.iter().zip(key.iter().cycle()).map(|(b, k)| b ^ k).collect()
+/// }
+/// ```
+pub trait ColumnCipher {
+    /// Type of the ciphertext as stored in the database, e.g. `Vec<u8>` for a
+    /// binary column
+    type Ciphertext: TryGetable + Into<Value>;
+
+    /// Encrypt a plaintext value into a ciphertext value.
+    /// A `NULL` is written as is, it must be encrypted into a `NULL`.
+    fn encrypt(value: Value) -> Value;
+
+    /// Decrypt a ciphertext value read from the database, never `NULL`
+    fn decrypt(value: Value) -> Result<Value, DbErr>;
+}
+
+static CIPHER_KEYS: LazyLock<RwLock<HashMap<String, Arc<[u8]>>>> = LazyLock::new(Default::default);
+
+/// Register the key material of a [`ColumnCipher`] under `name`, replacing any
+/// key previously registered under the same name.
+///
+/// The registry is global to the process, keys are usually registered on
+/// startup, before any query runs.
+pub fn register_cipher_key<N, K>(name: N, key: K)
+where
+    N: Into<String>,
+    K: Into<Vec<u8>>,
+{
+    CIPHER_KEYS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(name.into(), key.into().into());
+}
+
+/// The key registered under `name` with [`register_cipher_key`]
+pub fn cipher_key(name: &str) -> Result<Arc<[u8]>, DbErr> {
+    CIPHER_KEYS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(name)
+        .cloned()
+        .ok_or_else(|| DbErr::Custom(format!("No cipher key registered as '{name}'")))
+}
+
+/// Read the ciphertext of column `col` from `res` and decrypt it with the
+/// [`ColumnCipher`] `C`, `None` for a `NULL`
+pub fn try_get_decrypted<C>(res: &QueryResult, pre: &str, col: &str) -> Result<Option<Value>, DbErr>
+where
+    C: ColumnCipher,
+{
+    let ciphertext: Option<C::Ciphertext> = res.try_get(pre, col)?;
+    ciphertext.map(|c| C::decrypt(c.into())).transpose()
+}
+
+/// A value of type `T` stored encrypted with the [`ColumnCipher`] `C`,
+/// decrypted when read from a [`QueryResult`].
+///
+/// A `NULL` ciphertext is [`TryGetError::Null`], read an `Option<Decrypted<C, T>>`
+/// for a nullable column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decrypted<C, T> {
+    value: T,
+    cipher: PhantomData<C>,
+}
+
+impl<C, T> Decrypted<C, T> {
+    /// The decrypted value
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<C, T> TryGetable for Decrypted<C, T>
+where
+    C: ColumnCipher,
+    T: ValueType,
+{
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let ciphertext = C::Ciphertext::try_get_by(res, index)?;
+        let value = C::decrypt(ciphertext.into())?;
+        let value = T::try_from(value).map_err(|e| DbErr::Type(e.to_string()))?;
+        Ok(Self {
+            value,
+            cipher: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "mock", feature = "macros"))]
+mod tests {
+    use crate as sea_orm;
+    use crate::{
+        DbBackend, DerivePartialModel, FromQueryResult, MockDatabase, MockExecResult, Transaction,
+        entity::prelude::*, entity::*, query::*,
+    };
+    use pretty_assertions::assert_eq;
+
+    const KEY: &[u8] = b"secret";
+
+    pub struct XorCipher;
+
+    impl ColumnCipher for XorCipher {
+        type Ciphertext = Vec<u8>;
+
+        fn encrypt(value: Value) -> Value {
+            let key = cipher_key("xor_test").unwrap();
+            match value {
+                Value::String(Some(s)) => Value::Bytes(Some(xor(s.as_bytes(), &key))),
+                _ => Value::Bytes(None),
+            }
+        }
+
+        fn decrypt(value: Value) -> Result<Value, DbErr> {
+            let key = cipher_key("xor_test")?;
+            match value {
+                Value::Bytes(Some(b)) => String::from_utf8(xor(&b, &key))
+                    .map(|s| Value::String(Some(s)))
+                    .map_err(|e| DbErr::Type(e.to_string())),
+                _ => Err(DbErr::Type("Expected bytes".to_owned())),
+            }
+        }
+    }
+
+    fn xor(bytes: &[u8], key: &[u8]) -> Vec<u8> {
+        bytes
+            .iter()
+            .zip(key.iter().cycle())
+            .map(|(b, k)| b ^ k)
+            .collect()
+    }
+
+    fn ciphertext(s: &str) -> Value {
+        Value::Bytes(Some(xor(s.as_bytes(), KEY)))
+    }
+
+    mod patient {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "patient")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub name: String,
+            #[sea_orm(column_type = "Blob", encrypt_with = "super::XorCipher")]
+            pub ssn: String,
+            #[sea_orm(column_type = "Blob", encrypt_with = "super::XorCipher")]
+            pub note: Option<String>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    fn patient_row(note: Value) -> std::collections::BTreeMap<&'static str, Value> {
+        maplit::btreemap! {
+            "id" => 1.into(),
+            "name" => "Alice".into(),
+            "ssn" => ciphertext("123-45-6789"),
+            "note" => note,
+        }
+    }
+
+    fn alice() -> patient::Model {
+        patient::Model {
+            id: 1,
+            name: "Alice".to_owned(),
+            ssn: "123-45-6789".to_owned(),
+            note: None,
+        }
+    }
+
+    #[smol_potat::test]
+    async fn encrypt_on_write() -> Result<(), DbErr> {
+        register_cipher_key("xor_test", KEY);
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[patient_row(Value::Bytes(None))]])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        // the model returned by the insert is decrypted
+        let model = patient::ActiveModel {
+            id: Set(1),
+            name: Set("Alice".to_owned()),
+            ssn: Set("123-45-6789".to_owned()),
+            note: Set(None),
+        }
+        .insert(&db)
+        .await?;
+        assert_eq!(model, alice());
+
+        patient::Entity::update_many()
+            .set(patient::ActiveModel {
+                note: Set(Some("allergic".to_owned())),
+                ..Default::default()
+            })
+            .filter(patient::Column::Id.eq(1))
+            .exec(&db)
+            .await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "patient" ("id", "name", "ssn", "note") VALUES ($1, $2, $3, $4) RETURNING "id", "name", "ssn", "note""#,
+                    [
+                        1.into(),
+                        "Alice".into(),
+                        ciphertext("123-45-6789"),
+                        Value::Bytes(None)
+                    ]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"UPDATE "patient" SET "note" = $1 WHERE "patient"."id" = $2"#,
+                    [ciphertext("allergic"), 1.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn decrypt_on_read() -> Result<(), DbErr> {
+        register_cipher_key("xor_test", KEY);
+
+        #[derive(Debug, PartialEq, DerivePartialModel)]
+        #[sea_orm(entity = "patient::Entity")]
+        struct PatientSsn {
+            name: String,
+            #[sea_orm(encrypt_with = "XorCipher")]
+            ssn: String,
+            #[sea_orm(encrypt_with = "XorCipher")]
+            note: Option<String>,
+        }
+
+        #[derive(Debug, PartialEq, FromQueryResult)]
+        struct RawPatient {
+            #[sea_orm(encrypt_with = "XorCipher")]
+            ssn: String,
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([
+                [patient_row(Value::Bytes(None))],
+                [patient_row(ciphertext("allergic"))],
+                [patient_row(ciphertext("allergic"))],
+            ])
+            .into_connection();
+
+        assert_eq!(patient::Entity::find().one(&db).await?, Some(alice()));
+
+        assert_eq!(
+            patient::Entity::find()
+                .into_partial_model::<PatientSsn>()
+                .one(&db)
+                .await?,
+            Some(PatientSsn {
+                name: "Alice".to_owned(),
+                ssn: "123-45-6789".to_owned(),
+                note: Some("allergic".to_owned()),
+            })
+        );
+
+        assert_eq!(
+            patient::Entity::find()
+                .into_model::<RawPatient>()
+                .one(&db)
+                .await?,
+            Some(RawPatient {
+                ssn: "123-45-6789".to_owned(),
+            })
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "with-json")]
+    #[smol_potat::test]
+    async fn decrypt_into_json() -> Result<(), DbErr> {
+        register_cipher_key("xor_test", KEY);
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([
+                [patient_row(ciphertext("allergic"))],
+                [patient_row(Value::Bytes(None))],
+            ])
+            .into_connection();
+
+        assert_eq!(
+            patient::Entity::find().into_json().one(&db).await?,
+            Some(serde_json::json!({
+                "id": 1,
+                "name": "Alice",
+                "ssn": "123-45-6789",
+                "note": "allergic",
+            }))
+        );
+
+        assert_eq!(
+            patient::Entity::find().into_json().all(&db).await?,
+            [serde_json::json!({
+                "id": 1,
+                "name": "Alice",
+                "ssn": "123-45-6789",
+                "note": null,
+            })]
+        );
+
+        Ok(())
+    }
+}
@@ -1,8 +1,9 @@
 use crate::{
-    ColumnDef, ColumnType, DbBackend, EntityName, Iden, IdenStatic, IntoSimpleExpr, Iterable,
+    ColumnDef, ColumnType, DbBackend, DbErr, EntityName, Iden, IdenStatic, IntoSimpleExpr,
+    Iterable, QueryResult,
 };
 use sea_query::{
-    BinOper, DynIden, Expr, ExprTrait, IntoIden, IntoLikeExpr, SeaRc, SelectStatement, Value,
+    BinOper, DynIden, Expr, ExprTrait, Func, IntoIden, IntoLikeExpr, SeaRc, SelectStatement, Value,
 };
 use std::{borrow::Cow, str::FromStr};
 
@@ -83,6 +84,53 @@ pub(crate) mod macros {
 
 use macros::*;
 
+/// Part of a date or time, see [`ColumnTrait::date_trunc`] and [`ColumnTrait::extract`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DatePart {
+    #[allow(missing_docs)]
+    Year,
+    #[allow(missing_docs)]
+    Month,
+    #[allow(missing_docs)]
+    Day,
+    #[allow(missing_docs)]
+    Hour,
+    #[allow(missing_docs)]
+    Minute,
+    #[allow(missing_docs)]
+    Second,
+}
+
+impl DatePart {
+    /// The name of the part, as used by Postgres' `EXTRACT`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Year => "YEAR",
+            Self::Month => "MONTH",
+            Self::Day => "DAY",
+            Self::Hour => "HOUR",
+            Self::Minute => "MINUTE",
+            Self::Second => "SECOND",
+        }
+    }
+
+    /// Format of a timestamp truncated to this part, for `DATE_FORMAT` on MySQL
+    /// and `strftime` on SQLite
+    fn trunc_format(&self, db_backend: DbBackend) -> &'static str {
+        let mysql = db_backend == DbBackend::MySql;
+        match self {
+            Self::Year => "%Y-01-01 00:00:00",
+            Self::Month => "%Y-%m-01 00:00:00",
+            Self::Day => "%Y-%m-%d 00:00:00",
+            Self::Hour => "%Y-%m-%d %H:00:00",
+            Self::Minute if mysql => "%Y-%m-%d %H:%i:00",
+            Self::Minute => "%Y-%m-%d %H:%M:00",
+            Self::Second if mysql => "%Y-%m-%d %H:%i:%s",
+            Self::Second => "%Y-%m-%d %H:%M:%S",
+        }
+    }
+}
+
 /// Operations and comparisons available on every entity column.
 ///
 /// Implemented by the generated `Column` enum for each entity, this is what
@@ -96,6 +144,17 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     /// SQL type and constraints attached to this column.
     fn def(&self) -> ColumnDef;
 
+    /// Names of every column of this entity, in declaration order.
+    ///
+    /// Handy for validating a user supplied sort field or listing the accepted values;
+    /// [`FromStr`] on the `Column` enum accepts these names as well.
+    fn column_names() -> Vec<&'static str>
+    where
+        Self: Sized,
+    {
+        Self::iter().map(|col| col.as_str()).collect()
+    }
+
     /// If the column maps to a database `ENUM`, the enum's type name.
     /// Returns `None` for non-enum columns.
     fn enum_type_name(&self) -> Option<&'static str> {
@@ -113,6 +172,30 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
         (self.entity_name(), SeaRc::new(*self))
     }
 
+    /// Reference this column through another table name or alias, e.g. the
+    /// alias of a subquery joined with [`UpdateMany::join_from`](crate::UpdateMany::join_from).
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::cake};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(
+    ///             cake::Column::Id
+    ///                 .into_expr()
+    ///                 .eq(cake::Column::Id.of_table("c"))
+    ///         )
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = "c"."id""#
+    /// );
+    /// ```
+    fn of_table<T>(&self, table: T) -> Expr
+    where
+        T: IntoIden,
+    {
+        Expr::col((table.into_iden(), SeaRc::new(*self) as DynIden))
+    }
+
     /// Perform equality against a Value. `None` will be converted to `IS NULL`.
     /// ```
     /// use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::fruit};
@@ -177,6 +260,40 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
         }
     }
 
+    /// Like [`eq`](Self::eq), but checks first that the [`Value`] fits the column type,
+    /// returning a [`DbErr::Type`] on a mismatch instead of SQL the database rejects.
+    /// Meant for values of dynamic type, e.g. parsed from a query string.
+    ///
+    /// Integers are accepted for decimal and floating point columns. Custom column types,
+    /// and the types of a disabled feature, are not checked.
+    /// ```
+    /// use sea_orm::{DbBackend, DbErr, Value, entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .filter(fruit::Column::CakeId.eq_value(Value::Int(Some(2)))?)
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit` WHERE `fruit`.`cake_id` = 2"
+    /// );
+    /// assert!(matches!(
+    ///     fruit::Column::CakeId.eq_value("2".into()),
+    ///     Err(DbErr::Type(_))
+    /// ));
+    /// # Ok::<(), DbErr>(())
+    /// ```
+    fn eq_value(&self, v: Value) -> Result<Expr, DbErr> {
+        let def = self.def();
+        if !value_fits_column_type(&v, def.get_column_type()) {
+            return Err(DbErr::Type(format!(
+                "Value {v:?} does not match the type {:?} of column `{}`",
+                def.get_column_type(),
+                self.as_str()
+            )));
+        }
+        Ok(self.eq(v))
+    }
+
     bind_oper!(gt, GreaterThan);
     bind_oper!(gte, GreaterThanOrEqual);
     bind_oper!(lt, SmallerThan);
@@ -495,6 +612,174 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     bind_array_oper!(array_contained, Contained);
     bind_array_oper!(array_overlap, Overlap);
 
+    /// Postgres only. Whether the JSON document in this column contains `v`,
+    /// with the `jsonb` containment operator `@>`. `json` columns are cast
+    /// to `jsonb` first.
+    #[cfg(feature = "with-json")]
+    fn json_contains(&self, v: serde_json::Value, db_backend: DbBackend) -> Result<Expr, DbErr> {
+        use sea_query::extension::postgres::PgBinOper;
+
+        check_postgres_json(db_backend, "json_contains")?;
+        Ok(as_jsonb(self.as_column_ref(), self.def().get_column_type())
+            .binary(PgBinOper::Contains, Expr::val(v)))
+    }
+
+    /// Postgres only. Whether the SQL/JSON `path` returns any item for the
+    /// JSON document in this column, with `jsonb_path_exists`. `json` columns
+    /// are cast to `jsonb` first.
+    #[cfg(feature = "with-json")]
+    fn json_path_exists(&self, path: &str, db_backend: DbBackend) -> Result<Expr, DbErr> {
+        check_postgres_json(db_backend, "json_path_exists")?;
+        Ok(sea_query::Func::cust("jsonb_path_exists")
+            .args([
+                as_jsonb(self.as_column_ref(), self.def().get_column_type()),
+                Expr::val(path).cast_as(JsonPath),
+            ])
+            .into())
+    }
+
+    /// Postgres only. The value of `key` in the JSON object in this column,
+    /// as text (`->>`). Usable in both filters and selects.
+    #[cfg(feature = "with-json")]
+    fn json_get_text(&self, key: &str, db_backend: DbBackend) -> Result<Expr, DbErr> {
+        use sea_query::extension::postgres::PgBinOper;
+
+        check_postgres_json(db_backend, "json_get_text")?;
+        Ok(Expr::col(self.as_column_ref()).binary(PgBinOper::CastJsonField, Expr::val(key)))
+    }
+
+    /// Truncate the timestamp in this column to the start of `part`, e.g. to
+    /// group rows by day. Spelled `date_trunc` on Postgres, and by formatting
+    /// the timestamp on MySQL and SQLite.
+    fn date_trunc(&self, part: DatePart, db_backend: DbBackend) -> Expr {
+        let col = Expr::col(self.as_column_ref());
+        match db_backend {
+            DbBackend::MySql => Func::cust("DATE_FORMAT")
+                .args([col, Expr::Constant(part.trunc_format(db_backend).into())])
+                .cast_as(SqlType("DATETIME")),
+            DbBackend::Postgres => Func::cust("date_trunc")
+                .args([Expr::Constant(part.as_str().to_lowercase().into()), col])
+                .into(),
+            DbBackend::Sqlite => Func::cust("strftime")
+                .args([Expr::Constant(part.trunc_format(db_backend).into()), col])
+                .into(),
+        }
+    }
+
+    /// Extract `part` of the timestamp in this column as a number, with
+    /// `EXTRACT` on Postgres and MySQL, and `strftime` on SQLite.
+    fn extract(&self, part: DatePart, db_backend: DbBackend) -> Expr {
+        let sqlite = match part {
+            DatePart::Year => "%Y",
+            DatePart::Month => "%m",
+            DatePart::Day => "%d",
+            DatePart::Hour => "%H",
+            DatePart::Minute => "%M",
+            DatePart::Second => "%S",
+        };
+        extract(part.as_str(), sqlite, self.as_column_ref(), db_backend)
+    }
+
+    /// Seconds since the Unix epoch of the timestamp in this column, with
+    /// `EXTRACT(EPOCH FROM ..)` on Postgres, `UNIX_TIMESTAMP` on MySQL and
+    /// `strftime('%s', ..)` on SQLite.
+    fn to_unix_epoch(&self, db_backend: DbBackend) -> Expr {
+        match db_backend {
+            DbBackend::MySql => Func::cust("UNIX_TIMESTAMP")
+                .arg(Expr::col(self.as_column_ref()))
+                .into(),
+            _ => extract("EPOCH", "%s", self.as_column_ref(), db_backend),
+        }
+    }
+
+    /// Add a value to the column, for use in [`UpdateMany::col_expr`](crate::UpdateMany::col_expr).
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let query = fruit::Entity::update_many()
+    ///     .col_expr(fruit::Column::CakeId, fruit::Column::CakeId.increment(2))
+    ///     .filter(fruit::Column::Name.contains("Apple"));
+    ///
+    /// assert_eq!(
+    ///     query.build(DbBackend::Postgres).to_string(),
+    ///     r#"UPDATE "fruit" SET "cake_id" = "cake_id" + 2 WHERE "fruit"."name" LIKE '%Apple%'"#
+    /// );
+    /// assert_eq!(
+    ///     query.build(DbBackend::MySql).to_string(),
+    ///     "UPDATE `fruit` SET `cake_id` = `cake_id` + 2 WHERE `fruit`.`name` LIKE '%Apple%'"
+    /// );
+    /// ```
+    fn increment<V>(&self, v: V) -> Expr
+    where
+        V: Into<Value>,
+    {
+        self.save_as(Expr::col(*self).add(v.into()))
+    }
+
+    /// Subtract a value from the column, for use in [`UpdateMany::col_expr`](crate::UpdateMany::col_expr).
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let query = fruit::Entity::update_many()
+    ///     .col_expr(fruit::Column::CakeId, fruit::Column::CakeId.decrement(1))
+    ///     .filter(fruit::Column::Id.eq(3));
+    ///
+    /// assert_eq!(
+    ///     query.build(DbBackend::Postgres).to_string(),
+    ///     r#"UPDATE "fruit" SET "cake_id" = "cake_id" - 1 WHERE "fruit"."id" = 3"#
+    /// );
+    /// assert_eq!(
+    ///     query.build(DbBackend::MySql).to_string(),
+    ///     "UPDATE `fruit` SET `cake_id` = `cake_id` - 1 WHERE `fruit`.`id` = 3"
+    /// );
+    /// ```
+    fn decrement<V>(&self, v: V) -> Expr
+    where
+        V: Into<Value>,
+    {
+        self.save_as(Expr::col(*self).sub(v.into()))
+    }
+
+    /// Append a value to the column, for use in [`UpdateMany::col_expr`](crate::UpdateMany::col_expr).
+    /// Spelled `||` on Postgres and SQLite, and `CONCAT` on MySQL, where `||` means `OR`.
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let query = |db_backend| {
+    ///     fruit::Entity::update_many()
+    ///         .col_expr(
+    ///             fruit::Column::Name,
+    ///             fruit::Column::Name.concat(" (sold out)", db_backend),
+    ///         )
+    ///         .filter(fruit::Column::CakeId.is_null())
+    ///         .build(db_backend)
+    ///         .to_string()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     query(DbBackend::Postgres),
+    ///     r#"UPDATE "fruit" SET "name" = "name" || ' (sold out)' WHERE "fruit"."cake_id" IS NULL"#
+    /// );
+    /// assert_eq!(
+    ///     query(DbBackend::MySql),
+    ///     "UPDATE `fruit` SET `name` = CONCAT(`name`, ' (sold out)') WHERE `fruit`.`cake_id` IS NULL"
+    /// );
+    /// assert_eq!(
+    ///     query(DbBackend::Sqlite),
+    ///     r#"UPDATE "fruit" SET "name" = "name" || ' (sold out)' WHERE "fruit"."cake_id" IS NULL"#
+    /// );
+    /// ```
+    fn concat<V>(&self, v: V, db_backend: DbBackend) -> Expr
+    where
+        V: Into<Value>,
+    {
+        let (col, val) = (Expr::col(*self), Expr::val(v));
+        self.save_as(match db_backend {
+            DbBackend::MySql => Func::cust("CONCAT").args([col, val]).into(),
+            DbBackend::Postgres | DbBackend::Sqlite => col.binary(BinOper::Custom("||"), val),
+        })
+    }
+
     /// Wrap the column in a plain [`Expr`], suitable for use anywhere a
     /// `sea_query` expression is expected.
     fn into_expr(self) -> Expr {
@@ -532,6 +817,25 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
         cast_enum_as(val, &self.def(), save_enum_as)
     }
 
+    /// Encrypt a value of an active model before it is bound for this column,
+    /// with the [`ColumnCipher`](crate::ColumnCipher) of `encrypt_with`; no-op
+    /// for other columns.
+    fn encrypt_value(&self, val: Value) -> Value {
+        val
+    }
+
+    /// Read the ciphertext of this column from `res` and decrypt it with the
+    /// [`ColumnCipher`](crate::ColumnCipher) of `encrypt_with`, see
+    /// [`try_get_decrypted`](crate::try_get_decrypted); `None` for other columns.
+    fn try_get_decrypted(
+        &self,
+        res: &QueryResult,
+        pre: &str,
+    ) -> Option<Result<Option<Value>, DbErr>> {
+        let _ = (res, pre);
+        None
+    }
+
     /// JSON key used for this column when (de)serializing the model.
     #[cfg(feature = "with-json")]
     fn json_key(&self) -> &'static str {
@@ -539,6 +843,122 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     }
 }
 
+/// Whether `value` can be compared against a column of `col_type` without a cast
+fn value_fits_column_type(value: &Value, col_type: &ColumnType) -> bool {
+    let integer = matches!(
+        value,
+        Value::TinyInt(_)
+            | Value::SmallInt(_)
+            | Value::Int(_)
+            | Value::BigInt(_)
+            | Value::TinyUnsigned(_)
+            | Value::SmallUnsigned(_)
+            | Value::Unsigned(_)
+            | Value::BigUnsigned(_)
+    );
+    match col_type {
+        ColumnType::TinyInteger
+        | ColumnType::SmallInteger
+        | ColumnType::Integer
+        | ColumnType::BigInteger
+        | ColumnType::TinyUnsigned
+        | ColumnType::SmallUnsigned
+        | ColumnType::Unsigned
+        | ColumnType::BigUnsigned
+        | ColumnType::Year => integer,
+        ColumnType::Float | ColumnType::Double => {
+            integer || matches!(value, Value::Float(_) | Value::Double(_))
+        }
+        ColumnType::Decimal(_) | ColumnType::Money(_) => match value {
+            Value::Float(_) | Value::Double(_) => true,
+            #[cfg(feature = "with-rust_decimal")]
+            Value::Decimal(_) => true,
+            #[cfg(feature = "with-bigdecimal")]
+            Value::BigDecimal(_) => true,
+            _ => integer,
+        },
+        ColumnType::Char(_)
+        | ColumnType::String(_)
+        | ColumnType::Text
+        | ColumnType::Enum { .. } => matches!(value, Value::String(_) | Value::Char(_)),
+        ColumnType::Boolean => matches!(value, Value::Bool(_)),
+        ColumnType::Binary(_)
+        | ColumnType::VarBinary(_)
+        | ColumnType::Bit(_)
+        | ColumnType::VarBit(_)
+        | ColumnType::Blob => matches!(value, Value::Bytes(_)),
+        #[cfg(feature = "with-json")]
+        ColumnType::Json | ColumnType::JsonBinary => matches!(value, Value::Json(_)),
+        #[cfg(feature = "with-uuid")]
+        ColumnType::Uuid => matches!(value, Value::Uuid(_)),
+        #[cfg(any(feature = "with-chrono", feature = "with-time"))]
+        ColumnType::Date => match value {
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDate(_) => true,
+            #[cfg(feature = "with-time")]
+            Value::TimeDate(_) => true,
+            _ => false,
+        },
+        #[cfg(any(feature = "with-chrono", feature = "with-time"))]
+        ColumnType::Time => match value {
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoTime(_) => true,
+            #[cfg(feature = "with-time")]
+            Value::TimeTime(_) => true,
+            _ => false,
+        },
+        #[cfg(any(feature = "with-chrono", feature = "with-time"))]
+        ColumnType::DateTime | ColumnType::Timestamp | ColumnType::TimestampWithTimeZone => {
+            match value {
+                #[cfg(feature = "with-chrono")]
+                Value::ChronoDateTime(_)
+                | Value::ChronoDateTimeUtc(_)
+                | Value::ChronoDateTimeLocal(_)
+                | Value::ChronoDateTimeWithTimeZone(_) => true,
+                #[cfg(feature = "with-time")]
+                Value::TimeDateTime(_) | Value::TimeDateTimeWithTimeZone(_) => true,
+                _ => false,
+            }
+        }
+        _ => true,
+    }
+}
+
+/// Convert a number to the [`Value`] variant of a numeric column type, e.g. the `Int`
+/// of `default_value = 10` to the `BigInt` of an `i64` column. `None` if the value
+/// isn't a number, the column isn't numeric or the number is out of its range.
+pub(crate) fn numeric_value_as_column_type(value: &Value, col_type: &ColumnType) -> Option<Value> {
+    let integer = match value {
+        Value::TinyInt(Some(v)) => Some(i128::from(*v)),
+        Value::SmallInt(Some(v)) => Some(i128::from(*v)),
+        Value::Int(Some(v)) => Some(i128::from(*v)),
+        Value::BigInt(Some(v)) => Some(i128::from(*v)),
+        Value::TinyUnsigned(Some(v)) => Some(i128::from(*v)),
+        Value::SmallUnsigned(Some(v)) => Some(i128::from(*v)),
+        Value::Unsigned(Some(v)) => Some(i128::from(*v)),
+        Value::BigUnsigned(Some(v)) => Some(i128::from(*v)),
+        _ => None,
+    };
+    let float = match value {
+        Value::Float(Some(v)) => Some(f64::from(*v)),
+        Value::Double(Some(v)) => Some(*v),
+        _ => integer.map(|v| v as f64),
+    };
+    match col_type {
+        ColumnType::TinyInteger => Some(Value::TinyInt(Some(integer?.try_into().ok()?))),
+        ColumnType::SmallInteger => Some(Value::SmallInt(Some(integer?.try_into().ok()?))),
+        ColumnType::Integer => Some(Value::Int(Some(integer?.try_into().ok()?))),
+        ColumnType::BigInteger => Some(Value::BigInt(Some(integer?.try_into().ok()?))),
+        ColumnType::TinyUnsigned => Some(Value::TinyUnsigned(Some(integer?.try_into().ok()?))),
+        ColumnType::SmallUnsigned => Some(Value::SmallUnsigned(Some(integer?.try_into().ok()?))),
+        ColumnType::Unsigned => Some(Value::Unsigned(Some(integer?.try_into().ok()?))),
+        ColumnType::BigUnsigned => Some(Value::BigUnsigned(Some(integer?.try_into().ok()?))),
+        ColumnType::Float => Some(Value::Float(Some(float? as f32))),
+        ColumnType::Double => Some(Value::Double(Some(float?))),
+        _ => None,
+    }
+}
+
 /// Extension methods on [`ColumnType`] for building [`ColumnDef`]s and
 /// inspecting database `ENUM` metadata.
 pub trait ColumnTypeTrait {
@@ -562,6 +982,9 @@ impl ColumnTypeTrait for ColumnType {
             unique_key: None,
             renamed_from: None,
             extra: None,
+            generated: false,
+            #[cfg(feature = "with-uuid")]
+            auto_uuid: None,
             seaography: Default::default(),
         }
     }
@@ -619,6 +1042,101 @@ impl Iden for TextArray {
     }
 }
 
+#[cfg(feature = "with-json")]
+struct JsonBinary;
+#[cfg(feature = "with-json")]
+struct JsonPath;
+/// A type name to cast to, left unquoted
+struct SqlType(&'static str);
+
+#[cfg(feature = "with-json")]
+impl Iden for JsonBinary {
+    fn quoted(&self) -> Cow<'static, str> {
+        Cow::Borrowed("jsonb")
+    }
+
+    fn unquoted(&self) -> &str {
+        match self.quoted() {
+            Cow::Borrowed(s) => s,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(feature = "with-json")]
+impl Iden for JsonPath {
+    fn quoted(&self) -> Cow<'static, str> {
+        Cow::Borrowed("jsonpath")
+    }
+
+    fn unquoted(&self) -> &str {
+        match self.quoted() {
+            Cow::Borrowed(s) => s,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Iden for SqlType {
+    fn quoted(&self) -> Cow<'static, str> {
+        Cow::Borrowed(self.0)
+    }
+
+    fn unquoted(&self) -> &str {
+        self.0
+    }
+}
+
+/// `EXTRACT(part FROM col)`, or the `strftime` field `sqlite` as a number on SQLite
+fn extract(
+    part: &'static str,
+    sqlite: &'static str,
+    col: (DynIden, DynIden),
+    db_backend: DbBackend,
+) -> Expr {
+    match db_backend {
+        DbBackend::MySql | DbBackend::Postgres => Func::cust("EXTRACT")
+            .arg(Expr::custom_keyword(part).binary(BinOper::Custom("FROM"), Expr::col(col)))
+            .into(),
+        DbBackend::Sqlite => Func::cust("strftime")
+            .args([Expr::Constant(sqlite.into()), Expr::col(col)])
+            .cast_as(SqlType("INTEGER")),
+    }
+}
+
+/// The `jsonb` operators and functions of the JSON helpers only exist on Postgres
+#[cfg(feature = "with-json")]
+fn check_postgres_json(db_backend: DbBackend, ctx: &'static str) -> Result<(), DbErr> {
+    match db_backend {
+        DbBackend::Postgres => Ok(()),
+        _ => Err(DbErr::BackendNotSupported {
+            db: db_backend.as_str(),
+            ctx,
+        }),
+    }
+}
+
+/// `jsonb` operators don't apply to `json`, so cast unless the column is `jsonb`
+#[cfg(feature = "with-json")]
+fn as_jsonb(col: (DynIden, DynIden), col_type: &ColumnType) -> Expr {
+    match col_type {
+        ColumnType::JsonBinary => Expr::col(col),
+        _ => Expr::col(col).cast_as(JsonBinary),
+    }
+}
+
+/// [ColumnTrait::select_as] for a column in a select list or `RETURNING` clause.
+/// A custom SQL expression, e.g. the `select_expr` of a `DeriveValueType`,
+/// would be named after the expression, so it is aliased back to the column name.
+pub(crate) fn select_as_named<C: ColumnTrait>(col: &C, expr: Expr) -> Expr {
+    match col.select_as(expr) {
+        expr @ Expr::CustomWithExpr(..) => {
+            Expr::cust_with_exprs("$1 AS $2", [expr, Expr::col(col.as_column_ref().1)])
+        }
+        expr => expr,
+    }
+}
+
 pub(crate) fn select_enum_as(col: Expr, _: DynIden, col_type: &ColumnType) -> Expr {
     let type_name = match col_type {
         ColumnType::Array(_) => TextArray.into_iden(),
@@ -628,6 +1146,9 @@ pub(crate) fn select_enum_as(col: Expr, _: DynIden, col_type: &ColumnType) -> Ex
 }
 
 pub(crate) fn save_enum_as(col: Expr, enum_name: DynIden, col_type: &ColumnType) -> Expr {
+    if let (Some(schema), name) = split_enum_name(&enum_name.to_string()) {
+        return save_schema_enum_as(col, schema, name, col_type);
+    }
     if matches!(col, Expr::Value(Value::Enum(_))) {
         return col;
     }
@@ -646,6 +1167,42 @@ pub(crate) fn save_enum_as(col: Expr, enum_name: DynIden, col_type: &ColumnType)
     col.as_enum(type_name)
 }
 
+/// Split an enum type name qualified by `#[sea_orm(schema_name = "..")]`,
+/// i.e. `schema.name`, into its schema and name
+pub(crate) fn split_enum_name(enum_name: &str) -> (Option<&str>, &str) {
+    match enum_name.split_once('.') {
+        Some((schema, name)) => (Some(schema), name),
+        None => (None, enum_name),
+    }
+}
+
+/// Cast to an enum type living in `schema`; enum values are sent as text,
+/// since their own cast would name the type without its schema
+fn save_schema_enum_as(col: Expr, schema: &str, name: &str, col_type: &ColumnType) -> Expr {
+    let suffix = match col_type {
+        ColumnType::Array(_) => "[]",
+        _ => "",
+    };
+    let col = match col {
+        Expr::Value(value) => Expr::Value(enum_value_as_string(value)),
+        col => col,
+    };
+    Expr::cust_with_expr(format!(r#"CAST($1 AS "{schema}"."{name}"{suffix})"#), col)
+}
+
+fn enum_value_as_string(value: Value) -> Value {
+    match value {
+        Value::Enum(sea_query::OptionEnum::Some(v)) => Value::String(Some(v.value.to_string())),
+        Value::Enum(sea_query::OptionEnum::None(_)) => Value::String(None),
+        #[cfg(feature = "postgres-array")]
+        Value::Array(sea_query::ArrayType::Enum(_), vals) => Value::Array(
+            sea_query::ArrayType::String,
+            vals.map(|vals| Box::new(vals.into_iter().map(enum_value_as_string).collect())),
+        ),
+        value => value,
+    }
+}
+
 pub(crate) fn cast_enum_as<F>(expr: Expr, col_def: &ColumnDef, f: F) -> Expr
 where
     F: Fn(Expr, DynIden, &ColumnType) -> Expr,
@@ -1002,6 +1559,92 @@ mod tests {
         });
     }
 
+    #[test]
+    fn concat_order_by() {
+        use crate::QueryOrder;
+
+        let query = |db_backend| {
+            fruit::Entity::find()
+                .order_by_asc(fruit::Column::Name.concat("!", db_backend))
+                .build(db_backend)
+                .to_string()
+        };
+
+        assert_eq!(
+            query(DbBackend::MySql),
+            [
+                "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit`",
+                "ORDER BY CONCAT(`name`, '!') ASC",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            query(DbBackend::Postgres),
+            [
+                r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                r#"ORDER BY "name" || '!' ASC"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn save_as_increment() {
+        use crate::{EntityTrait, QueryFilter};
+
+        mod hello {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "hello")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub one: i32,
+                #[sea_orm(save_as = "text")]
+                pub two: i32,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        assert_eq!(
+            hello::Entity::update_many()
+                .col_increment(hello::Column::One, 1)
+                .col_expr(hello::Column::Two, hello::Column::Two.increment(2))
+                .filter(hello::Column::Id.eq(1))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"UPDATE "hello" SET "one" = "one" + 1, "two" = CAST(("two" + 2) AS text) WHERE "hello"."id" = 1"#,
+        );
+        assert_eq!(
+            hello::Entity::update_many()
+                .col_expr(hello::Column::One, hello::Column::One.decrement(1))
+                .col_expr(
+                    hello::Column::Two,
+                    hello::Column::Two.concat("0", DbBackend::MySql),
+                )
+                .build(DbBackend::MySql)
+                .to_string(),
+            "UPDATE `hello` SET `one` = `one` - 1, `two` = CAST(CONCAT(`two`, '0') AS text)",
+        );
+        assert_eq!(
+            hello::Entity::update_many()
+                .col_expr(
+                    hello::Column::Two,
+                    hello::Column::Two.concat("0", DbBackend::Sqlite),
+                )
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            r#"UPDATE "hello" SET "two" = CAST(("two" || '0') AS text)"#,
+        );
+    }
+
     #[test]
     #[cfg(feature = "macros")]
     fn select_as_and_value_1() {
@@ -1141,4 +1784,144 @@ mod tests {
             three: ActiveValue::set(3),
         });
     }
+
+    #[test]
+    #[cfg(all(feature = "macros", feature = "with-json"))]
+    fn json_operators() -> Result<(), crate::DbErr> {
+        use crate::{DbErr, QuerySelect};
+        use sea_query::ExprTrait;
+        use serde_json::json;
+
+        mod document {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            #[sea_orm(table_name = "document")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub json: Json,
+                #[sea_orm(column_type = "JsonBinary")]
+                pub jsonb: Json,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        let db_backend = DbBackend::Postgres;
+        let select = || {
+            document::Entity::find()
+                .select_only()
+                .column(document::Column::Id)
+        };
+
+        assert_eq!(
+            select()
+                .filter(document::Column::Jsonb.json_contains(json!({ "tag": "new" }), db_backend)?)
+                .filter(document::Column::Json.json_contains(json!([1]), db_backend)?)
+                .build(db_backend)
+                .to_string(),
+            [
+                r#"SELECT "document"."id" FROM "document""#,
+                r#"WHERE "document"."jsonb" @> '{"tag":"new"}'"#,
+                r#"AND CAST("document"."json" AS jsonb) @> '[1]'"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            select()
+                .filter(document::Column::Jsonb.json_path_exists("$.a.b", db_backend)?)
+                .filter(document::Column::Json.json_path_exists("$.a", db_backend)?)
+                .build(db_backend)
+                .to_string(),
+            [
+                r#"SELECT "document"."id" FROM "document""#,
+                r#"WHERE jsonb_path_exists("document"."jsonb", CAST('$.a.b' AS jsonpath))"#,
+                r#"AND jsonb_path_exists(CAST("document"."json" AS jsonb), CAST('$.a' AS jsonpath))"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            select()
+                .column_as(
+                    document::Column::Json.json_get_text("name", db_backend)?,
+                    "name"
+                )
+                .filter(
+                    document::Column::Jsonb
+                        .json_get_text("tag", db_backend)?
+                        .eq("new")
+                )
+                .build(db_backend)
+                .to_string(),
+            [
+                r#"SELECT "document"."id", "document"."json" ->> 'name' AS "name" FROM "document""#,
+                r#"WHERE ("document"."jsonb" ->> 'tag') = 'new'"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            document::Column::Json.json_get_text("name", DbBackend::MySql),
+            Err(DbErr::BackendNotSupported {
+                db: "MySql",
+                ctx: "json_get_text",
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn eq_value_checks_column_type() {
+        use crate::{DbErr, Value};
+
+        let sql = |expr| {
+            fruit::Entity::find()
+                .filter(expr)
+                .build(DbBackend::Postgres)
+                .to_string()
+        };
+        assert!(
+            sql(fruit::Column::Id
+                .eq_value(Value::BigUnsigned(Some(1)))
+                .unwrap())
+            .ends_with(r#"WHERE "fruit"."id" = 1"#)
+        );
+        assert!(
+            sql(fruit::Column::CakeId.eq_value(Value::Int(None)).unwrap())
+                .ends_with(r#"WHERE "fruit"."cake_id" IS NULL"#)
+        );
+        assert!(
+            sql(fruit::Column::Name
+                .eq_value(Value::Char(Some('a')))
+                .unwrap())
+            .ends_with(r#"WHERE "fruit"."name" = 'a'"#)
+        );
+
+        for (col, value) in [
+            (fruit::Column::Id, Value::from("1")),
+            (fruit::Column::CakeId, Value::String(None)),
+            (fruit::Column::Name, Value::Int(Some(1))),
+            (fruit::Column::Name, Value::Bool(Some(true))),
+        ] {
+            assert!(
+                matches!(col.eq_value(value.clone()), Err(DbErr::Type(_))),
+                "{col:?} accepted {value:?}"
+            );
+        }
+        assert_eq!(
+            fruit::Column::Id.eq_value(Value::from("1")).unwrap_err(),
+            DbErr::Type(
+                r#"Value String(Some("1")) does not match the type Integer of column `id`"#
+                    .to_owned()
+            )
+        );
+    }
 }
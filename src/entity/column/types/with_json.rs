@@ -1,5 +1,6 @@
 use super::*;
 use crate::prelude::Json;
+use crate::{DbBackend, DbErr};
 
 impl<E: EntityTrait> JsonColumn<E> {
     boilerplate!(pub);
@@ -26,6 +27,24 @@ impl<E: EntityTrait> JsonColumn<E> {
         self.0.eq_any(v)
     }
 
+    /// `@>` containment operator. Postgres only.
+    pub fn json_contains<V>(&self, v: V, db_backend: DbBackend) -> Result<Expr, DbErr>
+    where
+        V: Into<Json>,
+    {
+        self.0.json_contains(v.into(), db_backend)
+    }
+
+    /// `jsonb_path_exists(..)` function. Postgres only.
+    pub fn json_path_exists(&self, path: &str, db_backend: DbBackend) -> Result<Expr, DbErr> {
+        self.0.json_path_exists(path, db_backend)
+    }
+
+    /// `->>` operator. Postgres only.
+    pub fn json_get_text(&self, key: &str, db_backend: DbBackend) -> Result<Expr, DbErr> {
+        self.0.json_get_text(key, db_backend)
+    }
+
     bind_subquery_func!(pub in_subquery);
     bind_subquery_func!(pub not_in_subquery);
 }
@@ -141,13 +141,17 @@ where
     }
 
     /// Get the total number of pages
+    ///
+    /// Every call runs its own `COUNT` query, nothing is cached on the paginator.
+    /// Use [`num_items_and_pages`](Self::num_items_and_pages) to get both totals
+    /// from a single `COUNT`.
     pub async fn num_pages(&self) -> Result<u64, DbErr> {
         let num_items = self.num_items().await?;
         let num_pages = self.compute_pages_number(num_items);
         Ok(num_pages)
     }
 
-    /// Get the total number of items and pages
+    /// Get the total number of items and pages, with a single `COUNT` query
     pub async fn num_items_and_pages(&self) -> Result<ItemsAndPagesNumber, DbErr> {
         let number_of_items = self.num_items().await?;
         let number_of_pages = self.compute_pages_number(number_of_items);
@@ -0,0 +1,58 @@
+use crate::ColumnTrait;
+
+/// An index declared on an Entity with the struct-level
+/// `#[sea_orm(index(name = "..", columns("..", ..), unique))]` attribute.
+///
+/// Returned by [`EntityTrait::indexes`](crate::EntityTrait::indexes) and turned into
+/// `CREATE INDEX` statements by [`Schema::create_index_from_entity`](crate::Schema::create_index_from_entity).
+/// Single-column indexes can also be declared with `#[sea_orm(indexed)]` on the field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexDef<C>
+where
+    C: ColumnTrait,
+{
+    pub(crate) name: String,
+    pub(crate) columns: Vec<C>,
+    pub(crate) unique: bool,
+}
+
+impl<C> IndexDef<C>
+where
+    C: ColumnTrait,
+{
+    /// A new index called `name`, without any columns yet
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            columns: Vec::new(),
+            unique: false,
+        }
+    }
+
+    /// Append a column to the index
+    pub fn col(mut self, col: C) -> Self {
+        self.columns.push(col);
+        self
+    }
+
+    /// Marks the index as `UNIQUE`
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    /// Get the name of the index
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the indexed columns, in order
+    pub fn get_columns(&self) -> &[C] {
+        &self.columns
+    }
+
+    /// Returns true if the index is `UNIQUE`
+    pub fn is_unique(&self) -> bool {
+        self.unique
+    }
+}
@@ -133,10 +133,12 @@ mod active_value;
 #[cfg(feature = "with-arrow")]
 mod arrow_schema;
 mod base_entity;
+mod cipher;
 pub(crate) mod column;
 mod column_def;
 pub mod compound;
 mod identity;
+mod index_def;
 mod link;
 mod model;
 mod partial_model;
@@ -157,10 +159,12 @@ pub use active_value::*;
 #[cfg(feature = "with-arrow")]
 pub use arrow_schema::*;
 pub use base_entity::*;
+pub use cipher::*;
 pub use column::*;
 pub use column_def::*;
 pub use compound::EntityLoaderTrait;
 pub use identity::*;
+pub use index_def::*;
 pub use link::*;
 pub use model::*;
 pub use partial_model::*;
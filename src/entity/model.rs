@@ -1,8 +1,8 @@
 use crate::{
-    ActiveModelBehavior, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, DeleteResult,
-    EntityTrait, IntoActiveModel, Iterable, Linked, PrimaryKeyArity, PrimaryKeyToColumn,
-    PrimaryKeyTrait, QueryFilter, QueryResult, Related, Select, SelectModel, SelectorRaw,
-    Statement, TryGetError, find_linked_recursive,
+    ActiveModelBehavior, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, DeleteReport,
+    DeleteResult, EntityTrait, IntoActiveModel, Iterable, Linked, PrimaryKeyArity,
+    PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, QueryResult, Related, Select, SelectModel,
+    SelectorRaw, Statement, TryGetError, find_linked_recursive,
 };
 pub use sea_query::Value;
 use sea_query::{ArrayType, ValueTuple};
@@ -82,6 +82,24 @@ pub trait ModelTrait: Clone + Send + Debug {
         self.into_active_model().delete(db).await
     }
 
+    /// Delete a model, returning it with the number of rows affected and the
+    /// relations the database cascades the delete to, see
+    /// [`EntityTrait::cascade_targets`]. Useful to log or undo a delete; no
+    /// query is made besides the `DELETE`.
+    async fn delete_with_report<'a, A, C>(self, db: &'a C) -> Result<DeleteReport<Self>, DbErr>
+    where
+        Self: IntoActiveModel<A>,
+        C: ConnectionTrait,
+        A: ActiveModelTrait<Entity = Self::Entity> + ActiveModelBehavior + Send + 'a,
+    {
+        let DeleteResult { rows_affected } = self.clone().delete(db).await?;
+        Ok(DeleteReport {
+            model: self,
+            rows_affected,
+            cascades: Self::Entity::cascade_targets(),
+        })
+    }
+
     /// Get the primary key value of the Model
     fn get_primary_key_value(&self) -> ValueTuple {
         let mut cols = <Self::Entity as EntityTrait>::PrimaryKey::iter();
@@ -116,6 +134,96 @@ pub trait ModelTrait: Clone + Send + Debug {
             }
         }
     }
+
+    /// Serialize the Model into a JSON object keyed by [`ColumnTrait::json_key`],
+    /// alongside a parallel map describing each column by its `column_type`,
+    /// whether it is `nullable` and whether it is part of the `primary_key`.
+    #[cfg(feature = "with-json")]
+    fn to_json_with_meta(&self) -> (JsonMap, JsonMap) {
+        let mut values = JsonMap::new();
+        let mut meta = JsonMap::new();
+        for col in <<Self::Entity as EntityTrait>::Column>::iter() {
+            let key = col.json_key();
+            values.insert(
+                key.to_owned(),
+                sea_query::sea_value_to_json_value(&self.get(col)),
+            );
+            meta.insert(key.to_owned(), column_json_meta::<Self::Entity>(col).into());
+        }
+        (values, meta)
+    }
+}
+
+/// A JSON object, as returned by [`ModelTrait::to_json_with_meta`]
+#[cfg(feature = "with-json")]
+pub type JsonMap = serde_json::Map<String, serde_json::Value>;
+
+#[cfg(feature = "with-json")]
+pub(super) fn column_json_meta<E>(col: E::Column) -> JsonMap
+where
+    E: EntityTrait,
+{
+    let def = col.def();
+    let mut meta = JsonMap::new();
+    meta.insert(
+        "column_type".to_owned(),
+        column_type_name(def.get_column_type()).into(),
+    );
+    meta.insert("nullable".to_owned(), def.is_null().into());
+    meta.insert(
+        "primary_key".to_owned(),
+        E::PrimaryKey::from_column(col).is_some().into(),
+    );
+    meta
+}
+
+/// The name of the variant, without its parameters
+#[cfg(feature = "with-json")]
+fn column_type_name(column_type: &sea_query::ColumnType) -> &'static str {
+    use sea_query::ColumnType;
+
+    match column_type {
+        ColumnType::Char(_) => "Char",
+        ColumnType::String(_) => "String",
+        ColumnType::Text => "Text",
+        ColumnType::Blob => "Blob",
+        ColumnType::TinyInteger => "TinyInteger",
+        ColumnType::SmallInteger => "SmallInteger",
+        ColumnType::Integer => "Integer",
+        ColumnType::BigInteger => "BigInteger",
+        ColumnType::TinyUnsigned => "TinyUnsigned",
+        ColumnType::SmallUnsigned => "SmallUnsigned",
+        ColumnType::Unsigned => "Unsigned",
+        ColumnType::BigUnsigned => "BigUnsigned",
+        ColumnType::Float => "Float",
+        ColumnType::Double => "Double",
+        ColumnType::Decimal(_) => "Decimal",
+        ColumnType::DateTime => "DateTime",
+        ColumnType::Timestamp => "Timestamp",
+        ColumnType::TimestampWithTimeZone => "TimestampWithTimeZone",
+        ColumnType::Time => "Time",
+        ColumnType::Date => "Date",
+        ColumnType::Year => "Year",
+        ColumnType::Interval(_, _) => "Interval",
+        ColumnType::Binary(_) => "Binary",
+        ColumnType::VarBinary(_) => "VarBinary",
+        ColumnType::Bit(_) => "Bit",
+        ColumnType::VarBit(_) => "VarBit",
+        ColumnType::Boolean => "Boolean",
+        ColumnType::Money(_) => "Money",
+        ColumnType::Json => "Json",
+        ColumnType::JsonBinary => "JsonBinary",
+        ColumnType::Uuid => "Uuid",
+        ColumnType::Custom(_) => "Custom",
+        ColumnType::Enum { .. } => "Enum",
+        ColumnType::Array(_) => "Array",
+        ColumnType::Vector(_) => "Vector",
+        ColumnType::Cidr => "Cidr",
+        ColumnType::Inet => "Inet",
+        ColumnType::MacAddr => "MacAddr",
+        ColumnType::LTree => "LTree",
+        _ => "Unknown",
+    }
 }
 
 /// Construct a value from a [`QueryResult`] row.
@@ -354,4 +462,30 @@ mod tests {
 
         assert_eq!(cake_ex.into_active_model(), cake_am);
     }
+
+    #[test]
+    #[cfg(feature = "with-json")]
+    fn test_to_json_with_meta() {
+        use serde_json::json;
+
+        let (values, meta) = fruit::Model {
+            id: 1,
+            name: "Apple".into(),
+            cake_id: None,
+        }
+        .to_json_with_meta();
+
+        assert_eq!(
+            serde_json::Value::Object(values),
+            json!({ "id": 1, "name": "Apple", "cake_id": null })
+        );
+        assert_eq!(
+            serde_json::Value::Object(meta),
+            json!({
+                "id": { "column_type": "Integer", "nullable": false, "primary_key": true },
+                "name": { "column_type": "String", "nullable": false, "primary_key": false },
+                "cake_id": { "column_type": "Integer", "nullable": true, "primary_key": false },
+            })
+        );
+    }
 }
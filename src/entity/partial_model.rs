@@ -18,6 +18,13 @@ pub trait PartialModelTrait: FromQueryResult {
         Self::select_cols_nested(select, None, None)
     }
 
+    /// Add the partial model's columns to a [`QuerySelect`]'s projection, aliased
+    /// as `{prefix}{column}`. Used by `SelectTwo` etc., which read the models of
+    /// each entity from columns prefixed with `A_`, `B_`, and so on.
+    fn select_cols_with_prefix<S: QuerySelect>(select: S, prefix: &str) -> S {
+        Self::select_cols_nested(select, Some(prefix), None)
+    }
+
     /// Used when nesting these structs into each other.
     ///
     /// Example impl
@@ -1,9 +1,9 @@
 pub use crate::{
-    ActiveEnum, ActiveModelBehavior, ActiveModelTrait, ColumnDef, ColumnTrait, ColumnType,
-    ColumnTypeTrait, ConnectionTrait, CursorTrait, DatabaseConnection, DbConn, EntityName,
-    EntityTrait, EnumIter, ForeignKeyAction, Iden, IdenStatic, Linked, LoaderTrait, ModelTrait,
-    PaginatorTrait, PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, QueryResult,
-    Related, RelatedSelfVia, RelationDef, RelationTrait, Select, SelectExt, Value,
+    ActiveEnum, ActiveModelBehavior, ActiveModelTrait, ColumnCipher, ColumnDef, ColumnTrait,
+    ColumnType, ColumnTypeTrait, ConnectionTrait, CursorTrait, DatabaseConnection, DbConn,
+    EntityName, EntityTrait, EnumIter, ForeignKeyAction, Iden, IdenStatic, Linked, LoaderTrait,
+    ModelTrait, PaginatorTrait, PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter,
+    QueryResult, Related, RelatedSelfVia, RelationDef, RelationTrait, Select, SelectExt, Value,
     error::*,
     sea_query::{DynIden, Expr, RcOrArc, SeaRc, StringLen},
 };
@@ -89,9 +89,14 @@ pub use time::OffsetDateTime as TimeDateTimeWithTimeZone;
 #[cfg(feature = "with-time")]
 pub use crate::value::{TimeUnixTimestamp, TimeUnixTimestampMillis};
 
+pub use crate::value::FixedBytes;
+
 #[cfg(feature = "with-rust_decimal")]
 pub use rust_decimal::Decimal;
 
+#[cfg(feature = "with-rust_decimal")]
+pub use crate::value::TryIntoDecimal;
+
 #[cfg(feature = "with-bigdecimal")]
 pub use bigdecimal::BigDecimal;
 
@@ -106,3 +111,9 @@ pub use pgvector::Vector as PgVector;
 
 #[cfg(feature = "with-ipnetwork")]
 pub use ipnetwork::IpNetwork;
+
+#[cfg(feature = "with-ipnetwork")]
+pub use crate::value::IpAddress;
+
+#[cfg(feature = "with-mac_address")]
+pub use mac_address::MacAddress;
@@ -1,5 +1,6 @@
 use crate::{
-    EntityTrait, Identity, IdentityOf, Iterable, QuerySelect, Select, join_tbl_on_condition,
+    DbBackend, EntityTrait, Identity, IdentityOf, Iterable, QuerySelect, Select,
+    join_tbl_on_condition,
 };
 use core::marker::PhantomData;
 use sea_query::{
@@ -52,6 +53,27 @@ where
         None
     }
 
+    /// The junction table relation of a many-to-many `Self <-> R`, as declared on the
+    /// junction entity, i.e. from the junction table to `Self`. Together with [`to`](Self::to),
+    /// which goes from the junction table to `R`, this describes both foreign keys of the
+    /// junction table. `None` if the relation is not many-to-many.
+    ///
+    /// ```
+    /// use sea_orm::{
+    ///     Related,
+    ///     sea_query::IntoIden,
+    ///     tests_cfg::{cake, filling},
+    /// };
+    ///
+    /// let via = <cake::Entity as Related<filling::Entity>>::via_def().unwrap();
+    /// assert_eq!(via.from_tbl().sea_orm_table(), &"cake_filling".into_iden());
+    /// assert_eq!(via.to_tbl().sea_orm_table(), &"cake".into_iden());
+    /// assert!(!via.is_owner()); // `cake_filling` belongs to `cake`
+    /// ```
+    fn via_def() -> Option<RelationDef> {
+        Self::via().map(RelationDef::rev)
+    }
+
     /// Build a [`Select<R>`] pre-joined with `Self` via this relation.
     fn find_related() -> Select<R> {
         Select::<R>::new().join_join_rev(JoinType::InnerJoin, Self::to(), Self::via())
@@ -96,7 +118,8 @@ pub struct RelationDef {
     pub from_col: Identity,
     /// Referenced column(s) on [`to_tbl`](Self::to_tbl) (usually the primary key).
     pub to_col: Identity,
-    /// `true` if `Self` is the FK-owning side (i.e. holds `from_col`).
+    /// `true` if `Self` owns the related rows (`has_one` / `has_many`), `false` if
+    /// `Self` belongs to them (`belongs_to`).
     pub is_owner: bool,
     /// Skip emitting a `FOREIGN KEY` constraint when this relation is used
     /// to generate schema DDL.
@@ -114,6 +137,10 @@ pub struct RelationDef {
     /// How [`on_condition`](Self::on_condition) is combined with the
     /// column equality predicate (`All` = AND, `Any` = OR).
     pub condition_type: ConditionType,
+    /// Match `NULL` key columns against each other in the join condition,
+    /// with the null-safe comparison of this backend;
+    /// see [`RelationDef::treat_nulls_as_equal`].
+    pub treat_nulls_as_equal: Option<DbBackend>,
 }
 
 /// Idiomatically generate the join condition.
@@ -169,6 +196,7 @@ impl From<RelationDef> for Condition {
             to_tbl.clone(),
             owner_keys,
             foreign_keys,
+            rel.treat_nulls_as_equal,
         ));
         if let Some(f) = rel.on_condition.take() {
             condition = condition.add(f(from_tbl.clone(), to_tbl.clone()));
@@ -201,6 +229,7 @@ where
     on_condition: Option<Arc<dyn Fn(DynIden, DynIden) -> Condition + Send + Sync>>,
     fk_name: Option<String>,
     condition_type: ConditionType,
+    treat_nulls_as_equal: Option<DbBackend>,
 }
 
 impl RelationDef {
@@ -220,9 +249,55 @@ impl RelationDef {
             on_condition: self.on_condition,
             fk_name: None,
             condition_type: self.condition_type,
+            treat_nulls_as_equal: self.treat_nulls_as_equal,
         }
     }
 
+    /// Cardinality of the relation.
+    pub fn rel_type(&self) -> &RelationType {
+        &self.rel_type
+    }
+
+    /// Table at the FK-owning end of the relation.
+    pub fn from_tbl(&self) -> &TableRef {
+        &self.from_tbl
+    }
+
+    /// Table being pointed to by the foreign key.
+    pub fn to_tbl(&self) -> &TableRef {
+        &self.to_tbl
+    }
+
+    /// FK column(s) on [`from_tbl`](Self::from_tbl).
+    pub fn from_col(&self) -> &Identity {
+        &self.from_col
+    }
+
+    /// Referenced column(s) on [`to_tbl`](Self::to_tbl).
+    pub fn to_col(&self) -> &Identity {
+        &self.to_col
+    }
+
+    /// The `(from_col, to_col)` column pairs joined on, in order.
+    pub fn column_pairs(&self) -> impl Iterator<Item = (&DynIden, &DynIden)> {
+        self.from_col.iter().zip(self.to_col.iter())
+    }
+
+    /// `true` for `has_one` / `has_many`, `false` for `belongs_to`.
+    pub fn is_owner(&self) -> bool {
+        self.is_owner
+    }
+
+    /// `ON DELETE` action for the foreign key.
+    pub fn on_delete(&self) -> Option<ForeignKeyAction> {
+        self.on_delete
+    }
+
+    /// `ON UPDATE` action for the foreign key.
+    pub fn on_update(&self) -> Option<ForeignKeyAction> {
+        self.on_update
+    }
+
     /// Express the relation from a table alias.
     ///
     /// This is a shorter and more discoverable equivalent to modifying `from_tbl` field by hand.
@@ -277,6 +352,12 @@ impl RelationDef {
     ///
     /// This method takes a closure with two parameters
     /// denoting the left-hand side and right-hand side table in the join expression.
+    /// When a table is joined under an alias, as with [`join_as`](crate::QuerySelect::join_as)
+    /// or the `r0`, `r1`, ... hops of a [`Linked`](crate::Linked) query, the closure receives
+    /// the alias, so qualify columns with these parameters rather than with the Entity.
+    ///
+    /// In `#[sea_orm(on_condition = "...")]` of [`DeriveRelation`](crate::DeriveRelation),
+    /// the condition can likewise be written as a closure `|left, right| ...`.
     ///
     /// This replaces the current condition if it is already set.
     ///
@@ -351,6 +432,65 @@ impl RelationDef {
         self.condition_type = condition_type;
         self
     }
+
+    /// Match `NULL` key columns against each other in the join condition, so
+    /// that rows whose (nullable) foreign key is `NULL` pair up with related
+    /// rows whose key is `NULL` as well.
+    ///
+    /// Each key column pair is compared with the null-safe equality of
+    /// `db_backend` instead of `=`: `IS NOT DISTINCT FROM` on Postgres, `<=>` on
+    /// MySQL and `(a = b OR (a IS NULL AND b IS NULL))` on SQLite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_orm::{
+    ///     DbBackend,
+    ///     entity::*,
+    ///     query::*,
+    ///     tests_cfg::{cake, fruit},
+    /// };
+    ///
+    /// let query = |db_backend| {
+    ///     fruit::Entity::find()
+    ///         .join(
+    ///             JoinType::LeftJoin,
+    ///             fruit::Relation::Cake.def().treat_nulls_as_equal(db_backend),
+    ///         )
+    ///         .build(db_backend)
+    ///         .to_string()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     query(DbBackend::Postgres),
+    ///     [
+    ///         r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+    ///         r#"LEFT JOIN "cake" ON "fruit"."cake_id" IS NOT DISTINCT FROM "cake"."id""#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// assert_eq!(
+    ///     query(DbBackend::MySql),
+    ///     [
+    ///         "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit`",
+    ///         "LEFT JOIN `cake` ON `fruit`.`cake_id` <=> `cake`.`id`",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// assert_eq!(
+    ///     query(DbBackend::Sqlite),
+    ///     [
+    ///         r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+    ///         r#"LEFT JOIN "cake" ON "fruit"."cake_id" = "cake"."id""#,
+    ///         r#"OR ("fruit"."cake_id" IS NULL AND "cake"."id" IS NULL)"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn treat_nulls_as_equal(mut self, db_backend: DbBackend) -> Self {
+        self.treat_nulls_as_equal = Some(db_backend);
+        self
+    }
 }
 
 impl<E, R> RelationBuilder<E, R>
@@ -373,6 +513,7 @@ where
             on_condition: None,
             fk_name: None,
             condition_type: ConditionType::All,
+            treat_nulls_as_equal: None,
         }
     }
 
@@ -386,11 +527,13 @@ where
             to_col: Some(rel.to_col),
             is_owner,
             skip_fk: false,
-            on_delete: None,
-            on_update: None,
+            // keep the foreign key actions of the reverse relation for introspection
+            on_delete: rel.on_delete,
+            on_update: rel.on_update,
             on_condition: None,
             fk_name: None,
             condition_type: ConditionType::All,
+            treat_nulls_as_equal: None,
         }
     }
 
@@ -436,7 +579,8 @@ where
     }
 
     /// Add an extra predicate to the join's `ON` clause. The closure receives
-    /// the left-hand-side and right-hand-side table idens.
+    /// the left-hand-side and right-hand-side table idens, or their aliases when joined
+    /// under one; see [`RelationDef::on_condition`].
     pub fn on_condition<F>(mut self, f: F) -> Self
     where
         F: Fn(DynIden, DynIden) -> Condition + 'static + Send + Sync,
@@ -457,6 +601,13 @@ where
         self.condition_type = condition_type;
         self
     }
+
+    /// Match `NULL` key columns against each other when joining, with the
+    /// null-safe comparison of `db_backend`; see [`RelationDef::treat_nulls_as_equal`].
+    pub fn treat_nulls_as_equal(mut self, db_backend: DbBackend) -> Self {
+        self.treat_nulls_as_equal = Some(db_backend);
+        self
+    }
 }
 
 impl<E, R> From<RelationBuilder<E, R>> for RelationDef
@@ -478,6 +629,7 @@ where
             on_condition: b.on_condition,
             fk_name: b.fk_name,
             condition_type: b.condition_type,
+            treat_nulls_as_equal: b.treat_nulls_as_equal,
         }
     }
 }
@@ -541,6 +693,7 @@ impl From<RelationDef> for ForeignKeyCreateStatement {
 ///     fk_name: Some("foo-bar".to_string()),
 ///     skip_fk: false,
 ///     condition_type: ConditionType::All,
+///     treat_nulls_as_equal: None,
 /// };
 ///
 /// let mut alter_table = TableAlterStatement::new()
@@ -586,12 +739,87 @@ impl PartialEq for RelationDef {
 
 impl Eq for RelationDef {}
 
+/// The Rust type of a column, keyed by the discriminant of its `Column` variant,
+/// without `Option`. Implemented by `DeriveEntityModel`.
+#[doc(hidden)]
+pub trait ColumnValueType<const I: usize> {
+    type Value;
+}
+
+/// The `from` and `to` columns of a relation, used by `DeriveRelation` to check
+/// their types match
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct RelationColumns<F, const I: usize, T, const J: usize>(PhantomData<(F, T)>);
+
+impl<F, const I: usize, T, const J: usize> RelationColumns<F, I, T, J> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<F, const I: usize, T, const J: usize> Default for RelationColumns<F, I, T, J> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[doc(hidden)]
+pub trait RelationColumnTypes {
+    type From;
+    type To;
+
+    fn types(&self) -> (PhantomData<Self::From>, PhantomData<Self::To>) {
+        (PhantomData, PhantomData)
+    }
+}
+
+impl<F, const I: usize, T, const J: usize> RelationColumnTypes for RelationColumns<F, I, T, J>
+where
+    F: ColumnValueType<I>,
+    T: ColumnValueType<J>,
+{
+    type From = F::Value;
+    type To = T::Value;
+}
+
+/// Picked by method resolution when the type of either column is not known,
+/// i.e. its `Column` enum is not derived by `DeriveEntityModel`
+#[doc(hidden)]
+pub trait RelationColumnTypesUnknown {
+    fn types(&self) -> (PhantomData<()>, PhantomData<()>) {
+        (PhantomData, PhantomData)
+    }
+}
+
+impl<F, const I: usize, T, const J: usize> RelationColumnTypesUnknown
+    for &RelationColumns<F, I, T, J>
+{
+}
+
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "relation column types differ, `from` is `{Self}` but `to` is `{T}`",
+    label = "expected a column of type `{T}`"
+)]
+pub trait SameColumnType<T> {}
+
+impl<T> SameColumnType<T> for T {}
+
+#[doc(hidden)]
+pub fn assert_same_column_type<F, T>(_: (PhantomData<F>, PhantomData<T>))
+where
+    F: SameColumnType<T>,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        RelationBuilder, RelationDef,
-        tests_cfg::{cake, fruit},
+        ForeignKeyAction, Related, RelationBuilder, RelationDef, RelationType,
+        tests_cfg::{cake, filling, fruit},
     };
+    use sea_query::{DynIden, IntoIden};
 
     #[cfg(not(feature = "sync"))]
     #[test]
@@ -601,4 +829,67 @@ mod tests {
         assert_send_sync::<RelationDef>();
         assert_send_sync::<RelationBuilder<cake::Entity, fruit::Entity>>();
     }
+
+    #[test]
+    fn introspect_via_relation() {
+        fn dump(rel: &RelationDef) -> (DynIden, DynIden, Vec<(String, String)>) {
+            (
+                rel.from_tbl().sea_orm_table().clone(),
+                rel.to_tbl().sea_orm_table().clone(),
+                rel.column_pairs()
+                    .map(|(from, to)| (from.to_string(), to.to_string()))
+                    .collect(),
+            )
+        }
+        let pair = |from: &str, to: &str| vec![(from.to_owned(), to.to_owned())];
+
+        // both go from the junction table to either end
+        let via = <cake::Entity as Related<filling::Entity>>::via_def().unwrap();
+        let to = <cake::Entity as Related<filling::Entity>>::to();
+        assert_eq!(
+            dump(&via),
+            (
+                "cake_filling".into_iden(),
+                "cake".into_iden(),
+                pair("cake_id", "id")
+            )
+        );
+        assert_eq!(
+            dump(&to),
+            (
+                "cake_filling".into_iden(),
+                "filling".into_iden(),
+                pair("filling_id", "id")
+            )
+        );
+        for rel in [&via, &to] {
+            assert_eq!(rel.rel_type(), &RelationType::HasOne);
+            assert!(!rel.is_owner());
+            assert!(matches!(rel.on_delete(), Some(ForeignKeyAction::Cascade)));
+            assert!(matches!(rel.on_update(), Some(ForeignKeyAction::Cascade)));
+        }
+
+        // and the other way round
+        let via = <filling::Entity as Related<cake::Entity>>::via_def().unwrap();
+        assert_eq!(
+            dump(&via),
+            (
+                "cake_filling".into_iden(),
+                "filling".into_iden(),
+                pair("filling_id", "id")
+            )
+        );
+
+        assert!(<cake::Entity as Related<fruit::Entity>>::via_def().is_none());
+        let to = <cake::Entity as Related<fruit::Entity>>::to();
+        assert_eq!(
+            dump(&to),
+            (
+                "cake".into_iden(),
+                "fruit".into_iden(),
+                pair("id", "cake_id")
+            )
+        );
+        assert!(to.is_owner());
+    }
 }
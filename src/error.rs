@@ -51,6 +51,9 @@ pub enum DbErr {
     /// The record was not found in the database
     #[error("RecordNotFound Error: {0}")]
     RecordNotFound(String),
+    /// More than one record was found where exactly one was expected
+    #[error("MultipleRecordsFound Error: {0}")]
+    MultipleRecordsFound(String),
     /// Thrown by `TryFrom<ActiveModel>`, which assumes all attributes are set/unchanged
     #[error("Attribute {0} is NotSet")]
     AttrNotSet(String),
@@ -63,6 +66,14 @@ pub enum DbErr {
     /// Error occurred while parsing json value as target type
     #[error("Json Error: {0}")]
     Json(String),
+    /// Error occurred while parsing the value of a field of a json object as target type
+    #[error("Json Error: field `{field}`: {source_msg}")]
+    JsonField {
+        /// The json key of the field
+        field: String,
+        /// The error parsing its value
+        source_msg: String,
+    },
     /// A migration error
     #[error("Migration Error: {0}")]
     Migration(String),
@@ -90,12 +101,20 @@ pub enum DbErr {
         /// Received value
         received: u8,
     },
-    /// Primay key not set for update / delete
-    #[error("Primay key not set for {ctx}")]
+    /// Primary key not set for update / delete
+    #[error("Primary key not set for {ctx}")]
     PrimaryKeyNotSet {
         /// Context
         ctx: &'static str,
     },
+    /// A primary key column that has to be given is not set, e.g. for insert
+    #[error("Primary key column `{column}` not set for {ctx}")]
+    PrimaryKeyColumnNotSet {
+        /// Context
+        ctx: &'static str,
+        /// The primary key column left `NotSet`
+        column: &'static str,
+    },
     /// Error while running RBAC checks
     #[error("RBAC error: {0}")]
     RbacError(String),
@@ -107,9 +126,65 @@ pub enum DbErr {
         /// The requested resource
         resource: String,
     },
+    /// A statement reads a scoped entity where the condition of its scope
+    /// cannot be added, see [`QueryScope::build`](crate::QueryScope::build)
+    #[error("Statement against `{table}` is out of scope: {sql}")]
+    ScopeViolation {
+        /// The table of the scoped entity
+        table: String,
+        /// The rejected statement
+        sql: String,
+    },
     /// Mutex was poisoned by another thread
     #[error("Mutex poisoned")]
     MutexPoisonError,
+    /// An ActiveModel was rejected by [`ActiveModelBehavior::validate`](crate::ActiveModelBehavior::validate)
+    #[error("Validation Error: {}", display_validation_errors(.0))]
+    Validation(Vec<FieldValidationError>),
+    /// Plain columns are selected without being grouped by, see
+    /// [`QuerySelect::validate_group_by`](crate::QuerySelect::validate_group_by)
+    #[error(
+        "Selected columns must appear in the GROUP BY clause or be used in an aggregate function: {}",
+        .0.join(", ")
+    )]
+    GroupByMissing(Vec<String>),
+    /// The statement ran over its [`Statement::timeout`](crate::Statement::timeout)
+    /// and was cancelled by the database
+    #[error("Query timed out after {0:?}")]
+    QueryTimeout(std::time::Duration),
+}
+
+/// A validation failure of a single column, see
+/// [`ActiveModelBehavior::validate`](crate::ActiveModelBehavior::validate)
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{column}: {message}")]
+pub struct FieldValidationError {
+    /// The column failing validation
+    pub column: String,
+    /// What is wrong with the value
+    pub message: String,
+}
+
+impl FieldValidationError {
+    /// Create a new validation error for `column`
+    pub fn new<C, M>(column: C, message: M) -> Self
+    where
+        C: Into<String>,
+        M: Into<String>,
+    {
+        Self {
+            column: column.into(),
+            message: message.into(),
+        }
+    }
+}
+
+fn display_validation_errors(errors: &[FieldValidationError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
 }
 
 /// An error from trying to get a row from a Model
@@ -157,9 +232,11 @@ impl PartialEq for DbErr {
 impl Eq for DbErr {}
 
 /// Error during `impl FromStr for Entity::Column`
+///
+/// Holds the rejected input and the column names that would have been accepted.
 #[derive(Error, Debug)]
-#[error("Failed to match \"{0}\" as Column")]
-pub struct ColumnFromStrErr(pub String);
+#[error("Failed to match \"{}\" as Column, expected one of: {}", .0, .1.join(", "))]
+pub struct ColumnFromStrErr(pub String, pub &'static [&'static str]);
 
 #[allow(dead_code)]
 pub(crate) fn conn_err<T>(s: T) -> DbErr
@@ -313,4 +390,35 @@ impl DbErr {
         }
         None
     }
+
+    /// Whether SQLite failed with `SQLITE_BUSY` or `SQLITE_LOCKED`: another connection
+    /// holds a lock on the database, and the statement may succeed if run again.
+    ///
+    /// Outside of a transaction, such statements are retried if
+    /// [`ConnectOptions::sqlite_busy_retry`](crate::ConnectOptions::sqlite_busy_retry) is set;
+    /// this is the error once the retries are exhausted. Inside of a transaction they are
+    /// never retried, the whole transaction should be.
+    pub fn is_busy(&self) -> bool {
+        #[cfg(feature = "sqlx-sqlite")]
+        if let DbErr::Exec(RuntimeErr::SqlxError(e)) | DbErr::Query(RuntimeErr::SqlxError(e)) = self
+            && let sqlx::Error::Database(e) = e.as_ref()
+            && e.try_downcast_ref::<sqlx::sqlite::SqliteError>().is_some()
+        {
+            // the extended result code, its primary code is the lowest byte
+            let code: i32 = e
+                .code()
+                .and_then(|code| code.parse().ok())
+                .unwrap_or_default();
+            // 5 SQLITE_BUSY, 6 SQLITE_LOCKED
+            return matches!(code & 0xff, 5 | 6);
+        }
+        #[cfg(feature = "rusqlite")]
+        if let DbErr::Exec(RuntimeErr::Rusqlite(err)) | DbErr::Query(RuntimeErr::Rusqlite(err)) =
+            self
+            && let crate::driver::rusqlite::RusqliteError::SqliteFailure(err, _) = err.as_ref()
+        {
+            return matches!(err.extended_code & 0xff, 5 | 6);
+        }
+        false
+    }
 }
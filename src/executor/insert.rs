@@ -1,10 +1,14 @@
 use super::ReturningSelector;
 use crate::{
-    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, Insert, InsertMany,
-    IntoActiveModel, Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, SelectModel, TryFromU64,
-    TryInsert, error::*,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult,
+    Insert, InsertFromSelect, InsertMany, IntoActiveModel, Iterable, ModelTrait,
+    PrimaryKeyToColumn, PrimaryKeyTrait, Select, SelectModel, TryFromU64, TryInsert,
+    entity::column::select_as_named, error::*,
+};
+use sea_query::{
+    Expr, FromValueTuple, Func, Iden, InsertStatement, OnConflict, Query, ReturningClause,
+    TableRef, ValueTuple,
 };
-use sea_query::{FromValueTuple, Iden, InsertStatement, Query, ReturningClause, ValueTuple};
 use std::marker::PhantomData;
 
 type PrimaryKey<A> = <<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey;
@@ -21,6 +25,7 @@ where
 {
     primary_key: Option<ValueTuple>,
     query: InsertStatement,
+    table_ref: Option<TableRef>,
     model: PhantomData<A>,
 }
 
@@ -34,6 +39,10 @@ where
 {
     /// Primary key of the inserted row.
     pub last_insert_id: <PrimaryKey<A> as PrimaryKeyTrait>::ValueType,
+    /// Number of rows inserted. With `RETURNING` this is the number of rows returned,
+    /// otherwise it's the affected rows count reported by the database (on MySQL an
+    /// `ON DUPLICATE KEY UPDATE` counts an updated row twice).
+    pub rows_affected: u64,
 }
 
 /// Result of inserting many ActiveModels: the primary key of the last row
@@ -46,6 +55,8 @@ where
 {
     /// Primary key of the last inserted row, if any.
     pub last_insert_id: Option<<PrimaryKey<A> as PrimaryKeyTrait>::ValueType>,
+    /// Number of rows inserted, see [`InsertResult::rows_affected`].
+    pub rows_affected: u64,
 }
 
 /// The result of executing a [`crate::TryInsert`].
@@ -57,6 +68,7 @@ pub enum TryInsertResult<T> {
     /// There was nothing to insert, so no SQL was executed.
     ///
     /// This typically happens when creating a [`crate::TryInsert`] from an empty iterator or None.
+    /// The `exec_with_returning_*` methods returning a `Vec` give `Inserted(vec![])` instead.
     Empty,
     /// The statement was executed, but SeaORM could not get the inserted row / insert id.
     ///
@@ -73,6 +85,18 @@ pub enum TryInsertResult<T> {
     Inserted(T),
 }
 
+/// Whether an upsert inserted a new row or updated an existing one, as
+/// reported by [`Insert::exec_with_returning_outcome`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpsertOutcome {
+    /// A new row was inserted
+    Inserted,
+    /// The insert conflicted and the existing row was updated
+    Updated,
+    /// The backend gave no reliable way to tell
+    Unknown,
+}
+
 impl<A> TryInsertResult<InsertResult<A>>
 where
     A: ActiveModelTrait,
@@ -91,6 +115,14 @@ where
             Self::Conflicted => Err(DbErr::RecordNotInserted),
         }
     }
+
+    /// Number of rows inserted, `0` if there was nothing to insert or the insert conflicted.
+    pub fn rows_affected(&self) -> u64 {
+        match self {
+            Self::Empty | Self::Conflicted => 0,
+            Self::Inserted(v) => v.rows_affected,
+        }
+    }
 }
 
 impl<A> TryInsert<A>
@@ -150,7 +182,8 @@ where
         }
     }
 
-    /// Execute an insert operation and return primary keys of inserted models
+    /// Execute an insert operation and return primary keys of inserted models.
+    /// With nothing to insert, no SQL is executed and an empty `Vec` is returned.
     pub async fn exec_with_returning_keys<C>(
         self,
         db: &C,
@@ -160,7 +193,7 @@ where
         C: ConnectionTrait,
     {
         if self.empty {
-            return Ok(TryInsertResult::Empty);
+            return Ok(TryInsertResult::Inserted(Vec::new()));
         }
 
         let res = self.insert_struct.exec_with_returning_keys(db).await;
@@ -171,7 +204,9 @@ where
         }
     }
 
-    /// Execute an insert operation and return all inserted models
+    /// Execute an insert operation and return all inserted models.
+    /// With nothing to insert, no SQL is executed and an empty `Vec` is returned,
+    /// same as [`InsertMany::exec_with_returning`].
     pub async fn exec_with_returning_many<C>(
         self,
         db: &C,
@@ -181,7 +216,7 @@ where
         C: ConnectionTrait,
     {
         if self.empty {
-            return Ok(TryInsertResult::Empty);
+            return Ok(TryInsertResult::Inserted(Vec::new()));
         }
 
         let res = self.insert_struct.exec_with_returning_many(db).await;
@@ -203,6 +238,8 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
+        self.primary_key_check?;
+        check_set_default(self.set_default, db)?;
         // so that self is dropped before entering await
         let mut query = self.query;
         if db.support_returning() {
@@ -218,6 +255,8 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
+        self.primary_key_check?;
+        check_set_default(self.set_default, db)?;
         Inserter::<A>::new(self.primary_key, self.query)
             .exec_without_returning(db)
             .await
@@ -237,11 +276,95 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
+        self.primary_key_check?;
+        check_set_default(self.set_default, db)?;
         Inserter::<A>::new(self.primary_key, self.query)
+            .table_ref(self.table_ref)
             .exec_with_returning(db)
             .await
     }
 
+    /// Execute an upsert (an insert with `ON CONFLICT ... DO UPDATE`) and return
+    /// the resulting model, along with whether the row was inserted or updated.
+    ///
+    /// How the outcome is detected depends on the backend:
+    ///
+    /// + Postgres: `RETURNING (xmax = 0)`, which is only true for freshly inserted rows.
+    /// + MySQL: the affected-rows count of `ON DUPLICATE KEY UPDATE`, which is `2` for an
+    ///   updated row. A count of `1` is reported both for an inserted row and for a conflicting
+    ///   row left unchanged, so it is [`UpsertOutcome::Unknown`]. Without a primary key set on
+    ///   the ActiveModel, the update sets `id = LAST_INSERT_ID(id)` on the auto-increment key,
+    ///   so the row can be read back either way.
+    /// + SQLite: checks whether the primary key already exists before running the upsert.
+    ///   This needs a primary key that is not auto-increment, set on the ActiveModel.
+    ///
+    /// [`UpsertOutcome::Unknown`] is returned when none of the above applies.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[maplit::btreemap! {
+    /// #         "id" => Into::<Value>::into(1),
+    /// #         "name" => Into::<Value>::into("Apple Pie"),
+    /// #         "_sea_orm_upsert_inserted" => Into::<Value>::into(false),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{UpsertOutcome, entity::*, query::*, sea_query::OnConflict, tests_cfg::cake};
+    ///
+    /// let (model, outcome) = cake::Entity::insert(cake::ActiveModel {
+    ///     id: Set(1),
+    ///     name: Set("Apple Pie".to_owned()),
+    /// })
+    /// .on_conflict(
+    ///     OnConflict::column(cake::Column::Id)
+    ///         .update_column(cake::Column::Name)
+    ///         .to_owned(),
+    /// )
+    /// .exec_with_returning_outcome(&db)
+    /// .await?;
+    ///
+    /// assert_eq!(model.name, "Apple Pie");
+    /// assert_eq!(outcome, UpsertOutcome::Updated);
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"INSERT INTO "cake" ("id", "name") VALUES ($1, $2) ON CONFLICT ("id") DO UPDATE SET "name" = "excluded"."name" RETURNING "id", "name", (xmax = 0) AS "_sea_orm_upsert_inserted""#,
+    ///         [1.into(), "Apple Pie".into()]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn exec_with_returning_outcome<'a, C>(
+        self,
+        db: &'a C,
+    ) -> Result<(<A::Entity as EntityTrait>::Model, UpsertOutcome), DbErr>
+    where
+        <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+        C: ConnectionTrait,
+        A: 'a,
+    {
+        self.primary_key_check?;
+        check_set_default(self.set_default, db)?;
+        exec_upsert_with_returning_outcome::<A, _>(
+            self.primary_key,
+            self.on_conflict,
+            self.query,
+            self.table_ref,
+            db,
+        )
+        .await
+    }
+
     /// Execute an insert operation and return primary keys of inserted models
     pub async fn exec_with_returning_keys<'a, C>(
         self,
@@ -252,6 +375,8 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
+        self.primary_key_check?;
+        check_set_default(self.set_default, db)?;
         Inserter::<A>::new(self.primary_key, self.query)
             .exec_with_returning_keys(db)
             .await
@@ -267,6 +392,8 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
+        self.primary_key_check?;
+        check_set_default(self.set_default, db)?;
         Inserter::<A>::new(self.primary_key, self.query)
             .exec_with_returning_many(db)
             .await
@@ -285,12 +412,14 @@ where
         if self.empty {
             return Ok(InsertManyResult {
                 last_insert_id: None,
+                rows_affected: 0,
             });
         }
         let res = self.into_one().exec(db).await;
         match res {
             Ok(r) => Ok(InsertManyResult {
                 last_insert_id: Some(r.last_insert_id),
+                rows_affected: r.rows_affected,
             }),
             Err(err) => Err(err),
         }
@@ -361,6 +490,41 @@ where
     }
 }
 
+impl<E> InsertFromSelect<E>
+where
+    E: EntityTrait,
+{
+    /// Execute an `INSERT INTO ... SELECT`, returning the number of rows inserted
+    pub async fn exec<C>(self, db: &C) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        exec_insert_without_returning(self.query, db).await
+    }
+
+    /// Execute an `INSERT INTO ... SELECT` and return the inserted models (uses
+    /// `RETURNING`, fails if the backend doesn't support it)
+    pub async fn exec_with_returning<C>(self, db: &C) -> Result<Vec<E::Model>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let db_backend = db.get_database_backend();
+        if !db.support_returning() {
+            return Err(DbErr::BackendNotSupported {
+                db: db_backend.as_str(),
+                ctx: "INSERT RETURNING",
+            });
+        }
+        let mut query = self.query;
+        query.returning(Query::returning().exprs(
+            E::Column::iter().map(|c| select_as_named(&c, c.into_returning_expr(db_backend))),
+        ));
+        ReturningSelector::<SelectModel<E::Model>, _>::from_query(query)
+            .all(db)
+            .await
+    }
+}
+
 impl<A> Inserter<A>
 where
     A: ActiveModelTrait,
@@ -370,10 +534,17 @@ where
         Self {
             primary_key,
             query,
+            table_ref: None,
             model: PhantomData,
         }
     }
 
+    /// The table the statement inserts into, if not the entity's own table
+    fn table_ref(mut self, table_ref: Option<TableRef>) -> Self {
+        self.table_ref = table_ref;
+        self
+    }
+
     /// Execute an insert operation, returning the last inserted id
     pub async fn exec<'a, C>(self, db: &'a C) -> Result<InsertResult<A>, DbErr>
     where
@@ -402,7 +573,7 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        exec_insert_with_returning::<A, _>(self.primary_key, self.query, db).await
+        exec_insert_with_returning::<A, _>(self.primary_key, self.query, self.table_ref, db).await
     }
 
     /// Execute an insert operation and return primary keys of inserted models
@@ -445,9 +616,10 @@ where
 
     let db_backend = db.get_database_backend();
 
-    let last_insert_id = match (primary_key, db.support_returning()) {
+    let (last_insert_id, rows_affected) = match (primary_key, db.support_returning()) {
         (_, true) => {
             let mut rows = db.query_all(&statement).await?;
+            let rows_affected = rows.len() as u64;
             let row = match rows.pop() {
                 Some(row) => row,
                 None => return Err(DbErr::RecordNotInserted),
@@ -455,21 +627,33 @@ where
             let cols = PrimaryKey::<A>::iter()
                 .map(|col| col.to_string())
                 .collect::<Vec<_>>();
-            row.try_get_many("", cols.as_ref())
-                .map_err(|_| DbErr::UnpackInsertId)?
+            let last_insert_id = row
+                .try_get_many("", cols.as_ref())
+                .map_err(|_| DbErr::UnpackInsertId)?;
+            (last_insert_id, rows_affected)
         }
         (Some(value_tuple), false) => {
             let res = db.execute(&statement).await?;
             if res.rows_affected() == 0 {
                 return Err(DbErr::RecordNotInserted);
             }
-            FromValueTuple::from_value_tuple(value_tuple)
+            (
+                FromValueTuple::from_value_tuple(value_tuple),
+                res.rows_affected(),
+            )
         }
         (None, false) => {
             let res = db.execute(&statement).await?;
             if res.rows_affected() == 0 {
                 return Err(DbErr::RecordNotInserted);
             }
+            if !PrimaryKey::<A>::auto_increment() {
+                // e.g. filled in by a trigger, only `RETURNING` could read it back
+                return Err(DbErr::BackendNotSupported {
+                    db: db_backend.as_str(),
+                    ctx: "reading back a database generated primary key",
+                });
+            }
             let last_insert_id = res.last_insert_id();
             // For MySQL, the affected-rows number:
             //   - The affected-rows value per row is `1` if the row is inserted as a new row,
@@ -479,11 +663,19 @@ where
             if db_backend == DbBackend::MySql && last_insert_id == 0 {
                 return Err(DbErr::RecordNotInserted);
             }
-            ValueTypeOf::<A>::try_from_u64(last_insert_id).map_err(|_| DbErr::UnpackInsertId)?
+            // the key was not set, so it can only come from the auto-increment id;
+            // conversion fails for non-integer keys and for ids out of the key's range
+            (
+                ValueTypeOf::<A>::try_from_u64(last_insert_id)?,
+                res.rows_affected(),
+            )
         }
     };
 
-    Ok(InsertResult { last_insert_id })
+    Ok(InsertResult {
+        last_insert_id,
+        rows_affected,
+    })
 }
 
 async fn exec_insert_without_returning<C>(
@@ -500,6 +692,7 @@ where
 async fn exec_insert_with_returning<A, C>(
     primary_key: Option<ValueTuple>,
     mut insert_statement: InsertStatement,
+    table_ref: Option<TableRef>,
     db: &C,
 ) -> Result<<A::Entity as EntityTrait>::Model, DbErr>
 where
@@ -512,7 +705,7 @@ where
         true => {
             let returning = Query::returning().exprs(
                 <A::Entity as EntityTrait>::Column::iter()
-                    .map(|c| c.select_as(c.into_returning_expr(db_backend))),
+                    .map(|c| select_as_named(&c, c.into_returning_expr(db_backend))),
             );
             insert_statement.returning(returning);
             ReturningSelector::<SelectModel<<A::Entity as EntityTrait>::Model>, _>::from_query(
@@ -523,7 +716,9 @@ where
         }
         false => {
             let insert_res = exec_insert::<A, _>(primary_key, insert_statement, db).await?;
-            <A::Entity as EntityTrait>::find_by_id(insert_res.last_insert_id)
+            // read back without the find hooks, the same as with `RETURNING`
+            Select::<A::Entity>::find_by_id_in(insert_res.last_insert_id, table_ref)
+                .into_model()
                 .one(db)
                 .await?
         }
@@ -536,6 +731,112 @@ where
     }
 }
 
+const UPSERT_INSERTED: &str = "_sea_orm_upsert_inserted";
+
+async fn exec_upsert_with_returning_outcome<A, C>(
+    primary_key: Option<ValueTuple>,
+    on_conflict: Option<OnConflict>,
+    mut insert_statement: InsertStatement,
+    table_ref: Option<TableRef>,
+    db: &C,
+) -> Result<(<A::Entity as EntityTrait>::Model, UpsertOutcome), DbErr>
+where
+    <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+    C: ConnectionTrait,
+    A: ActiveModelTrait,
+{
+    type ValueTypeOf<A> = <PrimaryKey<A> as PrimaryKeyTrait>::ValueType;
+
+    let db_backend = db.get_database_backend();
+    let not_found = || DbErr::RecordNotFound("Failed to find inserted item".to_owned());
+
+    match db_backend {
+        DbBackend::Postgres => {
+            let mut exprs: Vec<Expr> = <A::Entity as EntityTrait>::Column::iter()
+                .map(|c| select_as_named(&c, c.into_returning_expr(db_backend)))
+                .collect();
+            // `xmax` is only zero for a row version created by this insert
+            exprs.push(Expr::cust(format!(r#"(xmax = 0) AS "{UPSERT_INSERTED}""#)));
+            insert_statement.returning(Query::returning().exprs(exprs));
+
+            let row = db
+                .query_one(&insert_statement)
+                .await?
+                .ok_or_else(not_found)?;
+            let model = <A::Entity as EntityTrait>::Model::from_query_result(&row, "")?;
+            let outcome = match row.try_get::<bool>("", UPSERT_INSERTED)? {
+                true => UpsertOutcome::Inserted,
+                false => UpsertOutcome::Updated,
+            };
+            Ok((model, outcome))
+        }
+        DbBackend::MySql => {
+            // A row updated by `ON DUPLICATE KEY UPDATE` has no insert id, unless the
+            // update sets one with `LAST_INSERT_ID(expr)`
+            if let (None, Some(mut on_conflict)) = (&primary_key, on_conflict)
+                && <PrimaryKey<A> as PrimaryKeyTrait>::auto_increment()
+                && let Some(key) = <PrimaryKey<A> as Iterable>::iter().next()
+            {
+                let key = key.into_column();
+                on_conflict.value(key, Func::cust("LAST_INSERT_ID").arg(Expr::col(key)));
+                insert_statement.on_conflict(on_conflict);
+            }
+
+            let res = db.execute(&insert_statement).await?;
+            let last_insert_id = res.last_insert_id();
+            // `ON DUPLICATE KEY UPDATE` affects 1 row per insert and 2 per update, but the
+            // connection also reports 1 for a conflicting row set to its current values
+            let outcome = match res.rows_affected() {
+                0 | 2 => UpsertOutcome::Updated,
+                _ => UpsertOutcome::Unknown,
+            };
+            let key = match primary_key {
+                Some(value_tuple) => FromValueTuple::from_value_tuple(value_tuple),
+                None if last_insert_id != 0 => ValueTypeOf::<A>::try_from_u64(last_insert_id)?,
+                None => return Err(not_found()),
+            };
+            let model = Select::<A::Entity>::find_by_id_in(key, table_ref)
+                .into_model()
+                .one(db)
+                .await?
+                .ok_or_else(not_found)?;
+            Ok((model, outcome))
+        }
+        DbBackend::Sqlite => {
+            let existed = match &primary_key {
+                Some(value_tuple) => Some(
+                    Select::<A::Entity>::find_by_id_in(
+                        ValueTypeOf::<A>::from_value_tuple(value_tuple.clone()),
+                        table_ref.clone(),
+                    )
+                    .into_model::<<A::Entity as EntityTrait>::Model>()
+                    .one(db)
+                    .await?
+                    .is_some(),
+                ),
+                None => None,
+            };
+            let model = exec_insert_with_returning::<A, _>(
+                primary_key.clone(),
+                insert_statement,
+                table_ref,
+                db,
+            )
+            .await?;
+            // the returned row has a different key if the conflict was on another unique column
+            let outcome = match (existed, primary_key) {
+                (Some(true), _) => UpsertOutcome::Updated,
+                (Some(false), Some(key)) if model.get_primary_key_value() == key => {
+                    UpsertOutcome::Inserted
+                }
+                (Some(false), _) => UpsertOutcome::Updated,
+                (None, _) => UpsertOutcome::Unknown,
+            };
+            Ok((model, outcome))
+        }
+    }
+}
+
 async fn exec_insert_with_returning_keys<A, C>(
     mut insert_statement: InsertStatement,
     db: &C,
@@ -583,7 +884,7 @@ where
         true => {
             let returning = Query::returning().exprs(
                 <A::Entity as EntityTrait>::Column::iter()
-                    .map(|c| c.select_as(c.into_returning_expr(db_backend))),
+                    .map(|c| select_as_named(&c, c.into_returning_expr(db_backend))),
             );
             insert_statement.returning(returning);
             ReturningSelector::<SelectModel<<A::Entity as EntityTrait>::Model>, _>::from_query(
@@ -599,12 +900,706 @@ where
     }
 }
 
+/// SQLite has no `DEFAULT` keyword in `VALUES`
+fn check_set_default<C>(set_default: bool, db: &C) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    let db_backend = db.get_database_backend();
+    if set_default && db_backend == DbBackend::Sqlite {
+        return Err(DbErr::BackendNotSupported {
+            db: db_backend.as_str(),
+            ctx: "ActiveValue::SetDefault",
+        });
+    }
+    Ok(())
+}
+
 fn returning_pk<A>(db_backend: DbBackend) -> ReturningClause
 where
     A: ActiveModelTrait,
 {
     Query::returning().exprs(<A::Entity as EntityTrait>::PrimaryKey::iter().map(|c| {
-        c.into_column()
-            .select_as(c.into_column().into_returning_expr(db_backend))
+        select_as_named(
+            &c.into_column(),
+            c.into_column().into_returning_expr(db_backend),
+        )
     }))
 }
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::tests_cfg::{cake, cake_filling};
+    use crate::{
+        ActiveModelTrait, DbBackend, DbErr, EntityTrait, Insert, MockDatabase, MockExecResult, Set,
+        Transaction, TryInsertResult, UpsertOutcome,
+    };
+    use pretty_assertions::assert_eq;
+    use sea_query::{Alias, OnConflict};
+
+    mod repository {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "repository")]
+        pub struct Model {
+            #[sea_orm(primary_key, auto_increment = false)]
+            pub id: String,
+            pub name: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    mod repository_by_trigger {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "repository")]
+        pub struct Model {
+            #[sea_orm(primary_key, auto_increment = false, db_generated)]
+            pub id: String,
+            pub name: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[cfg(feature = "with-uuid-v4")]
+    mod repository_by_uuid {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "repository")]
+        pub struct Model {
+            #[sea_orm(primary_key, auto_uuid = "v4")]
+            pub id: Uuid,
+            pub name: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        #[async_trait::async_trait]
+        impl ActiveModelBehavior for ActiveModel {
+            async fn before_save<C>(self, _: &C, _: bool) -> Result<Self, DbErr>
+            where
+                C: ConnectionTrait,
+            {
+                if self.id.is_not_set() {
+                    return Err(DbErr::Custom("id is not set".to_owned()));
+                }
+                Ok(self)
+            }
+        }
+    }
+
+    mod counter {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "counter")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: u64,
+            pub name: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    fn sea_orm_repository() -> repository::Model {
+        repository::Model {
+            id: "sea-orm".to_owned(),
+            name: "SeaORM".to_owned(),
+        }
+    }
+
+    #[smol_potat::test]
+    async fn insert_primary_key_not_set() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        let not_set = || repository::ActiveModel {
+            name: Set("SeaORM".to_owned()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            repository::Entity::insert(not_set()).exec(&db).await.err(),
+            Some(DbErr::PrimaryKeyColumnNotSet {
+                ctx: "Insert",
+                column: "id",
+            })
+        );
+        assert_eq!(
+            repository::Entity::insert(not_set())
+                .exec_with_returning(&db)
+                .await
+                .err(),
+            Some(DbErr::PrimaryKeyColumnNotSet {
+                ctx: "Insert",
+                column: "id",
+            })
+        );
+        assert_eq!(
+            repository::Entity::insert(not_set())
+                .on_conflict_do_nothing()
+                .exec(&db)
+                .await
+                .err(),
+            Some(DbErr::PrimaryKeyColumnNotSet {
+                ctx: "Insert",
+                column: "id",
+            })
+        );
+        assert_eq!(
+            repository::Entity::insert_many([sea_orm_repository().into(), not_set()])
+                .exec_without_returning(&db)
+                .await
+                .err(),
+            Some(DbErr::PrimaryKeyColumnNotSet {
+                ctx: "InsertMany",
+                column: "id",
+            })
+        );
+        assert_eq!(
+            not_set().insert(&db).await.err(),
+            Some(DbErr::PrimaryKeyColumnNotSet {
+                ctx: "Insert",
+                column: "id",
+            })
+        );
+
+        // rejected before reaching the database
+        assert!(db.into_transaction_log().is_empty());
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_last_insert_id_mysql() -> Result<(), DbErr> {
+        let large_id = u64::MAX - 1;
+        let exec_result = |last_insert_id| MockExecResult {
+            last_insert_id,
+            rows_affected: 1,
+        };
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([
+                exec_result(large_id),
+                exec_result(0),
+                exec_result(large_id),
+                exec_result(large_id),
+                exec_result(0),
+            ])
+            .append_query_results([[counter::Model {
+                id: large_id,
+                name: "big".to_owned(),
+            }]])
+            .into_connection();
+
+        // unsigned BIGINT auto-increment id above i64::MAX
+        let counter = || counter::ActiveModel {
+            name: Set("big".to_owned()),
+            ..Default::default()
+        };
+        let res = counter::Entity::insert(counter()).exec(&db).await?;
+        assert_eq!(res.last_insert_id, large_id);
+
+        // a key that is set is read back, whatever the driver reports
+        let res = counter::Entity::insert(counter::ActiveModel {
+            id: Set(large_id - 1),
+            ..counter()
+        })
+        .exec(&db)
+        .await?;
+        assert_eq!(res.last_insert_id, large_id - 1);
+
+        assert_eq!(
+            counter().insert(&db).await?,
+            counter::Model {
+                id: large_id,
+                name: "big".to_owned(),
+            }
+        );
+
+        // the id does not fit into an i32 key
+        assert!(matches!(
+            cake::Entity::insert(cake::ActiveModel {
+                name: Set("Cake".to_owned()),
+                ..Default::default()
+            })
+            .exec(&db)
+            .await,
+            Err(DbErr::TryIntoErr { into: "i32", .. })
+        ));
+
+        // a database generated key that is not an integer cannot be read back
+        assert_eq!(
+            repository_by_trigger::Entity::insert(repository_by_trigger::ActiveModel {
+                name: Set("SeaORM".to_owned()),
+                ..Default::default()
+            })
+            .exec(&db)
+            .await
+            .err(),
+            Some(DbErr::BackendNotSupported {
+                db: "MySql",
+                ctx: "reading back a database generated primary key",
+            })
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_primary_key_db_generated() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[repository_by_trigger::Model {
+                id: "sea-orm".to_owned(),
+                name: "SeaORM".to_owned(),
+            }]])
+            .into_connection();
+
+        assert_eq!(
+            repository_by_trigger::ActiveModel {
+                name: Set("SeaORM".to_owned()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await?,
+            repository_by_trigger::Model {
+                id: "sea-orm".to_owned(),
+                name: "SeaORM".to_owned(),
+            }
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "repository" ("name") VALUES ($1) RETURNING "id", "name""#,
+                ["SeaORM".into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "with-uuid-v4")]
+    async fn insert_auto_uuid() -> Result<(), DbErr> {
+        use crate::{ActiveModelBehavior, Value};
+        use uuid::Uuid;
+
+        let preset = Uuid::new_v4();
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[repository_by_uuid::Model {
+                id: preset,
+                name: "SeaORM".to_owned(),
+            }]])
+            .append_query_results([[repository_by_uuid::Model {
+                id: preset,
+                name: "SeaORM".to_owned(),
+            }]])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 2,
+            }])
+            .into_connection();
+
+        let mut am = <repository_by_uuid::ActiveModel as ActiveModelBehavior>::new();
+        am.name = Set("SeaORM".to_owned());
+        am.clone().insert(&db).await?;
+        am.id = Set(preset);
+        am.clone().insert(&db).await?;
+        am.id = Default::default();
+        repository_by_uuid::Entity::insert_many([am.clone(), am])
+            .exec_without_returning(&db)
+            .await?;
+
+        let ids: Vec<_> = db
+            .into_transaction_log()
+            .iter()
+            .flat_map(|txn| txn.statements())
+            .map(|stmt| {
+                let values = &stmt.values.as_ref().unwrap().0;
+                values
+                    .iter()
+                    .filter_map(|value| match value {
+                        Value::Uuid(Some(id)) => Some(*id),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        // a new v4 UUID per row, unless one was set
+        assert_eq!(ids.len(), 3);
+        assert_eq!(ids[0].len(), 1);
+        assert_eq!(ids[0][0].get_version_num(), 4);
+        assert_eq!(ids[1], [preset]);
+        assert_eq!(ids[2].len(), 2);
+        assert_ne!(ids[2][0], ids[2][1]);
+        assert!(!ids[2].contains(&ids[0][0]));
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_rows_affected() -> Result<(), DbErr> {
+        use crate::TryInsertResult;
+
+        let cake = |id: i32| cake::ActiveModel {
+            id: Set(id),
+            name: Set(format!("Cake {id}")),
+        };
+        let exec_result = |last_insert_id, rows_affected| MockExecResult {
+            last_insert_id,
+            rows_affected,
+        };
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([
+                exec_result(1, 1),
+                exec_result(4, 3),
+                exec_result(6, 2),
+                exec_result(0, 0),
+            ])
+            .into_connection();
+
+        let res = cake::Entity::insert(cake(1)).exec(&db).await?;
+        assert_eq!((res.last_insert_id, res.rows_affected), (1, 1));
+
+        let res = cake::Entity::insert_many([cake(2), cake(3), cake(4)])
+            .exec(&db)
+            .await?;
+        assert_eq!((res.last_insert_id, res.rows_affected), (Some(4), 3));
+
+        // one of the rows already exists
+        let res = cake::Entity::insert_many([cake(4), cake(5), cake(6)])
+            .on_conflict_do_nothing()
+            .exec(&db)
+            .await?;
+        assert!(matches!(res, TryInsertResult::Inserted(_)));
+        assert_eq!(res.rows_affected(), 2);
+
+        // all of the rows already exist
+        let res = cake::Entity::insert_many([cake(5), cake(6)])
+            .on_conflict_do_nothing()
+            .exec(&db)
+            .await?;
+        assert!(matches!(res, TryInsertResult::Conflicted));
+        assert_eq!(res.rows_affected(), 0);
+
+        assert_eq!(
+            cake::Entity::insert_many(Vec::<cake::ActiveModel>::new())
+                .exec(&db)
+                .await?
+                .rows_affected,
+            0
+        );
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([vec![apple_pie()], vec![apple_pie(), apple_pie()], vec![]])
+            .into_connection();
+
+        let res = cake::Entity::insert(cake(1)).exec(&db).await?;
+        assert_eq!(res.rows_affected, 1);
+
+        // only the inserted rows are returned
+        let res = cake::Entity::insert_many([cake(1), cake(2), cake(3)])
+            .on_conflict_do_nothing()
+            .exec(&db)
+            .await?;
+        assert_eq!(res.rows_affected(), 2);
+
+        let res = cake::Entity::insert_many([cake(1), cake(2)])
+            .on_conflict_do_nothing()
+            .exec(&db)
+            .await?;
+        assert!(matches!(res, TryInsertResult::Conflicted));
+        assert_eq!(res.rows_affected(), 0);
+
+        Ok(())
+    }
+
+    fn apple_pie() -> cake::Model {
+        cake::Model {
+            id: 1,
+            name: "Apple Pie".to_owned(),
+        }
+    }
+
+    fn upsert_cake() -> Insert<cake::ActiveModel> {
+        cake::Entity::insert(cake::ActiveModel {
+            name: Set("Apple Pie".to_owned()),
+            ..Default::default()
+        })
+        .on_conflict(
+            OnConflict::column(cake::Column::Name)
+                .update_column(cake::Column::Name)
+                .to_owned(),
+        )
+    }
+
+    fn cake_filling() -> cake_filling::Model {
+        cake_filling::Model {
+            cake_id: 1,
+            filling_id: 2,
+        }
+    }
+
+    fn upsert_cake_filling() -> Insert<cake_filling::ActiveModel> {
+        cake_filling::Entity::insert(cake_filling::ActiveModel {
+            cake_id: Set(1),
+            filling_id: Set(2),
+        })
+        .on_conflict(
+            OnConflict::columns([
+                cake_filling::Column::CakeId,
+                cake_filling::Column::FillingId,
+            ])
+            .update_column(cake_filling::Column::FillingId)
+            .to_owned(),
+        )
+    }
+
+    #[smol_potat::test]
+    async fn upsert_outcome_mysql() -> Result<(), DbErr> {
+        // the update sets the insert id to the key of the existing row
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 1,
+                    rows_affected: 2,
+                },
+                MockExecResult {
+                    last_insert_id: 1,
+                    rows_affected: 1,
+                },
+            ])
+            .append_query_results([[apple_pie()], [apple_pie()]])
+            .into_connection();
+
+        assert_eq!(
+            upsert_cake().exec_with_returning_outcome(&db).await?,
+            (apple_pie(), UpsertOutcome::Updated)
+        );
+        // an insert and a row left unchanged both affect 1 row
+        assert_eq!(
+            upsert_cake().exec_with_returning_outcome(&db).await?,
+            (apple_pie(), UpsertOutcome::Unknown)
+        );
+
+        let log = db.into_transaction_log();
+        assert_eq!(
+            log[..2],
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "INSERT INTO `cake` (`name`) VALUES (?) ON DUPLICATE KEY UPDATE `name` = VALUES(`name`), `id` = LAST_INSERT_ID(`id`)",
+                    ["Apple Pie".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = ? LIMIT ?",
+                    [1.into(), 1u64.into()]
+                ),
+            ]
+        );
+
+        // a given key is read back as is, as the row has no insert id
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 2,
+            }])
+            .append_query_results([[cake_filling()]])
+            .into_connection();
+
+        assert_eq!(
+            upsert_cake_filling()
+                .exec_with_returning_outcome(&db)
+                .await?,
+            (cake_filling(), UpsertOutcome::Updated)
+        );
+
+        let log = db.into_transaction_log();
+        assert_eq!(
+            log[0],
+            Transaction::from_sql_and_values(
+                DbBackend::MySql,
+                "INSERT INTO `cake_filling` (`cake_id`, `filling_id`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `filling_id` = VALUES(`filling_id`)",
+                [1.into(), 2.into()]
+            )
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn upsert_outcome_sqlite() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([vec![], vec![cake_filling()]])
+            .append_query_results([[cake_filling()], [cake_filling()]])
+            .append_query_results([[apple_pie()]])
+            .into_connection();
+
+        assert_eq!(
+            upsert_cake_filling()
+                .exec_with_returning_outcome(&db)
+                .await?,
+            (cake_filling(), UpsertOutcome::Inserted)
+        );
+        assert_eq!(
+            upsert_cake_filling()
+                .exec_with_returning_outcome(&db)
+                .await?,
+            (cake_filling(), UpsertOutcome::Updated)
+        );
+        assert_eq!(
+            upsert_cake().exec_with_returning_outcome(&db).await?,
+            (apple_pie(), UpsertOutcome::Unknown)
+        );
+
+        let log = db.into_transaction_log();
+        assert_eq!(
+            log[..2],
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    [
+                        r#"SELECT "cake_filling"."cake_id", "cake_filling"."filling_id" FROM "cake_filling""#,
+                        r#"WHERE "cake_filling"."cake_id" = ? AND "cake_filling"."filling_id" = ? LIMIT ?"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    [1.into(), 2.into(), 1u64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    [
+                        r#"INSERT INTO "cake_filling" ("cake_id", "filling_id") VALUES (?, ?)"#,
+                        r#"ON CONFLICT ("cake_id", "filling_id") DO UPDATE SET "filling_id" = "excluded"."filling_id""#,
+                        r#"RETURNING "cake_id", "filling_id""#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    [1.into(), 2.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_into_table_mysql() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 1,
+                rows_affected: 1,
+            }])
+            .append_query_results([[apple_pie()]])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::insert(cake::ActiveModel {
+                name: Set("Apple Pie".to_owned()),
+                ..Default::default()
+            })
+            .into_table(Alias::new("cake_2024"))
+            .exec_with_returning(&db)
+            .await?,
+            apple_pie()
+        );
+
+        // the inserted row is read back from the same table
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "INSERT INTO `cake_2024` (`name`) VALUES (?)",
+                    ["Apple Pie".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "SELECT `cake`.`id`, `cake`.`name` FROM `cake_2024` AS `cake` WHERE `cake`.`id` = ? LIMIT ?",
+                    [1.into(), 1u64.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn try_insert_empty_with_returning() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        let res = cake::Entity::insert_many::<cake::ActiveModel, _>([])
+            .on_conflict_do_nothing()
+            .exec_with_returning_many(&db)
+            .await?;
+        assert!(matches!(res, TryInsertResult::Inserted(models) if models.is_empty()));
+
+        let res = cake::Entity::insert_many::<cake::ActiveModel, _>([])
+            .on_conflict_do_nothing()
+            .exec_with_returning_keys(&db)
+            .await?;
+        assert!(matches!(res, TryInsertResult::Inserted(keys) if keys.is_empty()));
+
+        // no SQL is executed
+        assert!(db.into_transaction_log().is_empty());
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn try_insert_return_existing_postgres() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[apple_pie()]])
+            .into_connection();
+
+        let res = cake::Entity::insert(cake::ActiveModel {
+            id: Set(1),
+            name: Set("Orange Scone".to_owned()),
+        })
+        .on_conflict_do_nothing_return_existing()
+        .exec_with_returning(&db)
+        .await?;
+        assert!(matches!(res, TryInsertResult::Inserted(model) if model == apple_pie()));
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"INSERT INTO "cake" ("id", "name") VALUES ($1, $2)"#,
+                    r#"ON CONFLICT ("id") DO UPDATE SET "id" = "excluded"."id""#,
+                    r#"RETURNING "id", "name""#,
+                ]
+                .join(" ")
+                .as_str(),
+                [1.into(), "Orange Scone".into()]
+            )]
+        );
+
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
 mod consolidate;
+#[cfg(feature = "sqlx-postgres")]
+mod copy;
 mod cursor;
 mod delete;
 mod execute;
@@ -11,6 +13,8 @@ mod select_ext;
 mod update;
 
 use consolidate::*;
+#[cfg(feature = "sqlx-postgres")]
+pub(crate) use copy::*;
 pub use cursor::*;
 pub use delete::*;
 pub use execute::*;
@@ -1,6 +1,7 @@
 use crate::{
-    ConnectionTrait, EntityTrait, FromQueryResult, Select, SelectModel, SelectTwo, SelectTwoModel,
-    Selector, SelectorRaw, SelectorTrait, error::*,
+    ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, Select, SelectModel, SelectTwo,
+    SelectTwoModel, Selector, SelectorRaw, SelectorTrait, Statement, error::*,
+    query::check_distinct_on,
 };
 use async_stream::stream;
 use futures_util::Stream;
@@ -11,6 +12,10 @@ use std::{marker::PhantomData, pin::Pin};
 type PinBoxStream<'db, Item> = Pin<Box<dyn Stream<Item = Item> + 'db>>;
 #[cfg(feature = "sync")]
 type PinBoxStream<'db, Item> = Box<dyn Iterator<Item = Item> + 'db>;
+#[cfg(not(feature = "sync"))]
+type PinBoxSendStream<'db, Item> = Pin<Box<dyn Stream<Item = Item> + Send + 'db>>;
+#[cfg(feature = "sync")]
+type PinBoxSendStream<'db, Item> = Box<dyn Iterator<Item = Item> + 'db>;
 
 /// Fetches a [`Select`](crate::Select)'s results one page at a time with
 /// `LIMIT` / `OFFSET`. Build one with
@@ -35,7 +40,7 @@ where
 }
 
 /// Pair of totals returned by [`Paginator::num_items_and_pages`].
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ItemsAndPagesNumber {
     /// Total number of rows matched by the query.
     pub number_of_items: u64,
@@ -52,18 +57,7 @@ where
 {
     /// Fetch a specific page; page index starts from zero
     pub async fn fetch_page(&self, page: u64) -> Result<Vec<S::Item>, DbErr> {
-        let query = self
-            .query
-            .clone()
-            .limit(self.page_size)
-            .offset(self.page_size * page)
-            .to_owned();
-        let rows = self.db.query_all(&query).await?;
-        let mut buffer = Vec::with_capacity(rows.len());
-        for row in rows.into_iter() {
-            buffer.push(S::from_raw_query_result(row)?);
-        }
-        Ok(buffer)
+        fetch_page::<C, S>(self.db, &self.query, self.page_size, page).await
     }
 
     /// Fetch the current page
@@ -72,8 +66,53 @@ where
     }
 
     /// Get the total number of items
+    ///
+    /// The query is counted as a subquery, so `DISTINCT` still applies and a
+    /// grouped query reports the number of groups, not the rows of one group.
     pub async fn num_items(&self) -> Result<u64, DbErr> {
-        let query = SelectStatement::new()
+        check_distinct_on(&self.query, self.db.get_database_backend())?;
+        let query = self.count_query();
+        let result = match self.db.query_one(&query).await? {
+            Some(res) => res,
+            None => return Ok(0),
+        };
+        #[allow(clippy::match_single_binding)]
+        let num_items = match self.db.get_database_backend() {
+            _ => result.try_get::<i64>("", "num_items")? as u64,
+        };
+        Ok(num_items)
+    }
+
+    /// The `COUNT` query run by [`num_items`](Self::num_items), built for `db_backend`
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let cake_pages = cake::Entity::find()
+    ///     .filter(cake::Column::Name.contains("cheese"))
+    ///     .order_by_asc(cake::Column::Id)
+    ///     .paginate(&db, 50);
+    ///
+    /// assert_eq!(
+    ///     cake_pages.count_statement(DbBackend::Postgres).to_string(),
+    ///     [
+    ///         r#"SELECT COUNT(*) AS num_items FROM"#,
+    ///         r#"(SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" LIKE '%cheese%')"#,
+    ///         r#"AS "sub_query""#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn count_statement(&self, db_backend: DbBackend) -> Statement {
+        db_backend.build(&self.count_query())
+    }
+
+    fn count_query(&self) -> SelectStatement {
+        SelectStatement::new()
             .expr(Expr::cust("COUNT(*) AS num_items"))
             .from_subquery(
                 self.query
@@ -84,26 +123,21 @@ where
                     .to_owned(),
                 "sub_query",
             )
-            .to_owned();
-        let result = match self.db.query_one(&query).await? {
-            Some(res) => res,
-            None => return Ok(0),
-        };
-        #[allow(clippy::match_single_binding)]
-        let num_items = match self.db.get_database_backend() {
-            _ => result.try_get::<i64>("", "num_items")? as u64,
-        };
-        Ok(num_items)
+            .to_owned()
     }
 
     /// Get the total number of pages
+    ///
+    /// Every call runs its own `COUNT` query, nothing is cached on the paginator.
+    /// Use [`num_items_and_pages`](Self::num_items_and_pages) to get both totals
+    /// from a single `COUNT`.
     pub async fn num_pages(&self) -> Result<u64, DbErr> {
         let num_items = self.num_items().await?;
         let num_pages = self.compute_pages_number(num_items);
         Ok(num_pages)
     }
 
-    /// Get the total number of items and pages
+    /// Get the total number of items and pages, with a single `COUNT` query
     pub async fn num_items_and_pages(&self) -> Result<ItemsAndPagesNumber, DbErr> {
         let number_of_items = self.num_items().await?;
         let number_of_pages = self.compute_pages_number(number_of_items);
@@ -130,6 +164,13 @@ where
         self.page
     }
 
+    /// Start fetching from `page` instead of the first page, e.g. to resume a batch job;
+    /// page index starts from zero
+    pub fn starting_from(mut self, page: u64) -> Self {
+        self.page = page;
+        self
+    }
+
     /// Fetch one page and increment the page counter
     ///
     /// ```
@@ -253,6 +294,116 @@ where
     }
 }
 
+impl<C, S> Paginator<'_, C, S>
+where
+    C: ConnectionTrait + Clone + Send + Sync + 'static,
+    S: SelectorTrait + 'static,
+    S::Item: Send,
+{
+    /// Convert self into an async stream of pages paired with their page index.
+    /// The stream owns a clone of the connection, e.g. a [`DatabaseConnection`](crate::DatabaseConnection),
+    /// instead of borrowing it, so it is `Send + 'static` and can be consumed in a spawned task.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(all(feature = "mock", not(feature = "sync")))]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let owned_db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([
+    /// #         vec![cake::Model {
+    /// #             id: 1,
+    /// #             name: "Cake".to_owned(),
+    /// #         }],
+    /// #         vec![],
+    /// #     ])
+    /// #     .into_connection();
+    /// # let db = &owned_db;
+    /// #
+    /// use futures_util::TryStreamExt;
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    /// let mut cake_stream = cake::Entity::find()
+    ///     .order_by_asc(cake::Column::Id)
+    ///     .paginate(db, 50)
+    ///     .starting_from(0)
+    ///     .into_stream_indexed();
+    ///
+    /// while let Some((page, cakes)) = cake_stream.try_next().await? {
+    ///     // Do something on cakes: Vec<cake::Model>, then record `page` as done
+    /// }
+    /// #
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(all(feature = "mock", feature = "sync"))]
+    /// # fn main() {}
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn into_stream_indexed(
+        self,
+    ) -> PinBoxSendStream<'static, Result<(u64, Vec<S::Item>), DbErr>> {
+        let db = self.db.clone();
+        let Paginator {
+            query,
+            mut page,
+            page_size,
+            ..
+        } = self;
+        #[cfg(not(feature = "sync"))]
+        {
+            Box::pin(stream! {
+                loop {
+                    let vec = fetch_page::<C, S>(&db, &query, page_size, page).await?;
+                    if vec.is_empty() {
+                        break;
+                    }
+                    yield Ok((page, vec));
+                    page += 1;
+                }
+            })
+        }
+        #[cfg(feature = "sync")]
+        {
+            Box::new(std::iter::from_fn(move || {
+                match fetch_page::<C, S>(&db, &query, page_size, page) {
+                    Ok(vec) if vec.is_empty() => None,
+                    Ok(vec) => {
+                        page += 1;
+                        Some(Ok((page - 1, vec)))
+                    }
+                    Err(err) => Some(Err(err)),
+                }
+            }))
+        }
+    }
+}
+
+/// Fetch page `page` of `query`, `page_size` rows per page
+async fn fetch_page<C, S>(
+    db: &C,
+    query: &SelectStatement,
+    page_size: u64,
+    page: u64,
+) -> Result<Vec<S::Item>, DbErr>
+where
+    C: ConnectionTrait,
+    S: SelectorTrait,
+{
+    check_distinct_on(query, db.get_database_backend())?;
+    let query = query
+        .clone()
+        .limit(page_size)
+        .offset(page_size * page)
+        .to_owned();
+    let rows = db.query_all(&query).await?;
+    let mut buffer = Vec::with_capacity(rows.len());
+    for row in rows.into_iter() {
+        buffer.push(S::from_raw_query_result(row)?);
+    }
+    Ok(buffer)
+}
+
 #[cfg(feature = "sync")]
 #[derive(Debug)]
 /// Synchronous `Iterator` wrapper around a [`Paginator`], yielding one page
@@ -280,6 +431,10 @@ where
     fn paginate(self, db: &'db C, page_size: u64) -> Paginator<'db, C, Self::Selector>;
 
     /// Perform a count on the paginated results
+    ///
+    /// Counts the rows the query would return: `DISTINCT` is respected, and a query with
+    /// `GROUP BY` yields the number of groups. To count the distinct values of a single column,
+    /// see [`Select::count_distinct`](crate::Select::count_distinct).
     async fn count(self, db: &'db C) -> Result<u64, DbErr>
     where
         Self: Send + Sized,
@@ -579,7 +734,7 @@ mod tests {
 
         let num_items = num_items as u64;
         let page_size = 2_u64;
-        let num_pages = (num_items / page_size) + (num_items % page_size > 0) as u64;
+        let num_pages = (num_items / page_size) + !num_items.is_multiple_of(page_size) as u64;
         let paginator = fruit::Entity::find().paginate(&db, page_size);
 
         assert_eq!(paginator.num_pages().await?, num_pages);
@@ -605,6 +760,44 @@ mod tests {
         Ok(())
     }
 
+    #[smol_potat::test]
+    async fn num_items_and_pages() -> Result<(), DbErr> {
+        let (db, num_items) = setup_num_items();
+
+        let num_items = num_items as u64;
+        let page_size = 2_u64;
+        let num_pages = (num_items / page_size) + (num_items % page_size > 0) as u64;
+        let paginator = fruit::Entity::find().paginate(&db, page_size);
+
+        assert_eq!(
+            paginator.num_items_and_pages().await?,
+            ItemsAndPagesNumber {
+                number_of_items: num_items,
+                number_of_pages: num_pages,
+            }
+        );
+
+        let sub_query = SelectStatement::new()
+            .exprs([
+                Expr::col((fruit::Entity, fruit::Column::Id)),
+                Expr::col((fruit::Entity, fruit::Column::Name)),
+                Expr::col((fruit::Entity, fruit::Column::CakeId)),
+            ])
+            .from(fruit::Entity)
+            .to_owned();
+
+        let select = SelectStatement::new()
+            .expr(Expr::cust("COUNT(*) AS num_items"))
+            .from_subquery(sub_query, "sub_query")
+            .to_owned();
+
+        let query_builder = db.get_database_backend();
+        let stmts = [query_builder.build(&select)];
+
+        assert_eq!(db.into_transaction_log(), Transaction::wrap(stmts));
+        Ok(())
+    }
+
     #[smol_potat::test]
     async fn num_pages_raw() -> Result<(), DbErr> {
         let (db, num_items) = setup_num_items();
@@ -639,6 +832,71 @@ mod tests {
         Ok(())
     }
 
+    #[smol_potat::test]
+    async fn count_distinct_and_grouped() -> Result<(), DbErr> {
+        let num_items = |n: i64| {
+            [maplit::btreemap! {
+                "num_items" => Into::<Value>::into(n),
+            }]
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([num_items(3), num_items(2), num_items(2), num_items(2)])
+            .into_connection();
+
+        assert_eq!(fruit::Entity::find().count(&db).await?, 3);
+        assert_eq!(
+            fruit::Entity::find()
+                .select_only()
+                .column(fruit::Column::CakeId)
+                .distinct()
+                .count(&db)
+                .await?,
+            2
+        );
+        assert_eq!(
+            fruit::Entity::find()
+                .select_only()
+                .column(fruit::Column::CakeId)
+                .group_by(fruit::Column::CakeId)
+                .count(&db)
+                .await?,
+            2
+        );
+        assert_eq!(
+            fruit::Entity::find()
+                .order_by_asc(fruit::Column::Id)
+                .count_distinct(fruit::Column::CakeId, &db)
+                .await?,
+            2
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                r#"SELECT COUNT(*) AS num_items FROM (SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit") AS "sub_query""#,
+                r#"SELECT COUNT(*) AS num_items FROM (SELECT DISTINCT "fruit"."cake_id" FROM "fruit") AS "sub_query""#,
+                r#"SELECT COUNT(*) AS num_items FROM (SELECT "fruit"."cake_id" FROM "fruit" GROUP BY "fruit"."cake_id") AS "sub_query""#,
+                r#"SELECT COUNT(DISTINCT "fruit"."cake_id") AS "num_items" FROM "fruit""#,
+            ]
+            .map(|sql| Transaction::from_sql_and_values(DbBackend::Postgres, sql, []))
+        );
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn count_distinct_on_not_supported() {
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+
+        assert!(matches!(
+            fruit::Entity::find()
+                .distinct_on_columns([fruit::Column::CakeId])
+                .count(&db)
+                .await,
+            Err(DbErr::BackendNotSupported { .. })
+        ));
+        assert!(db.into_transaction_log().is_empty());
+    }
+
     #[smol_potat::test]
     async fn next_and_cur_page() -> Result<(), DbErr> {
         let (db, _) = setup();
@@ -777,6 +1035,88 @@ mod tests {
         Ok(())
     }
 
+    #[smol_potat::test]
+    async fn into_stream_indexed() -> Result<(), DbErr> {
+        let (db, pages) = setup();
+
+        let mut fruit_stream = fruit::Entity::find().paginate(&db, 2).into_stream_indexed();
+
+        assert_eq!(fruit_stream.try_next().await?, Some((0, pages[0].clone())));
+        assert_eq!(fruit_stream.try_next().await?, Some((1, pages[1].clone())));
+        assert_eq!(fruit_stream.try_next().await?, None);
+
+        drop(fruit_stream);
+
+        let mut select = SelectStatement::new()
+            .exprs([
+                Expr::col((fruit::Entity, fruit::Column::Id)),
+                Expr::col((fruit::Entity, fruit::Column::Name)),
+                Expr::col((fruit::Entity, fruit::Column::CakeId)),
+            ])
+            .from(fruit::Entity)
+            .to_owned();
+
+        let query_builder = db.get_database_backend();
+        let stmts = [
+            query_builder.build(select.clone().offset(0).limit(2)),
+            query_builder.build(select.clone().offset(2).limit(2)),
+            query_builder.build(select.offset(4).limit(2)),
+        ];
+
+        assert_eq!(db.into_transaction_log(), Transaction::wrap(stmts));
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn into_stream_indexed_starting_from() -> Result<(), DbErr> {
+        let (_, pages) = setup();
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([pages[1].clone(), Vec::new()])
+            .into_connection();
+
+        let mut fruit_stream = fruit::Entity::find()
+            .paginate(&db, 2)
+            .starting_from(1)
+            .into_stream_indexed();
+
+        assert_eq!(fruit_stream.try_next().await?, Some((1, pages[1].clone())));
+        assert_eq!(fruit_stream.try_next().await?, None);
+
+        drop(fruit_stream);
+
+        let select = SelectStatement::new()
+            .exprs([
+                Expr::col((fruit::Entity, fruit::Column::Id)),
+                Expr::col((fruit::Entity, fruit::Column::Name)),
+                Expr::col((fruit::Entity, fruit::Column::CakeId)),
+            ])
+            .from(fruit::Entity)
+            .to_owned();
+
+        let query_builder = db.get_database_backend();
+        let stmts = [
+            query_builder.build(select.clone().offset(2).limit(2)),
+            query_builder.build(select.clone().offset(4).limit(2)),
+        ];
+
+        assert_eq!(db.into_transaction_log(), Transaction::wrap(stmts));
+        Ok(())
+    }
+
+    #[cfg(not(feature = "sync"))]
+    #[tokio::test]
+    async fn into_stream_indexed_spawned() -> Result<(), DbErr> {
+        let (db, pages) = setup();
+
+        let fruit_stream = fruit::Entity::find().paginate(&db, 2).into_stream_indexed();
+        let fetched = tokio::spawn(fruit_stream.try_collect::<Vec<_>>())
+            .await
+            .unwrap()?;
+
+        assert_eq!(fetched, [(0, pages[0].clone()), (1, pages[1].clone())]);
+        Ok(())
+    }
+
     #[smol_potat::test]
     async fn into_stream_raw() -> Result<(), DbErr> {
         let (db, pages) = setup();
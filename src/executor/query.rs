@@ -54,6 +54,11 @@ pub(crate) enum QueryResultRow {
 /// booleans, JSON, UUIDs, chrono / time types when their features are
 /// enabled, etc.). User code rarely names this trait directly; it's the
 /// foundation that [`FromQueryResult`](crate::FromQueryResult) builds on.
+///
+/// With `with-rust_decimal` enabled, `i64` (and `u64` on MySQL) can also be decoded out
+/// of a `DECIMAL` / `NUMERIC` value, as long as it is integral and in range. This is what
+/// aggregates like `SUM` return on MySQL, and on Postgres when summing a `bigint` column;
+/// a value with a fractional part is still a decode error.
 pub trait TryGetable: Sized {
     /// Decode the value at the column named or positioned by `index`.
     fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError>;
@@ -191,6 +196,22 @@ impl QueryResult {
         Ok(T::try_get_many_by_index(self)?)
     }
 
+    /// Get a `DECIMAL` / `NUMERIC` value with prefixed column name as an `f64`.
+    ///
+    /// The value is read as a [`Decimal`] and then converted, so digits beyond
+    /// what an `f64` can represent are silently lost. Read the column as
+    /// [`Decimal`] instead whenever the exact value matters.
+    #[cfg(feature = "with-rust_decimal")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "with-rust_decimal")))]
+    pub fn try_get_f64_lossy(&self, pre: &str, col: &str) -> Result<f64, DbErr> {
+        use rust_decimal::prelude::ToPrimitive;
+
+        let value: Decimal = self.try_get(pre, col)?;
+        value
+            .to_f64()
+            .ok_or_else(|| type_err(format!("Failed to convert {value} to f64")))
+    }
+
     /// Retrieves the names of the columns in the result set
     pub fn column_names(&self) -> Vec<String> {
         #[cfg(feature = "sqlx-dep")]
@@ -462,6 +483,93 @@ macro_rules! try_getable_all {
     };
 }
 
+/// Like `try_getable_all`, but on MySQL and Postgres an integral `DECIMAL` / `NUMERIC`
+/// value is also accepted, see `try_get_integral_decimal`.
+macro_rules! try_getable_integral {
+    ( $type: ty ) => {
+        impl TryGetable for $type {
+            #[allow(unused_variables)]
+            fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(row) => row
+                        .try_get::<Option<$type>, _>(idx.as_sqlx_mysql_index())
+                        .or_else(|e| try_get_integral_decimal(row, idx.as_sqlx_mysql_index(), e))
+                        .map_err(|e| sqlx_error_to_query_err(e).into())
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => row
+                        .try_get::<Option<$type>, _>(idx.as_sqlx_postgres_index())
+                        .or_else(|e| try_get_integral_decimal(row, idx.as_sqlx_postgres_index(), e))
+                        .map_err(|e| sqlx_error_to_query_err(e).into())
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(row) => row
+                        .try_get::<Option<$type>, _>(idx.as_sqlx_sqlite_index())
+                        .map_err(|e| sqlx_error_to_query_err(e).into())
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "rusqlite")]
+                    QueryResultRow::Rusqlite(row) => row
+                        .try_get::<Option<$type>, _>(idx)
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        err_null_idx_col(idx)
+                    }),
+                    #[cfg(feature = "proxy")]
+                    QueryResultRow::Proxy(row) => row.try_get(idx).map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        err_null_idx_col(idx)
+                    }),
+                    #[allow(unreachable_patterns)]
+                    _ => unreachable!(),
+                }
+            }
+        }
+    };
+}
+
+/// Fallback for decoding an integer out of a `DECIMAL` / `NUMERIC` column.
+///
+/// Aggregates do not preserve the column type: `SUM` over an integer column returns
+/// `DECIMAL` on MySQL, and `NUMERIC` on Postgres when summing a `bigint`. Such a value
+/// is accepted only if it has no fractional part and fits in the target type,
+/// otherwise the original decode error `err` is returned.
+#[cfg(all(
+    feature = "with-rust_decimal",
+    any(feature = "sqlx-mysql", feature = "sqlx-postgres")
+))]
+fn try_get_integral_decimal<'r, R, I, T>(
+    row: &'r R,
+    idx: I,
+    err: sqlx::Error,
+) -> Result<Option<T>, sqlx::Error>
+where
+    R: Row,
+    I: sqlx::ColumnIndex<R>,
+    rust_decimal::Decimal: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    T: TryFrom<rust_decimal::Decimal>,
+{
+    match row.try_get::<Option<rust_decimal::Decimal>, I>(idx) {
+        Ok(Some(v)) if v.fract().is_zero() => T::try_from(v).map(Some).map_err(|_| err),
+        Ok(None) => Ok(None),
+        _ => Err(err),
+    }
+}
+
+#[cfg(all(
+    not(feature = "with-rust_decimal"),
+    any(feature = "sqlx-mysql", feature = "sqlx-postgres")
+))]
+fn try_get_integral_decimal<R, I, T>(
+    _: &R,
+    _: I,
+    err: sqlx::Error,
+) -> Result<Option<T>, sqlx::Error> {
+    Err(err)
+}
+
 macro_rules! try_getable_unsigned {
     ( $type: ty ) => {
         impl TryGetable for $type {
@@ -515,6 +623,7 @@ macro_rules! try_getable_mysql {
                     #[cfg(feature = "sqlx-mysql")]
                     QueryResultRow::SqlxMySql(row) => row
                         .try_get::<Option<$type>, _>(idx.as_sqlx_mysql_index())
+                        .or_else(|e| try_get_integral_decimal(row, idx.as_sqlx_mysql_index(), e))
                         .map_err(|e| sqlx_error_to_query_err(e).into())
                         .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
                     #[cfg(feature = "sqlx-postgres")]
@@ -653,7 +762,7 @@ try_getable_all!(bool);
 try_getable_all!(i8);
 try_getable_all!(i16);
 try_getable_all!(i32);
-try_getable_all!(i64);
+try_getable_integral!(i64);
 try_getable_unsigned!(u8);
 try_getable_unsigned!(u16);
 try_getable_mysql!(u64);
@@ -925,6 +1034,39 @@ try_getable_postgres!(ipnetwork::IpNetwork);
 #[cfg(feature = "with-mac_address")]
 try_getable_postgres!(mac_address::MacAddress);
 
+#[cfg(feature = "with-ipnetwork")]
+impl TryGetable for std::net::IpAddr {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+        let net = ipnetwork::IpNetwork::try_get_by(res, idx)?;
+        crate::value::ip_addr_from_network(net)
+            .ok_or_else(|| type_err(format!("Network {net} is not a single IP address")).into())
+    }
+}
+
+#[cfg(feature = "with-ipnetwork")]
+impl TryGetable for std::net::Ipv4Addr {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+        match std::net::IpAddr::try_get_by(res, idx)? {
+            std::net::IpAddr::V4(addr) => Ok(addr),
+            std::net::IpAddr::V6(addr) => {
+                Err(type_err(format!("{addr} is not an IPv4 address")).into())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "with-ipnetwork")]
+impl TryGetable for std::net::Ipv6Addr {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+        match std::net::IpAddr::try_get_by(res, idx)? {
+            std::net::IpAddr::V6(addr) => Ok(addr),
+            std::net::IpAddr::V4(addr) => {
+                Err(type_err(format!("{addr} is not an IPv6 address")).into())
+            }
+        }
+    }
+}
+
 impl TryGetable for u32 {
     #[allow(unused_variables)]
     fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
@@ -1305,7 +1447,8 @@ impl TryGetable for pgvector::Vector {
 ///
 /// The tuple impls let you write things like
 /// `Select::values::<(i32, String)>(...)` for queries with a small number of
-/// scalar outputs.
+/// scalar outputs. Tuples of up to 16 elements are supported, and elements
+/// can themselves be tuples, see [`TryGetableManyElement`].
 pub trait TryGetableMany: Sized {
     /// Decode by column name. `pre` is the prefix used when nesting (e.g.
     /// from a joined table); `cols` names each tuple position.
@@ -1409,25 +1552,82 @@ where
     }
 }
 
+/// A tuple element decoded by [`TryGetableMany`], either a single column or a
+/// nested tuple spanning several consecutive columns.
+///
+/// Nested tuples are flattened positionally, so `((A, B), (C, D))` is decoded
+/// from the same four columns as `(A, B, C, D)`.
+pub trait TryGetableManyElement: Sized {
+    /// Number of columns this element is decoded from
+    const COLUMNS: usize;
+
+    /// Decode by column name, from the first [`COLUMNS`](Self::COLUMNS) names of `cols`
+    fn try_get_element(res: &QueryResult, pre: &str, cols: &[String]) -> Result<Self, TryGetError>;
+
+    /// Decode positionally, starting from the column at `offset`
+    fn try_get_element_by_index(res: &QueryResult, offset: usize) -> Result<Self, TryGetError>;
+}
+
+impl<T> TryGetableManyElement for T
+where
+    T: TryGetable,
+{
+    const COLUMNS: usize = 1;
+
+    fn try_get_element(res: &QueryResult, pre: &str, cols: &[String]) -> Result<Self, TryGetError> {
+        T::try_get(res, pre, &cols[0])
+    }
+
+    fn try_get_element_by_index(res: &QueryResult, offset: usize) -> Result<Self, TryGetError> {
+        T::try_get_by_index(res, offset)
+    }
+}
+
 macro_rules! impl_try_get_many {
-    ( $LEN:expr, $($T:ident : $N:expr),+ $(,)? ) => {
-        impl< $($T),+ > TryGetableMany for ( $($T),+ )
+    ( $($T:ident),+ $(,)? ) => {
+        impl< $($T),+ > TryGetableManyElement for ( $($T),+ )
         where
-            $($T: TryGetable),+
+            $($T: TryGetableManyElement),+
         {
-            fn try_get_many(res: &QueryResult, pre: &str, cols: &[String]) -> Result<Self, TryGetError> {
-                try_get_many_with_slice_len_of($LEN, cols)?;
+            const COLUMNS: usize = 0 $(+ $T::COLUMNS)+;
+
+            #[allow(unused_assignments)]
+            fn try_get_element(res: &QueryResult, pre: &str, cols: &[String]) -> Result<Self, TryGetError> {
+                let mut offset = 0;
                 Ok((
-                    $($T::try_get(res, pre, &cols[$N])?),+
+                    $({
+                        let value = $T::try_get_element(res, pre, &cols[offset..])?;
+                        offset += $T::COLUMNS;
+                        value
+                    }),+
                 ))
             }
 
-            fn try_get_many_by_index(res: &QueryResult) -> Result<Self, TryGetError> {
+            #[allow(unused_assignments)]
+            fn try_get_element_by_index(res: &QueryResult, mut offset: usize) -> Result<Self, TryGetError> {
                 Ok((
-                    $($T::try_get_by_index(res, $N)?),+
+                    $({
+                        let value = $T::try_get_element_by_index(res, offset)?;
+                        offset += $T::COLUMNS;
+                        value
+                    }),+
                 ))
             }
         }
+
+        impl< $($T),+ > TryGetableMany for ( $($T),+ )
+        where
+            $($T: TryGetableManyElement),+
+        {
+            fn try_get_many(res: &QueryResult, pre: &str, cols: &[String]) -> Result<Self, TryGetError> {
+                try_get_many_with_slice_len_of(<Self as TryGetableManyElement>::COLUMNS, cols)?;
+                Self::try_get_element(res, pre, cols)
+            }
+
+            fn try_get_many_by_index(res: &QueryResult) -> Result<Self, TryGetError> {
+                Self::try_get_element_by_index(res, 0)
+            }
+        }
     };
 }
 
@@ -1435,17 +1635,21 @@ macro_rules! impl_try_get_many {
 mod impl_try_get_many {
     use super::*;
 
-    impl_try_get_many!( 2, T0:0, T1:1);
-    impl_try_get_many!( 3, T0:0, T1:1, T2:2);
-    impl_try_get_many!( 4, T0:0, T1:1, T2:2, T3:3);
-    impl_try_get_many!( 5, T0:0, T1:1, T2:2, T3:3, T4:4);
-    impl_try_get_many!( 6, T0:0, T1:1, T2:2, T3:3, T4:4, T5:5);
-    impl_try_get_many!( 7, T0:0, T1:1, T2:2, T3:3, T4:4, T5:5, T6:6);
-    impl_try_get_many!( 8, T0:0, T1:1, T2:2, T3:3, T4:4, T5:5, T6:6, T7:7);
-    impl_try_get_many!( 9, T0:0, T1:1, T2:2, T3:3, T4:4, T5:5, T6:6, T7:7, T8:8);
-    impl_try_get_many!(10, T0:0, T1:1, T2:2, T3:3, T4:4, T5:5, T6:6, T7:7, T8:8, T9:9);
-    impl_try_get_many!(11, T0:0, T1:1, T2:2, T3:3, T4:4, T5:5, T6:6, T7:7, T8:8, T9:9, T10:10);
-    impl_try_get_many!(12, T0:0, T1:1, T2:2, T3:3, T4:4, T5:5, T6:6, T7:7, T8:8, T9:9, T10:10, T11:11);
+    impl_try_get_many!(T0, T1);
+    impl_try_get_many!(T0, T1, T2);
+    impl_try_get_many!(T0, T1, T2, T3);
+    impl_try_get_many!(T0, T1, T2, T3, T4);
+    impl_try_get_many!(T0, T1, T2, T3, T4, T5);
+    impl_try_get_many!(T0, T1, T2, T3, T4, T5, T6);
+    impl_try_get_many!(T0, T1, T2, T3, T4, T5, T6, T7);
+    impl_try_get_many!(T0, T1, T2, T3, T4, T5, T6, T7, T8);
+    impl_try_get_many!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9);
+    impl_try_get_many!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+    impl_try_get_many!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
+    impl_try_get_many!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+    impl_try_get_many!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13);
+    impl_try_get_many!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14);
+    impl_try_get_many!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15);
 }
 
 fn try_get_many_with_slice_len_of(len: usize, cols: &[String]) -> Result<(), TryGetError> {
@@ -1488,52 +1692,70 @@ pub trait TryGetableFromJson: Sized
 where
     for<'de> Self: serde::Deserialize<'de>,
 {
-    /// Get a JSON from the query result with prefixed column name
+    /// Get a JSON from the query result with prefixed column name.
+    /// A JSON `null` is reported as [`TryGetError::Null`], same as SQL `NULL`,
+    /// so `Option<Self>` reads either of them as `None`.
     #[allow(unused_variables, unreachable_code)]
     fn try_get_from_json<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
         match &res.row {
             #[cfg(feature = "sqlx-mysql")]
             QueryResultRow::SqlxMySql(row) => row
-                .try_get::<Option<sqlx::types::Json<Self>>, _>(idx.as_sqlx_mysql_index())
+                .try_get::<Option<sqlx::types::Json<Option<Self>>>, _>(idx.as_sqlx_mysql_index())
                 .map_err(|e| sqlx_error_to_query_err(e).into())
-                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx)).map(|json| json.0)),
+                .and_then(|opt| {
+                    opt.and_then(|json| json.0)
+                        .ok_or_else(|| err_null_idx_col(idx))
+                }),
             #[cfg(feature = "sqlx-postgres")]
             QueryResultRow::SqlxPostgres(row) => row
-                .try_get::<Option<sqlx::types::Json<Self>>, _>(idx.as_sqlx_postgres_index())
+                .try_get::<Option<sqlx::types::Json<Option<Self>>>, _>(idx.as_sqlx_postgres_index())
                 .map_err(|e| sqlx_error_to_query_err(e).into())
-                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx)).map(|json| json.0)),
+                .and_then(|opt| {
+                    opt.and_then(|json| json.0)
+                        .ok_or_else(|| err_null_idx_col(idx))
+                }),
             #[cfg(feature = "sqlx-sqlite")]
             QueryResultRow::SqlxSqlite(row) => row
-                .try_get::<Option<sqlx::types::Json<Self>>, _>(idx.as_sqlx_sqlite_index())
+                .try_get::<Option<sqlx::types::Json<Option<Self>>>, _>(idx.as_sqlx_sqlite_index())
                 .map_err(|e| sqlx_error_to_query_err(e).into())
-                .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx)).map(|json| json.0)),
+                .and_then(|opt| {
+                    opt.and_then(|json| json.0)
+                        .ok_or_else(|| err_null_idx_col(idx))
+                }),
             #[cfg(feature = "rusqlite")]
             QueryResultRow::Rusqlite(row) => row
                 .try_get::<Option<serde_json::Value>, _>(idx)?
+                .filter(|json| !json.is_null())
                 .ok_or_else(|| err_null_idx_col(idx))
                 .and_then(|json| {
                     serde_json::from_value(json).map_err(|e| crate::error::json_err(e).into())
                 }),
             #[cfg(feature = "mock")]
-            QueryResultRow::Mock(row) => row
-                .try_get::<serde_json::Value, I>(idx)
-                .map_err(|e| {
-                    debug_print!("{:#?}", e.to_string());
-                    err_null_idx_col(idx)
-                })
-                .and_then(|json| {
-                    serde_json::from_value(json).map_err(|e| crate::error::json_err(e).into())
-                }),
+            QueryResultRow::Mock(row) => {
+                row.try_get::<serde_json::Value, I>(idx)
+                    .map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        err_null_idx_col(idx)
+                    })
+                    .and_then(|json| match json {
+                        serde_json::Value::Null => Err(err_null_idx_col(idx)),
+                        json => serde_json::from_value(json)
+                            .map_err(|e| crate::error::json_err(e).into()),
+                    })
+            }
             #[cfg(feature = "proxy")]
-            QueryResultRow::Proxy(row) => row
-                .try_get::<serde_json::Value, I>(idx)
-                .map_err(|e| {
-                    debug_print!("{:#?}", e.to_string());
-                    err_null_idx_col(idx)
-                })
-                .and_then(|json| {
-                    serde_json::from_value(json).map_err(|e| crate::error::json_err(e).into())
-                }),
+            QueryResultRow::Proxy(row) => {
+                row.try_get::<serde_json::Value, I>(idx)
+                    .map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        err_null_idx_col(idx)
+                    })
+                    .and_then(|json| match json {
+                        serde_json::Value::Null => Err(err_null_idx_col(idx)),
+                        json => serde_json::from_value(json)
+                            .map_err(|e| crate::error::json_err(e).into()),
+                    })
+            }
             #[allow(unreachable_patterns)]
             _ => unreachable!(),
         }
@@ -1572,7 +1794,11 @@ where
     T: TryGetableFromJson,
 {
     fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Vec<T>, TryGetError> {
-        T::from_json_vec(serde_json::Value::try_get_by(res, index)?)
+        match serde_json::Value::try_get_by(res, index)? {
+            // a JSON `null` is treated the same as SQL `NULL`
+            serde_json::Value::Null => Err(err_null_idx_col(index)),
+            json => T::from_json_vec(json),
+        }
     }
 }
 
@@ -1899,4 +2125,132 @@ mod tests {
             })
         );
     }
+
+    fn mock_result<C>(values: impl IntoIterator<Item = (C, Value)>) -> QueryResult
+    where
+        C: Into<String>,
+    {
+        let values = values
+            .into_iter()
+            .map(|(col, value)| (col.into(), value))
+            .collect();
+        QueryResult {
+            row: QueryResultRow::Mock(MockRow { values }),
+        }
+    }
+
+    #[test]
+    fn try_get_many_fourteen_columns() {
+        type Row = (
+            i32,
+            String,
+            bool,
+            i64,
+            Option<i32>,
+            f64,
+            String,
+            i32,
+            i32,
+            i32,
+            i32,
+            i32,
+            i32,
+            Option<String>,
+        );
+
+        let res = mock_result([
+            ("c00", 1i32.into()),
+            ("c01", "a".into()),
+            ("c02", true.into()),
+            ("c03", 4i64.into()),
+            ("c04", Option::<i32>::None.into()),
+            ("c05", 6.5f64.into()),
+            ("c06", "g".into()),
+            ("c07", 8i32.into()),
+            ("c08", 9i32.into()),
+            ("c09", 10i32.into()),
+            ("c10", 11i32.into()),
+            ("c11", 12i32.into()),
+            ("c12", 13i32.into()),
+            ("c13", "n".into()),
+        ]);
+        // tuples this long implement neither `PartialEq` nor `Debug`
+        let expected = |row: Row| {
+            matches!(
+                row,
+                (1, a, true, 4, None, 6.5, g, 8, 9, 10, 11, 12, 13, Some(n))
+                    if a == "a" && g == "g" && n == "n"
+            )
+        };
+
+        assert_eq!(<Row as TryGetableManyElement>::COLUMNS, 14);
+        assert!(expected(Row::try_get_many_by_index(&res).unwrap()));
+
+        let cols: Vec<String> = (0..14).map(|i| format!("c{i:02}")).collect();
+        assert!(expected(Row::try_get_many(&res, "", &cols).unwrap()));
+        assert!(matches!(
+            Row::try_get_many(&res, "", &cols[..13]),
+            Err(TryGetError::DbErr(DbErr::Type(_)))
+        ));
+    }
+
+    #[test]
+    fn try_get_many_sixteen_columns() {
+        type Row = (
+            i32,
+            i32,
+            i32,
+            i32,
+            i32,
+            i32,
+            i32,
+            i32,
+            i32,
+            i32,
+            i32,
+            i32,
+            i32,
+            i32,
+            i32,
+            i32,
+        );
+
+        let res = mock_result((0..16).map(|i: i32| (format!("c{i:02}"), i.into())));
+
+        assert_eq!(<Row as TryGetableManyElement>::COLUMNS, 16);
+        assert!(matches!(
+            Row::try_get_many_by_index(&res).unwrap(),
+            (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15)
+        ));
+    }
+
+    #[test]
+    fn try_get_many_nested_tuples() {
+        type Row = ((i32, String), (bool, (i64, Option<i32>)));
+
+        let res = mock_result([
+            ("a", 1i32.into()),
+            ("b", "b".into()),
+            ("c", false.into()),
+            ("d", 4i64.into()),
+            ("e", Option::<i32>::None.into()),
+        ]);
+        let expected: Row = ((1, "b".to_owned()), (false, (4, None)));
+
+        assert_eq!(<Row as TryGetableManyElement>::COLUMNS, 5);
+        assert_eq!(Row::try_get_many_by_index(&res).unwrap(), expected);
+
+        let cols: Vec<String> = ["a", "b", "c", "d", "e"].map(String::from).into();
+        assert_eq!(Row::try_get_many(&res, "", &cols).unwrap(), expected);
+        assert!(matches!(
+            Row::try_get_many(&res, "", &cols[..4]),
+            Err(TryGetError::DbErr(DbErr::Type(_)))
+        ));
+
+        // flattened positionally, same columns as the flat tuple
+        assert_eq!(
+            <(i32, String, bool, i64, Option<i32>)>::try_get_many_by_index(&res).unwrap(),
+            (1, "b".to_owned(), false, 4, None)
+        );
+    }
 }
@@ -3,19 +3,29 @@ use super::{
     consolidate_query_result_tee,
 };
 use crate::{
-    ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, IdenStatic, PartialModelTrait,
-    QueryResult, QuerySelect, Select, SelectA, SelectB, SelectTwo, SelectTwoMany,
-    SelectTwoRequired, Statement, TryGetableMany, error::*,
+    ActiveModelBehavior, ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult,
+    IdenStatic, PartialModelTrait, QueryResult, QueryScope, QuerySelect, Select, SelectA, SelectB,
+    SelectTwo, SelectTwoMany, SelectTwoRequired, Statement, StatementBuilder, TryGetableMany,
+    error::*,
+    query::{check_distinct_on, check_timeout},
 };
 
 #[cfg(feature = "stream")]
 pub use crate::StreamTrait;
 #[cfg(feature = "stream")]
+use crate::TransactionTrait;
+#[cfg(feature = "stream")]
 use futures_util::{Stream, TryStreamExt};
 
 use itertools::Itertools;
-use sea_query::SelectStatement;
-use std::marker::PhantomData;
+use sea_query::{
+    Expr, Func, MysqlQueryBuilder, QueryBuilder, SelectStatement, SqlWriter, SqlWriterValues, Value,
+};
+use std::{
+    fmt::{self, Write},
+    marker::PhantomData,
+    time::Duration,
+};
 
 mod five;
 mod four;
@@ -23,7 +33,7 @@ mod six;
 mod three;
 
 #[cfg(feature = "with-json")]
-use crate::JsonValue;
+use crate::{JsonValue, query::decrypt_json};
 
 #[cfg(all(not(feature = "sync"), feature = "stream"))]
 type PinBoxStream<'b, S> = std::pin::Pin<Box<dyn Stream<Item = Result<S, DbErr>> + 'b + Send>>;
@@ -42,6 +52,7 @@ where
     S: SelectorTrait,
 {
     pub(crate) query: SelectStatement,
+    timeout: Option<Duration>,
     selector: PhantomData<S>,
 }
 
@@ -56,6 +67,16 @@ where
     pub(super) selector: PhantomData<S>,
 }
 
+/// Options for [`Selector::stream_with_options`].
+#[cfg(feature = "stream")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StreamOptions {
+    /// Number of rows fetched per round trip. On Postgres, setting this reads
+    /// the result set through a server-side cursor in batches of this size,
+    /// instead of letting the driver buffer it. Ignored on other backends.
+    pub fetch_size: Option<u32>,
+}
+
 /// Decodes one row of a [`Selector`] / [`SelectorRaw`] result into a value of
 /// type [`Item`](Self::Item). Implemented by the `SelectModel*` types below;
 /// you usually never name this trait directly.
@@ -120,6 +141,41 @@ where
     model: PhantomData<(M, N)>,
 }
 
+/// [`SelectorTrait`] for a query that yields a row of entity `E` as JSON,
+/// with the columns declared `encrypt_with` decrypted.
+#[cfg(feature = "with-json")]
+#[derive(Clone, Debug)]
+pub struct SelectJson<E>
+where
+    E: EntityTrait,
+{
+    entity: PhantomData<E>,
+}
+
+/// [`SelectorTrait`] for a join that yields `(JsonValue, Option<JsonValue>)` per row,
+/// like [`SelectTwoModel`], with the columns declared `encrypt_with` decrypted.
+#[cfg(feature = "with-json")]
+#[derive(Clone, Debug)]
+pub struct SelectTwoJson<E, F>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    entity: PhantomData<(E, F)>,
+}
+
+/// [`SelectorTrait`] for a join that yields `(JsonValue, JsonValue)` per row,
+/// like [`SelectTwoRequiredModel`], with the columns declared `encrypt_with` decrypted.
+#[cfg(feature = "with-json")]
+#[derive(Clone, Debug)]
+pub struct SelectTwoRequiredJson<E, F>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    entity: PhantomData<(E, F)>,
+}
+
 /// [`SelectorTrait`] for a three-way join that yields `(M, Option<N>, Option<O>)`.
 #[derive(Clone, Debug)]
 pub struct SelectThreeModel<M, N, O>
@@ -251,6 +307,54 @@ where
     }
 }
 
+#[cfg(feature = "with-json")]
+impl<E> SelectorTrait for SelectJson<E>
+where
+    E: EntityTrait,
+{
+    type Item = JsonValue;
+
+    fn from_raw_query_result(res: QueryResult) -> Result<Self::Item, DbErr> {
+        decrypt_json::<E>(JsonValue::from_query_result(&res, "")?, &res, "")
+    }
+}
+
+#[cfg(feature = "with-json")]
+impl<E, F> SelectorTrait for SelectTwoJson<E, F>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    type Item = (JsonValue, Option<JsonValue>);
+
+    fn from_raw_query_result(res: QueryResult) -> Result<Self::Item, DbErr> {
+        let (a, b) = (SelectA.as_str(), SelectB.as_str());
+        Ok((
+            decrypt_json::<E>(JsonValue::from_query_result(&res, a)?, &res, a)?,
+            JsonValue::from_query_result_optional(&res, b)?
+                .map(|json| decrypt_json::<F>(json, &res, b))
+                .transpose()?,
+        ))
+    }
+}
+
+#[cfg(feature = "with-json")]
+impl<E, F> SelectorTrait for SelectTwoRequiredJson<E, F>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    type Item = (JsonValue, JsonValue);
+
+    fn from_raw_query_result(res: QueryResult) -> Result<Self::Item, DbErr> {
+        let (a, b) = (SelectA.as_str(), SelectB.as_str());
+        Ok((
+            decrypt_json::<E>(JsonValue::from_query_result(&res, a)?, &res, a)?,
+            decrypt_json::<F>(JsonValue::from_query_result(&res, b)?, &res, b)?,
+        ))
+    }
+}
+
 impl<E> Select<E>
 where
     E: EntityTrait,
@@ -271,6 +375,7 @@ where
     {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -314,11 +419,13 @@ where
         M::select_cols(QuerySelect::select_only(self)).into_model::<M>()
     }
 
-    /// Get a selectable Model as a [JsonValue] for SQL JSON operations
+    /// Get a selectable Model as a [JsonValue] for SQL JSON operations,
+    /// with the columns declared `encrypt_with` decrypted
     #[cfg(feature = "with-json")]
-    pub fn into_json(self) -> Selector<SelectModel<JsonValue>> {
+    pub fn into_json(self) -> Selector<SelectJson<E>> {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -432,6 +539,7 @@ where
     {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -533,6 +641,7 @@ where
     {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -542,7 +651,11 @@ where
     where
         C: ConnectionTrait,
     {
-        self.into_model().one(db).await
+        let (selector, hooks) = self.into_model_with_hooks();
+        match selector.one(db).await? {
+            Some(model) if hooks => Ok(Some(E::ActiveModel::after_find(model, db).await?)),
+            model => Ok(model),
+        }
     }
 
     /// Get all Models from the SELECT query
@@ -550,7 +663,147 @@ where
     where
         C: ConnectionTrait,
     {
-        self.into_model().all(db).await
+        let (selector, hooks) = self.into_model_with_hooks();
+        let models = selector.all(db).await?;
+        if !hooks {
+            return Ok(models);
+        }
+        let mut res = Vec::with_capacity(models.len());
+        for model in models {
+            res.push(E::ActiveModel::after_find(model, db).await?);
+        }
+        Ok(res)
+    }
+
+    /// Get one Model from the SELECT query, failing with [`DbErr::RecordNotFound`]
+    /// if there is none. The error names the table, and the primary key if the
+    /// query was built with [`find_by_id`](EntityTrait::find_by_id).
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([Vec::<cake::Model>::new()])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find_by_id(11).one_or_err(&db).await,
+    ///     Err(DbErr::RecordNotFound(
+    ///         "No `cake` with primary key 11 found".to_owned()
+    ///     ))
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn one_or_err<C>(self, db: &C) -> Result<E::Model, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let record = self.record_description();
+        self.one(db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound(format!("No {record} found")))
+    }
+
+    /// Get the only Model matching the SELECT query, failing with
+    /// [`DbErr::RecordNotFound`] if there is none, or [`DbErr::MultipleRecordsFound`]
+    /// if there is more than one. Fetches at most 2 rows, replacing any limit.
+    pub async fn expect_one<C>(self, db: &C) -> Result<E::Model, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let record = self.record_description();
+        let (selector, hooks) = self.into_model_with_hooks();
+        let model = selector.expect_one_of(db, &record).await?;
+        if hooks {
+            E::ActiveModel::after_find(model, db).await
+        } else {
+            Ok(model)
+        }
+    }
+
+    /// Count the distinct non-null values of `col` among the selected rows,
+    /// with a single `SELECT COUNT(DISTINCT col)` instead of wrapping the query in a subquery
+    ///
+    /// Filters and joins are kept; the selected columns, order, limit and offset are not
+    /// relevant to the count and are dropped. The query should not be grouped, otherwise
+    /// each group would produce its own count; use [`count`](crate::PaginatorTrait::count)
+    /// to count the number of groups.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[maplit::btreemap! {
+    /// #         "num_items" => Into::<Value>::into(2i64),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let cakes_with_fruits = fruit::Entity::find()
+    ///     .count_distinct(fruit::Column::CakeId, &db)
+    ///     .await?;
+    /// assert_eq!(cakes_with_fruits, 2);
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"SELECT COUNT(DISTINCT "fruit"."cake_id") AS "num_items" FROM "fruit""#,
+    ///         []
+    ///     ),]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn count_distinct<C>(self, col: E::Column, db: &C) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        check_distinct_on(&self.query, db.get_database_backend())?;
+        let mut query = self.query;
+        query
+            .clear_selects()
+            .reset_limit()
+            .reset_offset()
+            .clear_order_by()
+            .expr_as(
+                Func::count_distinct(Expr::col(col.as_column_ref())),
+                "num_items",
+            );
+        let num_items = match db.query_one(&query).await? {
+            Some(res) => res.try_get::<i64>("", "num_items")? as u64,
+            None => 0,
+        };
+        Ok(num_items)
+    }
+
+    /// The table and primary key being looked up, for error messages
+    fn record_description(&self) -> String {
+        let table = E::default().table_name();
+        match &self.primary_key {
+            None => format!("`{table}`"),
+            Some(values) => {
+                let values: Vec<_> = values.clone().into_iter().map(|v| v.to_string()).collect();
+                match values.as_slice() {
+                    [value] => format!("`{table}` with primary key {value}"),
+                    values => format!("`{table}` with primary key ({})", values.join(", ")),
+                }
+            }
+        }
     }
 
     /// Stream the results of a SELECT operation on a Model
@@ -562,7 +815,42 @@ where
     where
         C: ConnectionTrait + StreamTrait + Send,
     {
-        self.into_model().stream(db).await
+        let (selector, hooks) = self.into_model_with_hooks();
+        let stream = selector.stream(db).await?;
+        Ok(Self::after_find_stream(stream, db, hooks))
+    }
+
+    /// Stream the results of a SELECT operation on a Model with [`StreamOptions`],
+    /// see [`Selector::stream_with_options`]
+    #[cfg(feature = "stream")]
+    pub async fn stream_with_options<'a: 'b, 'b, C>(
+        self,
+        db: &'a C,
+        options: StreamOptions,
+    ) -> Result<impl Stream<Item = Result<E::Model, DbErr>> + 'b + Send, DbErr>
+    where
+        C: ConnectionTrait + TransactionTrait + StreamTrait + Send,
+        C::Transaction: Send + 'b,
+    {
+        let (selector, hooks) = self.into_model_with_hooks();
+        let stream = selector.stream_with_options(db, options).await?;
+        Ok(Self::after_find_stream(stream, db, hooks))
+    }
+
+    /// Stream the results of a SELECT operation on a Model in pages of `chunk_size`,
+    /// see [`Selector::stream_buffered`]
+    #[cfg(feature = "stream")]
+    pub async fn stream_buffered<'a: 'b, 'b, C>(
+        self,
+        db: &'a C,
+        chunk_size: u64,
+    ) -> Result<impl Stream<Item = Result<E::Model, DbErr>> + 'b + Send, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let (selector, hooks) = self.into_model_with_hooks();
+        let stream = selector.stream_buffered(db, chunk_size).await?;
+        Ok(Self::after_find_stream(stream, db, hooks))
     }
 
     /// Stream the result of the operation with PartialModel
@@ -577,6 +865,43 @@ where
     {
         self.into_partial_model().stream(db).await
     }
+
+    /// Apply the `before_find` hook, unless hooks are skipped
+    fn into_model_with_hooks(self) -> (Selector<SelectModel<E::Model>>, bool) {
+        let hooks = self.hooks;
+        let select = if hooks {
+            E::ActiveModel::before_find(self)
+        } else {
+            self
+        };
+        (select.into_model(), hooks)
+    }
+
+    /// Apply the `after_find` hook on every streamed model
+    #[cfg(feature = "stream")]
+    fn after_find_stream<'b, C>(
+        stream: PinBoxStream<'b, E::Model>,
+        db: &'b C,
+        hooks: bool,
+    ) -> PinBoxStream<'b, E::Model>
+    where
+        C: ConnectionTrait,
+    {
+        if !hooks {
+            return stream;
+        }
+        #[cfg(not(feature = "sync"))]
+        {
+            Box::pin(stream.and_then(move |model| E::ActiveModel::after_find(model, db)))
+        }
+        #[cfg(feature = "sync")]
+        {
+            Box::new(
+                stream
+                    .map(move |item| item.and_then(|model| E::ActiveModel::after_find(model, db))),
+            )
+        }
+    }
 }
 
 impl<E, F> SelectTwo<E, F>
@@ -592,6 +917,7 @@ where
     {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -603,16 +929,17 @@ where
         N: PartialModelTrait,
     {
         let select = QuerySelect::select_only(self);
-        let select = M::select_cols(select);
-        let select = N::select_cols(select);
+        let select = M::select_cols_with_prefix(select, SelectA.as_str());
+        let select = N::select_cols_with_prefix(select, SelectB.as_str());
         select.into_model::<M, N>()
     }
 
-    /// Convert the Models into JsonValue
+    /// Convert the Models into JsonValue, with the columns declared `encrypt_with` decrypted
     #[cfg(feature = "with-json")]
-    pub fn into_json(self) -> Selector<SelectTwoModel<JsonValue, JsonValue>> {
+    pub fn into_json(self) -> Selector<SelectTwoJson<E, F>> {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -633,6 +960,32 @@ where
         self.into_model().all(db).await
     }
 
+    /// Get one pair of Models from the Select query, requiring the related Model to be present.
+    ///
+    /// Returns [`DbErr::RecordNotFound`] if the related side is `NULL`, which means a
+    /// left join was used where an inner join was intended; see
+    /// [`find_also_related_strict`](crate::Select::find_also_related_strict).
+    pub async fn one_strict<C>(self, db: &C) -> Result<Option<(E::Model, F::Model)>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.one(db).await?.map(require_related::<E, F>).transpose()
+    }
+
+    /// Get all pairs of Models from the Select query, requiring the related Model to be present.
+    ///
+    /// Returns [`DbErr::RecordNotFound`] if the related side of any row is `NULL`.
+    pub async fn all_strict<C>(self, db: &C) -> Result<Vec<(E::Model, F::Model)>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.all(db)
+            .await?
+            .into_iter()
+            .map(require_related::<E, F>)
+            .collect()
+    }
+
     /// Stream the results of a Select operation on a Model
     #[cfg(feature = "stream")]
     pub async fn stream<'a: 'b, 'b, C>(
@@ -660,6 +1013,23 @@ where
     }
 }
 
+fn require_related<E, F>(
+    (model, related): (E::Model, Option<F::Model>),
+) -> Result<(E::Model, F::Model), DbErr>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    match related {
+        Some(related) => Ok((model, related)),
+        None => Err(DbErr::RecordNotFound(format!(
+            "Related `{}` of `{}` is NULL; use an inner join to require it",
+            F::default().table_name(),
+            E::default().table_name(),
+        ))),
+    }
+}
+
 impl<E, F> SelectTwoMany<E, F>
 where
     E: EntityTrait,
@@ -673,6 +1043,7 @@ where
     {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -718,6 +1089,7 @@ where
     {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -729,16 +1101,17 @@ where
         N: PartialModelTrait,
     {
         let select = QuerySelect::select_only(self);
-        let select = M::select_cols(select);
-        let select = N::select_cols(select);
+        let select = M::select_cols_with_prefix(select, SelectA.as_str());
+        let select = N::select_cols_with_prefix(select, SelectB.as_str());
         select.into_model::<M, N>()
     }
 
-    /// Convert the Models into JsonValue
+    /// Convert the Models into JsonValue, with the columns declared `encrypt_with` decrypted
     #[cfg(feature = "with-json")]
-    pub fn into_json(self) -> Selector<SelectTwoRequiredModel<JsonValue, JsonValue>> {
+    pub fn into_json(self) -> Selector<SelectTwoRequiredJson<E, F>> {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -786,13 +1159,126 @@ where
     }
 }
 
+/// The query of a [`Selector`] together with its timeout, built through the
+/// connection so that e.g. a [`ScopedConnection`](crate::ScopedConnection) can
+/// add its conditions
+struct TimedSelect<'a> {
+    query: &'a SelectStatement,
+    timeout: Option<Duration>,
+}
+
+impl StatementBuilder for TimedSelect<'_> {
+    fn build(&self, db_backend: &DbBackend) -> Statement {
+        self.build_query(self.query, db_backend)
+    }
+
+    fn build_scoped(&self, db_backend: &DbBackend, scope: &QueryScope) -> Result<Statement, DbErr> {
+        let query = scope.scope(self.query, db_backend)?;
+        Ok(self.build_query(&query, db_backend))
+    }
+
+    #[cfg(feature = "rbac")]
+    fn audit(&self) -> Result<crate::QueryAccessAudit, crate::AuditError> {
+        self.query.audit()
+    }
+}
+
+impl TimedSelect<'_> {
+    fn build_query(&self, query: &SelectStatement, db_backend: &DbBackend) -> Statement {
+        let Some(timeout) = self.timeout else {
+            return db_backend.build(query);
+        };
+        let stmt = match db_backend {
+            DbBackend::MySql if check_timeout(query, self.timeout, *db_backend).is_ok() => {
+                let mut writer = MaxExecutionTimeWriter {
+                    inner: SqlWriterValues::new("?", false),
+                    hint: Some(timeout),
+                };
+                query.build_collect_into(MysqlQueryBuilder, &mut writer);
+                let (sql, values) = writer.inner.into_parts();
+                Statement::from_sql_and_values(*db_backend, sql, values)
+            }
+            _ => db_backend.build(query),
+        };
+        stmt.with_timeout(timeout)
+    }
+}
+
+/// Writes a `MAX_EXECUTION_TIME` optimizer hint after the `SELECT` keyword, as
+/// MySQL only cancels a query once it runs past a limit given this way. Only
+/// used for a query without a `WITH` clause, see [`check_timeout`], which is
+/// written starting with that keyword.
+struct MaxExecutionTimeWriter {
+    inner: SqlWriterValues,
+    hint: Option<Duration>,
+}
+
+impl Write for MaxExecutionTimeWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_str(s)?;
+        match self.hint.take() {
+            // a timeout of 0 disables it
+            Some(timeout) => write!(
+                self.inner,
+                "/*+ MAX_EXECUTION_TIME({}) */ ",
+                timeout.as_millis().max(1)
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for MaxExecutionTimeWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl SqlWriter for MaxExecutionTimeWriter {
+    fn push_param<T: QueryBuilder>(&mut self, value: Value, query_builder: &T) {
+        self.inner.push_param(value, query_builder)
+    }
+
+    fn as_writer(&mut self) -> &mut dyn Write {
+        self as _
+    }
+}
+
 impl<S> Selector<S>
 where
     S: SelectorTrait,
 {
     /// Get the SQL statement
     pub fn into_statement(self, builder: DbBackend) -> Statement {
-        builder.build(&self.query)
+        self.statement().build(&builder)
+    }
+
+    fn statement(&self) -> TimedSelect<'_> {
+        TimedSelect {
+            query: &self.query,
+            timeout: self.timeout,
+        }
+    }
+
+    /// Have the database cancel the query if it runs longer than `timeout`,
+    /// failing with [`DbErr::QueryTimeout`]. See [`Statement::with_timeout`]
+    /// for how each backend enforces it. On MySQL, a query with a `WITH` clause
+    /// can't be limited, and fails with [`DbErr::BackendNotSupported`] instead.
+    ///
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::cake};
+    /// use std::time::Duration;
+    ///
+    /// let stmt = cake::Entity::find()
+    ///     .into_model::<cake::Model>()
+    ///     .timeout(Duration::from_secs(5))
+    ///     .into_statement(DbBackend::Postgres);
+    ///
+    /// assert_eq!(stmt.timeout(), Some(Duration::from_secs(5)));
+    /// ```
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
     }
 
     /// Get an item from the Select query
@@ -800,8 +1286,10 @@ where
     where
         C: ConnectionTrait,
     {
+        check_distinct_on(&self.query, db.get_database_backend())?;
+        check_timeout(&self.query, self.timeout, db.get_database_backend())?;
         self.query.limit(1);
-        let row = db.query_one(&self.query).await?;
+        let row = db.query_one(&self.statement()).await?;
         match row {
             Some(row) => Ok(Some(S::from_raw_query_result(row)?)),
             None => Ok(None),
@@ -813,13 +1301,50 @@ where
     where
         C: ConnectionTrait,
     {
-        db.query_all(&self.query)
+        check_distinct_on(&self.query, db.get_database_backend())?;
+        check_timeout(&self.query, self.timeout, db.get_database_backend())?;
+        db.query_all(&self.statement())
             .await?
             .into_iter()
             .map(|row| S::from_raw_query_result(row))
             .try_collect()
     }
 
+    /// Get an item from the Select query, failing with [`DbErr::RecordNotFound`] if there is none
+    pub async fn one_or_err<C>(self, db: &C) -> Result<S::Item, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.one(db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("No row found".to_owned()))
+    }
+
+    /// Get the only item from the Select query, failing with [`DbErr::RecordNotFound`]
+    /// if there is none, or [`DbErr::MultipleRecordsFound`] if there is more than one.
+    /// Fetches at most 2 rows, replacing any limit.
+    pub async fn expect_one<C>(self, db: &C) -> Result<S::Item, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.expect_one_of(db, "row").await
+    }
+
+    async fn expect_one_of<C>(mut self, db: &C, record: &str) -> Result<S::Item, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.query.limit(2);
+        let mut items = self.all(db).await?.into_iter();
+        match (items.next(), items.next()) {
+            (Some(item), None) => Ok(item),
+            (None, _) => Err(DbErr::RecordNotFound(format!("No {record} found"))),
+            (Some(_), Some(_)) => Err(DbErr::MultipleRecordsFound(format!(
+                "More than one {record} found"
+            ))),
+        }
+    }
+
     /// Stream the results of the Select operation
     #[cfg(feature = "stream")]
     pub async fn stream<'a: 'b, 'b, C>(self, db: &'a C) -> Result<PinBoxStream<'b, S::Item>, DbErr>
@@ -828,7 +1353,10 @@ where
         S: 'b,
         S::Item: Send,
     {
-        let stream = db.stream(&self.query).await?;
+        let db_backend = ConnectionTrait::get_database_backend(db);
+        check_distinct_on(&self.query, db_backend)?;
+        check_timeout(&self.query, self.timeout, db_backend)?;
+        let stream = db.stream_raw(self.into_statement(db_backend)).await?;
 
         #[cfg(not(feature = "sync"))]
         {
@@ -843,6 +1371,187 @@ where
             ))
         }
     }
+
+    /// Stream the results of the Select operation with [`StreamOptions`].
+    ///
+    /// On Postgres with a `fetch_size`, the query is run through a
+    /// `DECLARE ... CURSOR` and read with `FETCH n` batches inside a
+    /// transaction (a savepoint if `db` is already a transaction). Dropping
+    /// the stream early rolls that transaction back, which closes the cursor.
+    /// A [`timeout`](Self::timeout) then applies to each `FETCH`.
+    /// On other backends this is the same as [`Selector::stream`].
+    #[cfg(feature = "stream")]
+    pub async fn stream_with_options<'a: 'b, 'b, C>(
+        self,
+        db: &'a C,
+        options: StreamOptions,
+    ) -> Result<PinBoxStream<'b, S::Item>, DbErr>
+    where
+        C: ConnectionTrait + TransactionTrait + StreamTrait + Send,
+        C::Transaction: Send + 'b,
+        S: 'b,
+        S::Item: Send,
+    {
+        #[cfg(not(feature = "sync"))]
+        if let (DbBackend::Postgres, Some(fetch_size)) = (
+            ConnectionTrait::get_database_backend(db),
+            options.fetch_size,
+        ) {
+            return self.stream_with_cursor(db, fetch_size.max(1)).await;
+        }
+        #[cfg(feature = "sync")]
+        let _ = options;
+
+        self.stream(db).await
+    }
+
+    /// Stream the results of the Select operation, fetching `chunk_size` rows at a
+    /// time with `LIMIT` / `OFFSET`.
+    ///
+    /// Unlike [`Selector::stream`], no connection is borrowed between pages, so the
+    /// stream is `Send` and can be consumed inside a
+    /// [`transaction`](crate::TransactionTrait::transaction) closure, e.g. to write
+    /// aggregates while reading rows.
+    ///
+    /// This is not a cursor: every page is a separate query. Add an `ORDER BY` that
+    /// gives a total order, or pages may overlap. Rows inserted or deleted before the
+    /// current offset, by this transaction or a concurrent one under a weaker
+    /// isolation level, shift the pages and may cause rows to be skipped or seen twice.
+    ///
+    /// The pages are the `LIMIT` / `OFFSET` of the query, so a query that already
+    /// has either fails with [`DbErr::Custom`].
+    #[cfg(feature = "stream")]
+    pub async fn stream_buffered<'a: 'b, 'b, C>(
+        self,
+        db: &'a C,
+        chunk_size: u64,
+    ) -> Result<PinBoxStream<'b, S::Item>, DbErr>
+    where
+        C: ConnectionTrait,
+        S: 'b,
+        S::Item: Send,
+    {
+        let chunk_size = chunk_size.max(1);
+        let db_backend = db.get_database_backend();
+        check_distinct_on(&self.query, db_backend)?;
+        check_timeout(&self.query, self.timeout, db_backend)?;
+        let Self { query, timeout, .. } = self;
+        // `SelectStatement` does not expose its limit and offset, so compare against
+        // the statement without them
+        let mut unpaged = query.clone();
+        unpaged.reset_limit().reset_offset();
+        if unpaged != query {
+            return Err(DbErr::Custom(
+                "stream_buffered pages with LIMIT / OFFSET, the query can't have its own"
+                    .to_owned(),
+            ));
+        }
+        let page = move |offset: u64| unpaged.clone().limit(chunk_size).offset(offset).to_owned();
+
+        #[cfg(not(feature = "sync"))]
+        {
+            Ok(Box::pin(async_stream::try_stream! {
+                let mut offset = 0;
+                loop {
+                    let query = page(offset);
+                    let rows = db.query_all(&TimedSelect { query: &query, timeout }).await?;
+                    let done = (rows.len() as u64) < chunk_size;
+                    for row in rows {
+                        yield S::from_raw_query_result(row)?;
+                    }
+                    if done {
+                        break;
+                    }
+                    offset += chunk_size;
+                }
+            }))
+        }
+        #[cfg(feature = "sync")]
+        {
+            let mut offset = 0;
+            let mut rows = Vec::new().into_iter();
+            let mut done = false;
+            Ok(Box::new(std::iter::from_fn(move || {
+                loop {
+                    if let Some(row) = rows.next() {
+                        return Some(S::from_raw_query_result(row));
+                    }
+                    if done {
+                        return None;
+                    }
+                    match db.query_all(&TimedSelect {
+                        query: &page(offset),
+                        timeout,
+                    }) {
+                        Ok(page_rows) => {
+                            done = (page_rows.len() as u64) < chunk_size;
+                            offset += chunk_size;
+                            rows = page_rows.into_iter();
+                        }
+                        Err(err) => {
+                            done = true;
+                            return Some(Err(err));
+                        }
+                    }
+                }
+            })))
+        }
+    }
+
+    #[cfg(all(not(feature = "sync"), feature = "stream"))]
+    async fn stream_with_cursor<'b, C>(
+        self,
+        db: &C,
+        fetch_size: u32,
+    ) -> Result<PinBoxStream<'b, S::Item>, DbErr>
+    where
+        C: ConnectionTrait + TransactionTrait,
+        C::Transaction: Send + 'b,
+        S: 'b,
+        S::Item: Send,
+    {
+        use crate::TransactionSession;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static CURSOR_ID: AtomicUsize = AtomicUsize::new(0);
+
+        let backend = db.get_database_backend();
+        check_distinct_on(&self.query, backend)?;
+        let cursor = format!(
+            "\"sea_orm_cursor_{}\"",
+            CURSOR_ID.fetch_add(1, Ordering::Relaxed)
+        );
+        let stmt = self.into_statement(backend);
+        let options = stmt.options;
+        let txn = db.begin().await?;
+        txn.execute_raw(Statement {
+            sql: format!("DECLARE {cursor} NO SCROLL CURSOR FOR {}", stmt.sql),
+            ..stmt
+        })
+        .await?;
+
+        Ok(Box::pin(async_stream::try_stream! {
+            loop {
+                let fetch = Statement::from_string(
+                    backend,
+                    format!("FETCH FORWARD {fetch_size} FROM {cursor}"),
+                );
+                let rows = txn
+                    .query_all_raw(Statement { options, ..fetch })
+                    .await?;
+                let done = rows.len() < fetch_size as usize;
+                for row in rows {
+                    yield S::from_raw_query_result(row)?;
+                }
+                if done {
+                    break;
+                }
+            }
+            txn.execute_raw(Statement::from_string(backend, format!("CLOSE {cursor}")))
+                .await?;
+            txn.commit().await?;
+        }))
+    }
 }
 
 impl<S> SelectorRaw<S>
@@ -1003,6 +1712,13 @@ where
         self.stmt
     }
 
+    /// Have the database cancel the query if it runs longer than `timeout`,
+    /// see [`Statement::with_timeout`]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.stmt.options.timeout = Some(timeout);
+        self
+    }
+
     /// Get an item from the Select query
     /// ```
     /// # use sea_orm::{error::*, tests_cfg::*, *};
@@ -1129,3 +1845,769 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake, fruit, store_product, tag};
+    use crate::{
+        DbBackend, DbErr, EntityTrait, MockDatabase, PaginatorTrait, QuerySelect, Transaction,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn cake_and_fruit() -> (cake::Model, fruit::Model) {
+        (
+            cake::Model {
+                id: 1,
+                name: "Apple Cake".to_owned(),
+            },
+            fruit::Model {
+                id: 2,
+                name: "Apple".to_owned(),
+                cake_id: Some(1),
+            },
+        )
+    }
+
+    #[smol_potat::test]
+    async fn select_two_strict() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake_and_fruit()], [cake_and_fruit()]])
+            .append_query_results([[cake_and_fruit()]])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_related(fruit::Entity)
+                .all_strict(&db)
+                .await?,
+            [cake_and_fruit()]
+        );
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_related(fruit::Entity)
+                .one_strict(&db)
+                .await?,
+            Some(cake_and_fruit())
+        );
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_related_strict(fruit::Entity)
+                .all(&db)
+                .await?,
+            [cake_and_fruit()]
+        );
+
+        let log = db.into_transaction_log();
+        assert_eq!(
+            log[2],
+            Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+                    r#""fruit"."id" AS "B_id", "fruit"."name" AS "B_name", "fruit"."cake_id" AS "B_cake_id""#,
+                    r#"FROM "cake" INNER JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+                ]
+                .join(" ")
+                .as_str(),
+                []
+            )
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn select_two_schema_qualified() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([Vec::<(tag::Model, Option<store_product::Model>)>::new()])
+            .into_connection();
+
+        tag::Entity::find()
+            .find_also_related(store_product::Entity)
+            .all(&db)
+            .await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "tag"."id" AS "A_id", "tag"."tag" AS "A_tag","#,
+                    r#""product"."id" AS "B_id", "product"."name" AS "B_name", "product"."cake_id" AS "B_cake_id""#,
+                    r#"FROM "tag""#,
+                    r#"LEFT JOIN "store"."product_tag" ON "tag"."id" = "product_tag"."tag_id""#,
+                    r#"LEFT JOIN "store"."product" ON "product_tag"."product_id" = "product"."id""#,
+                ]
+                .join(" ")
+                .as_str(),
+                []
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn find_also_related_with_partial_model() -> Result<(), DbErr> {
+        use crate as sea_orm;
+        use crate::{ColumnTrait, DerivePartialModel, QueryOrder};
+
+        #[derive(Debug, PartialEq, DerivePartialModel)]
+        #[sea_orm(entity = "fruit::Entity")]
+        struct Fruit {
+            id: i32,
+            name: String,
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                maplit::btreemap! {
+                    "A_id" => 1.into(),
+                    "A_name" => "Cheese Cake".into(),
+                    "B_id" => 2.into(),
+                    "B_name" => "Cherry".into(),
+                },
+                maplit::btreemap! {
+                    "A_id" => 2.into(),
+                    "A_name" => "Chocolate Cake".into(),
+                    "B_id" => Option::<i32>::None.into(),
+                    "B_name" => Option::<String>::None.into(),
+                },
+            ]])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_related_with(fruit::Entity, |sel| {
+                    sel.filter(fruit::Column::Name.starts_with("Ch"))
+                        .columns([fruit::Column::Name])
+                })
+                .order_by_asc(cake::Column::Id)
+                .into_model::<cake::Model, Fruit>()
+                .all(&db)
+                .await?,
+            [
+                (
+                    cake::Model {
+                        id: 1,
+                        name: "Cheese Cake".to_owned(),
+                    },
+                    Some(Fruit {
+                        id: 2,
+                        name: "Cherry".to_owned(),
+                    }),
+                ),
+                (
+                    cake::Model {
+                        id: 2,
+                        name: "Chocolate Cake".to_owned(),
+                    },
+                    None,
+                ),
+            ]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+                    r#""fruit"."id" AS "B_id", "fruit"."name" AS "B_name""#,
+                    r#"FROM "cake""#,
+                    r#"LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+                    r#"AND "fruit"."name" LIKE $1"#,
+                    r#"ORDER BY "cake"."id" ASC"#,
+                ]
+                .join(" ")
+                .as_str(),
+                ["Ch%".into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn select_three_partial_model() -> Result<(), DbErr> {
+        use crate as sea_orm;
+        use crate::{
+            DerivePartialModel,
+            tests_cfg::{cake_filling, filling},
+        };
+
+        #[derive(Debug, PartialEq, DerivePartialModel)]
+        #[sea_orm(entity = "cake_filling::Entity")]
+        struct CakeFilling {
+            cake_id: i32,
+            filling_id: i32,
+        }
+
+        #[derive(Debug, PartialEq, DerivePartialModel)]
+        #[sea_orm(entity = "cake::Entity")]
+        struct Cake {
+            name: String,
+        }
+
+        #[derive(Debug, PartialEq, DerivePartialModel)]
+        #[sea_orm(entity = "filling::Entity")]
+        struct Filling {
+            id: i32,
+            name: String,
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[maplit::btreemap! {
+                "A_cake_id" => 1.into(),
+                "A_filling_id" => 2.into(),
+                "B_name" => "Apple Cake".into(),
+                "C_id" => 2.into(),
+                "C_name" => "Apple Jam".into(),
+            }]])
+            .into_connection();
+
+        assert_eq!(
+            cake_filling::Entity::find()
+                .find_also_related(cake::Entity)
+                .find_also_related(filling::Entity)
+                .into_partial_model::<CakeFilling, Cake, Filling>()
+                .all(&db)
+                .await?,
+            [(
+                CakeFilling {
+                    cake_id: 1,
+                    filling_id: 2,
+                },
+                Some(Cake {
+                    name: "Apple Cake".to_owned(),
+                }),
+                Some(Filling {
+                    id: 2,
+                    name: "Apple Jam".to_owned(),
+                }),
+            )]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake_filling"."cake_id" AS "A_cake_id", "cake_filling"."filling_id" AS "A_filling_id","#,
+                    r#""cake"."name" AS "B_name","#,
+                    r#""filling"."id" AS "C_id", "filling"."name" AS "C_name""#,
+                    r#"FROM "cake_filling""#,
+                    r#"LEFT JOIN "cake" ON "cake_filling"."cake_id" = "cake"."id""#,
+                    r#"LEFT JOIN "filling" ON "cake_filling"."filling_id" = "filling"."id""#,
+                ]
+                .join(" ")
+                .as_str(),
+                []
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn select_two_strict_missing_related() {
+        let (cake, _) = cake_and_fruit();
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[(cake.clone(), None::<fruit::Model>)]])
+            .append_query_results([[(cake, None::<fruit::Model>)]])
+            .into_connection();
+
+        let err = DbErr::RecordNotFound(
+            "Related `fruit` of `cake` is NULL; use an inner join to require it".to_owned(),
+        );
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_related(fruit::Entity)
+                .all_strict(&db)
+                .await,
+            Err(err.clone())
+        );
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_related(fruit::Entity)
+                .one_strict(&db)
+                .await,
+            Err(err)
+        );
+    }
+
+    #[smol_potat::test]
+    async fn distinct_on_postgres() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([Vec::<cake::Model>::new()])
+            .into_connection();
+
+        cake::Entity::find()
+            .distinct_on_columns([cake::Column::Name])
+            .all(&db)
+            .await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT DISTINCT ON ("cake"."name") "cake"."id", "cake"."name" FROM "cake""#,
+                []
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn distinct_on_not_supported() {
+        for db_backend in [DbBackend::MySql, DbBackend::Sqlite] {
+            let db = MockDatabase::new(db_backend).into_connection();
+
+            assert!(matches!(
+                cake::Entity::find()
+                    .distinct_on_columns([cake::Column::Name])
+                    .one(&db)
+                    .await,
+                Err(DbErr::BackendNotSupported {
+                    ctx: "SELECT DISTINCT ON",
+                    ..
+                })
+            ));
+
+            assert!(matches!(
+                cake::Entity::find()
+                    .distinct_on_columns([cake::Column::Id])
+                    .find_with_related(fruit::Entity)
+                    .all(&db)
+                    .await,
+                Err(DbErr::BackendNotSupported { .. })
+            ));
+
+            assert!(matches!(
+                cake::Entity::find()
+                    .distinct_on([(cake::Entity, cake::Column::Name)])
+                    .paginate(&db, 10)
+                    .fetch_page(0)
+                    .await,
+                Err(DbErr::BackendNotSupported { .. })
+            ));
+
+            assert!(db.into_transaction_log().is_empty());
+        }
+    }
+
+    #[smol_potat::test]
+    async fn distinct_is_supported() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([Vec::<cake::Model>::new(), Vec::<cake::Model>::new()])
+            .into_connection();
+
+        cake::Entity::find().distinct().all(&db).await?;
+        cake::Entity::find().all(&db).await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "SELECT DISTINCT `cake`.`id`, `cake`.`name` FROM `cake`",
+                    []
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                    []
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "with-json")]
+    async fn select_except() -> Result<(), DbErr> {
+        use crate::Value;
+        use serde_json::json;
+
+        let row = || {
+            maplit::btreemap! {
+                "id" => Value::from(2),
+                "cake_id" => Value::from(Some(1)),
+            }
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[row()], [row()]])
+            .into_connection();
+
+        assert_eq!(
+            fruit::Entity::find()
+                .select_except([fruit::Column::Name])
+                .into_json()
+                .one(&db)
+                .await?,
+            Some(json!({ "id": 2, "cake_id": 1 }))
+        );
+        // `name` is required by the model
+        assert_eq!(
+            fruit::Entity::find()
+                .select_except([fruit::Column::Name])
+                .one(&db)
+                .await,
+            Err(DbErr::Type("Missing value for column 'name'".to_owned()))
+        );
+
+        let stmt = || {
+            Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT "fruit"."id", "fruit"."cake_id" FROM "fruit" LIMIT $1"#,
+                [1u64.into()],
+            )
+        };
+        assert_eq!(db.into_transaction_log(), [stmt(), stmt()]);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "macros")]
+    async fn from_raw_sql_macro() -> Result<(), DbErr> {
+        use crate as sea_orm;
+        use crate::raw_sql;
+
+        let cheese = cake::Model {
+            id: 1,
+            name: "Cheese Cake".to_owned(),
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cheese.clone()], [cheese.clone()]])
+            .into_connection();
+
+        let (id, pattern) = (1, "%Cheese%");
+        assert_eq!(
+            cake::Entity::find()
+                .from_raw_sql(raw_sql!(
+                    db.get_database_backend(),
+                    r#"SELECT "id", "name" FROM "cake" WHERE "id" = {id} AND "name" LIKE {pattern}"#
+                )?)
+                .one(&db)
+                .await?,
+            Some(cheese.clone())
+        );
+        // positional arguments are bound in the order they appear in the SQL
+        assert_eq!(
+            cake::Entity::find()
+                .from_raw_sql(raw_sql!(
+                    Postgres,
+                    r#"SELECT "id", "name" FROM "cake" WHERE "id" = $2 AND "name" LIKE $1"#,
+                    pattern,
+                    id
+                ))
+                .all(&db)
+                .await?,
+            [cheese]
+        );
+
+        let stmt = || {
+            Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT "id", "name" FROM "cake" WHERE "id" = $1 AND "name" LIKE $2"#,
+                [1.into(), "%Cheese%".into()],
+            )
+        };
+        assert_eq!(db.into_transaction_log(), [stmt(), stmt()]);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn one_or_err() -> Result<(), DbErr> {
+        use crate::tests_cfg::cake_filling;
+
+        let (cake, _) = cake_and_fruit();
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake.clone()]])
+            .append_query_results([Vec::<cake::Model>::new()])
+            .append_query_results([Vec::<cake_filling::Model>::new()])
+            .append_query_results([Vec::<cake::Model>::new()])
+            .into_connection();
+
+        assert_eq!(cake::Entity::find_by_id(1).one_or_err(&db).await?, cake);
+        assert_eq!(
+            cake::Entity::find_by_id(2).one_or_err(&db).await,
+            Err(DbErr::RecordNotFound(
+                "No `cake` with primary key 2 found".to_owned()
+            ))
+        );
+        assert_eq!(
+            cake_filling::Entity::find_by_id((2, 3))
+                .one_or_err(&db)
+                .await,
+            Err(DbErr::RecordNotFound(
+                "No `cake_filling` with primary key (2, 3) found".to_owned()
+            ))
+        );
+        assert_eq!(
+            cake::Entity::find().into_json().one_or_err(&db).await,
+            Err(DbErr::RecordNotFound("No row found".to_owned()))
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn expect_one() -> Result<(), DbErr> {
+        let (cake, _) = cake_and_fruit();
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake.clone()]])
+            .append_query_results([[cake.clone(), cake.clone()]])
+            .append_query_results([Vec::<cake::Model>::new()])
+            .into_connection();
+
+        assert_eq!(cake::Entity::find().expect_one(&db).await?, cake);
+        assert_eq!(
+            cake::Entity::find().limit(10).expect_one(&db).await,
+            Err(DbErr::MultipleRecordsFound(
+                "More than one `cake` found".to_owned()
+            ))
+        );
+        assert_eq!(
+            cake::Entity::find_by_id(1).expect_one(&db).await,
+            Err(DbErr::RecordNotFound(
+                "No `cake` with primary key 1 found".to_owned()
+            ))
+        );
+
+        let stmt = |sql: &str, values: Vec<crate::Value>| {
+            Transaction::from_sql_and_values(DbBackend::Postgres, sql, values)
+        };
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                stmt(
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#,
+                    vec![2u64.into()]
+                ),
+                stmt(
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#,
+                    vec![2u64.into()]
+                ),
+                stmt(
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = $1 LIMIT $2"#,
+                    vec![1.into(), 2u64.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "macros")]
+    async fn find_hooks() -> Result<(), DbErr> {
+        use crate::Value;
+        use std::sync::atomic::Ordering;
+
+        mod note {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+            use std::sync::atomic::{AtomicUsize, Ordering};
+
+            pub static AFTER_FIND: AtomicUsize = AtomicUsize::new(0);
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "note")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub body: String,
+                pub deleted: bool,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {
+                fn before_find(select: Select<Entity>) -> Select<Entity> {
+                    select.filter(Column::Deleted.eq(false))
+                }
+
+                fn after_find<'a, C>(
+                    mut model: Model,
+                    _: &'a C,
+                ) -> crate::BoxFuture<'a, Result<Model, DbErr>>
+                where
+                    C: ConnectionTrait,
+                {
+                    Box::pin(async move {
+                        AFTER_FIND.fetch_add(1, Ordering::Relaxed);
+                        model.body = model.body.chars().rev().collect();
+                        Ok(model)
+                    })
+                }
+            }
+        }
+
+        let row = |id: i32| {
+            maplit::btreemap! {
+                "id" => Value::from(id),
+                "body" => Value::from("olleh"),
+                "deleted" => Value::from(false),
+            }
+        };
+        let note = |id: i32| note::Model {
+            id,
+            body: "hello".to_owned(),
+            deleted: false,
+        };
+        // finding doesn't require the ActiveModel to be `Send`
+        async fn find_all<E: EntityTrait>(
+            db: &crate::DatabaseConnection,
+        ) -> Result<Vec<E::Model>, DbErr> {
+            E::find().all(db).await
+        }
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([vec![row(1)], vec![row(1), row(2)], vec![row(1)]])
+            .into_connection();
+
+        assert_eq!(note::Entity::find_by_id(1).one(&db).await?, Some(note(1)));
+        assert_eq!(find_all::<note::Entity>(&db).await?, [note(1), note(2)]);
+        assert_eq!(note::AFTER_FIND.load(Ordering::Relaxed), 3);
+
+        // neither hook is invoked
+        assert_eq!(
+            note::Entity::find().without_hooks().all(&db).await?,
+            [note::Model {
+                id: 1,
+                body: "olleh".to_owned(),
+                deleted: false,
+            }]
+        );
+        assert_eq!(note::AFTER_FIND.load(Ordering::Relaxed), 3);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "note"."id", "note"."body", "note"."deleted" FROM "note" WHERE "note"."id" = $1 AND "note"."deleted" = $2 LIMIT $3"#,
+                    [1.into(), false.into(), 1u64.into()],
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "note"."id", "note"."body", "note"."deleted" FROM "note" WHERE "note"."deleted" = $1"#,
+                    [false.into()],
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "note"."id", "note"."body", "note"."deleted" FROM "note""#,
+                    [],
+                ),
+            ]
+        );
+
+        #[cfg(feature = "stream")]
+        {
+            #[cfg(feature = "sync")]
+            use crate::util::StreamShim;
+            #[cfg(not(feature = "sync"))]
+            use futures_util::TryStreamExt;
+
+            let db = MockDatabase::new(DbBackend::Postgres)
+                .append_query_results([[row(1), row(2), row(3)]])
+                .into_connection();
+
+            let mut stream = note::Entity::find().stream(&db).await?;
+            assert_eq!(stream.try_next().await?, Some(note(1)));
+            assert_eq!(note::AFTER_FIND.load(Ordering::Relaxed), 4);
+            assert_eq!(stream.try_next().await?, Some(note(2)));
+            assert_eq!(stream.try_next().await?, Some(note(3)));
+            assert_eq!(stream.try_next().await?, None);
+            assert_eq!(note::AFTER_FIND.load(Ordering::Relaxed), 6);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn timeout_hint() -> Result<(), DbErr> {
+        use crate::{ColumnTrait, QueryFilter, QueryScope};
+        use std::time::Duration;
+
+        let select = || {
+            cake::Entity::find()
+                .filter(cake::Column::Name.contains("cheese"))
+                .into_model::<cake::Model>()
+                .timeout(Duration::from_millis(1500))
+        };
+
+        let stmt = select().into_statement(DbBackend::MySql);
+        assert_eq!(
+            stmt.sql,
+            "SELECT /*+ MAX_EXECUTION_TIME(1500) */ `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE ?"
+        );
+        assert_eq!(stmt.timeout(), Some(Duration::from_millis(1500)));
+        assert_eq!(
+            select().into_statement(DbBackend::Postgres).sql,
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" LIKE $1"#
+        );
+
+        let scope = QueryScope::new().entity(cake::Entity, cake::Column::Id.eq(1));
+        assert_eq!(
+            scope
+                .build(DbBackend::MySql, &select().statement())?
+                .to_string(),
+            "SELECT /*+ MAX_EXECUTION_TIME(1500) */ `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '%cheese%' AND `cake`.`id` = 1"
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn timeout_with_cte() -> Result<(), DbErr> {
+        use crate::QueryTrait;
+        use sea_query::{CommonTableExpression, Query, WithClause};
+        use std::time::Duration;
+
+        let select = || {
+            let mut select = cake::Entity::find();
+            QueryTrait::query(&mut select).with_cte(
+                WithClause::new()
+                    .cte(
+                        CommonTableExpression::new()
+                            .query(
+                                Query::select()
+                                    .column(cake::Column::Id)
+                                    .from(cake::Entity)
+                                    .to_owned(),
+                            )
+                            .table_name("cheese_cake")
+                            .to_owned(),
+                    )
+                    .to_owned(),
+            );
+            select.into_model::<cake::Model>()
+        };
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([Vec::<cake::Model>::new()])
+            .into_connection();
+        assert_eq!(
+            select().timeout(Duration::from_secs(1)).all(&db).await,
+            Err(DbErr::BackendNotSupported {
+                db: "MySql",
+                ctx: "timeout of a query with a WITH clause",
+            })
+        );
+        select().all(&db).await?;
+        assert_eq!(db.into_transaction_log().len(), 1);
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([Vec::<cake::Model>::new()])
+            .into_connection();
+        select().timeout(Duration::from_secs(1)).all(&db).await?;
+
+        Ok(())
+    }
+}
@@ -129,6 +129,7 @@ where
     {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -143,11 +144,11 @@ where
         Q: PartialModelTrait,
     {
         let select = QuerySelect::select_only(self);
-        let select = M::select_cols(select);
-        let select = N::select_cols(select);
-        let select = O::select_cols(select);
-        let select = P::select_cols(select);
-        let select = Q::select_cols(select);
+        let select = M::select_cols_with_prefix(select, SelectA.as_str());
+        let select = N::select_cols_with_prefix(select, SelectB.as_str());
+        let select = O::select_cols_with_prefix(select, SelectC.as_str());
+        let select = P::select_cols_with_prefix(select, SelectD.as_str());
+        let select = Q::select_cols_with_prefix(select, SelectE.as_str());
         select.into_model::<M, N, O, P, Q>()
     }
 
@@ -158,6 +159,7 @@ where
     ) -> Selector<SelectFiveModel<JsonValue, JsonValue, JsonValue, JsonValue, JsonValue>> {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
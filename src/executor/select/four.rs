@@ -122,6 +122,7 @@ where
     {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -135,10 +136,10 @@ where
         P: PartialModelTrait,
     {
         let select = QuerySelect::select_only(self);
-        let select = M::select_cols(select);
-        let select = N::select_cols(select);
-        let select = O::select_cols(select);
-        let select = P::select_cols(select);
+        let select = M::select_cols_with_prefix(select, SelectA.as_str());
+        let select = N::select_cols_with_prefix(select, SelectB.as_str());
+        let select = O::select_cols_with_prefix(select, SelectC.as_str());
+        let select = P::select_cols_with_prefix(select, SelectD.as_str());
         select.into_model::<M, N, O, P>()
     }
 
@@ -149,6 +150,7 @@ where
     ) -> Selector<SelectFourModel<JsonValue, JsonValue, JsonValue, JsonValue>> {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -272,6 +274,7 @@ where
     {
         Selector {
             query: self.query,
+            timeout: None,
             selector: PhantomData,
         }
     }
@@ -1,9 +1,10 @@
 use super::ReturningSelector;
 use crate::{
     ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, IntoActiveModel, Iterable,
-    PrimaryKeyTrait, SelectModel, UpdateMany, UpdateOne, ValidatedUpdateOne, error::*,
+    PrimaryKeyTrait, Select, SelectModel, UpdateMany, UpdateManyModels, UpdateOne,
+    ValidatedUpdateOne, entity::column::select_as_named, error::*,
 };
-use sea_query::{FromValueTuple, Query, UpdateStatement};
+use sea_query::{FromValueTuple, Query, TableRef, UpdateStatement};
 
 /// Lower-level executor that runs a raw `sea_query` [`UpdateStatement`].
 /// Most code shouldn't need it directly — prefer
@@ -49,7 +50,7 @@ where
         C: ConnectionTrait,
     {
         Updater::new(self.query)
-            .exec_update_and_return_updated(self.model, db)
+            .exec_update_and_return_updated(self.model, self.table_ref, db)
             .await
     }
 }
@@ -87,6 +88,7 @@ where
     where
         C: ConnectionTrait,
     {
+        self.set_default_check?;
         Updater::new(self.query).exec(db).await
     }
 
@@ -95,12 +97,30 @@ where
     where
         C: ConnectionTrait,
     {
+        self.set_default_check?;
         Updater::new(self.query)
             .exec_update_with_returning::<E, _>(db)
             .await
     }
 }
 
+impl<E> UpdateManyModels<E>
+where
+    E: EntityTrait,
+{
+    /// Execute the update of all models in one statement. Nothing is sent to the
+    /// database if there is nothing to update.
+    pub async fn exec<C>(self, db: &C) -> Result<UpdateResult, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        match self.validate()? {
+            Some(update) => update.exec(db).await,
+            None => Ok(UpdateResult::default()),
+        }
+    }
+}
+
 impl Updater {
     /// Instantiate an update using an [UpdateStatement]
     fn new(query: UpdateStatement) -> Self {
@@ -135,6 +155,7 @@ impl Updater {
     async fn exec_update_and_return_updated<A, C>(
         mut self,
         model: A,
+        table_ref: Option<TableRef>,
         db: &C,
     ) -> Result<<A::Entity as EntityTrait>::Model, DbErr>
     where
@@ -146,14 +167,15 @@ impl Updater {
         type Column<A> = <Entity<A> as EntityTrait>::Column;
 
         if self.is_noop() {
-            return find_updated_model_by_id(model, db).await;
+            return find_updated_model_by_id(model, table_ref, db).await;
         }
 
         match db.support_returning() {
             true => {
                 let db_backend = db.get_database_backend();
                 let returning = Query::returning().exprs(
-                    Column::<A>::iter().map(|c| c.select_as(c.into_returning_expr(db_backend))),
+                    Column::<A>::iter()
+                        .map(|c| select_as_named(&c, c.into_returning_expr(db_backend))),
                 );
                 self.query.returning(returning);
                 let found: Option<Model<A>> =
@@ -170,7 +192,7 @@ impl Updater {
                 // If we updating a row that does not exist then an error will be thrown here.
                 self.check_record_exists = true;
                 self.exec(db).await?;
-                find_updated_model_by_id(model, db).await
+                find_updated_model_by_id(model, table_ref, db).await
             }
         }
     }
@@ -188,7 +210,8 @@ impl Updater {
         match db.support_returning() {
             true => {
                 let returning = Query::returning().exprs(
-                    E::Column::iter().map(|c| c.select_as(c.into_returning_expr(db_backend))),
+                    E::Column::iter()
+                        .map(|c| select_as_named(&c, c.into_returning_expr(db_backend))),
                 );
                 self.query.returning(returning);
                 let models: Vec<E::Model> =
@@ -211,6 +234,7 @@ impl Updater {
 
 async fn find_updated_model_by_id<A, C>(
     model: A,
+    table_ref: Option<TableRef>,
     db: &C,
 ) -> Result<<A::Entity as EntityTrait>::Model, DbErr>
 where
@@ -224,7 +248,10 @@ where
         Some(val) => ValueType::<A>::from_value_tuple(val),
         None => return Err(DbErr::UpdateGetPrimaryKey),
     };
-    let found = Entity::<A>::find_by_id(primary_key_value).one(db).await?;
+    let found = Select::<Entity<A>>::find_by_id_in(primary_key_value, table_ref)
+        .into_model()
+        .one(db)
+        .await?;
     // If we cannot select the updated row from db by the cached primary key
     match found {
         Some(model) => Ok(model),
@@ -241,7 +268,7 @@ mod tests {
         QueryFilter, Set, Transaction, Update, UpdateResult, tests_cfg::cake,
     };
     use pretty_assertions::assert_eq;
-    use sea_query::Expr;
+    use sea_query::{Alias, Expr};
 
     #[smol_potat::test]
     async fn update_record_not_found_1() -> Result<(), DbErr> {
@@ -423,4 +450,115 @@ mod tests {
             Err(DbErr::PrimaryKeyNotSet { .. })
         ));
     }
+
+    #[smol_potat::test]
+    async fn update_error_composite_key() {
+        use crate::tests_cfg::cake_filling;
+        use crate::{ActiveModelTrait, DbBackend, DbErr, MockDatabase, Set};
+
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        assert!(matches!(
+            Update::one(cake_filling::ActiveModel {
+                ..Default::default()
+            })
+            .exec(&db)
+            .await,
+            Err(DbErr::PrimaryKeyNotSet { ctx: "UpdateOne" })
+        ));
+
+        let partial = cake_filling::ActiveModel {
+            cake_id: Set(1),
+            ..Default::default()
+        };
+        assert!(matches!(
+            Update::one(partial.clone()).exec(&db).await,
+            Err(DbErr::PrimaryKeyNotSet { ctx: "UpdateOne" })
+        ));
+        assert!(matches!(
+            partial.update(&db).await,
+            Err(DbErr::PrimaryKeyNotSet { ctx: "UpdateOne" })
+        ));
+        assert!(matches!(
+            (cake::ActiveModel {
+                ..Default::default()
+            })
+            .update(&db)
+            .await,
+            Err(DbErr::PrimaryKeyNotSet { ctx: "UpdateOne" })
+        ));
+
+        // nothing was sent to the database
+        assert!(db.into_transaction_log().is_empty());
+    }
+
+    #[smol_potat::test]
+    async fn update_into_table_mysql() -> Result<(), DbErr> {
+        let model = cake::Model {
+            id: 1,
+            name: "Apple Pie".to_owned(),
+        };
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .append_query_results([[model.clone()]])
+            .into_connection();
+
+        assert_eq!(
+            Update::one(cake::ActiveModel {
+                id: Set(1),
+                name: Set("Apple Pie".to_owned()),
+            })
+            .into_table(Alias::new("cake_2024"))
+            .exec(&db)
+            .await?,
+            model
+        );
+
+        // the updated row is read back from the same table
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "UPDATE `cake_2024` AS `cake` SET `name` = ? WHERE `cake`.`id` = ?",
+                    ["Apple Pie".into(), 1.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    "SELECT `cake`.`id`, `cake`.`name` FROM `cake_2024` AS `cake` WHERE `cake`.`id` = ? LIMIT ?",
+                    [1.into(), 1u64.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn update_many_set_default() {
+        use crate::ActiveValue;
+
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        let update = || {
+            cake::Entity::update_many()
+                .set(cake::ActiveModel {
+                    id: ActiveValue::not_set(),
+                    name: ActiveValue::set_default(),
+                })
+                .filter(cake::Column::Id.eq(1))
+        };
+        let err = DbErr::Custom(
+            "Column `name` is SetDefault in UpdateMany, but `DEFAULT` is only supported in INSERT"
+                .to_owned(),
+        );
+        assert_eq!(update().exec(&db).await, Err(err.clone()));
+        assert_eq!(update().exec_with_returning(&db).await, Err(err));
+
+        // nothing was sent to the database
+        assert!(db.into_transaction_log().is_empty());
+    }
 }
@@ -0,0 +1,264 @@
+use crate::{
+    ActiveModelTrait, ConnectionTrait, DbBackend, DbErr, EntityTrait, IdenStatic, IntoActiveModel,
+    Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, TryIntoModel,
+};
+use sea_query::{Expr, ExprTrait, Func, Query};
+
+/// Options of [`load_with_options`]
+#[derive(Debug, Clone, Default)]
+pub struct LoadOptions {
+    reset_sequences: bool,
+}
+
+impl LoadOptions {
+    /// The default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// On Postgres, move the sequence of an auto-increment primary key past the largest id
+    /// after loading, so the rows inserted later don't collide with the fixtures' ids.
+    /// MySQL and SQLite do so by themselves.
+    pub fn reset_sequences(mut self, reset_sequences: bool) -> Self {
+        self.reset_sequences = reset_sequences;
+        self
+    }
+}
+
+/// Insert fixtures, a JSON array of objects, into the table of `E` and return the inserted Models.
+///
+/// Each object is converted with [`ActiveModelTrait::from_json`], so attributes it leaves out
+/// are `NotSet`. Load the fixtures of the entities a foreign key points to first.
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[smol_potat::main]
+/// # #[cfg(feature = "mock")]
+/// # pub async fn main() -> Result<(), DbErr> {
+/// # let db = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([[cake::Model {
+/// #         id: 1,
+/// #         name: "Cheese Cake".to_owned(),
+/// #     }]])
+/// #     .into_connection();
+/// use sea_orm::fixtures;
+///
+/// let cakes = fixtures::load::<cake::Entity, _>(
+///     &db,
+///     serde_json::json!([{ "id": 1, "name": "Cheese Cake" }]),
+/// )
+/// .await?;
+///
+/// assert_eq!(
+///     cakes,
+///     [cake::Model {
+///         id: 1,
+///         name: "Cheese Cake".to_owned(),
+///     }]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub async fn load<E, C>(db: &C, fixtures: serde_json::Value) -> Result<Vec<E::Model>, DbErr>
+where
+    E: EntityTrait,
+    E::ActiveModel: TryIntoModel<E::Model>,
+    E::Model: IntoActiveModel<E::ActiveModel> + serde::Serialize,
+    for<'de> E::Model: serde::Deserialize<'de>,
+    C: ConnectionTrait,
+{
+    load_with_options::<E, C>(db, fixtures, LoadOptions::default()).await
+}
+
+/// Insert fixtures into the table of `E` like [`load`], with options
+pub async fn load_with_options<E, C>(
+    db: &C,
+    fixtures: serde_json::Value,
+    options: LoadOptions,
+) -> Result<Vec<E::Model>, DbErr>
+where
+    E: EntityTrait,
+    E::ActiveModel: TryIntoModel<E::Model>,
+    E::Model: IntoActiveModel<E::ActiveModel> + serde::Serialize,
+    for<'de> E::Model: serde::Deserialize<'de>,
+    C: ConnectionTrait,
+{
+    let serde_json::Value::Array(fixtures) = fixtures else {
+        return Err(DbErr::Json(format!(
+            "invalid type: expected JSON array of {} fixtures",
+            E::default().as_str()
+        )));
+    };
+    let fixtures = fixtures
+        .into_iter()
+        .map(E::ActiveModel::from_json)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let models = if db.support_returning() {
+        E::insert_many(fixtures).exec_with_returning(db).await?
+    } else {
+        let mut models = Vec::with_capacity(fixtures.len());
+        for fixture in fixtures {
+            models.push(E::insert(fixture).exec_with_returning(db).await?);
+        }
+        models
+    };
+
+    if options.reset_sequences {
+        reset_sequences::<E, C>(db).await?;
+    }
+
+    Ok(models)
+}
+
+/// Set the next value of the primary key's sequence to the largest id plus one
+async fn reset_sequences<E, C>(db: &C) -> Result<(), DbErr>
+where
+    E: EntityTrait,
+    C: ConnectionTrait,
+{
+    if db.get_database_backend() != DbBackend::Postgres
+        || !<E::PrimaryKey as PrimaryKeyTrait>::auto_increment()
+    {
+        return Ok(());
+    }
+
+    let entity = E::default();
+    // `pg_get_serial_sequence` takes the table name as an identifier, quoted to keep its case
+    let table_name = match entity.schema_name() {
+        Some(schema_name) => format!(r#""{schema_name}"."{}""#, entity.table_name()),
+        None => format!(r#""{}""#, entity.table_name()),
+    };
+    for key in E::PrimaryKey::iter() {
+        let col = key.into_column();
+        let stmt = Query::select()
+            .expr(
+                Func::cust("setval").args([
+                    Func::cust("pg_get_serial_sequence")
+                        .args([Expr::val(table_name.as_str()), Expr::val(col.as_str())])
+                        .into(),
+                    Func::coalesce([Expr::col(col).max(), Expr::val(0)]).add(1),
+                    Expr::val(false),
+                ]),
+            )
+            .from(entity.table_ref())
+            .to_owned();
+        db.query_one(&stmt).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use super::LoadOptions;
+    use crate::{
+        DbBackend, DbErr, MockDatabase, MockExecResult, Transaction, fixtures, tests_cfg::cake,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn cakes() -> [cake::Model; 2] {
+        [
+            cake::Model {
+                id: 1,
+                name: "Cheese Cake".to_owned(),
+            },
+            cake::Model {
+                id: 2,
+                name: "Apple Pie".to_owned(),
+            },
+        ]
+    }
+
+    #[smol_potat::test]
+    async fn load_fixtures() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([cakes()])
+            .append_query_results([[maplit::btreemap! {
+                "setval" => 3i64.into(),
+            }]])
+            .into_connection();
+
+        assert_eq!(
+            fixtures::load_with_options::<cake::Entity, _>(
+                &db,
+                serde_json::json!([
+                    { "id": 1, "name": "Cheese Cake" },
+                    { "id": 2, "name": "Apple Pie" },
+                ]),
+                LoadOptions::new().reset_sequences(true),
+            )
+            .await?,
+            cakes()
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "cake" ("id", "name") VALUES ($1, $2), ($3, $4) RETURNING "id", "name""#,
+                    [1.into(), "Cheese Cake".into(), 2.into(), "Apple Pie".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT setval(pg_get_serial_sequence($1, $2), COALESCE(MAX("id"), $3) + $4, $5) FROM "cake""#,
+                    [
+                        r#""cake""#.into(),
+                        "id".into(),
+                        0.into(),
+                        1.into(),
+                        false.into()
+                    ]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn load_fixtures_without_returning() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 1,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 2,
+                    rows_affected: 1,
+                },
+            ])
+            .append_query_results([[cakes()[0].clone()], [cakes()[1].clone()]])
+            .into_connection();
+
+        assert_eq!(
+            fixtures::load::<cake::Entity, _>(
+                &db,
+                serde_json::json!([
+                    { "id": 1, "name": "Cheese Cake" },
+                    { "id": 2, "name": "Apple Pie" },
+                ]),
+            )
+            .await?,
+            cakes()
+        );
+        assert_eq!(db.into_transaction_log().len(), 4);
+
+        assert_eq!(
+            fixtures::load::<cake::Entity, _>(
+                &MockDatabase::new(DbBackend::MySql).into_connection(),
+                serde_json::json!({ "id": 1, "name": "Cheese Cake" }),
+            )
+            .await,
+            Err(DbErr::Json(
+                "invalid type: expected JSON array of cake fixtures".to_owned()
+            ))
+        );
+
+        Ok(())
+    }
+}
@@ -708,6 +708,10 @@ pub mod entity;
 /// Error types returned by SeaORM operations.
 pub mod error;
 mod executor;
+/// Loading reference data, e.g. the fixtures of integration tests, from JSON.
+#[cfg(feature = "with-json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-json")))]
+pub mod fixtures;
 /// Per-query metric collection hooks.
 pub mod metric;
 pub mod query;
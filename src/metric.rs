@@ -1,6 +1,67 @@
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
 
-pub(crate) type Callback = Arc<dyn Fn(&Info<'_>) + Send + Sync>;
+pub(crate) type Callback = Arc<Hooks>;
+
+type UserCallback = Arc<dyn Fn(&Info<'_>) + Send + Sync>;
+
+/// Everything notified after a statement is executed: the metric callback set by the
+/// user, and the query counter if counting is enabled
+#[derive(Clone, Default)]
+pub(crate) struct Hooks {
+    callback: Option<UserCallback>,
+    counter: Option<Arc<AtomicU64>>,
+}
+
+impl Hooks {
+    pub(crate) fn notify(&self, info: &Info<'_>) {
+        if let Some(counter) = &self.counter {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+        if let Some(callback) = &self.callback {
+            callback(info);
+        }
+    }
+
+    /// Number of statements executed since counting was enabled
+    pub(crate) fn query_count(&self) -> Option<u64> {
+        self.counter
+            .as_ref()
+            .map(|counter| counter.load(Ordering::Relaxed))
+    }
+}
+
+impl std::fmt::Debug for Hooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Hooks")
+            .field("callback", &self.callback.is_some())
+            .field("counter", &self.counter)
+            .finish()
+    }
+}
+
+/// Replace the user callback, keeping the query counter
+pub(crate) fn with_callback<F>(hooks: Option<Callback>, callback: F) -> Callback
+where
+    F: Fn(&Info<'_>) + Send + Sync + 'static,
+{
+    let mut hooks = hooks.as_deref().cloned().unwrap_or_default();
+    hooks.callback = Some(Arc::new(callback));
+    Arc::new(hooks)
+}
+
+/// Add a query counter, keeping the user callback. The counter is shared by connections
+/// and transactions created afterwards; it is kept if counting is already enabled.
+pub(crate) fn with_counter(hooks: Option<Callback>) -> Callback {
+    let mut hooks = hooks.as_deref().cloned().unwrap_or_default();
+    hooks.counter.get_or_insert_with(Default::default);
+    Arc::new(hooks)
+}
 
 #[allow(unused_imports)]
 pub(crate) use inner::metric;
@@ -29,7 +90,7 @@ mod inner {
                     statement: $stmt,
                     failed: res.is_err(),
                 };
-                callback(&info);
+                callback.notify(&info);
             }
             res
         }};
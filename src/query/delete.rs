@@ -1,11 +1,11 @@
 use crate::{
     ActiveModelTrait, ActiveValue, ColumnTrait, DbBackend, DbErr, EntityTrait, IntoActiveModel,
     Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, QueryTrait,
-    query::column_tuple_in_condition,
+    query::{column_tuple_in_condition, table_as_entity},
     sea_query::{IntoValueTuple, ValueTuple},
 };
 use core::marker::PhantomData;
-use sea_query::DeleteStatement;
+use sea_query::{DeleteStatement, IntoTableRef};
 
 /// Type-level entry point for `DELETE` builders, e.g.
 /// `Delete::one(model)` and `Delete::many(Entity)`. You normally call
@@ -46,6 +46,43 @@ impl<E: EntityTrait> DeleteOne<E> {
     pub fn validate(self) -> Result<ValidatedDeleteOne<E>, DbErr> {
         self.try_into()
     }
+
+    /// Delete the row from `table` instead of the entity's own table, e.g. a shard of it
+    /// chosen at runtime. The table is aliased as the entity's table name, so the
+    /// columns and filters of the entity keep working.
+    ///
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, sea_query::Alias, tests_cfg::cake};
+    ///
+    /// assert_eq!(
+    ///     Delete::one(cake::ActiveModel {
+    ///         id: ActiveValue::set(1),
+    ///         name: ActiveValue::set("Apple Pie".to_owned()),
+    ///     })
+    ///     .into_table(Alias::new("cake_2024"))
+    ///     .validate()
+    ///     .unwrap()
+    ///     .build(DbBackend::Postgres)
+    ///     .to_string(),
+    ///     r#"DELETE FROM "cake_2024" AS "cake" WHERE "cake"."id" = 1"#,
+    /// );
+    /// ```
+    pub fn into_table<T>(self, table: T) -> Self
+    where
+        T: IntoTableRef,
+    {
+        let table = table_as_entity::<E>(table.into_table_ref());
+        DeleteOne(self.0.map(|mut myself| {
+            myself.query.from_table(table);
+            myself
+        }))
+    }
+
+    /// Delete the row from the table of `entity`, for entities with a table name set at
+    /// runtime. See [`into_table`](Self::into_table).
+    pub fn for_entity_instance(self, entity: E) -> Self {
+        self.into_table(entity.table_ref())
+    }
 }
 
 /// Multi-row `DELETE` builder, returned by
@@ -120,7 +157,7 @@ impl Delete {
                 ActiveValue::Set(value) | ActiveValue::Unchanged(value) => {
                     myself = myself.filter(col.eq(value));
                 }
-                ActiveValue::NotSet => {
+                ActiveValue::NotSet | ActiveValue::SetDefault => {
                     return DeleteOne(Err(DbErr::PrimaryKeyNotSet { ctx: "DeleteOne" }));
                 }
             }
@@ -190,6 +227,37 @@ impl<E> DeleteMany<E>
 where
     E: EntityTrait,
 {
+    /// Delete the rows of `table` instead of the entity's own table, e.g. a shard of it
+    /// chosen at runtime. The table is aliased as the entity's table name, so the
+    /// columns and filters of the entity keep working.
+    ///
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, sea_query::Alias, tests_cfg::fruit};
+    ///
+    /// assert_eq!(
+    ///     Delete::many(fruit::Entity)
+    ///         .into_table(Alias::new("fruit_2024"))
+    ///         .filter(fruit::Column::Name.contains("Apple"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"DELETE FROM "fruit_2024" AS "fruit" WHERE "fruit"."name" LIKE '%Apple%'"#,
+    /// );
+    /// ```
+    pub fn into_table<T>(mut self, table: T) -> Self
+    where
+        T: IntoTableRef,
+    {
+        self.query
+            .from_table(table_as_entity::<E>(table.into_table_ref()));
+        self
+    }
+
+    /// Delete the rows of the table of `entity`, for entities with a table name set at
+    /// runtime. See [`into_table`](Self::into_table).
+    pub fn for_entity_instance(self, entity: E) -> Self {
+        self.into_table(entity.table_ref())
+    }
+
     /// Filter by vector of IDs by primary key
     ///
     /// # Panics
@@ -1,11 +1,59 @@
 use crate::{
-    ColumnTrait, EntityTrait, IdenStatic, Iterable, Linked, QueryFilter, QuerySelect, QueryTrait,
-    Related, Select, SelectA, SelectB, SelectThree, SelectTwo, SelectTwoMany, SelectTwoRequired,
-    TopologyChain, TopologyStar, find_linked_recursive, join_tbl_on_condition,
+    ColumnTrait, EntityTrait, IdenStatic, Iterable, Linked, PrimaryKeyToColumn, QueryFilter,
+    QuerySelect, QueryTrait, Related, Select, SelectA, SelectB, SelectThree, SelectTwo,
+    SelectTwoMany, SelectTwoRequired, TopologyChain, TopologyStar, find_linked_recursive,
+    hop_alias, join_tbl_on_condition, qualify_with_hop_alias,
 };
 pub use sea_query::JoinType;
 use sea_query::{Condition, Expr, IntoCondition, IntoIden, SelectExpr};
 
+/// Customizes the related side of [`Select::find_also_related_with`].
+#[derive(Debug)]
+pub struct RelatedSelector<R>
+where
+    R: EntityTrait,
+{
+    condition: Condition,
+    columns: Option<Vec<R::Column>>,
+}
+
+impl<R> RelatedSelector<R>
+where
+    R: EntityTrait,
+{
+    fn new() -> Self {
+        Self {
+            condition: Condition::all(),
+            columns: None,
+        }
+    }
+
+    /// Add a condition on the related Entity to the `ON` clause of the join.
+    /// Unlike filtering the whole query, rows without a matching related
+    /// Entity are kept, with the related side being `None`.
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: IntoCondition,
+    {
+        self.condition = self.condition.add(filter.into_condition());
+        self
+    }
+
+    /// Only select these columns of the related Entity, in addition to its
+    /// primary key. Decode the result with a partial model via
+    /// [`SelectTwo::into_model`].
+    pub fn columns<C, I>(mut self, cols: I) -> Self
+    where
+        C: Into<R::Column>,
+        I: IntoIterator<Item = C>,
+    {
+        self.columns
+            .get_or_insert_with(Vec::new)
+            .extend(cols.into_iter().map(Into::into));
+        self
+    }
+}
+
 impl<E> Select<E>
 where
     E: EntityTrait,
@@ -63,6 +111,76 @@ where
         self.left_join(r).select_also(r)
     }
 
+    /// Left Join with a Related Entity and select both Entity, customizing the
+    /// related side with a [`RelatedSelector`]. Filters are placed in the `ON`
+    /// clause of the join, so the parent rows are kept even when no related
+    /// row matches.
+    ///
+    /// ```
+    /// # use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::{cake, fruit}};
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .find_also_related_with(fruit::Entity, |sel| {
+    ///             sel.filter(fruit::Column::Name.contains("cherry"))
+    ///                 .columns([fruit::Column::Name])
+    ///         })
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+    ///         r#""fruit"."id" AS "B_id", "fruit"."name" AS "B_name""#,
+    ///         r#"FROM "cake""#,
+    ///         r#"LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+    ///         r#"AND "fruit"."name" LIKE '%cherry%'"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn find_also_related_with<R, F>(mut self, _: R, f: F) -> SelectTwo<E, R>
+    where
+        R: EntityTrait,
+        E: Related<R>,
+        F: FnOnce(RelatedSelector<R>) -> RelatedSelector<R>,
+    {
+        let selector = f(RelatedSelector::new());
+        if let Some(via) = E::via() {
+            self = self.join(JoinType::LeftJoin, via);
+        }
+        let rel = E::to();
+        let table_ref = rel.to_tbl.clone();
+        let mut condition = Condition::all().add(Condition::from(rel));
+        if !selector.condition.is_empty() {
+            condition = condition.add(selector.condition);
+        }
+        self.query.join(JoinType::LeftJoin, table_ref, condition);
+        self = self.apply_alias(SelectA.as_str());
+
+        let columns: Vec<R::Column> = match selector.columns {
+            Some(cols) => {
+                // the primary key is always selected to tell a missing related row apart
+                let mut columns: Vec<R::Column> = <R::PrimaryKey as Iterable>::iter()
+                    .map(|pk| pk.into_column())
+                    .collect();
+                for col in cols {
+                    if !columns.iter().any(|c| c.as_str() == col.as_str()) {
+                        columns.push(col);
+                    }
+                }
+                columns
+            }
+            None => <R::Column as Iterable>::iter().collect(),
+        };
+        for col in columns {
+            let alias = format!("{}{}", SelectB.as_str(), col.as_str());
+            self.query.expr(SelectExpr {
+                expr: col.select_as(col.into_expr()),
+                alias: Some(alias.into_iden()),
+                window: None,
+            });
+        }
+        SelectTwo::new_without_prepare(self.into_query())
+    }
+
     /// Inner Join with a Related Entity and select both Entity.
     pub fn find_both_related<R>(self, r: R) -> SelectTwoRequired<E, R>
     where
@@ -72,6 +190,17 @@ where
         self.inner_join(r).select_two_required(r)
     }
 
+    /// Inner Join with a Related Entity and select both Entity, like
+    /// [`find_also_related`](Self::find_also_related) but without the `Option`
+    /// around the related Model. Same as [`find_both_related`](Self::find_both_related).
+    pub fn find_also_related_strict<R>(self, r: R) -> SelectTwoRequired<E, R>
+    where
+        R: EntityTrait,
+        E: Related<R>,
+    {
+        self.find_both_related(r)
+    }
+
     /// Left Join with a Related Entity and select the related Entity as a `Vec`
     pub fn find_with_related<R>(self, r: R) -> SelectTwoMany<E, R>
     where
@@ -108,9 +237,9 @@ where
         for (i, mut rel) in l.link().into_iter().enumerate() {
             let r = self.linked_index;
             self.linked_index += 1;
-            let to_tbl = format!("r{r}").into_iden();
+            let to_tbl = hop_alias(r);
             let from_tbl = if i > 0 {
-                format!("r{}", i - 1).into_iden()
+                hop_alias(r - 1)
             } else {
                 rel.from_tbl.sea_orm_table().clone()
             };
@@ -121,6 +250,7 @@ where
                 to_tbl.clone(),
                 rel.from_col,
                 rel.to_col,
+                rel.treat_nulls_as_equal,
             ));
             if let Some(f) = rel.on_condition.take() {
                 condition = condition.add(f(from_tbl.clone(), to_tbl.clone()));
@@ -132,10 +262,7 @@ where
         self = self.apply_alias(SelectA.as_str());
         for col in <T::Column as Iterable>::iter() {
             let alias = format!("{}{}", SelectB.as_str(), col.as_str());
-            let expr = Expr::col((
-                format!("r{}", self.linked_index - 1).into_iden(),
-                col.into_iden(),
-            ));
+            let expr = Expr::col((hop_alias(self.linked_index - 1), col.into_iden()));
             self.query.expr(SelectExpr {
                 expr: col.select_as(expr),
                 alias: Some(alias.into_iden()),
@@ -145,6 +272,16 @@ where
         self
     }
 
+    /// Filter on the `hop`-th table joined by [`left_join_linked`](Self::left_join_linked).
+    /// See [`SelectTwo::filter_on_hop`].
+    pub fn filter_on_hop<X>(self, hop: usize, expr: X) -> Self
+    where
+        X: Into<Expr>,
+    {
+        let keep = E::default().table_name();
+        self.filter(qualify_with_hop_alias(expr.into(), hop, keep))
+    }
+
     /// Filter by condition on the related Entity. Uses `EXISTS` SQL statement under the hood.
     /// ```
     /// # use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::{cake, fruit, filling}};
@@ -219,6 +356,40 @@ where
     E: EntityTrait,
     F: EntityTrait,
 {
+    /// Filter on the `hop`-th table joined by
+    /// [`find_also_linked`](Select::find_also_linked), counting from 0.
+    ///
+    /// Columns in `expr` are rewritten to refer to the alias of the hop
+    /// (see [`Linked::hop_alias`]), except those of the first Entity.
+    ///
+    /// ```
+    /// # use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::*};
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .find_also_linked(entity_linked::CakeToFillingVendor)
+    ///         .filter_on_hop(1, filling::Column::Name.eq("cream"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+    ///         r#""r2"."id" AS "B_id", "r2"."name" AS "B_name""#,
+    ///         r#"FROM "cake""#,
+    ///         r#"LEFT JOIN "cake_filling" AS "r0" ON "cake"."id" = "r0"."cake_id""#,
+    ///         r#"LEFT JOIN "filling" AS "r1" ON "r0"."filling_id" = "r1"."id""#,
+    ///         r#"LEFT JOIN "vendor" AS "r2" ON "r1"."vendor_id" = "r2"."id""#,
+    ///         r#"WHERE "r1"."name" = 'cream'"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn filter_on_hop<X>(self, hop: usize, expr: X) -> Self
+    where
+        X: Into<Expr>,
+    {
+        let keep = E::default().table_name();
+        self.filter(qualify_with_hop_alias(expr.into(), hop, keep))
+    }
+
     /// Only used by Entity loader
     #[doc(hidden)]
     pub fn select_also_fake<R>(self, _: R) -> SelectThree<E, F, R, TopologyStar>
@@ -266,14 +437,31 @@ where
     }
 }
 
+impl<E, F> SelectTwoMany<E, F>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    /// Filter on the `hop`-th table joined by [`find_with_linked`](Select::find_with_linked).
+    /// See [`SelectTwo::filter_on_hop`].
+    pub fn filter_on_hop<X>(self, hop: usize, expr: X) -> Self
+    where
+        X: Into<Expr>,
+    {
+        let keep = E::default().table_name();
+        self.filter(qualify_with_hop_alias(expr.into(), hop, keep))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tests_cfg::{
         cake, cake_compact, cake_filling, cake_filling_price, entity_linked, filling, fruit,
+        ingredient, store_product, tag, vendor,
     };
     use crate::{
-        ColumnTrait, DbBackend, EntityTrait, ModelTrait, QueryFilter, QuerySelect, QueryTrait,
-        RelationTrait,
+        ColumnTrait, DbBackend, EntityAlias, EntityTrait, Linked, ModelTrait, QueryFilter,
+        QueryOrder, QuerySelect, QueryTrait, RelationDef, RelationTrait, TwoHopLink,
     };
     use pretty_assertions::assert_eq;
     use sea_query::{ConditionType, Expr, ExprTrait, IntoCondition, JoinType};
@@ -544,6 +732,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn join_13_filter_on_hop() {
+        use sea_query::Func;
+
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_linked(entity_linked::CakeToFillingVendor)
+                .filter_on_hop(1, filling::Column::Name.eq("cream"))
+                .filter_on_hop(
+                    2,
+                    Func::lower(vendor::Column::Name.into_expr())
+                        .like("%farm%")
+                        .or(cake::Column::Name.is_null())
+                )
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                r"SELECT `cake`.`id` AS `A_id`, `cake`.`name` AS `A_name`,",
+                r"`r2`.`id` AS `B_id`, `r2`.`name` AS `B_name`",
+                r"FROM `cake`",
+                r"LEFT JOIN `cake_filling` AS `r0` ON `cake`.`id` = `r0`.`cake_id`",
+                r"LEFT JOIN `filling` AS `r1` ON `r0`.`filling_id` = `r1`.`id`",
+                r"LEFT JOIN `vendor` AS `r2` ON `r1`.`vendor_id` = `r2`.`id`",
+                r"WHERE `r1`.`name` = 'cream'",
+                r"AND (LOWER(`r2`.`name`) LIKE '%farm%' OR `cake`.`name` IS NULL)",
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            cake::Entity::find()
+                .find_with_linked(entity_linked::CakeToFillingVendor)
+                .filter_on_hop(
+                    1,
+                    filling::Column::VendorId.into_expr().eq(Expr::col((
+                        entity_linked::CakeToFillingVendor::hop_alias(2),
+                        vendor::Column::Id,
+                    )))
+                )
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                r"SELECT `cake`.`id` AS `A_id`, `cake`.`name` AS `A_name`,",
+                r"`r2`.`id` AS `B_id`, `r2`.`name` AS `B_name`",
+                r"FROM `cake`",
+                r"LEFT JOIN `cake_filling` AS `r0` ON `cake`.`id` = `r0`.`cake_id`",
+                r"LEFT JOIN `filling` AS `r1` ON `r0`.`filling_id` = `r1`.`id`",
+                r"LEFT JOIN `vendor` AS `r2` ON `r1`.`vendor_id` = `r2`.`id`",
+                r"WHERE `r1`.`vendor_id` = `r2`.`id`",
+            ]
+            .join(" ")
+        );
+    }
+
     #[test]
     fn join_14() {
         assert_eq!(
@@ -817,4 +1059,490 @@ mod tests {
             .join(" ")
         );
     }
+
+    #[test]
+    fn join_26() {
+        type CakeToFillingVendor = TwoHopLink<cake::Entity, filling::Entity, vendor::Entity>;
+
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_linked(CakeToFillingVendor::new())
+                .build(DbBackend::MySql)
+                .to_string(),
+            cake::Entity::find()
+                .find_also_linked(entity_linked::CakeToFillingVendor)
+                .build(DbBackend::MySql)
+                .to_string(),
+        );
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_linked(CakeToFillingVendor::new())
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                r"SELECT `cake`.`id` AS `A_id`, `cake`.`name` AS `A_name`,",
+                r"`r2`.`id` AS `B_id`, `r2`.`name` AS `B_name`",
+                r"FROM `cake`",
+                r"LEFT JOIN `cake_filling` AS `r0` ON `cake`.`id` = `r0`.`cake_id`",
+                r"LEFT JOIN `filling` AS `r1` ON `r0`.`filling_id` = `r1`.`id`",
+                r"LEFT JOIN `vendor` AS `r2` ON `r1`.`vendor_id` = `r2`.`id`",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_27() {
+        let cake_model = cake::Model {
+            id: 18,
+            name: "".to_owned(),
+        };
+
+        assert_eq!(
+            cake_model
+                .find_linked(TwoHopLink::<cake::Entity, filling::Entity, vendor::Entity>::new())
+                .build(DbBackend::MySql)
+                .to_string(),
+            cake_model
+                .find_linked(entity_linked::CakeToFillingVendor)
+                .build(DbBackend::MySql)
+                .to_string(),
+        );
+        assert_eq!(
+            fruit::Entity::find()
+                .find_also_linked(TwoHopLink::<fruit::Entity, cake::Entity, filling::Entity>::new())
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                r"SELECT `fruit`.`id` AS `A_id`, `fruit`.`name` AS `A_name`, `fruit`.`cake_id` AS `A_cake_id`,",
+                r"`r2`.`id` AS `B_id`, `r2`.`name` AS `B_name`, `r2`.`vendor_id` AS `B_vendor_id`",
+                r"FROM `fruit`",
+                r"LEFT JOIN `cake` AS `r0` ON `fruit`.`cake_id` = `r0`.`id`",
+                r"LEFT JOIN `cake_filling` AS `r1` ON `r0`.`id` = `r1`.`cake_id`",
+                r"LEFT JOIN `filling` AS `r2` ON `r1`.`filling_id` = `r2`.`id`",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_28() {
+        assert_eq!(
+            store_product::Entity::find()
+                .find_also_related(cake::Entity)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "product"."id" AS "A_id", "product"."name" AS "A_name", "product"."cake_id" AS "A_cake_id","#,
+                r#""cake"."id" AS "B_id", "cake"."name" AS "B_name""#,
+                r#"FROM "store"."product""#,
+                r#"LEFT JOIN "cake" ON "product"."cake_id" = "cake"."id""#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_related(store_product::Entity)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+                r#""product"."id" AS "B_id", "product"."name" AS "B_name", "product"."cake_id" AS "B_cake_id""#,
+                r#"FROM "cake""#,
+                r#"LEFT JOIN "store"."product" ON "cake"."id" = "product"."cake_id""#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_29() {
+        assert_eq!(
+            tag::Entity::find()
+                .find_also_related(store_product::Entity)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "tag"."id" AS "A_id", "tag"."tag" AS "A_tag","#,
+                r#""product"."id" AS "B_id", "product"."name" AS "B_name", "product"."cake_id" AS "B_cake_id""#,
+                r#"FROM "tag""#,
+                r#"LEFT JOIN "store"."product_tag" ON "tag"."id" = "product_tag"."tag_id""#,
+                r#"LEFT JOIN "store"."product" ON "product_tag"."product_id" = "product"."id""#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            store_product::Entity::find()
+                .find_also_related(tag::Entity)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "product"."id" AS "A_id", "product"."name" AS "A_name", "product"."cake_id" AS "A_cake_id","#,
+                r#""tag"."id" AS "B_id", "tag"."tag" AS "B_tag""#,
+                r#"FROM "store"."product""#,
+                r#"LEFT JOIN "store"."product_tag" ON "product"."id" = "product_tag"."product_id""#,
+                r#"LEFT JOIN "tag" ON "product_tag"."tag_id" = "tag"."id""#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_30() {
+        let tag_model = tag::Model {
+            id: 1,
+            tag: "".to_owned(),
+        };
+
+        assert_eq!(
+            tag_model
+                .find_related(store_product::Entity)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "product"."id", "product"."name", "product"."cake_id""#,
+                r#"FROM "store"."product""#,
+                r#"INNER JOIN "store"."product_tag" ON "product_tag"."product_id" = "product"."id""#,
+                r#"INNER JOIN "tag" ON "tag"."id" = "product_tag"."tag_id""#,
+                r#"WHERE "tag"."id" = 1"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_31() {
+        type TagToProductCake = TwoHopLink<tag::Entity, store_product::Entity, cake::Entity>;
+
+        assert_eq!(
+            tag::Entity::find()
+                .find_also_linked(TagToProductCake::new())
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "tag"."id" AS "A_id", "tag"."tag" AS "A_tag","#,
+                r#""r2"."id" AS "B_id", "r2"."name" AS "B_name""#,
+                r#"FROM "tag""#,
+                r#"LEFT JOIN "store"."product_tag" AS "r0" ON "tag"."id" = "r0"."tag_id""#,
+                r#"LEFT JOIN "store"."product" AS "r1" ON "r0"."product_id" = "r1"."id""#,
+                r#"LEFT JOIN "cake" AS "r2" ON "r1"."cake_id" = "r2"."id""#,
+            ]
+            .join(" ")
+        );
+
+        let tag_model = tag::Model {
+            id: 1,
+            tag: "".to_owned(),
+        };
+
+        assert_eq!(
+            tag_model
+                .find_linked(TagToProductCake::new())
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "cake"."id", "cake"."name""#,
+                r#"FROM "cake""#,
+                r#"INNER JOIN "store"."product" AS "r0" ON "r0"."cake_id" = "cake"."id""#,
+                r#"INNER JOIN "store"."product_tag" AS "r1" ON "r1"."product_id" = "r0"."id""#,
+                r#"INNER JOIN "tag" AS "r2" ON "r2"."id" = "r1"."tag_id""#,
+                r#"WHERE "r2"."id" = 1"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_32() {
+        let query = |db_backend| {
+            cake_filling::Entity::find()
+                .join(
+                    JoinType::LeftJoin,
+                    cake_filling_price::Relation::CakeFilling
+                        .def()
+                        .rev()
+                        .treat_nulls_as_equal(db_backend),
+                )
+                .build(db_backend)
+                .to_string()
+        };
+
+        assert_eq!(
+            query(DbBackend::Postgres),
+            [
+                r#"SELECT "cake_filling"."cake_id", "cake_filling"."filling_id" FROM "cake_filling""#,
+                r#"LEFT JOIN "public"."cake_filling_price" ON"#,
+                r#"("cake_filling"."cake_id" IS NOT DISTINCT FROM "cake_filling_price"."cake_id")"#,
+                r#"AND ("cake_filling"."filling_id" IS NOT DISTINCT FROM "cake_filling_price"."filling_id")"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            query(DbBackend::MySql),
+            [
+                "SELECT `cake_filling`.`cake_id`, `cake_filling`.`filling_id` FROM `cake_filling`",
+                "LEFT JOIN `public`.`cake_filling_price` ON",
+                "(`cake_filling`.`cake_id` <=> `cake_filling_price`.`cake_id`)",
+                "AND (`cake_filling`.`filling_id` <=> `cake_filling_price`.`filling_id`)",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            query(DbBackend::Sqlite),
+            [
+                r#"SELECT "cake_filling"."cake_id", "cake_filling"."filling_id" FROM "cake_filling""#,
+                r#"LEFT JOIN "public"."cake_filling_price" ON"#,
+                r#"("cake_filling"."cake_id" = "cake_filling_price"."cake_id""#,
+                r#"OR ("cake_filling"."cake_id" IS NULL AND "cake_filling_price"."cake_id" IS NULL))"#,
+                r#"AND ("cake_filling"."filling_id" = "cake_filling_price"."filling_id""#,
+                r#"OR ("cake_filling"."filling_id" IS NULL AND "cake_filling_price"."filling_id" IS NULL))"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_33() {
+        struct FruitToCakeNullSafe(DbBackend);
+
+        impl Linked for FruitToCakeNullSafe {
+            type FromEntity = fruit::Entity;
+            type ToEntity = cake::Entity;
+
+            fn link(&self) -> Vec<RelationDef> {
+                vec![fruit::Relation::Cake.def().treat_nulls_as_equal(self.0)]
+            }
+        }
+
+        let query = |db_backend| {
+            fruit::Entity::find()
+                .find_also_linked(FruitToCakeNullSafe(db_backend))
+                .build(db_backend)
+                .to_string()
+        };
+
+        assert_eq!(
+            query(DbBackend::Postgres),
+            [
+                r#"SELECT "fruit"."id" AS "A_id", "fruit"."name" AS "A_name", "fruit"."cake_id" AS "A_cake_id","#,
+                r#""r0"."id" AS "B_id", "r0"."name" AS "B_name""#,
+                r#"FROM "fruit""#,
+                r#"LEFT JOIN "cake" AS "r0" ON "fruit"."cake_id" IS NOT DISTINCT FROM "r0"."id""#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            query(DbBackend::MySql),
+            [
+                "SELECT `fruit`.`id` AS `A_id`, `fruit`.`name` AS `A_name`, `fruit`.`cake_id` AS `A_cake_id`,",
+                "`r0`.`id` AS `B_id`, `r0`.`name` AS `B_name`",
+                "FROM `fruit`",
+                "LEFT JOIN `cake` AS `r0` ON `fruit`.`cake_id` <=> `r0`.`id`",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            query(DbBackend::Sqlite),
+            [
+                r#"SELECT "fruit"."id" AS "A_id", "fruit"."name" AS "A_name", "fruit"."cake_id" AS "A_cake_id","#,
+                r#""r0"."id" AS "B_id", "r0"."name" AS "B_name""#,
+                r#"FROM "fruit""#,
+                r#"LEFT JOIN "cake" AS "r0" ON "fruit"."cake_id" = "r0"."id""#,
+                r#"OR ("fruit"."cake_id" IS NULL AND "r0"."id" IS NULL)"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_34() {
+        let parent = EntityAlias::<ingredient::Entity>::new("parent");
+        let child = EntityAlias::<ingredient::Entity>::new("child");
+
+        assert_eq!(
+            ingredient::Entity::find()
+                .select_only()
+                .column(ingredient::Column::Name)
+                .column_as(parent.col(ingredient::Column::Name), "parent_name")
+                .column_as(child.col(ingredient::Column::Name), "child_name")
+                .join_as(
+                    JoinType::LeftJoin,
+                    ingredient::Relation::Ingredient.def(),
+                    &parent
+                )
+                .join_as_rev(
+                    JoinType::LeftJoin,
+                    ingredient::Relation::Ingredient.def(),
+                    &child
+                )
+                .filter(parent.col(ingredient::Column::Name).like("%sugar%"))
+                .filter(child.col(ingredient::Column::Id).is_not_null())
+                .order_by_asc(parent.col(ingredient::Column::Id))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "ingredient"."name", "parent"."name" AS "parent_name", "child"."name" AS "child_name""#,
+                r#"FROM "ingredient""#,
+                r#"LEFT JOIN "ingredient" AS "parent" ON "ingredient"."ingredient_id" = "parent"."id""#,
+                r#"LEFT JOIN "ingredient" AS "child" ON "child"."ingredient_id" = "ingredient"."id""#,
+                r#"WHERE "parent"."name" LIKE '%sugar%' AND "child"."id" IS NOT NULL"#,
+                r#"ORDER BY "parent"."id" ASC"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_35() {
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_linked(entity_linked::CakeToCreamFillingVendor)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+                r#""r2"."id" AS "B_id", "r2"."name" AS "B_name""#,
+                r#"FROM "cake""#,
+                r#"LEFT JOIN "cake_filling" AS "r0" ON "cake"."id" = "r0"."cake_id""#,
+                r#"LEFT JOIN "filling" AS "r1" ON "r0"."filling_id" = "r1"."id" AND "r1"."name" LIKE '%cream%'"#,
+                r#"LEFT JOIN "vendor" AS "r2" ON "r1"."vendor_id" = "r2"."id""#,
+            ]
+            .join(" ")
+        );
+
+        let cake_model = cake::Model {
+            id: 12,
+            name: "".to_owned(),
+        };
+
+        assert_eq!(
+            cake_model
+                .find_linked(entity_linked::CakeToCreamFillingVendor)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "vendor"."id", "vendor"."name""#,
+                r#"FROM "vendor""#,
+                r#"INNER JOIN "filling" AS "r0" ON "r0"."vendor_id" = "vendor"."id""#,
+                r#"INNER JOIN "cake_filling" AS "r1" ON "r1"."filling_id" = "r0"."id" AND "r0"."name" LIKE '%cream%'"#,
+                r#"INNER JOIN "cake" AS "r2" ON "r2"."id" = "r1"."cake_id""#,
+                r#"WHERE "r2"."id" = 12"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_36() {
+        assert_eq!(
+            cake_compact::Entity::find()
+                .join(
+                    JoinType::LeftJoin,
+                    cake_compact::Relation::AliasedTropicalFruit.def()
+                )
+                .join_as(
+                    JoinType::LeftJoin,
+                    cake_compact::Relation::AliasedTropicalFruit.def(),
+                    "tropical_fruit"
+                )
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "LEFT JOIN `fruit` ON `cake`.`id` = `fruit`.`cake_id` AND `fruit`.`name` LIKE '%tropical%'",
+                "LEFT JOIN `fruit` AS `tropical_fruit` ON `cake`.`id` = `tropical_fruit`.`cake_id` AND `tropical_fruit`.`name` LIKE '%tropical%'",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn find_also_related_with_1() {
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_related_with(fruit::Entity, |sel| {
+                    sel.filter(fruit::Column::Name.contains("cherry"))
+                })
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id` AS `A_id`, `cake`.`name` AS `A_name`,",
+                "`fruit`.`id` AS `B_id`, `fruit`.`name` AS `B_name`, `fruit`.`cake_id` AS `B_cake_id`",
+                "FROM `cake`",
+                "LEFT JOIN `fruit` ON `cake`.`id` = `fruit`.`cake_id` AND `fruit`.`name` LIKE '%cherry%'",
+            ]
+            .join(" ")
+        );
+
+        // filtering afterwards puts the predicate in WHERE instead
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_related(fruit::Entity)
+                .filter(fruit::Column::Name.contains("cherry"))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id` AS `A_id`, `cake`.`name` AS `A_name`,",
+                "`fruit`.`id` AS `B_id`, `fruit`.`name` AS `B_name`, `fruit`.`cake_id` AS `B_cake_id`",
+                "FROM `cake`",
+                "LEFT JOIN `fruit` ON `cake`.`id` = `fruit`.`cake_id`",
+                "WHERE `fruit`.`name` LIKE '%cherry%'",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn find_also_related_with_2() {
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_related_with(fruit::Entity, |sel| sel)
+                .build(DbBackend::MySql)
+                .to_string(),
+            cake::Entity::find()
+                .find_also_related(fruit::Entity)
+                .build(DbBackend::MySql)
+                .to_string(),
+        );
+
+        // the primary key is selected even if not requested
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_related_with(fruit::Entity, |sel| {
+                    sel.columns([fruit::Column::Name, fruit::Column::Id])
+                })
+                .filter(cake::Column::Id.eq(1))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id` AS `A_id`, `cake`.`name` AS `A_name`,",
+                "`fruit`.`id` AS `B_id`, `fruit`.`name` AS `B_name`",
+                "FROM `cake`",
+                "LEFT JOIN `fruit` ON `cake`.`id` = `fruit`.`cake_id`",
+                "WHERE `cake`.`id` = 1",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn find_also_related_with_3() {
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_related_with(filling::Entity, |sel| {
+                    sel.filter(filling::Column::Name.eq("Marmalade"))
+                        .columns([filling::Column::Name])
+                })
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id` AS `A_id`, `cake`.`name` AS `A_name`,",
+                "`filling`.`id` AS `B_id`, `filling`.`name` AS `B_name`",
+                "FROM `cake`",
+                "LEFT JOIN `cake_filling` ON `cake`.`id` = `cake_filling`.`cake_id`",
+                "LEFT JOIN `filling` ON `cake_filling`.`filling_id` = `filling`.`id` AND `filling`.`name` = 'Marmalade'",
+            ]
+            .join(" ")
+        );
+    }
 }
@@ -1,10 +1,14 @@
 use super::get_key_from_model;
 use crate::{
     ColumnTrait, Condition, ConnectionTrait, DbBackend, DbErr, EntityTrait, Identity, JoinType,
-    ModelTrait, QueryFilter, QuerySelect, Related, RelatedSelfVia, RelationDef, RelationTrait,
-    RelationType, Select, dynamic, query::column_tuple_in_condition, query_err,
+    Linked, ModelTrait, QueryFilter, QueryResult, QuerySelect, Related, RelatedSelfVia,
+    RelationDef, RelationTrait, RelationType, Select, TryGetError, TryGetable, dynamic,
+    query::{ValueTupleBuilder, column_tuple_in_condition},
+    query_err,
+};
+use sea_query::{
+    ColumnRef, DynIden, Expr, ExprTrait, IntoColumnRef, Query, TableRef, Value, ValueTuple,
 };
-use sea_query::{ColumnRef, DynIden, Expr, ExprTrait, IntoColumnRef, TableRef, ValueTuple};
 use std::{collections::HashMap, str::FromStr};
 
 // TODO: Replace DynIden::inner with a better API that without clone
@@ -22,6 +26,8 @@ type LoaderEntity<T> = <<T as LoaderTrait>::Model as ModelTrait>::Entity;
 type LoaderModel<T> = <<<T as LoaderTrait>::Model as ModelTrait>::Entity as EntityTrait>::Model;
 type LoaderRelation<T> =
     <<<T as LoaderTrait>::Model as ModelTrait>::Entity as EntityTrait>::Relation;
+type LoaderActiveModel<T> =
+    <<<T as LoaderTrait>::Model as ModelTrait>::Entity as EntityTrait>::ActiveModel;
 
 /// Batch-load related entities for a slice of parent models, avoiding the
 /// N+1 query problem.
@@ -45,6 +51,7 @@ pub trait LoaderTrait {
     where
         C: ConnectionTrait,
         LoaderModel<Self>: Send + Sync,
+        LoaderActiveModel<Self>: Send,
         LoaderRelation<Self>: Send,
         S: EntityOrSelect<LoaderEntity<Self>>;
 
@@ -59,6 +66,7 @@ pub trait LoaderTrait {
     where
         C: ConnectionTrait,
         LoaderModel<Self>: Send + Sync,
+        LoaderActiveModel<Self>: Send,
         LoaderRelation<Self>: Send,
         S: EntityOrSelect<LoaderEntity<Self>>;
 
@@ -72,6 +80,7 @@ pub trait LoaderTrait {
         C: ConnectionTrait,
         V: EntityTrait,
         LoaderModel<Self>: Send + Sync,
+        LoaderActiveModel<Self>: Send,
         LoaderEntity<Self>: RelatedSelfVia<V>;
 
     /// Used to eager load self_ref + via relations, but in reverse
@@ -84,6 +93,7 @@ pub trait LoaderTrait {
         C: ConnectionTrait,
         V: EntityTrait,
         LoaderModel<Self>: Send + Sync,
+        LoaderActiveModel<Self>: Send,
         LoaderEntity<Self>: RelatedSelfVia<V>;
 
     /// Used to eager load has_one relations
@@ -120,6 +130,20 @@ pub trait LoaderTrait {
         V: EntityTrait,
         V::Model: Send + Sync,
         <Self::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to eager load [`Linked`] relations without joins: one query per hop, with the keys
+    /// found by the previous hop. Models with no path get an empty `Vec`.
+    ///
+    /// Links with an `on_condition` are not supported, as it spans two tables.
+    async fn load_linked<L, C>(
+        &self,
+        link: L,
+        db: &C,
+    ) -> Result<Vec<Vec<<L::ToEntity as EntityTrait>::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        L: Linked<FromEntity = <Self::Model as ModelTrait>::Entity> + Send,
+        <L::ToEntity as EntityTrait>::Model: Send + Sync;
 }
 
 type LoaderExEntity<T> = <<T as LoaderTraitEx>::Model as ModelTrait>::Entity;
@@ -128,6 +152,8 @@ type LoaderExModelEx<T> =
     <<<T as LoaderTraitEx>::Model as ModelTrait>::Entity as EntityTrait>::ModelEx;
 type LoaderExRelation<T> =
     <<<T as LoaderTraitEx>::Model as ModelTrait>::Entity as EntityTrait>::Relation;
+type LoaderExActiveModel<T> =
+    <<<T as LoaderTraitEx>::Model as ModelTrait>::Entity as EntityTrait>::ActiveModel;
 
 #[doc(hidden)]
 #[async_trait::async_trait]
@@ -143,6 +169,7 @@ pub trait LoaderTraitEx {
     where
         C: ConnectionTrait,
         LoaderExModel<Self>: Send + Sync,
+        LoaderExActiveModel<Self>: Send,
         LoaderExModelEx<Self>: From<LoaderExModel<Self>>,
         LoaderExRelation<Self>: Send,
         S: EntityOrSelect<LoaderExEntity<Self>>;
@@ -156,6 +183,7 @@ pub trait LoaderTraitEx {
     where
         C: ConnectionTrait,
         LoaderExModel<Self>: Send + Sync,
+        LoaderExActiveModel<Self>: Send,
         LoaderExModelEx<Self>: From<LoaderExModel<Self>>,
         LoaderExRelation<Self>: Send,
         S: EntityOrSelect<LoaderExEntity<Self>>;
@@ -170,6 +198,7 @@ pub trait LoaderTraitEx {
         C: ConnectionTrait,
         V: EntityTrait,
         LoaderExModel<Self>: Send + Sync,
+        LoaderExActiveModel<Self>: Send,
         LoaderExModelEx<Self>: From<LoaderExModel<Self>>,
         LoaderExEntity<Self>: RelatedSelfVia<V>;
 
@@ -221,6 +250,8 @@ type NestedModelEx<T> =
     <<<T as NestedLoaderTrait>::Model as ModelTrait>::Entity as EntityTrait>::ModelEx;
 type NestedLoaderRelation<T> =
     <<<T as NestedLoaderTrait>::Model as ModelTrait>::Entity as EntityTrait>::Relation;
+type NestedActiveModel<T> =
+    <<<T as NestedLoaderTrait>::Model as ModelTrait>::Entity as EntityTrait>::ActiveModel;
 
 #[doc(hidden)]
 #[async_trait::async_trait]
@@ -236,6 +267,7 @@ pub trait NestedLoaderTrait {
     where
         C: ConnectionTrait,
         NestedModel<Self>: Send + Sync,
+        NestedActiveModel<Self>: Send,
         NestedModelEx<Self>: From<NestedModel<Self>>,
         NestedLoaderRelation<Self>: Send,
         S: EntityOrSelect<<<Self as NestedLoaderTrait>::Model as ModelTrait>::Entity>;
@@ -249,6 +281,7 @@ pub trait NestedLoaderTrait {
     where
         C: ConnectionTrait,
         NestedModel<Self>: Send + Sync,
+        NestedActiveModel<Self>: Send,
         NestedModelEx<Self>: From<NestedModel<Self>>,
         NestedLoaderRelation<Self>: Send,
         S: EntityOrSelect<<<Self as NestedLoaderTrait>::Model as ModelTrait>::Entity>;
@@ -263,6 +296,7 @@ pub trait NestedLoaderTrait {
         C: ConnectionTrait,
         V: EntityTrait,
         NestedModel<Self>: Send + Sync,
+        NestedActiveModel<Self>: Send,
         NestedModelEx<Self>: From<NestedModel<Self>>,
         NestedEntity<Self>: RelatedSelfVia<V>;
 
@@ -349,6 +383,7 @@ where
     where
         C: ConnectionTrait,
         LoaderModel<Self>: Send + Sync,
+        LoaderActiveModel<Self>: Send,
         LoaderRelation<Self>: Send,
         S: EntityOrSelect<LoaderEntity<Self>>,
     {
@@ -364,6 +399,7 @@ where
     where
         C: ConnectionTrait,
         LoaderModel<Self>: Send + Sync,
+        LoaderActiveModel<Self>: Send,
         LoaderRelation<Self>: Send,
         S: EntityOrSelect<LoaderEntity<Self>>,
     {
@@ -379,6 +415,7 @@ where
         C: ConnectionTrait,
         V: EntityTrait,
         LoaderModel<Self>: Send + Sync,
+        LoaderActiveModel<Self>: Send,
         LoaderEntity<Self>: RelatedSelfVia<V>,
     {
         LoaderTrait::load_self_via(&self.as_slice(), via, db).await
@@ -393,6 +430,7 @@ where
         C: ConnectionTrait,
         V: EntityTrait,
         LoaderModel<Self>: Send + Sync,
+        LoaderActiveModel<Self>: Send,
         LoaderEntity<Self>: RelatedSelfVia<V>,
     {
         LoaderTrait::load_self_via_rev(&self.as_slice(), via, db).await
@@ -437,6 +475,19 @@ where
     {
         LoaderTrait::load_many_to_many(&self.as_slice(), stmt, via, db).await
     }
+
+    async fn load_linked<L, C>(
+        &self,
+        link: L,
+        db: &C,
+    ) -> Result<Vec<Vec<<L::ToEntity as EntityTrait>::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        L: Linked<FromEntity = <Self::Model as ModelTrait>::Entity> + Send,
+        <L::ToEntity as EntityTrait>::Model: Send + Sync,
+    {
+        LoaderTrait::load_linked(&self.as_slice(), link, db).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -455,6 +506,7 @@ where
     where
         C: ConnectionTrait,
         LoaderModel<Self>: Send + Sync,
+        LoaderActiveModel<Self>: Send,
         LoaderRelation<Self>: Send,
         S: EntityOrSelect<LoaderEntity<Self>>,
     {
@@ -472,6 +524,7 @@ where
     where
         C: ConnectionTrait,
         LoaderModel<Self>: Send + Sync,
+        LoaderActiveModel<Self>: Send,
         LoaderRelation<Self>: Send,
         S: EntityOrSelect<LoaderEntity<Self>>,
     {
@@ -491,6 +544,7 @@ where
         C: ConnectionTrait,
         V: EntityTrait,
         LoaderModel<Self>: Send + Sync,
+        LoaderActiveModel<Self>: Send,
         LoaderEntity<Self>: RelatedSelfVia<V>,
     {
         let rel_def = <LoaderEntity<Self> as RelatedSelfVia<V>>::to();
@@ -514,6 +568,7 @@ where
         C: ConnectionTrait,
         V: EntityTrait,
         LoaderModel<Self>: Send + Sync,
+        LoaderActiveModel<Self>: Send,
         LoaderEntity<Self>: RelatedSelfVia<V>,
     {
         let rel_def = <LoaderEntity<Self> as RelatedSelfVia<V>>::via().rev();
@@ -659,6 +714,19 @@ where
             return Err(query_err("Relation is not ManyToMany"));
         }
     }
+
+    async fn load_linked<L, C>(
+        &self,
+        link: L,
+        db: &C,
+    ) -> Result<Vec<Vec<<L::ToEntity as EntityTrait>::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        L: Linked<FromEntity = <Self::Model as ModelTrait>::Entity> + Send,
+        <L::ToEntity as EntityTrait>::Model: Send + Sync,
+    {
+        linked_loader_impl::<_, _, L::ToEntity, _>(self.iter(), link.link(), db).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -677,6 +745,7 @@ where
     where
         C: ConnectionTrait,
         LoaderExModel<Self>: Send + Sync,
+        LoaderExActiveModel<Self>: Send,
         LoaderExModelEx<Self>: From<LoaderExModel<Self>>,
         LoaderExRelation<Self>: Send,
         S: EntityOrSelect<LoaderExEntity<Self>>,
@@ -695,6 +764,7 @@ where
     where
         C: ConnectionTrait,
         LoaderExModel<Self>: Send + Sync,
+        LoaderExActiveModel<Self>: Send,
         LoaderExModelEx<Self>: From<LoaderExModel<Self>>,
         LoaderExRelation<Self>: Send,
         S: EntityOrSelect<LoaderExEntity<Self>>,
@@ -714,6 +784,7 @@ where
         C: ConnectionTrait,
         V: EntityTrait,
         LoaderExModel<Self>: Send + Sync,
+        LoaderExActiveModel<Self>: Send,
         LoaderExModelEx<Self>: From<LoaderExModel<Self>>,
         LoaderExEntity<Self>: RelatedSelfVia<V>,
     {
@@ -814,6 +885,7 @@ where
     where
         C: ConnectionTrait,
         LoaderExModel<Self>: Send + Sync,
+        LoaderExActiveModel<Self>: Send,
         LoaderExModelEx<Self>: From<LoaderExModel<Self>>,
         LoaderExRelation<Self>: Send,
         S: EntityOrSelect<LoaderExEntity<Self>>,
@@ -840,6 +912,7 @@ where
     where
         C: ConnectionTrait,
         LoaderExModel<Self>: Send + Sync,
+        LoaderExActiveModel<Self>: Send,
         LoaderExModelEx<Self>: From<LoaderExModel<Self>>,
         LoaderExRelation<Self>: Send,
         S: EntityOrSelect<LoaderExEntity<Self>>,
@@ -867,6 +940,7 @@ where
         C: ConnectionTrait,
         V: EntityTrait,
         LoaderExModel<Self>: Send + Sync,
+        LoaderExActiveModel<Self>: Send,
         LoaderExModelEx<Self>: From<LoaderExModel<Self>>,
         LoaderExEntity<Self>: RelatedSelfVia<V>,
     {
@@ -988,6 +1062,7 @@ where
     where
         C: ConnectionTrait,
         NestedModel<Self>: Send + Sync,
+        NestedActiveModel<Self>: Send,
         NestedModelEx<Self>: From<NestedModel<Self>>,
         NestedLoaderRelation<Self>: Send,
         S: EntityOrSelect<<<Self as NestedLoaderTrait>::Model as ModelTrait>::Entity>,
@@ -1008,6 +1083,7 @@ where
     where
         C: ConnectionTrait,
         NestedModel<Self>: Send + Sync,
+        NestedActiveModel<Self>: Send,
         NestedModelEx<Self>: From<NestedModel<Self>>,
         NestedLoaderRelation<Self>: Send,
         S: EntityOrSelect<<<Self as NestedLoaderTrait>::Model as ModelTrait>::Entity>,
@@ -1029,6 +1105,7 @@ where
         C: ConnectionTrait,
         V: EntityTrait,
         NestedModel<Self>: Send + Sync,
+        NestedActiveModel<Self>: Send,
         NestedModelEx<Self>: From<NestedModel<Self>>,
         NestedEntity<Self>: RelatedSelfVia<V>,
     {
@@ -1321,6 +1398,200 @@ where
     Ok(result)
 }
 
+async fn linked_loader_impl<'a, Model, Iter, R, C>(
+    items: Iter,
+    link: Vec<RelationDef>,
+    db: &C,
+) -> Result<Vec<Vec<R::Model>>, DbErr>
+where
+    Model: ModelTrait + Sync + 'a,
+    Iter: Iterator<Item = &'a Model> + 'a,
+    C: ConnectionTrait,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+{
+    use itertools::Itertools;
+
+    let Some((last, hops)) = link.split_last() else {
+        return Err(query_err("Linked must have at least one hop"));
+    };
+    if link.iter().any(|rel_def| rel_def.on_condition.is_some()) {
+        return Err(query_err(
+            "Loader: Linked with on_condition is not supported",
+        ));
+    }
+
+    let keys = items
+        .map(|model| get_key_from_model(&link[0].from_col, model))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if keys.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let db_backend = db.get_database_backend();
+    let hop_condition = |hop: usize, rel_def: &RelationDef, keys: &[ValueTuple]| {
+        if hop == 0 {
+            prepare_condition::<Model>(
+                &rel_def.to_tbl,
+                &rel_def.from_col,
+                &rel_def.to_col,
+                keys,
+                db_backend,
+            )
+        } else {
+            column_tuple_in_condition(&rel_def.to_tbl, &rel_def.to_col, keys, db_backend)
+        }
+    };
+
+    // The intermediate entities are unknown, so each hop only selects the keys it joins on
+    // SELECT "cake_filling"."cake_id", "cake_filling"."filling_id" FROM "cake_filling"
+    // WHERE "cake_filling"."cake_id" IN (..)
+    let mut frontier: Vec<ValueTuple> = keys.iter().cloned().unique().collect();
+    // Per hop, map of `to_col` key -> `from_col` keys of the next hop
+    let mut hop_maps: Vec<HashMap<ValueTuple, Vec<ValueTuple>>> = Vec::new();
+
+    for (hop, rel_def) in hops.iter().enumerate() {
+        let next_def = &link[hop + 1];
+        let mut hop_map: HashMap<ValueTuple, Vec<ValueTuple>> = HashMap::new();
+        let mut next_frontier = Vec::new();
+
+        if !frontier.is_empty() {
+            let mut stmt = Query::select();
+            stmt.columns(column_refs_from_identity(&rel_def.to_tbl, &rel_def.to_col))
+                .columns(column_refs_from_identity(
+                    &rel_def.to_tbl,
+                    &next_def.from_col,
+                ))
+                .from(rel_def.to_tbl.clone())
+                .cond_where(hop_condition(hop, rel_def, &frontier)?);
+
+            for row in db.query_all(&stmt).await? {
+                let (Some(key), Some(next_key)) = (
+                    key_from_row(&row, &rel_def.to_col)?,
+                    key_from_row(&row, &next_def.from_col)?,
+                ) else {
+                    continue;
+                };
+                hop_map
+                    .entry(normalize_key(key))
+                    .or_default()
+                    .push(normalize_key(next_key.clone()));
+                next_frontier.push(next_key);
+            }
+        }
+
+        frontier = next_frontier.into_iter().unique().collect();
+        hop_maps.push(hop_map);
+    }
+
+    let mut data: HashMap<ValueTuple, Vec<R::Model>> = HashMap::new();
+
+    if !frontier.is_empty() {
+        let condition = hop_condition(hops.len(), last, &frontier)?;
+        for model in R::find().filter(condition).all(db).await? {
+            let key = get_key_from_model(&last.to_col, &model)?;
+            data.entry(normalize_key(key)).or_default().push(model);
+        }
+    }
+
+    // Follow the chain of each model, a row reached by several paths is only returned once
+    let result = keys
+        .into_iter()
+        .map(|key| {
+            let ends = hop_maps
+                .iter()
+                .fold(vec![normalize_key(key)], |keys, hop_map| {
+                    keys.iter()
+                        .filter_map(|key| hop_map.get(key))
+                        .flatten()
+                        .unique()
+                        .cloned()
+                        .collect()
+                });
+            ends.iter()
+                .filter_map(|key| data.get(key))
+                .flatten()
+                .cloned()
+                .collect()
+        })
+        .collect();
+
+    Ok(result)
+}
+
+/// Read a key of an unknown type from a row; `None` if any of its columns is null
+fn key_from_row(row: &QueryResult, identity: &Identity) -> Result<Option<ValueTuple>, DbErr> {
+    let mut values = ValueTupleBuilder::default();
+
+    for col in identity.iter() {
+        match value_from_row(row, &col.inner())? {
+            Some(value) => values.push(value),
+            None => return Ok(None),
+        }
+    }
+
+    Ok(values.into_inner())
+}
+
+/// Try the column types a key is usually made of, until one decodes
+fn value_from_row(row: &QueryResult, col: &str) -> Result<Option<Value>, DbErr> {
+    let mut is_null = true;
+
+    macro_rules! try_get_as {
+        ( $type: ty ) => {
+            match <$type as TryGetable>::try_get(row, "", col) {
+                Ok(value) => return Ok(Some(value.into())),
+                Err(TryGetError::Null(_)) => {}
+                Err(TryGetError::DbErr(_)) => is_null = false,
+            }
+        };
+    }
+
+    try_get_as!(i64);
+    try_get_as!(i32);
+    try_get_as!(i16);
+    try_get_as!(u64);
+    try_get_as!(u32);
+    try_get_as!(String);
+    #[cfg(feature = "with-uuid")]
+    try_get_as!(uuid::Uuid);
+    try_get_as!(Vec<u8>);
+
+    if is_null {
+        Ok(None)
+    } else {
+        Err(DbErr::Type(format!(
+            "Loader: unsupported type of key column '{col}'"
+        )))
+    }
+}
+
+/// Integers of different widths compare equal, as the same key can be decoded as either
+fn normalize_key(key: ValueTuple) -> ValueTuple {
+    fn normalize(value: Value) -> Value {
+        match value {
+            Value::TinyInt(v) => Value::BigInt(v.map(Into::into)),
+            Value::SmallInt(v) => Value::BigInt(v.map(Into::into)),
+            Value::Int(v) => Value::BigInt(v.map(Into::into)),
+            Value::TinyUnsigned(v) => Value::BigInt(v.map(Into::into)),
+            Value::SmallUnsigned(v) => Value::BigInt(v.map(Into::into)),
+            Value::Unsigned(v) => Value::BigInt(v.map(Into::into)),
+            Value::BigUnsigned(Some(v)) if i64::try_from(v).is_ok() => {
+                Value::BigInt(Some(v as i64))
+            }
+            value => value,
+        }
+    }
+
+    match key {
+        ValueTuple::One(a) => ValueTuple::One(normalize(a)),
+        ValueTuple::Two(a, b) => ValueTuple::Two(normalize(a), normalize(b)),
+        ValueTuple::Three(a, b, c) => ValueTuple::Three(normalize(a), normalize(b), normalize(c)),
+        ValueTuple::Many(values) => ValueTuple::Many(values.into_iter().map(normalize).collect()),
+    }
+}
+
 fn cmp_table_ref(left: &TableRef, right: &TableRef) -> bool {
     left == right
 }
@@ -1852,6 +2123,144 @@ mod tests {
         assert_eq!(values_count, 2, "Duplicate values were not removed");
     }
 
+    fn linked_filling_model(id: i32, vendor_id: Option<i32>) -> sea_orm::tests_cfg::filling::Model {
+        sea_orm::tests_cfg::filling::Model {
+            vendor_id,
+            ..filling_model(id)
+        }
+    }
+
+    fn vendor_model(id: i32) -> sea_orm::tests_cfg::vendor::Model {
+        sea_orm::tests_cfg::vendor::Model {
+            id,
+            name: format!("Vendor {id}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_load_linked_base() {
+        use sea_orm::{DbBackend, IntoMockRow, LoaderTrait, MockDatabase, Statement, tests_cfg::*};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([
+                [
+                    cake_filling_model(1, 1).into_mock_row(),
+                    cake_filling_model(2, 2).into_mock_row(),
+                    cake_filling_model(2, 3).into_mock_row(),
+                ],
+                [
+                    linked_filling_model(1, Some(1)).into_mock_row(),
+                    linked_filling_model(2, Some(2)).into_mock_row(),
+                    linked_filling_model(3, Some(3)).into_mock_row(),
+                ],
+                [
+                    vendor_model(1).into_mock_row(),
+                    vendor_model(2).into_mock_row(),
+                    vendor_model(3).into_mock_row(),
+                ],
+            ])
+            .into_connection();
+
+        let cakes = vec![cake_model(1), cake_model(2)];
+
+        let vendors = cakes
+            .load_linked(entity_linked::CakeToFillingVendor, &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            vendors,
+            [
+                vec![vendor_model(1)],
+                vec![vendor_model(2), vendor_model(3)]
+            ]
+        );
+
+        let logs = db.into_transaction_log();
+        let statements: Vec<_> = logs.iter().flat_map(|txn| txn.statements()).collect();
+        assert_eq!(statements.len(), 3);
+        assert_eq!(
+            statements[1],
+            &Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT "filling"."id", "filling"."vendor_id" FROM "filling" WHERE "filling"."id" IN ($1, $2, $3)"#,
+                [1i32.into(), 2i32.into(), 3i32.into()]
+            )
+        );
+        assert_eq!(
+            statements[2],
+            &Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT "vendor"."id", "vendor"."name" FROM "vendor" WHERE "vendor"."id" IN ($1, $2, $3)"#,
+                [1i32.into(), 2i32.into(), 3i32.into()]
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_linked_shared_and_empty() {
+        use sea_orm::{DbBackend, IntoMockRow, LoaderTrait, MockDatabase, tests_cfg::*};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([
+                vec![
+                    cake_filling_model(1, 1).into_mock_row(),
+                    cake_filling_model(1, 2).into_mock_row(),
+                    cake_filling_model(2, 2).into_mock_row(),
+                    cake_filling_model(3, 4).into_mock_row(),
+                ],
+                vec![
+                    linked_filling_model(1, Some(1)).into_mock_row(),
+                    linked_filling_model(2, Some(1)).into_mock_row(),
+                    linked_filling_model(4, None).into_mock_row(),
+                ],
+                vec![vendor_model(1).into_mock_row()],
+            ])
+            .into_connection();
+
+        // cake 1 reaches vendor 1 through two fillings, cake 3's filling has no vendor
+        // and cake 4 has no filling at all
+        let cakes = vec![cake_model(1), cake_model(2), cake_model(3), cake_model(4)];
+
+        let vendors = cakes
+            .load_linked(entity_linked::CakeToFillingVendor, &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            vendors,
+            [vec![vendor_model(1)], vec![vendor_model(1)], vec![], vec![]]
+        );
+
+        let logs = db.into_transaction_log();
+        let sql = format!("{:?}", logs[1]);
+        assert_eq!(
+            sql.matches('$').count(),
+            3,
+            "Duplicate intermediate keys were not removed"
+        );
+        let sql = format!("{:?}", logs[2]);
+        assert_eq!(
+            sql.matches('$').count(),
+            1,
+            "Duplicate intermediate keys were not removed"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_linked_on_condition() {
+        use sea_orm::{DbBackend, LoaderTrait, MockDatabase, tests_cfg::*};
+
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        assert!(
+            vec![cake_model(1)]
+                .load_linked(entity_linked::CheeseCakeToFillingVendor, &db)
+                .await
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_assemble_vectors() {
         use super::assemble_vectors;
@@ -19,6 +19,7 @@
 //! For raw SQL, use [`Statement`] together with the [`raw_sql!`](crate::raw_sql)
 //! macro.
 
+mod alias;
 pub(crate) mod combine;
 mod debug;
 mod delete;
@@ -33,11 +34,13 @@ mod traits;
 mod update;
 mod util;
 
+pub use alias::*;
 pub use combine::{SelectA, SelectB, SelectC};
 pub use debug::*;
 pub use delete::*;
 pub use helper::*;
 pub use insert::*;
+pub use join::RelatedSelector;
 #[cfg(feature = "with-json")]
 pub use json::*;
 pub use loader::*;
@@ -26,6 +26,71 @@ pub trait QueryTrait {
         StatementBuilder::build(self.as_query(), &db_backend)
     }
 
+    /// Render the query for `db_backend` as a SQL string with the values
+    /// inlined, for logging and debugging. To run the query, use the
+    /// [`Statement`] from [`build`](Self::build) instead.
+    ///
+    /// ```
+    /// use sea_orm::{
+    ///     DbBackend, entity::*, query::*,
+    ///     tests_cfg::{cake, fruit},
+    /// };
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.contains("cheese"))
+    ///         .to_sql_string(DbBackend::MySql),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '%cheese%'"
+    /// );
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .find_also_related(fruit::Entity)
+    ///         .filter(cake::Column::Id.eq(1))
+    ///         .to_sql_string(DbBackend::Postgres),
+    ///     [
+    ///         r#"SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+    ///         r#""fruit"."id" AS "B_id", "fruit"."name" AS "B_name", "fruit"."cake_id" AS "B_cake_id""#,
+    ///         r#"FROM "cake" LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+    ///         r#"WHERE "cake"."id" = 1"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// assert_eq!(
+    ///     cake::Entity::insert(cake::ActiveModel {
+    ///         name: Set("Apple Pie".to_owned()),
+    ///         ..Default::default()
+    ///     })
+    ///     .to_sql_string(DbBackend::Sqlite),
+    ///     r#"INSERT INTO "cake" ("name") VALUES ('Apple Pie')"#
+    /// );
+    /// assert_eq!(
+    ///     cake::Entity::update(cake::ActiveModel {
+    ///         id: Unchanged(1),
+    ///         name: Set("Orange Cake".to_owned()),
+    ///     })
+    ///     .validate()?
+    ///     .to_sql_string(DbBackend::Postgres),
+    ///     r#"UPDATE "cake" SET "name" = 'Orange Cake' WHERE "cake"."id" = 1"#
+    /// );
+    /// assert_eq!(
+    ///     cake::Entity::update_many()
+    ///         .col_expr(cake::Column::Name, "Orange Cake".into())
+    ///         .filter(cake::Column::Id.gt(10))
+    ///         .to_sql_string(DbBackend::MySql),
+    ///     "UPDATE `cake` SET `name` = 'Orange Cake' WHERE `cake`.`id` > 10"
+    /// );
+    /// assert_eq!(
+    ///     cake::Entity::delete_many()
+    ///         .filter(cake::Column::Name.eq("Apple Pie"))
+    ///         .to_sql_string(DbBackend::Postgres),
+    ///     r#"DELETE FROM "cake" WHERE "cake"."name" = 'Apple Pie'"#
+    /// );
+    /// # Ok::<(), sea_orm::DbErr>(())
+    /// ```
+    fn to_sql_string(&self, db_backend: DbBackend) -> String {
+        self.build(db_backend).to_string()
+    }
+
     /// Apply an operation on the [QueryTrait::QueryStatement] if the given `Option<T>` is `Some(_)`
     ///
     /// # Example
@@ -1,9 +1,12 @@
 use crate::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, DbErr, EntityTrait, Iterable, PrimaryKeyToColumn,
-    QueryFilter, QueryTrait,
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbErr, EntityName, EntityTrait, IdenStatic,
+    Iterable, PrimaryKeyToColumn, QueryFilter, QueryTrait, query::table_as_entity,
 };
 use core::marker::PhantomData;
-use sea_query::{Expr, IntoIden, SimpleExpr, UpdateStatement};
+use sea_query::{
+    CaseStatement, Condition, Expr, IntoCondition, IntoIden, IntoTableRef, SimpleExpr, TableRef,
+    UpdateStatement, Value,
+};
 
 /// Type-level entry point for `UPDATE` builders, e.g.
 /// `Update::one(model)` and `Update::many(Entity)`. You normally call
@@ -29,6 +32,7 @@ pub struct UpdateOne<A: ActiveModelTrait>(pub(crate) Result<ValidatedUpdateOne<A
 pub struct ValidatedUpdateOne<A: ActiveModelTrait> {
     pub(crate) query: UpdateStatement,
     pub(crate) model: A,
+    pub(crate) table_ref: Option<TableRef>,
 }
 
 impl<A: ActiveModelTrait> TryFrom<UpdateOne<A>> for ValidatedUpdateOne<A> {
@@ -44,6 +48,47 @@ impl<A: ActiveModelTrait> UpdateOne<A> {
     pub fn validate(self) -> Result<ValidatedUpdateOne<A>, DbErr> {
         self.try_into()
     }
+
+    /// Update the row in `table` instead of the entity's own table, e.g. a shard of it
+    /// chosen at runtime. The table is aliased as the entity's table name, so the
+    /// columns and filters of the entity keep working. Where the backend has no
+    /// `RETURNING`, the updated row is also read back from `table`.
+    ///
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, sea_query::Alias, tests_cfg::cake};
+    ///
+    /// assert_eq!(
+    ///     Update::one(cake::ActiveModel {
+    ///         id: ActiveValue::set(1),
+    ///         name: ActiveValue::set("Apple Pie".to_owned()),
+    ///     })
+    ///     .into_table(Alias::new("cake_2024"))
+    ///     .validate()
+    ///     .unwrap()
+    ///     .build(DbBackend::Postgres)
+    ///     .to_string(),
+    ///     r#"UPDATE "cake_2024" AS "cake" SET "name" = 'Apple Pie' WHERE "cake"."id" = 1"#,
+    /// );
+    /// ```
+    pub fn into_table<T>(self, table: T) -> Self
+    where
+        T: IntoTableRef,
+    {
+        let table = table.into_table_ref();
+        UpdateOne(self.0.map(|mut myself| {
+            myself
+                .query
+                .table(table_as_entity::<A::Entity>(table.clone()));
+            myself.table_ref = Some(table);
+            myself
+        }))
+    }
+
+    /// Update the row in the table of `entity`, for entities with a table name set at
+    /// runtime. See [`into_table`](Self::into_table).
+    pub fn for_entity_instance(self, entity: A::Entity) -> Self {
+        self.into_table(entity.table_ref())
+    }
 }
 
 /// Multi-row `UPDATE` builder, returned by
@@ -56,9 +101,34 @@ where
     E: EntityTrait,
 {
     pub(crate) query: UpdateStatement,
+    /// Whether a model passed to [`set`](Self::set) has a `SetDefault` column,
+    /// reported when executing
+    pub(crate) set_default_check: Result<(), DbErr>,
     pub(crate) entity: PhantomData<E>,
 }
 
+/// A request to update many [`ActiveModel`](ActiveModelTrait)s, each to its own
+/// values, with a single statement. Built by [`Update::many_models`].
+///
+/// Like [`UpdateOne`], an invalid request is reported by [`exec`](Self::exec)
+/// without sending any queries to the database. Use [`validate`](Self::validate)
+/// to access the generated SQL query.
+#[derive(Clone, Debug)]
+pub struct UpdateManyModels<E>(pub(crate) Result<Option<UpdateMany<E>>, DbErr>)
+where
+    E: EntityTrait;
+
+impl<E> UpdateManyModels<E>
+where
+    E: EntityTrait,
+{
+    /// Check whether every primary key is set and the models set the same columns.
+    /// Returns `None` if there is nothing to update.
+    pub fn validate(self) -> Result<Option<UpdateMany<E>>, DbErr> {
+        self.0
+    }
+}
+
 impl Update {
     /// Update one ActiveModel
     ///
@@ -91,6 +161,7 @@ impl Update {
                 .table(A::Entity::default().table_ref())
                 .to_owned(),
             model,
+            table_ref: None,
         };
         // Build the SQL condition from the primary key columns.
         for key in <A::Entity as EntityTrait>::PrimaryKey::iter() {
@@ -99,21 +170,26 @@ impl Update {
                 ActiveValue::Set(value) | ActiveValue::Unchanged(value) => {
                     myself = myself.filter(col.eq(value));
                 }
-                ActiveValue::NotSet => {
+                ActiveValue::NotSet | ActiveValue::SetDefault => {
                     return UpdateOne(Err(DbErr::PrimaryKeyNotSet { ctx: "UpdateOne" }));
                 }
             }
         }
         // Set the values to update (from the other columns).
         for col in <A::Entity as EntityTrait>::Column::iter() {
-            if <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_some() {
+            if <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_some()
+                || col.def().is_generated()
+            {
                 continue;
             }
             match myself.model.get(col) {
                 ActiveValue::Set(value) => {
-                    let expr = col.save_as(Expr::val(value));
+                    let expr = col.save_as(Expr::val(col.encrypt_value(value)));
                     myself.query.value(col, expr);
                 }
+                ActiveValue::SetDefault => {
+                    return UpdateOne(Err(set_default_not_supported("UpdateOne", col.as_str())));
+                }
                 ActiveValue::Unchanged(_) | ActiveValue::NotSet => {}
             }
         }
@@ -140,9 +216,145 @@ impl Update {
     {
         UpdateMany {
             query: UpdateStatement::new().table(entity.table_ref()).to_owned(),
+            set_default_check: Ok(()),
             entity: PhantomData,
         }
     }
+
+    /// Update many ActiveModels, each to its own values, with a single statement
+    ///
+    /// Each column `Set` on the models is assigned a `CASE` over the primary keys,
+    /// e.g. to write a new position to every row of a reordered list. The columns
+    /// must be `Set` on every model or on none of them; columns `Unchanged` or
+    /// `NotSet` on all models are left as is.
+    ///
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let update = Update::many_models([
+    ///     fruit::ActiveModel {
+    ///         id: ActiveValue::unchanged(1),
+    ///         name: ActiveValue::set("Apple".to_owned()),
+    ///         cake_id: ActiveValue::not_set(),
+    ///     },
+    ///     fruit::ActiveModel {
+    ///         id: ActiveValue::unchanged(2),
+    ///         name: ActiveValue::set("Orange".to_owned()),
+    ///         cake_id: ActiveValue::not_set(),
+    ///     },
+    /// ])
+    /// .validate()
+    /// .unwrap()
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     update.build(DbBackend::Postgres).to_string(),
+    ///     [
+    ///         r#"UPDATE "fruit" SET "name" = (CASE WHEN ("fruit"."id" = 1) THEN 'Apple' WHEN ("fruit"."id" = 2) THEN 'Orange' END)"#,
+    ///         r#"WHERE "fruit"."id" IN (1, 2)"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn many_models<A, I>(models: I) -> UpdateManyModels<A::Entity>
+    where
+        A: ActiveModelTrait,
+        I: IntoIterator<Item = A>,
+    {
+        UpdateManyModels(update_many_models(models.into_iter().collect()))
+    }
+}
+
+fn update_many_models<A>(models: Vec<A>) -> Result<Option<UpdateMany<A::Entity>>, DbErr>
+where
+    A: ActiveModelTrait,
+{
+    let Some(first) = models.first() else {
+        return Ok(None);
+    };
+    let is_value_column = |col: &<A::Entity as EntityTrait>::Column| {
+        <A::Entity as EntityTrait>::PrimaryKey::from_column(*col).is_none()
+            && !col.def().is_generated()
+    };
+    // The columns to update are those `Set` on the first model
+    let mut cases: Vec<_> = <A::Entity as EntityTrait>::Column::iter()
+        .map(|col| {
+            (is_value_column(&col) && matches!(first.get(col), ActiveValue::Set(_)))
+                .then(CaseStatement::new)
+        })
+        .collect();
+    if cases.iter().all(Option::is_none) {
+        return Ok(None);
+    }
+
+    let single_key = <A::Entity as EntityTrait>::PrimaryKey::iter().count() == 1;
+    let mut keys = Vec::with_capacity(models.len());
+    let mut filter = Condition::any();
+    for model in &models {
+        let mut key = Condition::all();
+        for pk in <A::Entity as EntityTrait>::PrimaryKey::iter() {
+            let col = pk.into_column();
+            match model.get(col) {
+                ActiveValue::Set(value) | ActiveValue::Unchanged(value) => {
+                    if single_key {
+                        keys.push(value.clone());
+                    }
+                    key = key.add(col.eq(value));
+                }
+                ActiveValue::NotSet | ActiveValue::SetDefault => {
+                    return Err(DbErr::PrimaryKeyColumnNotSet {
+                        ctx: "UpdateManyModels",
+                        column: col.as_str(),
+                    });
+                }
+            }
+        }
+        for (col, case) in <A::Entity as EntityTrait>::Column::iter().zip(cases.iter_mut()) {
+            match (case.take(), model.get(col)) {
+                (Some(then), ActiveValue::Set(value)) => {
+                    *case = Some(then.case(
+                        key.clone(),
+                        col.save_as(Expr::val(col.encrypt_value(value))),
+                    ));
+                }
+                (None, ActiveValue::Set(_)) if !is_value_column(&col) => {}
+                (None, ActiveValue::Unchanged(_) | ActiveValue::NotSet) => {}
+                (_, ActiveValue::SetDefault) => {
+                    return Err(set_default_not_supported("UpdateManyModels", col.as_str()));
+                }
+                _ => {
+                    return Err(DbErr::Custom(format!(
+                        "Column `{}` must be set on every model or none of them in UpdateManyModels",
+                        col.as_str()
+                    )));
+                }
+            }
+        }
+        if !single_key {
+            filter = filter.add(key);
+        }
+    }
+
+    let mut update = Update::many(A::Entity::default());
+    for (col, case) in <A::Entity as EntityTrait>::Column::iter().zip(cases) {
+        if let Some(case) = case {
+            update.query.value(col, case);
+        }
+    }
+    // `pk IN (..)` rather than a long chain of `OR`s when the key is a single column
+    if let (true, Some(pk)) = (
+        single_key,
+        <A::Entity as EntityTrait>::PrimaryKey::iter().next(),
+    ) {
+        filter = filter.add(pk.into_column().is_in(keys));
+    }
+    Ok(Some(update.filter(filter)))
+}
+
+fn set_default_not_supported(ctx: &str, column: &str) -> DbErr {
+    DbErr::Custom(format!(
+        "Column `{column}` is SetDefault in {ctx}, but `DEFAULT` is only supported in INSERT"
+    ))
 }
 
 impl<A> QueryFilter for ValidatedUpdateOne<A>
@@ -210,22 +422,66 @@ where
     E: EntityTrait,
 {
     /// Add the models to update to Self
+    ///
+    /// Only the `Set` columns are updated. [`ActiveValue::SetDefault`] is only supported
+    /// in `INSERT`, executing the update fails if the model has such a column.
     pub fn set<A>(mut self, model: A) -> Self
     where
         A: ActiveModelTrait<Entity = E>,
     {
         for col in E::Column::iter() {
+            if col.def().is_generated() {
+                continue;
+            }
             match model.get(col) {
                 ActiveValue::Set(value) => {
-                    let expr = col.save_as(Expr::val(value));
+                    let expr = col.save_as(Expr::val(col.encrypt_value(value)));
                     self.query.value(col, expr);
                 }
+                ActiveValue::SetDefault => {
+                    if self.set_default_check.is_ok() {
+                        self.set_default_check =
+                            Err(set_default_not_supported("UpdateMany", col.as_str()));
+                    }
+                }
                 ActiveValue::Unchanged(_) | ActiveValue::NotSet => {}
             }
         }
         self
     }
 
+    /// Update the rows of `table` instead of the entity's own table, e.g. a shard of it
+    /// chosen at runtime. The table is aliased as the entity's table name, so the
+    /// columns and filters of the entity keep working.
+    ///
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, sea_query::{Alias, Expr}, tests_cfg::fruit};
+    ///
+    /// assert_eq!(
+    ///     Update::many(fruit::Entity)
+    ///         .into_table(Alias::new("fruit_2024"))
+    ///         .col_expr(fruit::Column::Name, Expr::value("Golden Apple"))
+    ///         .filter(fruit::Column::Name.contains("Apple"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"UPDATE "fruit_2024" AS "fruit" SET "name" = 'Golden Apple' WHERE "fruit"."name" LIKE '%Apple%'"#,
+    /// );
+    /// ```
+    pub fn into_table<T>(mut self, table: T) -> Self
+    where
+        T: IntoTableRef,
+    {
+        self.query
+            .table(table_as_entity::<E>(table.into_table_ref()));
+        self
+    }
+
+    /// Update the rows of the table of `entity`, for entities with a table name set at
+    /// runtime. See [`into_table`](Self::into_table).
+    pub fn for_entity_instance(self, entity: E) -> Self {
+        self.into_table(entity.table_ref())
+    }
+
     /// Creates a [SimpleExpr] from a column
     pub fn col_expr<T>(mut self, col: T, expr: SimpleExpr) -> Self
     where
@@ -234,12 +490,92 @@ where
         self.query.value(col, expr);
         self
     }
+
+    /// Increment a column by the given value, shorthand for
+    /// `.col_expr(col, col.increment(v))`
+    /// ```
+    /// use sea_orm::{DbBackend, entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let query = fruit::Entity::update_many()
+    ///     .col_increment(fruit::Column::CakeId, 1)
+    ///     .filter(fruit::Column::CakeId.eq(2));
+    ///
+    /// assert_eq!(
+    ///     query.build(DbBackend::Postgres).to_string(),
+    ///     r#"UPDATE "fruit" SET "cake_id" = "cake_id" + 1 WHERE "fruit"."cake_id" = 2"#
+    /// );
+    /// assert_eq!(
+    ///     query.build(DbBackend::MySql).to_string(),
+    ///     "UPDATE `fruit` SET `cake_id` = `cake_id` + 1 WHERE `fruit`.`cake_id` = 2"
+    /// );
+    /// ```
+    pub fn col_increment<V>(self, col: E::Column, v: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        self.col_expr(col, col.increment(v))
+    }
+
+    /// Update from another table or subquery, joined on `condition`.
+    /// Columns of the source can be referenced in [`col_expr`](Self::col_expr)
+    /// with [`ColumnTrait::of_table`].
+    ///
+    /// Rendered as `UPDATE .. SET .. FROM .. WHERE ..` on Postgres and SQLite (3.33+),
+    /// and as `UPDATE .. JOIN .. ON .. SET ..` on MySQL. On MySQL only one source
+    /// is supported, and the join condition and filters all go into the `ON` clause.
+    /// ```
+    /// use sea_orm::{
+    ///     DbBackend, entity::*, query::*,
+    ///     sea_query::{IntoIden, TableRef},
+    ///     tests_cfg::{cake, fruit},
+    /// };
+    ///
+    /// let cakes = cake::Entity::find()
+    ///     .filter(cake::Column::Name.contains("cheese"))
+    ///     .into_query();
+    ///
+    /// let query = fruit::Entity::update_many()
+    ///     .col_expr(fruit::Column::CakeId, cake::Column::Id.of_table("c"))
+    ///     .join_from(
+    ///         TableRef::SubQuery(Box::new(cakes), "c".into_iden()),
+    ///         fruit::Column::Name.into_expr().eq(cake::Column::Name.of_table("c")),
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     query.build(DbBackend::Postgres).to_string(),
+    ///     [
+    ///         r#"UPDATE "fruit" SET "cake_id" = "c"."id""#,
+    ///         r#"FROM (SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" LIKE '%cheese%') AS "c""#,
+    ///         r#"WHERE "fruit"."name" = "c"."name""#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// assert_eq!(
+    ///     query.build(DbBackend::MySql).to_string(),
+    ///     [
+    ///         "UPDATE `fruit`",
+    ///         "JOIN (SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '%cheese%') AS `c`",
+    ///         "ON `fruit`.`name` = `c`.`name`",
+    ///         "SET `fruit`.`cake_id` = `c`.`id`",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn join_from<R, C>(mut self, source: R, condition: C) -> Self
+    where
+        R: IntoTableRef,
+        C: IntoCondition,
+    {
+        self.query.from(source);
+        self.query.cond_where(condition);
+        self
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::tests_cfg::{cake, fruit, lunch_set, sea_orm_active_enums::Tea};
-    use crate::{DbBackend, entity::*, query::*};
+    use crate::{DbBackend, DbErr, entity::*, query::*};
     use sea_query::{Expr, Value};
 
     #[test]
@@ -362,4 +698,219 @@ mod tests {
             r#"UPDATE "lunch_set" SET "tea" = CAST('EverydayTea' AS "tea") WHERE "lunch_set"."id" = 1"#,
         );
     }
+
+    #[test]
+    fn update_many_join_from_subquery() {
+        use sea_query::{IntoIden, TableRef};
+
+        let cakes = cake::Entity::find()
+            .filter(cake::Column::Name.contains("cheese"))
+            .into_query();
+        let query = fruit::Entity::update_many()
+            .col_expr(fruit::Column::CakeId, cake::Column::Id.of_table("c"))
+            .join_from(
+                TableRef::SubQuery(Box::new(cakes), "c".into_iden()),
+                fruit::Column::Name
+                    .into_expr()
+                    .eq(cake::Column::Name.of_table("c")),
+            )
+            .filter(fruit::Column::CakeId.is_null());
+
+        assert_eq!(
+            query.build(DbBackend::Postgres).to_string(),
+            [
+                r#"UPDATE "fruit" SET "cake_id" = "c"."id""#,
+                r#"FROM (SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" LIKE '%cheese%') AS "c""#,
+                r#"WHERE "fruit"."name" = "c"."name" AND "fruit"."cake_id" IS NULL"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            query.build(DbBackend::Sqlite).to_string(),
+            [
+                r#"UPDATE "fruit" SET "cake_id" = "c"."id""#,
+                r#"FROM (SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" LIKE '%cheese%') AS "c""#,
+                r#"WHERE "fruit"."name" = "c"."name" AND "fruit"."cake_id" IS NULL"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            query.build(DbBackend::MySql).to_string(),
+            [
+                "UPDATE `fruit`",
+                "JOIN (SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '%cheese%') AS `c`",
+                "ON `fruit`.`name` = `c`.`name` AND `fruit`.`cake_id` IS NULL",
+                "SET `fruit`.`cake_id` = `c`.`id`",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn update_many_join_from_entity() {
+        let query = fruit::Entity::update_many()
+            .col_expr(
+                fruit::Column::Name,
+                Expr::col((cake::Entity, cake::Column::Name)),
+            )
+            .join_from(
+                cake::Entity,
+                fruit::Column::CakeId
+                    .into_expr()
+                    .eq(Expr::col((cake::Entity, cake::Column::Id))),
+            );
+
+        assert_eq!(
+            query.build(DbBackend::Postgres).to_string(),
+            r#"UPDATE "fruit" SET "name" = "cake"."name" FROM "cake" WHERE "fruit"."cake_id" = "cake"."id""#,
+        );
+        assert_eq!(
+            query.build(DbBackend::Sqlite).to_string(),
+            r#"UPDATE "fruit" SET "name" = "cake"."name" FROM "cake" WHERE "fruit"."cake_id" = "cake"."id""#,
+        );
+        assert_eq!(
+            query.build(DbBackend::MySql).to_string(),
+            "UPDATE `fruit` JOIN `cake` ON `fruit`.`cake_id` = `cake`.`id` SET `fruit`.`name` = `cake`.`name`",
+        );
+    }
+
+    #[test]
+    fn update_many_models() -> Result<(), DbErr> {
+        let update = Update::many_models([
+            fruit::ActiveModel {
+                id: ActiveValue::unchanged(1),
+                name: ActiveValue::set("Apple".to_owned()),
+                cake_id: ActiveValue::set(Some(2)),
+            },
+            fruit::ActiveModel {
+                id: ActiveValue::set(2),
+                name: ActiveValue::set("Orange".to_owned()),
+                cake_id: ActiveValue::set(None),
+            },
+        ])
+        .validate()?
+        .expect("has columns to update");
+
+        assert_eq!(
+            update.build(DbBackend::Postgres).to_string(),
+            [
+                r#"UPDATE "fruit" SET"#,
+                r#""name" = (CASE WHEN ("fruit"."id" = 1) THEN 'Apple' WHEN ("fruit"."id" = 2) THEN 'Orange' END),"#,
+                r#""cake_id" = (CASE WHEN ("fruit"."id" = 1) THEN 2 WHEN ("fruit"."id" = 2) THEN NULL END)"#,
+                r#"WHERE "fruit"."id" IN (1, 2)"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            update.build(DbBackend::MySql).to_string(),
+            [
+                "UPDATE `fruit` SET",
+                "`name` = (CASE WHEN (`fruit`.`id` = 1) THEN 'Apple' WHEN (`fruit`.`id` = 2) THEN 'Orange' END),",
+                "`cake_id` = (CASE WHEN (`fruit`.`id` = 1) THEN 2 WHEN (`fruit`.`id` = 2) THEN NULL END)",
+                "WHERE `fruit`.`id` IN (1, 2)",
+            ]
+            .join(" ")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn update_many_models_composite_key() -> Result<(), DbErr> {
+        use crate::tests_cfg::cake_filling_price;
+
+        let update = Update::many_models([
+            cake_filling_price::ActiveModel {
+                cake_id: ActiveValue::unchanged(1),
+                filling_id: ActiveValue::unchanged(2),
+                price: ActiveValue::set(3.into()),
+            },
+            cake_filling_price::ActiveModel {
+                cake_id: ActiveValue::unchanged(4),
+                filling_id: ActiveValue::unchanged(5),
+                price: ActiveValue::set(6.into()),
+            },
+        ])
+        .validate()?
+        .expect("has columns to update");
+
+        assert_eq!(
+            update.build(DbBackend::Postgres).to_string(),
+            [
+                r#"UPDATE "public"."cake_filling_price" SET "price" = (CASE"#,
+                r#"WHEN ("cake_filling_price"."cake_id" = 1 AND "cake_filling_price"."filling_id" = 2) THEN 3"#,
+                r#"WHEN ("cake_filling_price"."cake_id" = 4 AND "cake_filling_price"."filling_id" = 5) THEN 6 END)"#,
+                r#"WHERE ("cake_filling_price"."cake_id" = 1 AND "cake_filling_price"."filling_id" = 2)"#,
+                r#"OR ("cake_filling_price"."cake_id" = 4 AND "cake_filling_price"."filling_id" = 5)"#,
+            ]
+            .join(" ")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn update_many_models_invalid() {
+        let no_pk = Update::many_models([fruit::ActiveModel {
+            name: ActiveValue::set("Apple".to_owned()),
+            ..Default::default()
+        }]);
+        assert!(matches!(
+            no_pk.validate(),
+            Err(DbErr::PrimaryKeyColumnNotSet {
+                ctx: "UpdateManyModels",
+                column: "id",
+            })
+        ));
+
+        let mixed = Update::many_models([
+            fruit::ActiveModel {
+                id: ActiveValue::unchanged(1),
+                name: ActiveValue::set("Apple".to_owned()),
+                cake_id: ActiveValue::not_set(),
+            },
+            fruit::ActiveModel {
+                id: ActiveValue::unchanged(2),
+                name: ActiveValue::set("Orange".to_owned()),
+                cake_id: ActiveValue::set(Some(3)),
+            },
+        ]);
+        assert_eq!(
+            mixed.validate().map(|_| ()),
+            Err(DbErr::Custom(
+                "Column `cake_id` must be set on every model or none of them in UpdateManyModels"
+                    .to_owned()
+            ))
+        );
+
+        assert!(matches!(
+            Update::many_models(Vec::<fruit::ActiveModel>::new()).validate(),
+            Ok(None)
+        ));
+    }
+
+    #[test]
+    fn update_set_default() {
+        assert_eq!(
+            Update::one(cake::ActiveModel {
+                id: ActiveValue::unchanged(1),
+                name: ActiveValue::set_default(),
+            })
+            .validate()
+            .map(|_| ()),
+            Err(DbErr::Custom(
+                "Column `name` is SetDefault in UpdateOne, but `DEFAULT` is only supported in INSERT"
+                    .to_owned()
+            ))
+        );
+
+        assert!(matches!(
+            Update::one(cake::ActiveModel {
+                id: ActiveValue::set_default(),
+                name: ActiveValue::set("Apple Pie".to_owned()),
+            })
+            .validate(),
+            Err(DbErr::PrimaryKeyNotSet { ctx: "UpdateOne" })
+        ));
+    }
 }
@@ -1,6 +1,7 @@
 use crate::{
     ActiveEnum, ColumnTrait, ColumnType, DbBackend, EntityTrait, IdenStatic, Iterable,
     PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait, RelationTrait, Schema,
+    entity::column::split_enum_name,
 };
 use sea_query::{
     ColumnDef, DynIden, Iden, Index, IndexCreateStatement, SeaRc, TableCreateStatement, TableName,
@@ -50,6 +51,10 @@ impl Schema {
 
     /// Creates the indexes from an Entity, returning an empty Vec if there are none
     /// to create. See [IndexCreateStatement] for more details
+    ///
+    /// The indexes come from columns marked `#[sea_orm(indexed)]`, from columns sharing a
+    /// `#[sea_orm(unique_key = "..")]`, and from the struct-level `#[sea_orm(index(..))]`
+    /// attributes listed by [`EntityTrait::indexes`].
     pub fn create_index_from_entity<E>(&self, entity: E) -> Vec<IndexCreateStatement>
     where
         E: EntityTrait,
@@ -115,7 +120,13 @@ pub(crate) fn create_enum_from_column_type(col_type: &ColumnType) -> Option<Type
         ColumnType::Enum { name, variants } => (name.clone(), variants.clone()),
         _ => return None,
     };
-    Some(Type::create().as_enum(name).values(values).to_owned())
+    let name = name.to_string();
+    let mut stmt = Type::create();
+    match split_enum_name(&name) {
+        (Some(schema), name) => stmt.as_enum((schema.to_owned(), name.to_owned())),
+        (None, name) => stmt.as_enum(name.to_owned()),
+    };
+    Some(stmt.values(values).to_owned())
 }
 
 #[allow(clippy::needless_borrow)]
@@ -179,6 +190,20 @@ where
         indexes.push(stmt);
     }
 
+    for index in E::indexes() {
+        let mut stmt = Index::create()
+            .name(index.name)
+            .table(index_table_ref(entity.table_ref(), backend))
+            .take();
+        if index.unique {
+            stmt.unique();
+        }
+        for col in index.columns {
+            stmt.col(col);
+        }
+        indexes.push(stmt);
+    }
+
     indexes
 }
 
@@ -299,7 +324,11 @@ mod tests {
         use crate::entity::prelude::*;
 
         #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
-        #[sea_orm(schema_name = "sys", table_name = "app_user")]
+        #[sea_orm(
+            schema_name = "sys",
+            table_name = "app_user",
+            index(columns("tenant_id", "email"), unique)
+        )]
         pub struct Model {
             #[sea_orm(primary_key)]
             pub id: i32,
@@ -317,6 +346,54 @@ mod tests {
         impl ActiveModelBehavior for ActiveModel {}
     }
 
+    mod custom_types {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "collection")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            #[sea_orm(
+                column_type = "custom(\"citext\")",
+                select_as = "text",
+                save_as = "citext"
+            )]
+            pub name: String,
+            #[sea_orm(
+                column_type = "custom(\"ltree\")",
+                select_as = "text",
+                save_as = "ltree",
+                nullable
+            )]
+            pub path: Option<String>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    fn test_create_table_from_entity_custom_type() {
+        let schema = Schema::new(DbBackend::Postgres);
+        assert_eq!(
+            DbBackend::Postgres
+                .build(&schema.create_table_from_entity(custom_types::Entity))
+                .to_string(),
+            [
+                r#"CREATE TABLE "collection" ("#,
+                r#""id" integer GENERATED BY DEFAULT AS IDENTITY NOT NULL PRIMARY KEY,"#,
+                r#""name" citext NOT NULL,"#,
+                r#""path" ltree"#,
+                r#")"#,
+            ]
+            .join(" ")
+        );
+    }
+
     #[test]
     fn test_create_table_from_entity_table_ref() {
         for builder in [DbBackend::MySql, DbBackend::Postgres, DbBackend::Sqlite] {
@@ -385,7 +462,7 @@ mod tests {
             );
 
             let stmts = schema.create_index_from_entity(indexes::Entity);
-            assert_eq!(stmts.len(), 2);
+            assert_eq!(stmts.len(), 3);
 
             let index_table = match builder {
                 DbBackend::Postgres => indexes::Entity.table_ref(),
@@ -410,6 +487,18 @@ mod tests {
                 .unique()
                 .take();
             assert_eq!(builder.build(&stmts[1]), builder.build(&idx));
+
+            let index_table = match builder {
+                DbBackend::Postgres => indexes::Entity.table_ref(),
+                DbBackend::MySql | DbBackend::Sqlite => indexes::Entity.into_table_ref(),
+            };
+            let idx: IndexCreateStatement = Index::create()
+                .name("idx_index1_index2")
+                .table(index_table)
+                .col(indexes::Column::Index1Attr)
+                .col(indexes::Column::Index2Attr)
+                .take();
+            assert_eq!(builder.build(&stmts[2]), builder.build(&idx));
         }
     }
 
@@ -418,7 +507,7 @@ mod tests {
         let builder = DbBackend::Postgres;
         let schema = Schema::new(builder);
         let stmts = schema.create_index_from_entity(custom_schema_indexes::Entity);
-        assert_eq!(stmts.len(), 2);
+        assert_eq!(stmts.len(), 3);
 
         let idx: IndexCreateStatement = Index::create()
             .name("idx-app_user-email")
@@ -436,6 +525,15 @@ mod tests {
             .take();
         assert_eq!(builder.build(&stmts[1]), builder.build(&idx));
 
+        let idx: IndexCreateStatement = Index::create()
+            .name("idx-app_user-tenant_id-email")
+            .table(custom_schema_indexes::Entity.table_ref())
+            .col(custom_schema_indexes::Column::TenantId)
+            .col(custom_schema_indexes::Column::Email)
+            .unique()
+            .take();
+        assert_eq!(builder.build(&stmts[2]), builder.build(&idx));
+
         // The generated DDL targets the schema-qualified table.
         assert!(builder.build(&stmts[0]).sql.contains(r#""sys"."app_user""#));
     }
@@ -450,7 +548,7 @@ mod tests {
             let schema = Schema::new(builder);
             // Must not panic for a `schema_name` entity on MySQL/SQLite.
             let stmts = schema.create_index_from_entity(custom_schema_indexes::Entity);
-            assert_eq!(stmts.len(), 2);
+            assert_eq!(stmts.len(), 3);
 
             for stmt in &stmts {
                 let sql = builder.build(stmt).sql;
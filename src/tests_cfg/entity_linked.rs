@@ -58,6 +58,29 @@ impl Linked for CheeseCakeToFillingVendor {
     }
 }
 
+#[derive(Debug)]
+pub struct CakeToCreamFillingVendor;
+
+impl Linked for CakeToCreamFillingVendor {
+    type FromEntity = super::cake::Entity;
+
+    type ToEntity = super::vendor::Entity;
+
+    fn link(&self) -> Vec<RelationDef> {
+        vec![
+            super::cake_filling::Relation::Cake.def().rev(),
+            super::cake_filling::Relation::Filling
+                .def()
+                .on_condition(|_left, right| {
+                    Expr::col((right, super::filling::Column::Name))
+                        .like("%cream%")
+                        .into_condition()
+                }),
+            super::filling::Relation::Vendor.def(),
+        ]
+    }
+}
+
 #[derive(Debug)]
 pub struct CakeToCakeViaFilling;
 
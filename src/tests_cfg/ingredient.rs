@@ -14,7 +14,7 @@ pub struct Model {
     pub name: String,
     pub filling_id: Option<i32>,
     pub ingredient_id: Option<i32>,
-    #[sea_orm(belongs_to, from = "filling_id", to = "id")]
+    #[sea_orm(belongs_to, from = "filling_id", to = "id", on_delete = "SetNull")]
     pub filling: HasOne<super::filling::Entity>,
     #[sea_orm(
         self_ref,
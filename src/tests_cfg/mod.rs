@@ -15,12 +15,15 @@ pub mod filling;
 pub mod fruit;
 pub mod indexes;
 pub mod ingredient;
+pub mod invoice;
 pub mod lunch_set;
 pub mod lunch_set_expanded;
 pub mod rust_keyword;
 pub mod sea_orm_active_enums;
 #[cfg(feature = "with-json")]
 pub mod serde_rename;
+pub mod store_product;
+pub mod store_product_tag;
 pub mod vendor;
 
 pub mod comment;
@@ -0,0 +1,101 @@
+use crate::DbErr;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+/// Fallible conversion of floating point numbers into a [`Decimal`].
+///
+/// Unlike `Decimal::from_f64`, which returns `None` and leaves the caller to
+/// decide what that means, this reports `NaN`, infinite and out of range inputs
+/// as [`DbErr::Type`], so it composes with the rest of the `?` chain.
+///
+/// ```
+/// use sea_orm::{DbErr, prelude::{Decimal, TryIntoDecimal}};
+///
+/// assert_eq!(0.25f64.try_into_decimal()?, Decimal::new(25, 2));
+/// assert_eq!(1.23456f64.try_into_decimal_rounded(2)?, Decimal::new(123, 2));
+/// assert!(matches!(f64::NAN.try_into_decimal(), Err(DbErr::Type(_))));
+/// # Ok::<(), DbErr>(())
+/// ```
+pub trait TryIntoDecimal {
+    /// Convert into a [`Decimal`], keeping as many digits as the float carries.
+    fn try_into_decimal(self) -> Result<Decimal, DbErr>;
+
+    /// Convert into a [`Decimal`] rounded to `dp` decimal places.
+    ///
+    /// Midpoints are rounded away from zero (`2.5` becomes `3`, `-2.5` becomes `-3`),
+    /// the way amounts are usually rounded by hand, rather than to the nearest even digit.
+    fn try_into_decimal_rounded(self, dp: u32) -> Result<Decimal, DbErr>
+    where
+        Self: Sized,
+    {
+        Ok(self
+            .try_into_decimal()?
+            .round_dp_with_strategy(dp, RoundingStrategy::MidpointAwayFromZero))
+    }
+}
+
+impl TryIntoDecimal for f64 {
+    fn try_into_decimal(self) -> Result<Decimal, DbErr> {
+        if !self.is_finite() {
+            return Err(DbErr::Type(format!(
+                "Failed to convert {self} to Decimal: value is not finite"
+            )));
+        }
+        Decimal::try_from(self)
+            .map_err(|e| DbErr::Type(format!("Failed to convert {self} to Decimal: {e}")))
+    }
+}
+
+impl TryIntoDecimal for f32 {
+    fn try_into_decimal(self) -> Result<Decimal, DbErr> {
+        if !self.is_finite() {
+            return Err(DbErr::Type(format!(
+                "Failed to convert {self} to Decimal: value is not finite"
+            )));
+        }
+        Decimal::try_from(self)
+            .map_err(|e| DbErr::Type(format!("Failed to convert {self} to Decimal: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_into_decimal_not_finite() {
+        for v in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(matches!(v.try_into_decimal(), Err(DbErr::Type(_))));
+            assert!(matches!(v.try_into_decimal_rounded(2), Err(DbErr::Type(_))));
+        }
+        assert!(matches!(f32::NAN.try_into_decimal(), Err(DbErr::Type(_))));
+        assert!(matches!(f64::MAX.try_into_decimal(), Err(DbErr::Type(_))));
+    }
+
+    #[test]
+    fn test_try_into_decimal_rounded() {
+        assert_eq!(
+            1.23456f64.try_into_decimal_rounded(2).unwrap(),
+            Decimal::new(123, 2)
+        );
+        assert_eq!(
+            1.23456f64.try_into_decimal_rounded(4).unwrap(),
+            Decimal::new(12346, 4)
+        );
+        assert_eq!(
+            2.5f64.try_into_decimal_rounded(0).unwrap(),
+            Decimal::new(3, 0)
+        );
+        assert_eq!(
+            (-2.5f64).try_into_decimal_rounded(0).unwrap(),
+            Decimal::new(-3, 0)
+        );
+        assert_eq!(
+            (0.1f64 + 0.2).try_into_decimal_rounded(2).unwrap(),
+            Decimal::new(30, 2)
+        );
+        assert_eq!(
+            0.5f32.try_into_decimal_rounded(0).unwrap(),
+            Decimal::new(1, 0)
+        );
+    }
+}
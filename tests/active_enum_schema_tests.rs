@@ -0,0 +1,148 @@
+#![cfg(all(feature = "sqlx-postgres", feature = "postgres-array"))]
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+use crate::common::TestContext;
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    DatabaseBackend, DbErr, DeriveActiveEnum, EnumIter, Schema, Statement,
+    entity::*,
+    query::*,
+    sea_query::{PostgresQueryBuilder, Query},
+};
+
+// A native enum living in a non-default PostgreSQL schema.
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(
+    db_type = "Enum",
+    schema_name = "billing",
+    enum_name = "invoice_status"
+)]
+pub enum InvoiceStatus {
+    #[sea_orm(string_value = "draft")]
+    Draft,
+    #[sea_orm(string_value = "paid")]
+    Paid,
+    #[sea_orm(string_value = "void")]
+    Void,
+}
+
+mod invoice {
+    use super::InvoiceStatus;
+    use sea_orm::entity::prelude::*;
+
+    #[sea_orm::model]
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(schema_name = "billing", table_name = "invoice")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub status: InvoiceStatus,
+        pub history: Vec<InvoiceStatus>,
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[test]
+fn active_enum_schema_statements() {
+    let backend = DatabaseBackend::Postgres;
+
+    assert_eq!(
+        backend.build(
+            &Schema::new(backend)
+                .create_enum_from_active_enum::<InvoiceStatus>()
+                .unwrap()
+        ),
+        Statement::from_string(
+            backend,
+            r#"CREATE TYPE "billing"."invoice_status" AS ENUM ('draft', 'paid', 'void')"#
+        )
+    );
+
+    assert_eq!(
+        invoice::Entity::find()
+            .select_only()
+            .column(invoice::Column::Id)
+            .filter(invoice::Column::Status.eq(InvoiceStatus::Paid))
+            .filter(invoice::Column::History.eq(vec![InvoiceStatus::Draft]))
+            .build(backend)
+            .to_string(),
+        [
+            r#"SELECT "invoice"."id" FROM "billing"."invoice""#,
+            r#"WHERE "invoice"."status" = (CAST('paid' AS "billing"."invoice_status"))"#,
+            r#"AND "invoice"."history" = (CAST(ARRAY ['draft'] AS "billing"."invoice_status"[]))"#,
+        ]
+        .join(" ")
+    );
+
+    assert_eq!(
+        Query::select()
+            .expr(ActiveEnum::as_enum(&InvoiceStatus::Void))
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT CAST('void' AS "billing"."invoice_status")"#
+    );
+}
+
+#[sea_orm_macros::test]
+async fn active_enum_schema_round_trip() -> Result<(), DbErr> {
+    let ctx = TestContext::new("active_enum_schema_round_trip").await;
+    let db = &ctx.db;
+
+    db.execute_raw(Statement::from_string(
+        DatabaseBackend::Postgres,
+        "CREATE SCHEMA IF NOT EXISTS billing".to_owned(),
+    ))
+    .await?;
+
+    // creates `billing.invoice_status` before the table using it
+    db.get_schema_builder()
+        .register(invoice::Entity)
+        .apply(db)
+        .await?;
+
+    let model = invoice::ActiveModel {
+        id: Set(1),
+        status: Set(InvoiceStatus::Draft),
+        history: Set(vec![InvoiceStatus::Draft]),
+    }
+    .insert(db)
+    .await?;
+
+    assert_eq!(
+        model,
+        invoice::Model {
+            id: 1,
+            status: InvoiceStatus::Draft,
+            history: vec![InvoiceStatus::Draft],
+        }
+    );
+
+    let model = invoice::ActiveModel {
+        status: Set(InvoiceStatus::Paid),
+        history: Set(vec![InvoiceStatus::Draft, InvoiceStatus::Paid]),
+        ..model.into_active_model()
+    }
+    .update(db)
+    .await?;
+
+    assert_eq!(
+        invoice::Entity::find()
+            .filter(invoice::Column::Status.eq(InvoiceStatus::Paid))
+            .one(db)
+            .await?,
+        Some(model)
+    );
+    assert_eq!(
+        invoice::Entity::find()
+            .filter(invoice::Column::Status.eq(InvoiceStatus::Void))
+            .one(db)
+            .await?,
+        None
+    );
+
+    ctx.delete().await;
+
+    Ok(())
+}
@@ -21,6 +21,50 @@ async fn main() -> Result<(), DbErr> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "postgres-array")]
+fn into_active_model_enum_array() {
+    #[derive(DeriveIntoActiveModel)]
+    #[sea_orm(active_model = "collection::ActiveModel")]
+    struct NewCollectionTeas {
+        teas: Vec<Tea>,
+        teas_opt: Option<Vec<Tea>>,
+    }
+
+    assert_eq!(
+        NewCollectionTeas {
+            teas: vec![Tea::EverydayTea, Tea::BreakfastTea],
+            teas_opt: Some(vec![Tea::BreakfastTea]),
+        }
+        .into_active_model(),
+        collection::ActiveModel {
+            teas: Set(vec![Tea::EverydayTea, Tea::BreakfastTea]),
+            teas_opt: Set(Some(vec![Tea::BreakfastTea])),
+            ..Default::default()
+        }
+    );
+
+    #[derive(DeriveIntoActiveModel)]
+    #[sea_orm(active_model = "collection::ActiveModel")]
+    struct UpdateCollectionTeas {
+        teas: Option<Vec<Tea>>,
+        teas_opt: Option<Option<Vec<Tea>>>,
+    }
+
+    assert_eq!(
+        UpdateCollectionTeas {
+            teas: None,
+            teas_opt: Some(None),
+        }
+        .into_active_model(),
+        collection::ActiveModel {
+            teas: NotSet,
+            teas_opt: Set(None),
+            ..Default::default()
+        }
+    );
+}
+
 pub async fn insert_collection(db: &DatabaseConnection) -> Result<(), DbErr> {
     use collection::*;
 
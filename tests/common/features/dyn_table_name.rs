@@ -10,7 +10,7 @@ impl EntityName for Entity {
         match self.table_name {
             1 => "dyn_table_1",
             2 => "dyn_table_2",
-            _ => "",
+            _ => "dyn_table",
         }
     }
 }
@@ -4,6 +4,7 @@ pub mod active_enum_vec;
 pub mod applog;
 pub mod binary;
 pub mod bits;
+pub mod byte_array_primary_key;
 pub mod byte_primary_key;
 pub mod categories;
 pub mod collection;
@@ -14,13 +15,14 @@ pub mod edit_log;
 #[cfg(feature = "postgres-vector")]
 pub mod embedding;
 pub mod event_trigger;
-#[cfg(feature = "with-ipnetwork")]
+#[cfg(all(feature = "with-ipnetwork", feature = "with-mac_address"))]
 pub mod host_network;
 pub mod insert_default;
 pub mod json_struct;
 pub mod json_vec;
 pub mod json_vec_derive;
 pub mod metadata;
+pub mod money;
 #[cfg(feature = "with-bigdecimal")]
 pub mod pi;
 pub mod repository;
@@ -39,6 +41,7 @@ pub use active_enum_vec::Entity as ActiveEnumVec;
 pub use applog::Entity as Applog;
 pub use binary::Entity as Binary;
 pub use bits::Entity as Bits;
+pub use byte_array_primary_key::Entity as ByteArrayPrimaryKey;
 pub use byte_primary_key::Entity as BytePrimaryKey;
 pub use categories::Entity as Categories;
 pub use collection::Entity as Collection;
@@ -53,7 +56,9 @@ pub use json_struct::Entity as JsonStruct;
 pub use json_vec::Entity as JsonVec;
 pub use json_vec_derive::json_string_vec::Entity as JsonStringVec;
 pub use json_vec_derive::json_struct_vec::Entity as JsonStructVec;
+pub use json_vec_derive::json_struct_vec_nested::Entity as JsonStructVecNested;
 pub use metadata::Entity as Metadata;
+pub use money::Entity as Money;
 pub use repository::Entity as Repository;
 pub use satellite::Entity as Satellite;
 pub use schema::*;
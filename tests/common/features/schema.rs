@@ -170,6 +170,25 @@ pub async fn create_byte_primary_key_table(db: &DbConn) -> Result<ExecResult, Db
     create_table_without_asserts(db, &stmt).await
 }
 
+pub async fn create_byte_array_primary_key_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let stmt = sea_query::Table::create()
+        .table(byte_array_primary_key::Entity)
+        .col(
+            ColumnDef::new(byte_array_primary_key::Column::Id)
+                .binary_len(4)
+                .not_null()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(byte_array_primary_key::Column::Value)
+                .string()
+                .not_null(),
+        )
+        .to_owned();
+
+    create_table(db, &stmt, ByteArrayPrimaryKey).await
+}
+
 pub async fn create_active_enum_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     let create_table_stmt = sea_query::Table::create()
         .table(active_enum::Entity.table_ref())
@@ -394,6 +413,22 @@ pub async fn create_json_struct_vec_table(db: &DbConn) -> Result<ExecResult, DbE
     create_table(db, &create_table_stmt, JsonStructVec).await
 }
 
+pub async fn create_json_struct_vec_nested_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let create_table_stmt = sea_query::Table::create()
+        .table(JsonStructVecNested.table_ref())
+        .col(
+            ColumnDef::new(json_vec_derive::json_struct_vec_nested::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(json_vec_derive::json_struct_vec_nested::Column::OptVec).json_binary())
+        .to_owned();
+
+    create_table(db, &create_table_stmt, JsonStructVecNested).await
+}
+
 pub async fn create_collection_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     db.execute_raw(sea_orm::Statement::from_string(
         db.get_database_backend(),
@@ -464,7 +499,7 @@ pub async fn create_collection_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     create_table(db, &stmt, Collection).await
 }
 
-#[cfg(feature = "with-ipnetwork")]
+#[cfg(all(feature = "with-ipnetwork", feature = "with-mac_address"))]
 pub async fn create_host_network_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     let stmt = sea_query::Table::create()
         .table(host_network::Entity)
@@ -490,6 +525,17 @@ pub async fn create_host_network_table(db: &DbConn) -> Result<ExecResult, DbErr>
                 .cidr()
                 .not_null(),
         )
+        .col(
+            ColumnDef::new(host_network::Column::Address)
+                .inet()
+                .not_null(),
+        )
+        .col(ColumnDef::new(host_network::Column::Gateway).inet())
+        .col(
+            ColumnDef::new(host_network::Column::MacAddress)
+                .mac_address()
+                .not_null(),
+        )
         .to_owned();
 
     create_table(db, &stmt, host_network::Entity).await
@@ -523,6 +569,27 @@ pub async fn create_pi_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     create_table(db, &stmt, pi::Entity).await
 }
 
+pub async fn create_money_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let stmt = sea_query::Table::create()
+        .table(money::Entity)
+        .col(
+            ColumnDef::new(money::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(money::Column::Amount)
+                .decimal_len(12, 2)
+                .not_null(),
+        )
+        .col(ColumnDef::new(money::Column::Refund).decimal_len(12, 2))
+        .to_owned();
+
+    create_table(db, &stmt, money::Entity).await
+}
+
 pub async fn create_event_trigger_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     let stmt = sea_query::Table::create()
         .table(event_trigger::Entity)
@@ -99,6 +99,533 @@ pub async fn connection_ping_closed_sqlite() {
     ctx.delete().await;
 }
 
+#[sea_orm_macros::test]
+#[cfg(all(feature = "sqlx-sqlite", not(feature = "sync")))]
+pub async fn connection_close_with_timeout_sqlite() -> Result<(), DbErr> {
+    use sea_orm::{DbBackend, Statement};
+    use std::time::{Duration, Instant};
+
+    let base_url = std::env::var("DATABASE_URL").unwrap();
+    let db = sea_orm::Database::connect(base_url).await?;
+
+    // A busy loop keeping its connection in use for longer than the timeout
+    async fn slow_query(db: &DatabaseConnection) -> Result<Option<i64>, DbErr> {
+        db.query_one_raw(Statement::from_string(
+            DbBackend::Sqlite,
+            "WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c WHERE x < 1000000) \
+            SELECT COUNT(*) AS n FROM c",
+        ))
+        .await?
+        .map(|row| row.try_get("", "n"))
+        .transpose()
+    }
+
+    async fn close(db: &DatabaseConnection) -> Result<(), DbErr> {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let timeout = Duration::from_millis(100);
+        let start = Instant::now();
+        db.close_with_timeout(timeout).await?;
+        assert!(start.elapsed() >= timeout);
+
+        assert_eq!(
+            db.ping().await,
+            Err(DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed))
+        );
+        Ok(())
+    }
+
+    let (res, closed) = tokio::join!(slow_query(&db), close(&db));
+    closed?;
+    // the query still in flight past the timeout is cancelled
+    assert_eq!(
+        res,
+        Err(DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed))
+    );
+
+    assert_eq!(
+        slow_query(&db).await,
+        Err(DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed))
+    );
+
+    // closing again is a no-op
+    db.close_by_ref().await?;
+    db.close_with_timeout(Duration::from_millis(100)).await?;
+    db.close().await?;
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(all(feature = "sqlx-sqlite", not(feature = "sync")))]
+pub async fn connection_map_sqlx_sqlite_opts() -> Result<(), DbErr> {
+    use sea_orm::{ConnectOptions, DbBackend, Statement};
+    use sqlx::sqlite::SqliteJournalMode;
+    use std::time::Duration;
+
+    async fn pragma(db: &DatabaseConnection, name: &str) -> Result<Option<QueryResult>, DbErr> {
+        db.query_one_raw(Statement::from_string(
+            DbBackend::Sqlite,
+            format!("PRAGMA {name}"),
+        ))
+        .await
+    }
+
+    // an in-memory database is always in `memory` journal mode
+    let path = std::env::temp_dir().join("sea-orm-map-sqlx-sqlite-opts.sqlite");
+    let _ = std::fs::remove_file(&path);
+
+    let mut opt = ConnectOptions::new(format!("sqlite://{}?mode=rwc", path.display()));
+    opt.map_sqlx_sqlite_opts(|opts| {
+        opts.journal_mode(SqliteJournalMode::Wal)
+            .busy_timeout(Duration::from_millis(1234))
+            .foreign_keys(false)
+    });
+    let db = sea_orm::Database::connect(opt).await?;
+
+    let journal_mode: String = pragma(&db, "journal_mode")
+        .await?
+        .unwrap()
+        .try_get_by_index(0)?;
+    assert_eq!(journal_mode, "wal");
+
+    let busy_timeout: i64 = pragma(&db, "busy_timeout")
+        .await?
+        .unwrap()
+        .try_get_by_index(0)?;
+    assert_eq!(busy_timeout, 1234);
+
+    let foreign_keys: i64 = pragma(&db, "foreign_keys")
+        .await?
+        .unwrap()
+        .try_get_by_index(0)?;
+    assert_eq!(foreign_keys, 0);
+
+    db.close().await?;
+    let _ = std::fs::remove_file(&path);
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(all(feature = "sqlx-sqlite", not(feature = "sync")))]
+pub async fn connection_warmup_sqlite() -> Result<(), DbErr> {
+    use sea_orm::{ConnectOptions, Database, DatabaseConnection};
+
+    // connections are returned to the pool in the background, so idle ones are not counted
+    fn pool_size(db: &DatabaseConnection) -> Option<u32> {
+        db.pool_stats().map(|stats| stats.size)
+    }
+
+    let base_url = std::env::var("DATABASE_URL").unwrap();
+
+    let db = Database::connect(base_url.clone()).await?;
+    assert_eq!(pool_size(&db), Some(1));
+
+    let mut opts = ConnectOptions::new(base_url.clone());
+    opts.max_connections(5).warmup_connections(3);
+    let db = Database::connect(opts).await?;
+    assert_eq!(pool_size(&db), Some(3));
+
+    // bounded by max_connections
+    let mut opts = ConnectOptions::new(base_url.clone());
+    opts.max_connections(2).warmup_connections(3);
+    let db = Database::connect(opts).await?;
+    assert_eq!(pool_size(&db), Some(2));
+
+    // ignored by lazy pools
+    let mut opts = ConnectOptions::new(base_url);
+    opts.max_connections(5)
+        .warmup_connections(3)
+        .connect_lazy(true);
+    let db = Database::connect(opts).await?;
+    // at most the connection used to check the SQLite version
+    assert!(pool_size(&db) <= Some(1));
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(all(feature = "sqlx-sqlite", not(feature = "sync")))]
+pub async fn connection_single_sqlite() -> Result<(), DbErr> {
+    use sea_orm::{Set, SqlxSqliteConnector, TransactionTrait};
+    use sqlx::Connection;
+
+    async fn insert_bakery<C: ConnectionTrait>(db: &C, name: &str) -> Result<bakery::Model, DbErr> {
+        bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(10.4),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+    }
+
+    async fn insert_waiting_bakery(db: DatabaseConnection) -> Result<bakery::Model, DbErr> {
+        insert_bakery(&db, "Waiting Bakery").await
+    }
+
+    let conn = sqlx::SqliteConnection::connect("sqlite::memory:")
+        .await
+        .expect("in-memory database");
+    let db = SqlxSqliteConnector::from_sqlx_sqlite_connection(conn);
+    assert_eq!(db.pool_stats(), None);
+    db.ping().await?;
+
+    // an in-memory database lives as long as its connection, so the
+    // table is seen by every statement below
+    create_bakery_table(&db).await?;
+
+    let seaside = insert_bakery(&db, "SeaSide Bakery").await?;
+    assert_eq!(
+        bakery::Entity::find_by_id(seaside.id).one(&db).await?,
+        Some(seaside.clone())
+    );
+    let mut seaside: bakery::ActiveModel = seaside.into();
+    seaside.profit_margin = Set(12.5);
+    let seaside = seaside.update(&db).await?;
+    assert_eq!(seaside.profit_margin, 12.5);
+    seaside.delete(&db).await?;
+    assert_eq!(bakery::Entity::find().count(&db).await?, 0);
+
+    let txn = db.begin().await?;
+    insert_bakery(&txn, "Rolled Back Bakery").await?;
+    assert_eq!(bakery::Entity::find().count(&txn).await?, 1);
+    txn.rollback().await?;
+    assert_eq!(bakery::Entity::find().count(&db).await?, 0);
+
+    // a transaction holds the connection until it ends, so a statement on
+    // another clone waits for it instead of becoming part of it
+    let txn = db.begin().await?;
+    insert_bakery(&txn, "Rolled Back Bakery").await?;
+    let other = tokio::spawn(insert_waiting_bakery(db.clone()));
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    assert!(!other.is_finished());
+    assert_eq!(bakery::Entity::find().count(&txn).await?, 1);
+    txn.rollback().await?;
+    let waiting = other.await.expect("insert task")?;
+    assert_eq!(
+        bakery::Entity::find().all(&db).await?,
+        std::slice::from_ref(&waiting)
+    );
+    waiting.delete(&db).await?;
+
+    let txn = db.begin().await?;
+    insert_bakery(&txn, "Committed Bakery").await?;
+    txn.commit().await?;
+    assert_eq!(bakery::Entity::find().count(&db).await?, 1);
+
+    // concurrent calls, also on clones, wait for each other instead of failing
+    let other = db.clone();
+    let (a, b, c) = tokio::join!(
+        insert_bakery(&db, "Bakery A"),
+        insert_bakery(&other, "Bakery B"),
+        bakery::Entity::find().all(&db),
+    );
+    a?;
+    b?;
+    c?;
+    assert_eq!(bakery::Entity::find().count(&other).await?, 3);
+
+    db.close().await?;
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(all(feature = "sqlx-sqlite", not(feature = "sync")))]
+pub async fn connection_busy_retry_sqlite() -> Result<(), DbErr> {
+    use sea_orm::{ConnectOptions, Database, Set, TransactionTrait};
+    use std::time::Duration;
+
+    async fn insert_bakery<C: ConnectionTrait>(db: &C, name: &str) -> Result<bakery::Model, DbErr> {
+        bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(10.4),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+    }
+
+    async fn insert_bakeries(db: DatabaseConnection, task: &str) -> Result<(), DbErr> {
+        for i in 0..50 {
+            insert_bakery(&db, &format!("{task} Bakery {i}")).await?;
+        }
+        Ok(())
+    }
+
+    let path = std::env::temp_dir().join("sea-orm-busy-retry.sqlite");
+    let _ = std::fs::remove_file(&path);
+
+    // each `connect` opens a pool of its own; without a busy timeout,
+    // a write fails right away while another connection holds the lock
+    let connect = |busy_retry: Option<(u32, Duration)>| {
+        let mut opt = ConnectOptions::new(format!("sqlite://{}?mode=rwc", path.display()));
+        opt.map_sqlx_sqlite_opts(|opts| opts.busy_timeout(Duration::ZERO));
+        if let Some((max_attempts, backoff)) = busy_retry {
+            opt.sqlite_busy_retry(max_attempts, backoff);
+        }
+        Database::connect(opt)
+    };
+
+    let locker = connect(None).await?;
+    create_bakery_table(&locker).await?;
+    let no_retry = connect(None).await?;
+    let retry = connect(Some((100, Duration::from_millis(1)))).await?;
+
+    let txn = locker.begin().await?;
+    insert_bakery(&txn, "Locking Bakery").await?;
+
+    let err = insert_bakery(&no_retry, "Busy Bakery").await.unwrap_err();
+    assert!(err.is_busy(), "{err}");
+
+    // statements in a transaction are not retried
+    let retry_txn = retry.begin().await?;
+    let err = insert_bakery(&retry_txn, "Busy Bakery").await.unwrap_err();
+    assert!(err.is_busy(), "{err}");
+    retry_txn.rollback().await?;
+
+    // nor are scripts, of which the statements before the busy one would run again
+    let err = retry
+        .execute_unprepared(
+            "INSERT INTO bakery (name, profit_margin) VALUES ('Script Bakery', 1.0);
+             INSERT INTO bakery (name, profit_margin) VALUES ('Script Bakery', 2.0);",
+        )
+        .await
+        .unwrap_err();
+    assert!(err.is_busy(), "{err}");
+
+    // retried until the lock is released
+    let (inserted, committed) = tokio::join!(insert_bakery(&retry, "Waiting Bakery"), async {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        txn.commit().await
+    });
+    committed?;
+    inserted?;
+
+    // two tasks hammering the same file
+    let other = connect(Some((100, Duration::from_millis(1)))).await?;
+    let (a, b) = tokio::join!(
+        tokio::spawn(insert_bakeries(retry.clone(), "A")),
+        tokio::spawn(insert_bakeries(other.clone(), "B")),
+    );
+    a.expect("task A")?;
+    b.expect("task B")?;
+    assert_eq!(bakery::Entity::find().count(&retry).await?, 102);
+
+    for db in [locker, no_retry, retry, other] {
+        db.close().await?;
+    }
+    let _ = std::fs::remove_file(&path);
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(feature = "sqlx-sqlite", feature = "rusqlite"))]
+pub async fn connection_query_timeout_sqlite() -> Result<(), DbErr> {
+    use sea_orm::{DbBackend, FromQueryResult, Statement, TransactionTrait};
+    use std::time::{Duration, Instant};
+
+    let base_url = std::env::var("DATABASE_URL").unwrap();
+    let db = sea_orm::Database::connect(base_url).await?;
+
+    #[derive(Debug, PartialEq, FromQueryResult)]
+    struct Count {
+        n: i64,
+    }
+
+    fn count_to(n: i64) -> Statement {
+        Statement::from_string(
+            DbBackend::Sqlite,
+            format!(
+                "WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c WHERE x < {n}) \
+                SELECT COUNT(*) AS n FROM c"
+            ),
+        )
+    }
+
+    let timeout = Duration::from_millis(100);
+
+    let start = Instant::now();
+    let res = Count::find_by_statement(count_to(1_000_000_000))
+        .timeout(timeout)
+        .one(&db)
+        .await;
+    assert_eq!(res, Err(DbErr::QueryTimeout(timeout)));
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    // the connection is usable again, without a timeout
+    let res = Count::find_by_statement(count_to(1_000)).one(&db).await?;
+    assert_eq!(res, Some(Count { n: 1_000 }));
+
+    // well within the timeout
+    let res = Count::find_by_statement(count_to(1_000))
+        .timeout(Duration::from_secs(10))
+        .all(&db)
+        .await?;
+    assert_eq!(res, [Count { n: 1_000 }]);
+
+    let txn = db.begin().await?;
+    let res = txn
+        .query_all_raw(count_to(1_000_000_000).with_timeout(timeout))
+        .await;
+    assert_eq!(res.err(), Some(DbErr::QueryTimeout(timeout)));
+    let res = Count::find_by_statement(count_to(10)).one(&txn).await?;
+    assert_eq!(res, Some(Count { n: 10 }));
+    txn.rollback().await?;
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+pub async fn connection_query_timeout_postgres() -> Result<(), DbErr> {
+    use sea_orm::{DbBackend, FromQueryResult, Statement, TransactionTrait};
+    use std::time::{Duration, Instant};
+
+    let ctx = TestContext::new("connection_query_timeout").await;
+
+    let base_url = std::env::var("DATABASE_URL").unwrap();
+    let mut opt = sea_orm::ConnectOptions::new(format!("{base_url}/connection_query_timeout"));
+    // A single connection, to check that statement_timeout is restored on it
+    opt.max_connections(1);
+    let db = &sea_orm::Database::connect(opt).await?;
+
+    #[derive(Debug, PartialEq, FromQueryResult)]
+    struct Slept {
+        slept: String,
+    }
+
+    fn sleep(secs: f64) -> Statement {
+        Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT pg_sleep($1)::text AS slept",
+            [secs.into()],
+        )
+    }
+
+    async fn statement_timeout<C: ConnectionTrait>(conn: &C) -> Result<String, DbErr> {
+        conn.query_one_raw(Statement::from_string(
+            DbBackend::Postgres,
+            "SHOW statement_timeout",
+        ))
+        .await?
+        .expect("statement_timeout is set")
+        .try_get("", "statement_timeout")
+    }
+
+    let default_statement_timeout = statement_timeout(db).await?;
+    let timeout = Duration::from_millis(200);
+
+    // cancelled by the server, instead of sleeping for a minute
+    let start = Instant::now();
+    let res = Slept::find_by_statement(sleep(60.0))
+        .timeout(timeout)
+        .one(db)
+        .await;
+    assert_eq!(res, Err(DbErr::QueryTimeout(timeout)));
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_eq!(statement_timeout(db).await?, default_statement_timeout);
+
+    let res = db.execute_raw(sleep(60.0).with_timeout(timeout)).await;
+    assert_eq!(res.err(), Some(DbErr::QueryTimeout(timeout)));
+
+    // dropping the future rolls back the transaction the timeout is set in,
+    // so it doesn't outlive the statement on the connection either
+    let mut opt = sea_orm::ConnectOptions::new(format!("{base_url}/connection_query_timeout"));
+    opt.max_connections(2);
+    let dropped = &sea_orm::Database::connect(opt).await?;
+    let res = tokio::time::timeout(
+        timeout,
+        Slept::find_by_statement(sleep(60.0))
+            .timeout(Duration::from_secs(1))
+            .one(dropped),
+    )
+    .await;
+    assert!(res.is_err());
+    assert_eq!(statement_timeout(dropped).await?, default_statement_timeout);
+    let res = Slept::find_by_statement(sleep(1.5)).one(dropped).await?;
+    assert_eq!(res, Some(Slept { slept: "".into() }));
+
+    let res = Slept::find_by_statement(sleep(0.0))
+        .timeout(Duration::from_secs(10))
+        .all(db)
+        .await?;
+    assert_eq!(res, [Slept { slept: "".into() }]);
+    assert_eq!(statement_timeout(db).await?, default_statement_timeout);
+
+    let txn = db.begin().await?;
+    let res = Slept::find_by_statement(sleep(0.0))
+        .timeout(Duration::from_secs(10))
+        .one(&txn)
+        .await?;
+    assert_eq!(res, Some(Slept { slept: "".into() }));
+    assert_eq!(statement_timeout(&txn).await?, default_statement_timeout);
+    let res = txn.query_all_raw(sleep(60.0).with_timeout(timeout)).await;
+    assert_eq!(res.err(), Some(DbErr::QueryTimeout(timeout)));
+    txn.rollback().await?;
+    assert_eq!(statement_timeout(db).await?, default_statement_timeout);
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+pub async fn connection_statement_caching_postgres() -> Result<(), DbErr> {
+    use sea_orm::{DbBackend, Statement};
+
+    let ctx = TestContext::new("connection_statement_caching").await;
+
+    let base_url = std::env::var("DATABASE_URL").unwrap();
+    let url = format!("{base_url}/connection_statement_caching");
+
+    fn select(name: &str) -> Statement {
+        Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(r#"SELECT $1::int AS "{name}""#),
+            [1.into()],
+        )
+    }
+
+    // the named statements prepared on the (only) connection
+    async fn prepared(db: &DatabaseConnection, name: &str) -> Result<i64, DbErr> {
+        db.query_one_raw(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT COUNT(*) AS "count" FROM pg_prepared_statements WHERE statement LIKE $1"#,
+            [format!("%{name}%").into()],
+        ))
+        .await?
+        .expect("a count")
+        .try_get("", "count")
+    }
+
+    let mut opt = sea_orm::ConnectOptions::new(&url);
+    opt.max_connections(1);
+    let db = &sea_orm::Database::connect(opt).await?;
+
+    db.query_one_raw(select("cached")).await?;
+    assert_eq!(prepared(db, "cached").await?, 1);
+    db.query_one_raw(select("not_cached").persistent(false))
+        .await?;
+    assert_eq!(prepared(db, "not_cached").await?, 0);
+
+    let mut opt = sea_orm::ConnectOptions::new(&url);
+    opt.max_connections(1).disable_statement_caching();
+    assert_eq!(opt.get_statement_cache_capacity(), Some(0));
+    let db = &sea_orm::Database::connect(opt).await?;
+
+    db.query_one_raw(select("cached")).await?;
+    assert_eq!(prepared(db, "cached").await?, 0);
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
 #[sea_orm_macros::test]
 #[cfg(feature = "sqlx-postgres")]
 pub async fn connection_ping_closed_postgres() {
@@ -161,6 +688,78 @@ pub async fn connection_with_search_path_postgres() {
     ctx.delete().await;
 }
 
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+pub async fn connection_pinned_search_path_postgres() -> Result<(), DbErr> {
+    use sea_orm::{DbBackend, Statement, TransactionTrait};
+
+    let ctx = TestContext::new("connection_pinned_search_path").await;
+
+    let base_url = std::env::var("DATABASE_URL").unwrap();
+    let mut opt = sea_orm::ConnectOptions::new(format!("{base_url}/connection_pinned_search_path"));
+    opt
+        // The connection pool has a single connection only, shared by all tenants
+        .max_connections(1)
+        // A controlled connection acquire timeout
+        .acquire_timeout(std::time::Duration::from_secs(2));
+
+    let db = sea_orm::Database::connect(opt).await?;
+
+    for tenant in ["tenant_a", "tenant_b"] {
+        db.execute_unprepared(&format!(
+            r#"CREATE SCHEMA "{tenant}";
+            CREATE TABLE "{tenant}"."tenant" ("name" text NOT NULL);
+            INSERT INTO "{tenant}"."tenant" ("name") VALUES ('{tenant}');"#
+        ))
+        .await?;
+    }
+
+    async fn tenant<C: ConnectionTrait>(conn: &C) -> Result<String, DbErr> {
+        conn.query_one_raw(Statement::from_string(
+            DbBackend::Postgres,
+            r#"SELECT "name" FROM "tenant""#,
+        ))
+        .await?
+        .expect("one tenant")
+        .try_get("", "name")
+    }
+
+    async fn search_path<C: ConnectionTrait>(conn: &C) -> Result<String, DbErr> {
+        conn.query_one_raw(Statement::from_string(
+            DbBackend::Postgres,
+            "SHOW search_path",
+        ))
+        .await?
+        .expect("search_path is set")
+        .try_get("", "search_path")
+    }
+
+    let default_search_path = search_path(&db).await?;
+    assert!(tenant(&db).await.is_err());
+
+    let conn = db.with_search_path("tenant_a").await?;
+    assert_eq!(tenant(&conn).await?, "tenant_a");
+    let txn = conn.begin().await?;
+    assert_eq!(tenant(&txn).await?, "tenant_a");
+    txn.commit().await?;
+    conn.release().await?;
+
+    // restored before going back to the pool
+    assert_eq!(search_path(&db).await?, default_search_path);
+
+    let conn = db.with_search_path("tenant_b").await?;
+    assert_eq!(tenant(&conn).await?, "tenant_b");
+    drop(conn);
+
+    // closed instead of going back to the pool, as it's dropped without being released
+    assert_eq!(search_path(&db).await?, default_search_path);
+    assert!(tenant(&db).await.is_err());
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
 #[sea_orm_macros::test]
 #[cfg(feature = "rusqlite")]
 pub async fn rusqlite_connects_file_uri_with_sqlite_query_parameters() {
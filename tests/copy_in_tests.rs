@@ -0,0 +1,70 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{TestContext, bakery_chain::*, setup::*};
+use pretty_assertions::assert_eq;
+use sea_orm::{Set, TransactionTrait, entity::*, prelude::*};
+
+fn bakeries(n: usize) -> impl Iterator<Item = bakery::ActiveModel> + Send {
+    (0..n).map(|i| bakery::ActiveModel {
+        name: Set(format!("Bakery #{i}\twith a tab")),
+        profit_margin: Set(i as f64 / 4.0),
+        ..Default::default()
+    })
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+pub async fn copy_in_bulk_insert() -> Result<(), DbErr> {
+    let ctx = TestContext::new("copy_in_bulk_insert_test").await;
+    create_bakery_table(&ctx.db).await?;
+
+    assert_eq!(bakery::Entity::copy_in(&ctx.db, bakeries(0)).await?, 0);
+    assert_eq!(
+        bakery::Entity::copy_in(&ctx.db, bakeries(10_000)).await?,
+        10_000
+    );
+    assert_eq!(bakery::Entity::find().count(&ctx.db).await?, 10_000);
+
+    let last = bakery::Entity::find()
+        .filter(bakery::Column::Name.eq("Bakery #9999\twith a tab"))
+        .one(&ctx.db)
+        .await?
+        .expect("copied row");
+    assert_eq!(last.profit_margin, 2499.75);
+
+    ctx.delete().await;
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+pub async fn copy_in_transaction() -> Result<(), DbErr> {
+    let ctx = TestContext::new("copy_in_transaction_test").await;
+    create_bakery_table(&ctx.db).await?;
+
+    let txn = ctx.db.begin().await?;
+    assert_eq!(bakery::Entity::copy_in(&txn, bakeries(100)).await?, 100);
+    assert_eq!(bakery::Entity::find().count(&txn).await?, 100);
+    assert_eq!(bakery::Entity::find().count(&ctx.db).await?, 0);
+    txn.rollback().await?;
+    assert_eq!(bakery::Entity::find().count(&ctx.db).await?, 0);
+
+    let txn = ctx.db.begin().await?;
+    bakery::Entity::copy_in(&txn, bakeries(100)).await?;
+    txn.commit().await?;
+    assert_eq!(bakery::Entity::find().count(&ctx.db).await?, 100);
+
+    // a model setting a different column list fails the whole COPY
+    let mut models: Vec<_> = bakeries(3).collect();
+    models[2].id = Set(1_000_000);
+    assert!(matches!(
+        bakery::Entity::copy_in(&ctx.db, models).await,
+        Err(DbErr::Custom(_))
+    ));
+    assert_eq!(bakery::Entity::find().count(&ctx.db).await?, 100);
+
+    ctx.delete().await;
+    Ok(())
+}
@@ -5,7 +5,7 @@ pub mod common;
 pub use common::{TestContext, features::*, setup::*};
 use pretty_assertions::assert_eq;
 use sea_orm::{
-    DatabaseConnection, Delete, IntoActiveModel, Iterable, QueryTrait, Set, Update,
+    DatabaseConnection, Delete, IntoActiveModel, Iterable, QueryOrder, QueryTrait, Set, Update,
     entity::prelude::*,
 };
 use sea_query::{Expr, ExprTrait, Query};
@@ -15,6 +15,7 @@ async fn main() -> Result<(), DbErr> {
     let ctx = TestContext::new("dyn_table_name_tests").await;
     create_dyn_table_name_lazy_static_table(&ctx.db).await?;
     dyn_table_name(&ctx.db).await?;
+    dyn_table_name_override(&ctx.db).await?;
     ctx.delete().await;
 
     Ok(())
@@ -73,3 +74,87 @@ pub async fn dyn_table_name(db: &DatabaseConnection) -> Result<(), DbErr> {
 
     Ok(())
 }
+
+pub async fn dyn_table_name_override(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use dyn_table_name::*;
+
+    for i in 1..=2 {
+        let entity = Entity { table_name: i };
+        // Insert into the table chosen at runtime
+        let model = Entity::insert(ActiveModel {
+            name: Set(format!("Table {i}, 1st Row")),
+            ..Default::default()
+        })
+        .for_entity_instance(entity)
+        .exec_with_returning(db)
+        .await?;
+        assert_eq!(model.name, format!("Table {i}, 1st Row"));
+
+        Entity::insert_many([
+            ActiveModel {
+                name: Set(format!("Table {i}, 2nd Row")),
+                ..Default::default()
+            },
+            ActiveModel {
+                name: Set(format!("Table {i}, 3rd Row")),
+                ..Default::default()
+            },
+        ])
+        .for_entity_instance(entity)
+        .exec(db)
+        .await?;
+    }
+
+    for i in 1..=2 {
+        let entity = Entity { table_name: i };
+        // Each table only has the rows inserted into it
+        let models = Entity::find()
+            .for_entity_instance(entity)
+            .order_by_asc(Column::Id)
+            .all(db)
+            .await?;
+        assert_eq!(
+            models.iter().map(|m| m.name.as_str()).collect::<Vec<_>>(),
+            [
+                format!("Table {i}, 1st Row"),
+                format!("Table {i}, 2nd Row"),
+                format!("Table {i}, 3rd Row"),
+            ]
+        );
+
+        let model = Entity::update(ActiveModel {
+            name: Set(format!("Table {i}, 1st Row (edited)")),
+            ..models[0].clone().into_active_model()
+        })
+        .for_entity_instance(entity)
+        .exec(db)
+        .await?;
+        assert_eq!(
+            Entity::find_by_id(model.id)
+                .for_entity_instance(entity)
+                .one(db)
+                .await?,
+            Some(model.clone())
+        );
+
+        let res = Entity::delete(model.into_active_model())
+            .for_entity_instance(entity)
+            .exec(db)
+            .await?;
+        assert_eq!(res.rows_affected, 1);
+
+        let res = Entity::delete_many()
+            .for_entity_instance(entity)
+            .filter(Column::Name.ends_with("Row"))
+            .exec(db)
+            .await?;
+        assert_eq!(res.rows_affected, 2);
+
+        assert_eq!(
+            Entity::find().for_entity_instance(entity).all(db).await?,
+            []
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+use common::{TestContext, setup::create_table_from_entity};
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    DbErr, EntityTrait, QueryOrder,
+    entity::*,
+    fixtures::{self, LoadOptions},
+    tests_cfg::{cake, fruit},
+};
+use serde_json::json;
+
+#[sea_orm_macros::test]
+async fn fixtures_tests() -> Result<(), DbErr> {
+    let ctx = TestContext::new("fixtures_tests").await;
+    let db = &ctx.db;
+    create_table_from_entity(db, cake::Entity).await?;
+    create_table_from_entity(db, fruit::Entity).await?;
+
+    let cakes = fixtures::load_with_options::<cake::Entity, _>(
+        db,
+        json!([
+            { "id": 1, "name": "Cheese Cake" },
+            { "id": 2, "name": "Apple Pie" },
+        ]),
+        LoadOptions::new().reset_sequences(true),
+    )
+    .await?;
+    assert_eq!(
+        cakes,
+        [
+            cake::Model {
+                id: 1,
+                name: "Cheese Cake".to_owned(),
+            },
+            cake::Model {
+                id: 2,
+                name: "Apple Pie".to_owned(),
+            },
+        ]
+    );
+
+    let fruits = fixtures::load::<fruit::Entity, _>(
+        db,
+        json!([
+            { "name": "Apple", "cake_id": 2 },
+            { "name": "Lemon", "cake_id": null },
+        ]),
+    )
+    .await?;
+    assert_eq!(
+        fruits,
+        [
+            fruit::Model {
+                id: 1,
+                name: "Apple".to_owned(),
+                cake_id: Some(2),
+            },
+            fruit::Model {
+                id: 2,
+                name: "Lemon".to_owned(),
+                cake_id: None,
+            },
+        ]
+    );
+
+    assert_eq!(
+        fruit::Entity::find()
+            .find_also_related(cake::Entity)
+            .order_by_asc(fruit::Column::Id)
+            .all(db)
+            .await?,
+        [
+            (fruits[0].clone(), Some(cakes[1].clone())),
+            (fruits[1].clone(), None),
+        ]
+    );
+
+    // the sequence continues after the ids of the fixtures
+    let cake = cake::ActiveModel {
+        name: Set("Lemon Tart".to_owned()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(cake.id, 3);
+
+    ctx.delete().await;
+
+    Ok(())
+}
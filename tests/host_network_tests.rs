@@ -1,12 +1,14 @@
 #![allow(unused_imports, dead_code)]
-#![cfg(feature = "with-ipnetwork")]
+#![cfg(all(feature = "with-ipnetwork", feature = "with-mac_address"))]
 
 pub mod common;
 
 use common::{TestContext, features::*, setup::*};
 use pretty_assertions::assert_eq;
-use sea_orm::{DatabaseConnection, entity::prelude::*, entity::*};
-use std::net::{Ipv4Addr, Ipv6Addr};
+use sea_orm::{
+    DatabaseConnection, DbBackend, QuerySelect, Statement, entity::prelude::*, entity::*,
+};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 #[sea_orm_macros::test]
 #[cfg(feature = "sqlx-postgres")]
@@ -14,6 +16,40 @@ async fn main() -> Result<(), DbErr> {
     let ctx = TestContext::new("host_network_tests").await;
     create_host_network_table(&ctx.db).await?;
     create_and_update_host_network(&ctx.db).await?;
+    select_ip_addresses(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(feature = "sqlx-mysql", feature = "sqlx-sqlite", feature = "rusqlite"))]
+async fn ip_address_unsupported() -> Result<(), DbErr> {
+    let ctx = TestContext::new("host_network_unsupported_tests").await;
+    let backend = ctx.db.get_database_backend();
+
+    let row = ctx
+        .db
+        .query_one_raw(Statement::from_string(
+            backend,
+            "SELECT '192.168.0.20' AS address",
+        ))
+        .await?
+        .unwrap();
+
+    assert!(matches!(
+        row.try_get::<IpAddr>("", "address"),
+        Err(DbErr::Type(_))
+    ));
+    assert!(matches!(
+        row.try_get::<IpAddress>("", "address"),
+        Err(DbErr::Type(_))
+    ));
+    assert!(matches!(
+        row.try_get::<MacAddress>("", "address"),
+        Err(DbErr::Type(_))
+    ));
+
     ctx.delete().await;
 
     Ok(())
@@ -28,6 +64,9 @@ async fn create_and_update_host_network(db: &DatabaseConnection) -> Result<(), D
         hostname: "example.com".to_owned(),
         ipaddress: addr,
         network: net,
+        address: Ipv4Addr::new(192, 168, 0, 20).into(),
+        gateway: None,
+        mac_address: MacAddress::new([0x02, 0x42, 0xac, 0x11, 0x00, 0x02]),
     };
     let res = host.clone().into_active_model().insert(db).await?;
 
@@ -46,6 +85,10 @@ async fn create_and_update_host_network(db: &DatabaseConnection) -> Result<(), D
         id: Set(1),
         ipaddress: Set(addrv6),
         network: Set(netv6),
+        address: Set(addrv6.ip().into()),
+        gateway: Set(Some(
+            Ipv6Addr::new(0xfd89, 0x1926, 0x4cae, 0x8abd, 0, 0, 0, 1).into(),
+        )),
         ..Default::default()
     }
     .update(db)
@@ -58,8 +101,59 @@ async fn create_and_update_host_network(db: &DatabaseConnection) -> Result<(), D
             hostname: "example.com".to_owned(),
             ipaddress: addrv6,
             network: netv6,
+            address: addrv6.ip().into(),
+            gateway: Some(Ipv6Addr::new(0xfd89, 0x1926, 0x4cae, 0x8abd, 0, 0, 0, 1).into()),
+            mac_address: host.mac_address,
         }
     );
 
     Ok(())
 }
+
+async fn select_ip_addresses(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let addrv6 = Ipv6Addr::new(0xfd89, 0x1926, 0x4cae, 0x8abd, 0, 0, 0, 0x6f52);
+
+    let (address, gateway): (IpAddr, Option<Ipv6Addr>) = host_network::Entity::find()
+        .select_only()
+        .column(host_network::Column::Address)
+        .column(host_network::Column::Gateway)
+        .into_tuple()
+        .one(db)
+        .await?
+        .unwrap();
+    assert_eq!(address, IpAddr::V6(addrv6));
+    assert_eq!(
+        gateway,
+        Some(Ipv6Addr::new(0xfd89, 0x1926, 0x4cae, 0x8abd, 0, 0, 0, 1))
+    );
+
+    // the address is not an IPv4 one
+    assert!(matches!(
+        host_network::Entity::find()
+            .select_only()
+            .column(host_network::Column::Address)
+            .into_tuple::<Ipv4Addr>()
+            .one(db)
+            .await,
+        Err(DbErr::Type(_))
+    ));
+
+    // `ipaddress` is a /64 network, which can't be read as a single address
+    assert!(matches!(
+        host_network::Entity::find()
+            .select_only()
+            .column(host_network::Column::Ipaddress)
+            .into_tuple::<IpAddr>()
+            .one(db)
+            .await,
+        Err(DbErr::Type(_))
+    ));
+
+    let found = host_network::Entity::find()
+        .filter(host_network::Column::Address.eq(IpAddress::from(addrv6)))
+        .one(db)
+        .await?;
+    assert!(found.is_some());
+
+    Ok(())
+}
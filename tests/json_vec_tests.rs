@@ -12,9 +12,11 @@ async fn main() -> Result<(), DbErr> {
     create_json_vec_table(&ctx.db).await?;
     create_json_string_vec_table(&ctx.db).await?;
     create_json_struct_vec_table(&ctx.db).await?;
+    create_json_struct_vec_nested_table(&ctx.db).await?;
     insert_json_vec(&ctx.db).await?;
     insert_json_string_vec_derive(&ctx.db).await?;
     insert_json_struct_vec_derive(&ctx.db).await?;
+    insert_json_struct_vec_nested(&ctx.db).await?;
 
     ctx.delete().await;
 
@@ -110,3 +112,54 @@ pub async fn insert_json_struct_vec_derive(db: &DatabaseConnection) -> Result<()
 
     Ok(())
 }
+
+pub async fn insert_json_struct_vec_nested(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use json_vec_derive::{json_struct_vec::JsonColumn, json_struct_vec_nested::*};
+
+    let models = [
+        Model {
+            id: 1,
+            opt_vec: Some(vec![
+                JsonColumn {
+                    value: "7".to_string(),
+                },
+                JsonColumn {
+                    value: "8".to_string(),
+                },
+            ]),
+        },
+        Model {
+            id: 2,
+            opt_vec: Some(vec![]),
+        },
+        Model {
+            id: 3,
+            opt_vec: None,
+        },
+    ];
+
+    for model in models.iter() {
+        model.clone().into_active_model().insert(db).await?;
+        assert_eq!(
+            Entity::find_by_id(model.id).one(db).await?.as_ref(),
+            Some(model)
+        );
+    }
+
+    // a JSON `null` reads back the same as SQL `NULL`
+    Entity::update_many()
+        .col_expr(Column::OptVec, Expr::val(serde_json::Value::Null))
+        .filter(Column::Id.eq(3))
+        .exec(db)
+        .await?;
+
+    assert_eq!(
+        Entity::find_by_id(3).one(db).await?,
+        Some(Model {
+            id: 3,
+            opt_vec: None,
+        })
+    );
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{TestContext, bakery_chain::*, setup::*};
+use pretty_assertions::assert_eq;
+pub use sea_orm::entity::*;
+pub use sea_orm::{ConnectionTrait, DbErr, QueryFilter, TransactionTrait};
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+
+#[sea_orm_macros::test]
+pub async fn query_count() -> Result<(), DbErr> {
+    let mut ctx = TestContext::new("query_count_tests").await;
+    create_bakery_table(&ctx.db).await?;
+
+    let metric_calls = Arc::new(AtomicU64::new(0));
+    ctx.db.enable_query_counting();
+    ctx.db.set_metric_callback({
+        let metric_calls = metric_calls.clone();
+        move |_| {
+            metric_calls.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+    let db = &ctx.db;
+    let start = db.query_count();
+
+    let (res, count) = db
+        .count_queries(async || {
+            Bakery::insert(bakery::ActiveModel {
+                name: Set("SeaSide Bakery".to_owned()),
+                profit_margin: Set(10.4),
+                ..Default::default()
+            })
+            .exec(db)
+            .await
+        })
+        .await;
+    let bakery_id = res?.last_insert_id;
+    assert_eq!(count, 1);
+
+    let (bakery, count) = db
+        .count_queries(async || Bakery::find_by_id(bakery_id).one(db).await)
+        .await;
+    assert_eq!(bakery?.map(|b| b.name), Some("SeaSide Bakery".to_owned()));
+    assert_eq!(count, 1);
+
+    let (res, count) = db
+        .count_queries(async || {
+            db.transaction_async::<_, _, DbErr>(async |txn| {
+                let (bakeries, count) = txn
+                    .count_queries(async || Bakery::find().all(txn).await)
+                    .await;
+                assert_eq!(bakeries?.len(), 1);
+                assert_eq!(count, 1);
+
+                Bakery::update_many()
+                    .col_expr(bakery::Column::ProfitMargin, 12.5.into())
+                    .filter(bakery::Column::Id.eq(bakery_id))
+                    .exec(txn)
+                    .await?;
+                Ok(txn.query_count())
+            })
+            .await
+        })
+        .await;
+    assert_eq!(res.map_err(|e| DbErr::Custom(e.to_string()))?, start + 4);
+    assert_eq!(count, 2);
+
+    #[cfg(feature = "stream")]
+    {
+        use futures_util::StreamExt;
+
+        let mut stream = Bakery::find().stream(db).await?;
+        let mut rows = 0;
+        while let Some(bakery) = stream.next().await {
+            bakery?;
+            rows += 1;
+        }
+        drop(stream);
+        assert_eq!(rows, 1);
+        assert_eq!(db.query_count(), start + 5);
+    }
+
+    // the user callback is called for every counted statement
+    assert_eq!(
+        metric_calls.load(Ordering::Relaxed),
+        db.query_count() - start
+    );
+
+    ctx.delete().await;
+
+    Ok(())
+}
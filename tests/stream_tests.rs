@@ -35,3 +35,136 @@ pub async fn stream() -> Result<(), DbErr> {
 
     Ok(())
 }
+
+#[sea_orm_macros::test]
+#[cfg(all(feature = "stream", feature = "sqlx-postgres"))]
+pub async fn stream_with_fetch_size() -> Result<(), DbErr> {
+    use futures_util::StreamExt;
+    use sea_orm::{QueryOrder, StreamOptions};
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    let mut ctx = TestContext::new("stream_with_fetch_size").await;
+    create_bakery_table(&ctx.db).await?;
+
+    for chunk in (0..10_000).collect::<Vec<i32>>().chunks(1_000) {
+        Bakery::insert_many(chunk.iter().map(|i| bakery::ActiveModel {
+            name: Set(format!("Bakery {i}")),
+            profit_margin: Set(*i as f64),
+            ..Default::default()
+        }))
+        .exec(&ctx.db)
+        .await?;
+    }
+
+    let fetches = Arc::new(AtomicUsize::new(0));
+    let counter = fetches.clone();
+    ctx.db.set_metric_callback(move |info| {
+        if info.statement.sql.starts_with("FETCH FORWARD 100 ") {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+
+    let mut stream = Bakery::find()
+        .order_by_asc(bakery::Column::Id)
+        .stream_with_options(
+            &ctx.db,
+            StreamOptions {
+                fetch_size: Some(100),
+            },
+        )
+        .await?;
+
+    let mut rows = 0;
+    while let Some(bakery) = stream.next().await {
+        let bakery = bakery?;
+        assert_eq!(bakery.name, format!("Bakery {rows}"));
+        rows += 1;
+        // never more than one batch ahead of the consumer
+        assert!(fetches.load(Ordering::Relaxed) <= rows / 100 + 1);
+    }
+    drop(stream);
+
+    assert_eq!(rows, 10_000);
+    // 100 full batches, plus the empty one that ends the cursor
+    assert_eq!(fetches.load(Ordering::Relaxed), 101);
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "stream")]
+pub async fn stream_buffered_in_transaction() -> Result<(), DbErr> {
+    use futures_util::StreamExt;
+    use sea_orm::{QueryOrder, QuerySelect, TransactionTrait};
+
+    let ctx = TestContext::new("stream_buffered_in_transaction").await;
+    create_bakery_table(&ctx.db).await?;
+
+    Bakery::insert_many((0..25).map(|i| bakery::ActiveModel {
+        name: Set(format!("Bakery {i}")),
+        profit_margin: Set(i as f64),
+        ..Default::default()
+    }))
+    .exec(&ctx.db)
+    .await?;
+
+    let names = ctx
+        .db
+        .transaction::<_, _, DbErr>(|txn| {
+            Box::pin(async move {
+                let mut stream = Bakery::find()
+                    .order_by_asc(bakery::Column::Id)
+                    .stream_buffered(txn, 10)
+                    .await?;
+
+                let mut names = Vec::new();
+                while let Some(bakery) = stream.next().await {
+                    let bakery = bakery?;
+                    // write in the same transaction while the stream is alive
+                    bakery::ActiveModel {
+                        id: Set(bakery.id),
+                        profit_margin: Set(bakery.profit_margin + 100.0),
+                        ..Default::default()
+                    }
+                    .update(txn)
+                    .await?;
+                    names.push(bakery.name);
+                }
+                Ok(names)
+            })
+        })
+        .await
+        .map_err(|e| DbErr::Custom(e.to_string()))?;
+
+    // every row is seen exactly once, in order
+    assert_eq!(
+        names,
+        (0..25).map(|i| format!("Bakery {i}")).collect::<Vec<_>>()
+    );
+    assert!(
+        Bakery::find()
+            .all(&ctx.db)
+            .await?
+            .iter()
+            .all(|bakery| bakery.profit_margin >= 100.0)
+    );
+
+    // the pages can't be combined with a limit of the query
+    assert!(matches!(
+        Bakery::find()
+            .order_by_asc(bakery::Column::Id)
+            .limit(5)
+            .stream_buffered(&ctx.db, 10)
+            .await,
+        Err(DbErr::Custom(_))
+    ));
+
+    ctx.delete().await;
+
+    Ok(())
+}
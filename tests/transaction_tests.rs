@@ -5,7 +5,7 @@ pub mod common;
 pub use common::{TestContext, bakery_chain::*, setup::*};
 use pretty_assertions::assert_eq;
 use sea_orm::{
-    AccessMode, DatabaseTransaction, IsolationLevel, Set, SqliteTransactionMode,
+    AccessMode, DatabaseTransaction, IsolationLevel, Set, SqliteTransactionMode, TransactionError,
     TransactionOptions, TransactionTrait, prelude::*,
 };
 
@@ -363,6 +363,124 @@ pub async fn transaction_closure_rollback() -> Result<(), DbErr> {
     Ok(())
 }
 
+#[sea_orm_macros::test]
+#[cfg(feature = "runtime-tokio")]
+pub async fn transaction_commit_on_success_spawn() -> Result<(), DbErr> {
+    use std::sync::Arc;
+
+    let ctx = TestContext::new("transaction_commit_on_success_spawn_test").await;
+    create_bakery_table(&ctx.db).await?;
+
+    async fn save_in_task(txn: Arc<DatabaseTransaction>) -> Result<(), DbErr> {
+        seaside_bakery().save(txn.as_ref()).await?;
+        Ok(())
+    }
+
+    let res = ctx
+        .db
+        .begin()
+        .await?
+        .commit_on_success::<_, _, DbErr>(async |txn| {
+            let task = tokio::spawn(save_in_task(Arc::clone(&txn)));
+            task.await.expect("task panicked")?;
+            top_bakery().save(txn.as_ref()).await?;
+            assert_eq!(bakery::Entity::find().all(txn.as_ref()).await?.len(), 2);
+            Ok(())
+        })
+        .await;
+
+    assert!(res.is_ok());
+    assert_eq!(bakery::Entity::find().all(&ctx.db).await?.len(), 2);
+
+    let res = ctx
+        .db
+        .begin()
+        .await?
+        .commit_on_success::<_, (), _>(async |txn| {
+            let task = tokio::spawn(save_in_task(Arc::clone(&txn)));
+            task.await.expect("task panicked")?;
+            Err(DbErr::Custom("abort".to_owned()))
+        })
+        .await;
+
+    assert!(matches!(
+        res,
+        Err(TransactionError::<DbErr>::Transaction(_))
+    ));
+    assert_eq!(bakery::Entity::find().all(&ctx.db).await?.len(), 2);
+
+    let mut leaked = None;
+    let res = ctx
+        .db
+        .begin()
+        .await?
+        .commit_on_success::<_, _, DbErr>(async |txn| {
+            top_bakery().save(txn.as_ref()).await?;
+            leaked = Some(txn);
+            Ok(())
+        })
+        .await;
+
+    assert!(matches!(res, Err(TransactionError::Connection(_))));
+    drop(leaked);
+    assert_eq!(bakery::Entity::find().all(&ctx.db).await?.len(), 2);
+
+    ctx.delete().await;
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+pub async fn transaction_with_raw_sqlx_copy_in() -> Result<(), DbErr> {
+    use sea_orm::SqlxConnection;
+
+    let ctx = TestContext::new("transaction_with_raw_sqlx_copy_in_test").await;
+    create_bakery_table(&ctx.db).await?;
+
+    async fn copy_bakeries(conn: SqlxConnection<'_>) -> Result<u64, sea_orm::sqlx::Error> {
+        let SqlxConnection::Postgres(conn) = conn else {
+            panic!("Not Postgres Connection");
+        };
+        let mut copy = conn
+            .copy_in_raw("COPY bakery (name, profit_margin) FROM STDIN")
+            .await?;
+        copy.send(b"Seaside Bakery\t10.4\nTop Bakery\t15\n".as_slice())
+            .await?;
+        copy.finish().await
+    }
+
+    let txn = ctx.db.begin().await?;
+    seaside_bakery().save(&txn).await?;
+    let copied = txn
+        .with_raw_sqlx(copy_bakeries)
+        .await?
+        .map_err(|e| DbErr::Custom(e.to_string()))?;
+    assert_eq!(copied, 2);
+    // the copied rows are visible to SeaORM within the same transaction only
+    assert_eq!(bakery::Entity::find().all(&txn).await?.len(), 3);
+    assert_eq!(bakery::Entity::find().all(&ctx.db).await?.len(), 0);
+    txn.rollback().await?;
+    assert_eq!(bakery::Entity::find().all(&ctx.db).await?.len(), 0);
+
+    let txn = ctx.db.begin().await?;
+    txn.with_raw_sqlx(copy_bakeries)
+        .await?
+        .map_err(|e| DbErr::Custom(e.to_string()))?;
+    txn.commit().await?;
+    assert_eq!(bakery::Entity::find().all(&ctx.db).await?.len(), 2);
+
+    let copied = ctx
+        .db
+        .with_raw_sqlx(copy_bakeries)
+        .await?
+        .map_err(|e| DbErr::Custom(e.to_string()))?;
+    assert_eq!(copied, 2);
+    assert_eq!(bakery::Entity::find().all(&ctx.db).await?.len(), 4);
+
+    ctx.delete().await;
+    Ok(())
+}
+
 #[sea_orm_macros::test]
 pub async fn transaction_with_active_model_behaviour() -> Result<(), DbErr> {
     let ctx = TestContext::new("transaction_with_active_model_behaviour_test").await;
@@ -0,0 +1,73 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{TestContext, bakery_chain::*, setup::*};
+use pretty_assertions::assert_eq;
+pub use sea_orm::entity::*;
+pub use sea_orm::{ConnectionTrait, DbErr, QueryFilter, QueryOrder, query::Update};
+
+#[sea_orm_macros::test]
+pub async fn update_many_models() -> Result<(), DbErr> {
+    let ctx = TestContext::new("update_many_models_tests").await;
+    create_bakery_table(&ctx.db).await?;
+    let db = &ctx.db;
+
+    for (name, profit_margin) in [("Alpha", 1.0), ("Beta", 2.0), ("Gamma", 3.0)] {
+        bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(profit_margin),
+            ..Default::default()
+        }
+        .insert(db)
+        .await?;
+    }
+
+    let bakeries = Bakery::find()
+        .order_by_asc(bakery::Column::Id)
+        .all(db)
+        .await?;
+    let ids: Vec<i32> = bakeries.iter().map(|b| b.id).collect();
+
+    // rename and re-rank the first two bakeries, leave the third untouched
+    let res = Update::many_models([
+        bakery::ActiveModel {
+            id: Unchanged(ids[0]),
+            name: Set("Beta".to_owned()),
+            profit_margin: Set(2.5),
+        },
+        bakery::ActiveModel {
+            id: Unchanged(ids[1]),
+            name: Set("Alpha".to_owned()),
+            profit_margin: Set(1.5),
+        },
+    ])
+    .exec(db)
+    .await?;
+    assert_eq!(res.rows_affected, 2);
+
+    assert_eq!(
+        Bakery::find()
+            .order_by_asc(bakery::Column::Id)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|b| (b.name, b.profit_margin))
+            .collect::<Vec<_>>(),
+        [
+            ("Beta".to_owned(), 2.5),
+            ("Alpha".to_owned(), 1.5),
+            ("Gamma".to_owned(), 3.0),
+        ]
+    );
+
+    // nothing to update, no statement is sent
+    let res = Update::many_models(Vec::<bakery::ActiveModel>::new())
+        .exec(db)
+        .await?;
+    assert_eq!(res.rows_affected, 0);
+
+    ctx.delete().await;
+
+    Ok(())
+}
@@ -4,15 +4,19 @@ pub mod common;
 
 pub use common::{TestContext, features::*, setup::*};
 use pretty_assertions::assert_eq;
-use sea_orm::TryInsertResult;
 use sea_orm::entity::prelude::*;
-use sea_orm::{Set, sea_query::OnConflict};
+use sea_orm::{DbBackend, Set, sea_query::OnConflict};
+use sea_orm::{TryInsertResult, UpsertOutcome};
 
 #[sea_orm_macros::test]
 async fn main() -> Result<(), DbErr> {
     let ctx = TestContext::new("upsert_tests").await;
     create_insert_default_table(&ctx.db).await?;
     create_insert_default(&ctx.db).await?;
+    create_repository_table(&ctx.db).await?;
+    create_edit_log_table(&ctx.db).await?;
+    upsert_with_outcome(&ctx.db).await?;
+    insert_return_existing(&ctx.db).await?;
     ctx.delete().await;
 
     Ok(())
@@ -80,3 +84,97 @@ pub async fn create_insert_default(db: &DatabaseConnection) -> Result<(), DbErr>
 
     Ok(())
 }
+
+pub async fn upsert_with_outcome(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use repository::*;
+
+    let backend = db.get_database_backend();
+    let upsert = |description: &str| {
+        Entity::insert(ActiveModel {
+            id: Set("sea-orm".to_owned()),
+            owner: Set("SeaQL".to_owned()),
+            name: Set("SeaORM".to_owned()),
+            description: Set(Some(description.to_owned())),
+        })
+        .on_conflict(
+            OnConflict::column(Column::Id)
+                .update_column(Column::Description)
+                .to_owned(),
+        )
+    };
+
+    let (model, outcome) = upsert("first").exec_with_returning_outcome(db).await?;
+    assert_eq!(model.description.as_deref(), Some("first"));
+    assert_eq!(
+        outcome,
+        match backend {
+            // the primary key is given, so a fresh insert looks like an unchanged row
+            DbBackend::MySql => UpsertOutcome::Unknown,
+            _ => UpsertOutcome::Inserted,
+        }
+    );
+
+    let (model, outcome) = upsert("second").exec_with_returning_outcome(db).await?;
+    assert_eq!(model.description.as_deref(), Some("second"));
+    assert_eq!(outcome, UpsertOutcome::Updated);
+
+    // without a primary key, SQLite has no key to look up beforehand, and MySQL reads the
+    // row back through `LAST_INSERT_ID`, which an insert and an unchanged row both set
+    let (model, outcome) = edit_log::Entity::insert(edit_log::ActiveModel {
+        action: Set("upsert".to_owned()),
+        values: Set(serde_json::json!({})),
+        ..Default::default()
+    })
+    .on_conflict(
+        OnConflict::column(edit_log::Column::Id)
+            .update_column(edit_log::Column::Action)
+            .to_owned(),
+    )
+    .exec_with_returning_outcome(db)
+    .await?;
+    assert_eq!(model.action, "upsert");
+    assert_eq!(
+        outcome,
+        match backend {
+            DbBackend::Sqlite | DbBackend::MySql => UpsertOutcome::Unknown,
+            _ => UpsertOutcome::Inserted,
+        }
+    );
+
+    Ok(())
+}
+
+pub async fn insert_return_existing(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use repository::*;
+
+    // needs `RETURNING` to hand back the existing row
+    if !db.support_returning() {
+        return Ok(());
+    }
+
+    let insert = |name: &str| {
+        Entity::insert(ActiveModel {
+            id: Set("sea-query".to_owned()),
+            owner: Set("SeaQL".to_owned()),
+            name: Set(name.to_owned()),
+            description: Set(None),
+        })
+        .on_conflict_do_nothing_return_existing()
+    };
+
+    let res = insert("SeaQuery").exec_with_returning(db).await?;
+    let TryInsertResult::Inserted(model) = res else {
+        panic!("the row should be inserted");
+    };
+    assert_eq!(model.name, "SeaQuery");
+
+    // the existing row comes back with its original values
+    let res = insert("SeaQuery 2").exec_with_returning(db).await?;
+    let TryInsertResult::Inserted(existing) = res else {
+        panic!("the existing row should be returned");
+    };
+    assert_eq!(existing, model);
+    assert_eq!(Entity::find_by_id("sea-query").one(db).await?, Some(model));
+
+    Ok(())
+}
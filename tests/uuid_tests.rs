@@ -13,6 +13,7 @@ async fn main() -> Result<(), DbErr> {
     create_metadata_table(&ctx.db).await?;
     create_and_update_metadata(&ctx.db).await?;
     insert_metadata(&ctx.db).await?;
+    save_metadata_by_lookup(&ctx.db).await?;
     ctx.delete().await;
 
     Ok(())
@@ -110,3 +111,54 @@ pub async fn create_and_update_metadata(db: &DatabaseConnection) -> Result<(), D
 
     Ok(())
 }
+
+pub async fn save_metadata_by_lookup(db: &DatabaseConnection) -> Result<(), DbErr> {
+    async fn save(db: &DatabaseConnection, metadata: &metadata::Model) -> Result<(), DbErr> {
+        metadata
+            .clone()
+            .into_active_model()
+            .reset_all()
+            .save_by_lookup(db)
+            .await?;
+        Ok(())
+    }
+
+    let mut metadata = metadata::Model {
+        uuid: Uuid::new_v4(),
+        ty: "Type".to_owned(),
+        key: "lookup".to_owned(),
+        value: "1.18".to_owned(),
+        bytes: vec![4, 5, 6],
+        date: None,
+        time: None,
+    };
+
+    // a new row is inserted, where `save` would update and fail
+    assert_eq!(
+        metadata
+            .clone()
+            .into_active_model()
+            .reset_all()
+            .save(db)
+            .await,
+        Err(DbErr::RecordNotUpdated)
+    );
+    save(db, &metadata).await?;
+    assert_eq!(
+        Metadata::find_by_id(metadata.uuid).one(db).await?,
+        Some(metadata.clone())
+    );
+
+    // the existing row is updated through the same code path
+    metadata.value = "0.22".to_owned();
+    save(db, &metadata).await?;
+    assert_eq!(
+        Metadata::find()
+            .filter(metadata::Column::Key.eq("lookup"))
+            .all(db)
+            .await?,
+        [metadata]
+    );
+
+    Ok(())
+}